@@ -69,9 +69,20 @@ impl CommonCompression {
     }
 
     pub fn compress(&self, input_buf: &[u8], output_buf: &mut Vec<u8>) -> Result<usize> {
+        self.compress_with_level(input_buf, output_buf, 0)
+    }
+
+    /// Compress with the given level, only zstd takes the level into account
+    /// and 0 means the default level of it.
+    pub fn compress_with_level(
+        &self,
+        input_buf: &[u8],
+        output_buf: &mut Vec<u8>,
+        level: i32,
+    ) -> Result<usize> {
         match self {
             Self::Lz4 => compress_lz4(input_buf, output_buf),
-            Self::Zstd => compress_zstd(input_buf, output_buf),
+            Self::Zstd => compress_zstd(input_buf, output_buf, level),
             Self::Snappy => compress_snappy(input_buf, output_buf),
             Self::None => {
                 output_buf.extend_from_slice(input_buf);
@@ -117,14 +128,14 @@ pub fn compress_lz4(input_buf: &[u8], output_buf: &mut Vec<u8>) -> Result<usize>
     Ok(size)
 }
 
-pub fn compress_zstd(input_buf: &[u8], output_buf: &mut Vec<u8>) -> Result<usize> {
+pub fn compress_zstd(input_buf: &[u8], output_buf: &mut Vec<u8>, level: i32) -> Result<usize> {
     let bound = zstd::zstd_safe::compress_bound(input_buf.len());
     let len = output_buf.len();
     output_buf.reserve(bound);
 
     let s = unsafe { core::slice::from_raw_parts_mut(output_buf.as_mut_ptr().add(len), bound) };
 
-    let size = zstd::bulk::compress_to_buffer(input_buf, s, 0).map_err(|e| {
+    let size = zstd::bulk::compress_to_buffer(input_buf, s, level).map_err(|e| {
         crate::arrow::error::Error::External("Compress zstd failed".to_owned(), Box::new(e))
     })?;
 
@@ -70,7 +70,8 @@ pub fn compress_binary<O: Offset>(
             let pos = buf.len();
             buf.extend_from_slice(&[0u8; 8]);
 
-            let compressed_size = c.compress(input_buf, buf)?;
+            let compressed_size =
+                c.compress_with_level(input_buf, buf, write_options.compression_level)?;
 
             buf[pos..pos + 4].copy_from_slice(&(compressed_size as u32).to_le_bytes());
             buf[pos + 4..pos + 8].copy_from_slice(&(input_buf.len() as u32).to_le_bytes());
@@ -86,7 +87,8 @@ pub fn compress_binary<O: Offset>(
             let pos = buf.len();
             buf.extend_from_slice(&[0u8; 8]);
 
-            let compressed_size = c.compress(input_buf, buf)?;
+            let compressed_size =
+                c.compress_with_level(input_buf, buf, write_options.compression_level)?;
             buf[pos..pos + 4].copy_from_slice(&(compressed_size as u32).to_le_bytes());
             buf[pos + 4..pos + 8].copy_from_slice(&(input_buf.len() as u32).to_le_bytes());
         }
@@ -345,6 +347,9 @@ fn choose_compressor<O: Offset>(
                 continue;
             }
             let r = encoder.compress_ratio(stats);
+            if r > 0.0f64 && write_options.preferred_compression == Some(encoder.to_compression()) {
+                return BinaryCompressor::Extend(encoder);
+            }
             if r > max_ratio {
                 max_ratio = r;
                 result = BinaryCompressor::Extend(encoder);
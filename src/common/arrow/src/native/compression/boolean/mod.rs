@@ -63,7 +63,7 @@ pub fn compress_boolean(
                 bitmap.clone()
             };
             let (slice, _, _) = bitmap.as_slice();
-            c.compress(slice, buf)
+            c.compress_with_level(slice, buf, write_options.compression_level)
         }
         BooleanCompressor::Extend(c) => c.compress(array, buf),
     }?;
@@ -234,6 +234,9 @@ fn choose_compressor(
             }
 
             let r = c.compress_ratio(stats);
+            if r > 0.0f64 && write_options.preferred_compression == Some(c.to_compression()) {
+                return BooleanCompressor::Extend(c);
+            }
             if r > max_ratio {
                 max_ratio = r;
                 result = BooleanCompressor::Extend(c);
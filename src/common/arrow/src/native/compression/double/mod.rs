@@ -64,7 +64,7 @@ pub fn compress_double<T: DoubleType>(
     let compressed_size = match compressor {
         DoubleCompressor::Basic(c) => {
             let input_buf = bytemuck::cast_slice(array.values());
-            c.compress(input_buf, buf)
+            c.compress_with_level(input_buf, buf, write_options.compression_level)
         }
         DoubleCompressor::Extend(c) => c.compress(array, &stats, &write_options, buf),
     }?;
@@ -295,6 +295,9 @@ fn choose_compressor<T: DoubleType>(
                 continue;
             }
             let r = c.compress_ratio(stats);
+            if r > 0.0f64 && write_options.preferred_compression == Some(c.to_compression()) {
+                return DoubleCompressor::Extend(c);
+            }
 
             log::debug!(
                 "compress ratio {:?} : {}, max_ratio: {}",
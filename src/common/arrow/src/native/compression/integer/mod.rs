@@ -68,7 +68,7 @@ pub fn compress_integer<T: IntegerType>(
     let compressed_size = match compressor {
         IntCompressor::Basic(c) => {
             let input_buf = bytemuck::cast_slice(array.values());
-            c.compress(input_buf, buf)
+            c.compress_with_level(input_buf, buf, write_options.compression_level)
         }
         IntCompressor::Extend(c) => c.compress(array, &stats, &write_options, buf),
     }?;
@@ -297,6 +297,9 @@ fn choose_compressor<T: IntegerType>(
                 continue;
             }
             let r = c.compress_ratio(stats);
+            if r > 0.0f64 && write_options.preferred_compression == Some(c.to_compression()) {
+                return IntCompressor::Extend(c);
+            }
 
             log::debug!(
                 "compress ratio {:?} : {}, max_ratio: {}",
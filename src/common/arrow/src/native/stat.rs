@@ -199,6 +199,7 @@ mod test {
             max_page_size: Some(PAGE_SIZE),
             default_compress_ratio: Some(1.2),
             forbidden_compressions: vec![],
+            ..Default::default()
         };
 
         let mut bytes = Vec::new();
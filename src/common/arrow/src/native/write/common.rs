@@ -40,6 +40,12 @@ pub struct WriteOptions {
     pub default_compress_ratio: Option<f64>,
     pub max_page_size: Option<usize>,
     pub forbidden_compressions: Vec<Compression>,
+    /// The level of the default compression, 0 means the default level of it.
+    /// Only zstd supports the level for now.
+    pub compression_level: i32,
+    /// The encoding to use whenever it is applicable to the data, instead of the
+    /// one with the best compress ratio.
+    pub preferred_compression: Option<Compression>,
 }
 
 impl<W: Write> NativeWriter<W> {
@@ -51,11 +57,17 @@ impl<W: Write> NativeWriter<W> {
             .unwrap_or(chunk.len())
             .min(chunk.len());
 
-        for (array, type_) in chunk
+        for (index, (array, type_)) in chunk
             .arrays()
             .iter()
             .zip(self.schema_descriptor.fields().to_vec())
+            .enumerate()
         {
+            let options = self
+                .column_options
+                .get(&index)
+                .unwrap_or(&self.options)
+                .clone();
             let array = array.as_ref();
             let nested = to_nested(array, &type_)?;
             let types: Vec<parquet2::schema::types::PrimitiveType> = to_parquet_leaves(type_);
@@ -88,7 +100,7 @@ impl<W: Write> NativeWriter<W> {
                             &sub_nested,
                             type_.clone(),
                             length,
-                            self.options.clone(),
+                            options.clone(),
                             &mut self.scratch,
                         )
                         .unwrap();
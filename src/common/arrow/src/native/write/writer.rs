@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::io::Write;
 
 use super::super::ARROW_MAGIC;
@@ -42,6 +43,8 @@ pub struct NativeWriter<W: Write> {
     pub(crate) writer: OffsetWriter<W>,
     /// pa write options
     pub(crate) options: WriteOptions,
+    /// Write options of the fields that override `options`, keyed by the field index
+    pub(crate) column_options: HashMap<usize, WriteOptions>,
     /// A reference to the schema, used in validating record batches
     pub(crate) schema: Schema,
     pub(crate) schema_descriptor: SchemaDescriptor,
@@ -73,6 +76,7 @@ impl<W: Write> NativeWriter<W> {
                 offset: 0,
             },
             options,
+            column_options: HashMap::new(),
             schema,
             schema_descriptor,
             metas: Vec::with_capacity(num_cols),
@@ -81,6 +85,13 @@ impl<W: Write> NativeWriter<W> {
         })
     }
 
+    /// Sets the write options of some fields, fields not in `column_options`
+    /// are written with the default options.
+    pub fn with_column_options(mut self, column_options: HashMap<usize, WriteOptions>) -> Self {
+        self.column_options = column_options;
+        self
+    }
+
     /// Consumes itself into the inner writer
     pub fn into_inner(self) -> W {
         self.writer.w
@@ -447,6 +447,7 @@ fn test_write_read(chunk: Chunk<Box<dyn Array>>) {
                 max_page_size: Some(*page_size),
                 default_compress_ratio: Some(2.0f64),
                 forbidden_compressions: vec![],
+                ..Default::default()
             });
         }
     }
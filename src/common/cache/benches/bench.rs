@@ -0,0 +1,99 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use criterion::black_box;
+use criterion::Criterion;
+use databend_common_cache::Cache;
+use databend_common_cache::LruCache;
+use databend_common_cache::S3FifoCache;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+const CACHE_CAPACITY: usize = 2000;
+const HOT_KEYS: usize = 1000;
+const HOT_ACCESSES_PER_ROUND: usize = 20000;
+const SCAN_KEYS_PER_ROUND: usize = 5000;
+const ROUNDS: usize = 10;
+
+// Dashboard-like accesses to a hot set of keys, interleaved with ad-hoc scans
+// reading keys which are never accessed again.
+fn workload() -> Vec<String> {
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut keys = Vec::with_capacity(ROUNDS * (HOT_ACCESSES_PER_ROUND + SCAN_KEYS_PER_ROUND));
+    for round in 0..ROUNDS {
+        for _ in 0..HOT_ACCESSES_PER_ROUND {
+            keys.push(format!("hot_{}", rng.gen_range(0..HOT_KEYS)));
+        }
+        for i in 0..SCAN_KEYS_PER_ROUND {
+            keys.push(format!("scan_{}_{}", round, i));
+        }
+    }
+    keys
+}
+
+fn replay<C: Cache<String, ()>>(cache: &mut C, workload: &[String]) -> usize {
+    let mut hits = 0;
+    for key in workload {
+        if cache.get(key).is_some() {
+            hits += 1;
+        } else {
+            cache.insert(key.clone(), ());
+        }
+    }
+    hits
+}
+
+fn bench(c: &mut Criterion) {
+    let workload = workload();
+
+    let lru_hits = replay(
+        &mut LruCache::with_items_capacity(CACHE_CAPACITY),
+        &workload,
+    );
+    let s3fifo_hits = replay(
+        &mut S3FifoCache::with_items_capacity(CACHE_CAPACITY),
+        &workload,
+    );
+    println!(
+        "hit ratio of {} accesses, lru: {:.2}%, s3fifo: {:.2}%",
+        workload.len(),
+        lru_hits as f64 * 100.0 / workload.len() as f64,
+        s3fifo_hits as f64 * 100.0 / workload.len() as f64,
+    );
+
+    let mut group = c.benchmark_group("bench_cache_policy");
+    group.sample_size(10);
+
+    group.bench_function("lru_hot_set_with_scans", |b| {
+        b.iter(|| {
+            let mut cache = LruCache::with_items_capacity(CACHE_CAPACITY);
+            black_box(replay(&mut cache, &workload));
+        })
+    });
+    group.bench_function("s3fifo_hot_set_with_scans", |b| {
+        b.iter(|| {
+            let mut cache = S3FifoCache::with_items_capacity(CACHE_CAPACITY);
+            black_box(replay(&mut cache, &workload));
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,129 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use crate::cache::lru::LruCache;
+use crate::cache::s3fifo::S3FifoCache;
+use crate::cache::Cache;
+use crate::mem_sized::MemSized;
+
+/// A cache whose eviction policy is chosen at runtime, e.g. from the configuration.
+#[derive(Clone)]
+pub enum PolicyCache<K: Eq + Hash + MemSized, V: MemSized> {
+    Lru(LruCache<K, V>),
+    S3Fifo(S3FifoCache<K, V>),
+}
+
+impl<K: Eq + Hash + MemSized, V: MemSized> PolicyCache<K, V> {
+    /// Returns the name of the eviction policy.
+    pub fn policy_name(&self) -> &'static str {
+        match self {
+            PolicyCache::Lru(_) => "lru",
+            PolicyCache::S3Fifo(_) => "s3fifo",
+        }
+    }
+}
+
+impl<K: Eq + Hash + MemSized, V: MemSized> From<LruCache<K, V>> for PolicyCache<K, V> {
+    fn from(cache: LruCache<K, V>) -> Self {
+        PolicyCache::Lru(cache)
+    }
+}
+
+impl<K: Eq + Hash + MemSized, V: MemSized> From<S3FifoCache<K, V>> for PolicyCache<K, V> {
+    fn from(cache: S3FifoCache<K, V>) -> Self {
+        PolicyCache::S3Fifo(cache)
+    }
+}
+
+macro_rules! dispatch {
+    ($self:expr, $cache:ident => $body:expr) => {
+        match $self {
+            PolicyCache::Lru($cache) => $body,
+            PolicyCache::S3Fifo($cache) => $body,
+        }
+    };
+}
+
+impl<K: Eq + Hash + MemSized, V: MemSized> Cache<K, V> for PolicyCache<K, V> {
+    fn get<Q>(&mut self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        dispatch!(self, cache => cache.get(k))
+    }
+
+    fn peek<Q>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        dispatch!(self, cache => cache.peek(k))
+    }
+
+    fn peek_by_policy(&self) -> Option<(&K, &V)> {
+        dispatch!(self, cache => cache.peek_by_policy())
+    }
+
+    fn insert(&mut self, k: K, v: V) -> Option<V> {
+        dispatch!(self, cache => cache.insert(k, v))
+    }
+
+    fn pop<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        dispatch!(self, cache => cache.pop(k))
+    }
+
+    fn pop_by_policy(&mut self) -> Option<(K, V)> {
+        dispatch!(self, cache => cache.pop_by_policy())
+    }
+
+    fn contains<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        dispatch!(self, cache => cache.contains(k))
+    }
+
+    fn len(&self) -> usize {
+        dispatch!(self, cache => cache.len())
+    }
+
+    fn is_empty(&self) -> bool {
+        dispatch!(self, cache => cache.is_empty())
+    }
+
+    fn bytes_capacity(&self) -> u64 {
+        dispatch!(self, cache => cache.bytes_capacity())
+    }
+
+    fn items_capacity(&self) -> u64 {
+        dispatch!(self, cache => cache.items_capacity())
+    }
+
+    fn bytes_size(&self) -> u64 {
+        dispatch!(self, cache => cache.bytes_size())
+    }
+
+    fn clear(&mut self) {
+        dispatch!(self, cache => cache.clear())
+    }
+}
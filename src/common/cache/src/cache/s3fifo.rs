@@ -0,0 +1,296 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::hash::Hash;
+
+use hashlink::LinkedHashMap;
+use hashlink::LinkedHashSet;
+
+use crate::cache::Cache;
+use crate::mem_sized::MemSized;
+
+/// Upper bound of the access frequency tracked for each entry.
+const MAX_FREQUENCY: u8 = 3;
+
+/// Share (in percent) of the capacity used by the small queue.
+const SMALL_QUEUE_PERCENTAGE: usize = 10;
+
+#[derive(Clone)]
+struct Entry<V> {
+    value: V,
+    freq: u8,
+}
+
+/// A scan-resistant cache using the S3-FIFO eviction policy.
+///
+/// New entries are admitted into a small FIFO queue. When evicted from it, entries that have
+/// been accessed again are promoted to the main queue, the others are dropped and their key
+/// hashes are remembered in a ghost queue: if such a key is inserted again, it goes directly
+/// into the main queue. The main queue is a FIFO with reinsertion, entries accessed since they
+/// were last examined are given another round instead of being evicted.
+///
+/// Entries that are only touched once, like the blocks read by a large ad-hoc scan, never leave
+/// the small queue, so they can not flush the frequently accessed entries out of the cache.
+///
+/// See "FIFO queues are all you need for cache eviction" (SOSP '23).
+#[derive(Clone)]
+pub struct S3FifoCache<K: Eq + Hash + MemSized, V: MemSized> {
+    small: LinkedHashMap<K, Entry<V>>,
+    main: LinkedHashMap<K, Entry<V>>,
+    ghost: LinkedHashSet<u64>,
+    hash_builder: RandomState,
+    max_items: usize,
+    max_bytes: usize,
+    small_bytes: usize,
+    main_bytes: usize,
+}
+
+impl<K: Eq + Hash + MemSized, V: MemSized> S3FifoCache<K, V> {
+    /// Creates an empty cache that can hold at most `items_capacity` items.
+    pub fn with_items_capacity(items_capacity: usize) -> Self {
+        Self::new(items_capacity, usize::MAX)
+    }
+
+    /// Creates an empty cache that can hold at most `bytes_capacity` bytes of values.
+    pub fn with_bytes_capacity(bytes_capacity: usize) -> Self {
+        Self::new(usize::MAX, bytes_capacity)
+    }
+
+    fn new(max_items: usize, max_bytes: usize) -> Self {
+        S3FifoCache {
+            small: LinkedHashMap::new(),
+            main: LinkedHashMap::new(),
+            ghost: LinkedHashSet::new(),
+            hash_builder: RandomState::new(),
+            max_items,
+            max_bytes,
+            small_bytes: 0,
+            main_bytes: 0,
+        }
+    }
+
+    /// Returns the number of entries in the small queue.
+    pub fn small_len(&self) -> usize {
+        self.small.len()
+    }
+
+    /// Returns the number of entries in the main queue.
+    pub fn main_len(&self) -> usize {
+        self.main.len()
+    }
+
+    fn is_full(&self) -> bool {
+        self.len() > self.max_items || self.small_bytes + self.main_bytes > self.max_bytes
+    }
+
+    // Whether the small queue holds at least its share of the capacity.
+    fn small_is_full(&self) -> bool {
+        if self.max_bytes == usize::MAX {
+            self.small.len() >= self.max_items.saturating_mul(SMALL_QUEUE_PERCENTAGE) / 100
+        } else {
+            self.small_bytes >= self.max_bytes.saturating_mul(SMALL_QUEUE_PERCENTAGE) / 100
+        }
+    }
+
+    fn evict_from_small(&self) -> bool {
+        !self.small.is_empty() && (self.small_is_full() || self.main.is_empty())
+    }
+
+    fn remember(&mut self, k: &K) {
+        let hash = self.hash_builder.hash_one(k);
+        self.ghost.insert(hash);
+        // the ghost queue tracks about as many keys as the cache holds
+        while self.ghost.len() > self.len().max(1) {
+            self.ghost.pop_front();
+        }
+    }
+}
+
+impl<K: Eq + Hash + MemSized, V: MemSized> Cache<K, V> for S3FifoCache<K, V> {
+    /// Returns a reference to the value corresponding to the given key in the cache, if
+    /// any, and records the access.
+    fn get<Q>(&mut self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = if self.small.contains_key(k) {
+            self.small.get_mut(k)
+        } else {
+            self.main.get_mut(k)
+        };
+
+        entry.map(|entry| {
+            entry.freq = (entry.freq + 1).min(MAX_FREQUENCY);
+            &entry.value
+        })
+    }
+
+    /// Returns a reference to the value corresponding to the key in the cache or `None` if it is
+    /// not present in the cache. Unlike `get`, `peek` does not record the access.
+    fn peek<Q>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.small
+            .get(k)
+            .or_else(|| self.main.get(k))
+            .map(|entry| &entry.value)
+    }
+
+    /// Returns the entry that will be examined by the next eviction. Note that the entry may
+    /// be given another round instead of being evicted, if it has been accessed.
+    fn peek_by_policy(&self) -> Option<(&K, &V)> {
+        let queue = if self.evict_from_small() {
+            &self.small
+        } else {
+            &self.main
+        };
+        queue.front().map(|(k, entry)| (k, &entry.value))
+    }
+
+    /// Inserts a key-value pair into the cache. If the key already existed, the old value is
+    /// returned and the entry keeps its position.
+    fn insert(&mut self, k: K, v: V) -> Option<V> {
+        let bytes = v.mem_bytes();
+
+        if let Some(entry) = self.small.get_mut(&k) {
+            let old = std::mem::replace(&mut entry.value, v);
+            self.small_bytes += bytes;
+            self.small_bytes -= old.mem_bytes();
+            while self.is_full() && self.pop_by_policy().is_some() {}
+            return Some(old);
+        }
+
+        if let Some(entry) = self.main.get_mut(&k) {
+            let old = std::mem::replace(&mut entry.value, v);
+            self.main_bytes += bytes;
+            self.main_bytes -= old.mem_bytes();
+            while self.is_full() && self.pop_by_policy().is_some() {}
+            return Some(old);
+        }
+
+        // make room before admitting the new entry, so that it is not the first one examined
+        while (self.len() >= self.max_items
+            || (self.small_bytes + self.main_bytes).saturating_add(bytes) > self.max_bytes)
+            && self.pop_by_policy().is_some()
+        {}
+
+        let hash = self.hash_builder.hash_one(&k);
+        let entry = Entry { value: v, freq: 0 };
+        if self.ghost.remove(&hash) {
+            self.main_bytes += bytes;
+            self.main.insert(k, entry);
+        } else {
+            self.small_bytes += bytes;
+            self.small.insert(k, entry);
+        }
+
+        // the value itself may exceed the capacity
+        while self.is_full() && self.pop_by_policy().is_some() {}
+        None
+    }
+
+    /// Removes the given key from the cache and returns its corresponding value.
+    fn pop<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if let Some(entry) = self.small.remove(k) {
+            self.small_bytes -= entry.value.mem_bytes();
+            return Some(entry.value);
+        }
+
+        self.main.remove(k).map(|entry| {
+            self.main_bytes -= entry.value.mem_bytes();
+            entry.value
+        })
+    }
+
+    /// Evicts an entry according to the S3-FIFO policy and returns it. Accessed entries met on
+    /// the way are promoted to (or reinserted into) the main queue.
+    fn pop_by_policy(&mut self) -> Option<(K, V)> {
+        loop {
+            if self.evict_from_small() {
+                let (k, mut entry) = self.small.pop_front()?;
+                let bytes = entry.value.mem_bytes();
+                self.small_bytes -= bytes;
+                if entry.freq > 0 {
+                    entry.freq = 0;
+                    self.main_bytes += bytes;
+                    self.main.insert(k, entry);
+                } else {
+                    self.remember(&k);
+                    return Some((k, entry.value));
+                }
+            } else {
+                let (k, mut entry) = self.main.pop_front()?;
+                if entry.freq > 0 {
+                    entry.freq -= 1;
+                    self.main.insert(k, entry);
+                } else {
+                    self.main_bytes -= entry.value.mem_bytes();
+                    return Some((k, entry.value));
+                }
+            }
+        }
+    }
+
+    /// Checks if the map contains the given key.
+    fn contains<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.small.contains_key(k) || self.main.contains_key(k)
+    }
+
+    /// Returns the number of key-value pairs in the cache.
+    fn len(&self) -> usize {
+        self.small.len() + self.main.len()
+    }
+
+    /// Returns `true` if the cache contains no key-value pairs.
+    fn is_empty(&self) -> bool {
+        self.small.is_empty() && self.main.is_empty()
+    }
+
+    /// Returns the maximum bytes size of the key-value pairs the cache can hold.
+    fn bytes_capacity(&self) -> u64 {
+        self.max_bytes as u64
+    }
+
+    fn items_capacity(&self) -> u64 {
+        self.max_items as u64
+    }
+
+    /// Returns the bytes size of all the key-value pairs in the cache.
+    fn bytes_size(&self) -> u64 {
+        (self.small_bytes + self.main_bytes) as u64
+    }
+
+    /// Removes all key-value pairs from the cache.
+    fn clear(&mut self) {
+        self.small.clear();
+        self.main.clear();
+        self.ghost.clear();
+        self.small_bytes = 0;
+        self.main_bytes = 0;
+    }
+}
@@ -19,6 +19,8 @@ mod cache;
 mod mem_sized;
 
 pub use cache::lru::LruCache;
+pub use cache::policy::PolicyCache;
+pub use cache::s3fifo::S3FifoCache;
 pub use cache::Cache;
 pub use hashbrown::hash_map::DefaultHashBuilder;
 pub use mem_sized::MemSized;
@@ -0,0 +1,149 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_cache::Cache;
+use databend_common_cache::MemSized;
+use databend_common_cache::S3FifoCache;
+
+#[derive(Eq, PartialEq, Hash, Debug)]
+struct TestKey(pub i32);
+
+impl MemSized for TestKey {
+    fn mem_bytes(&self) -> usize {
+        0
+    }
+}
+
+#[derive(Eq, PartialEq, Debug)]
+struct TestValue(pub Vec<u8>);
+
+impl MemSized for TestValue {
+    fn mem_bytes(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[test]
+fn test_put_and_get() {
+    let mut cache = S3FifoCache::with_items_capacity(2);
+    cache.insert(TestKey(1), TestValue(vec![1]));
+    cache.insert(TestKey(2), TestValue(vec![2]));
+    assert_eq!(cache.get(&TestKey(1)), Some(&TestValue(vec![1])));
+    assert_eq!(cache.get(&TestKey(2)), Some(&TestValue(vec![2])));
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.bytes_size(), 2);
+}
+
+#[test]
+fn test_put_update() {
+    let mut cache = S3FifoCache::with_items_capacity(1);
+    cache.insert(TestKey(1), TestValue(vec![1]));
+    let old = cache.insert(TestKey(1), TestValue(vec![1, 9]));
+    assert_eq!(old, Some(TestValue(vec![1])));
+    assert_eq!(cache.get(&TestKey(1)), Some(&TestValue(vec![1, 9])));
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.bytes_size(), 2);
+}
+
+#[test]
+fn test_pop() {
+    let mut cache = S3FifoCache::with_items_capacity(2);
+    cache.insert(TestKey(1), TestValue(vec![1]));
+    cache.insert(TestKey(2), TestValue(vec![2]));
+    assert_eq!(cache.pop(&TestKey(1)), Some(TestValue(vec![1])));
+    assert!(cache.get(&TestKey(1)).is_none());
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.bytes_size(), 1);
+}
+
+#[test]
+fn test_scan_resistance() {
+    let mut cache = S3FifoCache::with_items_capacity(10);
+    for i in 0..5 {
+        cache.insert(TestKey(i), TestValue(vec![]));
+    }
+    for i in 0..5 {
+        assert!(cache.get(&TestKey(i)).is_some());
+    }
+
+    // a scan of keys which are accessed only once
+    for i in 100..200 {
+        cache.insert(TestKey(i), TestValue(vec![]));
+    }
+
+    assert_eq!(cache.len(), 10);
+    assert_eq!(cache.main_len(), 5);
+    for i in 0..5 {
+        assert!(cache.contains(&TestKey(i)));
+    }
+}
+
+#[test]
+fn test_ghost_admission() {
+    let mut cache = S3FifoCache::with_items_capacity(10);
+    for i in 0..11 {
+        cache.insert(TestKey(i), TestValue(vec![]));
+    }
+    assert!(!cache.contains(&TestKey(0)));
+    assert_eq!(cache.main_len(), 0);
+
+    // evicted recently, goes to the main queue directly
+    cache.insert(TestKey(0), TestValue(vec![]));
+    assert!(cache.contains(&TestKey(0)));
+    assert!(!cache.contains(&TestKey(1)));
+    assert_eq!(cache.main_len(), 1);
+    assert_eq!(cache.small_len(), 9);
+}
+
+#[test]
+fn test_metered_cache() {
+    let mut cache = S3FifoCache::with_bytes_capacity(10);
+    for i in 1..=5 {
+        cache.insert(TestKey(i), TestValue(vec![0, 0]));
+    }
+    assert_eq!(cache.bytes_size(), 10);
+
+    cache.insert(TestKey(6), TestValue(vec![0, 0]));
+    assert_eq!(cache.bytes_size(), 10);
+    assert!(!cache.contains(&TestKey(1)));
+
+    // accessed entries are promoted instead of being evicted
+    assert!(cache.get(&TestKey(2)).is_some());
+    cache.insert(TestKey(7), TestValue(vec![0, 0]));
+    assert!(cache.contains(&TestKey(2)));
+    assert!(!cache.contains(&TestKey(3)));
+    assert_eq!(cache.main_len(), 1);
+    assert_eq!(cache.bytes_size(), 10);
+}
+
+#[test]
+fn test_metered_cache_oversize() {
+    let mut cache = S3FifoCache::with_bytes_capacity(2);
+    cache.insert(TestKey(1), TestValue(vec![1, 2]));
+    cache.insert(TestKey(2), TestValue(vec![3, 4, 5, 6]));
+    assert_eq!(cache.bytes_size(), 0);
+    assert!(!cache.contains(&TestKey(1)));
+    assert!(!cache.contains(&TestKey(2)));
+}
+
+#[test]
+fn test_clear() {
+    let mut cache = S3FifoCache::with_items_capacity(2);
+    cache.insert(TestKey(1), TestValue(vec![1]));
+    cache.insert(TestKey(2), TestValue(vec![2]));
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.bytes_size(), 0);
+    assert!(cache.get(&TestKey(1)).is_none());
+}
@@ -157,6 +157,8 @@ build_exceptions! {
     WrongSequenceCount(1125),
     UnknownSequence(1126),
     UnknownQuery(1127),
+    UnknownPreparedStatement(1128),
+    ConnectByLoop(1129),
 
     // Data Related Errors
 
@@ -180,6 +182,10 @@ build_exceptions! {
     ///
     /// For example: try to with 3 columns into a table with 4 columns.
     TableSchemaMismatch(1303),
+    /// ConstraintViolated is used when the data written violates a table constraint.
+    ///
+    /// For example: insert a row with a duplicated primary key.
+    ConstraintViolated(1304),
 
     // License related errors starts here
 
@@ -256,6 +262,10 @@ build_exceptions! {
     RoleAlreadyExists(2216),
     IllegalRole(2217),
     IllegalUser(2218),
+    UnknownWorkloadGroup(2219),
+    WorkloadGroupAlreadyExists(2220),
+    IllegalWorkloadGroup(2221),
+    WorkloadGroupIsUsed(2222),
 
     // Meta api error codes.
     DatabaseAlreadyExists(2301),
@@ -363,6 +373,10 @@ build_exceptions! {
     // dynamic error codes.
     IllegalDynamicTable(2740),
 
+    // Table tag and branch error codes.
+    UnknownTableRef(2750),
+    TableRefAlreadyExists(2751),
+
     // Variable error codes.
     UnknownVariable(2801),
     OnlySupportAsciiChars(2802),
@@ -376,6 +390,7 @@ build_exceptions! {
     // Script error codes.
     ScriptSemanticError(3001),
     ScriptExecutionError(3002),
+    ScriptUserException(3003),
 
     // sequence
     SequenceError(3101),
@@ -429,5 +444,7 @@ build_exceptions! {
     SessionTokenExpired(5101),
     RefreshTokenExpired(5102),
     SessionTokenNotFound(5103),
-    RefreshTokenNotFound(5104)
+    RefreshTokenNotFound(5104),
+    // the client ip is rejected by the network policy of the user
+    NetworkPolicyRejected(5105)
 }
@@ -35,6 +35,10 @@ static CACHE_MISS_LOAD_MILLISECOND: LazyLock<FamilyHistogram<CacheLabels>> =
     LazyLock::new(|| register_histogram_family_in_milliseconds("cache_miss_load_millisecond"));
 static CACHE_HIT_COUNT: LazyLock<FamilyCounter<CacheLabels>> =
     LazyLock::new(|| register_counter_family("cache_hit_count"));
+static CACHE_ADMISSION_COUNT: LazyLock<FamilyCounter<CacheLabels>> =
+    LazyLock::new(|| register_counter_family("cache_admission_count"));
+static CACHE_EVICTION_COUNT: LazyLock<FamilyCounter<CacheLabels>> =
+    LazyLock::new(|| register_counter_family("cache_eviction_count"));
 static CACHE_POPULATION_PENDING_COUNT: LazyLock<FamilyCounter<CacheLabels>> =
     LazyLock::new(|| register_counter_family("cache_population_pending_count"));
 static CACHE_POPULATION_OVERFLOW_COUNT: LazyLock<FamilyCounter<CacheLabels>> =
@@ -52,6 +56,14 @@ pub fn get_cache_miss_count(cache_name: &str) -> u64 {
     get_metric_count_by_name(&CACHE_MISS_COUNT, cache_name)
 }
 
+pub fn get_cache_admission_count(cache_name: &str) -> u64 {
+    get_metric_count_by_name(&CACHE_ADMISSION_COUNT, cache_name)
+}
+
+pub fn get_cache_eviction_count(cache_name: &str) -> u64 {
+    get_metric_count_by_name(&CACHE_EVICTION_COUNT, cache_name)
+}
+
 fn get_metric_count_by_name(
     metric: &LazyLock<FamilyCounter<CacheLabels>>,
     cache_name: &str,
@@ -105,6 +117,22 @@ pub fn metrics_inc_cache_hit_count(c: u64, cache_name: &str) {
         .inc_by(c);
 }
 
+pub fn metrics_inc_cache_admission_count(c: u64, cache_name: &str) {
+    CACHE_ADMISSION_COUNT
+        .get_or_create(&CacheLabels {
+            cache_name: cache_name.to_string(),
+        })
+        .inc_by(c);
+}
+
+pub fn metrics_inc_cache_eviction_count(c: u64, cache_name: &str) {
+    CACHE_EVICTION_COUNT
+        .get_or_create(&CacheLabels {
+            cache_name: cache_name.to_string(),
+        })
+        .inc_by(c);
+}
+
 pub fn metrics_inc_cache_population_pending_count(c: i64, cache_name: &str) {
     CACHE_POPULATION_PENDING_COUNT
         .get_or_create(&CacheLabels {
@@ -16,9 +16,13 @@ use std::sync::LazyLock;
 use std::time::Duration;
 
 use databend_common_base::runtime::metrics::register_counter;
+use databend_common_base::runtime::metrics::register_counter_family;
 use databend_common_base::runtime::metrics::register_gauge;
+use databend_common_base::runtime::metrics::register_gauge_family;
 use databend_common_base::runtime::metrics::register_histogram_in_milliseconds;
 use databend_common_base::runtime::metrics::Counter;
+use databend_common_base::runtime::metrics::FamilyCounter;
+use databend_common_base::runtime::metrics::FamilyGauge;
 use databend_common_base::runtime::metrics::Gauge;
 use databend_common_base::runtime::metrics::Histogram;
 
@@ -42,6 +46,15 @@ pub static SESSION_QUEUE_ACQUIRE_DURATION_MS: LazyLock<Histogram> =
 pub static SESSION_RUNNING_ACQUIRED_QUERIES: LazyLock<Gauge> =
     LazyLock::new(|| register_gauge("session_running_acquired_queries"));
 
+static WORKLOAD_GROUP_QUEUED_QUERIES: LazyLock<FamilyGauge<Vec<(&'static str, String)>>> =
+    LazyLock::new(|| register_gauge_family("workload_group_queued_queries"));
+static WORKLOAD_GROUP_RUNNING_QUERIES: LazyLock<FamilyGauge<Vec<(&'static str, String)>>> =
+    LazyLock::new(|| register_gauge_family("workload_group_running_queries"));
+static WORKLOAD_GROUP_MEMORY_USAGE: LazyLock<FamilyGauge<Vec<(&'static str, String)>>> =
+    LazyLock::new(|| register_gauge_family("workload_group_memory_usage"));
+static WORKLOAD_GROUP_QUEUE_TIMEOUT_COUNT: LazyLock<FamilyCounter<Vec<(&'static str, String)>>> =
+    LazyLock::new(|| register_counter_family("workload_group_queue_timeout_count"));
+
 pub fn incr_session_connect_numbers() {
     SESSION_CONNECT_NUMBERS.inc();
 }
@@ -81,3 +94,31 @@ pub fn inc_session_running_acquired_queries() {
 pub fn dec_session_running_acquired_queries() {
     SESSION_RUNNING_ACQUIRED_QUERIES.dec();
 }
+
+fn workload_group_labels(group: &str) -> Vec<(&'static str, String)> {
+    vec![("workload_group", group.to_string())]
+}
+
+pub fn set_workload_group_queued_queries(group: &str, num: usize) {
+    WORKLOAD_GROUP_QUEUED_QUERIES
+        .get_or_create(&workload_group_labels(group))
+        .set(num as i64);
+}
+
+pub fn set_workload_group_running_queries(group: &str, num: usize) {
+    WORKLOAD_GROUP_RUNNING_QUERIES
+        .get_or_create(&workload_group_labels(group))
+        .set(num as i64);
+}
+
+pub fn set_workload_group_memory_usage(group: &str, bytes: i64) {
+    WORKLOAD_GROUP_MEMORY_USAGE
+        .get_or_create(&workload_group_labels(group))
+        .set(bytes);
+}
+
+pub fn incr_workload_group_queue_timeout_count(group: &str) {
+    WORKLOAD_GROUP_QUEUE_TIMEOUT_COUNT
+        .get_or_create(&workload_group_labels(group))
+        .inc();
+}
@@ -146,6 +146,8 @@ pub enum FileParseError {
         next_char: String,
         column_data: String,
     },
+    #[error("{message}")]
+    ConstraintViolated { message: String },
     #[error("Unexpected: {message}")]
     Unexpected { message: String },
 }
@@ -159,6 +161,11 @@ impl FileParseError {
             }
             _ => format!("{self}"),
         };
-        ErrorCode::BadBytes(message).add_detail_back(pos)
+        match self {
+            FileParseError::ConstraintViolated { .. } => {
+                ErrorCode::ConstraintViolated(message).add_detail_back(pos)
+            }
+            _ => ErrorCode::BadBytes(message).add_detail_back(pos),
+        }
     }
 }
@@ -34,6 +34,7 @@ mod user_privilege;
 mod user_quota;
 mod user_setting;
 mod user_stage;
+mod workload_group;
 
 mod ownership_object;
 
@@ -55,6 +56,7 @@ pub mod user_setting_ident;
 pub mod user_stage_ident;
 pub mod user_token;
 pub mod user_token_ident;
+pub mod workload_group_ident;
 
 pub use connection::*;
 pub use file_format::*;
@@ -95,9 +97,12 @@ pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
 pub use user_defined_file_format::UserDefinedFileFormat;
 pub use user_defined_function::LambdaUDF;
+pub use user_defined_function::UDAFScript;
 pub use user_defined_function::UDFDefinition;
 pub use user_defined_function::UDFScript;
 pub use user_defined_function::UDFServer;
+pub use user_defined_function::UDTFScript;
+pub use user_defined_function::UDTFSql;
 pub use user_defined_function::UserDefinedFunction;
 pub use user_grant::GrantEntry;
 pub use user_grant::GrantObject;
@@ -114,3 +119,5 @@ pub use user_setting::UserSettingValue;
 pub use user_setting_ident::SettingIdent;
 pub use user_stage::*;
 pub use user_stage_ident::StageIdent;
+pub use workload_group::WorkloadGroup;
+pub use workload_group_ident::WorkloadGroupIdent;
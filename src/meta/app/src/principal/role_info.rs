@@ -32,6 +32,8 @@ pub struct RoleInfo {
     pub grants: UserGrantSet,
     pub created_on: DateTime<Utc>,
     pub update_on: DateTime<Utc>,
+    /// The workload group of the queries run with this role as the current role.
+    pub workload_group: Option<String>,
 }
 
 /// Error when ser/de RoleInfo
@@ -49,6 +51,7 @@ impl RoleInfo {
             grants: UserGrantSet::empty(),
             created_on: now,
             update_on: now,
+            workload_group: None,
         }
     }
 
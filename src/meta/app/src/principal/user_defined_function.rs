@@ -44,11 +44,62 @@ pub struct UDFScript {
     pub runtime_version: String,
 }
 
+/// A table-valued function defined by a parameterized SQL query.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UDTFSql {
+    pub arg_names: Vec<String>,
+    pub arg_types: Vec<DataType>,
+    pub return_names: Vec<String>,
+    pub return_types: Vec<DataType>,
+    pub sql: String,
+}
+
+/// A table-valued function whose handler returns the output rows of each input row.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UDTFScript {
+    pub code: String,
+    pub handler: String,
+    pub language: String,
+    pub arg_types: Vec<DataType>,
+    pub return_names: Vec<String>,
+    pub return_types: Vec<DataType>,
+    pub runtime_version: String,
+}
+
+/// An aggregate function whose script defines the `create_state`, `accumulate`,
+/// `merge` and `finish` hooks over a state of the given fields.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UDAFScript {
+    pub code: String,
+    pub language: String,
+    pub arg_types: Vec<DataType>,
+    pub state_names: Vec<String>,
+    pub state_types: Vec<DataType>,
+    pub return_type: DataType,
+    pub runtime_version: String,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum UDFDefinition {
     LambdaUDF(LambdaUDF),
     UDFServer(UDFServer),
     UDFScript(UDFScript),
+    UDTFSql(UDTFSql),
+    UDTFScript(UDTFScript),
+    UDAFScript(UDAFScript),
+}
+
+impl UDFDefinition {
+    pub fn is_table_function(&self) -> bool {
+        matches!(
+            self,
+            UDFDefinition::UDTFSql(_) | UDFDefinition::UDTFScript(_)
+        )
+    }
+
+    pub fn is_aggregate_function(&self) -> bool {
+        matches!(self, UDFDefinition::UDAFScript(_))
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -180,7 +231,82 @@ impl Display for UDFDefinition {
                     ") RETURNS {return_type} LANGUAGE {language} RUNTIME_VERSION = {runtime_version} HANDLER = {handler} AS $${code}$$"
                 )?;
             }
+            UDFDefinition::UDTFSql(UDTFSql {
+                arg_names,
+                arg_types,
+                return_names,
+                return_types,
+                sql,
+            }) => {
+                for (i, (name, ty)) in arg_names.iter().zip(arg_types.iter()).enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{name} {ty}")?;
+                }
+                write!(f, ") RETURNS TABLE (")?;
+                write_table_columns(f, return_names, return_types)?;
+                write!(f, ") AS $${sql}$$")?;
+            }
+            UDFDefinition::UDTFScript(UDTFScript {
+                code,
+                handler,
+                language,
+                arg_types,
+                return_names,
+                return_types,
+                runtime_version,
+            }) => {
+                for (i, item) in arg_types.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, ") RETURNS TABLE (")?;
+                write_table_columns(f, return_names, return_types)?;
+                write!(
+                    f,
+                    ") LANGUAGE {language} RUNTIME_VERSION = {runtime_version} HANDLER = {handler} AS $${code}$$"
+                )?;
+            }
+            UDFDefinition::UDAFScript(UDAFScript {
+                code,
+                language,
+                arg_types,
+                state_names,
+                state_types,
+                return_type,
+                runtime_version,
+            }) => {
+                for (i, item) in arg_types.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, ") STATE {{ ")?;
+                write_table_columns(f, state_names, state_types)?;
+                write!(
+                    f,
+                    " }} RETURNS {return_type} LANGUAGE {language} RUNTIME_VERSION = {runtime_version} AS $${code}$$"
+                )?;
+            }
         }
         Ok(())
     }
 }
+
+fn write_table_columns(
+    f: &mut Formatter,
+    names: &[String],
+    types: &[DataType],
+) -> std::fmt::Result {
+    for (i, (name, ty)) in names.iter().zip(types.iter()).enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{name} {ty}")?;
+    }
+    Ok(())
+}
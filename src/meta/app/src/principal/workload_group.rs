@@ -0,0 +1,45 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// A named set of resource limits shared by the queries of the users and roles assigned to it.
+///
+/// A zero limit means unlimited.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct WorkloadGroup {
+    pub name: String,
+    /// Max number of queries of the group running at the same time on a node.
+    pub max_concurrency: u64,
+    /// Max bytes of memory used by all the queries of the group on a node.
+    pub memory_quota: u64,
+    /// Share of the executor threads relative to the other groups.
+    pub cpu_weight: u64,
+    /// Max seconds a query waits in the queue of the group.
+    pub queue_timeout: u64,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
+
+impl WorkloadGroup {
+    /// Whether the limits of the two groups are the same.
+    pub fn same_limits(&self, other: &WorkloadGroup) -> bool {
+        self.max_concurrency == other.max_concurrency
+            && self.memory_quota == other.memory_quota
+            && self.cpu_weight == other.cpu_weight
+            && self.queue_timeout == other.queue_timeout
+    }
+}
@@ -278,6 +278,8 @@ pub struct TableMeta {
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
     pub indexes: BTreeMap<String, TableIndex>,
+    // constraints of the table, keyed by the constraint name.
+    pub constraints: BTreeMap<String, TableConstraint>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -295,6 +297,62 @@ pub struct TableIndex {
     pub options: BTreeMap<String, String>,
 }
 
+/// A constraint declared on a table, e.g. `PRIMARY KEY (a) ENFORCED`.
+///
+/// Enforced keys are checked on write, the not enforced ones are only
+/// informational, e.g. for the optimizer. `CHECK` constraints are always
+/// validated on write.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum TableConstraint {
+    PrimaryKey {
+        column_ids: Vec<u32>,
+        enforced: bool,
+    },
+    Unique {
+        column_ids: Vec<u32>,
+        enforced: bool,
+    },
+    Check {
+        /// The normalized boolean expression, in SQL text.
+        expr: String,
+        /// The columns referenced by `expr`.
+        column_ids: Vec<u32>,
+    },
+}
+
+impl TableConstraint {
+    pub fn column_ids(&self) -> &[u32] {
+        match self {
+            TableConstraint::PrimaryKey { column_ids, .. } => column_ids,
+            TableConstraint::Unique { column_ids, .. } => column_ids,
+            TableConstraint::Check { column_ids, .. } => column_ids,
+        }
+    }
+
+    /// Returns the expression if this is a check constraint.
+    pub fn check_expr(&self) -> Option<&str> {
+        match self {
+            TableConstraint::Check { expr, .. } => Some(expr),
+            _ => None,
+        }
+    }
+
+    /// Returns the key columns if this is an enforced primary or unique key.
+    pub fn enforced_key(&self) -> Option<&[u32]> {
+        match self {
+            TableConstraint::PrimaryKey {
+                column_ids,
+                enforced: true,
+            }
+            | TableConstraint::Unique {
+                column_ids,
+                enforced: true,
+            } => Some(column_ids),
+            _ => None,
+        }
+    }
+}
+
 impl TableMeta {
     pub fn add_column(
         &mut self,
@@ -426,6 +484,7 @@ impl Default for TableMeta {
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
             indexes: BTreeMap::new(),
+            constraints: BTreeMap::new(),
         }
     }
 }
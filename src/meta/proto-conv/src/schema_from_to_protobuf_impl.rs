@@ -84,7 +84,8 @@ impl FromToProto for ex::TableField {
             p.column_id,
         )
         .with_default_expr(p.default_expr)
-        .with_computed_expr(computed_expr);
+        .with_computed_expr(computed_expr)
+        .with_codec(p.codec);
         Ok(v)
     }
 
@@ -101,6 +102,7 @@ impl FromToProto for ex::TableField {
             data_type: Some(self.data_type().to_pb()?),
             column_id: self.column_id(),
             computed_expr,
+            codec: self.codec().cloned(),
         };
         Ok(p)
     }
@@ -192,6 +192,11 @@ impl FromToProto for mt::TableMeta {
             indexes.insert(name, mt::TableIndex::from_pb(index)?);
         }
 
+        let mut constraints = BTreeMap::new();
+        for (name, constraint) in p.constraints {
+            constraints.insert(name, mt::TableConstraint::from_pb(constraint)?);
+        }
+
         let v = Self {
             schema: Arc::new(ex::TableSchema::from_pb(schema)?),
             engine: p.engine,
@@ -225,6 +230,7 @@ impl FromToProto for mt::TableMeta {
                 Some(p.column_mask_policy)
             },
             indexes,
+            constraints,
         };
         Ok(v)
     }
@@ -234,6 +240,10 @@ impl FromToProto for mt::TableMeta {
         for (name, index) in &self.indexes {
             indexes.insert(name.clone(), index.to_pb()?);
         }
+        let mut constraints = BTreeMap::new();
+        for (name, constraint) in &self.constraints {
+            constraints.insert(name.clone(), constraint.to_pb()?);
+        }
         let p = pb::TableMeta {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
@@ -265,11 +275,72 @@ impl FromToProto for mt::TableMeta {
             shared_by: Vec::from_iter(self.shared_by.clone()),
             column_mask_policy: self.column_mask_policy.clone().unwrap_or_default(),
             indexes,
+            constraints,
         };
         Ok(p)
     }
 }
 
+impl FromToProto for mt::TableConstraint {
+    type PB = pb::TableConstraint;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableConstraint) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let constraint = p.constraint.ok_or_else(|| Incompatible {
+            reason: "Invalid TableConstraint: .constraint can not be None".to_string(),
+        })?;
+
+        let v = match constraint {
+            pb::table_constraint::Constraint::PrimaryKey(key) => Self::PrimaryKey {
+                column_ids: key.column_ids,
+                enforced: key.enforced,
+            },
+            pb::table_constraint::Constraint::Unique(key) => Self::Unique {
+                column_ids: key.column_ids,
+                enforced: key.enforced,
+            },
+            pb::table_constraint::Constraint::Check(check) => Self::Check {
+                expr: check.expr,
+                column_ids: check.column_ids,
+            },
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableConstraint, Incompatible> {
+        let constraint = match self {
+            mt::TableConstraint::PrimaryKey {
+                column_ids,
+                enforced,
+            } => pb::table_constraint::Constraint::PrimaryKey(pb::table_constraint::PrimaryKey {
+                column_ids: column_ids.clone(),
+                enforced: *enforced,
+            }),
+            mt::TableConstraint::Unique {
+                column_ids,
+                enforced,
+            } => pb::table_constraint::Constraint::Unique(pb::table_constraint::Unique {
+                column_ids: column_ids.clone(),
+                enforced: *enforced,
+            }),
+            mt::TableConstraint::Check { expr, column_ids } => {
+                pb::table_constraint::Constraint::Check(pb::table_constraint::Check {
+                    expr: expr.clone(),
+                    column_ids: column_ids.clone(),
+                })
+            }
+        };
+        Ok(pb::TableConstraint {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            constraint: Some(constraint),
+        })
+    }
+}
+
 impl FromToProto for mt::TableStatistics {
     type PB = pb::TableStatistics;
     fn get_pb_ver(p: &Self::PB) -> u64 {
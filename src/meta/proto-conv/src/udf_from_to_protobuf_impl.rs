@@ -164,6 +164,136 @@ impl FromToProto for mt::UDFScript {
     }
 }
 
+impl FromToProto for mt::UDTFSql {
+    type PB = pb::UdtfSql;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::UdtfSql) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        Ok(mt::UDTFSql {
+            arg_names: p.arg_names,
+            arg_types: data_types_from_pb(p.arg_types)?,
+            return_names: p.return_names,
+            return_types: data_types_from_pb(p.return_types)?,
+            sql: p.sql,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::UdtfSql, Incompatible> {
+        Ok(pb::UdtfSql {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            arg_names: self.arg_names.clone(),
+            arg_types: data_types_to_pb(&self.arg_types)?,
+            return_names: self.return_names.clone(),
+            return_types: data_types_to_pb(&self.return_types)?,
+            sql: self.sql.clone(),
+        })
+    }
+}
+
+impl FromToProto for mt::UDTFScript {
+    type PB = pb::UdtfScript;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::UdtfScript) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        Ok(mt::UDTFScript {
+            code: p.code,
+            handler: p.handler,
+            language: p.language,
+            arg_types: data_types_from_pb(p.arg_types)?,
+            return_names: p.return_names,
+            return_types: data_types_from_pb(p.return_types)?,
+            runtime_version: p.runtime_version,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::UdtfScript, Incompatible> {
+        Ok(pb::UdtfScript {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            code: self.code.clone(),
+            handler: self.handler.clone(),
+            language: self.language.clone(),
+            arg_types: data_types_to_pb(&self.arg_types)?,
+            return_names: self.return_names.clone(),
+            return_types: data_types_to_pb(&self.return_types)?,
+            runtime_version: self.runtime_version.clone(),
+        })
+    }
+}
+
+impl FromToProto for mt::UDAFScript {
+    type PB = pb::UdafScript;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::UdafScript) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let return_type = DataType::from(&TableDataType::from_pb(p.return_type.ok_or_else(
+            || Incompatible {
+                reason: "UDAFScript.return_type can not be None".to_string(),
+            },
+        )?)?);
+
+        Ok(mt::UDAFScript {
+            code: p.code,
+            language: p.language,
+            arg_types: data_types_from_pb(p.arg_types)?,
+            state_names: p.state_names,
+            state_types: data_types_from_pb(p.state_types)?,
+            return_type,
+            runtime_version: p.runtime_version,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::UdafScript, Incompatible> {
+        let return_type = infer_schema_type(&self.return_type)
+            .map_err(|e| Incompatible {
+                reason: format!("Convert DataType to TableDataType failed: {}", e.message()),
+            })?
+            .to_pb()?;
+
+        Ok(pb::UdafScript {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            code: self.code.clone(),
+            language: self.language.clone(),
+            arg_types: data_types_to_pb(&self.arg_types)?,
+            state_names: self.state_names.clone(),
+            state_types: data_types_to_pb(&self.state_types)?,
+            return_type: Some(return_type),
+            runtime_version: self.runtime_version.clone(),
+        })
+    }
+}
+
+fn data_types_from_pb(types: Vec<pb::DataType>) -> Result<Vec<DataType>, Incompatible> {
+    types
+        .into_iter()
+        .map(|ty| Ok(DataType::from(&TableDataType::from_pb(ty)?)))
+        .collect()
+}
+
+fn data_types_to_pb(types: &[DataType]) -> Result<Vec<pb::DataType>, Incompatible> {
+    types
+        .iter()
+        .map(|ty| {
+            infer_schema_type(ty)
+                .map_err(|e| Incompatible {
+                    reason: format!("Convert DataType to TableDataType failed: {}", e.message()),
+                })?
+                .to_pb()
+        })
+        .collect()
+}
+
 impl FromToProto for mt::UserDefinedFunction {
     type PB = pb::UserDefinedFunction;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -181,6 +311,15 @@ impl FromToProto for mt::UserDefinedFunction {
             Some(pb::user_defined_function::Definition::UdfScript(udf_script)) => {
                 mt::UDFDefinition::UDFScript(mt::UDFScript::from_pb(udf_script)?)
             }
+            Some(pb::user_defined_function::Definition::UdtfSql(udtf_sql)) => {
+                mt::UDFDefinition::UDTFSql(mt::UDTFSql::from_pb(udtf_sql)?)
+            }
+            Some(pb::user_defined_function::Definition::UdtfScript(udtf_script)) => {
+                mt::UDFDefinition::UDTFScript(mt::UDTFScript::from_pb(udtf_script)?)
+            }
+            Some(pb::user_defined_function::Definition::UdafScript(udaf_script)) => {
+                mt::UDFDefinition::UDAFScript(mt::UDAFScript::from_pb(udaf_script)?)
+            }
             None => {
                 return Err(Incompatible {
                     reason: "UserDefinedFunction.definition cannot be None".to_string(),
@@ -210,6 +349,15 @@ impl FromToProto for mt::UserDefinedFunction {
             mt::UDFDefinition::UDFScript(udf_script) => {
                 pb::user_defined_function::Definition::UdfScript(udf_script.to_pb()?)
             }
+            mt::UDFDefinition::UDTFSql(udtf_sql) => {
+                pb::user_defined_function::Definition::UdtfSql(udtf_sql.to_pb()?)
+            }
+            mt::UDFDefinition::UDTFScript(udtf_script) => {
+                pb::user_defined_function::Definition::UdtfScript(udtf_script.to_pb()?)
+            }
+            mt::UDFDefinition::UDAFScript(udaf_script) => {
+                pb::user_defined_function::Definition::UdafScript(udaf_script.to_pb()?)
+            }
         };
 
         Ok(pb::UserDefinedFunction {
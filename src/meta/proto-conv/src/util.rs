@@ -140,6 +140,12 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (108, "2024-08-29: Add: procedure.proto: ProcedureMeta and ProcedureIdentity"),
     (109, "2024-08-29: Refactor: ProcedureMeta add arg_names"),
     (110, "2024-09-18: Add: database.proto: DatabaseMeta.gc_in_progress"),
+    (111, "2024-09-25: Add: user.proto/WorkloadGroup, UserOption.workload_group, role.proto/RoleInfo.workload_group"),
+    (112, "2024-09-27: Add: metadata.proto/DataField.codec"),
+    (113, "2024-09-29: Add: table.proto/TableMeta.constraints, TableConstraint"),
+    (114, "2024-09-30: Add: table.proto/TableConstraint.check"),
+    (115, "2024-10-01: Add: udf.proto/UDTFSql, UDTFScript"),
+    (116, "2024-10-08: Add: udf.proto/UDAFScript"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -108,3 +108,9 @@ mod v107_geography_datatype;
 mod v108_procedure;
 mod v109_procedure_with_args;
 mod v110_database_meta_gc_in_progress;
+mod v111_workload_group;
+mod v112_column_codec;
+mod v113_table_constraint;
+mod v114_check_constraint;
+mod v115_udtf;
+mod v116_udaf_script;
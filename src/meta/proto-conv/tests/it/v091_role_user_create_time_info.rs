@@ -49,6 +49,7 @@ fn test_decode_v91_role() -> anyhow::Result<()> {
         grants: UserGrantSet::new(vec![], HashSet::new()),
         created_on: DateTime::<Utc>::from_timestamp(1702603569, 0).unwrap(),
         update_on: DateTime::<Utc>::from_timestamp(1702603570, 0).unwrap(),
+        workload_group: None,
     };
     common::test_pb_from_to(func_name!(), want())?;
     common::test_load_old(func_name!(), role_info_v91.as_slice(), 91, want())?;
@@ -0,0 +1,96 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app as mt;
+use databend_common_meta_app::principal::UserGrantSet;
+use databend_common_meta_app::principal::UserOptionFlag;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v111_workload_group() -> anyhow::Result<()> {
+    let workload_group_v111 = vec![
+        10, 3, 119, 103, 49, 16, 10, 24, 128, 128, 128, 128, 4, 32, 2, 40, 60, 50, 7, 102, 111,
+        114, 32, 101, 116, 108, 58, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48,
+        48, 58, 48, 57, 32, 85, 84, 67, 66, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 57, 32, 49, 50,
+        58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 111, 168, 6, 24,
+    ];
+
+    let want = || mt::principal::WorkloadGroup {
+        name: "wg1".to_string(),
+        max_concurrency: 10,
+        memory_quota: 1024 * 1024 * 1024,
+        cpu_weight: 2,
+        queue_timeout: 60,
+        comment: "for etl".to_string(),
+        create_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        update_on: Some(Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), workload_group_v111.as_slice(), 111, want())
+}
+
+#[test]
+fn test_decode_v111_role_workload_group() -> anyhow::Result<()> {
+    let role_info_v111 = vec![
+        10, 2, 114, 49, 18, 6, 160, 6, 111, 168, 6, 24, 26, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50,
+        56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 34, 23, 50, 48, 49, 52, 45, 49, 49,
+        45, 50, 57, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 42, 3, 119, 103, 49, 160,
+        6, 111, 168, 6, 24,
+    ];
+
+    let want = || mt::principal::RoleInfo {
+        name: "r1".to_string(),
+        grants: UserGrantSet::new(vec![], HashSet::new()),
+        created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        update_on: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap(),
+        workload_group: Some("wg1".to_string()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), role_info_v111.as_slice(), 111, want())
+}
+
+#[test]
+fn test_decode_v111_user_option_workload_group() -> anyhow::Result<()> {
+    let user_option_v111 = vec![
+        8, 1, 18, 5, 114, 111, 108, 101, 49, 58, 3, 119, 103, 49, 160, 6, 111, 168, 6, 24,
+    ];
+
+    let want = || {
+        mt::principal::UserOption::default()
+            .with_set_flag(UserOptionFlag::TenantSetting)
+            .with_default_role(Some("role1".to_string()))
+            .with_workload_group(Some("wg1".to_string()))
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), user_option_v111.as_slice(), 111, want())
+}
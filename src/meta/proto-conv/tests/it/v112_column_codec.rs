@@ -0,0 +1,45 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v112_column_codec() -> anyhow::Result<()> {
+    let table_field_v112 = vec![
+        10, 1, 97, 26, 9, 146, 2, 0, 160, 6, 112, 168, 6, 24, 32, 3, 50, 14, 68, 69, 76, 84, 65,
+        44, 32, 90, 83, 84, 68, 40, 51, 41, 160, 6, 112, 168, 6, 24,
+    ];
+
+    let want = || {
+        TableField::new_from_column_id("a", TableDataType::String, 3)
+            .with_codec(Some("DELTA, ZSTD(3)".to_string()))
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), table_field_v112.as_slice(), 112, want())
+}
@@ -0,0 +1,66 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_meta_app::principal::UDFDefinition;
+use databend_common_meta_app::principal::UDTFSql;
+use databend_common_meta_app::principal::UserDefinedFunction;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v115_udtf_sql() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 8, 97, 100, 100, 95, 114, 111, 119, 115, 18, 21, 84, 104, 105, 115, 32, 105, 115, 32,
+        97, 32, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 42, 23, 50, 48, 50, 52, 45,
+        49, 48, 45, 48, 49, 32, 48, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 58, 128, 1, 10, 1,
+        97, 18, 17, 154, 2, 8, 58, 0, 160, 6, 115, 168, 6, 24, 160, 6, 115, 168, 6, 24, 26, 1, 120,
+        26, 1, 121, 34, 17, 154, 2, 8, 58, 0, 160, 6, 115, 168, 6, 24, 160, 6, 115, 168, 6, 24, 34,
+        9, 146, 2, 0, 160, 6, 115, 168, 6, 24, 42, 62, 83, 69, 76, 69, 67, 84, 32, 97, 32, 43, 32,
+        110, 117, 109, 98, 101, 114, 32, 65, 83, 32, 120, 44, 32, 116, 111, 95, 115, 116, 114, 105,
+        110, 103, 40, 110, 117, 109, 98, 101, 114, 41, 32, 65, 83, 32, 121, 32, 70, 82, 79, 77, 32,
+        110, 117, 109, 98, 101, 114, 115, 40, 51, 41, 160, 6, 115, 168, 6, 24, 160, 6, 115, 168, 6,
+        24,
+    ];
+
+    let want = || UserDefinedFunction {
+        name: "add_rows".to_string(),
+        description: "This is a description".to_string(),
+        definition: UDFDefinition::UDTFSql(UDTFSql {
+            arg_names: vec!["a".to_string()],
+            arg_types: vec![DataType::Number(NumberDataType::Int32)],
+            return_names: vec!["x".to_string(), "y".to_string()],
+            return_types: vec![DataType::Number(NumberDataType::Int32), DataType::String],
+            sql: "SELECT a + number AS x, to_string(number) AS y FROM numbers(3)".to_string(),
+        }),
+        created_on: DateTime::<Utc>::from_timestamp(1727740800, 0).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 115, want())
+}
@@ -0,0 +1,77 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_meta_app::principal::UDAFScript;
+use databend_common_meta_app::principal::UDFDefinition;
+use databend_common_meta_app::principal::UserDefinedFunction;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v116_udaf_script() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 12, 119, 101, 105, 103, 104, 116, 101, 100, 95, 97, 118, 103, 18, 21, 84, 104, 105,
+        115, 32, 105, 115, 32, 97, 32, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 42,
+        23, 50, 48, 50, 52, 45, 49, 48, 45, 48, 56, 32, 48, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84,
+        67, 74, 190, 1, 10, 62, 101, 120, 112, 111, 114, 116, 32, 102, 117, 110, 99, 116, 105, 111,
+        110, 32, 99, 114, 101, 97, 116, 101, 95, 115, 116, 97, 116, 101, 40, 41, 32, 123, 32, 114,
+        101, 116, 117, 114, 110, 32, 123, 115, 117, 109, 58, 32, 48, 44, 32, 119, 101, 105, 103,
+        104, 116, 58, 32, 48, 125, 59, 32, 125, 18, 10, 106, 97, 118, 97, 115, 99, 114, 105, 112,
+        116, 26, 17, 154, 2, 8, 58, 0, 160, 6, 116, 168, 6, 24, 160, 6, 116, 168, 6, 24, 26, 17,
+        154, 2, 8, 58, 0, 160, 6, 116, 168, 6, 24, 160, 6, 116, 168, 6, 24, 34, 3, 115, 117, 109,
+        34, 6, 119, 101, 105, 103, 104, 116, 42, 17, 154, 2, 8, 66, 0, 160, 6, 116, 168, 6, 24,
+        160, 6, 116, 168, 6, 24, 42, 17, 154, 2, 8, 66, 0, 160, 6, 116, 168, 6, 24, 160, 6, 116,
+        168, 6, 24, 50, 17, 154, 2, 8, 82, 0, 160, 6, 116, 168, 6, 24, 160, 6, 116, 168, 6, 24,
+        160, 6, 116, 168, 6, 24, 160, 6, 116, 168, 6, 24,
+    ];
+
+    let want = || UserDefinedFunction {
+        name: "weighted_avg".to_string(),
+        description: "This is a description".to_string(),
+        definition: UDFDefinition::UDAFScript(UDAFScript {
+            code: "export function create_state() { return {sum: 0, weight: 0}; }".to_string(),
+            language: "javascript".to_string(),
+            arg_types: vec![
+                DataType::Number(NumberDataType::Int32),
+                DataType::Number(NumberDataType::Int32),
+            ],
+            state_names: vec!["sum".to_string(), "weight".to_string()],
+            state_types: vec![
+                DataType::Number(NumberDataType::Int64),
+                DataType::Number(NumberDataType::Int64),
+            ],
+            return_type: DataType::Number(NumberDataType::Float64),
+            runtime_version: "".to_string(),
+        }),
+        created_on: DateTime::<Utc>::from_timestamp(1728345600, 0).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 116, want())
+}
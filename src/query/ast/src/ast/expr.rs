@@ -151,6 +151,7 @@ pub enum Expr {
     /// `COUNT(*)` expression
     CountAll {
         span: Span,
+        filter: Option<Box<Expr>>,
         window: Option<Window>,
     },
     /// `(foo, bar)`
@@ -631,8 +632,11 @@ impl Display for Expr {
                 Expr::Literal { value, .. } => {
                     write!(f, "{value}")?;
                 }
-                Expr::CountAll { window, .. } => {
+                Expr::CountAll { filter, window, .. } => {
                     write!(f, "COUNT(*)")?;
+                    if let Some(filter) = filter {
+                        write!(f, " FILTER (WHERE {filter})")?;
+                    }
                     if let Some(window) = window {
                         write!(f, " OVER {window}")?;
                     }
@@ -877,6 +881,10 @@ pub struct FunctionCall {
     pub name: Identifier,
     pub args: Vec<Expr>,
     pub params: Vec<Expr>,
+    /// The ordering of an ordered-set aggregate, like `LISTAGG(a) WITHIN GROUP (ORDER BY b)`
+    pub order_by: Vec<OrderByExpr>,
+    /// The rows aggregated by an aggregate function, like `COUNT(*) FILTER (WHERE a > 1)`
+    pub filter: Option<Box<Expr>>,
     pub window: Option<WindowDesc>,
     pub lambda: Option<Lambda>,
 }
@@ -888,6 +896,8 @@ impl Display for FunctionCall {
             name,
             args,
             params,
+            order_by,
+            filter,
             window,
             lambda,
         } = self;
@@ -907,6 +917,14 @@ impl Display for FunctionCall {
         }
         write!(f, ")")?;
 
+        if !order_by.is_empty() {
+            write!(f, " WITHIN GROUP (ORDER BY ")?;
+            write_comma_separated_list(f, order_by)?;
+            write!(f, ")")?;
+        }
+        if let Some(filter) = filter {
+            write!(f, " FILTER (WHERE {filter})")?;
+        }
         if let Some(window) = window {
             if let Some(ignore_null) = window.ignore_nulls {
                 if ignore_null {
@@ -1535,6 +1553,10 @@ pub enum UnaryOperator {
     CubeRoot,
     Abs,
     BitwiseNot,
+    /// `PRIOR expr` in the `CONNECT BY` condition, refers to the parent row.
+    Prior,
+    /// `CONNECT_BY_ROOT expr` in a hierarchical query, refers to the root row.
+    ConnectByRoot,
 }
 
 impl Display for UnaryOperator {
@@ -1564,6 +1586,12 @@ impl Display for UnaryOperator {
             UnaryOperator::BitwiseNot => {
                 write!(f, "~")
             }
+            UnaryOperator::Prior => {
+                write!(f, "PRIOR")
+            }
+            UnaryOperator::ConnectByRoot => {
+                write!(f, "CONNECT_BY_ROOT")
+            }
         }
     }
 }
@@ -121,12 +121,17 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
 
 fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
     match source {
-        CreateTableSource::Columns(columns, inverted_indexes) => RcDoc::space()
+        CreateTableSource::Columns(columns, inverted_indexes, constraints) => RcDoc::space()
             .append(parenthesized(
                 interweave_comma(
                     columns
                         .into_iter()
-                        .map(|column| RcDoc::text(column.to_string())),
+                        .map(|column| RcDoc::text(column.to_string()))
+                        .chain(
+                            constraints
+                                .into_iter()
+                                .map(|constraint| RcDoc::text(constraint.to_string())),
+                        ),
                 )
                 .group(),
             ))
@@ -160,6 +165,9 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
                 RcDoc::nil()
             })
             .append(RcDoc::text(table.to_string())),
+        clone @ CreateTableSource::Clone { .. } => {
+            RcDoc::space().append(RcDoc::text(clone.to_string()))
+        }
     }
 }
 
@@ -253,6 +261,8 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             TimeTravelPoint::Snapshot(sid) => RcDoc::text(format!(" AT (SNAPSHOT => {sid})")),
             TimeTravelPoint::Timestamp(ts) => RcDoc::text(format!(" AT (TIMESTAMP => {ts})")),
             TimeTravelPoint::Offset(num) => RcDoc::text(format!(" AT (OFFSET => {num})")),
+            TimeTravelPoint::Tag(name) => RcDoc::text(format!(" AT (TAG => {name})")),
+            TimeTravelPoint::Branch(name) => RcDoc::text(format!(" AT (BRANCH => {name})")),
             TimeTravelPoint::Stream {
                 catalog,
                 database,
@@ -291,6 +301,17 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             }
             doc
         }
+        action @ AlterTableAction::CreateTableRef { .. } => {
+            RcDoc::line().append(RcDoc::text(action.to_string()))
+        }
+        action @ AlterTableAction::DropTableRef { .. } => {
+            RcDoc::line().append(RcDoc::text(action.to_string()))
+        }
+        action @ (AlterTableAction::AddConstraint { .. }
+        | AlterTableAction::DropConstraint { .. }
+        | AlterTableAction::WarmCache { .. }) => {
+            RcDoc::line().append(RcDoc::text(action.to_string()))
+        }
     }
 }
 
@@ -397,6 +418,8 @@ pub(crate) fn pretty_create_stream(stmt: CreateStreamStmt) -> RcDoc<'static> {
             Some(TimeTravelPoint::Snapshot(sid)) => RcDoc::text(format!(" AT (SNAPSHOT => {sid})")),
             Some(TimeTravelPoint::Timestamp(ts)) => RcDoc::text(format!(" AT (TIMESTAMP => {ts})")),
             Some(TimeTravelPoint::Offset(num)) => RcDoc::text(format!(" AT (OFFSET => {num})")),
+            Some(TimeTravelPoint::Tag(name)) => RcDoc::text(format!(" AT (TAG => {name})")),
+            Some(TimeTravelPoint::Branch(name)) => RcDoc::text(format!(" AT (BRANCH => {name})")),
             Some(TimeTravelPoint::Stream {
                 catalog,
                 database,
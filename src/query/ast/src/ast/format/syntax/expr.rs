@@ -234,15 +234,21 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(pretty_expr(*expr))
             .append(RcDoc::text(")")),
         Expr::Literal { value, .. } => RcDoc::text(value.to_string()),
-        Expr::CountAll { window, .. } => {
-            RcDoc::text("COUNT(*)").append(if let Some(window) = window {
+        Expr::CountAll { filter, window, .. } => RcDoc::text("COUNT(*)")
+            .append(if let Some(filter) = filter {
+                RcDoc::text(" FILTER (WHERE ")
+                    .append(pretty_expr(*filter))
+                    .append(RcDoc::text(")"))
+            } else {
+                RcDoc::nil()
+            })
+            .append(if let Some(window) = window {
                 RcDoc::text(" OVER (")
                     .append(RcDoc::text(window.to_string()))
                     .append(")")
             } else {
                 RcDoc::nil()
-            })
-        }
+            }),
         Expr::Tuple { exprs, .. } => RcDoc::text("(")
             .append(inline_comma(exprs.into_iter().map(pretty_expr)))
             .append(RcDoc::text(")")),
@@ -252,6 +258,8 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
                 distinct,
                 args,
                 params,
+                order_by,
+                filter,
                 window,
                 lambda,
             } = func;
@@ -294,6 +302,24 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
                     RcDoc::nil()
                 })
                 .append(RcDoc::text(")"))
+                .append(if !order_by.is_empty() {
+                    RcDoc::text(" WITHIN GROUP (ORDER BY ")
+                        .append(inline_comma(
+                            order_by
+                                .into_iter()
+                                .map(|order_by| RcDoc::text(order_by.to_string())),
+                        ))
+                        .append(")")
+                } else {
+                    RcDoc::nil()
+                })
+                .append(if let Some(filter) = filter {
+                    RcDoc::text(" FILTER (WHERE ")
+                        .append(pretty_expr(*filter))
+                        .append(")")
+                } else {
+                    RcDoc::nil()
+                })
                 .append(if let Some(window) = window {
                     if let Some(ignore_null) = window.ignore_nulls {
                         if ignore_null {
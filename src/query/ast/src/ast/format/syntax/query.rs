@@ -20,7 +20,9 @@ use crate::ast::format::syntax::inline_dot;
 use crate::ast::format::syntax::interweave_comma;
 use crate::ast::format::syntax::parenthesized;
 use crate::ast::format::syntax::NEST_FACTOR;
+use crate::ast::ConnectBy;
 use crate::ast::Expr;
+use crate::ast::Fetch;
 use crate::ast::GroupBy;
 use crate::ast::JoinCondition;
 use crate::ast::JoinOperator;
@@ -38,8 +40,10 @@ pub(crate) fn pretty_query(query: Query) -> RcDoc<'static> {
     pretty_with(query.with)
         .append(pretty_body(query.body))
         .append(pretty_order_by(query.order_by))
-        .append(pretty_limit(query.limit))
-        .append(pretty_offset(query.offset))
+        .append(match query.fetch {
+            Some(fetch) => pretty_offset(query.offset).append(pretty_fetch(query.limit, fetch)),
+            None => pretty_limit(query.limit).append(pretty_offset(query.offset)),
+        })
         .group()
 }
 
@@ -84,6 +88,7 @@ fn pretty_body(body: SetExpr) -> RcDoc<'static> {
         .append(pretty_select_list(select_stmt.select_list))
         .append(pretty_from(select_stmt.from))
         .append(pretty_selection(select_stmt.selection))
+        .append(pretty_connect_by(select_stmt.connect_by))
         .append(pretty_group_by(select_stmt.group_by))
         .append(pretty_having(select_stmt.having))
         .append(pretty_window(select_stmt.window_list)),
@@ -216,6 +221,33 @@ fn pretty_selection(selection: Option<Expr>) -> RcDoc<'static> {
     }
 }
 
+fn pretty_connect_by(connect_by: Option<ConnectBy>) -> RcDoc<'static> {
+    if let Some(connect_by) = connect_by {
+        if let Some(start_with) = connect_by.start_with {
+            RcDoc::line().append(RcDoc::text("START WITH")).append(
+                RcDoc::line()
+                    .nest(NEST_FACTOR)
+                    .append(pretty_expr(start_with).nest(NEST_FACTOR).group()),
+            )
+        } else {
+            RcDoc::nil()
+        }
+        .append(RcDoc::line())
+        .append(if connect_by.nocycle {
+            RcDoc::text("CONNECT BY NOCYCLE")
+        } else {
+            RcDoc::text("CONNECT BY")
+        })
+        .append(
+            RcDoc::line()
+                .nest(NEST_FACTOR)
+                .append(pretty_expr(connect_by.condition).nest(NEST_FACTOR).group()),
+        )
+    } else {
+        RcDoc::nil()
+    }
+}
+
 fn pretty_group_set(set: Vec<Expr>) -> RcDoc<'static> {
     RcDoc::nil()
         .append(RcDoc::text("("))
@@ -439,7 +471,7 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             } else {
                 RcDoc::nil()
             })
-            .append(match join.op {
+            .append(match &join.op {
                 JoinOperator::Inner => RcDoc::text("INNER JOIN"),
                 JoinOperator::LeftOuter => RcDoc::text("LEFT OUTER JOIN"),
                 JoinOperator::RightOuter => RcDoc::text("RIGHT OUTER JOIN"),
@@ -449,8 +481,22 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
                 JoinOperator::RightAnti => RcDoc::text("RIGHT ANTI JOIN"),
                 JoinOperator::LeftSemi => RcDoc::text("LEFT SEMI JOIN"),
                 JoinOperator::RightSemi => RcDoc::text("RIGHT SEMI JOIN"),
+                JoinOperator::Asof {
+                    left_outer: true, ..
+                } => RcDoc::text("ASOF LEFT JOIN"),
+                JoinOperator::Asof { .. } => RcDoc::text("ASOF JOIN"),
             })
             .append(RcDoc::space().append(pretty_table(*join.right)))
+            .append(match &join.op {
+                JoinOperator::Asof {
+                    match_condition: Some(expr),
+                    ..
+                } => RcDoc::space()
+                    .append(RcDoc::text("MATCH_CONDITION ("))
+                    .append(pretty_expr(*expr.clone()))
+                    .append(RcDoc::text(")")),
+                _ => RcDoc::nil(),
+            })
             .append(match &join.condition {
                 JoinCondition::On(expr) => RcDoc::space()
                     .append(RcDoc::text("ON"))
@@ -476,6 +522,19 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             } else {
                 RcDoc::nil()
             }),
+        TableReference::MatchRecognize {
+            span: _,
+            table,
+            match_recognize,
+            alias,
+        } => pretty_table(*table)
+            .append(RcDoc::space())
+            .append(RcDoc::text(match_recognize.to_string()))
+            .append(if let Some(alias) = alias {
+                RcDoc::text(format!(" AS {alias}"))
+            } else {
+                RcDoc::nil()
+            }),
     }
 }
 
@@ -525,6 +584,13 @@ fn pretty_limit(limit: Vec<Expr>) -> RcDoc<'static> {
     }
 }
 
+fn pretty_fetch(limit: Vec<Expr>, fetch: Fetch) -> RcDoc<'static> {
+    RcDoc::line()
+        .append(RcDoc::text("FETCH FIRST").append(RcDoc::space().nest(NEST_FACTOR)))
+        .append(interweave_comma(limit.into_iter().map(pretty_expr)))
+        .append(RcDoc::text(fetch.to_string()))
+}
+
 fn pretty_offset(offset: Option<Expr>) -> RcDoc<'static> {
     if let Some(offset) = offset {
         RcDoc::line()
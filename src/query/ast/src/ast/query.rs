@@ -49,6 +49,8 @@ pub struct Query {
     pub limit: Vec<Expr>,
     // `OFFSET` expr
     pub offset: Option<Expr>,
+    // `FETCH FIRST` options, the row count is stored in `limit`
+    pub fetch: Option<Fetch>,
 
     // If ignore the result (not output).
     pub ignore_result: bool,
@@ -70,15 +72,25 @@ impl Display for Query {
             write_comma_separated_list(f, &self.order_by)?;
         }
 
-        // LIMIT clause
-        if !self.limit.is_empty() {
-            write!(f, " LIMIT ")?;
+        if let Some(fetch) = &self.fetch {
+            // OFFSET must appear before FETCH
+            if let Some(offset) = &self.offset {
+                write!(f, " OFFSET {offset}")?;
+            }
+            write!(f, " FETCH FIRST ")?;
             write_comma_separated_list(f, &self.limit)?;
-        }
+            write!(f, "{fetch}")?;
+        } else {
+            // LIMIT clause
+            if !self.limit.is_empty() {
+                write!(f, " LIMIT ")?;
+                write_comma_separated_list(f, &self.limit)?;
+            }
 
-        // TODO: We should validate if offset exists, limit should be empty or just one element
-        if let Some(offset) = &self.offset {
-            write!(f, " OFFSET {offset}")?;
+            // TODO: We should validate if offset exists, limit should be empty or just one element
+            if let Some(offset) = &self.offset {
+                write!(f, " OFFSET {offset}")?;
+            }
         }
 
         if self.ignore_result {
@@ -89,6 +101,28 @@ impl Display for Query {
     }
 }
 
+/// The options of `FETCH { FIRST | NEXT } [ n ] [ PERCENT ] { ROW | ROWS } { ONLY | WITH TIES }`
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct Fetch {
+    /// The row count is a percentage of the rows
+    pub percent: bool,
+    /// Also return the rows that tie with the last row on the `ORDER BY` keys
+    pub with_ties: bool,
+}
+
+impl Display for Fetch {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if self.percent {
+            write!(f, " PERCENT")?;
+        }
+        if self.with_ties {
+            write!(f, " ROWS WITH TIES")
+        } else {
+            write!(f, " ROWS ONLY")
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct With {
     pub span: Span,
@@ -150,6 +184,8 @@ pub struct SelectStmt {
     pub from: Vec<TableReference>,
     // `WHERE` clause
     pub selection: Option<Expr>,
+    // `START WITH ... CONNECT BY ...` clause
+    pub connect_by: Option<ConnectBy>,
     // `GROUP BY` clause
     pub group_by: Option<GroupBy>,
     // `HAVING` clause
@@ -186,6 +222,11 @@ impl Display for SelectStmt {
             write!(f, " WHERE {expr}")?;
         }
 
+        // START WITH ... CONNECT BY clause
+        if let Some(connect_by) = &self.connect_by {
+            write!(f, " {connect_by}")?;
+        }
+
         // GROUP BY clause
         if self.group_by.is_some() {
             write!(f, " GROUP BY ")?;
@@ -239,6 +280,29 @@ impl Display for SelectStmt {
     }
 }
 
+/// Hierarchical query clause.
+///
+/// `[ START WITH <expr> ] CONNECT BY [ NOCYCLE ] <expr>`
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct ConnectBy {
+    pub start_with: Option<Expr>,
+    pub nocycle: bool,
+    pub condition: Expr,
+}
+
+impl Display for ConnectBy {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(start_with) = &self.start_with {
+            write!(f, "START WITH {start_with} ")?;
+        }
+        write!(f, "CONNECT BY ")?;
+        if self.nocycle {
+            write!(f, "NOCYCLE ")?;
+        }
+        write!(f, "{}", self.condition)
+    }
+}
+
 /// Group by Clause.
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum GroupBy {
@@ -501,6 +565,8 @@ pub enum TimeTravelPoint {
         database: Option<Identifier>,
         name: Identifier,
     },
+    Tag(Identifier),
+    Branch(Identifier),
 }
 
 impl Display for TimeTravelPoint {
@@ -515,6 +581,12 @@ impl Display for TimeTravelPoint {
             TimeTravelPoint::Offset(num) => {
                 write!(f, "(OFFSET => {num})")?;
             }
+            TimeTravelPoint::Tag(name) => {
+                write!(f, "(TAG => {name})")?;
+            }
+            TimeTravelPoint::Branch(name) => {
+                write!(f, "(BRANCH => {name})")?;
+            }
             TimeTravelPoint::Stream {
                 catalog,
                 database,
@@ -582,6 +654,191 @@ impl Display for Unpivot {
     }
 }
 
+/// `MATCH_RECOGNIZE (PARTITION BY ... ORDER BY ... MEASURES ... PATTERN (...) DEFINE ...)`
+///
+/// The rows of a partition are matched in memory, a partition must fit in the memory
+/// limit of the query.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct MatchRecognize {
+    pub partition_by: Vec<Expr>,
+    pub order_by: Vec<OrderByExpr>,
+    pub measures: Vec<MatchMeasure>,
+    pub rows_per_match: RowsPerMatch,
+    pub after_match_skip: AfterMatchSkip,
+    pub pattern: RowPattern,
+    pub define: Vec<PatternDefinition>,
+}
+
+impl Display for MatchRecognize {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "MATCH_RECOGNIZE (")?;
+        if !self.partition_by.is_empty() {
+            write!(f, "PARTITION BY ")?;
+            write_comma_separated_list(f, &self.partition_by)?;
+            write!(f, " ")?;
+        }
+        if !self.order_by.is_empty() {
+            write!(f, "ORDER BY ")?;
+            write_comma_separated_list(f, &self.order_by)?;
+            write!(f, " ")?;
+        }
+        if !self.measures.is_empty() {
+            write!(f, "MEASURES ")?;
+            write_comma_separated_list(f, &self.measures)?;
+            write!(f, " ")?;
+        }
+        write!(
+            f,
+            "{} {} PATTERN ({})",
+            self.rows_per_match, self.after_match_skip, self.pattern
+        )?;
+        if !self.define.is_empty() {
+            write!(f, " DEFINE ")?;
+            write_comma_separated_list(f, &self.define)?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct MatchMeasure {
+    pub expr: Expr,
+    pub alias: Identifier,
+}
+
+impl Display for MatchMeasure {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{} AS {}", self.expr, self.alias)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Drive, DriveMut)]
+pub enum RowsPerMatch {
+    OneRow,
+    AllRows,
+}
+
+impl Display for RowsPerMatch {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            RowsPerMatch::OneRow => write!(f, "ONE ROW PER MATCH"),
+            RowsPerMatch::AllRows => write!(f, "ALL ROWS PER MATCH"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub enum AfterMatchSkip {
+    /// `SKIP PAST LAST ROW`, resumes at the row after the last row of the match.
+    PastLastRow,
+    /// `SKIP TO NEXT ROW`, resumes at the row after the first row of the match.
+    ToNextRow,
+    /// `SKIP TO FIRST var`
+    ToFirst(Identifier),
+    /// `SKIP TO [LAST] var`
+    ToLast(Identifier),
+}
+
+impl Display for AfterMatchSkip {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "AFTER MATCH SKIP ")?;
+        match self {
+            AfterMatchSkip::PastLastRow => write!(f, "PAST LAST ROW"),
+            AfterMatchSkip::ToNextRow => write!(f, "TO NEXT ROW"),
+            AfterMatchSkip::ToFirst(var) => write!(f, "TO FIRST {var}"),
+            AfterMatchSkip::ToLast(var) => write!(f, "TO LAST {var}"),
+        }
+    }
+}
+
+/// The row pattern of `PATTERN (...)`, a regular expression over pattern variables.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum RowPattern {
+    Symbol(Identifier),
+    /// `A B C`
+    Concat(Vec<RowPattern>),
+    /// `A | B`
+    Alternation(Vec<RowPattern>),
+    /// `A*`, `A+`, `A?` or `A{n,m}`
+    Repetition {
+        pattern: Box<RowPattern>,
+        quantifier: RepetitionQuantifier,
+    },
+    /// `(A B)`
+    Group(Box<RowPattern>),
+}
+
+impl Display for RowPattern {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            RowPattern::Symbol(var) => write!(f, "{var}"),
+            RowPattern::Concat(patterns) => {
+                for (i, pattern) in patterns.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{pattern}")?;
+                }
+                Ok(())
+            }
+            RowPattern::Alternation(patterns) => {
+                for (i, pattern) in patterns.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "{pattern}")?;
+                }
+                Ok(())
+            }
+            RowPattern::Repetition {
+                pattern,
+                quantifier,
+            } => write!(f, "{pattern}{quantifier}"),
+            RowPattern::Group(pattern) => write!(f, "({pattern})"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Drive, DriveMut)]
+pub enum RepetitionQuantifier {
+    ZeroOrMore,
+    OneOrMore,
+    AtMostOne,
+    Range { min: u64, max: Option<u64> },
+}
+
+impl Display for RepetitionQuantifier {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            RepetitionQuantifier::ZeroOrMore => write!(f, "*"),
+            RepetitionQuantifier::OneOrMore => write!(f, "+"),
+            RepetitionQuantifier::AtMostOne => write!(f, "?"),
+            RepetitionQuantifier::Range { min, max: None } => write!(f, "{{{min},}}"),
+            RepetitionQuantifier::Range {
+                min,
+                max: Some(max),
+            } if min == max => write!(f, "{{{min}}}"),
+            RepetitionQuantifier::Range {
+                min,
+                max: Some(max),
+            } => write!(f, "{{{min},{max}}}"),
+        }
+    }
+}
+
+/// `var AS condition` of the `DEFINE` clause.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct PatternDefinition {
+    pub name: Identifier,
+    pub condition: Expr,
+}
+
+impl Display for PatternDefinition {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{} AS {}", self.name, self.condition)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Default, Drive, DriveMut)]
 pub struct WithOptions {
     pub options: BTreeMap<String, String>,
@@ -766,6 +1023,13 @@ pub enum TableReference {
         options: SelectStageOptions,
         alias: Option<TableAlias>,
     },
+    // `table MATCH_RECOGNIZE (...) [ AS alias ]`
+    MatchRecognize {
+        span: Span,
+        table: Box<TableReference>,
+        match_recognize: Box<MatchRecognize>,
+        alias: Option<TableAlias>,
+    },
 }
 
 impl TableReference {
@@ -903,7 +1167,7 @@ impl Display for TableReference {
                 if join.condition == JoinCondition::Natural {
                     write!(f, " NATURAL")?;
                 }
-                match join.op {
+                match &join.op {
                     JoinOperator::Inner => {
                         write!(f, " INNER JOIN")?;
                     }
@@ -931,8 +1195,22 @@ impl Display for TableReference {
                     JoinOperator::CrossJoin => {
                         write!(f, " CROSS JOIN")?;
                     }
+                    JoinOperator::Asof { left_outer, .. } => {
+                        if *left_outer {
+                            write!(f, " ASOF LEFT JOIN")?;
+                        } else {
+                            write!(f, " ASOF JOIN")?;
+                        }
+                    }
                 }
                 write!(f, " {}", join.right)?;
+                if let JoinOperator::Asof {
+                    match_condition: Some(match_condition),
+                    ..
+                } = &join.op
+                {
+                    write!(f, " MATCH_CONDITION ({match_condition})")?;
+                }
                 match &join.condition {
                     JoinCondition::On(expr) => {
                         write!(f, " ON {expr}")?;
@@ -959,6 +1237,17 @@ impl Display for TableReference {
                     write!(f, " AS {alias}")?;
                 }
             }
+            TableReference::MatchRecognize {
+                span: _,
+                table,
+                match_recognize,
+                alias,
+            } => {
+                write!(f, "{table} {match_recognize}")?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {alias}")?;
+                }
+            }
         }
         Ok(())
     }
@@ -990,7 +1279,7 @@ pub struct Join {
     pub right: Box<TableReference>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum JoinOperator {
     Inner,
     // Outer joins can not work with `JoinCondition::None`
@@ -1003,6 +1292,13 @@ pub enum JoinOperator {
     RightAnti,
     // CrossJoin can only work with `JoinCondition::None`
     CrossJoin,
+    // `ASOF [LEFT] JOIN ... MATCH_CONDITION (expr)`, which keeps only the nearest
+    // right row satisfying the match condition for each left row.
+    // The match condition is filled in once `MATCH_CONDITION` has been parsed.
+    Asof {
+        left_outer: bool,
+        match_condition: Option<Box<Expr>>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
@@ -1033,6 +1329,7 @@ impl SetExpr {
                 order_by: vec![],
                 limit: vec![],
                 offset: None,
+                fetch: None,
                 ignore_result: false,
             },
         }
@@ -54,12 +54,16 @@ impl Display for ProcedureType {
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum ProcedureLanguage {
     SQL,
+    Python,
+    JavaScript,
 }
 
 impl Display for ProcedureLanguage {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             ProcedureLanguage::SQL => write!(f, "LANGUAGE SQL "),
+            ProcedureLanguage::Python => write!(f, "LANGUAGE PYTHON "),
+            ProcedureLanguage::JavaScript => write!(f, "LANGUAGE JAVASCRIPT "),
         }
     }
 }
@@ -91,7 +95,7 @@ pub struct CreateProcedureStmt {
 impl Display for CreateProcedureStmt {
     // CREATE [ OR REPLACE ] PROCEDURE <name> ()
     // RETURNS { <result_data_type> }[ NOT NULL ]
-    // LANGUAGE SQL
+    // LANGUAGE { SQL | PYTHON | JAVASCRIPT }
     // [ COMMENT = '<string_literal>' ] AS <procedure_definition>
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "CREATE ")?;
@@ -27,6 +27,7 @@ pub struct ScriptBlock {
     pub span: Span,
     pub declares: Vec<DeclareItem>,
     pub body: Vec<ScriptStatement>,
+    pub exception_handlers: Vec<ExceptionHandler>,
 }
 
 impl Display for ScriptBlock {
@@ -47,15 +48,68 @@ impl Display for ScriptBlock {
                 indent::indent_all_by(INDENT_DEPTH, format!("{};", stmt))
             )?;
         }
+        write_exception_handlers(f, &self.exception_handlers)?;
         writeln!(f, "END;")?;
         Ok(())
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExceptionHandler {
+    pub span: Span,
+    /// The names of the handled exceptions, or `None` for `WHEN OTHER`.
+    pub exceptions: Option<Vec<Identifier>>,
+    pub body: Vec<ScriptStatement>,
+}
+
+impl Display for ExceptionHandler {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match &self.exceptions {
+            Some(exceptions) => {
+                write!(f, "WHEN ")?;
+                for (i, exception) in exceptions.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " OR ")?;
+                    }
+                    write!(f, "{exception}")?;
+                }
+                writeln!(f, " THEN")?;
+            }
+            None => writeln!(f, "WHEN OTHER THEN")?,
+        }
+        for stmt in &self.body {
+            writeln!(
+                f,
+                "{}",
+                indent::indent_all_by(INDENT_DEPTH, format!("{stmt};"))
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn write_exception_handlers(
+    f: &mut Formatter,
+    exception_handlers: &[ExceptionHandler],
+) -> std::fmt::Result {
+    if !exception_handlers.is_empty() {
+        writeln!(f, "EXCEPTION")?;
+        for handler in exception_handlers {
+            write!(
+                f,
+                "{}",
+                indent::indent_all_by(INDENT_DEPTH, format!("{handler}"))
+            )?;
+        }
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum DeclareItem {
     Var(DeclareVar),
     Set(DeclareSet),
+    Cursor(DeclareCursor),
 }
 
 impl Display for DeclareItem {
@@ -63,6 +117,7 @@ impl Display for DeclareItem {
         match self {
             DeclareItem::Var(declare) => write!(f, "{declare}"),
             DeclareItem::Set(declare) => write!(f, "{declare}"),
+            DeclareItem::Cursor(declare) => write!(f, "{declare}"),
         }
     }
 }
@@ -96,6 +151,20 @@ impl Display for DeclareSet {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeclareCursor {
+    pub span: Span,
+    pub name: Identifier,
+    pub stmt: Statement,
+}
+
+impl Display for DeclareCursor {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let DeclareCursor { name, stmt, .. } = self;
+        write!(f, "{name} CURSOR FOR {stmt}")
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ReturnItem {
     Var(Expr),
@@ -121,6 +190,9 @@ pub enum ScriptStatement {
     LetStatement {
         declare: DeclareSet,
     },
+    LetCursor {
+        declare: DeclareCursor,
+    },
     RunStatement {
         span: Span,
         stmt: Statement,
@@ -195,6 +267,30 @@ pub enum ScriptStatement {
         results: Vec<Vec<ScriptStatement>>,
         else_result: Option<Vec<ScriptStatement>>,
     },
+    Block {
+        span: Span,
+        body: Vec<ScriptStatement>,
+        exception_handlers: Vec<ExceptionHandler>,
+    },
+    /// `RAISE` without an exception re-raises the exception being handled.
+    Raise {
+        span: Span,
+        exception: Option<Identifier>,
+        message: Option<Expr>,
+    },
+    Open {
+        span: Span,
+        cursor: Identifier,
+    },
+    Fetch {
+        span: Span,
+        cursor: Identifier,
+        into: Vec<Identifier>,
+    },
+    Close {
+        span: Span,
+        cursor: Identifier,
+    },
 }
 
 impl Display for ScriptStatement {
@@ -202,6 +298,7 @@ impl Display for ScriptStatement {
         match self {
             ScriptStatement::LetVar { declare, .. } => write!(f, "LET {declare}"),
             ScriptStatement::LetStatement { declare, .. } => write!(f, "LET {declare}"),
+            ScriptStatement::LetCursor { declare, .. } => write!(f, "LET {declare}"),
             ScriptStatement::RunStatement { stmt, .. } => write!(f, "{stmt}"),
             ScriptStatement::Assign { name, value, .. } => write!(f, "{name} := {value}"),
             ScriptStatement::Return { value, .. } => {
@@ -422,6 +519,46 @@ impl Display for ScriptStatement {
                 }
                 write!(f, "END IF")
             }
+            ScriptStatement::Block {
+                body,
+                exception_handlers,
+                ..
+            } => {
+                writeln!(f, "BEGIN")?;
+                for stmt in body {
+                    writeln!(
+                        f,
+                        "{}",
+                        indent::indent_all_by(INDENT_DEPTH, format!("{stmt};"))
+                    )?;
+                }
+                write_exception_handlers(f, exception_handlers)?;
+                write!(f, "END")
+            }
+            ScriptStatement::Raise {
+                exception, message, ..
+            } => {
+                write!(f, "RAISE")?;
+                if let Some(exception) = exception {
+                    write!(f, " {exception}")?;
+                }
+                if let Some(message) = message {
+                    write!(f, "({message})")?;
+                }
+                Ok(())
+            }
+            ScriptStatement::Open { cursor, .. } => write!(f, "OPEN {cursor}"),
+            ScriptStatement::Fetch { cursor, into, .. } => {
+                write!(f, "FETCH {cursor} INTO ")?;
+                for (i, var) in into.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{var}")?;
+                }
+                Ok(())
+            }
+            ScriptStatement::Close { cursor, .. } => write!(f, "CLOSE {cursor}"),
         }
     }
 }
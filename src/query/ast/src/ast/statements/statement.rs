@@ -209,6 +209,10 @@ pub enum Statement {
         if_exists: bool,
         role_name: String,
     },
+    AlterRole {
+        role_name: String,
+        action: AlterRoleAction,
+    },
     Grant(GrantStmt),
     ShowGrants {
         principal: Option<PrincipalIdentity>,
@@ -283,6 +287,12 @@ pub enum Statement {
         show_options: Option<ShowOptions>,
     },
 
+    // workload group
+    CreateWorkloadGroup(CreateWorkloadGroupStmt),
+    AlterWorkloadGroup(AlterWorkloadGroupStmt),
+    DropWorkloadGroup(DropWorkloadGroupStmt),
+    ShowWorkloadGroups,
+
     // tasks
     CreateTask(CreateTaskStmt),
     AlterTask(AlterTaskStmt),
@@ -320,6 +330,11 @@ pub enum Statement {
     DescProcedure(DescProcedureStmt),
     CallProcedure(CallProcedureStmt),
 
+    // Prepared statements
+    Prepare(PrepareStmt),
+    Execute(ExecuteStmt),
+    Deallocate(DeallocateStmt),
+
     // Sequence
     CreateSequence(CreateSequenceStmt),
     DropSequence(DropSequenceStmt),
@@ -606,6 +621,9 @@ impl Display for Statement {
                 }
                 write!(f, " '{role}'")?;
             }
+            Statement::AlterRole { role_name, action } => {
+                write!(f, "ALTER ROLE {} {action}", QuotedString(role_name, '\''))?;
+            }
             Statement::Grant(stmt) => write!(f, "{stmt}")?,
             Statement::ShowGrants {
                 principal,
@@ -703,6 +721,10 @@ impl Display for Statement {
                     write!(f, " {show_options}")?;
                 }
             }
+            Statement::CreateWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::DropWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowWorkloadGroups => write!(f, "SHOW WORKLOAD GROUPS")?,
             Statement::CreateTask(stmt) => write!(f, "{stmt}")?,
             Statement::AlterTask(stmt) => write!(f, "{stmt}")?,
             Statement::ExecuteTask(stmt) => write!(f, "{stmt}")?,
@@ -725,6 +747,9 @@ impl Display for Statement {
             Statement::DropNotification(stmt) => write!(f, "{stmt}")?,
             Statement::DescribeNotification(stmt) => write!(f, "{stmt}")?,
             Statement::ExecuteImmediate(stmt) => write!(f, "{stmt}")?,
+            Statement::Prepare(stmt) => write!(f, "{stmt}")?,
+            Statement::Execute(stmt) => write!(f, "{stmt}")?,
+            Statement::Deallocate(stmt) => write!(f, "{stmt}")?,
             Statement::CreateProcedure(stmt) => write!(f, "{stmt}")?,
             Statement::DropProcedure(stmt) => write!(f, "{stmt}")?,
             Statement::DescProcedure(stmt) => write!(f, "{stmt}")?,
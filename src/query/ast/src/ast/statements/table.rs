@@ -178,6 +178,8 @@ pub struct CreateTableStmt {
     pub engine: Option<Engine>,
     pub uri_location: Option<UriLocation>,
     pub cluster_by: Option<ClusterOption>,
+    /// The time a row expires at, e.g. `ts + INTERVAL 7 DAY`.
+    pub ttl: Option<Expr>,
     pub table_options: BTreeMap<String, String>,
     pub as_query: Option<Box<Query>>,
     pub table_type: TableType,
@@ -229,6 +231,10 @@ impl Display for CreateTableStmt {
             write!(f, " {cluster_by}")?;
         }
 
+        if let Some(ttl) = &self.ttl {
+            write!(f, " TTL {ttl}")?;
+        }
+
         // Format table options
         if !self.table_options.is_empty() {
             write!(f, " ")?;
@@ -270,24 +276,39 @@ impl Display for AttachTableStmt {
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum CreateTableSource {
-    Columns(Vec<ColumnDefinition>, Option<Vec<InvertedIndexDefinition>>),
+    Columns(
+        Vec<ColumnDefinition>,
+        Option<Vec<InvertedIndexDefinition>>,
+        Vec<TableConstraintDefinition>,
+    ),
     Like {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
         table: Identifier,
     },
+    /// A zero-copy clone of the table, or of the table at `travel_point`.
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            CreateTableSource::Columns(columns, inverted_indexes) => {
+            CreateTableSource::Columns(columns, inverted_indexes, constraints) => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, columns)?;
                 if let Some(inverted_indexes) = inverted_indexes {
                     write!(f, ", ")?;
                     write_comma_separated_list(f, inverted_indexes)?;
                 }
+                if !constraints.is_empty() {
+                    write!(f, ", ")?;
+                    write_comma_separated_list(f, constraints)?;
+                }
                 write!(f, ")")
             }
             CreateTableSource::Like {
@@ -298,6 +319,19 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT {travel_point}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -420,6 +454,10 @@ pub enum AlterTableAction {
         selection: Option<Expr>,
         limit: Option<u64>,
     },
+    WarmCache {
+        columns: Option<Vec<Identifier>>,
+        selection: Option<Expr>,
+    },
     FlashbackTo {
         point: TimeTravelPoint,
     },
@@ -429,6 +467,22 @@ pub enum AlterTableAction {
     UnsetOptions {
         targets: Vec<Identifier>,
     },
+    CreateTableRef {
+        ref_type: TableRefType,
+        name: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+        retain_days: Option<u64>,
+    },
+    DropTableRef {
+        ref_type: TableRefType,
+        name: Identifier,
+    },
+    AddConstraint {
+        constraint: TableConstraintDefinition,
+    },
+    DropConstraint {
+        name: Identifier,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -483,6 +537,17 @@ impl Display for AlterTableAction {
                     write!(f, " LIMIT {limit}")?;
                 }
             }
+            AlterTableAction::WarmCache { columns, selection } => {
+                write!(f, "WARM CACHE")?;
+                if let Some(columns) = columns {
+                    write!(f, " COLUMNS (")?;
+                    write_comma_separated_list(f, columns)?;
+                    write!(f, ")")?;
+                }
+                if let Some(conditions) = selection {
+                    write!(f, " WHERE {conditions}")?;
+                }
+            }
             AlterTableAction::FlashbackTo { point } => {
                 write!(f, "FLASHBACK TO {}", point)?;
             }
@@ -498,11 +563,49 @@ impl Display for AlterTableAction {
                     write!(f, ")")?;
                 }
             }
+            AlterTableAction::CreateTableRef {
+                ref_type,
+                name,
+                travel_point,
+                retain_days,
+            } => {
+                write!(f, "CREATE {ref_type} {name}")?;
+                if let Some(point) = travel_point {
+                    write!(f, " AT {point}")?;
+                }
+                if let Some(days) = retain_days {
+                    write!(f, " RETAIN {days} DAYS")?;
+                }
+            }
+            AlterTableAction::DropTableRef { ref_type, name } => {
+                write!(f, "DROP {ref_type} {name}")?;
+            }
+            AlterTableAction::AddConstraint { constraint } => {
+                write!(f, "ADD {constraint}")?;
+            }
+            AlterTableAction::DropConstraint { name } => {
+                write!(f, "DROP CONSTRAINT {name}")?;
+            }
         };
         Ok(())
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Drive, DriveMut)]
+pub enum TableRefType {
+    Tag,
+    Branch,
+}
+
+impl Display for TableRefType {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TableRefType::Tag => write!(f, "TAG"),
+            TableRefType::Branch => write!(f, "BRANCH"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum AddColumnOption {
     End,
@@ -729,6 +832,7 @@ pub enum Engine {
     Random,
     Iceberg,
     Delta,
+    Kafka,
 }
 
 impl Display for Engine {
@@ -741,6 +845,7 @@ impl Display for Engine {
             Engine::Random => write!(f, "RANDOM"),
             Engine::Iceberg => write!(f, "ICEBERG"),
             Engine::Delta => write!(f, "DELTA"),
+            Engine::Kafka => write!(f, "KAFKA"),
         }
     }
 }
@@ -794,14 +899,21 @@ impl Display for VacuumDropTableOption {
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum OptimizeTableAction {
     All,
-    Purge { before: Option<TimeTravelPoint> },
-    Compact { target: CompactTarget },
+    Purge {
+        before: Option<TimeTravelPoint>,
+    },
+    Compact {
+        target: CompactTarget,
+    },
+    /// Remove the rows expired by the TTL of the table.
+    Expire,
 }
 
 impl Display for OptimizeTableAction {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             OptimizeTableAction::All => write!(f, "ALL"),
+            OptimizeTableAction::Expire => write!(f, "EXPIRE"),
             OptimizeTableAction::Purge { before } => {
                 write!(f, "PURGE")?;
                 if let Some(point) = before {
@@ -859,6 +971,7 @@ pub struct ColumnDefinition {
     pub name: Identifier,
     pub data_type: TypeName,
     pub expr: Option<ColumnExpr>,
+    pub codec: Vec<ColumnCodec>,
     pub comment: Option<String>,
 }
 
@@ -868,6 +981,11 @@ impl Display for ColumnDefinition {
         if let Some(expr) = &self.expr {
             write!(f, "{expr}")?;
         }
+        if !self.codec.is_empty() {
+            write!(f, " CODEC(")?;
+            write_comma_separated_list(f, &self.codec)?;
+            write!(f, ")")?;
+        }
         if let Some(comment) = &self.comment {
             write!(f, " COMMENT '{comment}'")?;
         }
@@ -875,6 +993,23 @@ impl Display for ColumnDefinition {
     }
 }
 
+/// An encoding or compression of a column, e.g. `DELTA` or `ZSTD(3)` in `CODEC(DELTA, ZSTD(3))`.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct ColumnCodec {
+    pub name: Identifier,
+    pub level: Option<u64>,
+}
+
+impl Display for ColumnCodec {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(level) = self.level {
+            write!(f, "({level})")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct InvertedIndexDefinition {
     pub index_name: Identifier,
@@ -903,10 +1038,60 @@ impl Display for InvertedIndexDefinition {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct TableConstraintDefinition {
+    pub name: Option<Identifier>,
+    pub constraint: TableConstraintKind,
+}
+
+impl Display for TableConstraintDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = &self.name {
+            write!(f, "CONSTRAINT {} ", name)?;
+        }
+        write!(f, "{}", self.constraint)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum TableConstraintKind {
+    PrimaryKey {
+        columns: Vec<Identifier>,
+        enforced: bool,
+    },
+    Unique {
+        columns: Vec<Identifier>,
+        enforced: bool,
+    },
+    Check {
+        expr: Expr,
+    },
+}
+
+impl Display for TableConstraintKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (keyword, columns, enforced) = match self {
+            TableConstraintKind::PrimaryKey { columns, enforced } => {
+                ("PRIMARY KEY", columns, enforced)
+            }
+            TableConstraintKind::Unique { columns, enforced } => ("UNIQUE", columns, enforced),
+            TableConstraintKind::Check { expr } => return write!(f, "CHECK ({expr})"),
+        };
+        write!(f, "{keyword} (")?;
+        write_comma_separated_list(f, columns)?;
+        write!(f, ")")?;
+        if !enforced {
+            write!(f, " NOT ENFORCED")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum CreateDefinition {
     Column(ColumnDefinition),
     InvertedIndex(InvertedIndexDefinition),
+    Constraint(TableConstraintDefinition),
 }
 
 impl Display for CreateDefinition {
@@ -918,6 +1103,9 @@ impl Display for CreateDefinition {
             CreateDefinition::InvertedIndex(inverted_index_def) => {
                 write!(f, "{}", inverted_index_def)?;
             }
+            CreateDefinition::Constraint(constraint_def) => {
+                write!(f, "{}", constraint_def)?;
+            }
         }
         Ok(())
     }
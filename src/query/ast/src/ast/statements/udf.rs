@@ -46,6 +46,30 @@ pub enum UDFDefinition {
         language: String,
         runtime_version: String,
     },
+
+    UDTFSql {
+        arg_types: Vec<(Identifier, TypeName)>,
+        return_types: Vec<(Identifier, TypeName)>,
+        sql: String,
+    },
+
+    UDTFScript {
+        arg_types: Vec<TypeName>,
+        return_types: Vec<(Identifier, TypeName)>,
+        code: String,
+        handler: String,
+        language: String,
+        runtime_version: String,
+    },
+
+    UDAFScript {
+        arg_types: Vec<TypeName>,
+        state_types: Vec<(Identifier, TypeName)>,
+        return_type: TypeName,
+        code: String,
+        language: String,
+        runtime_version: String,
+    },
 }
 
 impl Display for UDFDefinition {
@@ -88,11 +112,66 @@ impl Display for UDFDefinition {
                     ") RETURNS {return_type} LANGUAGE {language} HANDLER = '{handler}' AS $$\n{code}\n$$"
                 )?;
             }
+            UDFDefinition::UDTFSql {
+                arg_types,
+                return_types,
+                sql,
+            } => {
+                write!(f, "(")?;
+                write_typed_idents(f, arg_types)?;
+                write!(f, ") RETURNS TABLE (")?;
+                write_typed_idents(f, return_types)?;
+                write!(f, ") AS $$\n{sql}\n$$")?;
+            }
+            UDFDefinition::UDTFScript {
+                arg_types,
+                return_types,
+                code,
+                handler,
+                language,
+                runtime_version: _,
+            } => {
+                write!(f, "(")?;
+                write_comma_separated_list(f, arg_types)?;
+                write!(f, ") RETURNS TABLE (")?;
+                write_typed_idents(f, return_types)?;
+                write!(
+                    f,
+                    ") LANGUAGE {language} HANDLER = '{handler}' AS $$\n{code}\n$$"
+                )?;
+            }
+            UDFDefinition::UDAFScript {
+                arg_types,
+                state_types,
+                return_type,
+                code,
+                language,
+                runtime_version: _,
+            } => {
+                write!(f, "(")?;
+                write_comma_separated_list(f, arg_types)?;
+                write!(f, ") STATE {{ ")?;
+                write_typed_idents(f, state_types)?;
+                write!(
+                    f,
+                    " }} RETURNS {return_type} LANGUAGE {language} AS $$\n{code}\n$$"
+                )?;
+            }
         }
         Ok(())
     }
 }
 
+fn write_typed_idents(f: &mut Formatter, items: &[(Identifier, TypeName)]) -> std::fmt::Result {
+    for (i, (name, ty)) in items.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{name} {ty}")?;
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct CreateUDFStmt {
     pub create_option: CreateOption,
@@ -107,6 +186,9 @@ impl Display for CreateUDFStmt {
         if let CreateOption::CreateOrReplace = self.create_option {
             write!(f, " OR REPLACE")?;
         }
+        if let UDFDefinition::UDAFScript { .. } = self.definition {
+            write!(f, " AGGREGATE")?;
+        }
         write!(f, " FUNCTION")?;
         if let CreateOption::CreateIfNotExists = self.create_option {
             write!(f, " IF NOT EXISTS")?;
@@ -262,6 +262,8 @@ pub enum UserOptionItem {
     SetPasswordPolicy(String),
     UnsetPasswordPolicy,
     MustChangePassword(bool),
+    SetWorkloadGroup(String),
+    UnsetWorkloadGroup,
 }
 
 impl Display for UserOptionItem {
@@ -276,6 +278,8 @@ impl Display for UserOptionItem {
             UserOptionItem::UnsetPasswordPolicy => write!(f, "UNSET PASSWORD POLICY"),
             UserOptionItem::Disabled(v) => write!(f, "DISABLED = {}", v),
             UserOptionItem::MustChangePassword(v) => write!(f, "MUST_CHANGE_PASSWORD = {}", v),
+            UserOptionItem::SetWorkloadGroup(v) => write!(f, "SET WORKLOAD_GROUP = '{}'", v),
+            UserOptionItem::UnsetWorkloadGroup => write!(f, "UNSET WORKLOAD_GROUP"),
         }
     }
 }
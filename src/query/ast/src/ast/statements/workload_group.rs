@@ -0,0 +1,131 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+
+use crate::ast::CreateOption;
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct CreateWorkloadGroupStmt {
+    pub create_option: CreateOption,
+    pub name: String,
+    pub set_options: WorkloadGroupSetOptions,
+}
+
+impl Display for CreateWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE ")?;
+        if let CreateOption::CreateOrReplace = self.create_option {
+            write!(f, "OR REPLACE ")?;
+        }
+        write!(f, "WORKLOAD GROUP ")?;
+        if let CreateOption::CreateIfNotExists = self.create_option {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        write!(f, "{}", self.set_options)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct AlterWorkloadGroupStmt {
+    pub if_exists: bool,
+    pub name: String,
+    pub set_options: WorkloadGroupSetOptions,
+}
+
+impl Display for AlterWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER WORKLOAD GROUP ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{} SET", self.name)?;
+        write!(f, "{}", self.set_options)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct DropWorkloadGroupStmt {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+impl Display for DropWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP WORKLOAD GROUP ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Drive, DriveMut)]
+pub struct WorkloadGroupSetOptions {
+    pub max_concurrency: Option<u64>,
+    pub memory_quota: Option<u64>,
+    pub cpu_weight: Option<u64>,
+    pub queue_timeout: Option<u64>,
+    pub comment: Option<String>,
+}
+
+impl Display for WorkloadGroupSetOptions {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(max_concurrency) = self.max_concurrency {
+            write!(f, " MAX_CONCURRENCY = {}", max_concurrency)?;
+        }
+        if let Some(memory_quota) = self.memory_quota {
+            write!(f, " MEMORY_QUOTA = {}", memory_quota)?;
+        }
+        if let Some(cpu_weight) = self.cpu_weight {
+            write!(f, " CPU_WEIGHT = {}", cpu_weight)?;
+        }
+        if let Some(queue_timeout) = self.queue_timeout {
+            write!(f, " QUEUE_TIMEOUT = {}", queue_timeout)?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{}'", comment)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub enum AlterRoleAction {
+    SetWorkloadGroup(String),
+    UnsetWorkloadGroup,
+}
+
+impl Display for AlterRoleAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AlterRoleAction::SetWorkloadGroup(group) => {
+                write!(f, "SET WORKLOAD_GROUP = '{}'", group)
+            }
+            AlterRoleAction::UnsetWorkloadGroup => write!(f, "UNSET WORKLOAD_GROUP"),
+        }
+    }
+}
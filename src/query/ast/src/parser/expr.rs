@@ -233,6 +233,7 @@ pub enum ExprElement {
     },
     /// `Count(*)` expression
     CountAll {
+        filter: Option<Box<Expr>>,
         window: Option<Window>,
     },
     /// `(foo, bar)`
@@ -338,6 +339,8 @@ const fn unary_affix(op: &UnaryOperator) -> Affix {
         UnaryOperator::CubeRoot => Affix::Prefix(Precedence(60)),
         UnaryOperator::Abs => Affix::Prefix(Precedence(60)),
         UnaryOperator::Factorial => Affix::Postfix(Precedence(60)),
+        UnaryOperator::Prior => Affix::Prefix(Precedence(60)),
+        UnaryOperator::ConnectByRoot => Affix::Prefix(Precedence(60)),
     }
 }
 
@@ -529,8 +532,9 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 span: transform_span(elem.span.tokens),
                 value,
             },
-            ExprElement::CountAll { window } => Expr::CountAll {
+            ExprElement::CountAll { filter, window } => Expr::CountAll {
                 span: transform_span(elem.span.tokens),
+                filter,
                 window,
             },
             ExprElement::Tuple { exprs } => Expr::Tuple {
@@ -589,6 +593,8 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                             ),
                             args: vec![source],
                             params: vec![],
+                            order_by: vec![],
+                            filter: None,
                             window: None,
                             lambda: Some(Lambda {
                                 params: vec![param.clone()],
@@ -605,6 +611,8 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                         name: Identifier::from_name(transform_span(elem.span.tokens), "array_map"),
                         args: vec![source],
                         params: vec![],
+                        order_by: vec![],
+                        filter: None,
                         window: None,
                         lambda: Some(Lambda {
                             params: vec![param.clone()],
@@ -753,6 +761,8 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                     name,
                     args: [vec![lhs], args].concat(),
                     params: vec![],
+                    order_by: vec![],
+                    filter: None,
                     window: None,
                     lambda,
                 },
@@ -954,9 +964,12 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
 
     let count_all_with_window = map(
         rule! {
-            COUNT ~ "(" ~ "*" ~ ")" ~ ( OVER ~ #window_spec_ident )?
+            COUNT ~ "(" ~ "*" ~ ")"
+            ~ ( FILTER ~ "(" ~ WHERE ~ ^#subexpr(0) ~ ^")" )?
+            ~ ( OVER ~ #window_spec_ident )?
         },
-        |(_, _, _, _, window)| ExprElement::CountAll {
+        |(_, _, _, _, filter, window)| ExprElement::CountAll {
+            filter: filter.map(|(_, _, _, filter, _)| Box::new(filter)),
             window: window.map(|w| w.1),
         },
     );
@@ -992,13 +1005,16 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         rule! {
             #function_name
             ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
+            ~ #aggregate_clauses
         },
-        |(name, _, opt_distinct, opt_args, _)| ExprElement::FunctionCall {
+        |(name, _, opt_distinct, opt_args, _, (order_by, filter))| ExprElement::FunctionCall {
             func: FunctionCall {
                 distinct: opt_distinct.is_some(),
                 name,
                 args: opt_args.unwrap_or_default(),
                 params: vec![],
+                order_by,
+                filter,
                 window: None,
                 lambda: None,
             },
@@ -1015,6 +1031,8 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
                 name,
                 args: vec![arg],
                 params: vec![],
+                order_by: vec![],
+                filter: None,
                 window: None,
                 lambda: Some(Lambda {
                     params,
@@ -1027,17 +1045,22 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         rule! {
             #function_name
             ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
+            ~ #aggregate_clauses
             ~ #window_function
         },
-        |(name, _, opt_distinct, opt_args, _, window)| ExprElement::FunctionCall {
-            func: FunctionCall {
-                distinct: opt_distinct.is_some(),
-                name,
-                args: opt_args.unwrap_or_default(),
-                params: vec![],
-                window: Some(window),
-                lambda: None,
-            },
+        |(name, _, opt_distinct, opt_args, _, (order_by, filter), window)| {
+            ExprElement::FunctionCall {
+                func: FunctionCall {
+                    distinct: opt_distinct.is_some(),
+                    name,
+                    args: opt_args.unwrap_or_default(),
+                    params: vec![],
+                    order_by,
+                    filter,
+                    window: Some(window),
+                    lambda: None,
+                },
+            }
         },
     );
     let function_call_with_params = map(
@@ -1045,16 +1068,21 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             #function_name
             ~ ("(" ~ #comma_separated_list1(subexpr(0)) ~ ")")?
             ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
+            ~ #aggregate_clauses
         },
-        |(name, params, _, opt_distinct, opt_args, _)| ExprElement::FunctionCall {
-            func: FunctionCall {
-                distinct: opt_distinct.is_some(),
-                name,
-                args: opt_args.unwrap_or_default(),
-                params: params.map(|(_, x, _)| x).unwrap_or_default(),
-                window: None,
-                lambda: None,
-            },
+        |(name, params, _, opt_distinct, opt_args, _, (order_by, filter))| {
+            ExprElement::FunctionCall {
+                func: FunctionCall {
+                    distinct: opt_distinct.is_some(),
+                    name,
+                    args: opt_args.unwrap_or_default(),
+                    params: params.map(|(_, x, _)| x).unwrap_or_default(),
+                    order_by,
+                    filter,
+                    window: None,
+                    lambda: None,
+                },
+            }
         },
     );
 
@@ -1262,6 +1290,8 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
                 name: Identifier::from_name(transform_span(span.tokens), "current_timestamp"),
                 args: vec![],
                 params: vec![],
+                order_by: vec![],
+                filter: None,
                 window: None,
                 lambda: None,
             },
@@ -1331,6 +1361,8 @@ pub fn unary_op(i: Input) -> IResult<UnaryOperator> {
         value(UnaryOperator::BitwiseNot, rule! { BitWiseNot }),
         value(UnaryOperator::CubeRoot, rule! { CubeRoot }),
         value(UnaryOperator::Abs, rule! { Abs }),
+        value(UnaryOperator::Prior, rule! { PRIOR }),
+        value(UnaryOperator::ConnectByRoot, rule! { CONNECT_BY_ROOT }),
     ))(i)
 }
 
@@ -1876,6 +1908,8 @@ pub(crate) fn make_func_get_variable(span: Span, name: String) -> Expr {
                 value: Literal::String(name),
             }],
             params: vec![],
+            order_by: vec![],
+            filter: None,
             window: None,
             lambda: None,
         },
@@ -17,6 +17,7 @@ use std::collections::BTreeMap;
 use nom::branch::alt;
 use nom::combinator::consumed;
 use nom::combinator::map;
+use nom::combinator::not;
 use nom::combinator::value;
 use nom::error::context;
 use nom_rule::rule;
@@ -61,6 +62,7 @@ pub enum SetOperationElement {
         select_list: Vec<SelectTarget>,
         from: Vec<TableReference>,
         selection: Option<Expr>,
+        connect_by: Option<ConnectBy>,
         group_by: Option<GroupBy>,
         having: Option<Expr>,
         window_list: Option<Vec<WindowDefinition>>,
@@ -80,6 +82,10 @@ pub enum SetOperationElement {
     Offset {
         offset: Expr,
     },
+    Fetch {
+        limit: Expr,
+        fetch: Fetch,
+    },
     IgnoreResult,
     Group(SetExpr),
 }
@@ -109,6 +115,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
             ~ SELECT ~ #hint? ~ DISTINCT? ~ #top_n? ~ ^#comma_separated_list1(select_target)
             ~ ( FROM ~ ^#comma_separated_list1(table_reference) )?
             ~ ( WHERE ~ ^#expr )?
+            ~ #connect_by?
             ~ ( GROUP ~ ^BY ~ ^#group_by_items )?
             ~ ( HAVING ~ ^#expr )?
             ~ ( WINDOW ~ ^#comma_separated_list1(window_clause) )?
@@ -123,6 +130,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
             select_list,
             opt_from_block_second,
             opt_where_block,
+            opt_connect_by,
             opt_group_by_block,
             opt_having_block,
             opt_window_block,
@@ -144,6 +152,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
                     .map(|(_, table_refs)| table_refs)
                     .unwrap_or_default(),
                 selection: opt_where_block.map(|(_, selection)| selection),
+                connect_by: opt_connect_by,
                 group_by: opt_group_by_block.map(|(_, _, group_by)| group_by),
                 having: opt_having_block.map(|(_, having)| having),
                 window_list: opt_window_block.map(|(_, windows)| windows),
@@ -172,9 +181,38 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
     );
     let offset = map(
         rule! {
-            OFFSET ~ ^#expr
+            OFFSET ~ ^#expr ~ ( ROW | ROWS )?
+        },
+        |(_, offset, _)| SetOperationElement::Offset { offset },
+    );
+    let with_ties = alt((
+        value(false, rule! { ONLY }),
+        value(true, rule! { WITH ~ ^TIES }),
+    ));
+    let fetch = map(
+        rule! {
+            FETCH ~ ( FIRST | NEXT ) ~ #consumed(literal_number)? ~ PERCENT?
+            ~ ^( ROW | ROWS ) ~ ^#with_ties
+        },
+        |(_, _, opt_count, opt_percent, _, with_ties)| {
+            let limit = match opt_count {
+                Some((span, value)) => Expr::Literal {
+                    span: transform_span(span.tokens),
+                    value,
+                },
+                None => Expr::Literal {
+                    span: None,
+                    value: Literal::UInt64(1),
+                },
+            };
+            SetOperationElement::Fetch {
+                limit,
+                fetch: Fetch {
+                    percent: opt_percent.is_some(),
+                    with_ties,
+                },
+            }
         },
-        |(_, offset)| SetOperationElement::Offset { offset },
     );
     let ignore_result = map(
         rule! {
@@ -199,6 +237,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
             | #order_by
             | #limit
             | #offset
+            | #fetch
             | #ignore_result
         }),
         |(span, elem)| WithSpan { span, elem },
@@ -226,6 +265,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
             SetOperationElement::OrderBy { .. } => Affix::Postfix(Precedence(5)),
             SetOperationElement::Limit { .. } => Affix::Postfix(Precedence(5)),
             SetOperationElement::Offset { .. } => Affix::Postfix(Precedence(5)),
+            SetOperationElement::Fetch { .. } => Affix::Postfix(Precedence(5)),
             SetOperationElement::IgnoreResult => Affix::Postfix(Precedence(5)),
             _ => Affix::Nilfix,
         };
@@ -242,6 +282,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 select_list,
                 from,
                 selection,
+                connect_by,
                 group_by,
                 having,
                 window_list,
@@ -254,6 +295,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 select_list,
                 from,
                 selection,
+                connect_by,
                 group_by,
                 having,
                 window_list,
@@ -322,6 +364,9 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 if query.limit.is_empty() && limit.len() > 2 {
                     return Err("[LIMIT n OFFSET m] or [LIMIT n,m]");
                 }
+                if query.fetch.is_some() {
+                    return Err("LIMIT and FETCH cannot be used together");
+                }
                 if !query.limit.is_empty() {
                     return Err("duplicated LIMIT clause");
                 }
@@ -337,8 +382,21 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 if query.offset.is_some() {
                     return Err("duplicated OFFSET clause");
                 }
+                if query.fetch.is_some() {
+                    return Err("OFFSET must appear before FETCH");
+                }
                 query.offset = Some(offset);
             }
+            SetOperationElement::Fetch { limit, fetch } => {
+                if query.fetch.is_some() {
+                    return Err("duplicated FETCH clause");
+                }
+                if !query.limit.is_empty() {
+                    return Err("LIMIT and FETCH cannot be used together");
+                }
+                query.limit = vec![limit];
+                query.fetch = Some(fetch);
+            }
             SetOperationElement::IgnoreResult => {
                 query.ignore_result = true;
             }
@@ -521,8 +579,17 @@ pub fn travel_point(i: Input) -> IResult<TimeTravelPoint> {
         },
     );
 
+    let at_tag = map(
+        rule! { "(" ~ TAG ~ "=>" ~ #ident ~ ")" },
+        |(_, _, _, name, _)| TimeTravelPoint::Tag(name),
+    );
+    let at_branch = map(
+        rule! { "(" ~ BRANCH ~ "=>" ~ #ident ~ ")" },
+        |(_, _, _, name, _)| TimeTravelPoint::Branch(name),
+    );
+
     rule!(
-        #at_stream | #at_snapshot_or_ts
+        #at_stream | #at_tag | #at_branch | #at_snapshot_or_ts
     )(i)
 }
 
@@ -576,14 +643,22 @@ pub fn temporal_clause(i: Input) -> IResult<TemporalClause> {
 pub fn alias_name(i: Input) -> IResult<Identifier> {
     let short_alias = map(
         rule! {
-            #ident
+            // `START WITH`, `CONNECT BY`, `ASOF JOIN` and `MATCH_CONDITION` can directly
+            // follow the table references.
+            #not(rule! {
+                ( START ~ WITH )
+                | ( CONNECT ~ BY )
+                | ( ASOF ~ ( JOIN | LEFT ) )
+                | ( MATCH_CONDITION ~ "(" )
+            })
+            ~ #ident
             ~ #error_hint(
                 rule! { AS },
                 "an alias without `AS` keyword has already been defined before this one, \
                     please remove one of them"
             )
         },
-        |(ident, _)| ident,
+        |(_, ident, _)| ident,
     );
     let as_alias = map(
         rule! {
@@ -598,6 +673,41 @@ pub fn alias_name(i: Input) -> IResult<Identifier> {
     )(i)
 }
 
+fn start_with(i: Input) -> IResult<Expr> {
+    map(rule! { START ~ ^WITH ~ ^#expr }, |(_, _, expr)| expr)(i)
+}
+
+fn connect_by_condition(i: Input) -> IResult<(bool, Expr)> {
+    map(
+        rule! { CONNECT ~ ^BY ~ NOCYCLE? ~ ^#expr },
+        |(_, _, nocycle, condition)| (nocycle.is_some(), condition),
+    )(i)
+}
+
+pub fn connect_by(i: Input) -> IResult<ConnectBy> {
+    let start_with_first = map(
+        rule! { #start_with ~ ^#connect_by_condition },
+        |(start_with, (nocycle, condition))| ConnectBy {
+            start_with: Some(start_with),
+            nocycle,
+            condition,
+        },
+    );
+    let connect_by_first = map(
+        rule! { #connect_by_condition ~ #start_with? },
+        |((nocycle, condition), start_with)| ConnectBy {
+            start_with,
+            nocycle,
+            condition,
+        },
+    );
+
+    rule!(
+        #start_with_first
+        | #connect_by_first
+    )(i)
+}
+
 pub fn with_options(i: Input) -> IResult<WithOptions> {
     alt((
         map(rule! { WITH ~ CONSUME }, |_| WithOptions {
@@ -643,6 +753,10 @@ pub fn join_operator(i: Input) -> IResult<JoinOperator> {
         value(JoinOperator::RightOuter, rule! { RIGHT ~ OUTER? }),
         value(JoinOperator::FullOuter, rule! { FULL ~ OUTER? }),
         value(JoinOperator::CrossJoin, rule! { CROSS }),
+        map(rule! { ASOF ~ LEFT? }, |(_, opt_left)| JoinOperator::Asof {
+            left_outer: opt_left.is_some(),
+            match_condition: None,
+        }),
     ))(i)
 }
 
@@ -729,6 +843,13 @@ pub enum TableReferenceElement {
     },
     // ON expr | USING (ident, ...)
     JoinCondition(JoinCondition),
+    // MATCH_CONDITION (expr)
+    MatchCondition(Expr),
+    // MATCH_RECOGNIZE (...) [ AS alias ]
+    MatchRecognize {
+        match_recognize: Box<MatchRecognize>,
+        alias: Option<TableAlias>,
+    },
     Group(TableReference),
     Stage {
         location: FileLocation,
@@ -788,6 +909,21 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         },
         |(_, _, idents, _)| TableReferenceElement::JoinCondition(JoinCondition::Using(idents)),
     );
+    let match_condition = map(
+        rule! {
+            MATCH_CONDITION ~ "(" ~ #expr ~ ")"
+        },
+        |(_, _, expr, _)| TableReferenceElement::MatchCondition(expr),
+    );
+    let aliased_match_recognize = map(
+        rule! {
+            #match_recognize ~ #table_alias?
+        },
+        |(match_recognize, alias)| TableReferenceElement::MatchRecognize {
+            match_recognize: Box::new(match_recognize),
+            alias,
+        },
+    );
     let table_function = map(
         rule! {
             LATERAL? ~ #function_name ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias? ~ SAMPLE? ~ (BLOCK ~ "(" ~ #expr ~ ")")? ~ (ROW ~ "(" ~ #expr ~ ROWS? ~ ")")?
@@ -838,15 +974,19 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         },
     );
 
+    // `ASOF` and `MATCH_CONDITION` are not reserved, so the join elements must be tried
+    // before they could be taken as a table name or a table function.
     let (rest, (span, elem)) = consumed(rule! {
-        #aliased_stage
+        #join
+        | #match_condition
+        | #aliased_stage
         | #table_function
         | #aliased_table
         | #subquery
         | #group
-        | #join
         | #join_condition_on
         | #join_condition_using
+        | #aliased_match_recognize
     })(i)?;
     Ok((rest, WithSpan { span, elem }))
 }
@@ -879,6 +1019,145 @@ fn unpivot(i: Input) -> IResult<Unpivot> {
     )(i)
 }
 
+// MATCH_RECOGNIZE (
+//     [PARTITION BY expr, ...] [ORDER BY expr, ...] [MEASURES expr AS ident, ...]
+//     [ONE ROW PER MATCH | ALL ROWS PER MATCH] [AFTER MATCH SKIP ...]
+//     PATTERN (pattern) [DEFINE ident AS expr, ...]
+// )
+fn match_recognize(i: Input) -> IResult<MatchRecognize> {
+    let measure = map(rule! { #expr ~ AS ~ #ident }, |(expr, _, alias)| {
+        MatchMeasure { expr, alias }
+    });
+    let rows_per_match = alt((
+        value(RowsPerMatch::OneRow, rule! { ONE ~ ROW ~ PER ~ MATCH }),
+        value(RowsPerMatch::AllRows, rule! { ALL ~ ROWS ~ PER ~ MATCH }),
+    ));
+    let skip_to = alt((
+        value(AfterMatchSkip::PastLastRow, rule! { PAST ~ ^LAST ~ ^ROW }),
+        value(AfterMatchSkip::ToNextRow, rule! { TO ~ NEXT ~ ^ROW }),
+        map(rule! { TO ~ FIRST ~ #ident }, |(_, _, var)| {
+            AfterMatchSkip::ToFirst(var)
+        }),
+        map(rule! { TO ~ LAST? ~ #ident }, |(_, _, var)| {
+            AfterMatchSkip::ToLast(var)
+        }),
+    ));
+    let after_match_skip = map(
+        rule! { AFTER ~ ^MATCH ~ ^SKIP ~ ^#skip_to },
+        |(_, _, _, skip)| skip,
+    );
+    let define = map(rule! { #ident ~ ^AS ~ ^#expr }, |(name, _, condition)| {
+        PatternDefinition { name, condition }
+    });
+
+    map(
+        rule! {
+            MATCH_RECOGNIZE ~ ^"("
+            ~ ( PARTITION ~ ^BY ~ ^#comma_separated_list1(expr) )?
+            ~ ( ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) )?
+            ~ ( MEASURES ~ ^#comma_separated_list1(measure) )?
+            ~ #rows_per_match?
+            ~ #after_match_skip?
+            ~ ^PATTERN ~ ^"(" ~ ^#row_pattern ~ ^")"
+            ~ ( DEFINE ~ ^#comma_separated_list1(define) )?
+            ~ ^")"
+        },
+        |(
+            _,
+            _,
+            opt_partition,
+            opt_order,
+            opt_measures,
+            rows_per_match,
+            after_match_skip,
+            _,
+            _,
+            pattern,
+            _,
+            opt_define,
+            _,
+        )| MatchRecognize {
+            partition_by: opt_partition.map(|x| x.2).unwrap_or_default(),
+            order_by: opt_order.map(|x| x.2).unwrap_or_default(),
+            measures: opt_measures.map(|x| x.1).unwrap_or_default(),
+            rows_per_match: rows_per_match.unwrap_or(RowsPerMatch::OneRow),
+            after_match_skip: after_match_skip.unwrap_or(AfterMatchSkip::PastLastRow),
+            pattern,
+            define: opt_define.map(|x| x.1).unwrap_or_default(),
+        },
+    )(i)
+}
+
+// pattern | pattern | ...
+fn row_pattern(i: Input) -> IResult<RowPattern> {
+    map(
+        rule! { #row_pattern_concat ~ ( BitWiseOr ~ ^#row_pattern_concat )* },
+        |(first, rest)| {
+            if rest.is_empty() {
+                first
+            } else {
+                let mut patterns = vec![first];
+                patterns.extend(rest.into_iter().map(|(_, pattern)| pattern));
+                RowPattern::Alternation(patterns)
+            }
+        },
+    )(i)
+}
+
+// pattern pattern ...
+fn row_pattern_concat(i: Input) -> IResult<RowPattern> {
+    map(rule! { #row_pattern_quantified+ }, |mut patterns| {
+        if patterns.len() == 1 {
+            patterns.pop().unwrap()
+        } else {
+            RowPattern::Concat(patterns)
+        }
+    })(i)
+}
+
+// ident [quantifier] | (pattern) [quantifier]
+fn row_pattern_quantified(i: Input) -> IResult<RowPattern> {
+    let primary = alt((
+        map(rule! { #ident }, RowPattern::Symbol),
+        map(rule! { "(" ~ ^#row_pattern ~ ^")" }, |(_, pattern, _)| {
+            RowPattern::Group(Box::new(pattern))
+        }),
+    ));
+    let range = map_res(
+        rule! { "{" ~ #literal_u64? ~ ( "," ~ #literal_u64? )? ~ ^"}" },
+        |(_, min, max, _)| {
+            let min = min.unwrap_or(0);
+            let max = match max {
+                Some((_, max)) => max,
+                None => Some(min),
+            };
+            match max {
+                Some(max) if max < min || max == 0 => Err(nom::Err::Failure(ErrorKind::Other(
+                    "invalid range of pattern quantifier",
+                ))),
+                _ => Ok(RepetitionQuantifier::Range { min, max }),
+            }
+        },
+    );
+    let quantifier = alt((
+        value(RepetitionQuantifier::ZeroOrMore, rule! { "*" }),
+        value(RepetitionQuantifier::OneOrMore, rule! { "+" }),
+        value(RepetitionQuantifier::AtMostOne, rule! { "?" }),
+        range,
+    ));
+
+    map(
+        rule! { #primary ~ #quantifier? },
+        |(pattern, quantifier)| match quantifier {
+            Some(quantifier) => RowPattern::Repetition {
+                pattern: Box::new(pattern),
+                quantifier,
+            },
+            None => pattern,
+        },
+    )(i)
+}
+
 fn pivot_values(i: Input) -> IResult<PivotValues> {
     alt((
         map(comma_separated_list1(expr), PivotValues::ColumnValues),
@@ -918,6 +1197,8 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
         let affix = match &input.elem {
             TableReferenceElement::Join { .. } => Affix::Infix(Precedence(10), Associativity::Left),
             TableReferenceElement::JoinCondition(..) => Affix::Postfix(Precedence(5)),
+            TableReferenceElement::MatchCondition(..) => Affix::Postfix(Precedence(5)),
+            TableReferenceElement::MatchRecognize { .. } => Affix::Postfix(Precedence(20)),
             _ => Affix::Nilfix,
         };
         Ok(affix)
@@ -1067,6 +1348,39 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 },
                 _ => Err("join condition must apply to a join"),
             },
+            TableReferenceElement::MatchCondition(expr) => match &mut lhs {
+                TableReference::Join {
+                    join:
+                        Join {
+                            op:
+                                JoinOperator::Asof {
+                                    match_condition, ..
+                                },
+                            condition,
+                            ..
+                        },
+                    ..
+                } => {
+                    if *condition != JoinCondition::None {
+                        Err("MATCH_CONDITION must precede the join condition")
+                    } else if match_condition.is_some() {
+                        Err("match condition already set")
+                    } else {
+                        *match_condition = Some(Box::new(expr));
+                        Ok(lhs)
+                    }
+                }
+                _ => Err("MATCH_CONDITION must apply to an ASOF join"),
+            },
+            TableReferenceElement::MatchRecognize {
+                match_recognize,
+                alias,
+            } => Ok(TableReference::MatchRecognize {
+                span: transform_span(op.span.tokens),
+                table: Box::new(lhs),
+                match_recognize,
+                alias,
+            }),
             _ => unreachable!(),
         }
     }
@@ -1192,6 +1506,22 @@ pub fn window_function(i: Input) -> IResult<WindowDesc> {
     )(i)
 }
 
+/// The optional `WITHIN GROUP (ORDER BY ...)` and `FILTER (WHERE ...)` clauses of an aggregate function.
+pub fn aggregate_clauses(i: Input) -> IResult<(Vec<OrderByExpr>, Option<Box<Expr>>)> {
+    map(
+        rule! {
+            ( WITHIN ~ GROUP ~ ^"(" ~ ^ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) ~ ^")" )?
+            ~ ( FILTER ~ "(" ~ WHERE ~ ^#subexpr(0) ~ ^")" )?
+        },
+        |(opt_within_group, opt_filter)| {
+            (
+                opt_within_group.map(|x| x.5).unwrap_or_default(),
+                opt_filter.map(|x| Box::new(x.3)),
+            )
+        },
+    )(i)
+}
+
 pub fn window_clause(i: Input) -> IResult<WindowDefinition> {
     map(
         rule! {
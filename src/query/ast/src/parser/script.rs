@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use nom::branch::alt;
 use nom::combinator::consumed;
 use nom::combinator::map;
 use nom_rule::rule;
@@ -29,27 +30,59 @@ pub fn script_block(i: Input) -> IResult<ScriptBlock> {
             ( DECLARE ~ #semicolon_terminated_list1(declare_item) )?
             ~ BEGIN
             ~ #semicolon_terminated_list1(script_stmt)
+            ~ ( EXCEPTION ~ ^#exception_handler+ )?
             ~ END
             ~ ";"
         }),
-        |(span, (declares, _, body, _, _))| {
+        |(span, (declares, _, body, exception_handlers, _, _))| {
             let declares = declares.map(|(_, declare)| declare).unwrap_or_default();
+            let exception_handlers = exception_handlers
+                .map(|(_, handlers)| handlers)
+                .unwrap_or_default();
             ScriptBlock {
                 span: transform_span(span.tokens),
                 declares,
                 body,
+                exception_handlers,
             }
         },
     )(i)
 }
 
+pub fn exception_handler(i: Input) -> IResult<ExceptionHandler> {
+    let other = map(rule! { OTHER }, |_| None);
+    let named = map(
+        rule! {
+            #ident ~ ( OR ~ ^#ident )*
+        },
+        |(first, rest)| {
+            let mut exceptions = vec![first];
+            exceptions.extend(rest.into_iter().map(|(_, name)| name));
+            Some(exceptions)
+        },
+    );
+
+    map(
+        consumed(rule! {
+            WHEN ~ ^( #other | #named ) ~ ^THEN ~ ^#semicolon_terminated_list1(script_stmt)
+        }),
+        |(span, (_, exceptions, _, body))| ExceptionHandler {
+            span: transform_span(span.tokens),
+            exceptions,
+            body,
+        },
+    )(i)
+}
+
 pub fn declare_item(i: Input) -> IResult<DeclareItem> {
     let declare_var = map(declare_var, DeclareItem::Var);
     let declare_set = map(declare_set, DeclareItem::Set);
+    let declare_cursor = map(declare_cursor, DeclareItem::Cursor);
 
     rule!(
         #declare_var
         | #declare_set
+        | #declare_cursor
     )(i)
 }
 
@@ -79,6 +112,19 @@ pub fn declare_set(i: Input) -> IResult<DeclareSet> {
     )(i)
 }
 
+pub fn declare_cursor(i: Input) -> IResult<DeclareCursor> {
+    map(
+        consumed(rule! {
+            #ident ~ CURSOR ~ ^FOR ~ ^#statement_body
+        }),
+        |(span, (name, _, _, stmt))| DeclareCursor {
+            span: transform_span(span.tokens),
+            name,
+            stmt,
+        },
+    )(i)
+}
+
 pub fn script_stmts(i: Input) -> IResult<Vec<ScriptStatement>> {
     semicolon_terminated_list1(script_stmt)(i)
 }
@@ -96,6 +142,12 @@ pub fn script_stmt(i: Input) -> IResult<ScriptStatement> {
         },
         |(_, declare)| ScriptStatement::LetStatement { declare },
     );
+    let let_cursor_stmt = map(
+        rule! {
+            LET ~ #declare_cursor
+        },
+        |(_, declare)| ScriptStatement::LetCursor { declare },
+    );
     let run_stmt = map(
         consumed(rule! {
             #statement_body
@@ -298,25 +350,95 @@ pub fn script_stmt(i: Input) -> IResult<ScriptStatement> {
             }
         },
     );
+    let block_stmt = map(
+        consumed(rule! {
+            BEGIN ~ #semicolon_terminated_list1(script_stmt)
+            ~ ( EXCEPTION ~ ^#exception_handler+ )?
+            ~ ^END
+        }),
+        |(span, (_, body, exception_handlers, _))| ScriptStatement::Block {
+            span: transform_span(span.tokens),
+            body,
+            exception_handlers: exception_handlers
+                .map(|(_, handlers)| handlers)
+                .unwrap_or_default(),
+        },
+    );
+    let raise_stmt = map(
+        consumed(rule! {
+            RAISE ~ ( #ident ~ ( "(" ~ ^#expr ~ ^")" )? )?
+        }),
+        |(span, (_, exception))| {
+            let (exception, message) = match exception {
+                Some((exception, message)) => {
+                    (Some(exception), message.map(|(_, message, _)| message))
+                }
+                None => (None, None),
+            };
+            ScriptStatement::Raise {
+                span: transform_span(span.tokens),
+                exception,
+                message,
+            }
+        },
+    );
+    let open_stmt = map(
+        consumed(rule! {
+            OPEN ~ #ident
+        }),
+        |(span, (_, cursor))| ScriptStatement::Open {
+            span: transform_span(span.tokens),
+            cursor,
+        },
+    );
+    let fetch_stmt = map(
+        consumed(rule! {
+            FETCH ~ #ident ~ ^INTO ~ ^#comma_separated_list1(ident)
+        }),
+        |(span, (_, cursor, _, into))| ScriptStatement::Fetch {
+            span: transform_span(span.tokens),
+            cursor,
+            into,
+        },
+    );
+    let close_stmt = map(
+        consumed(rule! {
+            CLOSE ~ #ident
+        }),
+        |(span, (_, cursor))| ScriptStatement::Close {
+            span: transform_span(span.tokens),
+            cursor,
+        },
+    );
 
-    rule!(
-        #let_stmt_stmt
-        | #let_var_stmt
-        | #run_stmt
-        | #assign_stmt
-        | #return_set_stmt
-        | #return_stmt_stmt
-        | #return_var_stmt
-        | #return_stmt
-        | #for_loop_stmt
-        | #for_in_set_stmt
-        | #for_in_stmt_stmt
-        | #while_loop_stmt
-        | #repeat_loop_stmt
-        | #loop_stmt
-        | #break_stmt
-        | #continue_stmt
-        | #case_stmt
-        | #if_stmt
-    )(i)
+    alt((
+        rule!(
+            #let_stmt_stmt
+            | #let_var_stmt
+            | #let_cursor_stmt
+            | #block_stmt
+            | #run_stmt
+            | #assign_stmt
+            | #raise_stmt
+            | #open_stmt
+            | #fetch_stmt
+            | #close_stmt
+        ),
+        rule!(
+            #return_set_stmt
+            | #return_stmt_stmt
+            | #return_var_stmt
+            | #return_stmt
+            | #for_loop_stmt
+            | #for_in_set_stmt
+            | #for_in_stmt_stmt
+            | #while_loop_stmt
+            | #repeat_loop_stmt
+            | #loop_stmt
+            | #break_stmt
+            | #continue_stmt
+            | #case_stmt
+            | #if_stmt
+        ),
+    ))(i)
 }
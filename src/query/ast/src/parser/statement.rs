@@ -725,6 +725,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             ~ ( #engine )?
             ~ ( #uri_location )?
             ~ ( CLUSTER ~ ^BY ~ ( #cluster_type )? ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
+            ~ ( TTL ~ ^#expr )?
             ~ ( #table_option )?
             ~ ( AS ~ ^#query )?
         },
@@ -739,6 +740,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             engine,
             uri_location,
             opt_cluster_by,
+            opt_ttl,
             opt_table_options,
             opt_as_query,
         )| {
@@ -762,6 +764,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     cluster_type: typ.unwrap_or(ClusterType::Linear),
                     cluster_exprs: exprs,
                 }),
+                ttl: opt_ttl.map(|(_, ttl)| ttl),
                 table_options: opt_table_options.unwrap_or_default(),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
                 table_type,
@@ -1403,6 +1406,12 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             role_name,
         },
     );
+    let alter_role = map(
+        rule! {
+            ALTER ~ ROLE ~ #role_name ~ #alter_role_action
+        },
+        |(_, _, role_name, action)| Statement::AlterRole { role_name, action },
+    );
     let grant = map(
         rule! {
             GRANT ~ #grant_source ~ TO ~ #grant_option
@@ -1477,6 +1486,23 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             }))
         },
     );
+    let create_udaf = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ AGGREGATE ~ ^FUNCTION ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident ~ #udaf_definition
+            ~ ( DESC ~ ^"=" ~ ^#literal_string )?
+        },
+        |(_, opt_or_replace, _, _, opt_if_not_exists, udf_name, definition, opt_description)| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateUDF(CreateUDFStmt {
+                create_option,
+                udf_name,
+                description: opt_description.map(|(_, _, description)| description),
+                definition,
+            }))
+        },
+    );
     let drop_udf = map(
         rule! {
             DROP ~ FUNCTION ~ ( IF ~ ^EXISTS )? ~ #ident
@@ -1901,6 +1927,53 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         |(_, _, _, show_options)| Statement::ShowPasswordPolicies { show_options },
     );
 
+    let create_workload_group = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
+             ~ #workload_group_set_options
+        },
+        |(_, opt_or_replace, _, _, opt_if_not_exists, name, set_options)| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            let stmt = CreateWorkloadGroupStmt {
+                create_option,
+                name: name.to_string(),
+                set_options,
+            };
+            Ok(Statement::CreateWorkloadGroup(stmt))
+        },
+    );
+    let alter_workload_group = map(
+        rule! {
+            ALTER ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^EXISTS )? ~ ^#ident ~ SET
+             ~ #workload_group_set_options
+        },
+        |(_, _, _, opt_if_exists, name, _, set_options)| {
+            let stmt = AlterWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                set_options,
+            };
+            Statement::AlterWorkloadGroup(stmt)
+        },
+    );
+    let drop_workload_group = map(
+        rule! {
+            DROP ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^EXISTS )? ~ ^#ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            let stmt = DropWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            };
+            Statement::DropWorkloadGroup(stmt)
+        },
+    );
+    let show_workload_groups = value(
+        Statement::ShowWorkloadGroups,
+        rule! { SHOW ~ WORKLOAD ~ ^GROUPS },
+    );
+
     let create_pipe = map(
         rule! {
             CREATE ~ PIPE ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -2049,6 +2122,32 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         |(_, _, script)| Statement::ExecuteImmediate(ExecuteImmediateStmt { script }),
     );
 
+    let prepare = map(
+        rule! {
+            PREPARE ~ #ident ~ ^FROM ~ ^#literal_string
+        },
+        |(_, name, _, sql)| Statement::Prepare(PrepareStmt { name, sql }),
+    );
+
+    let execute = map(
+        rule! {
+            EXECUTE ~ #ident ~ ( USING ~ ^#comma_separated_list1(expr) )?
+        },
+        |(_, name, opt_params)| {
+            Statement::Execute(ExecuteStmt {
+                name,
+                params: opt_params.map(|(_, params)| params).unwrap_or_default(),
+            })
+        },
+    );
+
+    let deallocate = map(
+        rule! {
+            DEALLOCATE ~ PREPARE? ~ #ident
+        },
+        |(_, _, name)| Statement::Deallocate(DeallocateStmt { name }),
+    );
+
     let system_action = map(
         rule! {
             SYSTEM ~ #action
@@ -2099,13 +2198,27 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #procedure_args: "(<var_name> <type_name>, ...)")(i)
     }
 
+    fn procedure_language(i: Input) -> IResult<ProcedureLanguage> {
+        let sql = value(ProcedureLanguage::SQL, rule! { SQL });
+        let script = map_res(rule! { #ident }, |lang| {
+            match lang.name.to_lowercase().as_str() {
+                "python" => Ok(ProcedureLanguage::Python),
+                "javascript" => Ok(ProcedureLanguage::JavaScript),
+                _ => Err(nom::Err::Error(ErrorKind::Other(
+                    "procedure language must be one of SQL, PYTHON or JAVASCRIPT",
+                ))),
+            }
+        });
+        rule!(#sql | #script: "SQL | PYTHON | JAVASCRIPT")(i)
+    }
+
     // CREATE [ OR REPLACE ] PROCEDURE <name> ()
     // RETURNS { <result_data_type> }[ NOT NULL ]
-    // LANGUAGE SQL
+    // LANGUAGE { SQL | PYTHON | JAVASCRIPT }
     // [ COMMENT = '<string_literal>' ] AS <procedure_definition>
     let create_procedure = map_res(
         rule! {
-            CREATE ~ ( OR ~ ^REPLACE )? ~ PROCEDURE ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident ~ #procedure_arg ~ RETURNS ~ #procedure_return ~ LANGUAGE ~ SQL  ~ (COMMENT ~ "=" ~ #literal_string)? ~ AS ~ #code_string
+            CREATE ~ ( OR ~ ^REPLACE )? ~ PROCEDURE ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident ~ #procedure_arg ~ RETURNS ~ #procedure_return ~ LANGUAGE ~ ^#procedure_language ~ (COMMENT ~ "=" ~ #literal_string)? ~ AS ~ #code_string
         },
         |(
             _,
@@ -2117,7 +2230,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             _,
             return_type,
             _,
-            _,
+            language,
             opt_comment,
             _,
             script,
@@ -2141,7 +2254,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                 name,
                 args,
                 return_type,
-                language: ProcedureLanguage::SQL,
+                language,
                 comment: match opt_comment {
                     Some(opt) => Some(opt.2),
                     None => None,
@@ -2266,6 +2379,13 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #describe_password_policy: "`DESC PASSWORD POLICY name`"
             | #show_password_policies: "`SHOW PASSWORD POLICIES [<show_options>]`"
         ),
+        // workload group
+        rule!(
+            #create_workload_group: "`CREATE [OR REPLACE] WORKLOAD GROUP [IF NOT EXISTS] name [MAX_CONCURRENCY = <u64_literal>] [MEMORY_QUOTA = <u64_literal>] [CPU_WEIGHT = <u64_literal>] [QUEUE_TIMEOUT = <u64_literal>] [COMMENT = '<string_literal>']`"
+            | #alter_workload_group: "`ALTER WORKLOAD GROUP [IF EXISTS] name SET [MAX_CONCURRENCY = <u64_literal>] ... [COMMENT = '<string_literal>']`"
+            | #drop_workload_group: "`DROP WORKLOAD GROUP [IF EXISTS] name`"
+            | #show_workload_groups: "`SHOW WORKLOAD GROUPS`"
+        ),
         rule!(
             #conditional_multi_table_insert() : "`INSERT [OVERWRITE] {FIRST|ALL} { WHEN <condition> THEN intoClause [ ... ] } [ ... ] [ ELSE intoClause ] <subquery>`"
             | #unconditional_multi_table_insert() : "`INSERT [OVERWRITE] ALL intoClause [ ... ] <subquery>`"
@@ -2287,7 +2407,9 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #show_roles : "`SHOW ROLES`"
             | #create_role : "`CREATE ROLE [IF NOT EXISTS] <role_name>`"
             | #drop_role : "`DROP ROLE [IF EXISTS] <role_name>`"
+            | #alter_role : "`ALTER ROLE <role_name> (SET WORKLOAD_GROUP = '<group>' | UNSET WORKLOAD_GROUP)`"
             | #create_udf : "`CREATE [OR REPLACE] FUNCTION [IF NOT EXISTS] <name> {AS (<parameter>, ...) -> <definition expr> | (<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>} [DESC = <description>]`"
+            | #create_udaf : "`CREATE [OR REPLACE] AGGREGATE FUNCTION [IF NOT EXISTS] <name> (<arg_type>, ...) STATE { <field_name> <field_type>, ... } RETURNS <return_type> LANGUAGE <language> AS <language_codes> [DESC = <description>]`"
             | #drop_udf : "`DROP FUNCTION [IF EXISTS] <udf_name>`"
             | #alter_udf : "`ALTER FUNCTION <udf_name> (<parameter>, ...) -> <definition_expr> [DESC = <description>]`"
             | #set_role: "`SET [DEFAULT] ROLE <role>`"
@@ -2428,7 +2550,10 @@ AS
             | #desc_connection: "`DESC | DESCRIBE CONNECTION  <connection_name>`"
             | #show_connections: "`SHOW CONNECTIONS`"
             | #execute_immediate : "`EXECUTE IMMEDIATE $$ <script> $$`"
-            | #create_procedure : "`CREATE [ OR REPLACE ] PROCEDURE <procedure_name>() RETURNS { <result_data_type> [ NOT NULL ] | TABLE(<var_name> <data_type>, ...)} LANGUAGE SQL [ COMMENT = '<string_literal>' ] AS <procedure_definition>`"
+            | #prepare : "`PREPARE <name> FROM '<sql>'`"
+            | #execute : "`EXECUTE <name> [USING <expr>, ...]`"
+            | #deallocate : "`DEALLOCATE [PREPARE] <name>`"
+            | #create_procedure : "`CREATE [ OR REPLACE ] PROCEDURE <procedure_name>() RETURNS { <result_data_type> [ NOT NULL ] | TABLE(<var_name> <data_type>, ...)} LANGUAGE { SQL | PYTHON | JAVASCRIPT } [ COMMENT = '<string_literal>' ] AS <procedure_definition>`"
             | #drop_procedure : "`DROP PROCEDURE <procedure_name>()`"
             | #show_procedures : "`SHOW PROCEDURES [<show_options>]()`"
             | #describe_procedure : "`DESC PROCEDURE <procedure_name>()`"
@@ -2474,6 +2599,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
             rule! {
                 #with? ~ INSERT ~ #hint? ~ ( INTO | OVERWRITE ) ~ TABLE?
                 ~ #dot_separated_idents_1_to_3
+                ~ ( AT ~ "(" ~ BRANCH ~ "=>" ~ ^#ident ~ ")" )?
                 ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
                 ~ #insert_source_parser
             },
@@ -2484,6 +2610,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                 overwrite,
                 _,
                 (catalog, database, table),
+                opt_branch,
                 opt_columns,
                 source,
             )| {
@@ -2493,6 +2620,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                     catalog,
                     database,
                     table,
+                    branch: opt_branch.map(|(_, _, _, _, branch, _)| branch),
                     columns: opt_columns
                         .map(|(_, columns, _)| columns)
                         .unwrap_or_default(),
@@ -2844,6 +2972,13 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         ),
     ));
 
+    let codec = map(
+        rule! {
+            CODEC ~ ^"(" ~ ^#comma_separated_list1(column_codec) ~ ^")"
+        },
+        |(_, _, codec, _)| codec,
+    );
+
     let comment = map(
         rule! {
             COMMENT ~ #literal_string
@@ -2856,14 +2991,16 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             #ident
             ~ #type_name
             ~ ( #nullable | #expr )*
+            ~ ( #codec )?
             ~ ( #comment )?
-            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [COMMENT '<comment>']`"
+            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [CODEC(<codec>, ...)] [COMMENT '<comment>']`"
         },
-        |(name, data_type, constraints, comment)| {
+        |(name, data_type, constraints, codec, comment)| {
             let def = ColumnDefinition {
                 name,
                 data_type,
                 expr: None,
+                codec: codec.unwrap_or_default(),
                 comment,
             };
             (def, constraints)
@@ -2922,8 +3059,53 @@ pub fn inverted_index_def(i: Input) -> IResult<InvertedIndexDefinition> {
     )(i)
 }
 
+fn constraint_enforced(i: Input) -> IResult<bool> {
+    map(rule! { NOT? ~ ENFORCED }, |(opt_not, _)| opt_not.is_none())(i)
+}
+
+pub fn table_constraint_def(i: Input) -> IResult<TableConstraintDefinition> {
+    let primary_key = map(
+        rule! {
+            PRIMARY ~ KEY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")" ~ #constraint_enforced?
+        },
+        |(_, _, _, columns, _, opt_enforced)| TableConstraintKind::PrimaryKey {
+            columns,
+            enforced: opt_enforced.unwrap_or(true),
+        },
+    );
+    let unique = map(
+        rule! {
+            UNIQUE ~ "(" ~ ^#comma_separated_list1(ident) ~ ^")" ~ #constraint_enforced?
+        },
+        |(_, _, columns, _, opt_enforced)| TableConstraintKind::Unique {
+            columns,
+            enforced: opt_enforced.unwrap_or(true),
+        },
+    );
+    let check = map(
+        rule! {
+            CHECK ~ "(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, expr, _)| TableConstraintKind::Check { expr },
+    );
+
+    map(
+        rule! {
+            ( CONSTRAINT ~ #ident )? ~ ( #primary_key | #unique | #check )
+        },
+        |(opt_name, constraint)| TableConstraintDefinition {
+            name: opt_name.map(|(_, name)| name),
+            constraint,
+        },
+    )(i)
+}
+
 pub fn create_def(i: Input) -> IResult<CreateDefinition> {
     alt((
+        map(
+            rule! { #table_constraint_def },
+            CreateDefinition::Constraint,
+        ),
         map(rule! { #column_def }, CreateDefinition::Column),
         map(
             rule! { #inverted_index_def },
@@ -3252,6 +3434,7 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
         |(_, create_defs, _)| {
             let mut columns = Vec::with_capacity(create_defs.len());
             let mut inverted_indexes = Vec::new();
+            let mut constraints = Vec::new();
             for create_def in create_defs {
                 match create_def {
                     CreateDefinition::Column(column) => {
@@ -3260,6 +3443,9 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
                     CreateDefinition::InvertedIndex(inverted_index) => {
                         inverted_indexes.push(inverted_index);
                     }
+                    CreateDefinition::Constraint(constraint) => {
+                        constraints.push(constraint);
+                    }
                 }
             }
             let opt_inverted_indexes = if !inverted_indexes.is_empty() {
@@ -3267,7 +3453,7 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
             } else {
                 None
             };
-            CreateTableSource::Columns(columns, opt_inverted_indexes)
+            CreateTableSource::Columns(columns, opt_inverted_indexes, constraints)
         },
     );
     let like = map(
@@ -3281,9 +3467,22 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
         },
     );
 
+    let clone = map(
+        rule! {
+            CLONE ~ #dot_separated_idents_1_to_3 ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, (catalog, database, table), opt_travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: opt_travel_point.map(|(_, point)| point),
+        },
+    );
+
     rule!(
         #columns
         | #like
+        | #clone
     )(i)
 }
 
@@ -3300,6 +3499,18 @@ pub fn alter_database_action(i: Input) -> IResult<AlterDatabaseAction> {
     )(i)
 }
 
+pub fn column_codec(i: Input) -> IResult<ColumnCodec> {
+    map(
+        rule! {
+            #ident ~ ( "(" ~ ^#literal_u64 ~ ^")" )?
+        },
+        |(name, opt_level)| ColumnCodec {
+            name,
+            level: opt_level.map(|(_, level, _)| level),
+        },
+    )(i)
+}
+
 pub fn modify_column_type(i: Input) -> IResult<ColumnDefinition> {
     #[derive(Clone)]
     enum ColumnConstraint {
@@ -3325,19 +3536,28 @@ pub fn modify_column_type(i: Input) -> IResult<ColumnDefinition> {
         |(_, comment)| comment,
     );
 
+    let codec = map(
+        rule! {
+            CODEC ~ ^"(" ~ ^#comma_separated_list1(column_codec) ~ ^")"
+        },
+        |(_, _, codec, _)| codec,
+    );
+
     map_res(
         rule! {
             #ident
             ~ #type_name
             ~ ( #nullable | #expr )*
+            ~ ( #codec )?
             ~ ( #comment )?
-            : "`<column name> <type> [DEFAULT <expr>] [COMMENT '<comment>']`"
+            : "`<column name> <type> [DEFAULT <expr>] [CODEC(<codec>, ...)] [COMMENT '<comment>']`"
         },
-        |(name, data_type, constraints, comment)| {
+        |(name, data_type, constraints, codec, comment)| {
             let mut def = ColumnDefinition {
                 name,
                 data_type,
                 expr: None,
+                codec: codec.unwrap_or_default(),
                 comment,
             };
             for constraint in constraints {
@@ -3450,6 +3670,19 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, action)| AlterTableAction::ModifyColumn { action },
     );
 
+    let add_constraint = map(
+        rule! {
+            ADD ~ #table_constraint_def
+        },
+        |(_, constraint)| AlterTableAction::AddConstraint { constraint },
+    );
+    let drop_constraint = map(
+        rule! {
+            DROP ~ CONSTRAINT ~ #ident
+        },
+        |(_, _, name)| AlterTableAction::DropConstraint { name },
+    );
+
     let drop_column = map(
         rule! {
             DROP ~ COLUMN? ~ #ident
@@ -3486,6 +3719,16 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         },
     );
 
+    let warm_cache = map(
+        rule! {
+            WARM ~ CACHE ~ ( COLUMNS ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")" )? ~ ( WHERE ~ ^#expr )?
+        },
+        |(_, _, opt_columns, opt_selection)| AlterTableAction::WarmCache {
+            columns: opt_columns.map(|(_, _, columns, _)| columns),
+            selection: opt_selection.map(|(_, selection)| selection),
+        },
+    );
+
     let revert_table = map(
         rule! {
             FLASHBACK ~ TO ~ #travel_point
@@ -3507,22 +3750,52 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, targets)| AlterTableAction::UnsetOptions { targets },
     );
 
+    let create_table_ref = map(
+        rule! {
+            CREATE ~ #table_ref_type ~ #ident ~ ( AT ~ ^#travel_point )? ~ ( RETAIN ~ ^#literal_u64 ~ ^DAYS )?
+        },
+        |(_, ref_type, name, opt_travel_point, opt_retain)| AlterTableAction::CreateTableRef {
+            ref_type,
+            name,
+            travel_point: opt_travel_point.map(|(_, point)| point),
+            retain_days: opt_retain.map(|(_, days, _)| days),
+        },
+    );
+    let drop_table_ref = map(
+        rule! {
+            DROP ~ #table_ref_type ~ #ident
+        },
+        |(_, ref_type, name)| AlterTableAction::DropTableRef { ref_type, name },
+    );
+
     rule!(
         #alter_table_cluster_key
         | #drop_table_cluster_key
+        | #create_table_ref
+        | #drop_table_ref
         | #rename_table
         | #rename_column
         | #modify_table_comment
+        | #add_constraint
+        | #drop_constraint
         | #add_column
         | #drop_column
         | #modify_column
         | #recluster_table
+        | #warm_cache
         | #revert_table
         | #set_table_options
         | #unset_table_options
     )(i)
 }
 
+pub fn table_ref_type(i: Input) -> IResult<TableRefType> {
+    alt((
+        value(TableRefType::Tag, rule! { TAG }),
+        value(TableRefType::Branch, rule! { BRANCH }),
+    ))(i)
+}
+
 pub fn match_clause(i: Input) -> IResult<MergeOption> {
     map(
         rule! {
@@ -3631,6 +3904,7 @@ pub fn add_column_option(i: Input) -> IResult<AddColumnOption> {
 pub fn optimize_table_action(i: Input) -> IResult<OptimizeTableAction> {
     alt((
         value(OptimizeTableAction::All, rule! { ALL }),
+        value(OptimizeTableAction::Expire, rule! { EXPIRE }),
         map(
             rule! { PURGE ~ (BEFORE ~ ^#travel_point)? },
             |(_, opt_travel_point)| OptimizeTableAction::Purge {
@@ -4005,6 +4279,7 @@ pub fn engine(i: Input) -> IResult<Engine> {
         value(Engine::Random, rule! { RANDOM }),
         value(Engine::Iceberg, rule! { ICEBERG }),
         value(Engine::Delta, rule! { DELTA }),
+        value(Engine::Kafka, rule! { KAFKA }),
     ));
 
     map(
@@ -4088,6 +4363,16 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         },
         |(_, _, val)| UserOptionItem::MustChangePassword(val),
     );
+    let set_workload_group = map(
+        rule! {
+            SET ~ WORKLOAD_GROUP ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, group)| UserOptionItem::SetWorkloadGroup(group),
+    );
+    let unset_workload_group = value(
+        UserOptionItem::UnsetWorkloadGroup,
+        rule! { UNSET ~ WORKLOAD_GROUP },
+    );
 
     rule!(
         #tenant_setting
@@ -4099,6 +4384,8 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         | #unset_password_policy
         | #set_disabled_option
         | #must_change_password
+        | #set_workload_group
+        | #unset_workload_group
     )(i)
 }
 
@@ -4265,13 +4552,74 @@ pub fn udf_definition(i: Input) -> IResult<UDFDefinition> {
         },
     );
 
+    let udtf_sql = map(
+        rule! {
+            "(" ~ #comma_separated_list0(udtf_column) ~ ")"
+            ~ RETURNS ~ TABLE ~ "(" ~ ^#comma_separated_list1(udtf_column) ~ ^")"
+            ~ AS ~ ^#code_string
+        },
+        |(_, arg_types, _, _, _, _, return_types, _, _, sql)| UDFDefinition::UDTFSql {
+            arg_types,
+            return_types,
+            sql,
+        },
+    );
+
+    let udtf_script = map(
+        rule! {
+            "(" ~ #comma_separated_list0(udf_arg_type) ~ ")"
+            ~ RETURNS ~ TABLE ~ "(" ~ ^#comma_separated_list1(udtf_column) ~ ^")"
+            ~ LANGUAGE ~ #ident
+            ~ HANDLER ~ ^"=" ~ ^#literal_string
+            ~ AS ~ ^(#code_string | #literal_string)
+        },
+        |(_, arg_types, _, _, _, _, return_types, _, _, language, _, _, handler, _, code)| {
+            UDFDefinition::UDTFScript {
+                arg_types,
+                return_types,
+                code,
+                handler,
+                language: language.to_string(),
+                runtime_version: "".to_string(),
+            }
+        },
+    );
+
     rule!(
         #udf_server: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>"
         | #lambda_udf: "AS (<parameter>, ...) -> <definition expr>"
         | #udf_script: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> AS <language_codes>"
+        | #udtf_sql: "(<arg_name> <arg_type>, ...) RETURNS TABLE (<column_name> <column_type>, ...) AS <query>"
+        | #udtf_script: "(<arg_type>, ...) RETURNS TABLE (<column_name> <column_type>, ...) LANGUAGE <language> HANDLER=<handler> AS <language_codes>"
     )(i)
 }
 
+pub fn udaf_definition(i: Input) -> IResult<UDFDefinition> {
+    map(
+        rule! {
+            "(" ~ #comma_separated_list0(udf_arg_type) ~ ")"
+            ~ STATE ~ ^"{" ~ ^#comma_separated_list1(udtf_column) ~ ^"}"
+            ~ RETURNS ~ ^#udf_arg_type
+            ~ LANGUAGE ~ ^#ident
+            ~ AS ~ ^(#code_string | #literal_string)
+        },
+        |(_, arg_types, _, _, _, state_types, _, _, return_type, _, language, _, code)| {
+            UDFDefinition::UDAFScript {
+                arg_types,
+                state_types,
+                return_type,
+                code,
+                language: language.to_string(),
+                runtime_version: "".to_string(),
+            }
+        },
+    )(i)
+}
+
+fn udtf_column(i: Input) -> IResult<(Identifier, TypeName)> {
+    map(rule! { #ident ~ #udf_arg_type }, |(name, ty)| (name, ty))(i)
+}
+
 pub fn merge_update_expr(i: Input) -> IResult<MergeUpdateExpr> {
     map(
         rule! { #dot_separated_idents_1_to_2 ~ "=" ~ ^#expr },
@@ -4327,6 +4675,51 @@ pub fn password_set_options(i: Input) -> IResult<PasswordSetOptions> {
     )(i)
 }
 
+pub fn workload_group_set_options(i: Input) -> IResult<WorkloadGroupSetOptions> {
+    map(
+        rule! {
+             ( MAX_CONCURRENCY ~ Eq ~ ^#literal_u64 )?
+             ~ ( MEMORY_QUOTA ~ Eq ~ ^#literal_u64 )?
+             ~ ( CPU_WEIGHT ~ Eq ~ ^#literal_u64 )?
+             ~ ( QUEUE_TIMEOUT ~ Eq ~ ^#literal_u64 )?
+             ~ ( COMMENT ~ Eq ~ ^#literal_string)?
+        },
+        |(
+            opt_max_concurrency,
+            opt_memory_quota,
+            opt_cpu_weight,
+            opt_queue_timeout,
+            opt_comment,
+        )| {
+            WorkloadGroupSetOptions {
+                max_concurrency: opt_max_concurrency.map(|opt| opt.2),
+                memory_quota: opt_memory_quota.map(|opt| opt.2),
+                cpu_weight: opt_cpu_weight.map(|opt| opt.2),
+                queue_timeout: opt_queue_timeout.map(|opt| opt.2),
+                comment: opt_comment.map(|opt| opt.2),
+            }
+        },
+    )(i)
+}
+
+pub fn alter_role_action(i: Input) -> IResult<AlterRoleAction> {
+    let set_workload_group = map(
+        rule! {
+            SET ~ WORKLOAD_GROUP ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, group)| AlterRoleAction::SetWorkloadGroup(group),
+    );
+    let unset_workload_group = value(
+        AlterRoleAction::UnsetWorkloadGroup,
+        rule! { UNSET ~ WORKLOAD_GROUP },
+    );
+
+    rule!(
+        #set_workload_group
+        | #unset_workload_group
+    )(i)
+}
+
 pub fn password_unset_options(i: Input) -> IResult<PasswordUnSetOptions> {
     map(
         rule! {
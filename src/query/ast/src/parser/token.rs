@@ -345,6 +345,8 @@ pub enum TokenKind {
     ADD,
     #[token("AFTER", ignore(ascii_case))]
     AFTER,
+    #[token("AGGREGATE", ignore(ascii_case))]
+    AGGREGATE,
     #[token("AGGREGATING", ignore(ascii_case))]
     AGGREGATING,
     #[token("ANY", ignore(ascii_case))]
@@ -375,6 +377,8 @@ pub enum TokenKind {
     AT,
     #[token("ASC", ignore(ascii_case))]
     ASC,
+    #[token("ASOF", ignore(ascii_case))]
+    ASOF,
     #[token("ANTI", ignore(ascii_case))]
     ANTI,
     #[token("ASYNC", ignore(ascii_case))]
@@ -411,6 +415,8 @@ pub enum TokenKind {
     BOOLEAN,
     #[token("BOTH", ignore(ascii_case))]
     BOTH,
+    #[token("BRANCH", ignore(ascii_case))]
+    BRANCH,
     #[token("BY", ignore(ascii_case))]
     BY,
     #[token("BROTLI", ignore(ascii_case))]
@@ -419,6 +425,8 @@ pub enum TokenKind {
     BZ2,
     #[token("BLOCK", ignore(ascii_case))]
     BLOCK,
+    #[token("CACHE", ignore(ascii_case))]
+    CACHE,
     #[token("CALL", ignore(ascii_case))]
     CALL,
     #[token("CASE", ignore(ascii_case))]
@@ -433,18 +441,32 @@ pub enum TokenKind {
     CENTURY,
     #[token("CHANGES", ignore(ascii_case))]
     CHANGES,
+    #[token("CHECK", ignore(ascii_case))]
+    CHECK,
+    #[token("CLONE", ignore(ascii_case))]
+    CLONE,
+    #[token("CLOSE", ignore(ascii_case))]
+    CLOSE,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
+    #[token("CODEC", ignore(ascii_case))]
+    CODEC,
     #[token("COMMENT", ignore(ascii_case))]
     COMMENT,
     #[token("COMMENTS", ignore(ascii_case))]
     COMMENTS,
     #[token("COMPACT", ignore(ascii_case))]
     COMPACT,
+    #[token("CONNECT", ignore(ascii_case))]
+    CONNECT,
     #[token("CONNECTION", ignore(ascii_case))]
     CONNECTION,
     #[token("CONNECTIONS", ignore(ascii_case))]
     CONNECTIONS,
+    #[token("CONNECT_BY_ROOT", ignore(ascii_case))]
+    CONNECT_BY_ROOT,
+    #[token("CONSTRAINT", ignore(ascii_case))]
+    CONSTRAINT,
     #[token("CONSUME", ignore(ascii_case))]
     CONSUME,
     #[token("CONTENT_TYPE", ignore(ascii_case))]
@@ -469,6 +491,8 @@ pub enum TokenKind {
     COPY,
     #[token("COUNT", ignore(ascii_case))]
     COUNT,
+    #[token("CPU_WEIGHT", ignore(ascii_case))]
+    CPU_WEIGHT,
     #[token("CREDENTIAL", ignore(ascii_case))]
     CREDENTIAL,
     #[token("CREATE", ignore(ascii_case))]
@@ -481,6 +505,8 @@ pub enum TokenKind {
     CURRENT,
     #[token("CURRENT_TIMESTAMP", ignore(ascii_case))]
     CURRENT_TIMESTAMP,
+    #[token("CURSOR", ignore(ascii_case))]
+    CURSOR,
     #[token("DATABASE", ignore(ascii_case))]
     DATABASE,
     #[token("DATABASES", ignore(ascii_case))]
@@ -503,6 +529,8 @@ pub enum TokenKind {
     DATETIME,
     #[token("DAY", ignore(ascii_case))]
     DAY,
+    #[token("DEALLOCATE", ignore(ascii_case))]
+    DEALLOCATE,
     #[token("DECADE", ignore(ascii_case))]
     DECADE,
     #[token("DECIMAL", ignore(ascii_case))]
@@ -513,6 +541,8 @@ pub enum TokenKind {
     DEFAULT,
     #[token("DEFLATE", ignore(ascii_case))]
     DEFLATE,
+    #[token("DEFINE", ignore(ascii_case))]
+    DEFINE,
     #[token("DELETE", ignore(ascii_case))]
     DELETE,
     #[token("DESC", ignore(ascii_case))]
@@ -559,6 +589,8 @@ pub enum TokenKind {
     DYNAMIC,
     #[token("EXCEPT", ignore(ascii_case))]
     EXCEPT,
+    #[token("EXCEPTION", ignore(ascii_case))]
+    EXCEPTION,
     #[token("EXCLUDE", ignore(ascii_case))]
     EXCLUDE,
     #[token("ELSE", ignore(ascii_case))]
@@ -573,6 +605,8 @@ pub enum TokenKind {
     END,
     #[token("ENDPOINT", ignore(ascii_case))]
     ENDPOINT,
+    #[token("ENFORCED", ignore(ascii_case))]
+    ENFORCED,
     #[token("ENGINE", ignore(ascii_case))]
     ENGINE,
     #[token("ENGINES", ignore(ascii_case))]
@@ -597,6 +631,8 @@ pub enum TokenKind {
     ELSEIF,
     #[token("FALSE", ignore(ascii_case))]
     FALSE,
+    #[token("FETCH", ignore(ascii_case))]
+    FETCH,
     #[token("FIELDS", ignore(ascii_case))]
     FIELDS,
     #[token("FIELD_DELIMITER", ignore(ascii_case))]
@@ -613,6 +649,8 @@ pub enum TokenKind {
     FILE,
     #[token("FILES", ignore(ascii_case))]
     FILES,
+    #[token("FILTER", ignore(ascii_case))]
+    FILTER,
     #[token("FINAL", ignore(ascii_case))]
     FINAL,
     #[token("FLASHBACK", ignore(ascii_case))]
@@ -665,6 +703,8 @@ pub enum TokenKind {
     GRAPH,
     #[token("GROUP", ignore(ascii_case))]
     GROUP,
+    #[token("GROUPS", ignore(ascii_case))]
+    GROUPS,
     #[token("GZIP", ignore(ascii_case))]
     GZIP,
     #[token("HAVING", ignore(ascii_case))]
@@ -745,6 +785,8 @@ pub enum TokenKind {
     JULIAN,
     #[token("JWT", ignore(ascii_case))]
     JWT,
+    #[token("KAFKA", ignore(ascii_case))]
+    KAFKA,
     #[token("KEY", ignore(ascii_case))]
     KEY,
     #[token("KILL", ignore(ascii_case))]
@@ -790,14 +832,20 @@ pub enum TokenKind {
     MAP,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
     MAX_FILE_SIZE,
+    #[token("MAX_CONCURRENCY", ignore(ascii_case))]
+    MAX_CONCURRENCY,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
+    #[token("MEASURES", ignore(ascii_case))]
+    MEASURES,
     #[token("MEDIUM", ignore(ascii_case))]
     MEDIUM,
     #[token("MEMO", ignore(ascii_case))]
     MEMO,
     #[token("MEMORY", ignore(ascii_case))]
     MEMORY,
+    #[token("MEMORY_QUOTA", ignore(ascii_case))]
+    MEMORY_QUOTA,
     #[token("METRICS", ignore(ascii_case))]
     METRICS,
     #[token("MICROSECONDS", ignore(ascii_case))]
@@ -822,10 +870,14 @@ pub enum TokenKind {
     NATURAL,
     #[token("NETWORK", ignore(ascii_case))]
     NETWORK,
+    #[token("NEXT", ignore(ascii_case))]
+    NEXT,
     #[token("DISABLED", ignore(ascii_case))]
     DISABLED,
     #[token("NDJSON", ignore(ascii_case))]
     NDJSON,
+    #[token("NOCYCLE", ignore(ascii_case))]
+    NOCYCLE,
     #[token("NO_PASSWORD", ignore(ascii_case))]
     NO_PASSWORD,
     #[token("NONE", ignore(ascii_case))]
@@ -848,10 +900,16 @@ pub enum TokenKind {
     OFFSET,
     #[token("ON", ignore(ascii_case))]
     ON,
+    #[token("ONE", ignore(ascii_case))]
+    ONE,
+    #[token("ONLY", ignore(ascii_case))]
+    ONLY,
     #[token("ON_CREATE", ignore(ascii_case))]
     ON_CREATE,
     #[token("ON_SCHEDULE", ignore(ascii_case))]
     ON_SCHEDULE,
+    #[token("OPEN", ignore(ascii_case))]
+    OPEN,
     #[token("OPTIMIZE", ignore(ascii_case))]
     OPTIMIZE,
     #[token("OPTIONS", ignore(ascii_case))]
@@ -862,6 +920,8 @@ pub enum TokenKind {
     ORC,
     #[token("ORDER", ignore(ascii_case))]
     ORDER,
+    #[token("OTHER", ignore(ascii_case))]
+    OTHER,
     #[token("OUTPUT_HEADER", ignore(ascii_case))]
     OUTPUT_HEADER,
     #[token("OUTER", ignore(ascii_case))]
@@ -900,8 +960,14 @@ pub enum TokenKind {
     PASSWORD_LOCKOUT_TIME_MINS,
     #[token("PASSWORD_HISTORY", ignore(ascii_case))]
     PASSWORD_HISTORY,
+    #[token("PAST", ignore(ascii_case))]
+    PAST,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
+    #[token("PER", ignore(ascii_case))]
+    PER,
+    #[token("PERCENT", ignore(ascii_case))]
+    PERCENT,
     #[token("PIPELINE", ignore(ascii_case))]
     PIPELINE,
     #[token("PLAINTEXT_PASSWORD", ignore(ascii_case))]
@@ -926,8 +992,12 @@ pub enum TokenKind {
     QUARTER,
     #[token("QUERY", ignore(ascii_case))]
     QUERY,
+    #[token("QUEUE_TIMEOUT", ignore(ascii_case))]
+    QUEUE_TIMEOUT,
     #[token("QUOTE", ignore(ascii_case))]
     QUOTE,
+    #[token("RAISE", ignore(ascii_case))]
+    RAISE,
     #[token("RANGE", ignore(ascii_case))]
     RANGE,
     #[token("RAWDEFLATE", ignore(ascii_case))]
@@ -958,6 +1028,12 @@ pub enum TokenKind {
     MERGE,
     #[token("MATCHED", ignore(ascii_case))]
     MATCHED,
+    #[token("MATCH", ignore(ascii_case))]
+    MATCH,
+    #[token("MATCH_CONDITION", ignore(ascii_case))]
+    MATCH_CONDITION,
+    #[token("MATCH_RECOGNIZE", ignore(ascii_case))]
+    MATCH_RECOGNIZE,
     #[token("MISSING_FIELD_AS", ignore(ascii_case))]
     MISSING_FIELD_AS,
     #[token("NULL_FIELD_AS", ignore(ascii_case))]
@@ -980,8 +1056,12 @@ pub enum TokenKind {
     PRECEDING,
     #[token("PRECISION", ignore(ascii_case))]
     PRECISION,
+    #[token("PREPARE", ignore(ascii_case))]
+    PREPARE,
     #[token("PRESIGN", ignore(ascii_case))]
     PRESIGN,
+    #[token("PRIOR", ignore(ascii_case))]
+    PRIOR,
     #[token("PRIVILEGES", ignore(ascii_case))]
     PRIVILEGES,
     #[token("QUALIFY", ignore(ascii_case))]
@@ -1042,6 +1122,10 @@ pub enum TokenKind {
     VARIABLES,
     #[token("STAGES", ignore(ascii_case))]
     STAGES,
+    #[token("START", ignore(ascii_case))]
+    START,
+    #[token("STATE", ignore(ascii_case))]
+    STATE,
     #[token("STATISTIC", ignore(ascii_case))]
     STATISTIC,
     #[token("SUMMARY", ignore(ascii_case))]
@@ -1062,6 +1146,8 @@ pub enum TokenKind {
     MAX_FILES,
     #[token("SKIP_HEADER", ignore(ascii_case))]
     SKIP_HEADER,
+    #[token("SKIP", ignore(ascii_case))]
+    SKIP,
     #[token("SMALLINT", ignore(ascii_case))]
     SMALLINT,
     #[token("SNAPPY", ignore(ascii_case))]
@@ -1118,6 +1204,8 @@ pub enum TokenKind {
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TAG", ignore(ascii_case))]
+    TAG,
     #[token("TARGET_LAG", ignore(ascii_case))]
     TARGET_LAG,
     #[token("TEXT", ignore(ascii_case))]
@@ -1136,6 +1224,8 @@ pub enum TokenKind {
     TENANT,
     #[token("THEN", ignore(ascii_case))]
     THEN,
+    #[token("TIES", ignore(ascii_case))]
+    TIES,
     #[token("TIMESTAMP", ignore(ascii_case))]
     TIMESTAMP,
     #[token("TIMEZONE_HOUR", ignore(ascii_case))]
@@ -1164,6 +1254,8 @@ pub enum TokenKind {
     TRY_CAST,
     #[token("TSV", ignore(ascii_case))]
     TSV,
+    #[token("TTL", ignore(ascii_case))]
+    TTL,
     #[token("TUPLE", ignore(ascii_case))]
     TUPLE,
     #[token("TYPE", ignore(ascii_case))]
@@ -1182,6 +1274,8 @@ pub enum TokenKind {
     UINT8,
     #[token("UNDROP", ignore(ascii_case))]
     UNDROP,
+    #[token("UNIQUE", ignore(ascii_case))]
+    UNIQUE,
     #[token("UNSIGNED", ignore(ascii_case))]
     UNSIGNED,
     #[token("URL", ignore(ascii_case))]
@@ -1232,6 +1326,12 @@ pub enum TokenKind {
     WINDOW,
     #[token("WITH", ignore(ascii_case))]
     WITH,
+    #[token("WITHIN", ignore(ascii_case))]
+    WITHIN,
+    #[token("WORKLOAD", ignore(ascii_case))]
+    WORKLOAD,
+    #[token("WORKLOAD_GROUP", ignore(ascii_case))]
+    WORKLOAD_GROUP,
     #[token("XML", ignore(ascii_case))]
     XML,
     #[token("XOR", ignore(ascii_case))]
@@ -1290,6 +1390,8 @@ pub enum TokenKind {
     TOP,
     #[token("WAREHOUSE", ignore(ascii_case))]
     WAREHOUSE,
+    #[token("WARM", ignore(ascii_case))]
+    WARM,
     #[token("SCHEDULE", ignore(ascii_case))]
     SCHEDULE,
     #[token("SUSPEND_TASK_AFTER_NUM_FAILURES", ignore(ascii_case))]
@@ -1620,6 +1722,7 @@ impl TokenKind {
             // | TokenKind::CONCURRENTLY
             // | TokenKind::CONSTRAINT
             | TokenKind::CONNECTION
+            | TokenKind::CONNECT_BY_ROOT
             | TokenKind::CROSS
             // | TokenKind::CURRENT_CATALOG
             // | TokenKind::CURRENT_DATE
@@ -1650,6 +1753,7 @@ impl TokenKind {
             | TokenKind::LEADING
             | TokenKind::LEFT
             | TokenKind::LIKE
+            | TokenKind::MATCH_RECOGNIZE
             // | TokenKind::LOCALTIME
             // | TokenKind::LOCALTIMESTAMP
             | TokenKind::NATURAL
@@ -1659,6 +1763,7 @@ impl TokenKind {
             | TokenKind::OR
             // | TokenKind::PLACING
             // | TokenKind::PRIMARY
+            | TokenKind::PRIOR
             // | TokenKind::REFERENCES
             | TokenKind::RIGHT
             | TokenKind::SELECT
@@ -1687,7 +1792,7 @@ impl TokenKind {
             | TokenKind::CREATE
             | TokenKind::ATTACH
             | TokenKind::EXCEPT
-            // | TokenKind::FETCH
+            | TokenKind::FETCH
             | TokenKind::FROM
             | TokenKind::GRANT
             | TokenKind::GROUP
@@ -117,6 +117,9 @@ fn test_statement() {
         r#"CREATE OR REPLACE INVERTED INDEX idx2 ON t1 (a, b);"#,
         r#"create table a (c decimal(38, 0))"#,
         r#"create table a (c decimal(38))"#,
+        r#"create table a (c int codec(delta, zstd(3)))"#,
+        r#"create table a (c int, d string, constraint pk primary key (c), unique (d) not enforced)"#,
+        r#"create table a (c int, constraint c_pos check (c > 0))"#,
         r#"create or replace table a (c decimal(38))"#,
         r#"create or replace table a (c int(10) unsigned)"#,
         r#"create table if not exists a.b (c integer not null default 1, b varchar);"#,
@@ -128,6 +131,8 @@ fn test_statement() {
         r#"create table if not exists a.b (a string, b string, inverted index idx1 (a,b) tokenizer='chinese');"#,
         r#"create table a.b like c.d;"#,
         r#"create table t like t2 engine = memory;"#,
+        r#"create table t2 clone db.t1;"#,
+        r#"create or replace table t2 clone t1 at (snapshot => '9828b23f74664ff3806f44bbc1925ea5');"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900');"#,
         r#"
             create table if not exists a.b (a int) 's3://testbucket/admin/data/'
@@ -258,16 +263,20 @@ fn test_statement() {
         r#"OPTIMIZE TABLE t COMPACT LIMIT 10;"#,
         r#"OPTIMIZE TABLE t PURGE BEFORE (SNAPSHOT => '9828b23f74664ff3806f44bbc1925ea5') LIMIT 10;"#,
         r#"OPTIMIZE TABLE t PURGE BEFORE (TIMESTAMP => '2023-06-26 09:49:02.038483'::TIMESTAMP) LIMIT 10;"#,
+        r#"OPTIMIZE TABLE t EXPIRE;"#,
         r#"ALTER TABLE t CLUSTER BY(c1);"#,
         r#"ALTER TABLE t COMMENT='t1-commnet';"#,
         r#"ALTER TABLE t DROP CLUSTER KEY;"#,
         r#"ALTER TABLE t RECLUSTER FINAL WHERE c1 > 0 LIMIT 10;"#,
+        r#"ALTER TABLE t WARM CACHE COLUMNS (a, b) WHERE c1 > 0;"#,
         r#"ALTER TABLE t ADD c int null;"#,
         r#"ALTER TABLE t ADD COLUMN c int null;"#,
         r#"ALTER TABLE t ADD COLUMN a float default 1.1 COMMENT 'hello' FIRST;"#,
         r#"ALTER TABLE t ADD COLUMN b string default 'b' AFTER a;"#,
         r#"ALTER TABLE t RENAME COLUMN a TO b;"#,
         r#"ALTER TABLE t DROP COLUMN b;"#,
+        r#"ALTER TABLE t ADD CONSTRAINT c_pos CHECK (b > 0);"#,
+        r#"ALTER TABLE t DROP CONSTRAINT c_pos;"#,
         r#"ALTER TABLE t DROP b;"#,
         r#"ALTER TABLE t MODIFY COLUMN b SET MASKING POLICY mask;"#,
         r#"ALTER TABLE t MODIFY COLUMN b UNSET MASKING POLICY;"#,
@@ -786,6 +795,51 @@ fn test_statement() {
             handler = 'addone_py'
             as '@data/abc/a.py';
         "#,
+        r#"
+            create or replace function add_rows(a int)
+            returns table (x int, y string)
+            as
+            $$
+            select a + number as x, to_string(number) as y from numbers(3)
+            $$;
+        "#,
+        r#"
+            create function split_words(string)
+            returns table (word string, pos int)
+            language javascript
+            handler = 'split_words'
+            as
+            $$
+            export function split_words(s) {
+                return s.split(' ').map((w, i) => [w, i]);
+            }
+            $$;
+        "#,
+        r#"
+            create aggregate function weighted_avg (int, int)
+            state { sum int, weight int }
+            returns float
+            language javascript
+            as
+            $$
+            export function create_state() {
+                return {sum: 0, weight: 0};
+            }
+            export function accumulate(state, value, weight) {
+                state.sum += value * weight;
+                state.weight += weight;
+                return state;
+            }
+            export function merge(state1, state2) {
+                state1.sum += state2.sum;
+                state1.weight += state2.weight;
+                return state1;
+            }
+            export function finish(state) {
+                return state.sum / state.weight;
+            }
+            $$;
+        "#,
         r#"DROP FUNCTION binary_reverse;"#,
         r#"DROP FUNCTION isnotempty;"#,
         r#"
@@ -798,6 +852,9 @@ fn test_statement() {
             END;
             $$
         "#,
+        r#"PREPARE s FROM 'select * from t where id = ? and name = ?';"#,
+        r#"EXECUTE s USING 1, 'abc';"#,
+        r#"DEALLOCATE PREPARE s;"#,
         r#"
             with
             abc as (
@@ -873,6 +930,15 @@ fn test_statement() {
                 RETURN sum;
             END;
             $$;"#,
+        r#"create PROCEDURE p1(n int) returns string language python as $$
+            total = 0
+            for row in session.sql(f"SELECT number FROM numbers({n})"):
+                total += row["number"]
+            return total
+            $$;"#,
+        r#"create or replace PROCEDURE p1() returns table(a int) language javascript comment = 'test' as $$
+            return session.sql("SELECT 1 AS a");
+            $$;"#,
     ];
 
     for case in cases {
@@ -973,6 +1039,8 @@ fn test_statement_error() {
         r#"REVOKE OWNERSHIP ON d20_0014.* FROM ROLE A;"#,
         r#"GRANT OWNERSHIP ON *.* TO ROLE 'd20_0015_owner';"#,
         r#"CREATE FUNCTION IF NOT EXISTS isnotempty AS(p) -> not(is_null(p)"#,
+        r#"CREATE FUNCTION add_rows(a int) RETURNS TABLE () AS $$ select a $$"#,
+        r#"CREATE AGGREGATE FUNCTION my_sum(int) STATE {} RETURNS int LANGUAGE javascript AS $$ $$"#,
         r#"drop table :a"#,
         r#"drop table IDENTIFIER(a)"#,
         r#"drop table IDENTIFIER(:a)"#,
@@ -997,6 +1065,7 @@ fn test_statement_error() {
         r#"drop procedure p1"#,
         r#"drop procedure p1(a int)"#,
         r#"call procedure p1"#,
+        r#"create PROCEDURE p1() returns string language lua as $$ return 1 $$"#,
         r#"create PROCEDURE p1() returns table(string not null, int null) language sql comment = 'test' as $$
             BEGIN
                 LET sum := 0;
@@ -1106,6 +1175,15 @@ fn test_query() {
         r#"select * from t left join lateral(select 1) on true, lateral(select 2)"#,
         r#"select * from t, lateral flatten(input => u.col) f"#,
         r#"select * from flatten(input => parse_json('{"a":1, "b":[77,88]}'), outer => true)"#,
+        r#"select * from trades t asof left join quotes q match_condition(t.ts >= q.ts) on t.sym = q.sym"#,
+        r#"select asof, match_condition from t asof join u match_condition(t.ts >= u.ts) using (sym)"#,
+        r#"select * from ticker match_recognize(partition by symbol order by ts measures first(a.price) as start_price, last(c.price) as end_price, match_number() as mno one row per match after match skip past last row pattern (a b+ c?) define b as price < prev(price), c as price > prev(price)) as mr"#,
+        r#"select * from clicks match_recognize(partition by user_id order by ts measures classifier() as step, count(*) as cnt all rows per match after match skip to last b pattern ((a | b){2,} c{1,3}) define a as page = 'home', b as page = 'item')"#,
+        r#"select empno, level, sys_connect_by_path(ename, '/') as path, connect_by_root ename from emp start with mgr is null connect by prior empno = mgr"#,
+        r#"select part, connect_by_iscycle from bom e where qty > 1 connect by nocycle prior part = parent and level <= 10 start with parent = 'root' order by level"#,
+        r#"select player, score from leaderboard order by score desc fetch first 3 rows with ties"#,
+        r#"select * from t order by a offset 1 rows fetch next 10 percent rows only"#,
+        r#"select * from t fetch first row only"#,
     ];
 
     for case in cases {
@@ -1119,6 +1197,11 @@ fn test_query_error() {
     let file = &mut mint.new_goldenfile("query-error.txt").unwrap();
     let cases = &[
         r#"select * from customer join where a = b"#,
+        r#"select * from t match_recognize(order by ts pattern (a{3,1}) define a as x > 0)"#,
+        r#"select * from emp start with mgr is null"#,
+        r#"select * from t order by a limit 3 fetch first 3 rows only"#,
+        r#"select * from t order by a fetch first 3 rows only offset 1"#,
+        r#"select * from t order by a fetch first 3 rows"#,
         r#"from t1 select * from t2"#,
         r#"from t1 select * from t2 where a = b"#,
         r#"select * from join customer"#,
@@ -1225,6 +1308,9 @@ fn test_expr() {
         r#"ARRAY_FILTER(col, y -> y % 2 = 0)"#,
         r#"(current_timestamp, current_timestamp(), now())"#,
         r#"ARRAY_REDUCE([1,2,3], (acc,t) -> acc + t)"#,
+        r#"COUNT(*) FILTER (WHERE a > 1)"#,
+        r#"STRING_AGG(a, ',') WITHIN GROUP (ORDER BY b DESC) FILTER (WHERE c)"#,
+        r#"PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY a)"#,
     ];
 
     for case in cases {
@@ -1381,6 +1467,22 @@ fn test_script() {
                 SELECT c1, c2 FROM t WHERE c1 = 1;
             END LOOP
         "#,
+        r#"
+            BEGIN
+                INSERT INTO t VALUES (1);
+            EXCEPTION
+                WHEN UnknownTable OR my_error THEN
+                    RAISE;
+                WHEN OTHER THEN
+                    RETURN sqlerrm;
+            END
+        "#,
+        r#"RAISE my_error"#,
+        r#"RAISE my_error('invalid value: ' || x)"#,
+        r#"LET c1 CURSOR FOR SELECT * FROM numbers(10)"#,
+        r#"OPEN c1"#,
+        r#"FETCH c1 INTO a, b"#,
+        r#"CLOSE c1"#,
         r#"select :a + 1"#,
         r#"select IDENTIFIER(:b)"#,
         r#"select a.IDENTIFIER(:b).c + minus(:d)"#,
@@ -1413,6 +1515,19 @@ fn test_script() {
                 END FOR;
             END;
         "#,
+        r#"
+            DECLARE
+                c1 CURSOR FOR SELECT number FROM numbers(10);
+            BEGIN
+                OPEN c1;
+                FETCH c1 INTO x;
+                CLOSE c1;
+                RETURN x;
+            EXCEPTION
+                WHEN OTHER THEN
+                    RETURN sqlcode;
+            END;
+        "#,
     ];
 
     for case in cases {
@@ -0,0 +1,82 @@
+// Copyright 2024 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::Result;
+use databend_common_expression::filter_helper::FilterHelpers;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::RemoteExpr;
+
+/// A CHECK constraint of a table, bound to the columns of the written blocks.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CheckConstraintExpr {
+    pub name: String,
+    /// The check expression, in SQL text.
+    pub expr: String,
+    /// Names and offsets of the columns referenced by the check expression.
+    pub columns: Vec<(String, usize)>,
+    /// TRUE for the rows on which the check expression is FALSE, a NULL
+    /// result satisfies the constraint.
+    pub violation: RemoteExpr,
+}
+
+impl CheckConstraintExpr {
+    /// Returns the rows of `block` violating the constraint, if there are any.
+    pub fn violations(
+        &self,
+        block: &DataBlock,
+        func_ctx: &FunctionContext,
+        fn_registry: &FunctionRegistry,
+    ) -> Result<Option<Bitmap>> {
+        let expr = self.violation.as_expr(fn_registry);
+        let evaluator = Evaluator::new(block, func_ctx, fn_registry);
+        let violated = evaluator.run(&expr)?.try_downcast::<BooleanType>().unwrap();
+        if FilterHelpers::is_all_unset(&violated) {
+            return Ok(None);
+        }
+        Ok(Some(
+            FilterHelpers::filter_to_bitmap(violated, block.num_rows()).into(),
+        ))
+    }
+
+    /// Describes the violation of the constraint by the `row` of `block`.
+    pub fn violation_message(&self, block: &DataBlock, row: usize) -> String {
+        let names = self
+            .columns
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+        let values = self
+            .columns
+            .iter()
+            .map(
+                |(_, offset)| match block.get_by_offset(*offset).value.index(row) {
+                    Some(value) => value.to_string(),
+                    None => "NULL".to_string(),
+                },
+            )
+            .collect::<Vec<_>>();
+        format!(
+            "Row ({})=({}) violates check constraint {} ({})",
+            names.join(", "),
+            values.join(", "),
+            self.name,
+            self.expr
+        )
+    }
+}
@@ -27,10 +27,15 @@ use databend_common_storage::init_stage_operator;
 use databend_common_storage::StageFileInfo;
 use databend_common_storage::StageFilesInfo;
 
+use crate::plan::CheckConstraintExpr;
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq)]
 pub struct StageTableInfo {
     pub schema: TableSchemaRef,
     pub default_values: Option<Vec<RemoteExpr>>,
+    /// The check constraints of the target table of COPY that can be validated
+    /// while reading the files, the violating rows follow the ON_ERROR option.
+    pub check_constraints: Vec<CheckConstraintExpr>,
     pub files_info: StageFilesInfo,
     pub stage_info: StageInfo,
     pub files_to_copy: Option<Vec<StageFileInfo>>,
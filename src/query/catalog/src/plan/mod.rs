@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod agg_index;
+mod check_constraint;
 mod datasource;
 mod internal_column;
 mod partition;
@@ -23,6 +24,7 @@ mod pushdown;
 mod stream_column;
 
 pub use agg_index::*;
+pub use check_constraint::CheckConstraintExpr;
 pub use datasource::*;
 pub use internal_column::*;
 pub use partition::*;
@@ -99,6 +99,9 @@ pub enum PartitionsShuffleKind {
     Mod,
     // Bind the Partition to executor by ConsistentHash(partition.hash()) order.
     ConsistentHash,
+    // Bind the Partition to executor by ConsistentHash(partition.hash()) order, an executor
+    // taking more than its share of the partitions hands the others to the next ones on the ring.
+    CacheAffinity,
     // Bind the Partition to executor by partition.rand() order.
     Rand,
     // Bind the Partition to executor by broadcast
@@ -142,48 +145,23 @@ impl Partitions {
                 parts.into_iter().map(|x| x.1).collect()
             }
             PartitionsShuffleKind::ConsistentHash => {
-                let mut scale = 0;
-                let num_executors = executors_sorted.len();
-                const EXPECT_NODES: usize = 100;
-                while num_executors << scale < EXPECT_NODES {
-                    scale += 1;
-                }
+                let ring = Self::hash_ring(&executors_sorted);
 
                 let mut executor_part = executors_sorted
                     .iter()
                     .map(|e| (e.clone(), Partitions::default()))
                     .collect::<HashMap<_, _>>();
 
-                let mut ring = executors_sorted
-                    .iter()
-                    .flat_map(|e| {
-                        let mut s = DefaultHasher::new();
-                        e.hash(&mut s);
-                        (0..1 << scale).map(move |i| {
-                            i.hash(&mut s);
-                            (e, s.finish())
-                        })
-                    })
-                    .collect::<Vec<_>>();
-
-                ring.sort_by(|&(_, a), &(_, b)| a.cmp(&b));
-
                 for p in self.partitions.iter() {
-                    let k = p.hash();
-                    let idx = match ring.binary_search_by(|&(_, h)| h.cmp(&k)) {
-                        Err(i) => i,
-                        Ok(i) => i,
-                    };
-                    let executor = if idx == ring.len() {
-                        ring[0].0
-                    } else {
-                        ring[idx].0
-                    };
+                    let executor = ring[Self::ring_position(&ring, p.hash())].0;
                     let part = executor_part.get_mut(executor).unwrap();
                     part.partitions.push(p.clone());
                 }
                 return Ok(executor_part);
             }
+            PartitionsShuffleKind::CacheAffinity => {
+                return Ok(self.bounded_consistent_hash(&executors_sorted));
+            }
             PartitionsShuffleKind::Rand => {
                 let mut rng = thread_rng();
                 let mut parts = self.partitions.clone();
@@ -251,6 +229,75 @@ impl Partitions {
         Ok(executor_part)
     }
 
+    // Each executor owns several points of the ring, so that the partitions of a removed
+    // executor are spread over all the remaining ones.
+    fn hash_ring(executors_sorted: &[String]) -> Vec<(&String, u64)> {
+        let mut scale = 0;
+        let num_executors = executors_sorted.len();
+        const EXPECT_NODES: usize = 100;
+        while num_executors << scale < EXPECT_NODES {
+            scale += 1;
+        }
+
+        let mut ring = executors_sorted
+            .iter()
+            .flat_map(|e| {
+                let mut s = DefaultHasher::new();
+                e.hash(&mut s);
+                (0..1 << scale).map(move |i| {
+                    i.hash(&mut s);
+                    (e, s.finish())
+                })
+            })
+            .collect::<Vec<_>>();
+
+        ring.sort_by(|&(_, a), &(_, b)| a.cmp(&b));
+        ring
+    }
+
+    // The position of the first point of the ring following the hash.
+    fn ring_position(ring: &[(&String, u64)], hash: u64) -> usize {
+        let idx = match ring.binary_search_by(|&(_, h)| h.cmp(&hash)) {
+            Err(i) => i,
+            Ok(i) => i,
+        };
+        if idx == ring.len() { 0 } else { idx }
+    }
+
+    /// Consistent hashing with bounded loads: a partition goes to the executor owning its hash,
+    /// unless that executor already has `MAX_LOAD_FACTOR` times its fair share, then it goes to
+    /// the next executor on the ring with room left.
+    ///
+    /// A partition stays on the same executor, so in the same cache, as long as the executors
+    /// don't change and the partitions are not skewed. When an executor leaves, only its own
+    /// partitions are moved.
+    fn bounded_consistent_hash(&self, executors_sorted: &[String]) -> HashMap<String, Partitions> {
+        const MAX_LOAD_FACTOR: f64 = 1.25;
+
+        let ring = Self::hash_ring(executors_sorted);
+        let fair_share = self.partitions.len() as f64 / executors_sorted.len() as f64;
+        let capacity = ((fair_share * MAX_LOAD_FACTOR).ceil() as usize).max(1);
+
+        let mut executor_part = executors_sorted
+            .iter()
+            .map(|e| (e.clone(), Partitions::default()))
+            .collect::<HashMap<_, _>>();
+
+        for p in self.partitions.iter() {
+            let mut idx = Self::ring_position(&ring, p.hash());
+            // terminates, the capacity of all the executors exceeds the number of partitions
+            loop {
+                let part = executor_part.get_mut(ring[idx].0).unwrap();
+                if part.partitions.len() < capacity {
+                    part.partitions.push(p.clone());
+                    break;
+                }
+                idx = (idx + 1) % ring.len();
+            }
+        }
+        executor_part
+    }
+
     pub fn compute_sha256(&self) -> Result<String> {
         let buf = serde_json::to_vec(&self.partitions)?;
         let sha = sha2::Sha256::digest(buf);
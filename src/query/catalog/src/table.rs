@@ -41,6 +41,8 @@ use databend_common_storage::StorageMetrics;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::table::ChangeType;
+use databend_storages_common_table_meta::table::TableRefKind;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_BRANCH;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use databend_storages_common_table_meta::table_id_ranges::is_temp_table_id;
 
@@ -469,6 +471,13 @@ pub trait TableExt: Table {
         let tid = table_info.ident.table_id;
         let catalog = ctx.get_catalog(table_info.catalog()).await?;
 
+        // A handle on a branch keeps reading from and writing to the branch.
+        let mut meta = meta;
+        if let Some(branch) = table_info.options().get(OPT_KEY_TABLE_BRANCH) {
+            meta.options
+                .insert(OPT_KEY_TABLE_BRANCH.to_string(), branch.clone());
+        }
+
         let table_info = TableInfo {
             ident: TableIdent::new(tid, seq),
             meta,
@@ -507,6 +516,11 @@ pub enum NavigationPoint {
     SnapshotID(String),
     TimePoint(DateTime<Utc>),
     StreamInfo(TableInfo),
+    /// The snapshot held by a named tag, or the head of a named branch.
+    TableRef {
+        kind: TableRefKind,
+        name: String,
+    },
 }
 
 #[derive(Debug, Copy, Clone, Default)]
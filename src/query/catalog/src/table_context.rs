@@ -353,6 +353,10 @@ pub trait TableContext: Send + Sync {
     fn get_variable(&self, key: &str) -> Option<Scalar>;
     fn get_all_variables(&self) -> HashMap<String, Scalar>;
 
+    fn set_prepared_statement(&self, name: String, sql: String);
+    fn remove_prepared_statement(&self, name: &str) -> Option<String>;
+    fn get_prepared_statement(&self, name: &str) -> Option<String>;
+
     async fn load_datalake_schema(
         &self,
         _kind: &str,
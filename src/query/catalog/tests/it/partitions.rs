@@ -15,6 +15,7 @@
 use std::any::Any;
 use std::assert_eq;
 use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
 use std::hash::Hash;
 use std::hash::Hasher;
 use std::io::Write;
@@ -255,6 +256,71 @@ fn test_partition_reshuffle() {
     }
 }
 
+fn assignment(shuffle: &HashMap<String, Partitions>) -> HashMap<String, String> {
+    let mut assignment = HashMap::new();
+    for (executor, parts) in shuffle {
+        for part in &parts.partitions {
+            let part = part.as_any().downcast_ref::<TestPartInfo>().unwrap();
+            assert!(
+                assignment
+                    .insert(part.loc.clone(), executor.clone())
+                    .is_none()
+            );
+        }
+    }
+    assignment
+}
+
+#[test]
+fn test_partition_reshuffle_cache_affinity() {
+    let executors_3 = vec![
+        "node-1".to_string(),
+        "node-2".to_string(),
+        "node-3".to_string(),
+    ];
+    let executors_2 = vec!["node-1".to_string(), "node-2".to_string()];
+
+    let partitions = gen_parts(PartitionsShuffleKind::CacheAffinity, 100);
+    let shuffle = partitions.reshuffle(executors_3.clone()).unwrap();
+    let assignment_3 = assignment(&shuffle);
+    assert_eq!(assignment_3.len(), 100);
+    // no executor takes more than 1.25 times its share
+    for parts in shuffle.values() {
+        assert!(parts.len() <= 42);
+    }
+
+    // the assignment does not depend on the order of the executors
+    let mut executors = executors_3.clone();
+    executors.reverse();
+    let shuffle = partitions.reshuffle(executors).unwrap();
+    assert_eq!(assignment(&shuffle), assignment_3);
+
+    // when node-3 leaves, the partitions of the other nodes mostly stay in place
+    let shuffle = partitions.reshuffle(executors_2).unwrap();
+    let assignment_2 = assignment(&shuffle);
+    assert_eq!(assignment_2.len(), 100);
+    let kept = assignment_3
+        .iter()
+        .filter(|(_, executor)| executor.as_str() != "node-3")
+        .collect::<Vec<_>>();
+    let moved = kept
+        .iter()
+        .filter(|(loc, executor)| &assignment_2[*loc] != *executor)
+        .count();
+    assert!(moved * 5 <= kept.len());
+
+    // skewed partitions sharing the same hash are spread over the executors
+    let parts = (0..30)
+        .map(|_| TestPartInfo::create("skewed".to_string()))
+        .collect::<Vec<_>>();
+    let partitions = Partitions::create(PartitionsShuffleKind::CacheAffinity, parts);
+    let shuffle = partitions.reshuffle(executors_3).unwrap();
+    for parts in shuffle.values() {
+        assert!(parts.len() <= 13);
+    }
+    assert_eq!(shuffle.values().map(|parts| parts.len()).sum::<usize>(), 30);
+}
+
 #[test]
 fn test_split() {
     for seg in 0..1024 * 10 {
@@ -1518,6 +1518,10 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "10000")]
     pub max_query_log_size: usize,
 
+    /// Name of a stage the audit records are also written to as JSON files, empty to disable.
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub audit_log_stage: String,
+
     #[clap(long, value_name = "VALUE")]
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
@@ -1744,6 +1748,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: self.table_engine_memory_enabled,
             shutdown_wait_timeout_ms: self.shutdown_wait_timeout_ms,
             max_query_log_size: self.max_query_log_size,
+            audit_log_stage: self.audit_log_stage,
             databend_enterprise_license: self.databend_enterprise_license,
             management_mode: self.management_mode,
             parquet_fast_read_bytes: self.parquet_fast_read_bytes,
@@ -1834,6 +1839,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: inner.table_engine_memory_enabled,
             shutdown_wait_timeout_ms: inner.shutdown_wait_timeout_ms,
             max_query_log_size: inner.max_query_log_size,
+            audit_log_stage: inner.audit_log_stage,
             databend_enterprise_license: inner.databend_enterprise_license,
             management_mode: inner.management_mode,
             parquet_fast_read_bytes: inner.parquet_fast_read_bytes,
@@ -2856,6 +2862,28 @@ pub struct CacheConfig {
     )]
     pub table_data_deserialized_memory_ratio: u64,
 
+    /// Eviction policy of the in-memory and disk caches, "lru" or "s3fifo". By default it is "lru".
+    ///
+    /// "s3fifo" is scan-resistant: items accessed only once, like the data read by a large ad-hoc
+    /// scan, do not flush the frequently accessed items out of the caches.
+    #[clap(
+        long = "cache-eviction-policy",
+        value_name = "VALUE",
+        value_enum,
+        default_value_t
+    )]
+    pub eviction_policy: CacheEvictionPolicy,
+
+    /// Eviction policies of individual caches, overrides `eviction_policy`, e.g.
+    ///
+    /// ```toml
+    /// [cache.eviction_policies]
+    /// memory_cache_bloom_index_filter = "s3fifo"
+    /// disk_cache_table_data = "s3fifo"
+    /// ```
+    #[clap(skip)]
+    pub eviction_policies: BTreeMap<String, String>,
+
     // ----- the following options/args are all deprecated               ----
     /// Max number of cached table segment
     #[clap(long = "cache-table-meta-segment-count", value_name = "VALUE")]
@@ -2904,6 +2932,20 @@ impl Default for DiskCacheKeyReloadPolicy {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheEvictionPolicy {
+    Lru,
+    #[value(name = "s3fifo")]
+    S3Fifo,
+}
+
+impl Default for CacheEvictionPolicy {
+    fn default() -> Self {
+        Self::Lru
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args, Default)]
 #[serde(default, deny_unknown_fields)]
 pub struct DiskCacheConfig {
@@ -3065,6 +3107,27 @@ mod cache_config_converters {
                 data_cache_key_reload_policy: value.data_cache_key_reload_policy.try_into()?,
                 table_data_deserialized_data_bytes: value.table_data_deserialized_data_bytes,
                 table_data_deserialized_memory_ratio: value.table_data_deserialized_memory_ratio,
+                eviction_policy: value.eviction_policy.try_into()?,
+                eviction_policies: value
+                    .eviction_policies
+                    .into_iter()
+                    .map(|(name, policy)| {
+                        if !inner::EVICTION_POLICY_CACHE_NAMES.contains(&name.as_str()) {
+                            return Err(ErrorCode::InvalidConfig(format!(
+                                "Unknown cache '{}' in cache.eviction_policies, expecting one of: {}",
+                                name,
+                                inner::EVICTION_POLICY_CACHE_NAMES.join(", ")
+                            )));
+                        }
+                        let policy = CacheEvictionPolicy::from_str(&policy, true).map_err(|_| {
+                            ErrorCode::InvalidConfig(format!(
+                                "Invalid eviction policy '{}' of cache '{}', expecting \"lru\" or \"s3fifo\"",
+                                policy, name
+                            ))
+                        })?;
+                        Ok((name, policy.try_into()?))
+                    })
+                    .collect::<Result<_>>()?,
             })
         }
     }
@@ -3092,6 +3155,18 @@ mod cache_config_converters {
                 disk_cache_config: value.disk_cache_config.into(),
                 table_data_deserialized_data_bytes: value.table_data_deserialized_data_bytes,
                 table_data_deserialized_memory_ratio: value.table_data_deserialized_memory_ratio,
+                eviction_policy: value.eviction_policy.into(),
+                eviction_policies: value
+                    .eviction_policies
+                    .into_iter()
+                    .map(|(name, policy)| {
+                        let policy = match policy {
+                            inner::CacheEvictionPolicy::Lru => "lru",
+                            inner::CacheEvictionPolicy::S3Fifo => "s3fifo",
+                        };
+                        (name, policy.to_string())
+                    })
+                    .collect(),
                 table_meta_segment_count: None,
             }
         }
@@ -3172,6 +3247,25 @@ mod cache_config_converters {
         }
     }
 
+    impl TryFrom<CacheEvictionPolicy> for inner::CacheEvictionPolicy {
+        type Error = ErrorCode;
+        fn try_from(value: CacheEvictionPolicy) -> std::result::Result<Self, Self::Error> {
+            Ok(match value {
+                CacheEvictionPolicy::Lru => inner::CacheEvictionPolicy::Lru,
+                CacheEvictionPolicy::S3Fifo => inner::CacheEvictionPolicy::S3Fifo,
+            })
+        }
+    }
+
+    impl From<inner::CacheEvictionPolicy> for CacheEvictionPolicy {
+        fn from(value: inner::CacheEvictionPolicy) -> Self {
+            match value {
+                inner::CacheEvictionPolicy::Lru => CacheEvictionPolicy::Lru,
+                inner::CacheEvictionPolicy::S3Fifo => CacheEvictionPolicy::S3Fifo,
+            }
+        }
+    }
+
     impl TryFrom<DiskCacheKeyReloadPolicy> for inner::DiskCacheKeyReloadPolicy {
         type Error = ErrorCode;
         fn try_from(value: DiskCacheKeyReloadPolicy) -> std::result::Result<Self, Self::Error> {
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::ffi::OsString;
 use std::fmt;
@@ -206,6 +207,8 @@ pub struct QueryConfig {
     /// Graceful shutdown timeout
     pub shutdown_wait_timeout_ms: u64,
     pub max_query_log_size: usize,
+    /// Stage to write audit records to, empty to disable.
+    pub audit_log_stage: String,
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     pub management_mode: bool,
@@ -292,6 +295,7 @@ impl Default for QueryConfig {
             table_engine_memory_enabled: true,
             shutdown_wait_timeout_ms: 5000,
             max_query_log_size: 10_000,
+            audit_log_stage: "".to_string(),
             databend_enterprise_license: None,
             management_mode: false,
             parquet_fast_read_bytes: None,
@@ -610,6 +614,38 @@ pub struct CacheConfig {
     /// Only if query nodes have plenty of un-utilized memory, the working set can be fitted into,
     /// and the access pattern will benefit from caching, consider enabled this cache.
     pub table_data_deserialized_memory_ratio: u64,
+
+    /// Eviction policy of the in-memory and disk caches. By default it is "lru".
+    pub eviction_policy: CacheEvictionPolicy,
+
+    /// Eviction policies of individual caches by cache name, overrides `eviction_policy`.
+    pub eviction_policies: BTreeMap<String, CacheEvictionPolicy>,
+}
+
+/// The names of the caches whose eviction policy can be set in `eviction_policies`.
+pub const EVICTION_POLICY_CACHE_NAMES: &[&str] = &[
+    "memory_cache_table_data",
+    "memory_cache_table_snapshot",
+    "memory_cache_table_statistics",
+    "memory_cache_compact_segment_info",
+    "memory_cache_bloom_index_filter",
+    "memory_cache_bloom_index_file_meta_data",
+    "memory_cache_inverted_index_file_meta_data",
+    "memory_cache_inverted_index_file",
+    "memory_cache_prune_partitions",
+    "memory_cache_parquet_meta_data",
+    "memory_cache_block_meta",
+    "disk_cache_table_data",
+];
+
+impl CacheConfig {
+    /// Returns the eviction policy of the cache named `cache_name`.
+    pub fn eviction_policy_of(&self, cache_name: &str) -> CacheEvictionPolicy {
+        self.eviction_policies
+            .get(cache_name)
+            .unwrap_or(&self.eviction_policy)
+            .clone()
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -657,6 +693,30 @@ impl Display for DiskCacheKeyReloadPolicy {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CacheEvictionPolicy {
+    // evict the least recently used item
+    Lru,
+    // S3-FIFO, admit new items into a small probationary queue,
+    // so that items accessed only once do not flush the frequently accessed ones.
+    S3Fifo,
+}
+
+impl Default for CacheEvictionPolicy {
+    fn default() -> Self {
+        Self::Lru
+    }
+}
+
+impl Display for CacheEvictionPolicy {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CacheEvictionPolicy::Lru => write!(f, "lru"),
+            CacheEvictionPolicy::S3Fifo => write!(f, "s3fifo"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DiskCacheConfig {
     /// Max bytes of cached raw table data. Default 20GB, set it to 0 to disable it.
@@ -704,6 +764,8 @@ impl Default for CacheConfig {
             data_cache_key_reload_policy: Default::default(),
             table_data_deserialized_data_bytes: 0,
             table_data_deserialized_memory_ratio: 0,
+            eviction_policy: Default::default(),
+            eviction_policies: BTreeMap::new(),
         }
     }
 }
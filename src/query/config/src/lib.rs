@@ -44,6 +44,7 @@ pub use config::QueryConfig;
 pub use config::StorageConfig;
 pub use global::GlobalConfig;
 pub use inner::CacheConfig;
+pub use inner::CacheEvictionPolicy;
 pub use inner::CacheStorageTypeConfig as CacheStorageTypeInnerConfig;
 pub use inner::CatalogConfig;
 pub use inner::CatalogHiveConfig;
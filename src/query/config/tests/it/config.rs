@@ -15,6 +15,7 @@
 use std::ffi::OsString;
 
 use clap::Parser;
+use databend_common_config::CacheEvictionPolicy;
 use databend_common_config::Config;
 use databend_common_config::InnerConfig;
 use pretty_assertions::assert_eq;
@@ -32,3 +33,30 @@ fn test_config_default() {
         "default setting is different from default config, please check again"
     )
 }
+
+#[test]
+fn test_config_eviction_policies() {
+    let parse = |name: &str, policy: &str| {
+        let mut config = Config::parse_from(Vec::<OsString>::new());
+        config
+            .cache
+            .eviction_policies
+            .insert(name.to_string(), policy.to_string());
+        InnerConfig::try_from(config)
+    };
+
+    let inner = parse("disk_cache_table_data", "S3FIFO").expect("known cache and policy");
+    assert_eq!(
+        inner.cache.eviction_policy_of("disk_cache_table_data"),
+        CacheEvictionPolicy::S3Fifo
+    );
+
+    let err = parse("memory_cache_bloom_filter", "s3fifo").unwrap_err();
+    assert!(
+        err.message()
+            .contains("Unknown cache 'memory_cache_bloom_filter'")
+    );
+
+    let err = parse("disk_cache_table_data", "s3-fifo").unwrap_err();
+    assert!(err.message().contains("Invalid eviction policy 's3-fifo'"));
+}
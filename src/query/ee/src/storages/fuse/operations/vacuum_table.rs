@@ -28,6 +28,7 @@ use databend_common_storages_fuse::io::SnapshotLiteExtended;
 use databend_common_storages_fuse::io::SnapshotsIO;
 use databend_common_storages_fuse::io::TableMetaLocationGenerator;
 use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_fuse::FUSE_TBL_DELETION_VECTOR_PREFIX;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 
@@ -40,6 +41,7 @@ pub struct SnapshotReferencedFiles {
     pub segments: HashSet<String>,
     pub blocks: HashSet<String>,
     pub blocks_index: HashSet<String>,
+    pub deletion_vectors: HashSet<String>,
 }
 
 impl SnapshotReferencedFiles {
@@ -54,11 +56,14 @@ impl SnapshotReferencedFiles {
         for file in &self.blocks_index {
             files.push(file.clone());
         }
+        for file in &self.deletion_vectors {
+            files.push(file.clone());
+        }
         files
     }
 }
 
-// return all the segment\block\index files referenced by current snapshot.
+// return all the segment\block\index\deletion vector files referenced by current snapshot and the held snapshots.
 #[async_backtrace::framed]
 pub async fn get_snapshot_referenced_files(
     fuse_table: &FuseTable,
@@ -113,13 +118,19 @@ pub async fn get_snapshot_referenced_files(
     )
     .await?;
 
-    let segments_vec = match segments_opt {
+    let mut segments_vec = match segments_opt {
         Some(segments) => segments,
         None => {
             return Ok(None);
         }
     };
 
+    // The snapshots held by tags, branches and clones are roots as well.
+    for location in fuse_table.held_snapshot_locations(ctx).await? {
+        let (snapshot, _) = SnapshotsIO::read_snapshot(location, fuse_table.get_operator()).await?;
+        segments_vec.extend(snapshot.segments.iter().cloned());
+    }
+
     let locations_referenced = fuse_table
         .get_block_locations(ctx.clone(), &segments_vec, false, false)
         .await?;
@@ -132,6 +143,7 @@ pub async fn get_snapshot_referenced_files(
         segments,
         blocks: locations_referenced.block_location,
         blocks_index: locations_referenced.bloom_location,
+        deletion_vectors: locations_referenced.deletion_vector_location,
     }))
 }
 
@@ -142,7 +154,14 @@ async fn get_orphan_files_to_be_purged(
     referenced_files: HashSet<String>,
     retention_time: DateTime<Utc>,
 ) -> Result<Vec<String>> {
-    let files_to_be_purged = match referenced_files.iter().next().cloned() {
+    // Only the files under the storage prefix of the table are listed, the others are shared
+    // with the table it was cloned from.
+    let own_prefix = format!("{}/", fuse_table.meta_location_generator().prefix());
+    let files_to_be_purged = match referenced_files
+        .iter()
+        .find(|location| location.starts_with(&own_prefix))
+        .cloned()
+    {
         Some(location) => {
             let prefix = SnapshotsIO::get_s3_prefix_from_file(&location);
             if let Some(prefix) = prefix {
@@ -163,6 +182,28 @@ async fn get_orphan_files_to_be_purged(
     Ok(files_to_be_purged)
 }
 
+// return orphan deletion vector files to be purged.
+//
+// The deletion vectors directory is listed even if no deletion vector is referenced,
+// the blocks they were written for may all have been rewritten since.
+#[async_backtrace::framed]
+async fn get_orphan_deletion_vectors_to_be_purged(
+    fuse_table: &FuseTable,
+    referenced_files: HashSet<String>,
+    retention_time: DateTime<Utc>,
+) -> Result<Vec<String>> {
+    let prefix = format!(
+        "{}/{}/",
+        fuse_table.meta_location_generator().prefix(),
+        FUSE_TBL_DELETION_VECTOR_PREFIX
+    );
+    fuse_table
+        .list_files(prefix, |location, modified| {
+            modified <= retention_time && !referenced_files.contains(&location)
+        })
+        .await
+}
+
 #[async_backtrace::framed]
 pub async fn do_gc_orphan_files(
     fuse_table: &FuseTable,
@@ -176,10 +217,11 @@ pub async fn do_gc_orphan_files(
         None => return Ok(()),
     };
     let status = format!(
-        "gc orphan: read referenced files:{},{},{}, cost:{:?}",
+        "gc orphan: read referenced files:{},{},{},{}, cost:{:?}",
         referenced_files.segments.len(),
         referenced_files.blocks.len(),
         referenced_files.blocks_index.len(),
+        referenced_files.deletion_vectors.len(),
         start.elapsed()
     );
     ctx.set_status_info(&status);
@@ -266,6 +308,36 @@ pub async fn do_gc_orphan_files(
     );
     ctx.set_status_info(&status);
 
+    // 5. Purge orphan deletion vector files.
+    // 5.1 Get orphan deletion vector files to be purged
+    let deletion_vector_locations_to_be_purged = get_orphan_deletion_vectors_to_be_purged(
+        fuse_table,
+        referenced_files.deletion_vectors,
+        retention_time,
+    )
+    .await?;
+    let status = format!(
+        "gc orphan: read deletion_vector_locations_to_be_purged:{}, cost:{:?}",
+        deletion_vector_locations_to_be_purged.len(),
+        start.elapsed()
+    );
+    ctx.set_status_info(&status);
+
+    // 5.2 Delete all the orphan deletion vector files to be purged
+    let purged_file_num = deletion_vector_locations_to_be_purged.len();
+    fuse_table
+        .try_purge_location_files(
+            ctx.clone(),
+            HashSet::from_iter(deletion_vector_locations_to_be_purged.into_iter()),
+        )
+        .await?;
+    let status = format!(
+        "gc orphan: purged deletion vector files:{}, cost:{:?}",
+        purged_file_num,
+        start.elapsed()
+    );
+    ctx.set_status_info(&status);
+
     Ok(())
 }
 
@@ -284,10 +356,11 @@ pub async fn do_dry_run_orphan_files(
         None => return Ok(()),
     };
     let status = format!(
-        "dry_run orphan: read referenced files:{},{},{}, cost:{:?}",
+        "dry_run orphan: read referenced files:{},{},{},{}, cost:{:?}",
         referenced_files.segments.len(),
         referenced_files.blocks.len(),
         referenced_files.blocks_index.len(),
+        referenced_files.deletion_vectors.len(),
         start.elapsed()
     );
     ctx.set_status_info(&status);
@@ -334,6 +407,25 @@ pub async fn do_dry_run_orphan_files(
     ctx.set_status_info(&status);
 
     purge_files.extend(index_locations_to_be_purged);
+    if purge_files.len() >= dry_run_limit {
+        return Ok(());
+    }
+
+    // 5. Get purge orphan deletion vector files.
+    let deletion_vector_locations_to_be_purged = get_orphan_deletion_vectors_to_be_purged(
+        fuse_table,
+        referenced_files.deletion_vectors,
+        retention_time,
+    )
+    .await?;
+    let status = format!(
+        "dry_run orphan: read deletion_vector_locations_to_be_purged:{}, cost:{:?}",
+        deletion_vector_locations_to_be_purged.len(),
+        start.elapsed()
+    );
+    ctx.set_status_info(&status);
+
+    purge_files.extend(deletion_vector_locations_to_be_purged);
 
     Ok(())
 }
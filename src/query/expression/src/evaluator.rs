@@ -68,6 +68,7 @@ impl<'a> EvaluateOptions<'a> {
             suppress_error: false,
             selection,
             errors: None,
+            error_code: None,
         }
     }
 
@@ -76,6 +77,7 @@ impl<'a> EvaluateOptions<'a> {
             suppress_error,
             selection: self.selection,
             errors: None,
+            error_code: None,
         }
     }
 }
@@ -1113,6 +1115,7 @@ impl<'a> Evaluator<'a> {
                     num_rows: self.data_block.num_rows(),
                     validity: None,
                     errors: None,
+                    error_code: None,
                     func_ctx: self.func_ctx,
                     suppress_error: false,
                 };
@@ -517,6 +517,7 @@ impl<'a> Selector<'a> {
                     num_rows: self.evaluator.data_block().num_rows(),
                     validity: None,
                     errors: None,
+                    error_code: None,
                     func_ctx: self.evaluator.func_ctx(),
                     suppress_error: eval_options.suppress_error,
                 };
@@ -150,6 +150,8 @@ pub struct EvalContext<'a> {
     /// default value in nullable's inner column.
     pub validity: Option<Bitmap>,
     pub errors: Option<(MutableBitmap, String)>,
+    /// Creates the error from the message of `errors`, `ErrorCode::BadArguments` if not set.
+    pub error_code: Option<fn(String) -> ErrorCode>,
     pub suppress_error: bool,
 }
 
@@ -316,6 +318,7 @@ impl FunctionRegistry {
             .keys()
             .chain(self.factories.keys())
             .chain(self.aliases.keys())
+            .filter(|name| !self.is_internal(name))
             .unique()
             .cloned()
             .collect()
@@ -327,6 +330,13 @@ impl FunctionRegistry {
             || self.aliases.contains_key(func_name)
     }
 
+    /// Returns true if the function can't be called by the users.
+    pub fn is_internal(&self, func_name: &str) -> bool {
+        self.properties
+            .get(func_name)
+            .is_some_and(|property| property.internal)
+    }
+
     pub fn get(&self, id: &FunctionID) -> Option<Arc<Function>> {
         match id {
             FunctionID::Builtin { name, id } => self
@@ -485,6 +495,7 @@ impl FunctionRegistry {
             .keys()
             .chain(self.funcs.keys())
             .chain(self.factories.keys())
+            .filter(|name| !self.is_internal(name))
             .map(|s| s.to_string())
             .sorted()
             .dedup()
@@ -584,6 +595,21 @@ impl<'a> EvalContext<'a> {
         }
     }
 
+    /// Like `set_error`, but the error is created by `error_code` instead of
+    /// `ErrorCode::BadArguments`.
+    pub fn set_error_with_code(
+        &mut self,
+        row: usize,
+        error_code: fn(String) -> ErrorCode,
+        error_msg: impl Into<String>,
+    ) {
+        let first_error = self.errors.is_none();
+        self.set_error(row, error_msg);
+        if first_error && self.errors.is_some() {
+            self.error_code = Some(error_code);
+        }
+    }
+
     pub fn render_error(
         &self,
         span: Span,
@@ -637,7 +663,8 @@ impl<'a> EvalContext<'a> {
                     )
                 };
 
-                Err(ErrorCode::BadArguments(err_msg).set_span(span))
+                let error_code = self.error_code.unwrap_or(ErrorCode::BadArguments);
+                Err(error_code(err_msg).set_span(span))
             }
             None => Ok(()),
         }
@@ -45,6 +45,8 @@ use crate::Scalar;
 pub struct FunctionProperty {
     pub non_deterministic: bool,
     pub kind: FunctionKind,
+    /// The function is generated by the planner and can't be called by the users.
+    pub internal: bool,
 }
 
 impl FunctionProperty {
@@ -57,6 +59,11 @@ impl FunctionProperty {
         self.kind = kind;
         self
     }
+
+    pub fn internal(mut self) -> Self {
+        self.internal = true;
+        self
+    }
 }
 
 impl Default for FunctionProperty {
@@ -64,6 +71,7 @@ impl Default for FunctionProperty {
         FunctionProperty {
             non_deterministic: false,
             kind: FunctionKind::Scalar,
+            internal: false,
         }
     }
 }
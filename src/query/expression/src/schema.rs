@@ -181,6 +181,9 @@ pub struct TableField {
     #[serde(default = "uninit_column_id")]
     pub column_id: ColumnId,
     pub computed_expr: Option<ComputedExpr>,
+    /// The encoding and compression declared by `CODEC(...)`, e.g. `DELTA, ZSTD(3)`.
+    #[serde(default)]
+    pub codec: Option<String>,
 }
 
 /// DataType with more information that is only available for table field, e.g, the
@@ -1041,6 +1044,7 @@ impl TableField {
             data_type,
             column_id: 0,
             computed_expr: None,
+            codec: None,
         }
     }
 
@@ -1051,6 +1055,7 @@ impl TableField {
             data_type,
             column_id,
             computed_expr: None,
+            codec: None,
         }
     }
 
@@ -1097,6 +1102,7 @@ impl TableField {
             data_type: self.data_type.clone(),
             column_id,
             computed_expr: self.computed_expr.clone(),
+            codec: self.codec.clone(),
         }
     }
 
@@ -1142,6 +1148,11 @@ impl TableField {
         self
     }
 
+    pub fn with_codec(mut self, codec: Option<String>) -> Self {
+        self.codec = codec;
+        self
+    }
+
     pub fn name(&self) -> &String {
         &self.name
     }
@@ -1158,6 +1169,10 @@ impl TableField {
         self.computed_expr.as_ref()
     }
 
+    pub fn codec(&self) -> Option<&String> {
+        self.codec.as_ref()
+    }
+
     #[inline]
     pub fn is_nullable(&self) -> bool {
         self.data_type.is_nullable()
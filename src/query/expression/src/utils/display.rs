@@ -963,6 +963,9 @@ impl Display for FunctionProperty {
         if self.non_deterministic {
             properties.push("non_deterministic");
         }
+        if self.internal {
+            properties.push("internal");
+        }
         if !properties.is_empty() {
             write!(f, "{{{}}}", properties.join(", "))?;
         }
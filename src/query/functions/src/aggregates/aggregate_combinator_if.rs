@@ -49,6 +49,16 @@ impl AggregateIfCombinator {
         arguments: Vec<DataType>,
         nested_creator: &AggregateFunctionCreator,
     ) -> Result<AggregateFunctionRef> {
+        let nested_arguments = Self::nested_arguments(nested_name, &arguments)?;
+        let nested = nested_creator(nested_name, params, nested_arguments.to_vec())?;
+        Ok(Self::create(nested_name, arguments.len(), nested))
+    }
+
+    /// Returns the arguments of the nested function, the last argument is the condition.
+    pub fn nested_arguments<'a>(
+        nested_name: &str,
+        arguments: &'a [DataType],
+    ) -> Result<&'a [DataType]> {
         let name = format!("IfCombinator({})", nested_name);
         let argument_len = arguments.len();
 
@@ -67,15 +77,20 @@ impl AggregateIfCombinator {
             )));
         }
 
-        let nested_arguments = &arguments[0..argument_len - 1];
-        let nested = nested_creator(nested_name, params, nested_arguments.to_vec())?;
+        Ok(&arguments[0..argument_len - 1])
+    }
 
-        Ok(Arc::new(AggregateIfCombinator {
-            name,
+    pub fn create(
+        nested_name: &str,
+        argument_len: usize,
+        nested: AggregateFunctionRef,
+    ) -> AggregateFunctionRef {
+        Arc::new(AggregateIfCombinator {
+            name: format!("IfCombinator({})", nested_name),
             argument_len,
             nested_name: nested_name.to_owned(),
             nested,
-        }))
+        })
     }
 
     pub fn combinator_desc() -> CombinatorDescription {
@@ -0,0 +1,267 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::InputColumns;
+use databend_common_expression::Scalar;
+
+use super::borsh_deserialize_state;
+use super::borsh_serialize_state;
+use super::AggregateFunctionFactory;
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionCreator;
+use crate::aggregates::aggregate_function_factory::CombinatorDescription;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// The rows of an ordered-set aggregate, kept until the result is requested.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Default)]
+struct WithinGroupState {
+    rows: Vec<Vec<Scalar>>,
+}
+
+/// Aggregates the arguments in the order of the sort keys, like
+/// `string_agg(a, ',') WITHIN GROUP (ORDER BY b DESC)`.
+///
+/// The arguments are followed by the sort keys, and the last parameter describes the
+/// direction of each key, like `ASC NULLS LAST, DESC NULLS FIRST`.
+#[derive(Clone)]
+pub struct AggregateWithinGroupCombinator {
+    name: String,
+    nested_name: String,
+    arguments: Vec<DataType>,
+    sort_descs: Vec<(bool, bool)>,
+    nested: AggregateFunctionRef,
+}
+
+impl AggregateWithinGroupCombinator {
+    pub fn try_create(
+        nested_name: &str,
+        mut params: Vec<Scalar>,
+        mut arguments: Vec<DataType>,
+        _nested_creator: &AggregateFunctionCreator,
+    ) -> Result<AggregateFunctionRef> {
+        let name = format!("WithinGroupCombinator({})", nested_name);
+
+        let sort_descs = match params.pop() {
+            Some(Scalar::String(spec)) => parse_sort_descs(&spec)?,
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "{} expect the sort description as the last parameter",
+                    name
+                )));
+            }
+        };
+        if sort_descs.is_empty() || sort_descs.len() >= arguments.len() {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{} expect to have {} sort keys after the arguments, but got {} arguments",
+                name,
+                sort_descs.len(),
+                arguments.len()
+            )));
+        }
+
+        let nested_len = arguments.len() - sort_descs.len();
+        let keys = arguments.split_off(nested_len);
+        let nested =
+            AggregateFunctionFactory::instance().get(nested_name, params, arguments.clone())?;
+        arguments.extend(keys);
+
+        Ok(Arc::new(AggregateWithinGroupCombinator {
+            name,
+            nested_name: nested_name.to_owned(),
+            arguments,
+            sort_descs,
+            nested,
+        }))
+    }
+
+    pub fn combinator_desc() -> CombinatorDescription {
+        CombinatorDescription::creator(Box::new(Self::try_create))
+    }
+
+    fn nested_len(&self) -> usize {
+        self.arguments.len() - self.sort_descs.len()
+    }
+
+    fn nested_place(place: StateAddr) -> StateAddr {
+        place.next(Layout::new::<WithinGroupState>().size())
+    }
+
+    fn compare_rows(&self, lhs: &[Scalar], rhs: &[Scalar]) -> Ordering {
+        let nested_len = self.nested_len();
+        for (i, (asc, nulls_first)) in self.sort_descs.iter().enumerate() {
+            let (l, r) = (&lhs[nested_len + i], &rhs[nested_len + i]);
+            let ordering = match (l.is_null(), r.is_null()) {
+                (true, true) => Ordering::Equal,
+                (true, false) if *nulls_first => Ordering::Less,
+                (true, false) => Ordering::Greater,
+                (false, true) if *nulls_first => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) if *asc => l.cmp(r),
+                (false, false) => r.cmp(l),
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+/// Parses the sort description like `ASC NULLS LAST, DESC NULLS FIRST` into
+/// `(asc, nulls_first)` of each sort key.
+fn parse_sort_descs(spec: &str) -> Result<Vec<(bool, bool)>> {
+    spec.split(',')
+        .map(|desc| {
+            let words = desc.split_whitespace().collect::<Vec<_>>();
+            match words.as_slice() {
+                ["ASC", "NULLS", "FIRST"] => Ok((true, true)),
+                ["ASC", "NULLS", "LAST"] => Ok((true, false)),
+                ["DESC", "NULLS", "FIRST"] => Ok((false, true)),
+                ["DESC", "NULLS", "LAST"] => Ok((false, false)),
+                _ => Err(ErrorCode::BadArguments(format!(
+                    "Invalid sort description of WITHIN GROUP: {}",
+                    desc.trim()
+                ))),
+            }
+        })
+        .collect()
+}
+
+impl AggregateFunction for AggregateWithinGroupCombinator {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        self.nested.return_type()
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(WithinGroupState::default);
+        self.nested.init_state(Self::nested_place(place));
+    }
+
+    fn state_layout(&self) -> Layout {
+        let layout = Layout::new::<WithinGroupState>();
+        let nested = self.nested.state_layout();
+        Layout::from_size_align(layout.size() + nested.size(), layout.align()).unwrap()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: InputColumns,
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        for row in 0..input_rows {
+            if validity.map_or(true, |v| v.get_bit(row)) {
+                self.accumulate_row(place, columns, row)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: InputColumns, row: usize) -> Result<()> {
+        let state = place.get::<WithinGroupState>();
+        let values = columns
+            .iter()
+            .map(|col| col.index(row).unwrap().to_owned())
+            .collect();
+        state.rows.push(values);
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<WithinGroupState>();
+        borsh_serialize_state(writer, state)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<WithinGroupState>();
+        let rhs: WithinGroupState = borsh_deserialize_state(reader)?;
+        state.rows.extend(rhs.rows);
+        Ok(())
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<WithinGroupState>();
+        let other = rhs.get::<WithinGroupState>();
+        state.rows.extend(other.rows.iter().cloned());
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<WithinGroupState>();
+        state.rows.sort_by(|lhs, rhs| self.compare_rows(lhs, rhs));
+
+        let nested_len = self.nested_len();
+        let mut builders = self.arguments[..nested_len]
+            .iter()
+            .map(|ty| ColumnBuilder::with_capacity(ty, state.rows.len()))
+            .collect::<Vec<_>>();
+        for values in state.rows.iter() {
+            for (builder, value) in builders.iter_mut().zip(values) {
+                builder.push(value.as_ref());
+            }
+        }
+        let columns = builders
+            .into_iter()
+            .map(|builder| builder.build())
+            .collect::<Vec<_>>();
+
+        // The result of a window frame is merged more than once, the nested state is
+        // rebuilt from the sorted rows each time.
+        let nested_place = Self::nested_place(place);
+        if self.nested.need_manual_drop_state() {
+            unsafe { self.nested.drop_state(nested_place) };
+        }
+        self.nested.init_state(nested_place);
+        self.nested
+            .accumulate(nested_place, (&columns).into(), None, state.rows.len())?;
+        self.nested.merge_result(nested_place, builder)
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<WithinGroupState>();
+        std::ptr::drop_in_place(state);
+
+        if self.nested.need_manual_drop_state() {
+            self.nested.drop_state(Self::nested_place(place));
+        }
+    }
+}
+
+impl fmt::Display for AggregateWithinGroupCombinator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}_within_group", self.nested_name)
+    }
+}
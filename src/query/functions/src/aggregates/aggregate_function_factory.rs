@@ -23,10 +23,12 @@ use databend_common_expression::Scalar;
 
 use super::AggregateFunctionCombinatorNull;
 use super::AggregateFunctionOrNullAdaptor;
+use super::AggregateIfCombinator;
 use crate::aggregates::AggregateFunctionRef;
 use crate::aggregates::Aggregators;
 
 const STATE_SUFFIX: &str = "_state";
+const IF_SUFFIX: &str = "_if";
 
 pub type AggregateFunctionCreator =
     Box<dyn Fn(&str, Vec<Scalar>, Vec<DataType>) -> Result<AggregateFunctionRef> + Sync + Send>;
@@ -173,13 +175,16 @@ impl AggregateFunctionFactory {
         let name = name.as_ref();
         let mut features = AggregateFunctionFeatures::default();
         // The NULL value in the array_agg function needs to be added to the returned array column,
-        // so handled separately.
+        // so handled separately. The within group combinator creates its nested function
+        // with the NULL handling of its own.
         if name == "array_agg"
             || name == "list"
             || name == "json_array_agg"
             || name == "json_object_agg"
             || name == "group_array_moving_avg"
             || name == "group_array_moving_sum"
+            || name.to_lowercase().ends_with("_within_group")
+            || name.to_lowercase().ends_with("_within_group_if")
         {
             let agg = self.get_impl(name, params, arguments, &mut features)?;
             return Ok(agg);
@@ -239,6 +244,23 @@ impl AggregateFunctionFactory {
                 let aggregate_functions_map = &self.case_insensitive_desc;
 
                 match aggregate_functions_map.get(nested_name) {
+                    // FILTER with DISTINCT or WITHIN GROUP nests the `_if` combinator
+                    // over the other one, like `sum_distinct_if`.
+                    None if suffix == IF_SUFFIX && self.contains(nested_name) => {
+                        let nested_arguments =
+                            AggregateIfCombinator::nested_arguments(nested_name, &arguments)?;
+                        let nested = self.get_impl(
+                            nested_name,
+                            params,
+                            nested_arguments.to_vec(),
+                            features,
+                        )?;
+                        return Ok(AggregateIfCombinator::create(
+                            nested_name,
+                            arguments.len(),
+                            nested,
+                        ));
+                    }
                     None => {
                         break;
                     }
@@ -275,7 +297,9 @@ impl AggregateFunctionFactory {
         // find suffix
         for (suffix, _) in &self.case_insensitive_combinator_desc {
             if let Some(nested_name) = lowercase_name.strip_suffix(suffix) {
-                if self.case_insensitive_desc.contains_key(nested_name) {
+                if self.case_insensitive_desc.contains_key(nested_name)
+                    || (suffix == IF_SUFFIX && self.contains(nested_name))
+                {
                     return true;
                 }
             }
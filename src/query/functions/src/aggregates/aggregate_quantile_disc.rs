@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::any::Any;
 use std::sync::Arc;
 
 use borsh::BorshDeserialize;
@@ -29,13 +30,47 @@ use ethnum::i256;
 use super::get_levels;
 use super::AggregateUnaryFunction;
 use super::FunctionData;
-use super::QuantileData;
 use super::UnaryState;
 use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
 use crate::aggregates::assert_unary_arguments;
 use crate::aggregates::AggregateFunctionRef;
 use crate::with_simple_no_number_mapped_type;
 
+/// How the index of the value at a level is taken from the sorted values.
+#[derive(Clone, Copy)]
+enum DiscIndex {
+    /// `quantile_disc` takes `floor((n - 1) * level)`.
+    Quantile,
+    /// `percentile_disc` takes the first value whose cumulative distribution is not
+    /// less than the level, `ceil(n * level) - 1` in the ascending order.
+    Percentile,
+    /// `percentile_disc` in the descending order.
+    PercentileDesc,
+}
+
+impl DiscIndex {
+    fn index(self, level: f64, len: usize) -> usize {
+        match self {
+            DiscIndex::Quantile => ((len - 1) as f64 * level).floor() as usize,
+            DiscIndex::Percentile => ((len as f64 * level).ceil() as usize).max(1) - 1,
+            DiscIndex::PercentileDesc => {
+                len.saturating_sub(((len as f64 * level).ceil() as usize).max(1))
+            }
+        }
+    }
+}
+
+struct QuantileDiscData {
+    levels: Vec<f64>,
+    index: DiscIndex,
+}
+
+impl FunctionData for QuantileDiscData {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 #[derive(BorshSerialize, BorshDeserialize)]
 struct QuantileState<T>
 where
@@ -88,13 +123,13 @@ where
             function_data
                 .unwrap()
                 .as_any()
-                .downcast_ref_unchecked::<QuantileData>()
+                .downcast_ref_unchecked::<QuantileDiscData>()
         };
         if quantile_disc_data.levels.len() > 1 {
             let indices = quantile_disc_data
                 .levels
                 .iter()
-                .map(|level| ((value_len - 1) as f64 * (*level)).floor() as usize)
+                .map(|level| quantile_disc_data.index.index(*level, value_len))
                 .collect::<Vec<usize>>();
             for idx in indices {
                 if idx < value_len {
@@ -144,10 +179,12 @@ where
             function_data
                 .unwrap()
                 .as_any()
-                .downcast_ref_unchecked::<QuantileData>()
+                .downcast_ref_unchecked::<QuantileDiscData>()
         };
 
-        let idx = ((value_len - 1) as f64 * quantile_disc_data.levels[0]).floor() as usize;
+        let idx = quantile_disc_data
+            .index
+            .index(quantile_disc_data.levels[0], value_len);
         if idx >= value_len {
             T::push_default(builder);
         } else {
@@ -164,15 +201,47 @@ pub fn try_create_aggregate_quantile_disc_function(
     display_name: &str,
     params: Vec<Scalar>,
     arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    let levels = get_levels(&params)?;
+    create_quantile_disc(display_name, params, arguments, levels, DiscIndex::Quantile)
+}
+
+/// `percentile_disc(level[, desc])(x)`, which `PERCENTILE_DISC(level) WITHIN GROUP (ORDER BY x)`
+/// is resolved as.
+pub fn try_create_aggregate_percentile_disc_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    let index = match params.get(1) {
+        None => DiscIndex::Percentile,
+        Some(Scalar::Boolean(false)) if params.len() == 2 => DiscIndex::Percentile,
+        Some(Scalar::Boolean(true)) if params.len() == 2 => DiscIndex::PercentileDesc,
+        _ => {
+            return Err(ErrorCode::BadArguments(format!(
+                "{} expects a level and an optional boolean of the descending order, got: {:?}",
+                display_name, params
+            )));
+        }
+    };
+    let levels = get_levels(&params[..params.len().min(1)].to_vec())?;
+    create_quantile_disc(display_name, params, arguments, levels, index)
+}
+
+fn create_quantile_disc(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+    levels: Vec<f64>,
+    index: DiscIndex,
 ) -> Result<AggregateFunctionRef> {
     assert_unary_arguments(display_name, arguments.len())?;
     let data_type = arguments[0].clone();
-    let levels = get_levels(&params)?;
     with_simple_no_number_mapped_type!(|T| match data_type {
         DataType::Number(num_type) => {
             with_number_mapped_type!(|NUM_TYPE| match num_type {
                 NumberDataType::NUM_TYPE => {
-                    if params.len() > 1 {
+                    if levels.len() > 1 {
                         let func = AggregateUnaryFunction::<
                             QuantileState<NumberType<NUM_TYPE>>,
                             NumberType<NUM_TYPE>,
@@ -183,7 +252,7 @@ pub fn try_create_aggregate_quantile_disc_function(
                             params,
                             arguments[0].clone(),
                         )
-                        .with_function_data(Box::new(QuantileData { levels }))
+                        .with_function_data(Box::new(QuantileDiscData { levels, index }))
                         .with_need_drop(true);
                         Ok(Arc::new(func))
                     } else {
@@ -194,7 +263,7 @@ pub fn try_create_aggregate_quantile_disc_function(
                         >::try_create(
                             display_name, data_type, params, arguments[0].clone()
                         )
-                        .with_function_data(Box::new(QuantileData { levels }))
+                        .with_function_data(Box::new(QuantileDiscData { levels, index }))
                         .with_need_drop(true);
                         Ok(Arc::new(func))
                     }
@@ -207,7 +276,7 @@ pub fn try_create_aggregate_quantile_disc_function(
                 scale: s.scale,
             };
             let data_type = DataType::Decimal(DecimalDataType::from_size(decimal_size)?);
-            if params.len() > 1 {
+            if levels.len() > 1 {
                 let func = AggregateUnaryFunction::<
                     QuantileState<DecimalType<i128>>,
                     DecimalType<i128>,
@@ -218,7 +287,7 @@ pub fn try_create_aggregate_quantile_disc_function(
                     params,
                     arguments[0].clone(),
                 )
-                .with_function_data(Box::new(QuantileData { levels }))
+                .with_function_data(Box::new(QuantileDiscData { levels, index }))
                 .with_need_drop(true);
                 Ok(Arc::new(func))
             } else {
@@ -229,7 +298,7 @@ pub fn try_create_aggregate_quantile_disc_function(
                 >::try_create(
                     display_name, data_type, params, arguments[0].clone()
                 )
-                .with_function_data(Box::new(QuantileData { levels }))
+                .with_function_data(Box::new(QuantileDiscData { levels, index }))
                 .with_need_drop(true);
                 Ok(Arc::new(func))
             }
@@ -240,7 +309,7 @@ pub fn try_create_aggregate_quantile_disc_function(
                 scale: s.scale,
             };
             let data_type = DataType::Decimal(DecimalDataType::from_size(decimal_size)?);
-            if params.len() > 1 {
+            if levels.len() > 1 {
                 let func = AggregateUnaryFunction::<
                     QuantileState<DecimalType<i256>>,
                     DecimalType<i256>,
@@ -251,7 +320,7 @@ pub fn try_create_aggregate_quantile_disc_function(
                     params,
                     arguments[0].clone(),
                 )
-                .with_function_data(Box::new(QuantileData { levels }))
+                .with_function_data(Box::new(QuantileDiscData { levels, index }))
                 .with_need_drop(true);
                 Ok(Arc::new(func))
             } else {
@@ -262,7 +331,7 @@ pub fn try_create_aggregate_quantile_disc_function(
                 >::try_create(
                     display_name, data_type, params, arguments[0].clone()
                 )
-                .with_function_data(Box::new(QuantileData { levels }))
+                .with_function_data(Box::new(QuantileDiscData { levels, index }))
                 .with_need_drop(true);
                 Ok(Arc::new(func))
             }
@@ -276,3 +345,7 @@ pub fn try_create_aggregate_quantile_disc_function(
 pub fn aggregate_quantile_disc_function_desc() -> AggregateFunctionDescription {
     AggregateFunctionDescription::creator(Box::new(try_create_aggregate_quantile_disc_function))
 }
+
+pub fn aggregate_percentile_disc_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_percentile_disc_function))
+}
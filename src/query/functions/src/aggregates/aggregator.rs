@@ -38,6 +38,7 @@ use super::aggregate_window_funnel::aggregate_window_funnel_function_desc;
 use super::AggregateCountFunction;
 use super::AggregateFunctionFactory;
 use super::AggregateIfCombinator;
+use super::AggregateWithinGroupCombinator;
 use crate::aggregates::aggregate_array_agg_function_desc;
 use crate::aggregates::aggregate_array_moving_avg_function_desc;
 use crate::aggregates::aggregate_array_moving_sum_function_desc;
@@ -48,6 +49,7 @@ use crate::aggregates::aggregate_kurtosis_function_desc;
 use crate::aggregates::aggregate_median_function_desc;
 use crate::aggregates::aggregate_median_tdigest_function_desc;
 use crate::aggregates::aggregate_median_tdigest_weighted_function_desc;
+use crate::aggregates::aggregate_percentile_disc_function_desc;
 use crate::aggregates::aggregate_quantile_cont_function_desc;
 use crate::aggregates::aggregate_quantile_disc_function_desc;
 use crate::aggregates::aggregate_quantile_tdigest_function_desc;
@@ -81,6 +83,7 @@ impl Aggregators {
         factory.register("std", aggregate_stddev_pop_function_desc());
         factory.register("quantile", aggregate_quantile_disc_function_desc());
         factory.register("quantile_disc", aggregate_quantile_disc_function_desc());
+        factory.register("percentile_disc", aggregate_percentile_disc_function_desc());
         factory.register("quantile_cont", aggregate_quantile_cont_function_desc());
         factory.register(
             "quantile_tdigest",
@@ -117,6 +120,7 @@ impl Aggregators {
         factory.register("kurtosis", aggregate_kurtosis_function_desc());
         factory.register("skewness", aggregate_skewness_function_desc());
         factory.register("string_agg", aggregate_string_agg_function_desc());
+        factory.register("listagg", aggregate_string_agg_function_desc());
 
         factory.register(
             "bitmap_and_count",
@@ -150,5 +154,9 @@ impl Aggregators {
         factory.register_combinator("_if", AggregateIfCombinator::combinator_desc());
         factory.register_combinator("_distinct", aggregate_combinator_distinct_desc());
         factory.register_combinator("_state", AggregateStateCombinator::combinator_desc());
+        factory.register_combinator(
+            "_within_group",
+            AggregateWithinGroupCombinator::combinator_desc(),
+        );
     }
 }
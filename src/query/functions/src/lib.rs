@@ -86,6 +86,15 @@ pub const GENERAL_LAMBDA_FUNCTIONS: [&str; 10] = [
 
 pub const GENERAL_SEARCH_FUNCTIONS: [&str; 3] = ["match", "query", "score"];
 
+// The aggregate functions that accept the `WITHIN GROUP (ORDER BY ...)` clause.
+pub const ORDERED_SET_AGGREGATE_FUNCTIONS: [&str; 5] = [
+    "array_agg",
+    "list",
+    "string_agg",
+    "listagg",
+    "json_array_agg",
+];
+
 fn builtin_functions() -> FunctionRegistry {
     let mut registry = FunctionRegistry::empty();
 
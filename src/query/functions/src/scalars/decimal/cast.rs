@@ -436,6 +436,7 @@ pub fn convert_to_decimal_domain(
         func_ctx,
         validity: None,
         errors: None,
+        error_code: None,
         suppress_error: false,
     };
     let dest_size = dest_type.size();
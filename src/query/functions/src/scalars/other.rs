@@ -21,6 +21,7 @@ use databend_common_base::base::convert_byte_size;
 use databend_common_base::base::convert_number_size;
 use databend_common_base::base::uuid::Uuid;
 use databend_common_base::base::OrderedFloat;
+use databend_common_exception::ErrorCode;
 use databend_common_expression::error_to_null;
 use databend_common_expression::types::boolean::BooleanDomain;
 use databend_common_expression::types::nullable::NullableColumn;
@@ -32,6 +33,7 @@ use databend_common_expression::types::number::UInt8Type;
 use databend_common_expression::types::number::F64;
 use databend_common_expression::types::string::StringColumn;
 use databend_common_expression::types::ArgType;
+use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::DateType;
 use databend_common_expression::types::GenericType;
@@ -199,6 +201,54 @@ pub fn register(registry: &mut FunctionRegistry) {
         }))
     });
 
+    // `prepared_param(index, value)` is a parameter of a prepared statement in a cached plan,
+    // replaced with the value of the parameter before the plan is executed.
+    // It never evaluates, so that the parameter is not folded into the plan.
+    registry.properties.insert(
+        "prepared_param".to_string(),
+        FunctionProperty::default().non_deterministic().internal(),
+    );
+    registry.register_function_factory("prepared_param", |_, args_type| {
+        if args_type.len() != 2 {
+            return None;
+        }
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "prepared_param".to_string(),
+                args_type: vec![DataType::Generic(0), DataType::Generic(1)],
+                return_type: DataType::Generic(1),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(|args, ctx| {
+                    ctx.set_error(0, "prepared statement parameter is not bound");
+                    args[1].clone().to_owned()
+                }),
+            },
+        }))
+    });
+
+    // `connect_by_check_loop(is_loop)` is generated by the planner for CONNECT BY without
+    // NOCYCLE, it fails if a row is connected to one of its ancestors.
+    registry.properties.insert(
+        "connect_by_check_loop".to_string(),
+        FunctionProperty::default().internal(),
+    );
+    registry.register_passthrough_nullable_1_arg::<BooleanType, BooleanType, _, _>(
+        "connect_by_check_loop",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BooleanType, BooleanType>(|is_loop, output, ctx| {
+            if is_loop {
+                ctx.set_error_with_code(
+                    output.len(),
+                    ErrorCode::ConnectByLoop,
+                    "CONNECT BY loop in user data",
+                );
+            }
+            output.push(!is_loop);
+        }),
+    );
+
     registry.register_1_arg_core::<NullableType<GenericType<0>>, GenericType<0>, _, _>(
         "assume_not_null",
         |_, domain| {
@@ -247,6 +247,7 @@ fn list_all_builtin_functions() {
         .funcs
         .values()
         .flatten()
+        .filter(|(func, _)| !fn_registry.is_internal(&func.signature.name))
         .map(|(func, id)| {
             (
                 (func.signature.name.clone(), *id),
@@ -257,6 +258,7 @@ fn list_all_builtin_functions() {
     fn_registry
         .factories
         .iter()
+        .filter(|(name, _)| !fn_registry.is_internal(name))
         .flat_map(|(name, funcs)| {
             funcs
                 .iter()
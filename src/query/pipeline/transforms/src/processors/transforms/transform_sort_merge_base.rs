@@ -264,6 +264,7 @@ pub struct TransformSortMergeBuilder {
     spilling_batch_bytes: usize,
     enable_loser_tree: bool,
     limit: Option<usize>,
+    limit_with_ties: bool,
 }
 
 impl TransformSortMergeBuilder {
@@ -287,6 +288,7 @@ impl TransformSortMergeBuilder {
             spilling_batch_bytes: 8 * 1024 * 1024,
             enable_loser_tree: false,
             limit: None,
+            limit_with_ties: false,
         }
     }
 
@@ -305,6 +307,11 @@ impl TransformSortMergeBuilder {
         self
     }
 
+    pub fn with_limit_with_ties(mut self, limit_with_ties: bool) -> Self {
+        self.limit_with_ties = limit_with_ties;
+        self
+    }
+
     pub fn with_max_memory_usage(mut self, max_memory_usage: usize) -> Self {
         self.max_memory_usage = max_memory_usage;
         self
@@ -456,7 +463,11 @@ impl TransformSortMergeBuilder {
                 self.max_memory_usage,
                 self.spilling_bytes_threshold_per_core,
                 self.spilling_batch_bytes,
-                TransformSortMergeLimit::create(self.block_size, self.limit.unwrap()),
+                TransformSortMergeLimit::create(
+                    self.block_size,
+                    self.limit.unwrap(),
+                    self.limit_with_ties,
+                ),
             )?,
         ))
     }
@@ -14,6 +14,7 @@
 
 use std::cmp::Ordering;
 use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 use std::collections::HashMap;
 use std::intrinsics::unlikely;
 
@@ -31,6 +32,14 @@ pub struct TransformSortMergeLimit<R: Rows> {
     heap: FixedHeap<Reverse<Cursor<R, LocalCursorOrder>>>,
     buffer: HashMap<usize, DataBlock>,
 
+    /// If the rows which tie with the last row of the Top-N are also kept.
+    with_ties: bool,
+    /// The rows evicted from the heap which tie with the largest row in the heap.
+    ties: Vec<Cursor<R, LocalCursorOrder>>,
+    /// The rows of the heap with the largest one on top, only kept with ties.
+    /// The heap always evicts its largest row, so it is also popped from here.
+    max_heap: BinaryHeap<Cursor<R, LocalCursorOrder>>,
+
     /// Record current memory usage.
     num_bytes: usize,
     num_rows: usize,
@@ -54,14 +63,19 @@ impl<R: Rows> MergeSort<R> for TransformSortMergeLimit<R> {
         self.buffer.insert(cur_index, block);
 
         while !cursor.is_finished() {
-            if let Some(Reverse(evict)) = self.heap.push(Reverse(cursor.clone())) {
+            if let Some(evict) = self.push(&cursor) {
+                if self.with_ties && self.is_tie(&evict) {
+                    // Later rows in current block may also tie with the Top-N.
+                    self.ties.push(evict);
+                    cursor.advance();
+                    continue;
+                }
+                self.retain_ties();
+
                 if evict.row_index == 0 {
                     // Evict the first row of the block,
                     // which means the block must not appear in the Top-N result.
-                    if let Some(block) = self.buffer.remove(&evict.input_index) {
-                        self.num_bytes -= block.memory_size();
-                        self.num_rows -= block.num_rows();
-                    }
+                    self.remove_block(evict.input_index);
                 }
 
                 if evict.input_index == cur_index {
@@ -126,27 +140,95 @@ impl<R: Rows> CursorOrder<R> for LocalCursorOrder {
 }
 
 impl<R: Rows> TransformSortMergeLimit<R> {
-    pub fn create(block_size: usize, limit: usize) -> Self {
+    pub fn create(block_size: usize, limit: usize, with_ties: bool) -> Self {
         debug_assert!(limit <= 10000, "Too large sort merge limit: {}", limit);
         TransformSortMergeLimit {
             heap: FixedHeap::new(limit),
             buffer: HashMap::with_capacity(limit),
+            with_ties,
+            ties: vec![],
+            max_heap: BinaryHeap::new(),
             block_size,
             num_bytes: 0,
             num_rows: 0,
         }
     }
 
+    fn remove_block(&mut self, input_index: usize) {
+        if let Some(block) = self.buffer.remove(&input_index) {
+            self.num_bytes -= block.memory_size();
+            self.num_rows -= block.num_rows();
+        }
+    }
+
+    /// Pushes the row into the heap, returns the evicted row if the heap is full.
+    fn push(
+        &mut self,
+        cursor: &Cursor<R, LocalCursorOrder>,
+    ) -> Option<Cursor<R, LocalCursorOrder>> {
+        let evict = self
+            .heap
+            .push(Reverse(cursor.clone()))
+            .map(|Reverse(evict)| evict);
+        if self.with_ties {
+            match &evict {
+                // The row itself is evicted, the heap is unchanged.
+                Some(evict)
+                    if evict.input_index == cursor.input_index
+                        && evict.row_index == cursor.row_index => {}
+                Some(_) => {
+                    self.max_heap.pop();
+                    self.max_heap.push(cursor.clone());
+                }
+                None => self.max_heap.push(cursor.clone()),
+            }
+        }
+        evict
+    }
+
+    /// Returns the largest row in the heap.
+    fn heap_max(&self) -> Option<&Cursor<R, LocalCursorOrder>> {
+        self.max_heap.peek()
+    }
+
+    fn is_tie(&self, cursor: &Cursor<R, LocalCursorOrder>) -> bool {
+        self.heap_max()
+            .is_some_and(|max| max.current() == cursor.current())
+    }
+
+    /// Discards the ties if the largest row in the heap has become smaller than them.
+    fn retain_ties(&mut self) {
+        match self.ties.first() {
+            Some(tie) if !self.is_tie(tie) => {
+                for tie in std::mem::take(&mut self.ties) {
+                    if tie.row_index == 0 {
+                        self.remove_block(tie.input_index);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
     fn drain_heap(&mut self, batch_size: usize) -> Vec<DataBlock> {
         if self.heap.is_empty() {
             return vec![];
         }
 
-        let output_size = self.heap.len();
+        // The ties are not less than any row in the heap, so they are output at last.
+        let mut ties = std::mem::take(&mut self.ties);
+        self.max_heap.clear();
+        ties.sort_by_key(|cursor| (cursor.input_index, cursor.row_index));
+
+        let output_size = self.heap.len() + ties.len();
         let block_indices = self.buffer.keys().cloned().collect::<Vec<_>>();
         let blocks = self.buffer.values().cloned().collect::<Vec<_>>();
         let mut output_indices = Vec::with_capacity(output_size);
+        let mut heap_rows = Vec::with_capacity(self.heap.len());
         while let Some(Reverse(cursor)) = self.heap.pop() {
+            heap_rows.push(cursor);
+        }
+        for cursor in heap_rows.into_iter().chain(ties) {
             let block_index = block_indices
                 .iter()
                 .position(|i| *i == cursor.input_index)
@@ -166,8 +248,9 @@ impl<R: Rows> TransformSortMergeLimit<R> {
             let (block_idx, row_idx) = output_indices[start];
             merge_slices.push((block_idx, row_idx, 1));
             for (block_idx, row_idx) in output_indices.iter().take(end).skip(start + 1) {
-                if *block_idx == merge_slices.last().unwrap().0 {
-                    // If the block index is the same as the last one, we can merge them.
+                let (last_block_idx, last_row_idx, last_len) = *merge_slices.last().unwrap();
+                if *block_idx == last_block_idx && *row_idx == last_row_idx + last_len {
+                    // If the row is next to the last one in the same block, we can merge them.
                     merge_slices.last_mut().unwrap().2 += 1;
                 } else {
                     merge_slices.push((*block_idx, *row_idx, 1));
@@ -20,6 +20,7 @@ use std::vec;
 use databend_common_ast::ast::BinaryOperator;
 use databend_common_ast::ast::ColumnID;
 use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::ExceptionHandler;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::FunctionCall;
 use databend_common_ast::ast::Identifier;
@@ -104,6 +105,22 @@ impl Compiler {
                     )?);
                     self.declare_ref(&declare.name, RefItem::Set(to_set))?;
                 }
+                ScriptStatement::LetCursor { declare } => {
+                    let cursor = CursorItem {
+                        stmt: self.quote_sql_statement(declare.span, &declare.stmt)?,
+                        set: SetRef::new(
+                            declare.name.span,
+                            &declare.name.name,
+                            &mut self.ref_allocator,
+                        ),
+                        iter: IterRef::new(
+                            declare.name.span,
+                            &declare.name.name,
+                            &mut self.ref_allocator,
+                        ),
+                    };
+                    self.declare_ref(&declare.name, RefItem::Cursor(cursor))?;
+                }
                 ScriptStatement::RunStatement { span, stmt } => {
                     let to_set =
                         SetRef::new_internal(*span, "unused_result", &mut self.ref_allocator);
@@ -166,7 +183,17 @@ impl Compiler {
                     body,
                     label,
                 } => {
-                    let set = self.lookup_set(resultset)?;
+                    let set = match self.lookup_ref(resultset)? {
+                        RefItem::Cursor(cursor) => {
+                            // QUERY <cursor_stmt>, cursor_set
+                            output.push(ScriptIR::Query {
+                                stmt: cursor.stmt,
+                                to_set: cursor.set.clone(),
+                            });
+                            cursor.set
+                        }
+                        _ => self.lookup_set(resultset)?,
+                    };
                     output.append(&mut self.compile_for_in(*span, variable, set, body, label)?);
                 }
                 ScriptStatement::ForInStatement {
@@ -264,6 +291,83 @@ impl Compiler {
                         else_result,
                     )?);
                 }
+                ScriptStatement::Block {
+                    span,
+                    body,
+                    exception_handlers,
+                } => {
+                    output.append(&mut self.compile_block(*span, body, exception_handlers)?);
+                }
+                ScriptStatement::Raise {
+                    span,
+                    exception: Some(exception),
+                    message,
+                } => {
+                    let message = match message {
+                        Some(message) => {
+                            let to_var = VarRef::new_internal(
+                                message.span(),
+                                "raise_message",
+                                &mut self.ref_allocator,
+                            );
+                            output.append(&mut self.compile_expr(message, to_var.clone())?);
+                            Some(to_var)
+                        }
+                        None => None,
+                    };
+                    output.push(ScriptIR::Raise {
+                        span: *span,
+                        exception: self.normalize_ident(exception).0,
+                        message,
+                    });
+                }
+                ScriptStatement::Raise {
+                    span,
+                    exception: None,
+                    ..
+                } => {
+                    let handler = self.current_handler(*span)?;
+                    output.push(ScriptIR::Reraise { handler });
+                }
+                ScriptStatement::Open { cursor, .. } => {
+                    let cursor = self.lookup_cursor(cursor)?;
+
+                    // QUERY <cursor_stmt>, cursor_set
+                    output.push(ScriptIR::Query {
+                        stmt: cursor.stmt,
+                        to_set: cursor.set.clone(),
+                    });
+
+                    // ITER cursor_set, cursor_iter
+                    output.push(ScriptIR::Iter {
+                        set: cursor.set,
+                        to_iter: cursor.iter,
+                    });
+                }
+                ScriptStatement::Fetch { cursor, into, .. } => {
+                    let cursor = self.lookup_cursor(cursor)?;
+                    let mut to_vars = Vec::with_capacity(into.len());
+                    for name in into {
+                        // Undefined variables are declared on the fly, like `LET`.
+                        let to_var = match self.lookup_ref(name) {
+                            Ok(_) => self.lookup_var(name)?,
+                            Err(_) => self.declare_var(name)?,
+                        };
+                        to_vars.push(to_var);
+                    }
+
+                    // FETCH cursor_iter, [to_vars]
+                    output.push(ScriptIR::Fetch {
+                        iter: cursor.iter,
+                        to_vars,
+                    });
+                }
+                ScriptStatement::Close { cursor, .. } => {
+                    let cursor = self.lookup_cursor(cursor)?;
+
+                    // CLOSE cursor_iter
+                    output.push(ScriptIR::Close { iter: cursor.iter });
+                }
             }
         }
 
@@ -291,6 +395,7 @@ impl Compiler {
                 }],
                 from: vec![],
                 selection: None,
+                connect_by: None,
                 group_by: None,
                 having: None,
                 window_list: None,
@@ -299,6 +404,7 @@ impl Compiler {
             order_by: vec![],
             limit: vec![],
             offset: None,
+            fetch: None,
             ignore_result: false,
         }));
         let stmt = StatementTemplate::new(expr.whole_span(), select_stmt);
@@ -384,6 +490,7 @@ impl Compiler {
                     sample: None,
                 }],
                 selection: None,
+                connect_by: None,
                 group_by: None,
                 having: None,
                 window_list: None,
@@ -392,6 +499,7 @@ impl Compiler {
             order_by: vec![],
             limit: vec![],
             offset: None,
+            fetch: None,
             ignore_result: false,
         }));
         let stmt = StatementTemplate::new(variable.span, select_stmt);
@@ -736,12 +844,145 @@ impl Compiler {
         self.compile_if(span, &conditions, results, else_result)
     }
 
+    fn compile_block(
+        &mut self,
+        span: Span,
+        body: &[ScriptStatement],
+        exception_handlers: &[ExceptionHandler],
+    ) -> Result<Vec<ScriptIR>> {
+        let mut output = vec![];
+
+        if exception_handlers.is_empty() {
+            // <body>
+            self.push_scope();
+            output.append(&mut self.compile(body)?);
+            self.pop_scope();
+
+            return Ok(output);
+        }
+
+        let begin_label = LabelRef::new_internal(span, "GUARD", &mut self.ref_allocator);
+        let end_label = LabelRef::new_internal(span, "GUARD_END", &mut self.ref_allocator);
+        let handler_label = LabelRef::new_internal(span, "EXCEPTION", &mut self.ref_allocator);
+        let block_end_label = LabelRef::new_internal(span, "BLOCK_END", &mut self.ref_allocator);
+
+        // GUARD GUARD, GUARD_END, EXCEPTION
+        output.push(ScriptIR::Guard {
+            begin: begin_label.clone(),
+            end: end_label.clone(),
+            handler: handler_label.clone(),
+        });
+
+        // Label GUARD
+        output.push(ScriptIR::Label { label: begin_label });
+
+        // <body>
+        self.push_scope();
+        output.append(&mut self.compile(body)?);
+        self.pop_scope();
+
+        // Label GUARD_END
+        output.push(ScriptIR::Label { label: end_label });
+
+        // GOTO BLOCK_END
+        output.push(ScriptIR::Goto {
+            to_label: block_end_label.clone(),
+        });
+
+        // Label EXCEPTION
+        output.push(ScriptIR::Label {
+            label: handler_label.clone(),
+        });
+
+        self.push_scope();
+        self.scopes.last_mut().unwrap().handler = Some(handler_label.clone());
+
+        // CATCH EXCEPTION, sqlcode, sqlerrname, sqlerrm
+        output.push(ScriptIR::Catch {
+            handler: handler_label.clone(),
+            to_code: self.declare_var(&Identifier::from_name(span, "sqlcode"))?,
+            to_name: self.declare_var(&Identifier::from_name(span, "sqlerrname"))?,
+            to_message: self.declare_var(&Identifier::from_name(span, "sqlerrm"))?,
+        });
+
+        let when_labels = exception_handlers
+            .iter()
+            .map(|handler| LabelRef::new_internal(handler.span, "WHEN", &mut self.ref_allocator))
+            .collect::<Vec<_>>();
+
+        let mut has_other = false;
+        for (handler, when_label) in exception_handlers.iter().zip(&when_labels) {
+            match &handler.exceptions {
+                Some(exceptions) => {
+                    // JUMP_IF_CAUGHT EXCEPTION, [exceptions], WHEN
+                    output.push(ScriptIR::JumpIfCaught {
+                        handler: handler_label.clone(),
+                        exceptions: exceptions
+                            .iter()
+                            .map(|exception| self.normalize_ident(exception).0)
+                            .collect(),
+                        to_label: when_label.clone(),
+                    });
+                }
+                None => {
+                    // GOTO WHEN
+                    output.push(ScriptIR::Goto {
+                        to_label: when_label.clone(),
+                    });
+                    has_other = true;
+                    break;
+                }
+            }
+        }
+
+        if !has_other {
+            // RERAISE EXCEPTION
+            output.push(ScriptIR::Reraise {
+                handler: handler_label.clone(),
+            });
+        }
+
+        for (handler, when_label) in exception_handlers.iter().zip(&when_labels) {
+            // Label WHEN
+            output.push(ScriptIR::Label {
+                label: when_label.clone(),
+            });
+
+            // <handler>
+            self.push_scope();
+            output.append(&mut self.compile(&handler.body)?);
+            self.pop_scope();
+
+            // GOTO BLOCK_END
+            output.push(ScriptIR::Goto {
+                to_label: block_end_label.clone(),
+            });
+        }
+
+        self.pop_scope();
+
+        // Label BLOCK_END
+        output.push(ScriptIR::Label {
+            label: block_end_label,
+        });
+
+        Ok(output)
+    }
+
     fn compile_sql_statement(
         &self,
         span: Span,
         stmt: &Statement,
         to_set: SetRef,
     ) -> Result<Vec<ScriptIR>> {
+        // QUERY <stmt>, to_set
+        let stmt = self.quote_sql_statement(span, stmt)?;
+        let output = vec![ScriptIR::Query { stmt, to_set }];
+
+        Ok(output)
+    }
+
+    fn quote_sql_statement(&self, span: Span, stmt: &Statement) -> Result<StatementTemplate> {
         #[derive(VisitorMut)]
         #[visitor(Expr(enter), Identifier(enter), Statement(enter))]
         struct QuoteVisitor<'a> {
@@ -823,11 +1064,7 @@ impl Compiler {
             return Err(e);
         }
 
-        // QUERY <stmt>, to_set
-        let stmt = StatementTemplate::new(span, stmt);
-        let output = vec![ScriptIR::Query { stmt, to_set }];
-
-        Ok(output)
+        Ok(StatementTemplate::new(span, stmt))
     }
 
     fn push_scope(&mut self) {
@@ -849,6 +1086,12 @@ impl Compiler {
         Ok(())
     }
 
+    fn declare_var(&mut self, ident: &Identifier) -> Result<VarRef> {
+        let var = VarRef::new(ident.span, &ident.name, &mut self.ref_allocator);
+        self.declare_ref(ident, RefItem::Var(var.clone()))?;
+        Ok(var)
+    }
+
     fn declare_loop(&mut self, ident: &Identifier) -> Result<LoopItem> {
         let name = self.normalize_ident(ident);
         let continue_label = LabelRef::new(
@@ -925,6 +1168,17 @@ impl Compiler {
         Ok(iter)
     }
 
+    fn lookup_cursor(&self, ident: &Identifier) -> Result<CursorItem> {
+        let RefItem::Cursor(cursor) = self.lookup_ref(ident)? else {
+            let name = self.normalize_ident(ident);
+            return Err(
+                ErrorCode::ScriptSemanticError(format!("`{name}` is not a cursor"))
+                    .set_span(ident.span),
+            );
+        };
+        Ok(cursor)
+    }
+
     fn lookup_loop(&self, ident: &Identifier) -> Result<LoopItem> {
         let name = self.normalize_ident(ident);
         for scope in self.scopes.iter().rev() {
@@ -946,6 +1200,18 @@ impl Compiler {
         Err(ErrorCode::ScriptSemanticError("not in a loop".to_string()).set_span(span))
     }
 
+    fn current_handler(&self, span: Span) -> Result<LabelRef> {
+        for scope in self.scopes.iter().rev() {
+            if let Some(handler) = &scope.handler {
+                return Ok(handler.clone());
+            }
+        }
+        Err(
+            ErrorCode::ScriptSemanticError("not in an exception handler".to_string())
+                .set_span(span),
+        )
+    }
+
     fn quote_expr(&mut self, expr: &Expr) -> Result<(Vec<ScriptIR>, Expr)> {
         #[derive(VisitorMut)]
         #[visitor(Expr(enter), Identifier(enter))]
@@ -1064,6 +1330,7 @@ impl Compiler {
 struct Scope {
     items: HashMap<RefName, RefItem>,
     loop_item: Option<LoopItem>,
+    handler: Option<LabelRef>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1080,6 +1347,14 @@ enum RefItem {
     Var(VarRef),
     Set(SetRef),
     Iter(IterRef),
+    Cursor(CursorItem),
+}
+
+#[derive(Debug, Clone)]
+struct CursorItem {
+    stmt: StatementTemplate,
+    set: SetRef,
+    iter: IterRef,
 }
 
 #[derive(Debug, Clone)]
@@ -1149,6 +1424,8 @@ fn wrap_is_true(expr: Expr) -> Expr {
             name: Identifier::from_name(expr.span(), "is_true"),
             args: vec![expr],
             params: vec![],
+            order_by: vec![],
+            filter: None,
             window: None,
             lambda: None,
         },
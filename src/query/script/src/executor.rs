@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Literal;
 use databend_common_ast::Span;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -33,6 +34,7 @@ pub trait Client {
     #[allow(async_fn_in_trait)]
     async fn query(&self, query: &str) -> Result<Self::Set>;
     fn var_to_ast(&self, scalar: &Self::Var) -> Result<Expr>;
+    fn literal_to_var(&self, literal: &Literal) -> Result<Self::Var>;
     fn read_from_set(&self, block: &Self::Set, row: usize, col: &ColumnAccess)
     -> Result<Self::Var>;
     fn num_rows(&self, block: &Self::Set) -> usize;
@@ -52,6 +54,13 @@ struct Cursor {
     len: usize,
 }
 
+#[derive(Debug)]
+struct Guard {
+    begin: usize,
+    end: usize,
+    handler: LabelRef,
+}
+
 #[derive(Debug)]
 pub struct Executor<C: Client> {
     span: Span,
@@ -61,6 +70,8 @@ pub struct Executor<C: Client> {
     sets: HashMap<SetRef, C::Set>,
     iters: HashMap<IterRef, Cursor>,
     label_to_pc: HashMap<LabelRef, usize>,
+    guards: Vec<Guard>,
+    caught: HashMap<LabelRef, ErrorCode>,
     return_value: Option<ReturnValue<C>>,
     pc: usize,
 }
@@ -76,6 +87,22 @@ impl<C: Client> Executor<C> {
             }
         }
 
+        let mut guards = vec![];
+        for line in &code {
+            if let ScriptIR::Guard {
+                begin,
+                end,
+                handler,
+            } = line
+            {
+                guards.push(Guard {
+                    begin: label_to_pc[begin],
+                    end: label_to_pc[end],
+                    handler: handler.clone(),
+                });
+            }
+        }
+
         Executor {
             span,
             client,
@@ -84,6 +111,8 @@ impl<C: Client> Executor<C> {
             sets: HashMap::new(),
             iters: HashMap::new(),
             label_to_pc,
+            guards,
+            caught: HashMap::new(),
             return_value: None,
             pc: 0,
         }
@@ -94,7 +123,9 @@ impl<C: Client> Executor<C> {
             if self.pc >= self.code.len() {
                 return Ok(self.return_value.take());
             }
-            self.step().await?;
+            if let Err(err) = self.step().await {
+                self.handle_error(err)?;
+            }
         }
 
         Err(ErrorCode::ScriptExecutionError(format!(
@@ -181,6 +212,95 @@ impl<C: Client> Executor<C> {
                 self.return_value = Some(ReturnValue::Set(self.get_set(set)?.clone()));
                 self.goto_end();
             }
+            ScriptIR::Fetch { iter, to_vars } => {
+                let cursor = self.get_cursor(iter)?;
+                let values = if cursor.row < cursor.len {
+                    let block = self.get_set(&cursor.set)?;
+                    (0..to_vars.len())
+                        .map(|col| {
+                            self.client.read_from_set(
+                                block,
+                                cursor.row,
+                                &ColumnAccess::Position(col),
+                            )
+                        })
+                        .collect::<Result<Vec<_>>>()?
+                } else {
+                    to_vars
+                        .iter()
+                        .map(|_| self.client.literal_to_var(&Literal::Null))
+                        .collect::<Result<Vec<_>>>()?
+                };
+                let cursor = self.get_iter_mut(iter)?;
+                cursor.row = (cursor.row + 1).min(cursor.len);
+                for (to_var, value) in to_vars.iter().zip(values) {
+                    self.vars.insert(to_var.clone(), value);
+                }
+            }
+            ScriptIR::Close { iter } => {
+                self.get_cursor(iter)?;
+                self.iters.remove(iter);
+            }
+            ScriptIR::Guard { .. } => {}
+            ScriptIR::Catch {
+                handler,
+                to_code,
+                to_name,
+                to_message,
+            } => {
+                let err = self.get_caught(handler)?;
+                let code = self
+                    .client
+                    .literal_to_var(&Literal::UInt64(err.code() as u64))?;
+                let name = self.client.literal_to_var(&Literal::String(err.name()))?;
+                let message = self
+                    .client
+                    .literal_to_var(&Literal::String(err.message()))?;
+                self.vars.insert(to_code.clone(), code);
+                self.vars.insert(to_name.clone(), name);
+                self.vars.insert(to_message.clone(), message);
+            }
+            ScriptIR::JumpIfCaught {
+                handler,
+                exceptions,
+                to_label,
+            } => {
+                let name = self.get_caught(handler)?.name();
+                if exceptions
+                    .iter()
+                    .any(|exception| exception.eq_ignore_ascii_case(&name))
+                {
+                    self.goto(to_label)?;
+                }
+            }
+            ScriptIR::Raise {
+                span,
+                exception,
+                message,
+            } => {
+                let message = match message {
+                    Some(message) => match self.client.var_to_ast(self.get_var(message)?)? {
+                        Expr::Literal {
+                            value: Literal::String(message),
+                            ..
+                        } => message,
+                        expr => expr.to_string(),
+                    },
+                    None => format!("exception `{exception}` is raised"),
+                };
+                return Err(ErrorCode::create(
+                    ErrorCode::SCRIPT_USER_EXCEPTION,
+                    exception,
+                    message,
+                    String::new(),
+                    None,
+                    None,
+                )
+                .set_span(*span));
+            }
+            ScriptIR::Reraise { handler } => {
+                return Err(self.get_caught(handler)?.clone());
+            }
         }
 
         self.pc += 1;
@@ -188,6 +308,26 @@ impl<C: Client> Executor<C> {
         Ok(())
     }
 
+    /// Jumps to the innermost handler guarding the current instruction, or returns
+    /// the error if there is none.
+    fn handle_error(&mut self, err: ErrorCode) -> Result<()> {
+        let handler = self
+            .guards
+            .iter()
+            .filter(|guard| guard.begin < self.pc && self.pc < guard.end)
+            .max_by_key(|guard| guard.begin)
+            .map(|guard| guard.handler.clone());
+
+        match handler {
+            Some(handler) => {
+                self.goto(&handler)?;
+                self.caught.insert(handler, err);
+                Ok(())
+            }
+            None => Err(err),
+        }
+    }
+
     fn get_var(&self, var: &VarRef) -> Result<&C::Var> {
         self.vars
             .get(var)
@@ -206,6 +346,18 @@ impl<C: Client> Executor<C> {
             .ok_or_else(|| ErrorCode::ScriptExecutionError(format!("unknown iter: {iter}")))
     }
 
+    fn get_cursor(&self, iter: &IterRef) -> Result<&Cursor> {
+        self.iters.get(iter).ok_or_else(|| {
+            ErrorCode::ScriptExecutionError(format!("cursor `{}` is not open", iter.display_name))
+        })
+    }
+
+    fn get_caught(&self, handler: &LabelRef) -> Result<&ErrorCode> {
+        self.caught.get(handler).ok_or_else(|| {
+            ErrorCode::ScriptExecutionError(format!("no error caught by handler: {handler}"))
+        })
+    }
+
     fn get_iter_mut(&mut self, iter: &IterRef) -> Result<&mut Cursor> {
         self.iters
             .get_mut(iter)
@@ -92,6 +92,38 @@ pub enum ScriptIR {
     ReturnVar { var: VarRef },
     /// Returns a result set from the script.
     ReturnSet { set: SetRef },
+    /// Reads the current row into variables and forwards the iterator, or sets the
+    /// variables to NULL if the iterator has reached the end of the result set.
+    Fetch { iter: IterRef, to_vars: Vec<VarRef> },
+    /// Releases the iterator.
+    Close { iter: IterRef },
+    /// Errors raised between the `begin` and `end` labels jump to the `handler` label.
+    Guard {
+        begin: LabelRef,
+        end: LabelRef,
+        handler: LabelRef,
+    },
+    /// Retrieves the code, name and message of the error caught by a handler to variables.
+    Catch {
+        handler: LabelRef,
+        to_code: VarRef,
+        to_name: VarRef,
+        to_message: VarRef,
+    },
+    /// Jumps to a specified label if the error caught by a handler is one of the exceptions.
+    JumpIfCaught {
+        handler: LabelRef,
+        exceptions: Vec<String>,
+        to_label: LabelRef,
+    },
+    /// Raises a user-defined exception.
+    Raise {
+        span: Span,
+        exception: String,
+        message: Option<VarRef>,
+    },
+    /// Raises the error caught by a handler again.
+    Reraise { handler: LabelRef },
 }
 
 impl Display for ScriptIR {
@@ -122,6 +154,48 @@ impl Display for ScriptIR {
             ScriptIR::Return => write!(f, "RETURN")?,
             ScriptIR::ReturnVar { var } => write!(f, "RETURN {var}")?,
             ScriptIR::ReturnSet { set } => write!(f, "RETURN {set}")?,
+            ScriptIR::Fetch { iter, to_vars } => {
+                write!(f, "FETCH {iter}, [")?;
+                for (i, var) in to_vars.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{var}")?;
+                }
+                write!(f, "]")?;
+            }
+            ScriptIR::Close { iter } => write!(f, "CLOSE {iter}")?,
+            ScriptIR::Guard {
+                begin,
+                end,
+                handler,
+            } => write!(f, "GUARD {begin}, {end}, {handler}")?,
+            ScriptIR::Catch {
+                handler,
+                to_code,
+                to_name,
+                to_message,
+            } => write!(f, "CATCH {handler}, {to_code}, {to_name}, {to_message}")?,
+            ScriptIR::JumpIfCaught {
+                handler,
+                exceptions,
+                to_label,
+            } => write!(
+                f,
+                "JUMP_IF_CAUGHT {handler}, [{}], {to_label}",
+                exceptions.join(", ")
+            )?,
+            ScriptIR::Raise {
+                exception,
+                message: Some(message),
+                ..
+            } => write!(f, "RAISE {exception}, {message}")?,
+            ScriptIR::Raise {
+                exception,
+                message: None,
+                ..
+            } => write!(f, "RAISE {exception}")?,
+            ScriptIR::Reraise { handler } => write!(f, "RERAISE {handler}")?,
         };
         Ok(())
     }
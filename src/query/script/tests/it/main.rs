@@ -321,6 +321,69 @@ fn test_script() {
             END CASE;
         "#,
     );
+    run_script(
+        file,
+        r#"
+            LET zero := 0;
+            BEGIN
+                LET y := 1 + zero / 0;
+            EXCEPTION
+                WHEN SemanticError OR BadArguments THEN
+                    RETURN sqlerrm;
+            END;
+        "#,
+    );
+    run_script(
+        file,
+        r#"
+            BEGIN
+                BEGIN
+                    RAISE my_error;
+                EXCEPTION
+                    WHEN other_error THEN
+                        RETURN 'TWO';
+                END;
+            EXCEPTION
+                WHEN my_error THEN
+                    RETURN 'ONE';
+                WHEN OTHER THEN
+                    RETURN 'OTHER';
+            END;
+        "#,
+    );
+    run_script(
+        file,
+        r#"
+            BEGIN
+                RAISE my_error('oops');
+            EXCEPTION
+                WHEN OTHER THEN
+                    RETURN sqlerrm;
+            END;
+        "#,
+    );
+    run_script(
+        file,
+        r#"
+            LET c CURSOR FOR SELECT * FROM numbers(3);
+            OPEN c;
+            FETCH c INTO x;
+            FETCH c INTO y;
+            CLOSE c;
+            RETURN x + y;
+        "#,
+    );
+    run_script(
+        file,
+        r#"
+            LET c CURSOR FOR SELECT * FROM numbers(3);
+            LET sum := 0;
+            FOR row IN c DO
+                sum := sum + row.number;
+            END FOR;
+            RETURN sum;
+        "#,
+    );
 }
 
 #[test]
@@ -464,6 +527,37 @@ fn test_script_error() {
             END FOR;
         "#,
     );
+    run_script(
+        file,
+        r#"
+            RAISE;
+        "#,
+    );
+    run_script(
+        file,
+        r#"
+            BEGIN
+                RAISE my_error('oops');
+            EXCEPTION
+                WHEN other_error THEN
+                    RETURN;
+            END;
+        "#,
+    );
+    run_script(
+        file,
+        r#"
+            LET x := 1;
+            OPEN x;
+        "#,
+    );
+    run_script(
+        file,
+        r#"
+            LET c CURSOR FOR SELECT * FROM numbers(3);
+            FETCH c INTO x;
+        "#,
+    );
 }
 
 fn mock_client() -> MockClient {
@@ -522,6 +616,14 @@ fn mock_client() -> MockClient {
             "SELECT 'OTHER'",
             MockSet::unnamed(vec![vec![Literal::String("OTHER".to_string())]]),
         )
+        .response_when(
+            "SELECT 'oops'",
+            MockSet::unnamed(vec![vec![Literal::String("oops".to_string())]]),
+        )
+        .response_when(
+            "SELECT 'division by zero'",
+            MockSet::unnamed(vec![vec![Literal::String("division by zero".to_string())]]),
+        )
         .response_when(
             "SELECT 0 + 0",
             MockSet::unnamed(vec![vec![Literal::UInt64(0)]]),
@@ -678,6 +780,10 @@ impl Client for MockClient {
         })
     }
 
+    fn literal_to_var(&self, literal: &Literal) -> Result<Self::Var> {
+        Ok(literal.clone())
+    }
+
     fn read_from_set(&self, set: &Self::Set, row: usize, col: &ColumnAccess) -> Result<Self::Var> {
         let var = match col {
             ColumnAccess::Position(col) => set.data[row][*col].clone(),
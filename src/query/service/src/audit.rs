@@ -0,0 +1,464 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Audit trail of security relevant operations.
+//!
+//! Every record goes to `system.audit_log`, and if `query.audit_log_stage` is configured,
+//! also to a new JSON file of that stage, files are never overwritten.
+//!
+//! The records of a node are chained by sequence and hash, the chain is persisted in the
+//! meta service so that it continues after the node restarts.
+
+use std::sync::LazyLock;
+use std::time::SystemTime;
+
+use databend_common_base::base::tokio::sync::Mutex as AsyncMutex;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::GlobalConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PrincipalIdentity;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_types::UpsertKV;
+use databend_common_sql::plans::ModifyColumnAction;
+use databend_common_sql::plans::Plan;
+use databend_common_storage::init_stage_operator;
+use databend_common_storages_system::AuditEventType;
+use databend_common_storages_system::AuditLogElement;
+use databend_common_storages_system::AuditLogQueue;
+use databend_common_storages_system::AuditOutcome;
+use databend_common_users::UserApiProvider;
+use log::error;
+use log::info;
+use parking_lot::Mutex;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::auth::Credential;
+use crate::sessions::convert_query_log_timestamp;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+
+/// An auditable operation described by a plan.
+#[derive(Clone, Debug)]
+pub struct AuditEvent {
+    pub event_type: AuditEventType,
+    pub object_name: String,
+    // Rebuilt from the plan instead of the query text, which may contain passwords.
+    pub detail: String,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+struct AuditChain {
+    sequence: u64,
+    last_hash: String,
+}
+
+static AUDIT_CHAIN: LazyLock<Mutex<AuditChain>> = LazyLock::new(Default::default);
+
+// Serializes the writes of the chain to the meta service.
+static PERSIST_LOCK: LazyLock<AsyncMutex<()>> = LazyLock::new(Default::default);
+
+pub struct AuditLog;
+
+impl AuditLog {
+    /// Restores the audit chain of this node from the meta service.
+    #[async_backtrace::framed]
+    pub async fn init() -> Result<()> {
+        let meta_client = UserApiProvider::instance().get_meta_store_client();
+        if let Some(seq_v) = meta_client.get_kv(&chain_key()).await? {
+            *AUDIT_CHAIN.lock() = serde_json::from_slice(&seq_v.data)?;
+        }
+        Ok(())
+    }
+
+    /// Records an authentication attempt with a HTTP credential. The databend tokens
+    /// are checked on every request of a logged in session and are not audited.
+    pub fn log_credential_login(
+        session: &Session,
+        credential: &Credential,
+        authed_user: Option<&str>,
+        err: Option<&ErrorCode>,
+    ) {
+        match credential {
+            Credential::Password {
+                name, client_ip, ..
+            } => Self::log_login(session, name, client_ip.as_deref(), "password", err),
+            Credential::Jwt { client_ip, .. } => Self::log_login(
+                session,
+                authed_user.unwrap_or_default(),
+                client_ip.as_deref(),
+                "jwt",
+                err,
+            ),
+            Credential::DatabendToken { .. } | Credential::NoNeed => {}
+        }
+    }
+
+    /// Records an authentication attempt, a failure caused by the network policy
+    /// of the user is recorded as `NetworkPolicyReject`.
+    pub fn log_login(
+        session: &Session,
+        user_name: &str,
+        client_ip: Option<&str>,
+        detail: &str,
+        err: Option<&ErrorCode>,
+    ) {
+        let event_type = match err {
+            Some(e) if e.code() == ErrorCode::NETWORK_POLICY_REJECTED => {
+                AuditEventType::NetworkPolicyReject
+            }
+            _ => AuditEventType::Login,
+        };
+        let client_address = client_ip
+            .map(|ip| ip.to_string())
+            .or_else(|| session.get_client_host())
+            .unwrap_or_default();
+        let element = Self::new_element(
+            &session.get_current_tenant(),
+            event_type,
+            session.get_type().to_string(),
+            session.get_current_query_id().unwrap_or_default(),
+            UserIdentity::new(user_name, "%").display().to_string(),
+            client_address,
+            user_name.to_string(),
+            detail.to_string(),
+            err,
+        );
+        Self::write(session.get_current_tenant(), element)
+            .unwrap_or_else(|e| error!("fail to write audit_log {:?}", e));
+    }
+
+    /// Records the outcome of an auditable statement.
+    pub fn log_event(ctx: &QueryContext, event: &AuditEvent, err: Option<&ErrorCode>) {
+        let session = ctx.get_current_session();
+        let actor = match ctx.get_current_user() {
+            Ok(user) => user.identity().display().to_string(),
+            Err(_) => "".to_string(),
+        };
+        let element = Self::new_element(
+            &ctx.get_tenant(),
+            event.event_type,
+            session.get_type().to_string(),
+            ctx.get_id(),
+            actor,
+            ctx.get_client_address().unwrap_or_default(),
+            event.object_name.clone(),
+            event.detail.clone(),
+            err,
+        );
+        Self::write(ctx.get_tenant(), element)
+            .unwrap_or_else(|e| error!("fail to write audit_log {:?}", e));
+    }
+
+    /// Returns the audit event of the plan, `None` if the plan is not audited.
+    pub fn plan_event(plan: &Plan) -> Option<AuditEvent> {
+        let (event_type, object_name, detail) = match plan {
+            Plan::CreateUser(p) => (
+                AuditEventType::CreateUser,
+                p.user.display().to_string(),
+                format!(
+                    "CREATE USER {} IDENTIFIED WITH {}",
+                    p.user.display(),
+                    p.auth_info.get_type().to_str()
+                ),
+            ),
+            Plan::AlterUser(p) => {
+                let mut changes = vec![];
+                if let Some(auth_info) = &p.auth_info {
+                    changes.push(format!("IDENTIFIED WITH {}", auth_info.get_type().to_str()));
+                }
+                if p.user_option.is_some() {
+                    changes.push("WITH <options>".to_string());
+                }
+                (
+                    AuditEventType::AlterUser,
+                    p.user.display().to_string(),
+                    format!("ALTER USER {} {}", p.user.display(), changes.join(" ")),
+                )
+            }
+            Plan::DropUser(p) => (
+                AuditEventType::DropUser,
+                p.user.display().to_string(),
+                format!("DROP USER {}", p.user.display()),
+            ),
+            Plan::CreateRole(p) => (
+                AuditEventType::CreateRole,
+                p.role_name.clone(),
+                format!("CREATE ROLE '{}'", p.role_name),
+            ),
+            Plan::DropRole(p) => (
+                AuditEventType::DropRole,
+                p.role_name.clone(),
+                format!("DROP ROLE '{}'", p.role_name),
+            ),
+            Plan::GrantPriv(p) => (
+                AuditEventType::Grant,
+                principal_display(&p.principal),
+                format!(
+                    "GRANT {} ON {} TO {}",
+                    p.priv_types,
+                    p.on,
+                    principal_display(&p.principal)
+                ),
+            ),
+            Plan::GrantRole(p) => (
+                AuditEventType::Grant,
+                principal_display(&p.principal),
+                format!(
+                    "GRANT ROLE '{}' TO {}",
+                    p.role,
+                    principal_display(&p.principal)
+                ),
+            ),
+            Plan::RevokePriv(p) => (
+                AuditEventType::Revoke,
+                principal_display(&p.principal),
+                format!(
+                    "REVOKE {} ON {} FROM {}",
+                    p.priv_types,
+                    p.on.iter()
+                        .map(|on| on.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    principal_display(&p.principal)
+                ),
+            ),
+            Plan::RevokeRole(p) => (
+                AuditEventType::Revoke,
+                principal_display(&p.principal),
+                format!(
+                    "REVOKE ROLE '{}' FROM {}",
+                    p.role,
+                    principal_display(&p.principal)
+                ),
+            ),
+            Plan::CreateNetworkPolicy(p) => (
+                AuditEventType::CreateNetworkPolicy,
+                p.name.clone(),
+                format!(
+                    "CREATE NETWORK POLICY {} ALLOWED_IP_LIST = ({}) BLOCKED_IP_LIST = ({})",
+                    p.name,
+                    p.allowed_ip_list.join(", "),
+                    p.blocked_ip_list.join(", ")
+                ),
+            ),
+            Plan::AlterNetworkPolicy(p) => (
+                AuditEventType::AlterNetworkPolicy,
+                p.name.clone(),
+                format!(
+                    "ALTER NETWORK POLICY {} ALLOWED_IP_LIST = ({}) BLOCKED_IP_LIST = ({})",
+                    p.name,
+                    p.allowed_ip_list
+                        .as_ref()
+                        .map(|list| list.join(", "))
+                        .unwrap_or_default(),
+                    p.blocked_ip_list
+                        .as_ref()
+                        .map(|list| list.join(", "))
+                        .unwrap_or_default()
+                ),
+            ),
+            Plan::DropNetworkPolicy(p) => (
+                AuditEventType::DropNetworkPolicy,
+                p.name.clone(),
+                format!("DROP NETWORK POLICY {}", p.name),
+            ),
+            Plan::CreatePasswordPolicy(p) => (
+                AuditEventType::CreatePasswordPolicy,
+                p.name.clone(),
+                format!("CREATE PASSWORD POLICY {}", p.name),
+            ),
+            Plan::AlterPasswordPolicy(p) => (
+                AuditEventType::AlterPasswordPolicy,
+                p.name.clone(),
+                format!("ALTER PASSWORD POLICY {}", p.name),
+            ),
+            Plan::DropPasswordPolicy(p) => (
+                AuditEventType::DropPasswordPolicy,
+                p.name.clone(),
+                format!("DROP PASSWORD POLICY {}", p.name),
+            ),
+            Plan::CreateDatamaskPolicy(p) => (
+                AuditEventType::CreateDataMask,
+                p.name.clone(),
+                format!("CREATE MASKING POLICY {}", p.name),
+            ),
+            Plan::DropDatamaskPolicy(p) => (
+                AuditEventType::DropDataMask,
+                p.name.clone(),
+                format!("DROP MASKING POLICY {}", p.name),
+            ),
+            Plan::ModifyTableColumn(p) => {
+                let table = format!("{}.{}.{}", p.catalog, p.database, p.table);
+                match &p.action {
+                    ModifyColumnAction::SetMaskingPolicy(column, policy) => (
+                        AuditEventType::SetColumnDataMask,
+                        format!("{}.{}", table, column),
+                        format!(
+                            "ALTER TABLE {} MODIFY COLUMN {} SET MASKING POLICY {}",
+                            table, column, policy
+                        ),
+                    ),
+                    ModifyColumnAction::UnsetMaskingPolicy(column) => (
+                        AuditEventType::UnsetColumnDataMask,
+                        format!("{}.{}", table, column),
+                        format!(
+                            "ALTER TABLE {} MODIFY COLUMN {} UNSET MASKING POLICY",
+                            table, column
+                        ),
+                    ),
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+        Some(AuditEvent {
+            event_type,
+            object_name,
+            detail,
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn new_element(
+        tenant: &Tenant,
+        event_type: AuditEventType,
+        session_type: String,
+        query_id: String,
+        actor: String,
+        client_address: String,
+        object_name: String,
+        detail: String,
+        err: Option<&ErrorCode>,
+    ) -> AuditLogElement {
+        let config = GlobalConfig::instance();
+        let event_time = convert_query_log_timestamp(SystemTime::now());
+        let event_date = (event_time / (24 * 3_600_000_000)) as i32;
+        let (outcome, error_code, error_message) = match err {
+            None => (AuditOutcome::Success, 0, "".to_string()),
+            Some(e) => (AuditOutcome::Failure, e.code().into(), e.message()),
+        };
+        AuditLogElement {
+            event_date,
+            event_time,
+            event_type,
+            tenant_id: tenant.tenant_name().to_string(),
+            cluster_id: config.query.cluster_id.clone(),
+            node_id: config.query.node_id.clone(),
+            session_type,
+            query_id,
+            actor,
+            client_address,
+            object_name,
+            detail,
+            outcome,
+            error_code,
+            error_message,
+            sequence: 0,
+            prev_hash: "".to_string(),
+            hash: "".to_string(),
+        }
+    }
+
+    fn write(tenant: Tenant, mut element: AuditLogElement) -> Result<()> {
+        // Chain the record to the previous one, the lock is held until the record is
+        // queued so that the queue keeps the chain order.
+        let mut chain = AUDIT_CHAIN.lock();
+        chain.sequence += 1;
+        element.sequence = chain.sequence;
+        element.prev_hash = std::mem::take(&mut chain.last_hash);
+        element.hash = hex::encode(Sha256::digest(serde_json::to_vec(&element)?));
+        chain.last_hash = element.hash.clone();
+
+        let event_str = serde_json::to_string(&element)?;
+        info!(target: "databend::log::audit", "{}", event_str);
+        AuditLogQueue::instance()?.append_data(element.clone())?;
+        drop(chain);
+
+        // The chain is persisted before the stage file is written, so a restarted node
+        // never reuses the sequence of a file.
+        let stage_name = GlobalConfig::instance().query.audit_log_stage.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            if let Err(e) = persist_chain().await {
+                error!("fail to persist audit_log chain: {:?}", e);
+                return;
+            }
+            if stage_name.is_empty() {
+                return;
+            }
+            if let Err(e) = write_to_stage(&tenant, &stage_name, &element, event_str).await {
+                error!(
+                    "fail to write audit_log {} to stage {}: {:?}",
+                    element.sequence, stage_name, e
+                );
+            }
+        });
+        Ok(())
+    }
+}
+
+fn chain_key() -> String {
+    let config = GlobalConfig::instance();
+    format!(
+        "__fd_audit_chain/{}/{}",
+        config.query.cluster_id, config.query.node_id
+    )
+}
+
+// Writes the latest state of the chain, the writes are serialized so an older state
+// never overwrites a newer one.
+async fn persist_chain() -> Result<()> {
+    let _guard = PERSIST_LOCK.lock().await;
+    let chain = AUDIT_CHAIN.lock().clone();
+    let meta_client = UserApiProvider::instance().get_meta_store_client();
+    meta_client
+        .upsert_kv(UpsertKV::update(chain_key(), &serde_json::to_vec(&chain)?))
+        .await?;
+    Ok(())
+}
+
+// Each record is a new file named by node and sequence, so the stage is only appended to.
+async fn write_to_stage(
+    tenant: &Tenant,
+    stage_name: &str,
+    element: &AuditLogElement,
+    event_str: String,
+) -> Result<()> {
+    let stage_info = UserApiProvider::instance()
+        .get_stage(tenant, stage_name)
+        .await?;
+    let operator = init_stage_operator(&stage_info)?;
+    let date = chrono::DateTime::from_timestamp(element.event_time / 1_000_000, 0)
+        .unwrap_or_default()
+        .format("%Y-%m-%d");
+    let path = format!(
+        "audit_log/{}/{}_{:020}.json",
+        date, element.node_id, element.sequence
+    );
+    operator.write(&path, event_str + "\n").await?;
+    Ok(())
+}
+
+fn principal_display(principal: &PrincipalIdentity) -> String {
+    match principal {
+        PrincipalIdentity::User(user) => format!("USER {}", user.display()),
+        PrincipalIdentity::Role(role) => format!("ROLE '{}'", role),
+    }
+}
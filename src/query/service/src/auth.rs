@@ -133,7 +133,7 @@ impl AuthMgr {
                     Err(e) => {
                         match e.code() {
                             ErrorCode::UNKNOWN_USER => {}
-                            ErrorCode::META_SERVICE_ERROR => {
+                            ErrorCode::META_SERVICE_ERROR | ErrorCode::NETWORK_POLICY_REJECTED => {
                                 return Err(e);
                             }
                             _ => return Err(ErrorCode::AuthenticateFailure(e.message())),
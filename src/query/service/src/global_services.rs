@@ -37,6 +37,7 @@ use databend_common_users::UserApiProvider;
 use databend_storages_common_cache::CacheManager;
 use databend_storages_common_cache::TempDirManager;
 
+use crate::audit::AuditLog;
 use crate::auth::AuthMgr;
 use crate::builtin::BuiltinUDFs;
 use crate::builtin::BuiltinUsers;
@@ -50,6 +51,7 @@ use crate::servers::http::v1::ClientSessionManager;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::SessionManager;
+use crate::sessions::WorkloadGroupResourceManager;
 
 pub struct GlobalServices;
 
@@ -107,6 +109,7 @@ impl GlobalServices {
         }
 
         QueriesQueueManager::init(config.query.max_running_queries as usize)?;
+        WorkloadGroupResourceManager::init()?;
         HttpQueryManager::init(config).await?;
         ClientSessionManager::init(config).await?;
         DataExchangeManager::init()?;
@@ -135,6 +138,7 @@ impl GlobalServices {
         }
 
         RoleCacheManager::init()?;
+        AuditLog::init().await?;
 
         DataOperator::init(&config.storage).await?;
         ShareTableConfig::init(
@@ -61,6 +61,7 @@ impl AccessChecker for ManagementModeAccess {
                             | RewriteKind::ListStage
                             | RewriteKind::Call
                             | RewriteKind::ShowRoles
+                            | RewriteKind::ShowWorkloadGroups
                             | RewriteKind::ShowLocks
                             | RewriteKind::ShowStreams(_)),
                             _ => false
@@ -98,6 +99,7 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::ShowRoles(_)
                 | Plan::CreateRole(_)
                 | Plan::DropRole(_)
+                | Plan::AlterRole(_)
 
                 // Privilege.
                 | Plan::GrantPriv(_)
@@ -115,6 +117,10 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreatePasswordPolicy(_)
                 | Plan::AlterPasswordPolicy(_)
                 | Plan::DropPasswordPolicy(_)
+                // Workload group.
+                | Plan::CreateWorkloadGroup(_)
+                | Plan::AlterWorkloadGroup(_)
+                | Plan::DropWorkloadGroup(_)
 
                 // UDF
                 | Plan::CreateUDF(_)
@@ -940,6 +940,10 @@ impl AccessChecker for PrivilegeAccess {
                     self.check(ctx, query).await?;
                 }
             }
+            Plan::CloneTable(plan) => {
+                self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Create, false).await?;
+                self.validate_table_access(&plan.source_catalog, &plan.source_database, &plan.source_table, UserPrivilegeType::Select, false, false).await?
+            }
             Plan::DropTable(plan) => {
                 // For attach table
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Drop, plan.if_exists, true).await?;
@@ -967,6 +971,12 @@ impl AccessChecker for PrivilegeAccess {
             Plan::UnsetOptions(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
             }
+            Plan::CreateTableRef(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
+            Plan::DropTableRef(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
             Plan::AddTableColumn(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
             }
@@ -982,6 +992,12 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropTableColumn(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
             }
+            Plan::AddTableConstraint(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
+            Plan::DropTableConstraint(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
             Plan::AlterTableClusterKey(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
             }
@@ -996,6 +1012,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::TruncateTable(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Delete, false, false).await?
             }
+            Plan::WarmTableCache(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Select, false, false).await?
+            }
             Plan::OptimizePurge(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Super, false, false).await?
             },
@@ -1217,6 +1236,10 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::AlterPasswordPolicy(_)
             | Plan::DropPasswordPolicy(_)
             | Plan::DescPasswordPolicy(_)
+            | Plan::CreateWorkloadGroup(_)
+            | Plan::AlterWorkloadGroup(_)
+            | Plan::DropWorkloadGroup(_)
+            | Plan::AlterRole(_)
             | Plan::CreateConnection(_)
             | Plan::ShowConnections(_)
             | Plan::DescConnection(_)
@@ -1252,6 +1275,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::SetRole(_) => {}
             Plan::SetSecondaryRoles(_) => {}
             Plan::ShowRoles(_) => {}
+            // The privileges of a prepared statement are checked each time it is executed.
+            Plan::Prepare(_) => {}
+            Plan::Deallocate(_) => {}
             Plan::Presign(plan) => {
                 let privilege = match &plan.action {
                     PresignAction::Upload => UserPrivilegeType::Write,
@@ -29,6 +29,8 @@ use databend_common_sql::TableEntry;
 use databend_common_storages_factory::Table;
 use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::TableContext;
+use databend_common_storages_kafka::KafkaTable;
+use databend_common_storages_kafka::KAFKA_ENGINE;
 use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
@@ -44,15 +46,16 @@ pub async fn dml_build_update_stream_req(
     ctx: Arc<QueryContext>,
     metadata: &MetadataRef,
 ) -> Result<Vec<UpdateStreamMetaReq>> {
+    let mut reqs = dml_build_consume_kafka_req(metadata).await?;
+
     let tables = get_stream_table(metadata, |t| t.table().engine() == STREAM_ENGINE)?;
     if tables.is_empty() {
-        return Ok(vec![]);
+        return Ok(reqs);
     }
 
     LicenseManagerSwitch::instance()
         .check_enterprise_enabled(ctx.get_license_key(), Feature::Stream)?;
 
-    let mut reqs = Vec::with_capacity(tables.len());
     for table in tables.into_iter() {
         let stream = StreamTable::try_from_table(table.as_ref())?;
         let stream_info = stream.get_table_info();
@@ -96,6 +99,24 @@ pub async fn dml_build_update_stream_req(
     Ok(reqs)
 }
 
+/// Advance the committed offsets of the kafka tables read by the DML to the offsets it read up to,
+/// in the same meta transaction that commits the target table.
+async fn dml_build_consume_kafka_req(metadata: &MetadataRef) -> Result<Vec<UpdateStreamMetaReq>> {
+    let tables = get_stream_table(metadata, |t| t.table().engine() == KAFKA_ENGINE)?;
+
+    let mut reqs = Vec::with_capacity(tables.len());
+    for table in tables.into_iter() {
+        let kafka = KafkaTable::try_from_table(table.as_ref())?;
+        let table_info = kafka.get_table_info();
+        reqs.push(UpdateStreamMetaReq {
+            stream_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            options: kafka.consumed_options().await?,
+        });
+    }
+    Ok(reqs)
+}
+
 fn get_stream_table<F>(metadata: &MetadataRef, pred: F) -> Result<Vec<Arc<dyn Table>>>
 where F: Fn(&TableEntry) -> bool {
     let r_lock = metadata.read();
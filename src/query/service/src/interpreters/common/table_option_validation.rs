@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashSet;
+use std::str::FromStr;
 use std::sync::LazyLock;
 
 use chrono::Duration;
@@ -23,12 +24,16 @@ use databend_common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use databend_common_io::constants::DEFAULT_MIN_TABLE_LEVEL_DATA_RETENTION_PERIOD_IN_HOURS;
 use databend_common_settings::Settings;
 use databend_common_sql::BloomIndexColumns;
+use databend_common_storages_fuse::FuseStorageFormat;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use databend_common_storages_fuse::FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS;
+use databend_common_storages_fuse::FUSE_OPT_KEY_ENABLE_DELETION_VECTORS;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
+use databend_common_storages_kafka::KafkaTable;
+use databend_common_storages_kafka::KAFKA_ENGINE;
 use databend_storages_common_index::BloomIndex;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
@@ -37,11 +42,16 @@ use databend_storages_common_table_meta::table::OPT_KEY_COMMENT;
 use databend_storages_common_table_meta::table::OPT_KEY_CONNECTION_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE;
+use databend_storages_common_table_meta::table::OPT_KEY_KAFKA_AVRO_SCHEMA;
+use databend_storages_common_table_meta::table::OPT_KEY_KAFKA_BROKERS;
+use databend_storages_common_table_meta::table::OPT_KEY_KAFKA_FORMAT;
+use databend_storages_common_table_meta::table::OPT_KEY_KAFKA_TOPIC;
 use databend_storages_common_table_meta::table::OPT_KEY_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_SEED;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 use log::error;
 
 /// Table option keys that can occur in 'create table statement'.
@@ -53,6 +63,7 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
     r.insert(FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS);
+    r.insert(FUSE_OPT_KEY_ENABLE_DELETION_VECTORS);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
@@ -61,6 +72,7 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
     r.insert(OPT_KEY_COMMENT);
     r.insert(OPT_KEY_CHANGE_TRACKING);
     r.insert(OPT_KEY_CLUSTER_TYPE);
+    r.insert(OPT_KEY_TTL);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -69,6 +81,11 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
 
     r.insert(OPT_KEY_RANDOM_SEED);
 
+    r.insert(OPT_KEY_KAFKA_BROKERS);
+    r.insert(OPT_KEY_KAFKA_TOPIC);
+    r.insert(OPT_KEY_KAFKA_FORMAT);
+    r.insert(OPT_KEY_KAFKA_AVRO_SCHEMA);
+
     r.insert("transient");
     r.insert(OPT_KEY_TEMP_PREFIX);
     r
@@ -168,6 +185,40 @@ pub fn is_valid_change_tracking(
     Ok(())
 }
 
+/// Deletion vectors are not supported by the native storage format, and the
+/// streams of a table can't see the rows deleted by them.
+pub fn is_valid_deletion_vectors(
+    options: &BTreeMap<String, String>,
+) -> databend_common_exception::Result<()> {
+    let Some(value) = options.get(FUSE_OPT_KEY_ENABLE_DELETION_VECTORS) else {
+        return Ok(());
+    };
+    if !value.to_lowercase().parse::<bool>()? {
+        return Ok(());
+    }
+
+    if let Some(storage_format) = options.get(OPT_KEY_STORAGE_FORMAT) {
+        if matches!(
+            FuseStorageFormat::from_str(storage_format)?,
+            FuseStorageFormat::Native
+        ) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "{} is not supported by the native storage format",
+                FUSE_OPT_KEY_ENABLE_DELETION_VECTORS
+            )));
+        }
+    }
+    if let Some(value) = options.get(OPT_KEY_CHANGE_TRACKING) {
+        if value.to_lowercase().parse::<bool>()? {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "{} can't be enabled together with {}",
+                FUSE_OPT_KEY_ENABLE_DELETION_VECTORS, OPT_KEY_CHANGE_TRACKING
+            )));
+        }
+    }
+    Ok(())
+}
+
 pub fn is_valid_random_seed(
     options: &BTreeMap<String, String>,
 ) -> databend_common_exception::Result<()> {
@@ -176,3 +227,13 @@ pub fn is_valid_random_seed(
     }
     Ok(())
 }
+
+pub fn is_valid_kafka_options(
+    engine: &str,
+    options: &BTreeMap<String, String>,
+) -> databend_common_exception::Result<()> {
+    if engine == KAFKA_ENGINE {
+        KafkaTable::check_options(options)?;
+    }
+    Ok(())
+}
@@ -64,6 +64,8 @@ use crate::sessions::QueriesQueueManager;
 use crate::sessions::QueryContext;
 use crate::sessions::QueryEntry;
 use crate::sessions::SessionManager;
+use crate::sessions::WorkloadGroupResource;
+use crate::sessions::WorkloadGroupResourceManager;
 use crate::stream::DataBlockStream;
 use crate::stream::ProgressStream;
 use crate::stream::PullingExecutorStream;
@@ -259,19 +261,44 @@ async fn plan_sql(
         // If a lock is required, acquire the queue guard before
         // planning the statement, to avoid potential deadlocks.
         // See PR https://github.com/databendlabs/databend/pull/16632
-        let query_entry = QueryEntry::create_entry(&ctx, &extras, true)?;
+        let mut query_entry = QueryEntry::create_entry(&ctx, &extras, true)?;
+        query_entry.workload_group = resolve_workload_group(&ctx).await?;
         let guard = QueriesQueueManager::instance().acquire(query_entry).await?;
         let plan = planner.plan_stmt(&extras.statement).await?;
         Ok((plan, extras, guard))
     } else {
         // No lock is needed, plan the statement first, then acquire the queue guard.
         let plan = planner.plan_stmt(&extras.statement).await?;
-        let query_entry = QueryEntry::create(&ctx, &plan, &extras)?;
+        let mut query_entry = QueryEntry::create(&ctx, &plan, &extras)?;
+        query_entry.workload_group = resolve_workload_group(&ctx).await?;
         let guard = QueriesQueueManager::instance().acquire(query_entry).await?;
         Ok((plan, extras, guard))
     }
 }
 
+/// The workload group of the user, or else of the current role.
+async fn resolve_workload_group(
+    ctx: &Arc<QueryContext>,
+) -> Result<Option<Arc<WorkloadGroupResource>>> {
+    let user = ctx.get_current_user()?;
+    let name = match user.option.workload_group() {
+        Some(name) => Some(name.clone()),
+        None => ctx
+            .get_current_role()
+            .and_then(|role| role.workload_group.clone()),
+    };
+
+    let Some(name) = name else {
+        return Ok(None);
+    };
+
+    let tenant = ctx.get_tenant();
+    let group = WorkloadGroupResourceManager::instance()
+        .resolve(&tenant, &name)
+        .await?;
+    Ok(Some(group))
+}
+
 fn attach_query_hash(ctx: &Arc<QueryContext>, stmt: &mut Option<Statement>, sql: &str) {
     let (query_hash, query_parameterized_hash) = if let Some(stmt) = stmt {
         let query_hash = format!("{:x}", Md5::digest(stmt.to_string()));
@@ -359,7 +386,9 @@ fn need_acquire_lock(ctx: Arc<QueryContext>, stmt: &Statement) -> bool {
         | Statement::TruncateTable(_) => true,
         Statement::OptimizeTable(OptimizeTableStmt { action, .. }) => matches!(
             action,
-            OptimizeTableAction::All | OptimizeTableAction::Compact { .. }
+            OptimizeTableAction::All
+                | OptimizeTableAction::Compact { .. }
+                | OptimizeTableAction::Expire
         ),
         Statement::AlterTable(AlterTableStmt { action, .. }) => matches!(
             action,
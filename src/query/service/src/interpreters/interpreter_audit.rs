@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::SendableDataBlockStream;
+use databend_common_pipeline_core::SourcePipeBuilder;
+
+use crate::audit::AuditEvent;
+use crate::audit::AuditLog;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+/// Wraps the interpreter of an audited statement and records its outcome.
+///
+/// Audited statements are all executed without pipeline, so the outcome is known
+/// once `execute` returns.
+pub struct AuditInterpreter {
+    inner: InterpreterPtr,
+    event: AuditEvent,
+}
+
+impl AuditInterpreter {
+    pub fn create(inner: InterpreterPtr, event: AuditEvent) -> Self {
+        AuditInterpreter { inner, event }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AuditInterpreter {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn is_txn_command(&self) -> bool {
+        self.inner.is_txn_command()
+    }
+
+    fn is_ddl(&self) -> bool {
+        self.inner.is_ddl()
+    }
+
+    #[async_backtrace::framed]
+    async fn execute(&self, ctx: Arc<QueryContext>) -> Result<SendableDataBlockStream> {
+        let res = self.inner.execute(ctx.clone()).await;
+        AuditLog::log_event(&ctx, &self.event, res.as_ref().err());
+        res
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        self.inner.execute2().await
+    }
+
+    fn set_source_pipe_builder(&self, builder: Option<SourcePipeBuilder>) -> Result<()> {
+        self.inner.set_source_pipe_builder(builder)
+    }
+
+    fn inject_result(&self) -> Result<SendableDataBlockStream> {
+        self.inner.inject_result()
+    }
+}
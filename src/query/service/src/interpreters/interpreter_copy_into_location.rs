@@ -111,6 +111,7 @@ impl CopyIntoLocationInterpreter {
                 duplicated_files_detected: vec![],
                 is_select: false,
                 default_values: None,
+                check_constraints: vec![],
                 copy_into_location_options: options.clone(),
             },
         }));
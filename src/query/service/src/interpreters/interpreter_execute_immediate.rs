@@ -65,7 +65,7 @@ impl Interpreter for ExecuteImmediateInterpreter {
             let settings = self.ctx.get_settings();
             let sql_dialect = settings.get_sql_dialect()?;
             let tokens = tokenize_sql(&self.plan.script)?;
-            let mut ast = run_parser(
+            let ast = run_parser(
                 &tokens,
                 sql_dialect,
                 ParseMode::Template,
@@ -80,9 +80,16 @@ impl Interpreter for ExecuteImmediateInterpreter {
                     DeclareItem::Set(declare) => {
                         src.push(ScriptStatement::LetStatement { declare })
                     }
+                    DeclareItem::Cursor(declare) => {
+                        src.push(ScriptStatement::LetCursor { declare })
+                    }
                 }
             }
-            src.append(&mut ast.body);
+            src.push(ScriptStatement::Block {
+                span: ast.span,
+                body: ast.body,
+                exception_handlers: ast.exception_handlers,
+            });
             let compiled = compile(&src)?;
 
             let client = ScriptClient {
@@ -21,6 +21,7 @@ use databend_common_exception::Result;
 use databend_common_sql::binder::ExplainConfig;
 use log::error;
 
+use super::interpreter_audit::AuditInterpreter;
 use super::interpreter_catalog_create::CreateCatalogInterpreter;
 use super::interpreter_catalog_show_create::ShowCreateCatalogInterpreter;
 use super::interpreter_dictionary_create::CreateDictionaryInterpreter;
@@ -35,6 +36,7 @@ use super::interpreter_table_index_refresh::RefreshTableIndexInterpreter;
 use super::interpreter_table_set_options::SetOptionsInterpreter;
 use super::interpreter_user_stage_drop::DropUserStageInterpreter;
 use super::*;
+use crate::audit::AuditLog;
 use crate::interpreters::access::Accessor;
 use crate::interpreters::interpreter_catalog_drop::DropCatalogInterpreter;
 use crate::interpreters::interpreter_connection_create::CreateConnectionInterpreter;
@@ -58,6 +60,8 @@ use crate::interpreters::interpreter_role_show::ShowRolesInterpreter;
 use crate::interpreters::interpreter_set_priority::SetPriorityInterpreter;
 use crate::interpreters::interpreter_system_action::SystemActionInterpreter;
 use crate::interpreters::interpreter_table_create::CreateTableInterpreter;
+use crate::interpreters::interpreter_table_ref_create::CreateTableRefInterpreter;
+use crate::interpreters::interpreter_table_ref_drop::DropTableRefInterpreter;
 use crate::interpreters::interpreter_table_revert::RevertTableInterpreter;
 use crate::interpreters::interpreter_table_unset_options::UnsetOptionsInterpreter;
 use crate::interpreters::interpreter_task_alter::AlterTaskInterpreter;
@@ -87,19 +91,25 @@ pub struct InterpreterFactory;
 impl InterpreterFactory {
     #[async_backtrace::framed]
     pub async fn get(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
+        let audit_event = AuditLog::plan_event(plan);
+
         // Check the access permission.
         let access_checker = Accessor::create(ctx.clone());
-        access_checker
-            .check(plan)
-            .await
-            .map_err(|e| match e.code() {
-                ErrorCode::PERMISSION_DENIED => {
-                    error!("Access.denied(v2): {:?}", e);
-                    e
-                }
-                _ => e,
-            })?;
-        Self::get_inner(ctx, plan)
+        access_checker.check(plan).await.map_err(|e| {
+            if e.code() == ErrorCode::PERMISSION_DENIED {
+                error!("Access.denied(v2): {:?}", e);
+            }
+            if let Some(event) = &audit_event {
+                AuditLog::log_event(&ctx, event, Some(&e));
+            }
+            e
+        })?;
+
+        let interpreter = Self::get_inner(ctx, plan)?;
+        match audit_event {
+            Some(event) => Ok(Arc::new(AuditInterpreter::create(interpreter, event))),
+            None => Ok(interpreter),
+        }
     }
 
     pub fn get_inner(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
@@ -206,6 +216,10 @@ impl InterpreterFactory {
                 ctx,
                 *create_table.clone(),
             )?)),
+            Plan::CloneTable(clone_table) => Ok(Arc::new(CloneTableInterpreter::try_create(
+                ctx,
+                *clone_table.clone(),
+            )?)),
             Plan::DropTable(drop_table) => Ok(Arc::new(DropTableInterpreter::try_create(
                 ctx,
                 *drop_table.clone(),
@@ -241,6 +255,12 @@ impl InterpreterFactory {
             Plan::DropTableColumn(drop_table_column) => Ok(Arc::new(
                 DropTableColumnInterpreter::try_create(ctx, *drop_table_column.clone())?,
             )),
+            Plan::AddTableConstraint(plan) => Ok(Arc::new(
+                AddTableConstraintInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::DropTableConstraint(plan) => Ok(Arc::new(
+                DropTableConstraintInterpreter::try_create(ctx, *plan.clone())?,
+            )),
             Plan::AlterTableClusterKey(alter_table_cluster_key) => Ok(Arc::new(
                 AlterTableClusterKeyInterpreter::try_create(ctx, *alter_table_cluster_key.clone())?,
             )),
@@ -258,6 +278,9 @@ impl InterpreterFactory {
             Plan::TruncateTable(truncate_table) => Ok(Arc::new(
                 TruncateTableInterpreter::try_create(ctx, *truncate_table.clone())?,
             )),
+            Plan::WarmTableCache(warm_cache) => Ok(Arc::new(
+                WarmTableCacheInterpreter::try_create(ctx, *warm_cache.clone())?,
+            )),
             Plan::OptimizePurge(purge) => Ok(Arc::new(OptimizePurgeInterpreter::try_create(
                 ctx,
                 *purge.clone(),
@@ -394,6 +417,10 @@ impl InterpreterFactory {
                 ctx,
                 *drop_role.clone(),
             )?)),
+            Plan::AlterRole(alter_role) => Ok(Arc::new(AlterRoleInterpreter::try_create(
+                ctx,
+                *alter_role.clone(),
+            )?)),
             Plan::SetRole(set_role) => Ok(Arc::new(SetRoleInterpreter::try_create(
                 ctx,
                 *set_role.clone(),
@@ -474,11 +501,24 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
             Plan::Kill(p) => Ok(Arc::new(KillInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::Prepare(p) => Ok(Arc::new(PrepareInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::Deallocate(p) => Ok(Arc::new(DeallocateInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
 
             Plan::RevertTable(p) => Ok(Arc::new(RevertTableInterpreter::try_create(
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateTableRef(p) => Ok(Arc::new(CreateTableRefInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropTableRef(p) => Ok(Arc::new(DropTableRefInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
             Plan::CreateDatamaskPolicy(p) => Ok(Arc::new(CreateDataMaskInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -524,6 +564,17 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateWorkloadGroup(p) => Ok(Arc::new(
+                CreateWorkloadGroupInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AlterWorkloadGroup(p) => Ok(Arc::new(AlterWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropWorkloadGroup(p) => Ok(Arc::new(DropWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
 
             Plan::CreateTask(p) => Ok(Arc::new(CreateTaskInterpreter::try_create(
                 ctx,
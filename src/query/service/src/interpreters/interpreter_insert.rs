@@ -15,7 +15,9 @@
 use std::sync::Arc;
 
 use databend_common_catalog::lock::LockTableOption;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::TableExt;
+use databend_common_catalog::table::TimeNavigation;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
@@ -29,6 +31,7 @@ use databend_common_sql::plans::InsertInputSource;
 use databend_common_sql::plans::InsertValue;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::NameResolutionContext;
+use databend_storages_common_table_meta::table::TableRefKind;
 use log::info;
 
 use crate::interpreters::common::check_deduplicate_label;
@@ -103,6 +106,22 @@ impl Interpreter for InsertInterpreter {
         // check mutability
         table.check_mutable()?;
 
+        let table = match &self.plan.branch {
+            Some(branch) => {
+                let point = NavigationPoint::TableRef {
+                    kind: TableRefKind::Branch,
+                    name: branch.clone(),
+                };
+                table
+                    .navigate_to(
+                        &TimeNavigation::TimeTravel(point),
+                        self.ctx.clone().get_abort_checker(),
+                    )
+                    .await?
+            }
+            None => table,
+        };
+
         let mut build_res = PipelineBuildResult::create();
 
         match &self.plan.source {
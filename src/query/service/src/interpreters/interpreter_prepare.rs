@@ -0,0 +1,53 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_sql::plans::PreparePlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct PrepareInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: PreparePlan,
+}
+
+impl PrepareInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: PreparePlan) -> Result<Self> {
+        Ok(PrepareInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for PrepareInterpreter {
+    fn name(&self) -> &str {
+        "PrepareInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // A statement prepared again with the same name replaces the previous one.
+        self.ctx
+            .set_prepared_statement(self.plan.name.clone(), self.plan.sql.clone());
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -33,6 +33,7 @@ use databend_common_script::ReturnValue;
 use databend_common_sql::plans::CallProcedurePlan;
 use databend_common_storages_fuse::TableContext;
 
+use crate::interpreters::procedure_script::ScriptProcedure;
 use crate::interpreters::util::ScriptClient;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -63,6 +64,14 @@ impl Interpreter for CallProcedureInterpreter {
     #[fastrace::trace]
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if self.plan.language != "SQL" {
+            let procedure = ScriptProcedure::try_create(self.ctx.clone(), &self.plan)?;
+            let lines = procedure.execute(&self.plan.args).await?;
+            return PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+                StringType::from_data(lines),
+            ])]);
+        }
+
         let res: Result<_> = try {
             let mut src = vec![];
             for (arg, arg_name) in self.plan.args.iter().zip(self.plan.arg_names.iter()) {
@@ -77,7 +86,7 @@ impl Interpreter for CallProcedureInterpreter {
             let settings = self.ctx.get_settings();
             let sql_dialect = settings.get_sql_dialect()?;
             let tokens = tokenize_sql(&self.plan.script)?;
-            let mut ast = run_parser(
+            let ast = run_parser(
                 &tokens,
                 sql_dialect,
                 ParseMode::Template,
@@ -91,9 +100,16 @@ impl Interpreter for CallProcedureInterpreter {
                     DeclareItem::Set(declare) => {
                         src.push(ScriptStatement::LetStatement { declare })
                     }
+                    DeclareItem::Cursor(declare) => {
+                        src.push(ScriptStatement::LetCursor { declare })
+                    }
                 }
             }
-            src.append(&mut ast.body);
+            src.push(ScriptStatement::Block {
+                span: ast.span,
+                body: ast.body,
+                exception_handlers: ast.exception_handlers,
+            });
             let compiled = compile(&src)?;
 
             let client = ScriptClient {
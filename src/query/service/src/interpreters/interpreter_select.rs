@@ -16,7 +16,6 @@ use std::sync::Arc;
 
 use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_catalog::table::Table;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::infer_table_schema;
 use databend_common_expression::DataField;
@@ -232,21 +231,18 @@ impl SelectInterpreter {
         Ok(())
     }
 
-    fn result_scan_table(&self) -> Result<Option<Arc<dyn Table>>> {
+    /// Returns the `RESULT_SCAN` tables referenced by the query, together with a flag
+    /// telling whether the query reads from other tables as well.
+    fn result_scan_tables(&self) -> (Vec<Arc<dyn Table>>, bool) {
         let r_lock = self.metadata.read();
         let tables = r_lock.tables();
-        for t in tables {
-            if t.name().eq_ignore_ascii_case("result_scan") {
-                return if tables.len() > 1 {
-                    Err(ErrorCode::Unimplemented(
-                        "The current `RESULT_SCAN` only supports single table queries",
-                    ))
-                } else {
-                    Ok(Some(t.table()))
-                };
-            }
-        }
-        Ok(None)
+        let result_scans = tables
+            .iter()
+            .filter(|t| t.name().eq_ignore_ascii_case("result_scan"))
+            .map(|t| t.table())
+            .collect::<Vec<_>>();
+        let mixed = result_scans.len() != tables.len();
+        (result_scans, mixed)
     }
 
     fn attach_tables_to_ctx(&self) {
@@ -308,12 +304,20 @@ impl Interpreter for SelectInterpreter {
             // If we read cache for 5, we will see it returns same result as 1 and 2 cause the
             // generated result_cache_key are same for this statement, so here we fetch the previous
             // meta_key through related query_id and set this meta_key with current query_id.
-            if let Some(t) = self.result_scan_table()? {
-                let arg_query_id = parse_result_scan_args(&t.table_args().unwrap())?;
-                let meta_key = self.ctx.get_result_cache_key(&arg_query_id);
-                if let Some(meta_key) = meta_key {
-                    self.ctx
-                        .set_query_id_result_cache(self.ctx.get_id(), meta_key);
+            //
+            // If `RESULT_SCAN` is joined, unioned or filtered together with other tables, the
+            // formatted statement no longer identifies the result it reads from (the query id
+            // argument is resolved per session), so the result cache is bypassed entirely.
+            let (result_scans, mixed) = self.result_scan_tables();
+            if !result_scans.is_empty() {
+                if !mixed && result_scans.len() == 1 {
+                    let t = &result_scans[0];
+                    let arg_query_id = parse_result_scan_args(&t.table_args().unwrap())?;
+                    let meta_key = self.ctx.get_result_cache_key(&arg_query_id);
+                    if let Some(meta_key) = meta_key {
+                        self.ctx
+                            .set_query_id_result_cache(self.ctx.get_id(), meta_key);
+                    }
                 }
                 return self.build_pipeline(physical_plan).await;
             }
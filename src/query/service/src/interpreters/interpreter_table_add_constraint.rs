@@ -0,0 +1,121 @@
+// Copyright 2024 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::AddTableConstraintPlan;
+use databend_common_sql::Planner;
+use futures_util::TryStreamExt;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AddTableConstraintInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AddTableConstraintPlan,
+}
+
+impl AddTableConstraintInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AddTableConstraintPlan) -> Result<Self> {
+        Ok(AddTableConstraintInterpreter { ctx, plan })
+    }
+
+    /// Counts the existing rows for which the check expression is FALSE.
+    async fn count_violations(&self, expr: &str) -> Result<u64> {
+        let sql = format!(
+            "SELECT count(*) FROM `{}`.`{}`.`{}` WHERE NOT ({})",
+            self.plan.catalog, self.plan.database, self.plan.table, expr
+        );
+        let ctx = self
+            .ctx
+            .get_current_session()
+            .create_query_context()
+            .await?;
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_sql(&sql).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        Ok(blocks
+            .first()
+            .and_then(|block| block.get_by_offset(0).value.index(0))
+            .and_then(|count| count.as_number().and_then(|n| n.as_u_int64()).copied())
+            .unwrap_or_default())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AddTableConstraintInterpreter {
+    fn name(&self) -> &str {
+        "AddTableConstraintInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(
+                &self.ctx.get_tenant(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?;
+        table.check_mutable()?;
+
+        let table_info = table.get_table_info();
+        if table_info.meta.constraints.contains_key(&self.plan.name) {
+            return Err(ErrorCode::BadArguments(format!(
+                "constraint `{}` already exists",
+                self.plan.name
+            )));
+        }
+
+        // The rows already in the table must satisfy the new constraint.
+        if let Some(expr) = self.plan.constraint.check_expr() {
+            let violations = self.count_violations(expr).await?;
+            if violations > 0 {
+                return Err(ErrorCode::ConstraintViolated(format!(
+                    "Check constraint {} ({}) is violated by {} existing row(s) of table {}",
+                    self.plan.name, expr, violations, self.plan.table
+                )));
+            }
+        }
+
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta
+            .constraints
+            .insert(self.plan.name.clone(), self.plan.constraint.clone());
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+        };
+        catalog.update_single_table_meta(req, table_info).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -329,6 +329,7 @@ fn remove_exchange(plan: PhysicalPlan) -> PhysicalPlan {
                 input: Box::new(traverse(*plan.input)),
                 order_by: plan.order_by,
                 limit: plan.limit,
+                limit_with_ties: plan.limit_with_ties,
                 after_exchange: plan.after_exchange,
                 pre_projection: plan.pre_projection,
                 stat_info: plan.stat_info,
@@ -0,0 +1,164 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::schema::CommitTableMetaReq;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateTableReq;
+use databend_common_meta_app::schema::TableNameIdent;
+use databend_common_sql::plans::CloneTablePlan;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use log::info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+// The source is written concurrently, pinning the snapshot is retried on the new versions.
+const MAX_PIN_RETRIES: usize = 10;
+
+pub struct CloneTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CloneTablePlan,
+}
+
+impl CloneTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CloneTablePlan) -> Result<Self> {
+        Ok(CloneTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CloneTableInterpreter {
+    fn name(&self) -> &str {
+        "CloneTableInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+
+        // currently, context caches the table, we have to "refresh"
+        // the table by using the catalog API directly
+        let mut source = self
+            .ctx
+            .get_catalog(&self.plan.source_catalog)
+            .await?
+            .get_table(&tenant, &self.plan.source_database, &self.plan.source_table)
+            .await?;
+        let (mut table_meta, snapshot_location) = FuseTable::try_from_table(source.as_ref())?
+            .build_clone_table_meta(self.ctx.clone(), self.plan.point.as_ref())
+            .await?;
+
+        let db = catalog.get_database(&tenant, &self.plan.database).await?;
+        let db_id = db.get_db_info().database_id.db_id;
+        table_meta
+            .options
+            .insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
+
+        // The clone is created as a dropped table, and becomes visible once the snapshot it
+        // shares is pinned by the source. If pinning fails, the clone is left to the vacuum.
+        table_meta.drop_on = Some(Utc::now());
+        let req = CreateTableReq {
+            create_option: self.plan.create_option,
+            name_ident: TableNameIdent {
+                tenant: self.plan.tenant.clone(),
+                db_name: self.plan.database.clone(),
+                table_name: self.plan.table.clone(),
+            },
+            table_meta,
+            as_dropped: true,
+        };
+        let reply = catalog.create_table(req).await?;
+        if !reply.new_table && self.plan.create_option != CreateOption::CreateOrReplace {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        // The files of the shared snapshot are kept by the source as long as the clone exists.
+        if let Some(snapshot_location) = snapshot_location {
+            let mut retries = 0;
+            loop {
+                let fuse_table = FuseTable::try_from_table(source.as_ref())?;
+                match fuse_table
+                    .add_table_clone(self.ctx.clone(), reply.table_id, snapshot_location.clone())
+                    .await
+                {
+                    Err(e)
+                        if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED
+                            && retries < MAX_PIN_RETRIES =>
+                    {
+                        retries += 1;
+                        source = source.refresh(self.ctx.as_ref()).await?;
+                    }
+                    res => break res?,
+                }
+            }
+            info!(
+                "table {}.{} cloned from {}.{} at {}",
+                self.plan.database,
+                self.plan.table,
+                self.plan.source_database,
+                self.plan.source_table,
+                snapshot_location
+            );
+        }
+
+        catalog
+            .commit_table_meta(CommitTableMetaReq {
+                name_ident: TableNameIdent {
+                    tenant: self.plan.tenant.clone(),
+                    db_name: self.plan.database.clone(),
+                    table_name: self.plan.table.clone(),
+                },
+                db_id: reply.db_id,
+                table_id: reply.table_id,
+                prev_table_id: reply.prev_table_id,
+                orphan_table_name: reply.orphan_table_name.clone(),
+            })
+            .await?;
+
+        // grant the ownership of the table to the current role.
+        if let Some(current_role) = self.ctx.get_current_role() {
+            let role_api = UserApiProvider::instance().role_api(&tenant);
+            role_api
+                .grant_ownership(
+                    &OwnershipObject::Table {
+                        catalog_name: self.plan.catalog.clone(),
+                        db_id,
+                        table_id: reply.table_id,
+                    },
+                    &current_role.name,
+                )
+                .await?;
+            RoleCacheManager::instance().invalidate_cache(&tenant);
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -61,6 +61,8 @@ use crate::interpreters::common::table_option_validation::is_valid_bloom_index_c
 use crate::interpreters::common::table_option_validation::is_valid_change_tracking;
 use crate::interpreters::common::table_option_validation::is_valid_create_opt;
 use crate::interpreters::common::table_option_validation::is_valid_data_retention_period;
+use crate::interpreters::common::table_option_validation::is_valid_deletion_vectors;
+use crate::interpreters::common::table_option_validation::is_valid_kafka_options;
 use crate::interpreters::common::table_option_validation::is_valid_random_seed;
 use crate::interpreters::common::table_option_validation::is_valid_row_per_block;
 use crate::interpreters::InsertInterpreter;
@@ -233,6 +235,7 @@ impl CreateTableInterpreter {
             overwrite: false,
             source: InsertInputSource::SelectPlan(select_plan),
             table_info: Some(table_info),
+            branch: None,
         };
 
         let mut pipeline = InsertInterpreter::try_create(self.ctx.clone(), insert_plan)?
@@ -388,6 +391,7 @@ impl CreateTableInterpreter {
             statistics: statistics.unwrap_or_default(),
             comment: comment.unwrap_or_default(),
             indexes: self.plan.inverted_indexes.clone().unwrap_or_default(),
+            constraints: self.plan.constraints.clone(),
             ..Default::default()
         };
 
@@ -396,8 +400,11 @@ impl CreateTableInterpreter {
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&table_meta.options, schema)?;
         is_valid_change_tracking(&table_meta.options)?;
+        is_valid_deletion_vectors(&table_meta.options)?;
         // check random seed
         is_valid_random_seed(&table_meta.options)?;
+        // check kafka brokers, topic and message format
+        is_valid_kafka_options(&table_meta.engine, &table_meta.options)?;
         // check table level data_retention_period_in_hours
         is_valid_data_retention_period(&table_meta.options)?;
 
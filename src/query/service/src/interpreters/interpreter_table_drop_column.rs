@@ -109,6 +109,16 @@ impl Interpreter for DropTableColumnInterpreter {
             }
         }
 
+        // If the column is part of a table constraint, the column can't be dropped.
+        for (constraint_name, constraint) in &table_info.meta.constraints {
+            if constraint.column_ids().contains(&field.column_id) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "column `{}` is referenced by constraint `{}`",
+                    field.name, constraint_name,
+                )));
+            }
+        }
+
         let catalog = self.ctx.get_catalog(catalog_name).await?;
         let mut new_table_meta = table.get_table_info().meta.clone();
         new_table_meta.drop_column(&self.plan.column)?;
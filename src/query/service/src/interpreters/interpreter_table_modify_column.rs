@@ -300,6 +300,7 @@ impl ModifyTableColumnInterpreter {
                         data_type: old_data_type,
                         column_id: old_column_id,
                         computed_expr: old_computed_expr,
+                        codec: _,
                     } = old_field;
                     let TableField {
                         name: new_name,
@@ -307,6 +308,7 @@ impl ModifyTableColumnInterpreter {
                         data_type: new_data_type,
                         column_id: new_column_id,
                         computed_expr: new_computed_expr,
+                        codec: _,
                     } = new_field;
                     old_name == new_name
                         && old_default_expr == new_default_expr
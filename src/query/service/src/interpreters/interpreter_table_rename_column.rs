@@ -92,6 +92,21 @@ impl Interpreter for RenameTableColumnInterpreter {
 
             is_valid_column(&self.plan.new_column)?;
 
+            // The expression of a check constraint refers to the columns by name.
+            let column_id = table_info
+                .schema()
+                .field_with_name(self.plan.old_column.as_str())?
+                .column_id;
+            for (constraint_name, constraint) in &table_info.meta.constraints {
+                if constraint.check_expr().is_some() && constraint.column_ids().contains(&column_id)
+                {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "column `{}` is referenced by constraint `{}`",
+                        self.plan.old_column, constraint_name,
+                    )));
+                }
+            }
+
             let mut schema: DataSchema = table_info.schema().into();
             let field = schema.field_with_name(self.plan.old_column.as_str())?;
             if field.computed_expr().is_none() {
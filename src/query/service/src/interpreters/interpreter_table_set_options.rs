@@ -22,18 +22,21 @@ use databend_common_meta_app::schema::UpsertTableOptionReq;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::SetOptionsPlan;
 use databend_common_storages_fuse::TableContext;
+use databend_common_storages_fuse::FUSE_OPT_KEY_ENABLE_DELETION_VECTORS;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING_BEGIN_VER;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 use log::error;
 
 use crate::interpreters::common::table_option_validation::is_valid_block_per_segment;
 use crate::interpreters::common::table_option_validation::is_valid_bloom_index_columns;
 use crate::interpreters::common::table_option_validation::is_valid_create_opt;
 use crate::interpreters::common::table_option_validation::is_valid_data_retention_period;
+use crate::interpreters::common::table_option_validation::is_valid_deletion_vectors;
 use crate::interpreters::common::table_option_validation::is_valid_row_per_block;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -101,6 +104,14 @@ impl Interpreter for SetOptionsInterpreter {
                 OPT_KEY_CLUSTER_TYPE
             )));
         }
+        // The TTL expression is validated against the schema by CREATE TABLE only.
+        if self.plan.set_options.contains_key(OPT_KEY_TTL) {
+            error!("{}", &error_str);
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "can't change {} for alter table statement",
+                OPT_KEY_TTL
+            )));
+        }
         for table_option in self.plan.set_options.iter() {
             let key = table_option.0.to_lowercase();
             if !is_valid_create_opt(&key) {
@@ -131,6 +142,28 @@ impl Interpreter for SetOptionsInterpreter {
             }
         }
 
+        // check the deletion vectors against the options after alteration.
+        if let Some(value) = self
+            .plan
+            .set_options
+            .get(FUSE_OPT_KEY_ENABLE_DELETION_VECTORS)
+        {
+            let enabled = table
+                .get_table_info()
+                .options()
+                .get(FUSE_OPT_KEY_ENABLE_DELETION_VECTORS)
+                .is_some_and(|v| v.to_lowercase().parse::<bool>().unwrap_or(false));
+            if enabled && !value.to_lowercase().parse::<bool>()? {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "can't disable {} once it is enabled",
+                    FUSE_OPT_KEY_ENABLE_DELETION_VECTORS
+                )));
+            }
+        }
+        let mut new_options = table.get_table_info().options().clone();
+        new_options.extend(self.plan.set_options.clone());
+        is_valid_deletion_vectors(&new_options)?;
+
         // check mutability
         table.check_mutable()?;
 
@@ -26,6 +26,7 @@ use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Scalar;
 use databend_common_expression::Value;
+use databend_common_meta_app::schema::TableConstraint;
 use databend_common_sql::plans::ShowCreateTablePlan;
 use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
@@ -37,6 +38,7 @@ use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -189,6 +191,10 @@ impl ShowCreateTableInterpreter {
                     }
                     _ => "".to_string(),
                 };
+                let codec = match field.codec() {
+                    Some(codec) => format!(" CODEC({codec})"),
+                    None => "".to_string(),
+                };
                 // compatibility: creating table in the old planner will not have `fields_comments`
                 let comment = if field_comments.len() == n_fields && !field_comments[idx].is_empty()
                 {
@@ -202,12 +208,13 @@ impl ShowCreateTableInterpreter {
                     "".to_string()
                 };
                 let column_str = format!(
-                    "  {} {}{}{}{}{}",
+                    "  {} {}{}{}{}{}{}",
                     display_ident(field.name(), quoted_ident_case_sensitive, sql_dialect),
                     field.data_type().remove_recursive_nullable().sql_name(),
                     nullable,
                     default_expr,
                     computed_expr,
+                    codec,
                     comment
                 );
 
@@ -245,6 +252,41 @@ impl ShowCreateTableInterpreter {
                 create_defs.push(index_str);
             }
 
+            for (name, constraint) in table_info.meta.constraints.iter() {
+                if let TableConstraint::Check { expr, .. } = constraint {
+                    create_defs.push(format!(
+                        "  CONSTRAINT {} CHECK ({})",
+                        display_ident(name, quoted_ident_case_sensitive, sql_dialect),
+                        expr
+                    ));
+                    continue;
+                }
+                let mut column_names = Vec::with_capacity(constraint.column_ids().len());
+                for column_id in constraint.column_ids() {
+                    let field = schema.field_of_column_id(*column_id)?;
+                    column_names.push(display_ident(
+                        field.name(),
+                        quoted_ident_case_sensitive,
+                        sql_dialect,
+                    ));
+                }
+                let (keyword, enforced) = match constraint {
+                    TableConstraint::PrimaryKey { enforced, .. } => ("PRIMARY KEY", enforced),
+                    TableConstraint::Unique { enforced, .. } => ("UNIQUE", enforced),
+                    TableConstraint::Check { .. } => unreachable!(),
+                };
+                let mut constraint_str = format!(
+                    "  CONSTRAINT {} {} ({})",
+                    display_ident(name, quoted_ident_case_sensitive, sql_dialect),
+                    keyword,
+                    column_names.join(", ")
+                );
+                if !enforced {
+                    constraint_str.push_str(" NOT ENFORCED");
+                }
+                create_defs.push(constraint_str);
+            }
+
             // Format is:
             //  (
             //      x,
@@ -266,6 +308,10 @@ impl ShowCreateTableInterpreter {
                 .push_str(format!(" CLUSTER BY {}{}", cluster_type, cluster_keys_str).as_str());
         }
 
+        if let Some(ttl) = table_info.options().get(OPT_KEY_TTL) {
+            table_create_sql.push_str(format!(" TTL {}", ttl).as_str());
+        }
+
         if !hide_options_in_show_create_table || engine == "ICEBERG" || engine == "DELTA" {
             table_create_sql.push_str({
                 let mut opts = table_info.options().iter().collect::<Vec<_>>();
@@ -0,0 +1,178 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_sql::plans::WarmTableCachePlan;
+use databend_common_storages_fuse::operations::WarmCacheStats;
+use databend_common_storages_fuse::FuseTable;
+
+use crate::clusters::ClusterHelper;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::servers::flight::v1::actions::WarmTableCacheRequest;
+use crate::servers::flight::v1::actions::WARM_TABLE_CACHE;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+// The timeout of warming the caches of the other nodes if the statement has no time limit.
+const WARM_CACHE_TIMEOUT_SECS: u64 = 24 * 60 * 60;
+
+pub struct WarmTableCacheInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: WarmTableCachePlan,
+
+    proxy_to_cluster: bool,
+}
+
+impl WarmTableCacheInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: WarmTableCachePlan) -> Result<Self> {
+        Ok(WarmTableCacheInterpreter {
+            ctx,
+            plan,
+            proxy_to_cluster: true,
+        })
+    }
+
+    pub fn from_flight(ctx: Arc<QueryContext>, plan: WarmTableCachePlan) -> Result<Self> {
+        Ok(WarmTableCacheInterpreter {
+            ctx,
+            plan,
+            proxy_to_cluster: false,
+        })
+    }
+
+    /// Warms the caches of the local node, with the blocks scheduled to it if `executors`
+    /// are given, otherwise with all the blocks.
+    #[async_backtrace::framed]
+    pub async fn warm_local_cache(&self, executors: Option<Vec<String>>) -> Result<WarmCacheStats> {
+        let table = self
+            .ctx
+            .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let push_downs = self.plan.filters.clone().map(|v| PushDownInfo {
+            filters: Some(v),
+            ..PushDownInfo::default()
+        });
+        fuse_table
+            .warm_cache(
+                self.ctx.clone(),
+                self.plan.columns.clone(),
+                push_downs,
+                executors,
+            )
+            .await
+    }
+
+    // Warms the caches of the other nodes of the cluster, each node loads the blocks itself.
+    async fn warm_cluster_cache(
+        &self,
+        executors: Option<Vec<String>>,
+    ) -> Result<HashMap<String, WarmCacheStats>> {
+        let cluster = self.ctx.get_cluster();
+        if !self.proxy_to_cluster || cluster.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut message = HashMap::with_capacity(cluster.nodes.len());
+        for node_info in &cluster.nodes {
+            if node_info.id != cluster.local_id {
+                message.insert(node_info.id.clone(), WarmTableCacheRequest {
+                    plan: self.plan.clone(),
+                    executors: executors.clone(),
+                });
+            }
+        }
+
+        // Loading the blocks takes much longer than the other actions, it is bounded by the
+        // execution time of the statement only.
+        let settings = self.ctx.get_settings();
+        let timeout = match settings.get_max_execute_time_in_seconds()? {
+            0 => WARM_CACHE_TIMEOUT_SECS,
+            secs => secs,
+        };
+        cluster
+            .do_action::<_, WarmCacheStats>(WARM_TABLE_CACHE, message, timeout)
+            .await
+    }
+
+    // The nodes of the cluster if the blocks are scheduled to the nodes by cache affinity,
+    // then each node only loads the blocks that its queries will read.
+    fn affinity_executors(&self) -> Result<Option<Vec<String>>> {
+        let cluster = self.ctx.get_cluster();
+        if cluster.is_empty()
+            || !self
+                .ctx
+                .get_settings()
+                .get_enable_cache_affinity_scheduling()?
+        {
+            return Ok(None);
+        }
+
+        Ok(Some(
+            cluster.nodes.iter().map(|node| node.id.clone()).collect(),
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for WarmTableCacheInterpreter {
+    fn name(&self) -> &str {
+        "WarmTableCacheInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let executors = self.affinity_executors()?;
+        let (local, remote) = futures::try_join!(
+            self.warm_local_cache(executors.clone()),
+            self.warm_cluster_cache(executors)
+        )?;
+
+        let mut nodes = vec![(self.ctx.get_cluster().local_id.clone(), local)];
+        nodes.extend(remote);
+        nodes.sort_by(|a, b| a.0.cmp(&b.0));
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(
+                nodes
+                    .iter()
+                    .map(|(node, _)| node.clone())
+                    .collect::<Vec<_>>(),
+            ),
+            UInt64Type::from_data(nodes.iter().map(|(_, s)| s.segments).collect::<Vec<_>>()),
+            UInt64Type::from_data(nodes.iter().map(|(_, s)| s.blocks).collect::<Vec<_>>()),
+            UInt64Type::from_data(
+                nodes
+                    .iter()
+                    .map(|(_, s)| s.bloom_filters)
+                    .collect::<Vec<_>>(),
+            ),
+            UInt64Type::from_data(nodes.iter().map(|(_, s)| s.bytes).collect::<Vec<_>>()),
+        ])])
+    }
+}
@@ -31,6 +31,7 @@ use databend_common_meta_app::schema::DroppedId;
 use databend_common_meta_app::schema::GcDroppedTableReq;
 use databend_common_meta_app::schema::ListDroppedTableReq;
 use databend_common_sql::plans::VacuumDropTablePlan;
+use databend_common_storages_fuse::FuseTable;
 use databend_enterprise_vacuum_handler::get_vacuum_handler;
 use log::info;
 
@@ -164,9 +165,25 @@ impl Interpreter for VacuumDropTablesInterpreter {
             .filter(|tbl| !tbl.as_ref().is_read_only())
             .collect::<Vec<_>>();
 
+        // The files of a table are shared with its clones, the tables with clones still alive
+        // are postponed until the clones are vacuumed.
+        let table_ctx: Arc<dyn TableContext> = self.ctx.clone();
+        let mut cloned_tables = HashSet::new();
+        for table in &tables {
+            if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+                if !fuse_table.live_table_clones(&table_ctx).await?.is_empty() {
+                    cloned_tables.insert(table.get_id());
+                }
+            }
+        }
+        let tables = tables
+            .into_iter()
+            .filter(|tbl| !cloned_tables.contains(&tbl.get_id()))
+            .collect::<Vec<_>>();
+
         let handler = get_vacuum_handler();
         let threads_nums = self.ctx.get_settings().get_max_threads()? as usize;
-        let (files_opt, failed_tables) = handler
+        let (files_opt, mut failed_tables) = handler
             .do_vacuum_drop_tables(
                 threads_nums,
                 tables,
@@ -177,6 +194,7 @@ impl Interpreter for VacuumDropTablesInterpreter {
                 },
             )
             .await?;
+        failed_tables.extend(cloned_tables);
 
         let failed_db_ids = failed_tables
             .iter()
@@ -16,6 +16,7 @@ mod access;
 pub(crate) mod common;
 mod hook;
 mod interpreter;
+mod interpreter_audit;
 mod interpreter_catalog_create;
 mod interpreter_catalog_drop;
 mod interpreter_catalog_show_create;
@@ -36,6 +37,7 @@ mod interpreter_database_drop;
 mod interpreter_database_rename;
 mod interpreter_database_show_create;
 mod interpreter_database_undrop;
+mod interpreter_deallocate;
 mod interpreter_dictionary_create;
 mod interpreter_dictionary_drop;
 mod interpreter_dictionary_show_create;
@@ -69,6 +71,7 @@ mod interpreter_password_policy_alter;
 mod interpreter_password_policy_create;
 mod interpreter_password_policy_desc;
 mod interpreter_password_policy_drop;
+mod interpreter_prepare;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
@@ -76,6 +79,7 @@ mod interpreter_procedure_call;
 mod interpreter_procedure_create;
 mod interpreter_procedure_drop;
 mod interpreter_replace;
+mod interpreter_role_alter;
 mod interpreter_role_create;
 mod interpreter_role_drop;
 mod interpreter_role_grant;
@@ -92,11 +96,14 @@ mod interpreter_stream_create;
 mod interpreter_stream_drop;
 mod interpreter_system_action;
 mod interpreter_table_add_column;
+mod interpreter_table_add_constraint;
 mod interpreter_table_analyze;
+mod interpreter_table_clone;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
 mod interpreter_table_drop_column;
+mod interpreter_table_drop_constraint;
 mod interpreter_table_exists;
 mod interpreter_table_index_create;
 mod interpreter_table_index_drop;
@@ -104,6 +111,8 @@ mod interpreter_table_index_refresh;
 mod interpreter_table_modify_column;
 mod interpreter_table_modify_comment;
 mod interpreter_table_recluster;
+mod interpreter_table_ref_create;
+mod interpreter_table_ref_drop;
 mod interpreter_table_rename;
 mod interpreter_table_rename_column;
 mod interpreter_table_revert;
@@ -113,6 +122,7 @@ mod interpreter_table_truncate;
 mod interpreter_table_undrop;
 mod interpreter_table_unset_options;
 mod interpreter_table_vacuum;
+mod interpreter_table_warm_cache;
 mod interpreter_task_alter;
 mod interpreter_task_create;
 mod interpreter_task_describe;
@@ -144,6 +154,10 @@ mod interpreter_virtual_column_alter;
 mod interpreter_virtual_column_create;
 mod interpreter_virtual_column_drop;
 mod interpreter_virtual_column_refresh;
+mod interpreter_workload_group_alter;
+mod interpreter_workload_group_create;
+mod interpreter_workload_group_drop;
+mod procedure_script;
 mod util;
 
 pub use access::ManagementModeAccess;
@@ -163,6 +177,7 @@ pub use interpreter_database_drop::DropDatabaseInterpreter;
 pub use interpreter_database_rename::RenameDatabaseInterpreter;
 pub use interpreter_database_show_create::ShowCreateDatabaseInterpreter;
 pub use interpreter_database_undrop::UndropDatabaseInterpreter;
+pub use interpreter_deallocate::DeallocateInterpreter;
 pub use interpreter_execute_immediate::ExecuteImmediateInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
@@ -184,9 +199,11 @@ pub use interpreter_password_policy_alter::AlterPasswordPolicyInterpreter;
 pub use interpreter_password_policy_create::CreatePasswordPolicyInterpreter;
 pub use interpreter_password_policy_desc::DescPasswordPolicyInterpreter;
 pub use interpreter_password_policy_drop::DropPasswordPolicyInterpreter;
+pub use interpreter_prepare::PrepareInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_replace::ReplaceInterpreter;
+pub use interpreter_role_alter::AlterRoleInterpreter;
 pub use interpreter_role_create::CreateRoleInterpreter;
 pub use interpreter_role_drop::DropRoleInterpreter;
 pub use interpreter_role_grant::GrantRoleInterpreter;
@@ -202,11 +219,14 @@ pub use interpreter_stream_create::CreateStreamInterpreter;
 pub use interpreter_stream_drop::DropStreamInterpreter;
 pub use interpreter_system_action::SystemActionInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
+pub use interpreter_table_add_constraint::AddTableConstraintInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
+pub use interpreter_table_clone::CloneTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
 pub use interpreter_table_drop_column::DropTableColumnInterpreter;
+pub use interpreter_table_drop_constraint::DropTableConstraintInterpreter;
 pub use interpreter_table_exists::ExistsTableInterpreter;
 pub use interpreter_table_index_create::CreateTableIndexInterpreter;
 pub use interpreter_table_index_drop::DropTableIndexInterpreter;
@@ -221,6 +241,7 @@ pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
 pub use interpreter_table_vacuum::VacuumTableInterpreter;
+pub use interpreter_table_warm_cache::WarmTableCacheInterpreter;
 pub use interpreter_unset::UnSetInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
 pub use interpreter_user_alter::AlterUserInterpreter;
@@ -242,3 +263,6 @@ pub use interpreter_virtual_column_alter::AlterVirtualColumnInterpreter;
 pub use interpreter_virtual_column_create::CreateVirtualColumnInterpreter;
 pub use interpreter_virtual_column_drop::DropVirtualColumnInterpreter;
 pub use interpreter_virtual_column_refresh::RefreshVirtualColumnInterpreter;
+pub use interpreter_workload_group_alter::AlterWorkloadGroupInterpreter;
+pub use interpreter_workload_group_create::CreateWorkloadGroupInterpreter;
+pub use interpreter_workload_group_drop::DropWorkloadGroupInterpreter;
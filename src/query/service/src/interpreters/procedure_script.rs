@@ -0,0 +1,489 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Stored procedures written in Python or JavaScript.
+//!
+//! The procedure body runs inside the same embedded runtimes as script UDFs.
+//! Those runtimes are synchronous and cannot call back into the query engine,
+//! so the body is driven by replay: when it calls `session.sql(...)` for a
+//! statement that has not been executed yet, the body is suspended and the
+//! statement is executed here with the caller's session. The body is then
+//! started again with the results recorded so far, until it returns.
+//!
+//! Every statement is executed exactly once, but the code between statements
+//! is re-run on each replay, so the body must issue its statements in a
+//! deterministic order. Each reply lists the statements issued by the body,
+//! and the procedure fails if they differ from the statements executed so far.
+//!
+//! Each replay also sends and parses all the results recorded so far, so the
+//! cost of a procedure grows with the square of its number of statements. To
+//! bound it, the rows of a result set larger than `MAX_RESULT_ROWS` are not
+//! sent to the body, reading them fails and they must be read in pages with
+//! `LIMIT` and `OFFSET` instead. The procedure also fails once the recorded
+//! results are larger than `MAX_RESULTS_BYTES`. A result set returned by the
+//! body is still rendered in full.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use databend_common_ast::ast::Expr;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::box_render;
+use databend_common_expression::types::variant::cast_scalar_to_variant;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
+use databend_common_script::Client;
+use databend_common_sql::executor::physical_plans::UdfFunctionDesc;
+use databend_common_sql::plans::CallProcedurePlan;
+use databend_common_sql::plans::UDFType;
+use databend_common_storages_fuse::TableContext;
+use itertools::Itertools;
+use serde_json::json;
+
+use crate::interpreters::util::QueryResult;
+use crate::interpreters::util::ScriptClient;
+use crate::pipelines::processors::transforms::ScriptRuntime;
+use crate::sessions::QueryContext;
+
+const HANDLER: &str = "__procedure_main";
+/// The maximum number of rows of a result set that can be read by the body.
+const MAX_RESULT_ROWS: usize = 10_000;
+/// The maximum size of the serialized results that are sent to the body on each replay.
+const MAX_RESULTS_BYTES: usize = 64 * 1024 * 1024;
+
+const JAVASCRIPT_PRELUDE: &str = r#"
+class ProcedureSuspend {}
+
+class ResultSet {
+    constructor(index, result) {
+        this.index = index;
+        this.columns = result.columns;
+        this.rowsError = result.rows_error;
+        if (result.rows !== undefined) {
+            this._rows = result.rows.map((row) => Object.fromEntries(this.columns.map((column, i) => [column, row[i]])));
+        }
+    }
+
+    get rows() {
+        if (this.rowsError !== undefined) {
+            throw new Error(this.rowsError);
+        }
+        return this._rows;
+    }
+
+    [Symbol.iterator]() {
+        return this.rows[Symbol.iterator]();
+    }
+}
+
+class Session {
+    constructor(results) {
+        this.results = results;
+        this.queries = [];
+        this.suspended = false;
+    }
+
+    sql(query) {
+        if (this.suspended) {
+            throw new ProcedureSuspend();
+        }
+        const index = this.queries.length;
+        this.queries.push(query);
+        const result = this.results[index];
+        // The host executes a new statement, or fails if it differs from the recorded one.
+        if (result === undefined || result.query !== query) {
+            this.suspended = true;
+            throw new ProcedureSuspend();
+        }
+        if (result.error !== undefined) {
+            throw new Error(result.error);
+        }
+        return new ResultSet(index, result);
+    }
+}
+
+export function __procedure_main(args, results) {
+    const session = new Session(JSON.parse(results));
+    let value = null;
+    try {
+        value = __procedure_body(session, ...JSON.parse(args));
+    } catch (err) {
+        if (!session.suspended) {
+            throw err;
+        }
+    }
+    if (session.suspended) {
+        return JSON.stringify({ queries: session.queries, sql: session.queries[session.queries.length - 1] });
+    }
+    if (value instanceof ResultSet) {
+        return JSON.stringify({ queries: session.queries, table: value.index });
+    }
+    return JSON.stringify({ queries: session.queries, value: value === undefined ? null : value });
+}
+"#;
+
+const PYTHON_PRELUDE: &str = r#"
+import json
+
+
+class _ProcedureSuspend(BaseException):
+    pass
+
+
+class ResultSet:
+    def __init__(self, index, result):
+        self.index = index
+        self.columns = result["columns"]
+        self._rows_error = result.get("rows_error")
+        self._rows = [dict(zip(self.columns, row)) for row in result.get("rows", [])]
+
+    @property
+    def rows(self):
+        if self._rows_error is not None:
+            raise RuntimeError(self._rows_error)
+        return self._rows
+
+    def __iter__(self):
+        return iter(self.rows)
+
+    def __len__(self):
+        return len(self.rows)
+
+
+class Session:
+    def __init__(self, results):
+        self._results = results
+        self._queries = []
+        self._suspended = False
+
+    def sql(self, query):
+        if self._suspended:
+            raise _ProcedureSuspend()
+        index = len(self._queries)
+        self._queries.append(query)
+        # The host executes a new statement, or fails if it differs from the recorded one.
+        if index >= len(self._results) or self._results[index]["query"] != query:
+            self._suspended = True
+            raise _ProcedureSuspend()
+        result = self._results[index]
+        if "error" in result:
+            raise RuntimeError(result["error"])
+        return ResultSet(index, result)
+
+
+def __procedure_main(args, results):
+    session = Session(json.loads(results))
+    value = None
+    try:
+        value = __procedure_body(session, *json.loads(args))
+    except BaseException:
+        if not session._suspended:
+            raise
+    queries = session._queries
+    if session._suspended:
+        return json.dumps({"queries": queries, "sql": queries[-1]})
+    if isinstance(value, ResultSet):
+        return json.dumps({"queries": queries, "table": value.index})
+    return json.dumps({"queries": queries, "value": value}, default=str)
+"#;
+
+pub struct ScriptProcedure {
+    ctx: Arc<QueryContext>,
+    lang: &'static str,
+    func: UdfFunctionDesc,
+}
+
+impl ScriptProcedure {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: &CallProcedurePlan) -> Result<Self> {
+        let (lang, code) = match plan.language.as_str() {
+            "JAVASCRIPT" => ("javascript", Self::javascript_code(plan)),
+            "PYTHON" => ("python", Self::python_code(plan)),
+            other => {
+                return Err(ErrorCode::ScriptSemanticError(format!(
+                    "unsupported procedure language {}",
+                    other
+                )));
+            }
+        };
+
+        // The python runtime is shared by the whole process, so the function
+        // name must change together with the procedure body.
+        let mut hasher = DefaultHasher::new();
+        code.hash(&mut hasher);
+        let func = UdfFunctionDesc {
+            name: format!("procedure_{}_{:x}", plan.name, hasher.finish()),
+            func_name: HANDLER.to_string(),
+            output_column: 0,
+            arg_indices: vec![0, 1],
+            arg_exprs: vec![],
+            data_type: Box::new(DataType::String),
+            udf_type: UDFType::Script((lang.to_string(), String::new(), code.into_bytes())),
+        };
+
+        Ok(ScriptProcedure { ctx, lang, func })
+    }
+
+    fn javascript_code(plan: &CallProcedurePlan) -> String {
+        let params = std::iter::once("session")
+            .chain(plan.arg_names.iter().map(|name| name.as_str()))
+            .join(", ");
+        format!(
+            "{JAVASCRIPT_PRELUDE}\nfunction __procedure_body({params}) {{\n{}\n}}\n",
+            plan.script
+        )
+    }
+
+    fn python_code(plan: &CallProcedurePlan) -> String {
+        let params = std::iter::once("session")
+            .chain(plan.arg_names.iter().map(|name| name.as_str()))
+            .join(", ");
+        let body = plan
+            .script
+            .lines()
+            .map(|line| format!("    {line}"))
+            .join("\n");
+        format!("{PYTHON_PRELUDE}\n\ndef __procedure_body({params}):\n{body}\n    pass\n")
+    }
+
+    /// Runs the procedure and returns the lines of the `Result` column.
+    pub async fn execute(&self, args: &[Expr]) -> Result<Vec<String>> {
+        let client = ScriptClient {
+            ctx: self.ctx.clone(),
+        };
+        let args = self.eval_args(&client, args).await?;
+
+        let runtime = ScriptRuntime::try_create(self.lang, None, 1)?;
+        if let UDFType::Script((_, _, code)) = &self.func.udf_type {
+            runtime.add_function_with_handler(&self.func, code)?;
+        }
+
+        let max_steps = self.ctx.get_settings().get_script_max_steps()? as usize;
+        // The results are kept serialized, they are only sent to the body.
+        let mut results: Vec<String> = vec![];
+        let mut results_bytes = 0;
+        let mut queries: Vec<String> = vec![];
+        let mut sets = vec![];
+        for _ in 0..max_steps {
+            let reply = self.call(&runtime, &args, &format!("[{}]", results.join(",")))?;
+            Self::check_replay(&reply, &queries)?;
+            if let Some(query) = reply.get("sql").and_then(|query| query.as_str()) {
+                queries.push(query.to_string());
+                let result = match client.query(query).await {
+                    Ok(set) => {
+                        let columns = set.schema.fields().iter().map(|f| f.name());
+                        let mut result = json!({
+                            "query": query,
+                            "columns": columns.collect::<Vec<_>>(),
+                        });
+                        let num_rows = set.block.num_rows();
+                        if num_rows > MAX_RESULT_ROWS {
+                            result["rows_error"] = json!(format!(
+                                "The result set of `{}` has {} rows, more than the {} rows \
+                                 that can be read by a procedure, read it in pages with LIMIT and OFFSET.",
+                                query, num_rows, MAX_RESULT_ROWS
+                            ));
+                        } else {
+                            result["rows"] = json!(self.set_to_json(&set, MAX_RESULT_ROWS)?);
+                        }
+                        sets.push(Some(set));
+                        result
+                    }
+                    Err(err) => {
+                        sets.push(None);
+                        json!({
+                            "query": query,
+                            "error": format!("{}. Code: {}", err.message(), err.code()),
+                        })
+                    }
+                };
+                let result = result.to_string();
+                results_bytes += result.len();
+                if results_bytes > MAX_RESULTS_BYTES {
+                    return Err(ErrorCode::ScriptExecutionError(format!(
+                        "The results of the procedure statements have exceeded the limit of {} bytes, \
+                         try to return fewer rows from the statements.",
+                        MAX_RESULTS_BYTES
+                    )));
+                }
+                results.push(result);
+            } else if let Some(index) = reply.get("table").and_then(|index| index.as_u64()) {
+                let set = sets
+                    .get(index as usize)
+                    .and_then(|set| set.as_ref())
+                    .ok_or_else(|| {
+                        ErrorCode::ScriptExecutionError(format!(
+                            "procedure returned an invalid result set {}",
+                            index
+                        ))
+                    })?;
+                let rendered_table = box_render(
+                    &set.schema,
+                    &[set.block.clone()],
+                    usize::MAX,
+                    usize::MAX,
+                    usize::MAX,
+                    true,
+                )?;
+                return Ok(rendered_table.lines().map(|x| x.to_string()).collect());
+            } else {
+                let value = reply.get("value").cloned().unwrap_or_default();
+                return Ok(vec![Self::render_value(value)]);
+            }
+        }
+
+        Err(ErrorCode::ScriptExecutionError(format!(
+            "Execution of procedure has exceeded the limit of {} statements. \
+             You can increase the limit with `set script_max_steps = {};`.",
+            max_steps,
+            max_steps * 10
+        )))
+    }
+
+    /// Checks that the body issued the statements executed so far, in the same
+    /// order, before the statement of the reply or its return value.
+    fn check_replay(reply: &serde_json::Value, queries: &[String]) -> Result<()> {
+        let replayed = reply
+            .get("queries")
+            .and_then(|replayed| replayed.as_array())
+            .ok_or_else(|| {
+                ErrorCode::ScriptExecutionError(format!(
+                    "procedure returned an invalid reply {}",
+                    reply
+                ))
+            })?;
+        for (index, query) in queries.iter().enumerate() {
+            let replayed = replayed.get(index).and_then(|query| query.as_str());
+            if replayed != Some(query.as_str()) {
+                return Err(ErrorCode::ScriptExecutionError(format!(
+                    "Procedure body is not deterministic: statement {} was `{}` when executed, \
+                     but is {} on replay.",
+                    index + 1,
+                    query,
+                    match replayed {
+                        Some(replayed) => format!("`{}`", replayed),
+                        None => "missing".to_string(),
+                    }
+                )));
+            }
+        }
+
+        let expected = queries.len() + reply.get("sql").is_some() as usize;
+        if replayed.len() != expected {
+            return Err(ErrorCode::ScriptExecutionError(format!(
+                "procedure returned an invalid reply {}",
+                reply
+            )));
+        }
+        Ok(())
+    }
+
+    /// Evaluates the call arguments as a single query and encodes them as a json array.
+    async fn eval_args(&self, client: &ScriptClient, args: &[Expr]) -> Result<String> {
+        if args.is_empty() {
+            return Ok("[]".to_string());
+        }
+        let set = client
+            .query(&format!("SELECT {}", args.iter().join(", ")))
+            .await?;
+        let rows = self.set_to_json(&set, 1)?;
+        Ok(rows
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| serde_json::Value::Array(vec![]))
+            .to_string())
+    }
+
+    /// Encodes the first `max_rows` rows of the result set as json arrays.
+    fn set_to_json(&self, set: &QueryResult, max_rows: usize) -> Result<Vec<serde_json::Value>> {
+        let tz = self.ctx.get_function_context()?.tz;
+        let num_rows = set.block.num_rows().min(max_rows);
+        let mut rows = Vec::with_capacity(num_rows);
+        for row in 0..num_rows {
+            let mut values = Vec::with_capacity(set.block.num_columns());
+            for entry in set.block.columns() {
+                let scalar = entry.value.index(row).unwrap_or(ScalarRef::Null);
+                let mut buf = vec![];
+                cast_scalar_to_variant(scalar, tz, &mut buf);
+                let value = serde_json::from_str(&jsonb::to_string(&buf))
+                    .unwrap_or(serde_json::Value::Null);
+                values.push(value);
+            }
+            rows.push(serde_json::Value::Array(values));
+        }
+        Ok(rows)
+    }
+
+    fn call(
+        &self,
+        runtime: &ScriptRuntime,
+        args: &str,
+        results: &str,
+    ) -> Result<serde_json::Value> {
+        let block = DataBlock::new(
+            vec![
+                BlockEntry::new(
+                    DataType::String,
+                    Value::Scalar(Scalar::String(args.to_string())),
+                ),
+                BlockEntry::new(
+                    DataType::String,
+                    Value::Scalar(Scalar::String(results.to_string())),
+                ),
+            ],
+            1,
+        );
+        let schema = DataSchema::new(vec![
+            DataField::new("args", DataType::String),
+            DataField::new("results", DataType::String),
+        ]);
+        let input_batch = block.to_record_batch_with_dataschema(&schema)?;
+        let output_batch = runtime.handle_execution(&self.func, &input_batch, 0)?;
+        let reply = Self::read_reply(&output_batch)?;
+        serde_json::from_str(&reply).map_err(|err| {
+            ErrorCode::ScriptExecutionError(format!(
+                "procedure returned an invalid reply {}: {}",
+                reply, err
+            ))
+        })
+    }
+
+    fn read_reply(batch: &RecordBatch) -> Result<String> {
+        let schema = DataSchema::try_from(&(*batch.schema()))?;
+        let (block, _) = DataBlock::from_record_batch(&schema, batch)?;
+        match block.get_by_offset(0).value.index(0) {
+            Some(ScalarRef::String(reply)) => Ok(reply.to_string()),
+            _ => Err(ErrorCode::ScriptExecutionError(
+                "procedure returned no reply".to_string(),
+            )),
+        }
+    }
+
+    fn render_value(value: serde_json::Value) -> String {
+        match value {
+            serde_json::Value::Null => "NULL".to_string(),
+            serde_json::Value::String(s) => Scalar::String(s).to_string(),
+            other => other.to_string(),
+        }
+    }
+}
@@ -15,10 +15,14 @@
 use std::sync::Arc;
 
 use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Literal;
 use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::parser::Dialect;
 use databend_common_exception::ErrorCode;
+use databend_common_expression::types::decimal::DecimalScalar;
+use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::NumberScalar;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
@@ -123,6 +127,26 @@ impl Client for ScriptClient {
         Ok(ast)
     }
 
+    fn literal_to_var(&self, literal: &Literal) -> databend_common_exception::Result<Self::Var> {
+        let scalar = match literal {
+            Literal::UInt64(value) => Scalar::Number(NumberScalar::UInt64(*value)),
+            Literal::Decimal256 {
+                value,
+                precision,
+                scale,
+            } => Scalar::Decimal(DecimalScalar::Decimal256(*value, DecimalSize {
+                precision: *precision,
+                scale: *scale,
+            })),
+            Literal::Float64(value) => Scalar::Number(NumberScalar::Float64((*value).into())),
+            Literal::String(value) => Scalar::String(value.clone()),
+            Literal::Boolean(value) => Scalar::Boolean(*value),
+            Literal::Null => Scalar::Null,
+        };
+
+        Ok(scalar)
+    }
+
     fn read_from_set(
         &self,
         set: &Self::Set,
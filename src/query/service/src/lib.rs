@@ -42,6 +42,7 @@
 
 extern crate core;
 
+pub mod audit;
 pub mod auth;
 pub mod catalogs;
 pub mod clusters;
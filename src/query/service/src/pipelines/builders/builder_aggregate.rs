@@ -25,7 +25,6 @@ use databend_common_expression::HashMethodKind;
 use databend_common_expression::HashTableConfig;
 use databend_common_expression::LimitType;
 use databend_common_expression::SortColumnDescription;
-use databend_common_functions::aggregates::AggregateFunctionFactory;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::query_spill_prefix;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
@@ -39,6 +38,7 @@ use databend_common_sql::IndexType;
 use databend_common_storage::DataOperator;
 
 use crate::pipelines::processors::transforms::aggregator::build_partition_bucket;
+use crate::pipelines::processors::transforms::aggregator::create_aggregate_function;
 use crate::pipelines::processors::transforms::aggregator::AggregateInjector;
 use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
 use crate::pipelines::processors::transforms::aggregator::FinalSingleStateAggregator;
@@ -340,11 +340,7 @@ impl PipelineBuilder {
                     })
                     .collect::<Result<Vec<_>>>()?;
                 agg_args.push(args);
-                AggregateFunctionFactory::instance().get(
-                    agg_func.sig.name.as_str(),
-                    agg_func.sig.params.clone(),
-                    agg_func.sig.args.clone(),
-                )
+                create_aggregate_function(&agg_func.sig)
             })
             .collect::<Result<_>>()?;
 
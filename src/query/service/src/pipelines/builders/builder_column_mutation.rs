@@ -13,10 +13,12 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_expression::DataSchema;
 use databend_common_expression::RemoteExpr;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
@@ -30,6 +32,7 @@ use databend_common_storages_fuse::operations::TransformSerializeBlock;
 use databend_common_storages_fuse::FuseTable;
 use databend_storages_common_table_meta::meta::Statistics;
 
+use crate::pipelines::processors::transforms::TransformCheckConstraints;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -84,6 +87,14 @@ impl PipelineBuilder {
                 });
             }
         } else {
+            if TransformCheckConstraints::need_check(table) {
+                let schema = table.schema_with_stream().remove_virtual_computed_fields();
+                let schema = Arc::new(DataSchema::from(&schema));
+                self.main_pipeline.try_add_transformer(|| {
+                    TransformCheckConstraints::try_new(self.ctx.clone(), table, schema.clone())
+                })?;
+            }
+
             let block_thresholds = table.get_block_thresholds();
             let cluster_stats_gen = table.cluster_gen_for_append(
                 self.ctx.clone(),
@@ -21,6 +21,7 @@ use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
+use crate::pipelines::processors::transforms::TransformCheckConstraints;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
@@ -63,6 +64,17 @@ impl PipelineBuilder {
             })?;
         }
 
+        // Validate the check constraints.
+        if TransformCheckConstraints::need_check(table.as_ref()) {
+            pipeline.try_add_transformer(|| {
+                TransformCheckConstraints::try_new(
+                    ctx.clone(),
+                    table.as_ref(),
+                    computed_schema.clone(),
+                )
+            })?;
+        }
+
         Ok(())
     }
 }
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use databend_common_base::base::tokio::sync::Barrier;
 use databend_common_exception::Result;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sinks::AsyncSinker;
 use databend_common_pipeline_sinks::Sinker;
 use databend_common_sql::executor::physical_plans::HashJoin;
 use databend_common_sql::executor::physical_plans::MaterializedCte;
@@ -41,7 +42,7 @@ use crate::sessions::QueryContext;
 
 impl PipelineBuilder {
     pub(crate) fn build_range_join(&mut self, range_join: &RangeJoin) -> Result<()> {
-        let state = Arc::new(RangeJoinState::new(self.ctx.clone(), range_join));
+        let state = Arc::new(RangeJoinState::new(self.ctx.clone(), range_join)?);
         self.expand_right_side_pipeline(range_join, state.clone())?;
         self.build_left_side(range_join, state)?;
         Ok(())
@@ -84,7 +85,7 @@ impl PipelineBuilder {
         let mut right_res = right_side_builder.finalize(&range_join.right)?;
         right_res.main_pipeline.add_sink(|input| {
             Ok(ProcessorPtr::create(
-                Sinker::<TransformRangeJoinRight>::create(
+                AsyncSinker::<TransformRangeJoinRight>::create(
                     input,
                     TransformRangeJoinRight::create(state.clone()),
                 ),
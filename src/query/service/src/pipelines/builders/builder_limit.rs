@@ -23,6 +23,27 @@ impl PipelineBuilder {
     pub(crate) fn build_limit(&mut self, limit: &Limit) -> Result<()> {
         self.build_pipeline(&limit.input)?;
 
+        if !limit.with_ties.is_empty() || limit.percent.is_some() {
+            let input_schema = limit.input.output_schema()?;
+            let with_ties = limit
+                .with_ties
+                .iter()
+                .map(|index| input_schema.index_of(&index.to_string()))
+                .collect::<Result<Vec<_>>>()?;
+
+            self.main_pipeline.try_resize(1)?;
+            return self.main_pipeline.add_transform(|input, output| {
+                Ok(ProcessorPtr::create(TransformLimit::try_create_fetch(
+                    limit.limit,
+                    limit.offset,
+                    with_ties.clone(),
+                    limit.percent,
+                    input,
+                    output,
+                )?))
+            });
+        }
+
         if limit.limit.is_some() || limit.offset != 0 {
             self.main_pipeline.try_resize(1)?;
             return self.main_pipeline.add_transform(|input, output| {
@@ -0,0 +1,59 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_sql::executor::physical_plans::MatchRecognize;
+
+use crate::pipelines::processors::transforms::TransformMatchRecognize;
+use crate::pipelines::processors::transforms::WindowSpillSettings;
+use crate::pipelines::PipelineBuilder;
+
+impl PipelineBuilder {
+    pub(crate) fn build_match_recognize(&mut self, match_recognize: &MatchRecognize) -> Result<()> {
+        self.build_pipeline(&match_recognize.input)?;
+
+        let input_schema = match_recognize.input.output_schema()?;
+        let old_output_len = self.main_pipeline.output_len();
+        // `TransformMatchRecognize` is a pipeline breaker.
+        if match_recognize.partition_by.is_empty() {
+            self.main_pipeline.try_resize(1)?;
+        }
+
+        // The partitions are spilled like the window partitions.
+        let settings = self.ctx.get_settings();
+        let num_processors = self.main_pipeline.output_len();
+        let spill_settings = WindowSpillSettings::new(&settings, num_processors)?;
+        let disk_spill = self.window_disk_spill_config()?;
+        self.main_pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(Box::new(
+                TransformMatchRecognize::try_create(
+                    self.ctx.clone(),
+                    input,
+                    output,
+                    self.func_ctx.clone(),
+                    match_recognize,
+                    &input_schema,
+                    spill_settings.clone(),
+                    disk_spill.clone(),
+                )?,
+            )))
+        })?;
+        if match_recognize.partition_by.is_empty() {
+            self.main_pipeline.try_resize(old_output_len)?;
+        }
+        Ok(())
+    }
+}
@@ -39,6 +39,7 @@ use databend_common_storages_fuse::operations::UnMatchedExprs;
 use databend_common_storages_fuse::FuseTable;
 
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
+use crate::pipelines::processors::transforms::TransformCheckConstraints;
 use crate::pipelines::processors::TransformResortAddOnWithoutSourceSchema;
 use crate::pipelines::PipelineBuilder;
 
@@ -227,6 +228,26 @@ impl PipelineBuilder {
             }
             self.main_pipeline.add_pipe(builder.finalize());
         }
+
+        // validate check constraints
+        if TransformCheckConstraints::need_check(tbl.as_ref()) {
+            builder = self
+                .main_pipeline
+                .try_create_transform_pipeline_builder_with_len(
+                    || {
+                        TransformCheckConstraints::try_new(
+                            self.ctx.clone(),
+                            tbl.as_ref(),
+                            computed_schema.clone(),
+                        )
+                    },
+                    transform_len,
+                )?;
+            if need_match {
+                builder.add_items_prepend(vec![create_dummy_item()]);
+            }
+            self.main_pipeline.add_pipe(builder.finalize());
+        }
         Ok(())
     }
 
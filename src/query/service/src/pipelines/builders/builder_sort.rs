@@ -88,7 +88,13 @@ impl PipelineBuilder {
             })
             .collect::<Result<Vec<_>>>()?;
 
-        self.build_sort_pipeline(plan_schema, sort_desc, sort.limit, sort.after_exchange)
+        self.build_sort_pipeline(
+            plan_schema,
+            sort_desc,
+            sort.limit,
+            sort.limit_with_ties,
+            sort.after_exchange,
+        )
     }
 
     pub(crate) fn build_sort_pipeline(
@@ -96,6 +102,7 @@ impl PipelineBuilder {
         plan_schema: DataSchemaRef,
         sort_desc: Vec<SortColumnDescription>,
         limit: Option<usize>,
+        limit_with_ties: bool,
         after_exchange: Option<bool>,
     ) -> Result<()> {
         let max_threads = self.settings.get_max_threads()? as usize;
@@ -107,7 +114,8 @@ impl PipelineBuilder {
         }
 
         let builder = SortPipelineBuilder::create(self.ctx.clone(), plan_schema, sort_desc)?
-            .with_limit(limit);
+            .with_limit(limit)
+            .with_limit_with_ties(limit_with_ties);
 
         match after_exchange {
             Some(true) => {
@@ -147,6 +155,7 @@ pub struct SortPipelineBuilder {
     schema: DataSchemaRef,
     sort_desc: Arc<Vec<SortColumnDescription>>,
     limit: Option<usize>,
+    limit_with_ties: bool,
     block_size: usize,
     remove_order_col_at_last: bool,
 }
@@ -163,6 +172,7 @@ impl SortPipelineBuilder {
             schema,
             sort_desc,
             limit: None,
+            limit_with_ties: false,
             block_size,
             remove_order_col_at_last: false,
         })
@@ -173,6 +183,21 @@ impl SortPipelineBuilder {
         self
     }
 
+    // Keep the rows which tie with the last row within the limit.
+    pub fn with_limit_with_ties(mut self, limit_with_ties: bool) -> Self {
+        self.limit_with_ties = limit_with_ties;
+        self
+    }
+
+    // The limit which can be applied to the sorted rows without considering ties,
+    // only the sort merge with limit is aware of ties.
+    fn limit_without_ties(&self) -> Option<usize> {
+        match self.limit_with_ties {
+            true => None,
+            false => self.limit,
+        }
+    }
+
     // The expected output block size, the actual output block size will be equal to or less than the given value.
     pub fn with_block_size_hit(mut self, block_size: usize) -> Self {
         self.block_size = self.block_size.min(block_size);
@@ -188,7 +213,7 @@ impl SortPipelineBuilder {
         // Partial sort
         pipeline.add_transformer(|| {
             TransformSortPartial::new(
-                LimitType::from_limit_rows(self.limit),
+                LimitType::from_limit_rows(self.limit_without_ties()),
                 self.sort_desc.clone(),
             )
         });
@@ -265,6 +290,7 @@ impl SortPipelineBuilder {
                 self.block_size,
             )
             .with_limit(self.limit)
+            .with_limit_with_ties(self.limit_with_ties)
             .with_order_col_generated(order_col_generated)
             .with_output_order_col(output_order_col || may_spill)
             .with_max_memory_usage(max_memory_usage)
@@ -294,7 +320,7 @@ impl SortPipelineBuilder {
                     output,
                     schema.clone(),
                     self.sort_desc.clone(),
-                    self.limit,
+                    self.limit_without_ties(),
                     spiller,
                     output_order_col,
                     enable_loser_tree,
@@ -320,7 +346,7 @@ impl SortPipelineBuilder {
                 self.schema.clone(),
                 max_threads,
                 self.block_size,
-                self.limit,
+                self.limit_without_ties(),
                 self.sort_desc,
                 self.remove_order_col_at_last,
                 enable_loser_tree,
@@ -330,7 +356,7 @@ impl SortPipelineBuilder {
                 pipeline,
                 self.schema.clone(),
                 self.block_size,
-                self.limit,
+                self.limit_without_ties(),
                 self.sort_desc,
                 self.remove_order_col_at_last,
                 enable_loser_tree,
@@ -22,13 +22,26 @@ use databend_common_storages_fuse::TableContext;
 
 use crate::pipelines::processors::transforms::TransformUdfScript;
 use crate::pipelines::processors::transforms::TransformUdfServer;
+use crate::pipelines::processors::transforms::TransformUdtfScript;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
     pub(crate) fn build_udf(&mut self, udf: &Udf) -> Result<()> {
         self.build_pipeline(&udf.input)?;
 
-        if udf.script_udf {
+        if udf.table_function {
+            let index_seq = Arc::new(AtomicUsize::new(0));
+            let runtime_num = self.ctx.get_settings().get_max_threads()? as usize;
+            let func = &udf.udf_funcs[0];
+            let runtime = TransformUdtfScript::init_runtime(func, runtime_num)?;
+            self.main_pipeline.try_add_transformer(|| {
+                Ok(TransformUdtfScript::new(
+                    func.clone(),
+                    runtime.clone(),
+                    index_seq.clone(),
+                ))
+            })
+        } else if udf.script_udf {
             let index_seq = Arc::new(AtomicUsize::new(0));
             let runtime_num = self.ctx.get_settings().get_max_threads()? as usize;
             let runtimes = TransformUdfScript::init_runtime(&udf.udf_funcs, runtime_num)?;
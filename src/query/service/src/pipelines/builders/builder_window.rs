@@ -174,26 +174,7 @@ impl PipelineBuilder {
             WindowPartitionExchange::create(partition_by.clone(), num_partitions),
         );
 
-        let disk_bytes_limit = settings.get_window_partition_spilling_to_disk_bytes_limit()?;
-        let temp_dir_manager = TempDirManager::instance();
-
-        let enable_dio = settings.get_enable_dio()?;
-        let disk_spill =
-            match temp_dir_manager.get_disk_spill_dir(disk_bytes_limit, &self.ctx.get_id()) {
-                Some(temp_dir) if !enable_dio => {
-                    let builder = Fs::default().root(temp_dir.path().to_str().unwrap());
-                    Some(SpillerDiskConfig {
-                        temp_dir,
-                        local_operator: Some(Operator::new(builder)?.finish()),
-                    })
-                }
-                Some(temp_dir) => Some(SpillerDiskConfig {
-                    temp_dir,
-                    local_operator: None,
-                }),
-                None => None,
-            };
-
+        let disk_spill = self.window_disk_spill_config()?;
         let window_spill_settings = WindowSpillSettings::new(&settings, num_processors)?;
         let have_order_col = window_partition.after_exchange.unwrap_or(false);
 
@@ -217,4 +198,29 @@ impl PipelineBuilder {
             )))
         })
     }
+
+    // The config to spill the window partitions to the local disk.
+    pub(crate) fn window_disk_spill_config(&self) -> Result<Option<SpillerDiskConfig>> {
+        let settings = self.ctx.get_settings();
+        let disk_bytes_limit = settings.get_window_partition_spilling_to_disk_bytes_limit()?;
+        let temp_dir_manager = TempDirManager::instance();
+
+        let enable_dio = settings.get_enable_dio()?;
+        let disk_spill =
+            match temp_dir_manager.get_disk_spill_dir(disk_bytes_limit, &self.ctx.get_id()) {
+                Some(temp_dir) if !enable_dio => {
+                    let builder = Fs::default().root(temp_dir.path().to_str().unwrap());
+                    Some(SpillerDiskConfig {
+                        temp_dir,
+                        local_operator: Some(Operator::new(builder)?.finish()),
+                    })
+                }
+                Some(temp_dir) => Some(SpillerDiskConfig {
+                    temp_dir,
+                    local_operator: None,
+                }),
+                None => None,
+            };
+        Ok(disk_spill)
+    }
 }
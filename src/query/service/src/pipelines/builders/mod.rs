@@ -28,6 +28,7 @@ mod builder_filter;
 mod builder_insert_multi_table;
 mod builder_join;
 mod builder_limit;
+mod builder_match_recognize;
 mod builder_mutation;
 mod builder_mutation_manipulate;
 mod builder_mutation_organize;
@@ -18,6 +18,9 @@ use std::time::Duration;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 
+use crate::sessions::WorkloadGroupResource;
+use crate::sessions::WorkloadGroupResourceManager;
+
 #[derive(Clone)]
 pub struct ExecutorSettings {
     pub query_id: Arc<String>,
@@ -25,21 +28,29 @@ pub struct ExecutorSettings {
     pub enable_queries_executor: bool,
     pub max_execute_time_in_seconds: Duration,
     pub executor_node_id: String,
+    pub workload_group: Option<Arc<WorkloadGroupResource>>,
 }
 
 impl ExecutorSettings {
     pub fn try_create(ctx: Arc<dyn TableContext>) -> Result<ExecutorSettings> {
         let query_id = ctx.get_id();
         let settings = ctx.get_settings();
-        let max_threads = settings.get_max_threads()?;
+        let mut max_threads = settings.get_max_threads()?;
         let max_execute_time_in_seconds = settings.get_max_execute_time_in_seconds()?;
 
+        let manager = WorkloadGroupResourceManager::instance();
+        let workload_group = manager.get_by_query(&query_id);
+        if let Some(group) = &workload_group {
+            max_threads = manager.thread_share(group, max_threads);
+        }
+
         Ok(ExecutorSettings {
             enable_queries_executor: settings.get_enable_experimental_queries_executor()?,
             query_id: Arc::new(query_id),
             max_execute_time_in_seconds: Duration::from_secs(max_execute_time_in_seconds),
             max_threads,
             executor_node_id: ctx.get_cluster().local_id.clone(),
+            workload_group,
         })
     }
 }
@@ -170,6 +170,9 @@ impl PipelineBuilder {
             PhysicalPlan::WindowPartition(window_partition) => {
                 self.build_window_partition(window_partition)
             }
+            PhysicalPlan::MatchRecognize(match_recognize) => {
+                self.build_match_recognize(match_recognize)
+            }
             PhysicalPlan::Sort(sort) => self.build_sort(sort),
             PhysicalPlan::Limit(limit) => self.build_limit(limit),
             PhysicalPlan::RowFetch(row_fetch) => self.build_row_fetch(row_fetch),
@@ -0,0 +1,298 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::Read;
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow_array::ArrayRef;
+use arrow_array::RecordBatch;
+use arrow_ipc::reader::StreamReader;
+use arrow_ipc::writer::StreamWriter;
+use arrow_schema::Field;
+use arrow_schema::Schema;
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::infer_schema_type;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::InputColumns;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::Value;
+use databend_common_functions::aggregates::AggregateFunction;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+use databend_common_functions::aggregates::AggregateFunctionRef;
+use databend_common_functions::aggregates::StateAddr;
+use databend_common_sql::executor::physical_plans::AggregateFunctionSignature;
+use databend_common_sql::plans::UDAFDesc;
+use databend_common_sql::plans::UDFType;
+
+use crate::pipelines::processors::transforms::ScriptRuntime;
+
+/// Creates the aggregate function of the signature, either a built-in
+/// or a user-defined aggregate function.
+pub fn create_aggregate_function(sig: &AggregateFunctionSignature) -> Result<AggregateFunctionRef> {
+    match &sig.udaf {
+        Some(udaf) => Ok(Arc::new(AggregateUdfScript::try_create(
+            &sig.name,
+            sig.args.clone(),
+            udaf,
+        )?)),
+        None => AggregateFunctionFactory::instance().get(
+            sig.name.as_str(),
+            sig.params.clone(),
+            sig.args.clone(),
+        ),
+    }
+}
+
+/// A user-defined aggregate function implemented in a script language.
+///
+/// The state of each group is an arrow array of one element, whose type is
+/// a struct of the state fields of the function.
+pub struct AggregateUdfScript {
+    name: String,
+    runtime: Arc<ScriptRuntime>,
+    argument_schema: DataSchema,
+    init_state: ArrayRef,
+    return_type: DataType,
+}
+
+// The script runtimes are guarded by locks.
+unsafe impl Send for AggregateUdfScript {}
+unsafe impl Sync for AggregateUdfScript {}
+
+struct UdfScriptState {
+    state: ArrayRef,
+}
+
+impl AggregateUdfScript {
+    pub fn try_create(name: &str, arguments: Vec<DataType>, udaf: &UDAFDesc) -> Result<Self> {
+        let UDFType::Script((lang, _, code)) = &udaf.udf_type else {
+            return Err(ErrorCode::UDFDataError(format!(
+                "Unsupported UDFType variant for aggregate function '{}'",
+                name
+            )));
+        };
+
+        let fields_type = udaf
+            .state_types
+            .iter()
+            .map(infer_schema_type)
+            .collect::<Result<Vec<_>>>()?;
+        let state_field = Field::from(&TableField::new("state", TableDataType::Tuple {
+            fields_name: udaf.state_names.clone(),
+            fields_type,
+        }));
+        let output_field = Field::from(&DataField::new("output", udaf.return_type.clone()));
+
+        let runtime = ScriptRuntime::try_create(lang.trim(), Some(code), 1)?;
+        runtime.add_aggregate(name, state_field, output_field, code)?;
+        let init_state = runtime.create_state(name)?;
+
+        let argument_schema = DataSchema::new(
+            arguments
+                .into_iter()
+                .enumerate()
+                .map(|(idx, ty)| DataField::new(&format!("arg{}", idx + 1), ty))
+                .collect(),
+        );
+
+        Ok(Self {
+            name: name.to_string(),
+            runtime: Arc::new(runtime),
+            argument_schema,
+            init_state,
+            return_type: udaf.return_type.clone(),
+        })
+    }
+
+    fn create_input_batch(&self, columns: InputColumns, rows: Range<usize>) -> Result<DataBlock> {
+        let entries = columns
+            .iter()
+            .zip(self.argument_schema.fields())
+            .map(|(column, field)| {
+                BlockEntry::new(
+                    field.data_type().clone(),
+                    Value::Column(column.slice(rows.clone())),
+                )
+            })
+            .collect();
+        Ok(DataBlock::new(entries, rows.len()))
+    }
+
+    fn accumulate_block(&self, place: StateAddr, block: DataBlock) -> Result<()> {
+        if block.num_rows() == 0 {
+            return Ok(());
+        }
+        let batch = block.to_record_batch_with_dataschema(&self.argument_schema)?;
+        let state = place.get::<UdfScriptState>();
+        state.state = self.runtime.accumulate(&self.name, &state.state, &batch)?;
+        Ok(())
+    }
+
+    fn merge_state(&self, place: StateAddr, other: &ArrayRef) -> Result<()> {
+        let state = place.get::<UdfScriptState>();
+        let states = arrow_select::concat::concat(&[state.state.as_ref(), other.as_ref()])?;
+        state.state = self.runtime.merge(&self.name, &states)?;
+        Ok(())
+    }
+}
+
+impl AggregateFunction for AggregateUdfScript {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| UdfScriptState {
+            state: self.init_state.clone(),
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<UdfScriptState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: InputColumns,
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let block = self.create_input_batch(columns, 0..input_rows)?;
+        let block = match validity {
+            Some(validity) => block.filter_with_bitmap(validity)?,
+            None => block,
+        };
+        self.accumulate_block(place, block)
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: InputColumns,
+        input_rows: usize,
+    ) -> Result<()> {
+        // Call the script once for each group instead of once for each row.
+        let mut groups: HashMap<usize, Vec<u32>> = HashMap::new();
+        for (row, place) in places.iter().enumerate() {
+            groups
+                .entry(place.next(offset).addr())
+                .or_default()
+                .push(row as u32);
+        }
+
+        let block = self.create_input_batch(columns, 0..input_rows)?;
+        for (addr, rows) in groups {
+            let group = block.take(&rows, &mut None)?;
+            self.accumulate_block(StateAddr::new(addr), group)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: InputColumns, row: usize) -> Result<()> {
+        let block = self.create_input_batch(columns, row..row + 1)?;
+        self.accumulate_block(place, block)
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<UdfScriptState>();
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "state",
+            state.state.data_type().clone(),
+            true,
+        )]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![state.state.clone()])?;
+
+        let mut buf = Vec::new();
+        let mut stream = StreamWriter::try_new(&mut buf, &schema)?;
+        stream.write(&batch)?;
+        stream.finish()?;
+        drop(stream);
+
+        writer.extend_from_slice(&(buf.len() as u64).to_le_bytes());
+        writer.extend_from_slice(&buf);
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let mut len = [0u8; 8];
+        reader.read_exact(&mut len)?;
+        let len = u64::from_le_bytes(len) as usize;
+        if reader.len() < len {
+            return Err(ErrorCode::BadBytes(format!(
+                "Invalid state of aggregate function '{}': expected {} bytes, got {}",
+                self.name,
+                len,
+                reader.len()
+            )));
+        }
+        let (buf, rest) = reader.split_at(len);
+        *reader = rest;
+
+        let stream = StreamReader::try_new(buf, None)?;
+        for batch in stream {
+            self.merge_state(place, batch?.column(0))?;
+        }
+        Ok(())
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let other = rhs.get::<UdfScriptState>().state.clone();
+        self.merge_state(place, &other)
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<UdfScriptState>();
+        let output = self.runtime.finish(&self.name, &state.state)?;
+        let column = Column::from_arrow_rs(output, &self.return_type)?;
+        match column.index(0) {
+            Some(value) => builder.push(value),
+            None => builder.push_default(),
+        }
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<UdfScriptState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateUdfScript {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
@@ -0,0 +1,210 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+
+use crate::pipelines::processors::transforms::WindowSpillSettings;
+use crate::spillers::Location;
+use crate::spillers::Spiller;
+
+struct BufferedBlock {
+    // The first row of the block in the partition.
+    offset: usize,
+    num_rows: usize,
+    // `None` if the block is spilled and not restored.
+    data: Option<DataBlock>,
+    location: Option<Location>,
+}
+
+/// The `MatchRecognizeBuffer` holds the rows of the partition being matched, and controls
+/// the memory usage of the MatchRecognize operator.
+///
+/// The blocks are spilled once the memory is out of limit, and restored by row ranges when
+/// the matching reaches them again.
+pub struct MatchRecognizeBuffer {
+    spiller: Spiller,
+    spill_settings: WindowSpillSettings,
+    max_block_size: usize,
+    blocks: Vec<BufferedBlock>,
+    num_rows: usize,
+    memory_size: usize,
+}
+
+impl MatchRecognizeBuffer {
+    pub fn new(
+        spiller: Spiller,
+        spill_settings: WindowSpillSettings,
+        max_block_size: usize,
+    ) -> Self {
+        Self {
+            spiller,
+            spill_settings,
+            max_block_size,
+            blocks: vec![],
+            num_rows: 0,
+            memory_size: 0,
+        }
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.num_rows == 0
+    }
+
+    // The end of the block that contains the row.
+    pub fn block_end(&self, row: usize) -> usize {
+        let block = &self.blocks[self.block_index(row)];
+        block.offset + block.num_rows
+    }
+
+    pub fn add_data_block(&mut self, data_block: DataBlock) {
+        if data_block.is_empty() {
+            return;
+        }
+        let num_rows = data_block.num_rows();
+        self.memory_size += data_block.memory_size();
+        self.blocks.push(BufferedBlock {
+            offset: self.num_rows,
+            num_rows,
+            data: Some(data_block),
+            location: None,
+        });
+        self.num_rows += num_rows;
+    }
+
+    pub fn need_spill(&self) -> bool {
+        self.spill_settings.enable_spill()
+            && self.memory_size > 0
+            && self.spill_settings.out_of_memory_limit(self.memory_size)
+    }
+
+    // Spill the blocks in memory, the blocks that were spilled before are only released.
+    pub async fn spill(&mut self) -> Result<()> {
+        let mut blocks = Vec::with_capacity(self.blocks.len());
+        let mut to_spill: Vec<BufferedBlock> = vec![];
+        for block in std::mem::take(&mut self.blocks) {
+            if block.data.is_none() || block.location.is_some() {
+                self.spill_blocks(&mut blocks, std::mem::take(&mut to_spill))
+                    .await?;
+                blocks.push(BufferedBlock {
+                    data: None,
+                    ..block
+                });
+                continue;
+            }
+            // Merge the small blocks into one file.
+            let num_rows = to_spill.iter().map(|block| block.num_rows).sum::<usize>();
+            if num_rows + block.num_rows > self.max_block_size {
+                self.spill_blocks(&mut blocks, std::mem::take(&mut to_spill))
+                    .await?;
+            }
+            to_spill.push(block);
+        }
+        self.spill_blocks(&mut blocks, to_spill).await?;
+        self.blocks = blocks;
+        self.memory_size = 0;
+        Ok(())
+    }
+
+    async fn spill_blocks(
+        &mut self,
+        blocks: &mut Vec<BufferedBlock>,
+        to_spill: Vec<BufferedBlock>,
+    ) -> Result<()> {
+        let Some(offset) = to_spill.first().map(|block| block.offset) else {
+            return Ok(());
+        };
+        let num_rows = to_spill.iter().map(|block| block.num_rows).sum();
+        let data_blocks = to_spill
+            .into_iter()
+            .filter_map(|block| block.data)
+            .collect();
+        let location = self.spiller.spill(data_blocks).await?;
+        blocks.push(BufferedBlock {
+            offset,
+            num_rows,
+            data: None,
+            location: Some(location),
+        });
+        Ok(())
+    }
+
+    pub fn is_restored(&self, rows: &Range<usize>) -> bool {
+        self.blocks_of(rows).all(|block| block.data.is_some())
+    }
+
+    // Restore the spilled blocks that contain the rows.
+    pub async fn restore(&mut self, rows: &[Range<usize>]) -> Result<()> {
+        for rows in rows.iter() {
+            let range = self.block_range(rows);
+            for block in self.blocks[range].iter_mut() {
+                if block.data.is_some() {
+                    continue;
+                }
+                let location = block.location.as_ref().unwrap();
+                let data_block = self.spiller.read_spilled_file(location).await?;
+                self.memory_size += data_block.memory_size();
+                block.data = Some(data_block);
+            }
+        }
+        Ok(())
+    }
+
+    // Take the rows, which must be restored.
+    pub fn take_rows(&self, rows: Range<usize>) -> Result<DataBlock> {
+        let data_blocks = self
+            .blocks_of(&rows)
+            .map(|block| {
+                let begin = rows.start.max(block.offset) - block.offset;
+                let end = rows.end.min(block.offset + block.num_rows) - block.offset;
+                let data_block = block.data.as_ref().unwrap();
+                if begin == 0 && end == block.num_rows {
+                    data_block.clone()
+                } else {
+                    data_block.slice(begin..end)
+                }
+            })
+            .collect::<Vec<_>>();
+        DataBlock::concat(&data_blocks)
+    }
+
+    pub fn clear(&mut self) {
+        self.blocks.clear();
+        self.num_rows = 0;
+        self.memory_size = 0;
+    }
+
+    // The index of the block that contains the row.
+    fn block_index(&self, row: usize) -> usize {
+        self.blocks
+            .partition_point(|block| block.offset + block.num_rows <= row)
+    }
+
+    fn block_range(&self, rows: &Range<usize>) -> Range<usize> {
+        if rows.is_empty() {
+            return 0..0;
+        }
+        self.block_index(rows.start)..self.block_index(rows.end - 1) + 1
+    }
+
+    fn blocks_of(&self, rows: &Range<usize>) -> impl Iterator<Item = &BufferedBlock> {
+        self.blocks[self.block_range(rows)].iter()
+    }
+}
@@ -0,0 +1,277 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::rc::Rc;
+
+use databend_common_sql::plans::MatchPattern;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Inst {
+    // Consume a row which satisfies the condition of the variable.
+    Variable(usize),
+    // Fork the thread, the first branch has the higher priority.
+    Split(usize, usize),
+    Jump(usize),
+    Match,
+}
+
+// The variables of the rows consumed by a thread, in reverse order.
+struct Classifier {
+    variable: usize,
+    prev: Option<Rc<Classifier>>,
+}
+
+struct Thread {
+    pc: usize,
+    len: usize,
+    classifiers: Option<Rc<Classifier>>,
+}
+
+/// A row pattern compiled into a program of a Pike VM.
+///
+/// The threads are kept in the order of priority, so the first match follows the
+/// preferment rules of the standard: alternatives are tried from left to right and the
+/// quantifiers are greedy.
+pub struct RowPatternMatcher {
+    program: Vec<Inst>,
+}
+
+impl RowPatternMatcher {
+    pub fn create(pattern: &MatchPattern) -> Self {
+        let mut program = vec![];
+        compile(pattern, &mut program);
+        program.push(Inst::Match);
+        RowPatternMatcher { program }
+    }
+
+    /// Match the rows from `start` to `num_rows`, `matched(variable, row)` tells whether the
+    /// row satisfies the condition of the variable.
+    ///
+    /// Returns the variables of the matched rows, empty matches are ignored.
+    pub fn find_match(
+        &self,
+        start: usize,
+        num_rows: usize,
+        matched: impl Fn(usize, usize) -> bool,
+    ) -> Option<Vec<usize>> {
+        let mut visited = vec![false; self.program.len()];
+        let mut current = vec![];
+        self.add_thread(&mut current, &mut visited, Thread {
+            pc: 0,
+            len: 0,
+            classifiers: None,
+        });
+
+        let mut result = None;
+        let mut row = start;
+        while !current.is_empty() {
+            visited.iter_mut().for_each(|v| *v = false);
+            let mut next = vec![];
+            for thread in current {
+                match self.program[thread.pc] {
+                    Inst::Match => {
+                        if thread.len > 0 {
+                            // The threads after this one have the lower priority.
+                            result = Some(thread.classifiers);
+                            break;
+                        }
+                    }
+                    Inst::Variable(variable) => {
+                        if row < num_rows && matched(variable, row) {
+                            self.add_thread(&mut next, &mut visited, Thread {
+                                pc: thread.pc + 1,
+                                len: thread.len + 1,
+                                classifiers: Some(Rc::new(Classifier {
+                                    variable,
+                                    prev: thread.classifiers,
+                                })),
+                            });
+                        }
+                    }
+                    Inst::Split(_, _) | Inst::Jump(_) => unreachable!(),
+                }
+            }
+            current = next;
+            row += 1;
+        }
+
+        result.map(|classifiers| {
+            let mut variables = vec![];
+            let mut cur = classifiers;
+            while let Some(classifier) = cur {
+                variables.push(classifier.variable);
+                cur = classifier.prev.clone();
+            }
+            variables.reverse();
+            variables
+        })
+    }
+
+    fn add_thread(&self, threads: &mut Vec<Thread>, visited: &mut [bool], thread: Thread) {
+        if visited[thread.pc] {
+            return;
+        }
+        visited[thread.pc] = true;
+        match self.program[thread.pc] {
+            Inst::Jump(pc) => self.add_thread(threads, visited, Thread { pc, ..thread }),
+            Inst::Split(first, second) => {
+                self.add_thread(threads, visited, Thread {
+                    pc: first,
+                    len: thread.len,
+                    classifiers: thread.classifiers.clone(),
+                });
+                self.add_thread(threads, visited, Thread {
+                    pc: second,
+                    ..thread
+                });
+            }
+            _ => threads.push(thread),
+        }
+    }
+}
+
+fn compile(pattern: &MatchPattern, program: &mut Vec<Inst>) {
+    match pattern {
+        MatchPattern::Variable(variable) => program.push(Inst::Variable(*variable)),
+        MatchPattern::Concat(patterns) => {
+            for pattern in patterns {
+                compile(pattern, program);
+            }
+        }
+        MatchPattern::Alternation(patterns) => {
+            // split L1, next; L1: p1; jump end; next: split L2, ...; pn; end:
+            let mut jumps = vec![];
+            for (i, pattern) in patterns.iter().enumerate() {
+                if i + 1 < patterns.len() {
+                    let split = program.len();
+                    program.push(Inst::Split(split + 1, 0));
+                    compile(pattern, program);
+                    jumps.push(program.len());
+                    program.push(Inst::Jump(0));
+                    program[split] = Inst::Split(split + 1, program.len());
+                } else {
+                    compile(pattern, program);
+                }
+            }
+            let end = program.len();
+            for jump in jumps {
+                program[jump] = Inst::Jump(end);
+            }
+        }
+        MatchPattern::Repetition { pattern, min, max } => {
+            for _ in 0..*min {
+                compile(pattern, program);
+            }
+            match max {
+                None => {
+                    // L: split body, end; body; jump L; end:
+                    let split = program.len();
+                    program.push(Inst::Split(split + 1, 0));
+                    compile(pattern, program);
+                    program.push(Inst::Jump(split));
+                    program[split] = Inst::Split(split + 1, program.len());
+                }
+                Some(max) => {
+                    // split body, end; body; split body, end; body; ... end:
+                    let mut splits = vec![];
+                    for _ in *min..*max {
+                        splits.push(program.len());
+                        program.push(Inst::Split(program.len() + 1, 0));
+                        compile(pattern, program);
+                    }
+                    let end = program.len();
+                    for split in splits {
+                        program[split] = Inst::Split(split + 1, end);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use databend_common_sql::plans::MatchPattern;
+
+    use super::RowPatternMatcher;
+
+    const A: usize = 0;
+    const B: usize = 1;
+    const C: usize = 2;
+
+    fn find_match(pattern: MatchPattern, rows: &[&[usize]], start: usize) -> Option<Vec<usize>> {
+        let matcher = RowPatternMatcher::create(&pattern);
+        matcher.find_match(start, rows.len(), |variable, row| {
+            rows[row].contains(&variable)
+        })
+    }
+
+    fn repeat(pattern: MatchPattern, min: u64, max: Option<u64>) -> MatchPattern {
+        MatchPattern::Repetition {
+            pattern: Box::new(pattern),
+            min,
+            max,
+        }
+    }
+
+    #[test]
+    fn test_greedy_quantifier() {
+        // A B+ C?
+        let pattern = MatchPattern::Concat(vec![
+            MatchPattern::Variable(A),
+            repeat(MatchPattern::Variable(B), 1, None),
+            repeat(MatchPattern::Variable(C), 0, Some(1)),
+        ]);
+        let rows: &[&[usize]] = &[&[A], &[B, C], &[B, C], &[C], &[A]];
+        assert_eq!(find_match(pattern.clone(), rows, 0), Some(vec![A, B, B, C]));
+        assert_eq!(find_match(pattern.clone(), rows, 1), None);
+        assert_eq!(find_match(pattern, rows, 4), None);
+    }
+
+    #[test]
+    fn test_alternation_preferment() {
+        // (A | A B) C
+        let pattern = MatchPattern::Concat(vec![
+            MatchPattern::Alternation(vec![
+                MatchPattern::Variable(A),
+                MatchPattern::Concat(vec![MatchPattern::Variable(A), MatchPattern::Variable(B)]),
+            ]),
+            MatchPattern::Variable(C),
+        ]);
+        let rows: &[&[usize]] = &[&[A], &[B, C], &[C]];
+        assert_eq!(find_match(pattern, rows, 0), Some(vec![A, C]));
+    }
+
+    #[test]
+    fn test_bounded_quantifier() {
+        // A{2,3}
+        let pattern = repeat(MatchPattern::Variable(A), 2, Some(3));
+        let rows: &[&[usize]] = &[&[A], &[A], &[A], &[A]];
+        assert_eq!(find_match(pattern.clone(), rows, 0), Some(vec![A, A, A]));
+        assert_eq!(find_match(pattern.clone(), rows, 2), Some(vec![A, A]));
+        assert_eq!(find_match(pattern, rows, 3), None);
+    }
+
+    #[test]
+    fn test_empty_match() {
+        // A* B?
+        let pattern = MatchPattern::Concat(vec![
+            repeat(MatchPattern::Variable(A), 0, None),
+            repeat(MatchPattern::Variable(B), 0, Some(1)),
+        ]);
+        let rows: &[&[usize]] = &[&[C], &[A]];
+        assert_eq!(find_match(pattern.clone(), rows, 0), None);
+        assert_eq!(find_match(pattern, rows, 1), Some(vec![A]));
+    }
+}
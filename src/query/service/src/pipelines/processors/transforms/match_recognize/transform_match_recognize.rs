@@ -0,0 +1,812 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::InputColumns;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
+use databend_common_functions::aggregates::get_layout_offsets;
+use databend_common_functions::aggregates::AggregateFunctionRef;
+use databend_common_functions::aggregates::StateAddr;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_core::query_spill_prefix;
+use databend_common_sql::executor::physical_plans::MatchFunctionKind;
+use databend_common_sql::executor::physical_plans::MatchRecognize;
+use databend_common_sql::plans::AfterMatchSkip;
+use databend_common_storage::DataOperator;
+
+use super::row_pattern::RowPatternMatcher;
+use super::MatchRecognizeBuffer;
+use crate::pipelines::processors::transforms::aggregator::create_aggregate_function;
+use crate::pipelines::processors::transforms::WindowSpillSettings;
+use crate::sessions::QueryContext;
+use crate::spillers::Spiller;
+use crate::spillers::SpillerConfig;
+use crate::spillers::SpillerDiskConfig;
+use crate::spillers::SpillerType;
+
+struct NavigationInfo {
+    arg: usize,
+    is_prev: bool,
+    offset: usize,
+    data_type: DataType,
+}
+
+impl NavigationInfo {
+    // The row that `PREV` or `NEXT` of the row reaches, if it's in the partition.
+    fn source(&self, row: usize, num_rows: usize) -> Option<usize> {
+        if self.is_prev {
+            row.checked_sub(self.offset)
+        } else {
+            row.checked_add(self.offset)
+                .filter(|source| *source < num_rows)
+        }
+    }
+
+    // The rows that `PREV` or `NEXT` of the rows reach.
+    fn sources(&self, rows: &Range<usize>, num_rows: usize) -> Range<usize> {
+        if self.is_prev {
+            rows.start.saturating_sub(self.offset)..rows.end.saturating_sub(self.offset)
+        } else {
+            rows.start.saturating_add(self.offset).min(num_rows)
+                ..rows.end.saturating_add(self.offset).min(num_rows)
+        }
+    }
+}
+
+enum MatchFunctionInfo {
+    MatchNumber,
+    Classifier,
+    First {
+        variable: Option<usize>,
+        arg: usize,
+    },
+    Last {
+        variable: Option<usize>,
+        arg: usize,
+    },
+    Aggregate {
+        variable: Option<usize>,
+        func: AggregateFunctionRef,
+        args: Vec<usize>,
+        place: StateAddr,
+    },
+}
+
+impl MatchFunctionInfo {
+    fn variable(&self) -> Option<usize> {
+        match self {
+            MatchFunctionInfo::MatchNumber | MatchFunctionInfo::Classifier => None,
+            MatchFunctionInfo::First { variable, .. }
+            | MatchFunctionInfo::Last { variable, .. }
+            | MatchFunctionInfo::Aggregate { variable, .. } => *variable,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Step {
+    Collect,
+    Spill,
+    Match,
+    Restore,
+    Finish,
+}
+
+struct CurrentMatch {
+    start: usize,
+    classifiers: Vec<usize>,
+    // The rows of the match whose match functions are computed.
+    num_computed_rows: usize,
+}
+
+// The state of matching a partition.
+struct MatchState {
+    // Whether the rows satisfy the condition of each pattern variable, the rows are
+    // evaluated a block at a time.
+    matched: Vec<Option<Vec<bool>>>,
+    num_evaluated_rows: usize,
+    // The row to find the next match from.
+    start: usize,
+    match_number: u64,
+    current: Option<CurrentMatch>,
+    // The values of `FIRST` and `LAST` in the current match.
+    values: Vec<Option<Scalar>>,
+    // The match functions and the input rows of the output rows not sent yet.
+    builders: Vec<ColumnBuilder>,
+    rows: Vec<DataBlock>,
+    num_output_rows: usize,
+}
+
+impl MatchState {
+    fn create(
+        conditions: &[Option<Expr>],
+        functions: &[(MatchFunctionInfo, DataType)],
+    ) -> MatchState {
+        MatchState {
+            matched: conditions
+                .iter()
+                .map(|condition| condition.as_ref().map(|_| vec![]))
+                .collect(),
+            num_evaluated_rows: 0,
+            start: 0,
+            match_number: 0,
+            current: None,
+            values: vec![None; functions.len()],
+            builders: create_builders(functions),
+            rows: vec![],
+            num_output_rows: 0,
+        }
+    }
+}
+
+fn create_builders(functions: &[(MatchFunctionInfo, DataType)]) -> Vec<ColumnBuilder> {
+    functions
+        .iter()
+        .map(|(_, data_type)| ColumnBuilder::with_capacity(data_type, 0))
+        .collect()
+}
+
+/// Matches the rows of each partition against the row pattern.
+///
+/// The input is sorted by the partition and order by items, and the rows of a partition
+/// are sent to the same processor by the upstream `WindowPartition`. So a partition is
+/// complete once the partition key changes.
+///
+/// A match may navigate to any row of its partition, so the partition is collected into
+/// a `MatchRecognizeBuffer` before matching, which spills the blocks once the memory is out
+/// of limit. The partition is then matched a block at a time: the conditions of the pattern
+/// variables are evaluated first, and the spilled rows that `PREV` and `NEXT` reach are
+/// restored. Then the matches are found and the match functions are computed over the
+/// rows of each match, restoring them as well. `FIRST` and `LAST` keep their values, so
+/// the rows of a match don't need to be restored at the same time.
+pub struct TransformMatchRecognize {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+
+    func_ctx: FunctionContext,
+    partition_by: Vec<usize>,
+    partition_types: Vec<DataType>,
+    navigations: Vec<NavigationInfo>,
+    variables: Vec<String>,
+    conditions: Vec<Option<Expr>>,
+    matcher: RowPatternMatcher,
+    functions: Vec<(MatchFunctionInfo, DataType)>,
+    measures: Vec<(Expr, DataType)>,
+    all_rows_per_match: bool,
+    after_match_skip: AfterMatchSkip,
+    max_block_size: usize,
+
+    // Need to hold arena until `drop`.
+    _arena: Bump,
+    // The rows and the partition key of the current partition.
+    buffer: MatchRecognizeBuffer,
+    partition: Option<Vec<Scalar>>,
+    state: MatchState,
+    // The rows to restore before matching goes on.
+    restore_rows: Vec<Range<usize>>,
+
+    // The input block and the first row that's not collected, which is in a next partition.
+    input_data_block: Option<(DataBlock, usize)>,
+    output_data_blocks: VecDeque<DataBlock>,
+    step: Step,
+    is_input_finished: bool,
+}
+
+impl TransformMatchRecognize {
+    #[expect(clippy::too_many_arguments)]
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        func_ctx: FunctionContext,
+        plan: &MatchRecognize,
+        input_schema: &DataSchemaRef,
+        spill_settings: WindowSpillSettings,
+        disk_spill: Option<SpillerDiskConfig>,
+    ) -> Result<Self> {
+        let partition_by = plan
+            .partition_by
+            .iter()
+            .map(|index| input_schema.index_of(&index.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        let partition_types = partition_by
+            .iter()
+            .map(|offset| input_schema.field(*offset).data_type().clone())
+            .collect();
+
+        let navigations = plan
+            .navigations
+            .iter()
+            .map(|navigation| {
+                let arg = input_schema.index_of(&navigation.arg.to_string())?;
+                Ok(NavigationInfo {
+                    arg,
+                    is_prev: navigation.is_prev,
+                    offset: usize::try_from(navigation.offset).unwrap_or(usize::MAX),
+                    data_type: input_schema.field(arg).data_type().wrap_nullable(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let conditions = plan
+            .conditions
+            .iter()
+            .map(|condition| {
+                condition
+                    .as_ref()
+                    .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS))
+            })
+            .collect();
+
+        let arena = Bump::new();
+        let mut functions = Vec::with_capacity(plan.functions.len());
+        for desc in plan.functions.iter() {
+            let func = match &desc.func {
+                MatchFunctionKind::MatchNumber => MatchFunctionInfo::MatchNumber,
+                MatchFunctionKind::Classifier => MatchFunctionInfo::Classifier,
+                MatchFunctionKind::First { variable, arg } => MatchFunctionInfo::First {
+                    variable: *variable,
+                    arg: input_schema.index_of(&arg.to_string())?,
+                },
+                MatchFunctionKind::Last { variable, arg } => MatchFunctionInfo::Last {
+                    variable: *variable,
+                    arg: input_schema.index_of(&arg.to_string())?,
+                },
+                MatchFunctionKind::Aggregate { variable, agg } => {
+                    let func = create_aggregate_function(&agg.sig)?;
+                    let args = agg
+                        .arg_indices
+                        .iter()
+                        .map(|index| input_schema.index_of(&index.to_string()))
+                        .collect::<Result<Vec<_>>>()?;
+                    let mut offsets = Vec::with_capacity(1);
+                    let layout = get_layout_offsets(&[func.clone()], &mut offsets)?;
+                    let place: StateAddr = arena.alloc_layout(layout).into();
+                    MatchFunctionInfo::Aggregate {
+                        variable: *variable,
+                        func,
+                        args,
+                        place: place.next(offsets[0]),
+                    }
+                }
+            };
+            functions.push((func, desc.data_type.clone()));
+        }
+
+        let measures = plan
+            .measures
+            .iter()
+            .map(|measure| {
+                (
+                    measure.expr.as_expr(&BUILTIN_FUNCTIONS),
+                    measure.data_type.clone(),
+                )
+            })
+            .collect();
+
+        let settings = ctx.get_settings();
+        let spill_config = SpillerConfig {
+            spiller_type: SpillerType::Window,
+            location_prefix: query_spill_prefix(ctx.get_tenant().tenant_name(), &ctx.get_id()),
+            disk_spill,
+            use_parquet: settings.get_spilling_file_format()?.is_parquet(),
+        };
+        let operator = DataOperator::instance().operator();
+        let spiller = Spiller::create(ctx.clone(), operator, spill_config)?;
+        let max_block_size = settings.get_max_block_size()? as usize;
+        let buffer = MatchRecognizeBuffer::new(spiller, spill_settings, max_block_size);
+
+        let state = MatchState::create(&conditions, &functions);
+        Ok(TransformMatchRecognize {
+            input,
+            output,
+            func_ctx,
+            partition_by,
+            partition_types,
+            navigations,
+            variables: plan.variables.clone(),
+            conditions,
+            matcher: RowPatternMatcher::create(&plan.pattern),
+            functions,
+            measures,
+            all_rows_per_match: plan.all_rows_per_match,
+            after_match_skip: plan.after_match_skip.clone(),
+            max_block_size,
+            _arena: arena,
+            buffer,
+            partition: None,
+            state,
+            restore_rows: vec![],
+            input_data_block: None,
+            output_data_blocks: VecDeque::new(),
+            step: Step::Collect,
+            is_input_finished: false,
+        })
+    }
+
+    fn collect(&mut self) -> Result<Event> {
+        if self.input_data_block.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if self.buffer.need_spill() {
+            self.step = Step::Spill;
+            return Ok(Event::Async);
+        }
+
+        if self.input.has_data() {
+            let data_block = self.input.pull_data().unwrap()?;
+            self.input_data_block = Some((data_block.consume_convert_to_full(), 0));
+            return Ok(Event::Sync);
+        }
+
+        if self.input.is_finished() {
+            self.is_input_finished = true;
+            if self.buffer.is_empty() {
+                self.step = Step::Finish;
+                self.output.finish();
+                return Ok(Event::Finished);
+            }
+            self.step = Step::Match;
+            return Ok(Event::Sync);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    // Collects the rows of the current partition from the input block, and starts
+    // matching the partition once the partition key changes.
+    fn collect_data_block(&mut self) -> Result<()> {
+        let (block, begin) = self.input_data_block.take().unwrap();
+        let num_rows = block.num_rows();
+        if begin == num_rows {
+            return Ok(());
+        }
+
+        let partition = self.partition.get_or_insert_with(|| {
+            self.partition_by
+                .iter()
+                .map(|offset| {
+                    let value = &block.get_by_offset(*offset).value;
+                    value.index(begin).unwrap().to_owned()
+                })
+                .collect()
+        });
+        let end = (begin..num_rows)
+            .find(|row| {
+                !self
+                    .partition_by
+                    .iter()
+                    .zip(partition.iter())
+                    .all(|(offset, key)| {
+                        block.get_by_offset(*offset).value.index(*row).unwrap() == key.as_ref()
+                    })
+            })
+            .unwrap_or(num_rows);
+
+        if begin == 0 && end == num_rows {
+            self.buffer.add_data_block(block);
+            return Ok(());
+        }
+        self.buffer.add_data_block(block.slice(begin..end));
+        if end < num_rows {
+            self.input_data_block = Some((block, end));
+            self.step = Step::Match;
+        }
+        Ok(())
+    }
+
+    // Matches the current partition by a block of rows per call, the rows to restore
+    // are recorded in `restore_rows` if they are spilled.
+    fn match_partition(&mut self) -> Result<()> {
+        if self.state.num_evaluated_rows < self.buffer.num_rows() {
+            return self.evaluate_conditions();
+        }
+
+        if self.state.current.is_none() && !self.find_match() {
+            self.flush_output()?;
+            self.buffer.clear();
+            self.partition = None;
+            self.state = MatchState::create(&self.conditions, &self.functions);
+            self.step = match self.is_input_finished {
+                true => Step::Finish,
+                false => Step::Collect,
+            };
+            return Ok(());
+        }
+
+        self.compute_match()
+    }
+
+    // 1. Evaluate the conditions of the pattern variables.
+    fn evaluate_conditions(&mut self) -> Result<()> {
+        let num_rows = self.buffer.num_rows();
+        let begin = self.state.num_evaluated_rows;
+        let rows = begin..self.buffer.block_end(begin);
+        let sources = self
+            .navigations
+            .iter()
+            .map(|navigation| navigation.sources(&rows, num_rows))
+            .collect::<Vec<_>>();
+        self.restore_rows = std::iter::once(rows.clone())
+            .chain(sources.iter().cloned())
+            .filter(|rows| !self.buffer.is_restored(rows))
+            .collect();
+        if !self.restore_rows.is_empty() {
+            return Ok(());
+        }
+
+        let mut condition_block = self.buffer.take_rows(rows.clone())?;
+        for (navigation, sources) in self.navigations.iter().zip(sources) {
+            let mut builder = ColumnBuilder::with_capacity(&navigation.data_type, rows.len());
+            if sources.is_empty() {
+                builder.push_repeat(&ScalarRef::Null, rows.len());
+            } else {
+                let block = self.buffer.take_rows(sources.clone())?;
+                let column = block
+                    .get_by_offset(navigation.arg)
+                    .value
+                    .as_column()
+                    .unwrap();
+                for row in rows.clone() {
+                    match navigation.source(row, num_rows) {
+                        Some(source) => builder.push(column.index(source - sources.start).unwrap()),
+                        None => builder.push(ScalarRef::Null),
+                    }
+                }
+            }
+            condition_block.add_column(BlockEntry::new(
+                navigation.data_type.clone(),
+                Value::Column(builder.build()),
+            ));
+        }
+
+        let evaluator = Evaluator::new(&condition_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        for (condition, matched) in self.conditions.iter().zip(self.state.matched.iter_mut()) {
+            if let (Some(expr), Some(matched)) = (condition, matched) {
+                let value = evaluator.run(expr)?.try_downcast::<BooleanType>().unwrap();
+                match value {
+                    Value::Scalar(v) => matched.resize(matched.len() + rows.len(), v),
+                    Value::Column(bitmap) => matched.extend(bitmap.iter()),
+                }
+            }
+        }
+        self.state.num_evaluated_rows = rows.end;
+        Ok(())
+    }
+
+    // 2. Find the next match.
+    fn find_match(&mut self) -> bool {
+        let num_rows = self.buffer.num_rows();
+        let matched = &self.state.matched;
+        while self.state.start < num_rows {
+            let classifiers =
+                self.matcher
+                    .find_match(self.state.start, num_rows, |variable, row| {
+                        matched[variable]
+                            .as_ref()
+                            .map_or(true, |matched| matched[row])
+                    });
+            let Some(classifiers) = classifiers else {
+                self.state.start += 1;
+                continue;
+            };
+
+            self.state.match_number += 1;
+            self.state.current = Some(CurrentMatch {
+                start: self.state.start,
+                classifiers,
+                num_computed_rows: 0,
+            });
+            self.state.values.iter_mut().for_each(|value| *value = None);
+            for (func, _) in self.functions.iter() {
+                if let MatchFunctionInfo::Aggregate { func, place, .. } = func {
+                    func.init_state(*place);
+                }
+            }
+            return true;
+        }
+        false
+    }
+
+    // 3. Compute the match functions over the rows of the match in a block.
+    fn compute_match(&mut self) -> Result<()> {
+        let current = self.state.current.as_ref().unwrap();
+        let begin = current.start + current.num_computed_rows;
+        let end = (current.start + current.classifiers.len()).min(self.buffer.block_end(begin));
+        if !self.buffer.is_restored(&(begin..end)) {
+            self.restore_rows = vec![begin..end];
+            return Ok(());
+        }
+
+        let block = self.buffer.take_rows(begin..end)?;
+        self.accumulate_functions(&block, begin)?;
+        if self.all_rows_per_match {
+            self.state.rows.push(block);
+        }
+
+        let current = self.state.current.as_ref().unwrap();
+        if current.num_computed_rows == current.classifiers.len() {
+            self.drop_states();
+            let current = self.state.current.take().unwrap();
+            self.state.start = self.next_start(current.start, &current.classifiers)?;
+        }
+        if self.state.num_output_rows >= self.max_block_size {
+            self.flush_output()?;
+        }
+        Ok(())
+    }
+
+    // 4. Evaluate the measures and build the output block.
+    fn flush_output(&mut self) -> Result<()> {
+        let num_output_rows = self.state.num_output_rows;
+        if num_output_rows == 0 {
+            return Ok(());
+        }
+
+        let builders =
+            std::mem::replace(&mut self.state.builders, create_builders(&self.functions));
+        let function_block = DataBlock::new(
+            builders
+                .into_iter()
+                .zip(self.functions.iter())
+                .map(|(builder, (_, data_type))| {
+                    BlockEntry::new(data_type.clone(), Value::Column(builder.build()))
+                })
+                .collect(),
+            num_output_rows,
+        );
+        let evaluator = Evaluator::new(&function_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let measures = self
+            .measures
+            .iter()
+            .map(|(expr, data_type)| Ok(BlockEntry::new(data_type.clone(), evaluator.run(expr)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut output = if self.all_rows_per_match {
+            DataBlock::concat(&std::mem::take(&mut self.state.rows))?
+        } else {
+            let columns = self
+                .partition_types
+                .iter()
+                .zip(self.partition.as_ref().unwrap().iter())
+                .map(|(data_type, key)| {
+                    BlockEntry::new(data_type.clone(), Value::Scalar(key.clone()))
+                })
+                .collect();
+            DataBlock::new(columns, num_output_rows)
+        };
+        for measure in measures {
+            output.add_column(measure);
+        }
+        self.output_data_blocks.push_back(output);
+        self.state.num_output_rows = 0;
+        Ok(())
+    }
+
+    // Accumulates the rows of the current match in the block, which starts at the row
+    // `begin` of the partition. With `ALL ROWS PER MATCH`, a value is pushed for each row,
+    // otherwise only for the last row of the match.
+    fn accumulate_functions(&mut self, block: &DataBlock, begin: usize) -> Result<()> {
+        let MatchState {
+            match_number,
+            current,
+            values,
+            builders,
+            num_output_rows,
+            ..
+        } = &mut self.state;
+        let current = current.as_mut().unwrap();
+        for index in 0..block.num_rows() {
+            let k = begin + index - current.start;
+            let classifier = current.classifiers[k];
+            for (i, (func, _)) in self.functions.iter().enumerate() {
+                if func
+                    .variable()
+                    .map_or(false, |variable| variable != classifier)
+                {
+                    continue;
+                }
+                match func {
+                    MatchFunctionInfo::First { arg, .. } => {
+                        if values[i].is_none() {
+                            let value = &block.get_by_offset(*arg).value;
+                            values[i] = Some(value.index(index).unwrap().to_owned());
+                        }
+                    }
+                    MatchFunctionInfo::Last { arg, .. } => {
+                        let value = &block.get_by_offset(*arg).value;
+                        values[i] = Some(value.index(index).unwrap().to_owned());
+                    }
+                    MatchFunctionInfo::Aggregate {
+                        func, args, place, ..
+                    } => {
+                        let columns = InputColumns::new_block_proxy(args, block);
+                        func.accumulate_row(*place, columns, index)?;
+                    }
+                    MatchFunctionInfo::MatchNumber | MatchFunctionInfo::Classifier => {}
+                }
+            }
+
+            if !self.all_rows_per_match && k + 1 < current.classifiers.len() {
+                continue;
+            }
+            *num_output_rows += 1;
+            for (i, (func, _)) in self.functions.iter().enumerate() {
+                let builder = &mut builders[i];
+                match func {
+                    MatchFunctionInfo::MatchNumber => {
+                        builder.push(ScalarRef::Number(NumberScalar::UInt64(*match_number)))
+                    }
+                    MatchFunctionInfo::Classifier => {
+                        builder.push(ScalarRef::String(&self.variables[classifier]))
+                    }
+                    MatchFunctionInfo::First { .. } | MatchFunctionInfo::Last { .. } => {
+                        match &values[i] {
+                            Some(value) => builder.push(value.as_ref()),
+                            None => builder.push(ScalarRef::Null),
+                        }
+                    }
+                    MatchFunctionInfo::Aggregate { func, place, .. } => {
+                        func.merge_result(*place, builder)?
+                    }
+                }
+            }
+        }
+        current.num_computed_rows += block.num_rows();
+        Ok(())
+    }
+
+    fn drop_states(&self) {
+        for (func, _) in self.functions.iter() {
+            if let MatchFunctionInfo::Aggregate { func, place, .. } = func {
+                if func.need_manual_drop_state() {
+                    unsafe { func.drop_state(*place) }
+                }
+            }
+        }
+    }
+
+    fn next_start(&self, start: usize, classifiers: &[usize]) -> Result<usize> {
+        let (variable, position) = match &self.after_match_skip {
+            AfterMatchSkip::PastLastRow => return Ok(start + classifiers.len()),
+            AfterMatchSkip::ToNextRow => return Ok(start + 1),
+            AfterMatchSkip::ToFirst(variable) => {
+                (*variable, classifiers.iter().position(|v| v == variable))
+            }
+            AfterMatchSkip::ToLast(variable) => {
+                (*variable, classifiers.iter().rposition(|v| v == variable))
+            }
+        };
+        match position {
+            None => Err(ErrorCode::BadArguments(format!(
+                "AFTER MATCH SKIP failed, no row is mapped to the pattern variable {}",
+                self.variables[variable]
+            ))),
+            Some(0) => Err(ErrorCode::BadArguments(format!(
+                "AFTER MATCH SKIP failed, cannot skip to the first row of the match by the pattern variable {}",
+                self.variables[variable]
+            ))),
+            Some(position) => Ok(start + position),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformMatchRecognize {
+    fn name(&self) -> String {
+        "TransformMatchRecognize".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        // (collect <--> spill) -> (match <--> restore) -> collect or finish
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(data_block) = self.output_data_blocks.pop_front() {
+            self.output.push_data(Ok(data_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        match self.step {
+            Step::Collect => self.collect(),
+            Step::Spill => {
+                self.step = Step::Collect;
+                self.collect()
+            }
+            Step::Match => match self.restore_rows.is_empty() {
+                true => Ok(Event::Sync),
+                false => {
+                    self.step = Step::Restore;
+                    Ok(Event::Async)
+                }
+            },
+            Step::Restore => {
+                self.step = Step::Match;
+                Ok(Event::Sync)
+            }
+            Step::Finish => {
+                self.input.finish();
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match self.step {
+            Step::Collect => self.collect_data_block(),
+            Step::Match => self.match_partition(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match self.step {
+            Step::Spill => self.buffer.spill().await,
+            Step::Restore => {
+                if self.buffer.need_spill() {
+                    self.buffer.spill().await?;
+                }
+                let rows = std::mem::take(&mut self.restore_rows);
+                self.buffer.restore(&rows).await
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Drop for TransformMatchRecognize {
+    fn drop(&mut self) {
+        if self.state.current.is_some() {
+            self.drop_states();
+        }
+    }
+}
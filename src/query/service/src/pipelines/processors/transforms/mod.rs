@@ -15,6 +15,7 @@
 pub mod aggregator;
 pub mod group_by;
 mod hash_join;
+mod match_recognize;
 pub(crate) mod range_join;
 mod transform_add_computed_columns;
 mod transform_add_const_columns;
@@ -23,6 +24,7 @@ mod transform_add_stream_columns;
 mod transform_async_function;
 mod transform_cache_scan;
 mod transform_cast_schema;
+mod transform_check_constraints;
 mod transform_create_sets;
 mod transform_dictionary;
 mod transform_expression_scan;
@@ -42,6 +44,7 @@ mod transform_udf_server;
 mod window;
 
 pub use hash_join::*;
+pub use match_recognize::*;
 pub use transform_add_computed_columns::TransformAddComputedColumns;
 pub use transform_add_const_columns::TransformAddConstColumns;
 pub use transform_add_internal_columns::TransformAddInternalColumns;
@@ -51,6 +54,7 @@ pub use transform_cache_scan::CacheSourceState;
 pub use transform_cache_scan::HashJoinCacheState;
 pub use transform_cache_scan::TransformCacheScan;
 pub use transform_cast_schema::TransformCastSchema;
+pub use transform_check_constraints::TransformCheckConstraints;
 pub use transform_create_sets::TransformCreateSets;
 pub use transform_expression_scan::TransformExpressionScan;
 pub use transform_filter::TransformFilter;
@@ -66,6 +70,8 @@ pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_resort_addon_without_source_schema::TransformResortAddOnWithoutSourceSchema;
 pub use transform_sort_spill::create_transform_sort_spill;
 pub use transform_srf::TransformSRF;
+pub use transform_udf_script::ScriptRuntime;
 pub use transform_udf_script::TransformUdfScript;
+pub use transform_udf_script::TransformUdtfScript;
 pub use transform_udf_server::TransformUdfServer;
 pub use window::*;
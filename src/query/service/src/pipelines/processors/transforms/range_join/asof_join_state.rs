@@ -0,0 +1,439 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::atomic;
+use std::sync::atomic::AtomicUsize;
+use std::sync::Arc;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::nullable::NullableColumn;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_core::query_spill_prefix;
+use databend_common_sql::executor::physical_plans::RangeJoin;
+use databend_common_sql::plans::JoinType;
+use databend_common_storage::DataOperator;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+
+use crate::pipelines::processors::transforms::range_join::RangeJoinState;
+use crate::sessions::QueryContext;
+use crate::spillers::Location;
+use crate::spillers::Spiller;
+use crate::spillers::SpillerConfig;
+use crate::spillers::SpillerType;
+
+/// State of asof join.
+///
+/// The right table is sorted once by (equi keys, match key) after both sides are sunk,
+/// then every left block is a task that looks up the nearest match of each row with
+/// binary search, so the cost is `O((n + m) * log(m))`.
+///
+/// The keys of the right table are always kept in memory, but the right blocks are
+/// spilled to storage once their memory size exceeds the join spilling threshold, and
+/// a task restores the spilled blocks which have rows matched by its left block.
+pub struct AsofJoinState {
+    // Left rows without a match are kept with nulls if it's a left asof join.
+    left_outer: bool,
+    // Data types of the right table columns, used to pad unmatched rows.
+    right_data_types: Vec<DataType>,
+    // Evaluated keys of each right block, the first column is the match key
+    // and the rest are the equi keys.
+    right_keys: RwLock<Vec<Vec<Column>>>,
+    // (block index, row index) of the right rows which may be matched,
+    // sorted by equi keys then match key. Rows with null keys are excluded.
+    sorted_rows: RwLock<Vec<(u32, u32)>>,
+    // The right blocks are spilled if their memory size exceeds the threshold,
+    // it's `None` if spilling is disabled.
+    spill_threshold: Option<usize>,
+    // Memory size of the right blocks which are not spilled.
+    memory_size: AtomicUsize,
+    // Keeps the layouts of all the spilled right blocks, the right sinkers spill
+    // with their own clones and merge the layouts back.
+    spiller: Option<Mutex<Spiller>>,
+    // Locations of the spilled right blocks by block index, the spilled blocks
+    // are replaced with empty blocks in the right table.
+    spilled_blocks: RwLock<HashMap<usize, Location>>,
+}
+
+/// The matches of a left block, the right rows are restored before the output
+/// is built if some of them are spilled.
+pub struct AsofJoinTask {
+    left_idx: usize,
+    matched_left: Vec<u32>,
+    matched_right: Vec<(u32, u32)>,
+    unmatched_left: Vec<u32>,
+}
+
+impl AsofJoinState {
+    pub(crate) fn new(ctx: &Arc<QueryContext>, range_join: &RangeJoin) -> Result<Self> {
+        let right_data_types = range_join
+            .right
+            .output_schema()?
+            .fields()
+            .iter()
+            .map(|field| field.data_type().clone())
+            .collect();
+
+        // Follow the spilling settings of hash join.
+        let settings = ctx.get_settings();
+        let memory_ratio = settings.get_join_spilling_memory_ratio()?.min(100);
+        let spill_threshold = match settings.get_join_spilling_bytes_threshold_per_proc()? {
+            _ if memory_ratio == 0 => None,
+            0 => match settings.get_max_memory_usage()? as usize {
+                0 => None,
+                max_memory_usage => Some(max_memory_usage / 100 * memory_ratio),
+            },
+            bytes => Some(bytes),
+        };
+        let spiller = match spill_threshold {
+            Some(_) => {
+                let spill_config = SpillerConfig {
+                    spiller_type: SpillerType::AsofJoinBuild,
+                    location_prefix: query_spill_prefix(
+                        ctx.get_tenant().tenant_name(),
+                        &ctx.get_id(),
+                    ),
+                    disk_spill: None,
+                    use_parquet: settings.get_spilling_file_format()?.is_parquet(),
+                };
+                let operator = DataOperator::instance().operator();
+                Some(Mutex::new(Spiller::create(
+                    ctx.clone(),
+                    operator,
+                    spill_config,
+                )?))
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            left_outer: range_join.join_type == JoinType::LeftAsof,
+            right_data_types,
+            right_keys: Default::default(),
+            sorted_rows: Default::default(),
+            spill_threshold,
+            memory_size: AtomicUsize::new(0),
+            spiller,
+            spilled_blocks: Default::default(),
+        })
+    }
+
+    // A spiller for a right sinker or a left task, `None` if spilling is disabled.
+    pub(crate) fn create_spiller(&self) -> Option<Spiller> {
+        self.spiller.as_ref().map(|spiller| spiller.lock().clone())
+    }
+}
+
+impl RangeJoinState {
+    pub(crate) fn sink_asof_right(&self, block: DataBlock) -> Result<()> {
+        let asof_join_state = self.asof_join_state.as_ref().unwrap();
+        // The keys are evaluated here, so they are kept even if the block is spilled.
+        let keys = self.eval_asof_keys(&block, false)?;
+        let mut right_table = self.right_table.write();
+        asof_join_state.right_keys.write().push(keys);
+        asof_join_state
+            .memory_size
+            .fetch_add(block.memory_size(), atomic::Ordering::Relaxed);
+        right_table.push(block);
+        Ok(())
+    }
+
+    // Spill all the right blocks in memory if their memory size exceeds the threshold.
+    pub(crate) async fn spill_asof_right(&self, spiller: &mut Spiller) -> Result<()> {
+        let asof_join_state = self.asof_join_state.as_ref().unwrap();
+        let Some(spill_threshold) = asof_join_state.spill_threshold else {
+            return Ok(());
+        };
+        if asof_join_state.memory_size.load(atomic::Ordering::Relaxed) <= spill_threshold {
+            return Ok(());
+        }
+
+        // Take the blocks out of the right table, so other sinkers will not spill them again.
+        let mut blocks = vec![];
+        {
+            let mut right_table = self.right_table.write();
+            for (block_idx, block) in right_table.iter_mut().enumerate() {
+                if !block.is_empty() {
+                    let block = std::mem::replace(block, DataBlock::empty());
+                    asof_join_state
+                        .memory_size
+                        .fetch_sub(block.memory_size(), atomic::Ordering::Relaxed);
+                    blocks.push((block_idx, block));
+                }
+            }
+        }
+
+        for (block_idx, block) in blocks {
+            let location = spiller.spill(vec![block]).await?;
+            let layout = spiller.columns_layout.remove(&location).unwrap();
+            let shared_spiller = asof_join_state.spiller.as_ref().unwrap();
+            shared_spiller
+                .lock()
+                .columns_layout
+                .insert(location.clone(), layout);
+            asof_join_state
+                .spilled_blocks
+                .write()
+                .insert(block_idx, location);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn asof_partition(&self) -> Result<()> {
+        let asof_join_state = self.asof_join_state.as_ref().unwrap();
+        let right_keys = asof_join_state.right_keys.read();
+        let mut sorted_rows = asof_join_state.sorted_rows.write();
+        for (block_idx, keys) in right_keys.iter().enumerate() {
+            for row in 0..keys[0].len() {
+                if keys.iter().all(|key| !key.index(row).unwrap().is_null()) {
+                    sorted_rows.push((block_idx as u32, row as u32));
+                }
+            }
+        }
+        sorted_rows.sort_by(|(a_block, a_row), (b_block, b_row)| {
+            let a_keys = &right_keys[*a_block as usize];
+            let b_keys = &right_keys[*b_block as usize];
+            compare_equi_keys(a_keys, *a_row as usize, b_keys, *b_row as usize).then_with(|| {
+                a_keys[0]
+                    .index(*a_row as usize)
+                    .cmp(&b_keys[0].index(*b_row as usize))
+            })
+        });
+        drop(sorted_rows);
+
+        // Each left block is a task.
+        let left_table = self.left_table.read();
+        let mut tasks = self.tasks.write();
+        for left_idx in 0..left_table.len() {
+            tasks.push((left_idx, 0));
+        }
+        Ok(())
+    }
+
+    // Find the match of each row of the left block.
+    pub(crate) fn asof_join(&self, task_id: usize) -> Result<AsofJoinTask> {
+        let asof_join_state = self.asof_join_state.as_ref().unwrap();
+        let (left_idx, _) = self.tasks.read()[task_id];
+        let left_table = self.left_table.read();
+        let left_block = &left_table[left_idx];
+        let left_keys = self.eval_asof_keys(left_block, true)?;
+
+        let right_keys = asof_join_state.right_keys.read();
+        let sorted_rows = asof_join_state.sorted_rows.read();
+        let operator = self.conditions[0].operator.as_str();
+
+        let mut matched_left = Vec::with_capacity(left_block.num_rows());
+        let mut matched_right = Vec::with_capacity(left_block.num_rows());
+        let mut unmatched_left = vec![];
+        for row in 0..left_block.num_rows() {
+            let matched = if left_keys
+                .iter()
+                .any(|key| key.index(row).unwrap().is_null())
+            {
+                None
+            } else {
+                // Locate the rows with the same equi keys.
+                let start = sorted_rows.partition_point(|(block, r)| {
+                    compare_equi_keys(&right_keys[*block as usize], *r as usize, &left_keys, row)
+                        == Ordering::Less
+                });
+                let end = start
+                    + sorted_rows[start..].partition_point(|(block, r)| {
+                        compare_equi_keys(
+                            &right_keys[*block as usize],
+                            *r as usize,
+                            &left_keys,
+                            row,
+                        ) == Ordering::Equal
+                    });
+                let group = &sorted_rows[start..end];
+                // Within the group rows are sorted by the match key in ascending order.
+                let left_key = left_keys[0].index(row).unwrap();
+                let right_key =
+                    |(block, r): &(u32, u32)| right_keys[*block as usize][0].index(*r as usize);
+                let pos = match operator {
+                    // left >= right, pick the largest right key that is not greater than left.
+                    "gte" => group
+                        .partition_point(|r| right_key(r).unwrap() <= left_key)
+                        .checked_sub(1),
+                    "gt" => group
+                        .partition_point(|r| right_key(r).unwrap() < left_key)
+                        .checked_sub(1),
+                    // left <= right, pick the smallest right key that is not less than left.
+                    "lte" => Some(group.partition_point(|r| right_key(r).unwrap() < left_key)),
+                    "lt" => Some(group.partition_point(|r| right_key(r).unwrap() <= left_key)),
+                    _ => unreachable!(),
+                };
+                pos.and_then(|pos| group.get(pos))
+            };
+
+            match matched {
+                Some(matched) => {
+                    matched_left.push(row as u32);
+                    matched_right.push(*matched);
+                }
+                None if asof_join_state.left_outer => unmatched_left.push(row as u32),
+                None => {}
+            }
+        }
+
+        Ok(AsofJoinTask {
+            left_idx,
+            matched_left,
+            matched_right,
+            unmatched_left,
+        })
+    }
+
+    // The spilled right blocks which have rows matched by the task.
+    pub(crate) fn asof_spilled_blocks(&self, task: &AsofJoinTask) -> Vec<(usize, Location)> {
+        let asof_join_state = self.asof_join_state.as_ref().unwrap();
+        let spilled_blocks = asof_join_state.spilled_blocks.read();
+        if spilled_blocks.is_empty() {
+            return vec![];
+        }
+        let mut locations = HashMap::new();
+        for (block, _) in task.matched_right.iter() {
+            let block_idx = *block as usize;
+            if let Some(location) = spilled_blocks.get(&block_idx) {
+                locations.insert(block_idx, location.clone());
+            }
+        }
+        locations.into_iter().collect()
+    }
+
+    // Build the output of the task, `restored_blocks` are the spilled right blocks
+    // returned by `asof_spilled_blocks`.
+    pub(crate) fn asof_output(
+        &self,
+        task: AsofJoinTask,
+        mut restored_blocks: HashMap<usize, DataBlock>,
+    ) -> Result<Vec<DataBlock>> {
+        let asof_join_state = self.asof_join_state.as_ref().unwrap();
+        let left_table = self.left_table.read();
+        let left_block = &left_table[task.left_idx];
+
+        let mut result_blocks = Vec::with_capacity(2);
+        if !task.matched_left.is_empty() {
+            // Collect the right blocks which have matched rows.
+            let right_table = self.right_table.read();
+            let mut right_blocks = vec![];
+            let mut block_positions = HashMap::new();
+            let mut matched_right = Vec::with_capacity(task.matched_right.len());
+            for (block, row) in task.matched_right.iter() {
+                let block_idx = *block as usize;
+                let position = match block_positions.get(&block_idx) {
+                    Some(position) => *position,
+                    None => {
+                        let right_block = match restored_blocks.remove(&block_idx) {
+                            Some(right_block) => right_block,
+                            None => right_table[block_idx].clone(),
+                        };
+                        right_blocks.push(right_block);
+                        block_positions.insert(block_idx, right_blocks.len() - 1);
+                        right_blocks.len() - 1
+                    }
+                };
+                matched_right.push((position as u32, *row, 1));
+            }
+
+            let mut result_block = left_block.take(&task.matched_left, &mut None)?;
+            let right_block =
+                DataBlock::take_blocks(&right_blocks, &matched_right, matched_right.len());
+            let num_rows = result_block.num_rows();
+            for entry in right_block.columns() {
+                if asof_join_state.left_outer {
+                    result_block.add_column(wrap_nullable(entry, num_rows));
+                } else {
+                    result_block.add_column(entry.clone());
+                }
+            }
+            result_blocks.push(result_block);
+        }
+        if !task.unmatched_left.is_empty() {
+            let mut result_block = left_block.take(&task.unmatched_left, &mut None)?;
+            for data_type in asof_join_state.right_data_types.iter() {
+                result_block.add_column(BlockEntry::new(
+                    data_type.wrap_nullable(),
+                    Value::Scalar(Scalar::Null),
+                ));
+            }
+            result_blocks.push(result_block);
+        }
+        Ok(result_blocks)
+    }
+
+    // Evaluate the match key and the equi keys of the block.
+    fn eval_asof_keys(&self, block: &DataBlock, left: bool) -> Result<Vec<Column>> {
+        let func_ctx = FunctionContext::default();
+        let evaluator = Evaluator::new(block, &func_ctx, &BUILTIN_FUNCTIONS);
+        self.conditions
+            .iter()
+            .map(|condition| {
+                let expr: &RemoteExpr = if left {
+                    &condition.left_expr
+                } else {
+                    &condition.right_expr
+                };
+                let expr = expr.as_expr(&BUILTIN_FUNCTIONS);
+                Ok(evaluator
+                    .run(&expr)?
+                    .convert_to_full_column(expr.data_type(), block.num_rows()))
+            })
+            .collect()
+    }
+}
+
+// Compare the equi keys (all key columns but the first one) of two rows.
+fn compare_equi_keys(
+    left_keys: &[Column],
+    left_row: usize,
+    right_keys: &[Column],
+    right_row: usize,
+) -> Ordering {
+    for (left, right) in left_keys.iter().zip(right_keys.iter()).skip(1) {
+        let ordering = left.index(left_row).cmp(&right.index(right_row));
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
+
+fn wrap_nullable(entry: &BlockEntry, num_rows: usize) -> BlockEntry {
+    if entry.data_type.is_nullable_or_null() {
+        return entry.clone();
+    }
+    let column = entry
+        .value
+        .convert_to_full_column(&entry.data_type, num_rows);
+    let validity = Bitmap::new_constant(true, num_rows);
+    BlockEntry::new(
+        entry.data_type.wrap_nullable(),
+        Value::Column(NullableColumn::new_column(column, validity)),
+    )
+}
@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod asof_join_state;
 mod ie_join_state;
 mod ie_join_util;
 mod merge_join_state;
 mod range_join_state;
 mod transform_range_join;
 
+pub(crate) use asof_join_state::AsofJoinState;
+pub(crate) use asof_join_state::AsofJoinTask;
 pub(crate) use ie_join_state::IEJoinState;
 pub(crate) use ie_join_util::*;
 pub use range_join_state::RangeJoinState;
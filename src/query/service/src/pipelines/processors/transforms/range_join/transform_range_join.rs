@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::sync::Arc;
 
@@ -22,9 +23,11 @@ use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
-use databend_common_pipeline_sinks::Sink;
+use databend_common_pipeline_sinks::AsyncSink;
 
+use crate::pipelines::processors::transforms::range_join::AsofJoinTask;
 use crate::pipelines::processors::transforms::range_join::RangeJoinState;
+use crate::spillers::Spiller;
 
 enum RangeJoinStep {
     Sink,
@@ -41,6 +44,9 @@ pub struct TransformRangeJoinLeft {
     state: Arc<RangeJoinState>,
     step: RangeJoinStep,
     execute_finished: bool,
+    // Asof join task whose matched right blocks need to be restored from spilled files.
+    asof_spilled_task: Option<AsofJoinTask>,
+    spiller: Option<Spiller>,
 }
 
 impl TransformRangeJoinLeft {
@@ -58,6 +64,8 @@ impl TransformRangeJoinLeft {
             state: ie_join_state,
             step: RangeJoinStep::Sink,
             execute_finished: false,
+            asof_spilled_task: None,
+            spiller: None,
         })
     }
 }
@@ -67,6 +75,8 @@ impl Processor for TransformRangeJoinLeft {
     fn name(&self) -> String {
         if self.state.ie_join_state.is_some() {
             "TransformIEJoinLeft".to_string()
+        } else if self.state.asof_join_state.is_some() {
+            "TransformAsofJoinLeft".to_string()
         } else {
             "TransformMergeJoinLeft".to_string()
         }
@@ -113,6 +123,10 @@ impl Processor for TransformRangeJoinLeft {
                     return Ok(Event::NeedConsume);
                 }
 
+                if self.asof_spilled_task.is_some() {
+                    return Ok(Event::Async);
+                }
+
                 if !self.execute_finished {
                     Ok(Event::Sync)
                 } else {
@@ -134,9 +148,17 @@ impl Processor for TransformRangeJoinLeft {
             RangeJoinStep::Execute => {
                 let task_id = self.state.task_id();
                 if let Some(task_id) = task_id {
-                    let res = match self.state.ie_join_state {
-                        Some(ref _ie_join_state) => self.state.ie_join(task_id)?,
-                        None => self.state.merge_join(task_id)?,
+                    let res = if self.state.ie_join_state.is_some() {
+                        self.state.ie_join(task_id)?
+                    } else if self.state.asof_join_state.is_some() {
+                        let task = self.state.asof_join(task_id)?;
+                        if !self.state.asof_spilled_blocks(&task).is_empty() {
+                            self.asof_spilled_task = Some(task);
+                            return Ok(());
+                        }
+                        self.state.asof_output(task, HashMap::new())?
+                    } else {
+                        self.state.merge_join(task_id)?
                     };
                     for block in res {
                         if !block.is_empty() {
@@ -154,9 +176,31 @@ impl Processor for TransformRangeJoinLeft {
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        if let RangeJoinStep::Merging = self.step {
-            self.state.wait_merge_finish().await?;
-            self.step = RangeJoinStep::Execute;
+        match self.step {
+            RangeJoinStep::Merging => {
+                self.state.wait_merge_finish().await?;
+                self.step = RangeJoinStep::Execute;
+            }
+            RangeJoinStep::Execute => {
+                if let Some(task) = self.asof_spilled_task.take() {
+                    let asof_join_state = self.state.asof_join_state.as_ref().unwrap();
+                    if self.spiller.is_none() {
+                        self.spiller = asof_join_state.create_spiller();
+                    }
+                    let spiller = self.spiller.as_ref().unwrap();
+                    let mut restored_blocks = HashMap::new();
+                    for (block_idx, location) in self.state.asof_spilled_blocks(&task) {
+                        let block = spiller.read_spilled_file(&location).await?;
+                        restored_blocks.insert(block_idx, block);
+                    }
+                    for block in self.state.asof_output(task, restored_blocks)? {
+                        if !block.is_empty() {
+                            self.output_data_blocks.push_back(block);
+                        }
+                    }
+                }
+            }
+            _ => unreachable!(),
         }
         Ok(())
     }
@@ -164,26 +208,40 @@ impl Processor for TransformRangeJoinLeft {
 
 pub struct TransformRangeJoinRight {
     state: Arc<RangeJoinState>,
+    // Spills the right blocks of asof join, `None` if spilling is disabled.
+    spiller: Option<Spiller>,
 }
 
 impl TransformRangeJoinRight {
     pub fn create(ie_join_state: Arc<RangeJoinState>) -> Self {
         ie_join_state.right_attach();
+        let spiller = ie_join_state
+            .asof_join_state
+            .as_ref()
+            .and_then(|asof_join_state| asof_join_state.create_spiller());
         TransformRangeJoinRight {
             state: ie_join_state,
+            spiller,
         }
     }
 }
 
-impl Sink for TransformRangeJoinRight {
+#[async_trait::async_trait]
+impl AsyncSink for TransformRangeJoinRight {
     const NAME: &'static str = "TransformRangeJoinRight";
 
-    fn on_finish(&mut self) -> Result<()> {
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
         self.state.right_detach()?;
         Ok(())
     }
 
-    fn consume(&mut self, data_block: DataBlock) -> Result<()> {
-        self.state.sink_right(data_block)
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        self.state.sink_right(data_block)?;
+        if let Some(spiller) = self.spiller.as_mut() {
+            self.state.spill_asof_right(spiller).await?;
+        }
+        Ok(false)
     }
 }
@@ -0,0 +1,85 @@
+// Copyright 2024 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::CheckConstraintExpr;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::FunctionContext;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_sql::parse_check_constraints;
+
+use crate::sessions::QueryContext;
+
+/// Fails the write if a row violates a CHECK constraint of the table.
+///
+/// The leading columns of the input blocks are the stored columns of the
+/// table, the trailing ones (e.g. stream columns) are ignored.
+pub struct TransformCheckConstraints {
+    func_ctx: FunctionContext,
+    table_name: String,
+    checks: Vec<CheckConstraintExpr>,
+}
+
+impl TransformCheckConstraints
+where Self: Transform
+{
+    pub fn try_new(
+        ctx: Arc<QueryContext>,
+        table: &dyn Table,
+        input_schema: DataSchemaRef,
+    ) -> Result<Self> {
+        let checks = parse_check_constraints(ctx.clone(), table.get_table_info(), input_schema)?;
+        Ok(Self {
+            func_ctx: ctx.get_function_context()?,
+            table_name: table.name().to_string(),
+            checks,
+        })
+    }
+
+    pub fn need_check(table: &dyn Table) -> bool {
+        table
+            .get_table_info()
+            .meta
+            .constraints
+            .values()
+            .any(|constraint| constraint.check_expr().is_some())
+    }
+}
+
+impl Transform for TransformCheckConstraints {
+    const NAME: &'static str = "CheckConstraintsTransform";
+
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        for check in &self.checks {
+            if let Some(violations) =
+                check.violations(&block, &self.func_ctx, &BUILTIN_FUNCTIONS)?
+            {
+                let row = violations.iter().position(|v| v).unwrap_or_default();
+                return Err(ErrorCode::ConstraintViolated(format!(
+                    "{} of table {}",
+                    check.violation_message(&block, row),
+                    self.table_name
+                )));
+            }
+        }
+        Ok(block)
+    }
+}
@@ -13,10 +13,12 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::VecDeque;
 use std::sync::Arc;
 
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
 
 use crate::pipelines::processors::Event;
 use crate::pipelines::processors::InputPort;
@@ -38,6 +40,196 @@ impl TransformLimit {
             (Some(_), _) => OffsetAndLimitTransform::create(input, output, limit, offset),
         }
     }
+
+    /// Creates the limit of `FETCH FIRST n [PERCENT] ROWS WITH TIES`, `with_ties` are
+    /// the offsets of the `ORDER BY` columns, which are empty for `ROWS ONLY`.
+    pub fn try_create_fetch(
+        limit: Option<usize>,
+        offset: usize,
+        with_ties: Vec<usize>,
+        percent: Option<f64>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+    ) -> Result<Box<dyn Processor>> {
+        Ok(Box::new(TransformFetchLimit {
+            input,
+            output,
+            skip_remaining: offset,
+            take_remaining: limit.unwrap_or(0),
+            with_ties,
+            percent,
+            last_row: None,
+            finished: false,
+            buffered: vec![],
+            input_data_block: None,
+            output_data_blocks: VecDeque::new(),
+        }))
+    }
+}
+
+/// The limit whose number of output rows depends on the input rows.
+///
+/// With ties, after `take_remaining` rows are returned, the following rows that have
+/// the same `ORDER BY` values as the last returned row are also returned. With percent,
+/// all the input is buffered to count the rows before the limit is applied.
+struct TransformFetchLimit {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+
+    skip_remaining: usize,
+    take_remaining: usize,
+    with_ties: Vec<usize>,
+    percent: Option<f64>,
+
+    // The `ORDER BY` values of the last returned row, once the limit is reached.
+    last_row: Option<Vec<Scalar>>,
+    finished: bool,
+    buffered: Vec<DataBlock>,
+
+    input_data_block: Option<DataBlock>,
+    output_data_blocks: VecDeque<DataBlock>,
+}
+
+impl TransformFetchLimit {
+    fn tie_values(&self, data_block: &DataBlock, row: usize) -> Vec<Scalar> {
+        self.with_ties
+            .iter()
+            .map(|offset| {
+                data_block
+                    .get_by_offset(*offset)
+                    .value
+                    .index(row)
+                    .unwrap()
+                    .to_owned()
+            })
+            .collect()
+    }
+
+    fn is_tie(&self, data_block: &DataBlock, row: usize) -> bool {
+        match &self.last_row {
+            Some(last_row) => self.with_ties.iter().zip(last_row).all(|(offset, value)| {
+                data_block.get_by_offset(*offset).value.index(row).unwrap() == value.as_ref()
+            }),
+            None => false,
+        }
+    }
+
+    fn limit_block(&mut self, data_block: DataBlock) -> Option<DataBlock> {
+        let rows = data_block.num_rows();
+        if self.finished || rows == 0 {
+            return None;
+        }
+
+        let start = std::cmp::min(self.skip_remaining, rows);
+        self.skip_remaining -= start;
+
+        let mut end = start;
+        if self.take_remaining > 0 {
+            end = std::cmp::min(rows, start + self.take_remaining);
+            self.take_remaining -= end - start;
+            if self.take_remaining == 0 {
+                if self.with_ties.is_empty() {
+                    self.finished = true;
+                } else {
+                    self.last_row = Some(self.tie_values(&data_block, end - 1));
+                }
+            }
+        } else if self.skip_remaining == 0 && self.last_row.is_none() {
+            // The limit is zero.
+            self.finished = true;
+        }
+
+        if self.last_row.is_some() {
+            while end < rows && self.is_tie(&data_block, end) {
+                end += 1;
+            }
+            if end < rows {
+                self.finished = true;
+            }
+        }
+
+        match end > start {
+            true => Some(data_block.slice(start..end)),
+            false => None,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformFetchLimit {
+    fn name(&self) -> String {
+        String::from("FetchLimitTransform")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(data_block) = self.output_data_blocks.pop_front() {
+            self.output.push_data(Ok(data_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.finished {
+            self.input.finish();
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        if self.input_data_block.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if self.input.has_data() {
+            self.input_data_block = Some(self.input.pull_data().unwrap()?);
+            return Ok(Event::Sync);
+        }
+
+        if self.input.is_finished() {
+            // The percent limit is applied once all the input is buffered.
+            if self.percent.is_some() {
+                return Ok(Event::Sync);
+            }
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(data_block) = self.input_data_block.take() {
+            match self.percent.is_some() {
+                true => self.buffered.push(data_block),
+                false => self.output_data_blocks.extend(self.limit_block(data_block)),
+            }
+            return Ok(());
+        }
+
+        if let Some(percent) = self.percent.take() {
+            let total_rows = self.buffered.iter().map(|b| b.num_rows()).sum::<usize>();
+            self.take_remaining = (total_rows as f64 * percent / 100.0).ceil() as usize;
+            for data_block in std::mem::take(&mut self.buffered) {
+                if let Some(data_block) = self.limit_block(data_block) {
+                    self.output_data_blocks.push_back(data_block);
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 const ONLY_LIMIT: usize = 0;
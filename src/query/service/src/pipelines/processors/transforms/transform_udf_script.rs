@@ -18,19 +18,24 @@ use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use arrow_array::ArrayRef;
 use arrow_array::RecordBatch;
+use arrow_schema::Field;
 use arrow_schema::Schema;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::converts::arrow::EXTENSION_KEY;
 use databend_common_expression::converts::arrow2::ARROW_EXT_TYPE_VARIANT;
+use databend_common_expression::types::DataType;
 use databend_common_expression::variant_transform::contains_variant;
 use databend_common_expression::variant_transform::transform_variant;
 use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::FunctionContext;
+use databend_common_expression::Value;
 use databend_common_pipeline_transforms::processors::Transform;
 use databend_common_sql::executor::physical_plans::UdfFunctionDesc;
 use databend_common_sql::plans::UDFType;
@@ -188,6 +193,138 @@ impl ScriptRuntime {
         };
         Ok(result_batch)
     }
+
+    pub fn add_aggregate(
+        &self,
+        name: &str,
+        state_field: Field,
+        output_field: Field,
+        code: &[u8],
+    ) -> Result<()> {
+        let code = std::str::from_utf8(code)?;
+        match self {
+            ScriptRuntime::JavaScript(runtimes) => {
+                for runtime in runtimes {
+                    let mut runtime = runtime.write();
+                    runtime.add_aggregate(
+                        name,
+                        state_field.clone(),
+                        output_field.clone(),
+                        arrow_udf_js::CallMode::ReturnNullOnNullInput,
+                        code,
+                    )?;
+                }
+            }
+            #[cfg(feature = "python-udf")]
+            ScriptRuntime::Python => {
+                let mut runtime = GLOBAL_PYTHON_RUNTIME.write();
+                runtime.add_aggregate(
+                    name,
+                    state_field.data_type().clone(),
+                    output_field.data_type().clone(),
+                    arrow_udf_python::CallMode::ReturnNullOnNullInput,
+                    code,
+                )?;
+            }
+            #[cfg(not(feature = "python-udf"))]
+            ScriptRuntime::Python => {
+                return Err(ErrorCode::EnterpriseFeatureNotEnable(
+                    "Failed to create python script udaf",
+                ));
+            }
+            ScriptRuntime::WebAssembly(_) => {
+                return Err(ErrorCode::UDFDataError(format!(
+                    "WASM aggregate function '{}' is not supported",
+                    name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Creates the initial state of an aggregate function, as an array of one element.
+    pub fn create_state(&self, name: &str) -> Result<ArrayRef> {
+        self.call_aggregate(name, |runtime| match runtime {
+            AggregateRuntime::JavaScript(runtime) => Ok(runtime.create_state(name)?),
+            #[cfg(feature = "python-udf")]
+            AggregateRuntime::Python(runtime) => Ok(runtime.create_state(name)?),
+        })
+    }
+
+    /// Accumulates the input rows into the state, returns the new state.
+    pub fn accumulate(
+        &self,
+        name: &str,
+        state: &ArrayRef,
+        input: &RecordBatch,
+    ) -> Result<ArrayRef> {
+        self.call_aggregate(name, |runtime| match runtime {
+            AggregateRuntime::JavaScript(runtime) => Ok(runtime.accumulate(name, state, input)?),
+            #[cfg(feature = "python-udf")]
+            AggregateRuntime::Python(runtime) => Ok(runtime.accumulate(name, state, input)?),
+        })
+    }
+
+    /// Merges all the states into one.
+    pub fn merge(&self, name: &str, states: &ArrayRef) -> Result<ArrayRef> {
+        self.call_aggregate(name, |runtime| match runtime {
+            AggregateRuntime::JavaScript(runtime) => Ok(runtime.merge(name, states)?),
+            #[cfg(feature = "python-udf")]
+            AggregateRuntime::Python(runtime) => Ok(runtime.merge(name, states)?),
+        })
+    }
+
+    /// Computes the final value of each state.
+    pub fn finish(&self, name: &str, states: &ArrayRef) -> Result<ArrayRef> {
+        self.call_aggregate(name, |runtime| match runtime {
+            AggregateRuntime::JavaScript(runtime) => Ok(runtime.finish(name, states)?),
+            #[cfg(feature = "python-udf")]
+            AggregateRuntime::Python(runtime) => Ok(runtime.finish(name, states)?),
+        })
+    }
+
+    fn call_aggregate<T>(
+        &self,
+        name: &str,
+        f: impl FnOnce(AggregateRuntime) -> Result<T>,
+    ) -> Result<T> {
+        let result = match self {
+            ScriptRuntime::JavaScript(runtimes) => {
+                let runtime = runtimes[0].read();
+                f(AggregateRuntime::JavaScript(&runtime))
+            }
+            #[cfg(feature = "python-udf")]
+            ScriptRuntime::Python => {
+                let runtime = GLOBAL_PYTHON_RUNTIME.read();
+                f(AggregateRuntime::Python(&runtime))
+            }
+            #[cfg(not(feature = "python-udf"))]
+            ScriptRuntime::Python => {
+                return Err(ErrorCode::EnterpriseFeatureNotEnable(
+                    "Failed to execute python script udaf",
+                ));
+            }
+            ScriptRuntime::WebAssembly(_) => {
+                return Err(ErrorCode::UDFDataError(format!(
+                    "WASM aggregate function '{}' is not supported",
+                    name
+                )));
+            }
+        };
+        result.map_err(|err| {
+            ErrorCode::UDFDataError(format!(
+                "Aggregate function '{}' execution failed: {}",
+                name,
+                err.message()
+            ))
+        })
+    }
+}
+
+enum AggregateRuntime<'a> {
+    JavaScript(&'a arrow_udf_js::Runtime),
+    #[cfg(feature = "python-udf")]
+    Python(&'a arrow_udf_python::Runtime),
 }
 
 pub struct TransformUdfScript {
@@ -226,8 +363,8 @@ impl Transform for TransformUdfScript {
         let index = self.index_seq.fetch_add(1, Ordering::SeqCst);
         for func in &self.funcs {
             let num_rows = data_block.num_rows();
-            let block_entries = self.prepare_block_entries(func, &data_block)?;
-            let input_batch = self.create_input_batch(block_entries, num_rows)?;
+            let block_entries = Self::prepare_block_entries(func, &data_block)?;
+            let input_batch = Self::create_input_batch(block_entries, num_rows)?;
             let runtime_key = Self::get_runtime_key(func)?;
 
             if let Some(runtime) = self.script_runtimes.get(&runtime_key) {
@@ -299,7 +436,6 @@ impl TransformUdfScript {
     }
 
     fn prepare_block_entries(
-        &self,
         func: &UdfFunctionDesc,
         data_block: &DataBlock,
     ) -> Result<Vec<BlockEntry>> {
@@ -323,11 +459,7 @@ impl TransformUdfScript {
         Ok(block_entries)
     }
 
-    fn create_input_batch(
-        &self,
-        block_entries: Vec<BlockEntry>,
-        num_rows: usize,
-    ) -> Result<RecordBatch> {
+    fn create_input_batch(block_entries: Vec<BlockEntry>, num_rows: usize) -> Result<RecordBatch> {
         let fields = block_entries
             .iter()
             .enumerate()
@@ -396,3 +528,115 @@ impl TransformUdfScript {
         Ok(())
     }
 }
+
+/// Evaluates a script table function.
+///
+/// The handler is registered and called like a scalar script UDF, and returns the
+/// rows produced for each input row as an array of tuples of the declared columns.
+/// The arrays are flattened into a column of these tuples, and each input row is
+/// repeated once for each of its rows.
+pub struct TransformUdtfScript {
+    func: UdfFunctionDesc,
+    script_runtime: Arc<ScriptRuntime>,
+    index_seq: Arc<AtomicUsize>,
+}
+
+unsafe impl Send for TransformUdtfScript {}
+
+impl TransformUdtfScript {
+    pub fn new(
+        func: UdfFunctionDesc,
+        script_runtime: Arc<ScriptRuntime>,
+        index_seq: Arc<AtomicUsize>,
+    ) -> Self {
+        Self {
+            func,
+            script_runtime,
+            index_seq,
+        }
+    }
+
+    pub fn init_runtime(func: &UdfFunctionDesc, runtime_num: usize) -> Result<Arc<ScriptRuntime>> {
+        let UDFType::Script((lang, _, code)) = &func.udf_type else {
+            return Err(ErrorCode::UDFDataError(format!(
+                "Unsupported UDFType variant for table function '{}'",
+                func.name
+            )));
+        };
+        let runtime = ScriptRuntime::try_create(lang.trim(), Some(code.as_slice()), runtime_num)?;
+        runtime.add_function_with_handler(&Self::runtime_func(func), code)?;
+        Ok(Arc::new(runtime))
+    }
+
+    // The handler returns an array of rows for each input row, or NULL if an argument is NULL.
+    fn runtime_func(func: &UdfFunctionDesc) -> UdfFunctionDesc {
+        let data_type = DataType::Array(func.data_type.clone());
+        UdfFunctionDesc {
+            data_type: Box::new(DataType::Nullable(Box::new(data_type))),
+            ..func.clone()
+        }
+    }
+
+    fn read_rows(&self, result_batch: &RecordBatch, num_rows: usize) -> Result<(Column, Vec<u32>)> {
+        let schema = DataSchema::try_from(&(*result_batch.schema()))?;
+        let (result_block, _) = DataBlock::from_record_batch(&schema, result_batch)?;
+        let (column, validity) = match result_block.get_by_offset(0).to_column(num_rows) {
+            Column::Nullable(column) => (column.column, Some(column.validity)),
+            column => (column, None),
+        };
+        let array = match column {
+            Column::Array(array) => array,
+            column => {
+                return Err(ErrorCode::UDFDataError(format!(
+                    "Table function '{}' returned column with data type {:?} but expected an array",
+                    self.func.name,
+                    column.data_type()
+                )));
+            }
+        };
+
+        let mut indices = Vec::new();
+        let mut value_indices = Vec::new();
+        for row in 0..num_rows {
+            if validity
+                .as_ref()
+                .map_or(true, |validity| validity.get_bit(row))
+            {
+                for value in array.offsets[row]..array.offsets[row + 1] {
+                    indices.push(row as u32);
+                    value_indices.push(value as u32);
+                }
+            }
+        }
+        let values = DataBlock::new_from_columns(vec![array.values]);
+        let values = values.take(&value_indices, &mut None)?;
+        let values = values.get_by_offset(0).to_column(value_indices.len());
+        Ok((values, indices))
+    }
+}
+
+impl Transform for TransformUdtfScript {
+    const NAME: &'static str = "UDTFScriptTransform";
+
+    const SKIP_EMPTY_DATA_BLOCK: bool = true;
+
+    fn transform(&mut self, data_block: DataBlock) -> Result<DataBlock> {
+        let index = self.index_seq.fetch_add(1, Ordering::SeqCst);
+        let num_rows = data_block.num_rows();
+        let block_entries = TransformUdfScript::prepare_block_entries(&self.func, &data_block)?;
+        let input_batch = TransformUdfScript::create_input_batch(block_entries, num_rows)?;
+        let result_batch = self
+            .script_runtime
+            .handle_execution(&self.func, &input_batch, index)?;
+        let (rows, indices) = self.read_rows(&result_batch, num_rows)?;
+
+        let mut value = Value::Column(rows);
+        if contains_variant(&self.func.data_type) {
+            value = transform_variant(&value, false)?;
+        }
+        let entry = BlockEntry::new(self.func.data_type.as_ref().clone(), value);
+        let mut data_block = data_block.take(&indices, &mut None)?;
+        data_block.add_column(entry);
+        Ok(data_block)
+    }
+}
@@ -68,16 +68,9 @@ impl WindowPartitionBuffer {
     }
 
     pub fn out_of_memory_limit(&mut self) -> bool {
-        // Check if processor memory usage exceeds the threshold.
-        if self.partition_buffer.memory_size() + self.restored_partition_buffer.memory_size()
-            > self.spill_settings.processor_memory_threshold
-        {
-            return true;
-        }
-
-        // Check if global memory usage exceeds the threshold.
-        let global_memory_usage = std::cmp::max(GLOBAL_MEM_STAT.get_memory_usage(), 0) as usize;
-        global_memory_usage > self.spill_settings.global_memory_threshold
+        self.spill_settings.out_of_memory_limit(
+            self.partition_buffer.memory_size() + self.restored_partition_buffer.memory_size(),
+        )
     }
 
     pub fn is_empty(&self) -> bool {
@@ -299,4 +292,19 @@ impl WindowSpillSettings {
             spill_unit_size,
         })
     }
+
+    pub fn enable_spill(&self) -> bool {
+        self.enable_spill
+    }
+
+    pub fn out_of_memory_limit(&self, processor_memory_usage: usize) -> bool {
+        // Check if processor memory usage exceeds the threshold.
+        if processor_memory_usage > self.processor_memory_threshold {
+            return true;
+        }
+
+        // Check if global memory usage exceeds the threshold.
+        let global_memory_usage = std::cmp::max(GLOBAL_MEM_STAT.get_memory_usage(), 0) as usize;
+        global_memory_usage > self.global_memory_threshold
+    }
 }
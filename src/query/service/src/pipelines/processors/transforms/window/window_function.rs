@@ -24,11 +24,11 @@ use databend_common_expression::DataSchema;
 use databend_common_expression::InputColumns;
 use databend_common_functions::aggregates::get_layout_offsets;
 use databend_common_functions::aggregates::AggregateFunction;
-use databend_common_functions::aggregates::AggregateFunctionFactory;
 use databend_common_functions::aggregates::StateAddr;
 use databend_common_sql::executor::physical_plans::LagLeadDefault;
 use databend_common_sql::executor::physical_plans::WindowFunction;
 
+use crate::pipelines::processors::transforms::aggregator::create_aggregate_function;
 use crate::pipelines::processors::transforms::group_by::Area;
 
 #[derive(Clone)]
@@ -177,11 +177,7 @@ impl WindowFunctionInfo {
     pub fn try_create(window: &WindowFunction, schema: &DataSchema) -> Result<Self> {
         Ok(match window {
             WindowFunction::Aggregate(agg) => {
-                let agg_func = AggregateFunctionFactory::instance().get(
-                    agg.sig.name.as_str(),
-                    agg.sig.params.clone(),
-                    agg.sig.args.clone(),
-                )?;
+                let agg_func = create_aggregate_function(&agg.sig)?;
                 let args = agg
                     .arg_indices
                     .iter()
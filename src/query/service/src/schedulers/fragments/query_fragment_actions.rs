@@ -32,6 +32,7 @@ use crate::servers::flight::v1::packets::QueryFragment;
 use crate::servers::flight::v1::packets::QueryFragments;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
+use crate::sessions::WorkloadGroupResourceManager;
 use crate::sql::executor::PhysicalPlan;
 
 // Query plan fragment with executor name
@@ -192,6 +193,9 @@ impl QueryFragmentsActions {
                 .ctx
                 .get_settings()
                 .get_create_query_flight_client_with_current_rt()?,
+            workload_group: WorkloadGroupResourceManager::instance()
+                .get_by_query(&self.ctx.get_id())
+                .map(|group| group.meta.clone()),
         })
     }
 
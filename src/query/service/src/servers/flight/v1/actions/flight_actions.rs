@@ -36,11 +36,13 @@ use crate::servers::flight::v1::actions::start_prepared_query::start_prepared_qu
 use crate::servers::flight::v1::actions::system_action::system_action;
 use crate::servers::flight::v1::actions::truncate_table::truncate_table;
 use crate::servers::flight::v1::actions::truncate_table::TRUNCATE_TABLE;
+use crate::servers::flight::v1::actions::warm_table_cache::warm_table_cache;
 use crate::servers::flight::v1::actions::GET_PROFILE;
 use crate::servers::flight::v1::actions::INIT_QUERY_FRAGMENTS;
 use crate::servers::flight::v1::actions::KILL_QUERY;
 use crate::servers::flight::v1::actions::START_PREPARED_QUERY;
 use crate::servers::flight::v1::actions::SYSTEM_ACTION;
+use crate::servers::flight::v1::actions::WARM_TABLE_CACHE;
 
 pub struct FlightActions {
     #[allow(clippy::type_complexity)]
@@ -131,6 +133,7 @@ pub fn flight_actions() -> FlightActions {
         .action(INIT_QUERY_FRAGMENTS, init_query_fragments)
         .action(START_PREPARED_QUERY, start_prepared_query)
         .action(TRUNCATE_TABLE, truncate_table)
+        .action(WARM_TABLE_CACHE, warm_table_cache)
         .action(KILL_QUERY, kill_query)
         .action(SET_PRIORITY, set_priority)
         .action(SYSTEM_ACTION, system_action)
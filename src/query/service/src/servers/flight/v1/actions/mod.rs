@@ -21,6 +21,7 @@ mod set_priority;
 mod start_prepared_query;
 mod system_action;
 mod truncate_table;
+mod warm_table_cache;
 
 use std::sync::Arc;
 
@@ -38,6 +39,8 @@ pub use set_priority::SET_PRIORITY;
 pub use start_prepared_query::START_PREPARED_QUERY;
 pub use system_action::SYSTEM_ACTION;
 pub use truncate_table::TRUNCATE_TABLE;
+pub use warm_table_cache::WarmTableCacheRequest;
+pub use warm_table_cache::WARM_TABLE_CACHE;
 
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
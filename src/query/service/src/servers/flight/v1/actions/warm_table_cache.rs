@@ -0,0 +1,36 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_sql::plans::WarmTableCachePlan;
+use databend_common_storages_fuse::operations::WarmCacheStats;
+
+use crate::interpreters::WarmTableCacheInterpreter;
+use crate::servers::flight::v1::actions::create_session;
+
+pub static WARM_TABLE_CACHE: &str = "/actions/warm_table_cache";
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct WarmTableCacheRequest {
+    pub plan: WarmTableCachePlan,
+    /// The nodes the blocks are scheduled to by cache affinity, None if each node loads all of them.
+    pub executors: Option<Vec<String>>,
+}
+
+pub async fn warm_table_cache(req: WarmTableCacheRequest) -> Result<WarmCacheStats> {
+    let session = create_session()?;
+    let query_context = session.create_query_context().await?;
+    let interpreter = WarmTableCacheInterpreter::from_flight(query_context, req.plan)?;
+    interpreter.warm_local_cache(req.executors).await
+}
@@ -74,6 +74,7 @@ use crate::servers::flight::FlightReceiver;
 use crate::servers::flight::FlightSender;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
+use crate::sessions::WorkloadGroupResourceManager;
 
 pub struct DataExchangeManager {
     queries_coordinator: ReentrantMutex<SyncUnsafeCell<HashMap<String, QueryCoordinator>>>,
@@ -781,7 +782,10 @@ impl QueryCoordinator {
     }
 
     pub fn on_finished(self) {
-        // Do something when query finished.
+        // The query was accounted in its workload group when the remote env was created.
+        if let Some(info) = &self.info {
+            WorkloadGroupResourceManager::instance().unregister_remote_query(&info.query_id);
+        }
     }
 
     pub fn execute_pipeline(&mut self) -> Result<()> {
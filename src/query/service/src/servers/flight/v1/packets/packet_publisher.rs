@@ -24,6 +24,7 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::WorkloadGroup;
 use databend_common_meta_types::NodeInfo;
 use databend_common_settings::Settings;
 use log::debug;
@@ -38,6 +39,7 @@ use crate::servers::flight::v1::actions::INIT_QUERY_ENV;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
+use crate::sessions::WorkloadGroupResourceManager;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Edge {
@@ -137,6 +139,8 @@ pub struct QueryEnv {
     pub dataflow_diagram: Arc<DataflowDiagram>,
     pub request_server_id: String,
     pub create_rpc_clint_with_current_rt: bool,
+    /// The workload group of the query on the request node, its limits apply on every node.
+    pub workload_group: Option<WorkloadGroup>,
 }
 
 impl QueryEnv {
@@ -172,6 +176,11 @@ impl QueryEnv {
         query_ctx.set_id(self.query_id.clone());
         query_ctx.attach_query_str(self.query_kind, "".to_string());
 
+        if let Some(workload_group) = &self.workload_group {
+            WorkloadGroupResourceManager::instance()
+                .register_remote_query(&self.query_id, workload_group.clone());
+        }
+
         Ok(query_ctx)
     }
 }
@@ -97,6 +97,7 @@ impl ResponseError for HttpErrorCode {
             | ErrorCode::SESSION_TOKEN_NOT_FOUND
             | ErrorCode::REFRESH_TOKEN_EXPIRED
             | ErrorCode::REFRESH_TOKEN_NOT_FOUND
+            | ErrorCode::NETWORK_POLICY_REJECTED
             | ErrorCode::UNKNOWN_USER => StatusCode::UNAUTHORIZED,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
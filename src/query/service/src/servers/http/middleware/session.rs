@@ -56,6 +56,7 @@ use poem::Request;
 use poem::Response;
 use uuid::Uuid;
 
+use crate::audit::AuditLog;
 use crate::auth::AuthMgr;
 use crate::auth::Credential;
 use crate::clusters::ClusterDiscovery;
@@ -330,14 +331,29 @@ impl<E> HTTPSessionEndpoint<E> {
             .headers()
             .get(HEADER_SESSION_ID)
             .map(|v| v.to_str().unwrap().to_string());
-        let (user_name, authed_client_session_id) = self
+        let auth_res = self
             .auth_manager
             .auth(
                 &mut session,
                 &credential,
                 self.endpoint_kind.need_user_info(),
             )
-            .await?;
+            .await;
+        // A login is audited once per session: the login handler audits the logins of
+        // client sessions, so a request is audited here only if it fails, or if it does
+        // not belong to a client session and is a session by itself.
+        if auth_res.is_err()
+            || (header_client_session_id.is_none()
+                && !matches!(self.endpoint_kind, EndpointKind::Login))
+        {
+            AuditLog::log_credential_login(
+                &session,
+                &credential,
+                auth_res.as_ref().ok().map(|(name, _)| name.as_str()),
+                auth_res.as_ref().err(),
+            );
+        }
+        let (user_name, authed_client_session_id) = auth_res?;
         let client_session_id = authed_client_session_id.or(header_client_session_id);
         if let Some(id) = client_session_id.clone() {
             session.set_client_session_id(id)
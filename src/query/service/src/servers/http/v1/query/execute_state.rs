@@ -153,6 +153,7 @@ pub struct ExecutorSessionState {
     pub txn_manager: TxnManagerRef,
     pub temp_tbl_mgr: TempTblMgrRef,
     pub variables: HashMap<String, Scalar>,
+    pub prepared_statements: HashMap<String, String>,
 }
 
 impl ExecutorSessionState {
@@ -165,6 +166,7 @@ impl ExecutorSessionState {
             txn_manager: session.txn_mgr(),
             temp_tbl_mgr: session.temp_tbl_mgr(),
             variables: session.get_all_variables(),
+            prepared_statements: session.get_all_prepared_statements(),
         }
     }
 }
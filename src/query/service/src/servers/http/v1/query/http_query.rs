@@ -21,6 +21,13 @@ use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
 
+use databend_common_ast::ast::ExecuteStmt;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::UnaryOperator;
 use databend_common_base::base::short_sql;
 use databend_common_base::base::tokio::sync::Mutex as TokioMutex;
 use databend_common_base::base::tokio::sync::RwLock;
@@ -82,15 +89,35 @@ fn default_as_true() -> bool {
 pub struct HttpQueryRequest {
     pub session_id: Option<String>,
     pub session: Option<HttpSessionConf>,
+    #[serde(default)]
     pub sql: String,
     #[serde(default)]
     pub pagination: PaginationConf,
     #[serde(default = "default_as_true")]
     pub string_fields: bool,
     pub stage_attachment: Option<StageAttachmentConf>,
+    /// Executes a prepared statement of the session instead of `sql`.
+    pub prepared: Option<PreparedStatementConf>,
 }
 
 impl HttpQueryRequest {
+    /// The SQL to run, `EXECUTE <name> USING <params>` for a prepared statement.
+    pub(crate) fn get_sql(&self) -> Result<String> {
+        match &self.prepared {
+            None => Ok(self.sql.clone()),
+            Some(_) if !self.sql.is_empty() => Err(ErrorCode::BadArguments(
+                "sql and prepared can not be both specified",
+            )),
+            Some(prepared) => {
+                let stmt = Statement::Execute(ExecuteStmt {
+                    name: Identifier::from_name_with_quoted(None, &prepared.name, Some('`')),
+                    params: prepared.params.iter().map(json_to_expr).collect(),
+                });
+                Ok(stmt.to_string())
+            }
+        }
+    }
+
     pub(crate) fn fail_to_start_sql(&self, err: ErrorCode) -> impl IntoResponse {
         metrics_incr_http_response_errors_count(err.name(), err.code());
         let session = self.session.as_ref().map(|s| {
@@ -134,10 +161,51 @@ impl Debug for HttpQueryRequest {
             .field("pagination", &self.pagination)
             .field("string_fields", &self.string_fields)
             .field("stage_attachment", &self.stage_attachment)
+            .field("prepared", &self.prepared)
             .finish()
     }
 }
 
+#[derive(Deserialize, Debug, Clone)]
+pub struct PreparedStatementConf {
+    /// The name of the prepared statement, which is case-sensitive.
+    pub(crate) name: String,
+    /// The values bound to the placeholders in order, arrays and objects are bound as variants.
+    #[serde(default)]
+    pub(crate) params: Vec<serde_json::Value>,
+}
+
+fn json_to_expr(value: &serde_json::Value) -> Expr {
+    let literal = |value| Expr::Literal { span: None, value };
+    match value {
+        serde_json::Value::Null => literal(Literal::Null),
+        serde_json::Value::Bool(v) => literal(Literal::Boolean(*v)),
+        serde_json::Value::Number(v) => match (v.as_u64(), v.as_i64()) {
+            (Some(v), _) => literal(Literal::UInt64(v)),
+            (None, Some(v)) => Expr::UnaryOp {
+                span: None,
+                op: UnaryOperator::Minus,
+                expr: Box::new(literal(Literal::UInt64(v.unsigned_abs()))),
+            },
+            _ => literal(Literal::Float64(v.as_f64().unwrap_or(f64::NAN))),
+        },
+        serde_json::Value::String(v) => literal(Literal::String(v.clone())),
+        serde_json::Value::Array(_) | serde_json::Value::Object(_) => Expr::FunctionCall {
+            span: None,
+            func: FunctionCall {
+                distinct: false,
+                name: Identifier::from_name(None, "parse_json"),
+                args: vec![literal(Literal::String(value.to_string()))],
+                params: vec![],
+                order_by: vec![],
+                filter: None,
+                window: None,
+                lambda: None,
+            },
+        },
+    }
+}
+
 const DEFAULT_MAX_ROWS_IN_BUFFER: usize = 5 * 1000 * 1000;
 const DEFAULT_MAX_ROWS_PER_PAGE: usize = 10000;
 const DEFAULT_WAIT_TIME_SECS: u32 = 10;
@@ -195,10 +263,16 @@ pub struct ServerInfo {
 pub struct HttpSessionStateInternal {
     /// value is JSON of Scalar
     variables: Vec<(String, String)>,
+    /// name and SQL of the prepared statements
+    #[serde(default)]
+    prepared_statements: Vec<(String, String)>,
 }
 
 impl HttpSessionStateInternal {
-    fn new(variables: &HashMap<String, Scalar>) -> Self {
+    fn new(
+        variables: &HashMap<String, Scalar>,
+        prepared_statements: &HashMap<String, String>,
+    ) -> Self {
         let variables = variables
             .iter()
             .map(|(k, v)| {
@@ -208,7 +282,14 @@ impl HttpSessionStateInternal {
                 )
             })
             .collect();
-        Self { variables }
+        let prepared_statements = prepared_statements
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        Self {
+            variables,
+            prepared_statements,
+        }
     }
 
     pub fn get_variables(&self) -> Result<HashMap<String, Scalar>> {
@@ -449,6 +530,11 @@ impl HttpQuery {
                 if !state.variables.is_empty() {
                     session.set_all_variables(state.get_variables()?)
                 }
+                if !state.prepared_statements.is_empty() {
+                    session.set_all_prepared_statements(
+                        state.prepared_statements.iter().cloned().collect(),
+                    )
+                }
             }
             try_set_txn(&ctx.query_id, &session, session_conf, &http_query_manager)?;
             if session_conf.need_sticky
@@ -485,7 +571,7 @@ impl HttpQuery {
 
         let session_id = session.get_id().clone();
         let node_id = ctx.get_cluster().local_id.clone();
-        let sql = &request.sql;
+        let sql = &request.get_sql()?;
         info!(query_id = query_id, session_id = session_id, node_id = node_id, sql = sql; "create query");
 
         // Stage attachment is used to carry the data payload to the INSERT/REPLACE statements.
@@ -514,7 +600,7 @@ impl HttpQuery {
         let block_sender_closer = block_sender.closer();
         let state_clone = state.clone();
         let ctx_clone = ctx.clone();
-        let sql = request.sql.clone();
+        let sql = request.get_sql()?;
 
         let http_query_runtime_instance = GlobalQueryRuntime::instance();
         let span = if let Some(parent) = SpanContext::current_local_parent() {
@@ -655,8 +741,13 @@ impl HttpQuery {
         let role = session_state.current_role.clone();
         let secondary_roles = session_state.secondary_roles.clone();
         let txn_state = session_state.txn_manager.lock().state();
-        let internal = if !session_state.variables.is_empty() {
-            Some(HttpSessionStateInternal::new(&session_state.variables))
+        let internal = if !session_state.variables.is_empty()
+            || !session_state.prepared_statements.is_empty()
+        {
+            Some(HttpSessionStateInternal::new(
+                &session_state.variables,
+                &session_state.prepared_statements,
+            ))
         } else {
             None
         };
@@ -22,6 +22,7 @@ use poem::error::Result as PoemResult;
 use poem::web::Json;
 use poem::IntoResponse;
 
+use crate::audit::AuditLog;
 use crate::auth::Credential;
 use crate::servers::http::error::HttpErrorCode;
 use crate::servers::http::v1::session::client_session_manager::ClientSessionManager;
@@ -83,9 +84,15 @@ pub async fn login_handler(
     Json(req): Json<LoginRequest>,
 ) -> PoemResult<impl IntoResponse> {
     let version = QUERY_SEMVER.to_string();
-    check_login(ctx, &req)
-        .await
-        .map_err(HttpErrorCode::bad_request)?;
+    let checked = check_login(ctx, &req).await;
+    AuditLog::log_login(
+        &ctx.session,
+        &ctx.user_name,
+        ctx.client_host.as_deref(),
+        "session login",
+        checked.as_ref().err(),
+    );
+    checked.map_err(HttpErrorCode::bad_request)?;
 
     match ctx.credential {
         Credential::Jwt { .. } => {
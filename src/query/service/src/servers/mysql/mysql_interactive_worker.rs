@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
@@ -26,11 +27,16 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::ToErrorCode;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::SendableDataBlockStream;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_meta_app::principal::UserIdentity;
 use databend_common_metrics::mysql::*;
+use databend_common_sql::plans::InsertInputSource;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::PlaceholderSql;
+use databend_common_sql::Planner;
 use databend_common_users::CertifiedInfo;
 use databend_common_users::UserApiProvider;
 use fastrace::func_path;
@@ -39,6 +45,9 @@ use futures_util::StreamExt;
 use log::error;
 use log::info;
 use opensrv_mysql::AsyncMysqlShim;
+use opensrv_mysql::Column;
+use opensrv_mysql::ColumnFlags;
+use opensrv_mysql::ColumnType;
 use opensrv_mysql::ErrorKind;
 use opensrv_mysql::InitWriter;
 use opensrv_mysql::ParamParser;
@@ -47,9 +56,13 @@ use opensrv_mysql::StatementMetaWriter;
 use rand::RngCore;
 use uuid::Uuid;
 
+use crate::audit::AuditLog;
 use crate::interpreters::interpreter_plan_sql;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
+use crate::schedulers::ServiceQueryExecutor;
+use crate::servers::mysql::mysql_params::params_to_exprs;
+use crate::servers::mysql::writers::convert_schema;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::mysql::writers::ProgressReporter;
@@ -63,6 +76,9 @@ use crate::stream::DataBlockStream;
 
 struct InteractiveWorkerBase {
     session: Arc<Session>,
+    // The prepared statements of the connection, by statement id.
+    prepared_statements: HashMap<u32, PlaceholderSql>,
+    next_statement_id: u32,
 }
 
 pub struct InteractiveWorker {
@@ -269,6 +285,25 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
 impl InteractiveWorkerBase {
     #[async_backtrace::framed]
     async fn authenticate(&self, salt: &[u8], info: CertifiedInfo) -> Result<bool> {
+        let client_ip = info.user_client_address.split(':').collect::<Vec<_>>()[0].to_string();
+        let res = self.do_authenticate(salt, &info).await;
+        let err = match &res {
+            Ok(true) => None,
+            Ok(false) => Some(ErrorCode::AuthenticateFailure("wrong password")),
+            Err(e) => Some(e.clone()),
+        };
+        AuditLog::log_login(
+            &self.session,
+            &info.user_name,
+            Some(&client_ip),
+            "mysql",
+            err.as_ref(),
+        );
+        res
+    }
+
+    #[async_backtrace::framed]
+    async fn do_authenticate(&self, salt: &[u8], info: &CertifiedInfo) -> Result<bool> {
         let ctx = self.session.create_query_context().await?;
         let identity = UserIdentity::new(&info.user_name, "%");
         let client_ip = info.user_client_address.split(':').collect::<Vec<_>>()[0];
@@ -297,36 +332,132 @@ impl InteractiveWorkerBase {
     #[async_backtrace::framed]
     async fn do_prepare<W: AsyncWrite + Unpin>(
         &mut self,
-        _: &str,
+        query: &str,
         writer: StatementMetaWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Prepare is not support in Databend.".as_bytes(),
-            )
-            .await?;
+        match self.prepare_statement(query).await {
+            Ok((statement, params, columns)) => {
+                self.next_statement_id = self.next_statement_id.wrapping_add(1);
+                let id = self.next_statement_id;
+                self.prepared_statements.insert(id, statement);
+                writer.reply(id, &params, &columns).await?;
+            }
+            Err(error) => {
+                writer
+                    .error(
+                        ErrorKind::ER_UNKNOWN_ERROR,
+                        error.display_with_sql(query).to_string().as_bytes(),
+                    )
+                    .await?;
+            }
+        }
         Ok(())
     }
 
+    // Plans the statement with NULL parameters to describe its parameters and result columns.
     #[async_backtrace::framed]
-    async fn do_execute<W: AsyncWrite + Unpin>(
+    async fn prepare_statement(
         &mut self,
-        _: u32,
-        _: ParamParser<'_>,
+        query: &str,
+    ) -> Result<(PlaceholderSql, Vec<Column>, Vec<Column>)> {
+        let statement = PlaceholderSql::try_create(query)?;
+        let sql = statement.bind_nulls()?;
+        let mut params = vec![
+            Column {
+                table: "".to_string(),
+                column: "?".to_string(),
+                coltype: ColumnType::MYSQL_TYPE_VAR_STRING,
+                colflags: ColumnFlags::empty(),
+            };
+            statement.num_placeholders()
+        ];
+
+        if let Some((schema, _)) = self.federated_server_command_check(&sql) {
+            return Ok((statement, params, convert_schema(&schema, true)?));
+        }
+
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new_with_query_executor(
+            context.clone(),
+            Arc::new(ServiceQueryExecutor::new(context.clone())),
+        );
+        let extras = planner.parse_sql(&sql)?;
+        // NULL is not a valid value everywhere, e.g. in a non-nullable column, the
+        // statement is planned again with the parameters on execution anyway.
+        let plan = match planner.plan_stmt(&extras.statement).await {
+            Ok(plan) => plan,
+            Err(error) => {
+                info!("Cannot describe prepared statement {}: {}", query, error);
+                return Ok((statement, params, vec![]));
+            }
+        };
+
+        // The parameters of `INSERT ... VALUES (?, ?)` take the types of the columns.
+        if let Plan::Insert(insert) = &plan {
+            let num_fields = insert.schema.num_fields();
+            if matches!(insert.source, InsertInputSource::Values(_))
+                && num_fields > 0
+                && params.len() % num_fields == 0
+            {
+                let schema = Arc::new(DataSchema::from(&insert.schema));
+                let columns = convert_schema(&schema, true)?;
+                for (param, column) in params.iter_mut().zip(columns.into_iter().cycle()) {
+                    param.coltype = column.coltype;
+                    param.colflags = column.colflags;
+                }
+            }
+        }
+
+        let columns = match plan.has_result_set() {
+            true => convert_schema(&plan.schema(), true)?,
+            false => vec![],
+        };
+        Ok((statement, params, columns))
+    }
+
+    #[async_backtrace::framed]
+    async fn do_execute<W: AsyncWrite + Send + Unpin>(
+        &mut self,
+        id: u32,
+        params: ParamParser<'_>,
         writer: QueryResultWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Execute is not support in Databend.".as_bytes(),
-            )
-            .await?;
-        Ok(())
+        let mut writer = DFQueryResultWriter::create_binary(writer, self.session.clone());
+        let query = match self.prepared_statements.get(&id) {
+            None => Err(ErrorCode::BadArguments(format!(
+                "Unknown prepared statement {id}"
+            ))),
+            Some(statement) => params_to_exprs(params).and_then(|params| statement.bind(&params)),
+        };
+
+        let query_result = match &query {
+            Ok(query) => {
+                let query_id = Uuid::new_v4().to_string();
+                let mut tracking_payload = ThreadTracker::new_tracking_payload();
+                tracking_payload.query_id = Some(query_id.clone());
+                let _guard = ThreadTracker::tracking(tracking_payload);
+
+                ThreadTracker::tracking_future(self.do_query(query_id, query))
+                    .await
+                    .map_err(|err| err.display_with_sql(query))
+            }
+            Err(error) => Err(error.clone()),
+        };
+
+        let format = self.session.get_format_settings();
+        let mut write_result = writer.write(query_result, &format).await;
+        if let (Err(cause), Ok(query)) = (write_result.as_ref(), query.as_ref()) {
+            self.session.txn_mgr().lock().set_fail();
+            let suffix = format!("(while in query {})", query);
+            write_result = Err(cause.clone().add_message_back(suffix));
+        }
+        write_result
     }
 
     #[async_backtrace::framed]
-    async fn do_close(&mut self, _: u32) {}
+    async fn do_close(&mut self, id: u32) {
+        self.prepared_statements.remove(&id);
+    }
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
@@ -472,7 +603,11 @@ impl InteractiveWorker {
         }
 
         InteractiveWorker {
-            base: InteractiveWorkerBase { session },
+            base: InteractiveWorkerBase {
+                session,
+                prepared_statements: HashMap::new(),
+                next_statement_id: 0,
+            },
             salt: scramble,
             version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
             client_addr,
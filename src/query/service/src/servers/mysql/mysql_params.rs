@@ -0,0 +1,184 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::TypeName;
+use databend_common_ast::ast::UnaryOperator;
+use databend_common_ast::parser::parse_expr;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use opensrv_mysql::ColumnType;
+use opensrv_mysql::ParamParser;
+use opensrv_mysql::ParamValue;
+use opensrv_mysql::ValueInner;
+
+/// Converts the parameters of COM_STMT_EXECUTE to the literals bound to the placeholders.
+pub fn params_to_exprs(params: ParamParser<'_>) -> Result<Vec<Expr>> {
+    params.into_iter().map(param_to_expr).collect()
+}
+
+fn param_to_expr(param: ParamValue<'_>) -> Result<Expr> {
+    let coltype = param.coltype;
+    match param.value.into_inner() {
+        ValueInner::NULL => Ok(literal(Literal::Null)),
+        ValueInner::Int(v) if v < 0 => Ok(Expr::UnaryOp {
+            span: None,
+            op: UnaryOperator::Minus,
+            expr: Box::new(literal(Literal::UInt64(v.unsigned_abs()))),
+        }),
+        ValueInner::Int(v) => Ok(literal(Literal::UInt64(v as u64))),
+        ValueInner::UInt(v) => Ok(literal(Literal::UInt64(v))),
+        ValueInner::Double(v) => {
+            let target_type = match coltype {
+                ColumnType::MYSQL_TYPE_FLOAT => TypeName::Float32,
+                _ => TypeName::Float64,
+            };
+            Ok(cast(literal(Literal::Float64(v)), target_type))
+        }
+        ValueInner::Bytes(bytes) => match coltype {
+            ColumnType::MYSQL_TYPE_DECIMAL | ColumnType::MYSQL_TYPE_NEWDECIMAL => {
+                decimal_to_expr(bytes)
+            }
+            _ => match std::str::from_utf8(bytes) {
+                Ok(v) => Ok(literal(Literal::String(v.to_string()))),
+                // Binary data of a BLOB parameter.
+                Err(_) => Ok(Expr::FunctionCall {
+                    span: None,
+                    func: FunctionCall {
+                        distinct: false,
+                        name: Identifier::from_name(None, "from_hex"),
+                        args: vec![literal(Literal::String(hex::encode(bytes)))],
+                        params: vec![],
+                        order_by: vec![],
+                        filter: None,
+                        window: None,
+                        lambda: None,
+                    },
+                }),
+            },
+        },
+        ValueInner::Date(bytes) => {
+            let datetime = decode_datetime(bytes)?;
+            match coltype {
+                ColumnType::MYSQL_TYPE_DATE | ColumnType::MYSQL_TYPE_NEWDATE => Ok(cast(
+                    literal(Literal::String(datetime[..10].to_string())),
+                    TypeName::Date,
+                )),
+                _ => Ok(cast(
+                    literal(Literal::String(datetime)),
+                    TypeName::Timestamp,
+                )),
+            }
+        }
+        // There is no time type, a time is bound as a string.
+        ValueInner::Time(bytes) => Ok(literal(Literal::String(decode_time(bytes)?))),
+    }
+}
+
+fn literal(value: Literal) -> Expr {
+    Expr::Literal { span: None, value }
+}
+
+fn cast(expr: Expr, target_type: TypeName) -> Expr {
+    Expr::Cast {
+        span: None,
+        expr: Box::new(expr),
+        target_type,
+        pg_style: true,
+    }
+}
+
+// Decimals are sent as text, which is parsed as a number literal to keep its precision.
+fn decimal_to_expr(bytes: &[u8]) -> Result<Expr> {
+    let invalid = || ErrorCode::BadArguments("Invalid decimal parameter of prepared statement");
+    let text = std::str::from_utf8(bytes).map_err(|_| invalid())?;
+    let tokens = tokenize_sql(text)?;
+    match parse_expr(&tokens, Dialect::default())? {
+        expr @ Expr::Literal {
+            value: Literal::UInt64(_) | Literal::Decimal256 { .. } | Literal::Float64(_),
+            ..
+        } => Ok(expr),
+        Expr::UnaryOp {
+            op: UnaryOperator::Minus,
+            expr,
+            ..
+        } if matches!(*expr, Expr::Literal {
+            value: Literal::UInt64(_) | Literal::Decimal256 { .. } | Literal::Float64(_),
+            ..
+        }) =>
+        {
+            Ok(Expr::UnaryOp {
+                span: None,
+                op: UnaryOperator::Minus,
+                expr,
+            })
+        }
+        _ => Err(invalid()),
+    }
+}
+
+// https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_binary_resultset.html
+// year: u16, month, day, [hour, minute, second, [microsecond: u32]]
+fn decode_datetime(bytes: &[u8]) -> Result<String> {
+    let (year, month, day) = match bytes.len() {
+        0 => (0, 0, 0),
+        4 | 7 | 11 => (u16::from_le_bytes([bytes[0], bytes[1]]), bytes[2], bytes[3]),
+        len => {
+            return Err(ErrorCode::BadArguments(format!(
+                "Invalid datetime parameter of prepared statement, length: {len}"
+            )));
+        }
+    };
+    let (hour, minute, second) = match bytes.len() {
+        7 | 11 => (bytes[4], bytes[5], bytes[6]),
+        _ => (0, 0, 0),
+    };
+    let micros = match bytes.len() {
+        11 => u32::from_le_bytes([bytes[7], bytes[8], bytes[9], bytes[10]]),
+        _ => 0,
+    };
+    Ok(format!(
+        "{year:04}-{month:02}-{day:02} {hour:02}:{minute:02}:{second:02}.{micros:06}"
+    ))
+}
+
+// is_negative, days: u32, hour, minute, second, [microsecond: u32]
+fn decode_time(bytes: &[u8]) -> Result<String> {
+    match bytes.len() {
+        0 => Ok("00:00:00".to_string()),
+        8 | 12 => {
+            let sign = if bytes[0] == 1 { "-" } else { "" };
+            let days = u32::from_le_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]);
+            let hours = days as u64 * 24 + bytes[5] as u64;
+            let (minute, second) = (bytes[6], bytes[7]);
+            match bytes.len() {
+                12 => {
+                    let micros = u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]);
+                    Ok(format!(
+                        "{sign}{hours:02}:{minute:02}:{second:02}.{micros:06}"
+                    ))
+                }
+                _ => Ok(format!("{sign}{hours:02}:{minute:02}:{second:02}")),
+            }
+        }
+        len => Err(ErrorCode::BadArguments(format!(
+            "Invalid time parameter of prepared statement, length: {len}"
+        ))),
+    }
+}
@@ -1250,6 +1250,24 @@ impl TableContext for QueryContext {
         self.shared.session.session_ctx.get_all_variables()
     }
 
+    fn set_prepared_statement(&self, name: String, sql: String) {
+        self.shared
+            .session
+            .session_ctx
+            .set_prepared_statement(name, sql)
+    }
+
+    fn remove_prepared_statement(&self, name: &str) -> Option<String> {
+        self.shared
+            .session
+            .session_ctx
+            .remove_prepared_statement(name)
+    }
+
+    fn get_prepared_statement(&self, name: &str) -> Option<String> {
+        self.shared.session.session_ctx.get_prepared_statement(name)
+    }
+
     #[async_backtrace::framed]
     async fn load_datalake_schema(
         &self,
@@ -1309,6 +1327,7 @@ impl TableContext for QueryContext {
                     duplicated_files_detected: vec![],
                     is_select: true,
                     default_values: None,
+                    check_constraints: vec![],
                     copy_into_location_options: Default::default(),
                 };
                 OrcTable::try_create(info).await
@@ -1326,6 +1345,7 @@ impl TableContext for QueryContext {
                     duplicated_files_detected: vec![],
                     is_select: true,
                     default_values: None,
+                    check_constraints: vec![],
                     copy_into_location_options: Default::default(),
                 };
                 StageTable::try_create(info)
@@ -1361,6 +1381,7 @@ impl TableContext for QueryContext {
                     duplicated_files_detected: vec![],
                     is_select: true,
                     default_values: None,
+                    check_constraints: vec![],
                     copy_into_location_options: Default::default(),
                 };
                 StageTable::try_create(info)
@@ -38,6 +38,7 @@ use databend_common_metrics::session::inc_session_running_acquired_queries;
 use databend_common_metrics::session::incr_session_queue_abort_count;
 use databend_common_metrics::session::incr_session_queue_acquire_error_count;
 use databend_common_metrics::session::incr_session_queue_acquire_timeout_count;
+use databend_common_metrics::session::incr_workload_group_queue_timeout_count;
 use databend_common_metrics::session::record_session_queue_acquire_duration_ms;
 use databend_common_metrics::session::set_session_queued_queries;
 use databend_common_sql::plans::ModifyColumnAction;
@@ -53,6 +54,8 @@ use tokio::sync::Semaphore;
 use tokio::time::error::Elapsed;
 
 use crate::sessions::QueryContext;
+use crate::sessions::WorkloadGroupResource;
+use crate::sessions::WorkloadGroupResourceManager;
 
 pub trait QueueData: Send + Sync + 'static {
     type Key: Send + Sync + Eq + Hash + Display + Clone + 'static;
@@ -68,6 +71,11 @@ pub trait QueueData: Send + Sync + 'static {
     fn enter_wait_pending(&self) {}
 
     fn exit_wait_pending(&self, _wait_time: Duration) {}
+
+    /// The workload group whose limits the data is queued by, besides the global queue.
+    fn workload_group(&self) -> Option<Arc<WorkloadGroupResource>> {
+        None
+    }
 }
 
 pub(crate) struct Inner<Data: QueueData> {
@@ -132,6 +140,27 @@ impl<Data: QueueData> QueueManager<Data> {
     }
 
     pub async fn acquire(self: &Arc<Self>, data: Data) -> Result<AcquireQueueGuard> {
+        let data = Arc::new(data);
+
+        // The limits of the workload group apply to all the queries of the group,
+        // including the ones which skip the global queue.
+        let workload_group = match data.workload_group() {
+            None => None,
+            Some(group) => Some(self.acquire_workload_group(&data, group).await?),
+        };
+
+        let mut guard = self.acquire_global(data.clone()).await?;
+
+        if let Some((group, permit)) = workload_group {
+            let query_id = data.get_key().to_string();
+            group.enter_running();
+            WorkloadGroupResourceManager::instance().register_query(&query_id, group.clone());
+            guard.workload_group = Some((query_id, group, permit));
+        }
+        Ok(guard)
+    }
+
+    async fn acquire_global(self: &Arc<Self>, data: Arc<Data>) -> Result<AcquireQueueGuard> {
         if data.need_acquire_to_queue() {
             info!(
                 "preparing to acquire from query queue, length: {}",
@@ -140,7 +169,7 @@ impl<Data: QueueData> QueueManager<Data> {
 
             let timeout = data.timeout();
             let future = AcquireQueueFuture::create(
-                Arc::new(data),
+                data,
                 tokio::time::timeout(timeout, self.semaphore.clone().acquire_owned()),
                 self.clone(),
             );
@@ -176,6 +205,49 @@ impl<Data: QueueData> QueueManager<Data> {
         Ok(AcquireQueueGuard::create(None))
     }
 
+    /// Wait for a running slot of the workload group. The permit is None if the concurrency
+    /// of the group is unlimited.
+    async fn acquire_workload_group(
+        self: &Arc<Self>,
+        data: &Arc<Data>,
+        group: Arc<WorkloadGroupResource>,
+    ) -> Result<(Arc<WorkloadGroupResource>, Option<OwnedSemaphorePermit>)> {
+        let Some(semaphore) = group.semaphore.clone() else {
+            return Ok((group, None));
+        };
+
+        info!(
+            "preparing to acquire from workload group {} queue",
+            group.name()
+        );
+
+        let timeout = group.queue_timeout().unwrap_or_else(|| data.timeout());
+        let future = AcquireQueueFuture::create(
+            data.clone(),
+            tokio::time::timeout(timeout, semaphore.acquire_owned()),
+            self.clone(),
+        );
+
+        group.enter_queue();
+        let res = future.await;
+        group.exit_queue();
+
+        match res {
+            Ok(mut guard) => {
+                let permit = guard.permit.take();
+                Ok((group, permit))
+            }
+            Err(e) if e.code() == ErrorCode::TIMEOUT => {
+                incr_workload_group_queue_timeout_count(group.name());
+                Err(ErrorCode::Timeout(format!(
+                    "query queuing timeout in workload group {}",
+                    group.name()
+                )))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     pub(crate) fn add_entity(&self, inner: Inner<Data>) -> Data::Key {
         inner.data.enter_wait_pending();
 
@@ -208,8 +280,12 @@ impl<Data: QueueData> QueueManager<Data> {
 }
 
 pub struct AcquireQueueGuard {
-    #[allow(dead_code)]
     permit: Option<OwnedSemaphorePermit>,
+    workload_group: Option<(
+        String,
+        Arc<WorkloadGroupResource>,
+        Option<OwnedSemaphorePermit>,
+    )>,
 }
 
 impl Drop for AcquireQueueGuard {
@@ -217,12 +293,20 @@ impl Drop for AcquireQueueGuard {
         if self.permit.is_some() {
             dec_session_running_acquired_queries();
         }
+
+        if let Some((query_id, group, _permit)) = self.workload_group.take() {
+            WorkloadGroupResourceManager::instance().unregister_query(&query_id);
+            group.exit_running();
+        }
     }
 }
 
 impl AcquireQueueGuard {
     pub fn create(permit: Option<OwnedSemaphorePermit>) -> Self {
-        AcquireQueueGuard { permit }
+        AcquireQueueGuard {
+            permit,
+            workload_group: None,
+        }
     }
 }
 
@@ -322,6 +406,7 @@ pub struct QueryEntry {
     pub user_info: UserInfo,
     pub timeout: Duration,
     pub need_acquire_to_queue: bool,
+    pub workload_group: Option<Arc<WorkloadGroupResource>>,
 }
 
 impl QueryEntry {
@@ -338,6 +423,7 @@ impl QueryEntry {
             create_time: ctx.get_created_time(),
             sql: plan_extras.statement.to_mask_sql(),
             user_info: ctx.get_current_user()?,
+            workload_group: None,
             timeout: match settings.get_statement_queued_timeout()? {
                 0 => Duration::from_secs(60 * 60 * 24 * 365 * 35),
                 timeout => Duration::from_secs(timeout),
@@ -412,7 +498,8 @@ impl QueryEntry {
             | Plan::VacuumTemporaryFiles(_)
             | Plan::RefreshIndex(_)
             | Plan::ReclusterTable { .. }
-            | Plan::TruncateTable(_) => {
+            | Plan::TruncateTable(_)
+            | Plan::WarmTableCache(_) => {
                 return true;
             }
             Plan::DropTable(v) if v.all => {
@@ -470,6 +557,10 @@ impl QueueData for QueryEntry {
             .set_status_info(format!("resource scheduled(elapsed: {:?})", wait_time).as_str());
         self.ctx.set_query_queued_duration(wait_time)
     }
+
+    fn workload_group(&self) -> Option<Arc<WorkloadGroupResource>> {
+        self.workload_group.clone()
+    }
 }
 
 pub type QueriesQueueManager = QueueManager<QueryEntry>;
@@ -188,6 +188,10 @@ impl Session {
         self.session_ctx.set_client_host(host);
     }
 
+    pub fn get_client_host(&self) -> Option<String> {
+        self.session_ctx.get_client_host()
+    }
+
     pub fn set_current_database(&self, database_name: String) {
         self.session_ctx.set_current_database(database_name);
     }
@@ -371,6 +375,15 @@ impl Session {
         self.session_ctx.set_all_variables(variables)
     }
 
+    pub fn get_all_prepared_statements(&self) -> HashMap<String, String> {
+        self.session_ctx.get_all_prepared_statements()
+    }
+
+    pub fn set_all_prepared_statements(&self, prepared_statements: HashMap<String, String>) {
+        self.session_ctx
+            .set_all_prepared_statements(prepared_statements)
+    }
+
     pub fn get_client_session_id(&self) -> Option<String> {
         self.session_ctx.get_client_session_id()
     }
@@ -74,6 +74,8 @@ pub struct SessionContext {
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
     // Used in set variables inside session
     variables: Arc<RwLock<HashMap<String, Scalar>>>,
+    // Used in PREPARE and EXECUTE inside session, name -> sql
+    prepared_statements: Arc<RwLock<HashMap<String, String>>>,
     typ: SessionType,
     txn_mgr: Mutex<TxnManagerRef>,
     temp_tbl_mgr: Mutex<TempTblMgrRef>,
@@ -102,6 +104,7 @@ impl SessionContext {
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
             variables: Default::default(),
+            prepared_statements: Default::default(),
             typ,
             txn_mgr: Mutex::new(TxnManager::init()),
             client_session_id: Default::default(),
@@ -343,6 +346,24 @@ impl SessionContext {
         *self.variables.write() = variables
     }
 
+    pub fn set_prepared_statement(&self, name: String, sql: String) {
+        self.prepared_statements.write().insert(name, sql);
+    }
+
+    pub fn remove_prepared_statement(&self, name: &str) -> Option<String> {
+        self.prepared_statements.write().remove(name)
+    }
+
+    pub fn get_prepared_statement(&self, name: &str) -> Option<String> {
+        self.prepared_statements.read().get(name).cloned()
+    }
+    pub fn get_all_prepared_statements(&self) -> HashMap<String, String> {
+        self.prepared_statements.read().clone()
+    }
+    pub fn set_all_prepared_statements(&self, prepared_statements: HashMap<String, String>) {
+        *self.prepared_statements.write() = prepared_statements
+    }
+
     pub fn session_state(&self) -> SessionState {
         SessionState {
             txn_mgr: self.txn_mgr(),
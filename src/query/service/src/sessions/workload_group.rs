@@ -0,0 +1,218 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::MemStat;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::WorkloadGroup;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_metrics::session::set_workload_group_memory_usage;
+use databend_common_metrics::session::set_workload_group_queued_queries;
+use databend_common_metrics::session::set_workload_group_running_queries;
+use databend_common_users::UserApiProvider;
+use parking_lot::Mutex;
+use tokio::sync::Semaphore;
+
+/// How long a resolved workload group is reused before it is read from the meta again,
+/// so that queries don't pay a meta round trip each. ALTER and DROP on this node
+/// invalidate it at once, the other nodes pick the change up after the ttl.
+const RESOLVED_GROUP_TTL: Duration = Duration::from_secs(10);
+
+/// The runtime state of a workload group on this node.
+pub struct WorkloadGroupResource {
+    pub meta: WorkloadGroup,
+    /// None if the concurrency of the group is unlimited.
+    pub semaphore: Option<Arc<Semaphore>>,
+    /// The parent memory stat of all the queries of the group.
+    pub mem_stat: Arc<MemStat>,
+    queued: AtomicUsize,
+    running: AtomicUsize,
+}
+
+impl WorkloadGroupResource {
+    fn create(meta: WorkloadGroup) -> Arc<WorkloadGroupResource> {
+        let semaphore = match meta.max_concurrency {
+            0 => None,
+            permits => Some(Arc::new(Semaphore::new(permits as usize))),
+        };
+
+        let mem_stat = MemStat::create(format!("WorkloadGroupMemStat-{}", meta.name));
+        if meta.memory_quota != 0 {
+            mem_stat.set_limit(meta.memory_quota as i64);
+        }
+
+        Arc::new(WorkloadGroupResource {
+            meta,
+            semaphore,
+            mem_stat,
+            queued: AtomicUsize::new(0),
+            running: AtomicUsize::new(0),
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.meta.name
+    }
+
+    /// The queue timeout of the group, None if the statement setting applies.
+    pub fn queue_timeout(&self) -> Option<Duration> {
+        match self.meta.queue_timeout {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        }
+    }
+
+    pub fn enter_queue(&self) {
+        let queued = self.queued.fetch_add(1, Ordering::SeqCst) + 1;
+        set_workload_group_queued_queries(self.name(), queued);
+    }
+
+    pub fn exit_queue(&self) {
+        let queued = self.queued.fetch_sub(1, Ordering::SeqCst) - 1;
+        set_workload_group_queued_queries(self.name(), queued);
+    }
+
+    pub fn enter_running(&self) {
+        let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+        set_workload_group_running_queries(self.name(), running);
+    }
+
+    pub fn exit_running(&self) {
+        let running = self.running.fetch_sub(1, Ordering::SeqCst) - 1;
+        set_workload_group_running_queries(self.name(), running);
+        set_workload_group_memory_usage(self.name(), self.mem_stat.get_memory_usage());
+    }
+
+    pub fn running(&self) -> usize {
+        self.running.load(Ordering::SeqCst)
+    }
+}
+
+/// Holds the runtime resources of the workload groups used by the queries of this node.
+pub struct WorkloadGroupResourceManager {
+    groups: Mutex<HashMap<String, Arc<WorkloadGroupResource>>>,
+    queries: Mutex<HashMap<String, Arc<WorkloadGroupResource>>>,
+    resolved: Mutex<HashMap<(String, String), (Instant, Arc<WorkloadGroupResource>)>>,
+}
+
+impl WorkloadGroupResourceManager {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Self::create());
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<WorkloadGroupResourceManager> {
+        GlobalInstance::get()
+    }
+
+    pub fn create() -> Arc<WorkloadGroupResourceManager> {
+        Arc::new(WorkloadGroupResourceManager {
+            groups: Mutex::new(HashMap::new()),
+            queries: Mutex::new(HashMap::new()),
+            resolved: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Get the resource of the group, recreate it if the limits of the group were altered.
+    ///
+    /// The queries already running keep the resource they acquired.
+    pub fn get_or_create(&self, meta: WorkloadGroup) -> Arc<WorkloadGroupResource> {
+        let mut groups = self.groups.lock();
+        if let Some(resource) = groups.get(&meta.name) {
+            if resource.meta.same_limits(&meta) {
+                return resource.clone();
+            }
+        }
+
+        let resource = WorkloadGroupResource::create(meta);
+        groups.insert(resource.meta.name.clone(), resource.clone());
+        resource
+    }
+
+    /// Get the resource of the group by name, reading the group from the meta at most
+    /// once per [`RESOLVED_GROUP_TTL`].
+    pub async fn resolve(&self, tenant: &Tenant, name: &str) -> Result<Arc<WorkloadGroupResource>> {
+        let key = (tenant.tenant_name().to_string(), name.to_string());
+        if let Some((instant, resource)) = self.resolved.lock().get(&key) {
+            if instant.elapsed() < RESOLVED_GROUP_TTL {
+                return Ok(resource.clone());
+            }
+        }
+
+        let meta = UserApiProvider::instance()
+            .get_workload_group(tenant, name)
+            .await?;
+        let resource = self.get_or_create(meta);
+        self.resolved
+            .lock()
+            .insert(key, (Instant::now(), resource.clone()));
+        Ok(resource)
+    }
+
+    /// Forget the resolved group, called after the group is altered or dropped.
+    pub fn invalidate(&self, tenant: &Tenant, name: &str) {
+        let key = (tenant.tenant_name().to_string(), name.to_string());
+        self.resolved.lock().remove(&key);
+    }
+
+    /// Account a query of the group running on this node on behalf of another node,
+    /// the limits of the group apply to its fragments as well.
+    pub fn register_remote_query(&self, query_id: &str, meta: WorkloadGroup) {
+        let resource = self.get_or_create(meta);
+        resource.enter_running();
+        self.register_query(query_id, resource);
+    }
+
+    pub fn unregister_remote_query(&self, query_id: &str) {
+        if let Some(resource) = self.queries.lock().remove(query_id) {
+            resource.exit_running();
+        }
+    }
+
+    pub fn register_query(&self, query_id: &str, resource: Arc<WorkloadGroupResource>) {
+        self.queries.lock().insert(query_id.to_string(), resource);
+    }
+
+    pub fn unregister_query(&self, query_id: &str) {
+        self.queries.lock().remove(query_id);
+    }
+
+    pub fn get_by_query(&self, query_id: &str) -> Option<Arc<WorkloadGroupResource>> {
+        self.queries.lock().get(query_id).cloned()
+    }
+
+    /// The executor threads of a query of the group, split by the cpu weight of the groups
+    /// which have running queries.
+    pub fn thread_share(&self, resource: &WorkloadGroupResource, max_threads: u64) -> u64 {
+        let groups = self.groups.lock();
+        let total_weight = groups
+            .values()
+            .filter(|group| group.running() > 0 || group.meta.name == resource.meta.name)
+            .map(|group| group.meta.cpu_weight)
+            .sum::<u64>();
+
+        if total_weight == 0 {
+            return max_threads;
+        }
+
+        std::cmp::max(1, max_threads * resource.meta.cpu_weight / total_weight)
+    }
+}
@@ -47,6 +47,7 @@ pub enum SpillerType {
     HashJoinProbe,
     Window,
     OrderBy,
+    AsofJoinBuild,
     // Todo: Add more spillers type
     // Aggregation
 }
@@ -58,6 +59,7 @@ impl Display for SpillerType {
             SpillerType::HashJoinProbe => write!(f, "HashJoinProbe"),
             SpillerType::Window => write!(f, "Window"),
             SpillerType::OrderBy => write!(f, "OrderBy"),
+            SpillerType::AsofJoinBuild => write!(f, "AsofJoinBuild"),
         }
     }
 }
@@ -347,6 +347,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: Some("(id)".to_string()),
             inverted_indexes: None,
+            constraints: Default::default(),
         }
     }
 
@@ -372,6 +373,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            constraints: Default::default(),
         }
     }
 
@@ -408,6 +410,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            constraints: Default::default(),
         }
     }
 
@@ -444,6 +447,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            constraints: Default::default(),
         }
     }
 
@@ -489,6 +493,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            constraints: Default::default(),
         }
     }
 
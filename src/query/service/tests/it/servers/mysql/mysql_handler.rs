@@ -32,6 +32,7 @@ use mysql_async::prelude::Queryable;
 use mysql_async::FromRowError;
 use mysql_async::Row;
 use mysql_async::SslOpts;
+use mysql_async::Value;
 use tokio::sync::Barrier;
 
 use crate::tests::tls_constants::*;
@@ -72,6 +73,66 @@ async fn test_connect_with_tls() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port(), false).await?;
+
+    connection
+        .query_drop("CREATE TABLE t_prepared(a INT, b STRING, c DOUBLE, d DATE)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "create table")?;
+
+    let insert = connection
+        .prep("INSERT INTO t_prepared VALUES (?, ?, ?, ?)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "prepare insert")?;
+    assert_eq!(insert.num_params(), 4);
+    for (a, b) in [(1, "it's"), (-2, "--")] {
+        connection
+            .exec_drop(&insert, (a, b, 1.5f64, Value::Date(2024, 1, 2, 0, 0, 0, 0)))
+            .await
+            .map_err_to_code(ErrorCode::UnknownException, || "execute insert")?;
+    }
+    connection
+        .close(insert)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "close insert")?;
+
+    let select = connection
+        .prep("SELECT a, b, c, d FROM t_prepared WHERE a = ? OR b = ? ORDER BY a")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "prepare select")?;
+    assert_eq!(select.num_params(), 2);
+    assert_eq!(select.num_columns(), 4);
+    let rows: Vec<(i32, String, f64, Value)> = connection
+        .exec(&select, (1, "--"))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "execute select")?;
+    assert_eq!(rows, vec![
+        (
+            -2,
+            "--".to_string(),
+            1.5,
+            Value::Date(2024, 1, 2, 0, 0, 0, 0)
+        ),
+        (
+            1,
+            "it's".to_string(),
+            1.5,
+            Value::Date(2024, 1, 2, 0, 0, 0, 0)
+        ),
+    ]);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_rejected_session_with_sequence() -> Result<()> {
     // TestFixture will create a default session, so we should limit the max_active_sessions to 2.
@@ -889,6 +889,16 @@ impl TableContext for CtxDelegation {
         HashMap::new()
     }
 
+    fn set_prepared_statement(&self, _name: String, _sql: String) {}
+
+    fn remove_prepared_statement(&self, _name: &str) -> Option<String> {
+        None
+    }
+
+    fn get_prepared_statement(&self, _name: &str) -> Option<String> {
+        None
+    }
+
     fn get_license_key(&self) -> String {
         self.ctx.get_license_key()
     }
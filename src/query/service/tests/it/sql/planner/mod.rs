@@ -14,4 +14,5 @@
 
 mod builders;
 mod optimizer;
+mod prepared_statement;
 mod semantic;
@@ -0,0 +1,106 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_sql::optimizer::SExpr;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::plans::RelOperator;
+use databend_common_sql::MetadataRef;
+use databend_common_sql::Planner;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+// Returns the name of the table on the build side of the join.
+fn build_table(s_expr: &SExpr, metadata: &MetadataRef) -> Option<String> {
+    match s_expr.plan() {
+        RelOperator::Join(_) => scan_table(s_expr.child(1).ok()?, metadata),
+        _ => s_expr
+            .children()
+            .find_map(|child| build_table(child, metadata)),
+    }
+}
+
+fn scan_table(s_expr: &SExpr, metadata: &MetadataRef) -> Option<String> {
+    match s_expr.plan() {
+        RelOperator::Scan(scan) => Some(metadata.read().table(scan.table_index).name().to_string()),
+        _ => s_expr
+            .children()
+            .find_map(|child| scan_table(child, metadata)),
+    }
+}
+
+// Plans and executes the statement, returns the build table of the join and the number of rows.
+async fn execute(fixture: &TestFixture, sql: &str) -> Result<(Option<String>, usize)> {
+    let ctx = fixture.new_query_ctx().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let build = match &plan {
+        Plan::Query {
+            s_expr, metadata, ..
+        } => build_table(s_expr, metadata),
+        _ => None,
+    };
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let blocks: Vec<DataBlock> = interpreter.execute(ctx).await?.try_collect().await?;
+    Ok((build, blocks.iter().map(|block| block.num_rows()).sum()))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_prepared_plan_reoptimized() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!(
+            "create table {db}.big(id int) as select number from numbers(1000)"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "create table {db}.small(id int) as select number from numbers(10)"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "prepare s from 'select * from {db}.big join {db}.small on big.id = small.id where big.id >= ?'"
+        ))
+        .await?;
+
+    let (build, rows) = execute(&fixture, "execute s using 0").await?;
+    assert_eq!(build.as_deref(), Some("small"));
+    assert_eq!(rows, 10);
+
+    // The cached plan is reused while the number of rows barely changes.
+    fixture
+        .execute_command(&format!("insert into {db}.small values(10)"))
+        .await?;
+    let (build, rows) = execute(&fixture, "execute s using 5").await?;
+    assert_eq!(build.as_deref(), Some("small"));
+    assert_eq!(rows, 6);
+
+    // The plan is optimized again after the small table grows bigger than the other one.
+    fixture
+        .execute_command(&format!(
+            "insert into {db}.small select number from numbers(100000)"
+        ))
+        .await?;
+    let (build, rows) = execute(&fixture, "execute s using 995").await?;
+    assert_eq!(build.as_deref(), Some("big"));
+    assert_eq!(rows, 5);
+
+    Ok(())
+}
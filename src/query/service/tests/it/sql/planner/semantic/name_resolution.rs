@@ -59,6 +59,7 @@ fn test_normalize_identifier_quoted_case_insensitive() {
         unquoted_ident_case_sensitive: false,
         quoted_ident_case_sensitive: false,
         deny_column_reference: false,
+        allow_internal_functions: false,
     };
 
     {
@@ -82,6 +83,7 @@ fn test_normalize_identifier_unquoted_case_sensitive() {
         unquoted_ident_case_sensitive: true,
         quoted_ident_case_sensitive: true,
         deny_column_reference: false,
+        allow_internal_functions: false,
     };
 
     {
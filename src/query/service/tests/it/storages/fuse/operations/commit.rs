@@ -735,6 +735,16 @@ impl TableContext for CtxDelegation {
         HashMap::new()
     }
 
+    fn set_prepared_statement(&self, _name: String, _sql: String) {}
+
+    fn remove_prepared_statement(&self, _name: &str) -> Option<String> {
+        None
+    }
+
+    fn get_prepared_statement(&self, _name: &str) -> Option<String> {
+        None
+    }
+
     fn set_materialized_cte(
         &self,
         _idx: (usize, usize),
@@ -887,6 +887,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_cache_affinity_scheduling", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables assigning the blocks of fuse tables to cluster nodes by consistent hashing of their locations, so that each node caches its own slice of the table",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("max_vacuum_temp_files_after_query", DefaultSettingValue {
                     value: UserSettingValue::UInt64(u64::MAX),
                     desc: "The maximum temp files will be removed after query. please enable vacuum feature. disable if 0",
@@ -49,6 +49,7 @@ use crate::executor::physical_plans::Filter;
 use crate::executor::physical_plans::FragmentKind;
 use crate::executor::physical_plans::HashJoin;
 use crate::executor::physical_plans::Limit;
+use crate::executor::physical_plans::MatchRecognize;
 use crate::executor::physical_plans::MaterializedCte;
 use crate::executor::physical_plans::Mutation;
 use crate::executor::physical_plans::MutationManipulate;
@@ -351,6 +352,7 @@ fn to_format_tree(
         }
         PhysicalPlan::AggregateFinal(plan) => aggregate_final_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Window(plan) => window_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::MatchRecognize(plan) => match_recognize_to_format_tree(plan, metadata, profs),
         PhysicalPlan::WindowPartition(plan) => {
             window_partition_to_format_tree(plan, metadata, profs)
         }
@@ -1203,6 +1205,72 @@ fn window_to_format_tree(
     ))
 }
 
+fn match_recognize_to_format_tree(
+    plan: &MatchRecognize,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let partition_by = plan
+        .partition_by
+        .iter()
+        .map(|&index| metadata.column(index).name())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let order_by = plan
+        .order_by
+        .iter()
+        .map(|v| v.display_name.clone())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let measures = plan
+        .measures
+        .iter()
+        .map(|measure| measure.display_name.clone())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let rows_per_match = if plan.all_rows_per_match {
+        "ALL ROWS PER MATCH"
+    } else {
+        "ONE ROW PER MATCH"
+    };
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("partition by: [{partition_by}]")),
+        FormatTreeNode::new(format!("order by: [{order_by}]")),
+        FormatTreeNode::new(format!("measures: [{measures}]")),
+        FormatTreeNode::new(format!("rows per match: {rows_per_match}")),
+        FormatTreeNode::new(format!(
+            "after match skip: {}",
+            plan.after_match_skip.display(&plan.variables)
+        )),
+        FormatTreeNode::new(format!(
+            "pattern: ({})",
+            plan.pattern.display(&plan.variables)
+        )),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "MatchRecognize".to_string(),
+        children,
+    ))
+}
+
 fn sort_to_format_tree(
     plan: &Sort,
     metadata: &Metadata,
@@ -1302,6 +1370,20 @@ fn limit_to_format_tree(
         FormatTreeNode::new(format!("offset: {}", plan.offset)),
     ];
 
+    if let Some(percent) = plan.percent {
+        children.push(FormatTreeNode::new(format!("percent: {percent}")));
+    }
+
+    if !plan.with_ties.is_empty() {
+        let with_ties = plan
+            .with_ties
+            .iter()
+            .map(|index| metadata.column(*index).name())
+            .collect::<Vec<_>>()
+            .join(", ");
+        children.push(FormatTreeNode::new(format!("with ties: [{with_ties}]")));
+    }
+
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
         children.extend(items);
@@ -1407,6 +1489,7 @@ fn range_join_to_format_tree(
         match plan.range_join_type {
             RangeJoinType::IEJoin => "IEJoin".to_string(),
             RangeJoinType::Merge => "MergeJoin".to_string(),
+            RangeJoinType::Asof => "AsofJoin".to_string(),
         },
         children,
     ))
@@ -57,6 +57,7 @@ use crate::executor::physical_plans::ExpressionScan;
 use crate::executor::physical_plans::Filter;
 use crate::executor::physical_plans::HashJoin;
 use crate::executor::physical_plans::Limit;
+use crate::executor::physical_plans::MatchRecognize;
 use crate::executor::physical_plans::MaterializedCte;
 use crate::executor::physical_plans::Mutation;
 use crate::executor::physical_plans::ProjectSet;
@@ -86,6 +87,7 @@ pub enum PhysicalPlan {
     AggregatePartial(AggregatePartial),
     AggregateFinal(AggregateFinal),
     Window(Window),
+    MatchRecognize(MatchRecognize),
     Sort(Sort),
     WindowPartition(WindowPartition),
     Limit(Limit),
@@ -201,6 +203,11 @@ impl PhysicalPlan {
                 *next_id += 1;
                 plan.input.adjust_plan_id(next_id);
             }
+            PhysicalPlan::MatchRecognize(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
             PhysicalPlan::WindowPartition(plan) => {
                 plan.plan_id = *next_id;
                 *next_id += 1;
@@ -420,6 +427,7 @@ impl PhysicalPlan {
             PhysicalPlan::AggregatePartial(v) => v.plan_id,
             PhysicalPlan::AggregateFinal(v) => v.plan_id,
             PhysicalPlan::Window(v) => v.plan_id,
+            PhysicalPlan::MatchRecognize(v) => v.plan_id,
             PhysicalPlan::WindowPartition(v) => v.plan_id,
             PhysicalPlan::Sort(v) => v.plan_id,
             PhysicalPlan::Limit(v) => v.plan_id,
@@ -475,6 +483,7 @@ impl PhysicalPlan {
             PhysicalPlan::AggregatePartial(plan) => plan.output_schema(),
             PhysicalPlan::AggregateFinal(plan) => plan.output_schema(),
             PhysicalPlan::Window(plan) => plan.output_schema(),
+            PhysicalPlan::MatchRecognize(plan) => plan.output_schema(),
             PhysicalPlan::WindowPartition(plan) => plan.output_schema(),
             PhysicalPlan::Sort(plan) => plan.output_schema(),
             PhysicalPlan::Limit(plan) => plan.output_schema(),
@@ -537,6 +546,7 @@ impl PhysicalPlan {
             PhysicalPlan::AggregatePartial(_) => "AggregatePartial".to_string(),
             PhysicalPlan::AggregateFinal(_) => "AggregateFinal".to_string(),
             PhysicalPlan::Window(_) => "Window".to_string(),
+            PhysicalPlan::MatchRecognize(_) => "MatchRecognize".to_string(),
             PhysicalPlan::WindowPartition(_) => "WindowPartition".to_string(),
             PhysicalPlan::Sort(_) => "Sort".to_string(),
             PhysicalPlan::Limit(_) => "Limit".to_string(),
@@ -600,6 +610,7 @@ impl PhysicalPlan {
             PhysicalPlan::AggregatePartial(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AggregateFinal(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Window(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::MatchRecognize(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::WindowPartition(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Sort(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Limit(plan) => Box::new(std::iter::once(plan.input.as_ref())),
@@ -677,6 +688,7 @@ impl PhysicalPlan {
             PhysicalPlan::AsyncFunction(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::CopyIntoLocation(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::UnionAll(_)
+            | PhysicalPlan::MatchRecognize(_)
             | PhysicalPlan::ExchangeSource(_)
             | PhysicalPlan::HashJoin(_)
             | PhysicalPlan::RangeJoin(_)
@@ -825,6 +837,11 @@ impl PhysicalPlan {
 
                 format!("partition by {}, order by {}", partition_by, order_by)
             }
+            PhysicalPlan::MatchRecognize(v) => format!(
+                "pattern ({}), after match skip {}",
+                v.pattern.display(&v.variables),
+                v.after_match_skip.display(&v.variables)
+            ),
             PhysicalPlan::RowFetch(v) => {
                 let table_schema = v.source.source_info.schema();
                 let projected_schema = v.cols_to_fetch.project_schema(&table_schema);
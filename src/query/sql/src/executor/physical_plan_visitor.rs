@@ -50,6 +50,7 @@ use crate::executor::physical_plans::ExchangeSource;
 use crate::executor::physical_plans::Filter;
 use crate::executor::physical_plans::HashJoin;
 use crate::executor::physical_plans::Limit;
+use crate::executor::physical_plans::MatchRecognize;
 use crate::executor::physical_plans::MaterializedCte;
 use crate::executor::physical_plans::Mutation;
 use crate::executor::physical_plans::MutationSource;
@@ -80,6 +81,7 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::AggregatePartial(plan) => self.replace_aggregate_partial(plan),
             PhysicalPlan::AggregateFinal(plan) => self.replace_aggregate_final(plan),
             PhysicalPlan::Window(plan) => self.replace_window(plan),
+            PhysicalPlan::MatchRecognize(plan) => self.replace_match_recognize(plan),
             PhysicalPlan::WindowPartition(plan) => self.replace_window_partition(plan),
             PhysicalPlan::Sort(plan) => self.replace_sort(plan),
             PhysicalPlan::Limit(plan) => self.replace_limit(plan),
@@ -233,6 +235,15 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_match_recognize(&mut self, plan: &MatchRecognize) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::MatchRecognize(MatchRecognize {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
     fn replace_window_partition(&mut self, plan: &WindowPartition) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -314,6 +325,7 @@ pub trait PhysicalPlanReplacer {
             input: Box::new(input),
             order_by: plan.order_by.clone(),
             limit: plan.limit,
+            limit_with_ties: plan.limit_with_ties,
             after_exchange: plan.after_exchange,
             pre_projection: plan.pre_projection.clone(),
             stat_info: plan.stat_info.clone(),
@@ -328,6 +340,8 @@ pub trait PhysicalPlanReplacer {
             input: Box::new(input),
             limit: plan.limit,
             offset: plan.offset,
+            with_ties: plan.with_ties.clone(),
+            percent: plan.percent,
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -548,6 +562,7 @@ pub trait PhysicalPlanReplacer {
             udf_funcs: plan.udf_funcs.clone(),
             stat_info: plan.stat_info.clone(),
             script_udf: plan.script_udf,
+            table_function: plan.table_function,
         }))
     }
 
@@ -682,6 +697,9 @@ impl PhysicalPlan {
                 PhysicalPlan::Window(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::MatchRecognize(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::WindowPartition(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
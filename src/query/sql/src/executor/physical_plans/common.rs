@@ -20,6 +20,7 @@ use databend_common_expression::types::DataType;
 use databend_common_expression::Scalar;
 use databend_common_functions::aggregates::AggregateFunctionFactory;
 
+use crate::plans::UDAFDesc;
 use crate::IndexType;
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -27,10 +28,14 @@ pub struct AggregateFunctionSignature {
     pub name: String,
     pub params: Vec<Scalar>,
     pub args: Vec<DataType>,
+    pub udaf: Option<UDAFDesc>,
 }
 
 impl AggregateFunctionSignature {
     pub fn return_type(&self) -> Result<DataType> {
+        if let Some(udaf) = &self.udaf {
+            return Ok(udaf.return_type.clone());
+        }
         AggregateFunctionFactory::instance()
             .get(&self.name, self.params.clone(), self.args.clone())?
             .return_type()
@@ -36,6 +36,7 @@ mod physical_filter;
 mod physical_hash_join;
 mod physical_join;
 mod physical_limit;
+mod physical_match_recognize;
 mod physical_materialized_cte;
 mod physical_multi_table_insert;
 mod physical_mutation;
@@ -84,6 +85,7 @@ pub use physical_filter::Filter;
 pub use physical_hash_join::HashJoin;
 pub use physical_join::PhysicalJoinType;
 pub use physical_limit::Limit;
+pub use physical_match_recognize::*;
 pub use physical_materialized_cte::MaterializedCte;
 pub use physical_multi_table_insert::*;
 pub use physical_mutation::*;
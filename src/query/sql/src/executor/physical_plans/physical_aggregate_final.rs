@@ -136,6 +136,7 @@ impl PhysicalPlanBuilder {
                                     }
                                 }).collect::<Result<_>>()?,
                                 params: agg.params.clone(),
+                                udaf: agg.udaf.clone(),
                             },
                             output_column: v.index,
                             arg_indices: agg.args.iter().map(|arg| {
@@ -340,6 +341,7 @@ impl PhysicalPlanBuilder {
                                     }
                                 }).collect::<Result<_>>()?,
                                 params: agg.params.clone(),
+                                udaf: agg.udaf.clone(),
                             },
                             output_column: v.index,
                             arg_indices: agg.args.iter().map(|arg| {
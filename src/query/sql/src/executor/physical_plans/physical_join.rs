@@ -32,10 +32,16 @@ pub enum PhysicalJoinType {
     Hash,
     // The first arg is range conditions, the second arg is other conditions
     RangeJoin(Vec<ScalarExpr>, Vec<ScalarExpr>),
+    // Asof join is executed by the range join operator with a nearest-match search
+    AsofJoin,
 }
 
 // Choose physical join type by join conditions
 pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
+    if join.join_type.is_asof_join() {
+        return Ok(PhysicalJoinType::AsofJoin);
+    }
+
     if !join.equi_conditions.is_empty() {
         // Contain equi condition, use hash join
         return Ok(PhysicalJoinType::Hash);
@@ -168,6 +174,10 @@ impl PhysicalPlanBuilder {
                 self.build_range_join(s_expr, left_required, right_required, range, other)
                     .await
             }
+            PhysicalJoinType::AsofJoin => {
+                self.build_asof_join(join, s_expr, left_required, right_required)
+                    .await
+            }
         }
     }
 }
@@ -26,6 +26,7 @@ use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::SExpr;
 use crate::ColumnEntry;
 use crate::ColumnSet;
+use crate::IndexType;
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Limit {
@@ -34,6 +35,11 @@ pub struct Limit {
     pub input: Box<PhysicalPlan>,
     pub limit: Option<usize>,
     pub offset: usize,
+    /// The columns of `FETCH FIRST ... WITH TIES`, rows which have the same
+    /// values as the last row on these columns are also returned.
+    pub with_ties: Vec<IndexType>,
+    /// The percentage of input rows to return for `FETCH FIRST ... PERCENT`.
+    pub percent: Option<f64>,
 
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
@@ -62,6 +68,7 @@ impl PhysicalPlanBuilder {
             .cloned()
             .collect::<ColumnSet>();
         required.extend(metadata.row_id_indexes());
+        required.extend(limit.with_ties.iter().map(|item| item.index));
 
         // 2. Build physical plan.
         let input_plan = self.build(s_expr.child(0)?, required).await?;
@@ -72,6 +79,8 @@ impl PhysicalPlanBuilder {
                 input: Box::new(input_plan),
                 limit: limit.limit,
                 offset: limit.offset,
+                with_ties: limit.with_ties.iter().map(|item| item.index).collect(),
+                percent: limit.percent.map(|percent| percent.0),
                 stat_info: Some(stat_info),
             }));
         }
@@ -92,6 +101,8 @@ impl PhysicalPlanBuilder {
                 input: Box::new(input_plan),
                 limit: limit.limit,
                 offset: limit.offset,
+                with_ties: limit.with_ties.iter().map(|item| item.index).collect(),
+                percent: limit.percent.map(|percent| percent.0),
                 stat_info: Some(stat_info),
             }));
         }
@@ -116,6 +127,8 @@ impl PhysicalPlanBuilder {
                 input: Box::new(input_plan),
                 limit: limit.limit,
                 offset: limit.offset,
+                with_ties: limit.with_ties.iter().map(|item| item.index).collect(),
+                percent: limit.percent.map(|percent| percent.0),
                 stat_info: Some(stat_info),
             }));
         }
@@ -155,6 +168,8 @@ impl PhysicalPlanBuilder {
                 input: Box::new(input_plan),
                 limit: limit.limit,
                 offset: limit.offset,
+                with_ties: limit.with_ties.iter().map(|item| item.index).collect(),
+                percent: limit.percent.map(|percent| percent.0),
                 stat_info: Some(stat_info.clone()),
             })),
             source: Box::new(source_info),
@@ -0,0 +1,301 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::RemoteExpr;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::executor::cast_expr_to_non_null_boolean;
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::common::AggregateFunctionDesc;
+use crate::executor::physical_plans::common::AggregateFunctionSignature;
+use crate::executor::physical_plans::common::SortDesc;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::plans::AfterMatchSkip;
+use crate::plans::MatchFunction;
+use crate::plans::MatchNavigation;
+use crate::plans::MatchPattern;
+use crate::IndexType;
+use crate::ScalarExpr;
+use crate::TypeCheck;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MatchRecognize {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub partition_by: Vec<IndexType>,
+    pub order_by: Vec<SortDesc>,
+    pub navigations: Vec<MatchNavigation>,
+    pub variables: Vec<String>,
+    // The conditions of pattern variables, which are evaluated over the input columns
+    // followed by the navigation columns.
+    pub conditions: Vec<Option<RemoteExpr>>,
+    pub pattern: MatchPattern,
+    pub functions: Vec<MatchFunctionDesc>,
+    // The measures, which are evaluated over the columns of match functions.
+    pub measures: Vec<MatchMeasureDesc>,
+    pub all_rows_per_match: bool,
+    pub after_match_skip: AfterMatchSkip,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl MatchRecognize {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = if self.all_rows_per_match {
+            input_schema.fields().clone()
+        } else {
+            self.partition_by
+                .iter()
+                .map(|index| Ok(input_schema.field_with_name(&index.to_string())?.clone()))
+                .collect::<Result<Vec<_>>>()?
+        };
+        for measure in self.measures.iter() {
+            fields.push(DataField::new(
+                &measure.index.to_string(),
+                measure.data_type.clone(),
+            ));
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+
+    /// The schema of the conditions, the input columns followed by the navigation columns.
+    pub fn condition_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        navigation_schema(&input_schema, &self.navigations)
+    }
+}
+
+fn navigation_schema(
+    input_schema: &DataSchemaRef,
+    navigations: &[MatchNavigation],
+) -> Result<DataSchemaRef> {
+    let mut fields = input_schema.fields().clone();
+    for navigation in navigations.iter() {
+        let arg = input_schema.field_with_name(&navigation.arg.to_string())?;
+        fields.push(DataField::new(
+            &navigation.index.to_string(),
+            arg.data_type().wrap_nullable(),
+        ));
+    }
+    Ok(DataSchemaRefExt::create(fields))
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MatchFunctionDesc {
+    pub index: IndexType,
+    pub func: MatchFunctionKind,
+    pub data_type: DataType,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum MatchFunctionKind {
+    MatchNumber,
+    Classifier,
+    First {
+        variable: Option<usize>,
+        arg: IndexType,
+    },
+    Last {
+        variable: Option<usize>,
+        arg: IndexType,
+    },
+    Aggregate {
+        variable: Option<usize>,
+        agg: AggregateFunctionDesc,
+    },
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MatchMeasureDesc {
+    pub index: IndexType,
+    pub expr: RemoteExpr,
+    pub data_type: DataType,
+    pub display_name: String,
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_match_recognize(
+        &mut self,
+        s_expr: &SExpr,
+        match_recognize: &crate::plans::MatchRecognize,
+        mut required: ColumnSet,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        // 1. Prune unused Columns.
+        required.extend(match_recognize.used_columns());
+
+        // 2. Build physical plan.
+        let input = self.build(s_expr.child(0)?, required).await?;
+        let input_schema = input.output_schema()?;
+
+        let default_nulls_first = self.ctx.get_settings().get_nulls_first();
+        let order_by = match_recognize
+            .order_by
+            .iter()
+            .map(|v| {
+                let asc = v.asc.unwrap_or(true);
+                SortDesc {
+                    asc,
+                    nulls_first: v.nulls_first.unwrap_or_else(|| default_nulls_first(asc)),
+                    order_by: v.order_by_item.index,
+                    display_name: self.metadata.read().column(v.order_by_item.index).name(),
+                }
+            })
+            .collect::<Vec<_>>();
+        let partition_by = match_recognize
+            .partition_by
+            .iter()
+            .map(|v| v.index)
+            .collect::<Vec<_>>();
+
+        let condition_schema = navigation_schema(&input_schema, &match_recognize.navigations)?;
+        let conditions = match_recognize
+            .variables
+            .iter()
+            .map(|variable| {
+                variable
+                    .condition
+                    .as_ref()
+                    .map(|scalar| {
+                        let expr = scalar
+                            .type_check(condition_schema.as_ref())?
+                            .project_column_ref(|index| {
+                                condition_schema.index_of(&index.to_string()).unwrap()
+                            });
+                        let expr = cast_expr_to_non_null_boolean(expr)?;
+                        let (expr, _) =
+                            ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                        Ok(expr.as_remote_expr())
+                    })
+                    .transpose()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let functions = match_recognize
+            .functions
+            .iter()
+            .map(|item| {
+                let func = match &item.func {
+                    MatchFunction::MatchNumber => MatchFunctionKind::MatchNumber,
+                    MatchFunction::Classifier => MatchFunctionKind::Classifier,
+                    MatchFunction::First { variable, arg, .. } => MatchFunctionKind::First {
+                        variable: *variable,
+                        arg: *arg,
+                    },
+                    MatchFunction::Last { variable, arg, .. } => MatchFunctionKind::Last {
+                        variable: *variable,
+                        arg: *arg,
+                    },
+                    MatchFunction::Aggregate { variable, agg } => MatchFunctionKind::Aggregate {
+                        variable: *variable,
+                        agg: AggregateFunctionDesc {
+                            sig: AggregateFunctionSignature {
+                                name: agg.func_name.clone(),
+                                args: agg
+                                    .args
+                                    .iter()
+                                    .map(|s| s.data_type())
+                                    .collect::<Result<_>>()?,
+                                params: agg.params.clone(),
+                                udaf: agg.udaf.clone(),
+                            },
+                            output_column: item.index,
+                            arg_indices: agg
+                                .args
+                                .iter()
+                                .map(|arg| {
+                                    if let ScalarExpr::BoundColumnRef(col) = arg {
+                                        Ok(col.column.index)
+                                    } else {
+                                        Err(ErrorCode::Internal(
+                                            "Aggregate function argument must be a BoundColumnRef"
+                                                .to_string(),
+                                        ))
+                                    }
+                                })
+                                .collect::<Result<_>>()?,
+                            display: ScalarExpr::AggregateFunction(agg.clone())
+                                .as_expr()?
+                                .sql_display(),
+                        },
+                    },
+                };
+                Ok(MatchFunctionDesc {
+                    index: item.index,
+                    func,
+                    data_type: item.func.return_type(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let function_schema = DataSchemaRefExt::create(
+            functions
+                .iter()
+                .map(|desc| DataField::new(&desc.index.to_string(), desc.data_type.clone()))
+                .collect(),
+        );
+        let measures = match_recognize
+            .measures
+            .iter()
+            .map(|item| {
+                let expr = item
+                    .scalar
+                    .type_check(function_schema.as_ref())?
+                    .project_column_ref(|index| {
+                        function_schema.index_of(&index.to_string()).unwrap()
+                    });
+                let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                Ok(MatchMeasureDesc {
+                    index: item.index,
+                    data_type: expr.data_type().clone(),
+                    expr: expr.as_remote_expr(),
+                    display_name: self.metadata.read().column(item.index).name(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(PhysicalPlan::MatchRecognize(MatchRecognize {
+            plan_id: 0,
+            input: Box::new(input),
+            partition_by,
+            order_by,
+            navigations: match_recognize.navigations.clone(),
+            variables: match_recognize
+                .variables
+                .iter()
+                .map(|var| var.name.clone())
+                .collect(),
+            conditions,
+            pattern: match_recognize.pattern.clone(),
+            functions,
+            measures,
+            all_rows_per_match: match_recognize.all_rows_per_match,
+            after_match_skip: match_recognize.after_match_skip.clone(),
+            stat_info: Some(stat_info),
+        }))
+    }
+}
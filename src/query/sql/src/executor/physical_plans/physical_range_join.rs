@@ -15,6 +15,7 @@
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::type_check::common_super_type;
+use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::RemoteExpr;
@@ -29,6 +30,8 @@ use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
 use crate::optimizer::SExpr;
+use crate::plans::FunctionCall;
+use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::ScalarExpr;
 use crate::TypeCheck;
@@ -41,6 +44,8 @@ pub struct RangeJoin {
     pub right: Box<PhysicalPlan>,
     // The first two conditions: (>, >=, <, <=)
     // Condition's left/right side only contains one table's column
+    // For asof join, the first condition is the match condition and
+    // the rest are the equi conditions, whose operator is "eq".
     pub conditions: Vec<RangeJoinCondition>,
     // The other conditions
     pub other_conditions: Vec<RemoteExpr>,
@@ -55,7 +60,18 @@ pub struct RangeJoin {
 impl RangeJoin {
     pub fn output_schema(&self) -> Result<DataSchemaRef> {
         let mut fields = self.left.output_schema()?.fields().clone();
-        fields.extend(self.right.output_schema()?.fields().clone());
+        if self.join_type == JoinType::LeftAsof {
+            // Wrap nullable type for columns in right side.
+            fields.extend(
+                self.right
+                    .output_schema()?
+                    .fields()
+                    .iter()
+                    .map(|field| DataField::new(field.name(), field.data_type().wrap_nullable())),
+            );
+        } else {
+            fields.extend(self.right.output_schema()?.fields().clone());
+        }
         Ok(DataSchemaRefExt::create(fields))
     }
 }
@@ -64,13 +80,14 @@ impl RangeJoin {
 pub enum RangeJoinType {
     IEJoin,
     Merge,
+    Asof,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct RangeJoinCondition {
     pub left_expr: RemoteExpr,
     pub right_expr: RemoteExpr,
-    // "gt" | "lt" | "gte" | "lte"
+    // "gt" | "lt" | "gte" | "lte", or "eq" for the equi conditions of asof join
     pub operator: String,
 }
 
@@ -142,6 +159,70 @@ impl PhysicalPlanBuilder {
     }
 }
 
+impl PhysicalPlanBuilder {
+    pub async fn build_asof_join(
+        &mut self,
+        join: &Join,
+        s_expr: &SExpr,
+        left_required: ColumnSet,
+        right_required: ColumnSet,
+    ) -> Result<PhysicalPlan> {
+        // Unlike other range joins, the left side of asof join is always the left child.
+        let left_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
+        let right_prop = RelExpr::with_s_expr(s_expr.child(1)?).derive_relational_prop()?;
+
+        // The match condition is the only non-equi condition of an asof join, other
+        // predicates must not have been pushed into it.
+        if join.non_equi_conditions.len() != 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "ASOF JOIN requires exactly one MATCH_CONDITION, but got {} non-equi conditions",
+                join.non_equi_conditions.len()
+            )));
+        }
+
+        let left_side = self.build(s_expr.child(0)?, left_required).await?;
+        let right_side = self.build(s_expr.child(1)?, right_required).await?;
+
+        let left_schema = left_side.output_schema()?;
+        let right_schema = right_side.output_schema()?;
+
+        let mut conditions = Vec::with_capacity(join.equi_conditions.len() + 1);
+        conditions.push(resolve_range_condition(
+            &join.non_equi_conditions[0],
+            &left_schema,
+            &right_schema,
+            &left_prop,
+            &right_prop,
+        )?);
+        for condition in join.equi_conditions.iter() {
+            let equi_condition = ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: "eq".to_string(),
+                params: vec![],
+                arguments: vec![condition.left.clone(), condition.right.clone()],
+            });
+            conditions.push(resolve_range_condition(
+                &equi_condition,
+                &left_schema,
+                &right_schema,
+                &left_prop,
+                &right_prop,
+            )?);
+        }
+
+        Ok(PhysicalPlan::RangeJoin(RangeJoin {
+            plan_id: 0,
+            left: Box::new(left_side),
+            right: Box::new(right_side),
+            conditions,
+            other_conditions: vec![],
+            join_type: join.join_type.clone(),
+            range_join_type: RangeJoinType::Asof,
+            stat_info: Some(self.build_plan_stat_info(s_expr)?),
+        }))
+    }
+}
+
 fn resolve_range_condition(
     expr: &ScalarExpr,
     left_schema: &DataSchemaRef,
@@ -38,6 +38,8 @@ pub struct Sort {
     pub order_by: Vec<SortDesc>,
     /// limit = Limit.limit + Limit.offset
     pub limit: Option<usize>,
+    /// If the rows which tie with the last row within the limit are also returned.
+    pub limit_with_ties: bool,
     /// If the sort plan is after the exchange plan.
     /// It's [None] if the sorting plan is in single node mode.
     pub after_exchange: Option<bool>,
@@ -157,6 +159,7 @@ impl PhysicalPlanBuilder {
             input: Box::new(input_plan),
             order_by,
             limit: sort.limit,
+            limit_with_ties: sort.limit_with_ties,
             after_exchange: sort.after_exchange,
             pre_projection,
             stat_info: Some(stat_info),
@@ -36,6 +36,7 @@ pub struct Udf {
     pub input: Box<PhysicalPlan>,
     pub udf_funcs: Vec<UdfFunctionDesc>,
     pub script_udf: bool,
+    pub table_function: bool,
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
 }
@@ -73,10 +74,10 @@ impl PhysicalPlanBuilder {
         mut required: ColumnSet,
         stat_info: PlanStatsInfo,
     ) -> Result<PhysicalPlan> {
-        // 1. Prune unused Columns.
+        // 1. Prune unused Columns. A table function is kept, as it changes the number of rows.
         let mut used = vec![];
         for item in udf_plan.items.iter() {
-            if required.contains(&item.index) {
+            if udf_plan.table_function || required.contains(&item.index) {
                 required.extend(item.scalar.used_columns());
                 used.push(item.clone());
             }
@@ -147,6 +148,7 @@ impl PhysicalPlanBuilder {
             input: Box::new(input),
             udf_funcs,
             script_udf: udf_plan.script_udf,
+            table_function: udf_plan.table_function,
             stat_info: Some(stat_info),
         }))
     }
@@ -268,6 +268,7 @@ impl PhysicalPlanBuilder {
                         .map(|s| s.data_type())
                         .collect::<Result<_>>()?,
                     params: agg.params.clone(),
+                    udaf: agg.udaf.clone(),
                 },
                 output_column: w.index,
                 arg_indices: agg
@@ -249,6 +249,7 @@ impl<'a> AggregateRewriter<'a> {
             params: aggregate.params.clone(),
             args: replaced_args,
             return_type: aggregate.return_type.clone(),
+            udaf: aggregate.udaf.clone(),
         };
 
         agg_info.aggregate_functions.push(ScalarItem {
@@ -0,0 +1,879 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::ColumnFilter;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::ConnectBy;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Indirection;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::OrderByExpr;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::SetOperation;
+use databend_common_ast::ast::SetOperator;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::TypeName;
+use databend_common_ast::ast::UnaryOperator;
+use databend_common_ast::ast::With;
+use databend_common_ast::ast::CTE;
+use databend_common_ast::Span;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+use derive_visitor::Visitor;
+use derive_visitor::VisitorMut;
+
+use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::NameResolutionContext;
+use crate::Binder;
+
+const PRIOR_TABLE: &str = "__connect_by_prior";
+const CHILD_TABLE: &str = "__connect_by_child";
+const SOURCE_TABLE: &str = "__connect_by_source";
+const DUMMY_COLUMN: &str = "__connect_by_dummy";
+const LEVEL_COLUMN: &str = "__connect_by_level";
+const KEYS_COLUMN: &str = "__connect_by_keys";
+
+impl Binder {
+    /// Rewrite a hierarchical query into a recursive CTE:
+    ///
+    /// ```sql
+    /// SELECT ... FROM (
+    ///     WITH RECURSIVE __connect_by_n AS (
+    ///         SELECT t.*, 1 AS __connect_by_level, ... FROM t WHERE <start with>
+    ///         UNION ALL
+    ///         SELECT t.*, __connect_by_prior.__connect_by_level + 1, ...
+    ///         FROM t, __connect_by_n AS __connect_by_prior
+    ///         WHERE <connect by condition with PRIOR columns of __connect_by_prior>
+    ///     ) SELECT * FROM __connect_by_n
+    /// ) AS t WHERE ...
+    /// ```
+    ///
+    /// `LEVEL`, `SYS_CONNECT_BY_PATH`, `CONNECT_BY_ROOT` and `PRIOR` outside of the
+    /// condition are computed as hidden columns of the CTE. The keys of the ancestors,
+    /// which are the `PRIOR` expressions of the rows, are also kept to find the rows
+    /// already in the path. With `NOCYCLE`, the recursion stops at these rows. Without
+    /// it, the query fails with a "CONNECT BY loop in user data" error.
+    pub(crate) fn rewrite_connect_by(
+        &self,
+        stmt: &SelectStmt,
+        connect_by: &ConnectBy,
+        order_by: &[OrderByExpr],
+    ) -> Result<(SelectStmt, Vec<OrderByExpr>)> {
+        let mut hidden_columns = vec![LEVEL_COLUMN.to_string()];
+        let (source, source_name) = match stmt.from.as_slice() {
+            [] => {
+                hidden_columns.push(DUMMY_COLUMN.to_string());
+                let subquery = Query {
+                    span: None,
+                    with: None,
+                    body: select(
+                        vec![select_target(
+                            Expr::Literal {
+                                span: None,
+                                value: Literal::UInt64(1),
+                            },
+                            Some(DUMMY_COLUMN),
+                        )],
+                        vec![],
+                        None,
+                    ),
+                    order_by: vec![],
+                    limit: vec![],
+                    offset: None,
+                    fetch: None,
+                    ignore_result: false,
+                };
+                let source_name = Identifier::from_name(stmt.span, SOURCE_TABLE);
+                let source = TableReference::Subquery {
+                    span: stmt.span,
+                    lateral: false,
+                    subquery: Box::new(subquery),
+                    alias: Some(TableAlias {
+                        name: source_name.clone(),
+                        columns: vec![],
+                    }),
+                    pivot: None,
+                    unpivot: None,
+                };
+                (source, source_name)
+            }
+            [source @ TableReference::Table { table, alias, .. }] => {
+                let source_name = alias.as_ref().map_or(table, |alias| &alias.name).clone();
+                (source.clone(), source_name)
+            }
+            [
+                source @ TableReference::Subquery {
+                    lateral: false,
+                    alias,
+                    ..
+                },
+            ] => {
+                let mut source = source.clone();
+                let source_name = match alias {
+                    Some(alias) => alias.name.clone(),
+                    None => {
+                        let source_name = Identifier::from_name(stmt.span, SOURCE_TABLE);
+                        if let TableReference::Subquery { alias, .. } = &mut source {
+                            *alias = Some(TableAlias {
+                                name: source_name.clone(),
+                                columns: vec![],
+                            });
+                        }
+                        source_name
+                    }
+                };
+                (source, source_name)
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "CONNECT BY only supports a single table or subquery in FROM clause",
+                )
+                .set_span(stmt.span));
+            }
+        };
+        let source_table = normalize_identifier(&source_name, &self.name_resolution_ctx).name;
+        let prior_name = Identifier::from_name(None, PRIOR_TABLE);
+        let child_name = Identifier::from_name(None, CHILD_TABLE);
+
+        // The `PRIOR` expressions in the condition, which are also the keys of the rows.
+        let mut prior_collector = PriorCollector::default();
+        connect_by.condition.drive(&mut prior_collector);
+        if let Some(error) = prior_collector.error {
+            return Err(error);
+        }
+        let priors = prior_collector.priors;
+        let track_keys = !priors.is_empty();
+        if track_keys {
+            hidden_columns.push(KEYS_COLUMN.to_string());
+        }
+
+        // The qualifiers for the rows of the anchor, the child rows and the parent rows in the
+        // recursive step.
+        let anchor = ColumnQualifier::new(
+            &self.name_resolution_ctx,
+            &source_table,
+            &source_name,
+            Expr::Cast {
+                span: None,
+                expr: Box::new(Expr::Literal {
+                    span: None,
+                    value: Literal::UInt64(1),
+                }),
+                target_type: TypeName::UInt64,
+                pg_style: false,
+            },
+        );
+        let child = ColumnQualifier::new(
+            &self.name_resolution_ctx,
+            &source_table,
+            &source_name,
+            next_level(column_ref(Some(&prior_name), LEVEL_COLUMN)),
+        );
+        let parent = ColumnQualifier::new(
+            &self.name_resolution_ctx,
+            &source_table,
+            &prior_name,
+            column_ref(Some(&prior_name), LEVEL_COLUMN),
+        );
+        // The key is a tuple of the `PRIOR` expressions, so the NULLs and the composite keys
+        // are compared as values by `contains`.
+        let key = |qualifier: &ColumnQualifier| Expr::Tuple {
+            span: None,
+            exprs: priors
+                .iter()
+                .map(|prior| qualifier.qualify(prior))
+                .collect(),
+        };
+
+        // `CONNECT_BY_ISCYCLE` is 1 if a child of the row is also its ancestor.
+        let is_cycle = if !connect_by.nocycle {
+            None
+        } else if priors.is_empty() {
+            Some(Expr::Literal {
+                span: None,
+                value: Literal::UInt64(0),
+            })
+        } else {
+            let child = ColumnQualifier::new(
+                &self.name_resolution_ctx,
+                &source_table,
+                &child_name,
+                next_level(column_ref(Some(&source_name), LEVEL_COLUMN)),
+            );
+            let mut condition = connect_by.condition.clone();
+            let mut rewriter = ConditionRewriter::new(
+                (0..priors.len())
+                    .map(|i| column_ref(Some(&source_name), &format!("__connect_by_prior_{i}")))
+                    .collect(),
+                &child,
+            );
+            condition.drive_mut(&mut rewriter);
+            let selection = and(
+                condition,
+                function_call("contains", vec![
+                    column_ref(Some(&source_name), KEYS_COLUMN),
+                    key(&child),
+                ]),
+            );
+            let mut child_source = source.clone();
+            match &mut child_source {
+                TableReference::Table { alias, .. } | TableReference::Subquery { alias, .. } => {
+                    *alias = Some(TableAlias {
+                        name: child_name.clone(),
+                        columns: alias
+                            .as_ref()
+                            .map(|alias| alias.columns.clone())
+                            .unwrap_or_default(),
+                    });
+                }
+                _ => unreachable!(),
+            }
+            let subquery = Query {
+                span: None,
+                with: None,
+                body: select(
+                    vec![select_target(
+                        Expr::Literal {
+                            span: None,
+                            value: Literal::UInt64(1),
+                        },
+                        None,
+                    )],
+                    vec![child_source],
+                    Some(selection),
+                ),
+                order_by: vec![],
+                limit: vec![],
+                offset: None,
+                fetch: None,
+                ignore_result: false,
+            };
+            Some(Expr::Case {
+                span: None,
+                operand: None,
+                conditions: vec![Expr::Exists {
+                    span: None,
+                    not: false,
+                    subquery: Box::new(subquery),
+                }],
+                results: vec![Expr::Literal {
+                    span: None,
+                    value: Literal::UInt64(1),
+                }],
+                else_result: Some(Box::new(Expr::Literal {
+                    span: None,
+                    value: Literal::UInt64(0),
+                })),
+            })
+        };
+
+        // Replace the hierarchical pseudo columns and operators of the query.
+        let mut rewriter = HierarchicalRewriter::new(&self.name_resolution_ctx, is_cycle);
+        let mut new_stmt = stmt.clone();
+        new_stmt.connect_by = None;
+        new_stmt.select_list.drive_mut(&mut rewriter);
+        new_stmt.selection.drive_mut(&mut rewriter);
+        new_stmt.group_by.drive_mut(&mut rewriter);
+        new_stmt.having.drive_mut(&mut rewriter);
+        new_stmt.window_list.drive_mut(&mut rewriter);
+        new_stmt.qualify.drive_mut(&mut rewriter);
+        let mut new_order_by = order_by.to_vec();
+        new_order_by.drive_mut(&mut rewriter);
+        if let Some(error) = rewriter.error {
+            return Err(error);
+        }
+
+        let mut anchor_list = vec![
+            SelectTarget::StarColumns {
+                qualified: vec![
+                    Indirection::Identifier(source_name.clone()),
+                    Indirection::Star(None),
+                ],
+                column_filter: None,
+            },
+            select_target(anchor.level.clone(), Some(LEVEL_COLUMN)),
+        ];
+        let mut recursive_list = vec![
+            SelectTarget::StarColumns {
+                qualified: vec![
+                    Indirection::Identifier(source_name.clone()),
+                    Indirection::Star(None),
+                ],
+                column_filter: None,
+            },
+            select_target(child.level.clone(), None),
+        ];
+        for (i, (expr, separator)) in rewriter.paths.iter().enumerate() {
+            let name = format!("__connect_by_path_{i}");
+            anchor_list.push(select_target(
+                function_call("concat", vec![
+                    anchor.qualify(separator),
+                    function_call("coalesce", vec![
+                        cast_to_string(anchor.qualify(expr)),
+                        string_literal(""),
+                    ]),
+                ]),
+                Some(&name),
+            ));
+            recursive_list.push(select_target(
+                function_call("concat", vec![
+                    column_ref(Some(&prior_name), &name),
+                    child.qualify(separator),
+                    function_call("coalesce", vec![
+                        cast_to_string(child.qualify(expr)),
+                        string_literal(""),
+                    ]),
+                ]),
+                None,
+            ));
+            hidden_columns.push(name);
+        }
+        for (i, expr) in rewriter.roots.iter().enumerate() {
+            let name = format!("__connect_by_root_{i}");
+            anchor_list.push(select_target(anchor.qualify(expr), Some(&name)));
+            recursive_list.push(select_target(column_ref(Some(&prior_name), &name), None));
+            hidden_columns.push(name);
+        }
+        for (i, expr) in rewriter.parents.iter().enumerate() {
+            let name = format!("__connect_by_parent_{i}");
+            // The roots have no parent, `if(false, expr, NULL)` keeps the type of the expression.
+            anchor_list.push(select_target(
+                function_call("if", vec![
+                    Expr::Literal {
+                        span: None,
+                        value: Literal::Boolean(false),
+                    },
+                    anchor.qualify(expr),
+                    Expr::Literal {
+                        span: None,
+                        value: Literal::Null,
+                    },
+                ]),
+                Some(&name),
+            ));
+            recursive_list.push(select_target(parent.qualify(expr), None));
+            hidden_columns.push(name);
+        }
+        if track_keys {
+            anchor_list.push(select_target(
+                Expr::Array {
+                    span: None,
+                    exprs: vec![key(&anchor)],
+                },
+                Some(KEYS_COLUMN),
+            ));
+            recursive_list.push(select_target(
+                function_call("array_append", vec![
+                    column_ref(Some(&prior_name), KEYS_COLUMN),
+                    key(&child),
+                ]),
+                None,
+            ));
+        }
+        if rewriter.use_is_cycle {
+            for (i, prior) in priors.iter().enumerate() {
+                let name = format!("__connect_by_prior_{i}");
+                anchor_list.push(select_target(anchor.qualify(prior), Some(&name)));
+                recursive_list.push(select_target(child.qualify(prior), None));
+                hidden_columns.push(name);
+            }
+        }
+
+        let start_with = connect_by
+            .start_with
+            .as_ref()
+            .map(|start_with| anchor.qualify(start_with));
+        let mut recursive_condition = connect_by.condition.clone();
+        let mut condition_rewriter = ConditionRewriter::new(
+            priors.iter().map(|prior| parent.qualify(prior)).collect(),
+            &child,
+        );
+        recursive_condition.drive_mut(&mut condition_rewriter);
+        if track_keys {
+            let is_loop = function_call("contains", vec![
+                column_ref(Some(&prior_name), KEYS_COLUMN),
+                key(&child),
+            ]);
+            let check = if connect_by.nocycle {
+                Expr::UnaryOp {
+                    span: None,
+                    op: UnaryOperator::Not,
+                    expr: Box::new(is_loop),
+                }
+            } else {
+                // Only the connected rows are checked, the condition may not be evaluated
+                // before the check.
+                function_call("connect_by_check_loop", vec![and(
+                    recursive_condition.clone(),
+                    is_loop,
+                )])
+            };
+            recursive_condition = and(recursive_condition, check);
+        }
+
+        let cte_name = Identifier::from_name(None, format!("__connect_by_{}", self.ctes_map.len()));
+        let cte = CTE {
+            span: None,
+            alias: TableAlias {
+                name: cte_name.clone(),
+                columns: vec![],
+            },
+            materialized: false,
+            query: Box::new(Query {
+                span: None,
+                with: None,
+                body: SetExpr::SetOperation(Box::new(SetOperation {
+                    span: None,
+                    op: SetOperator::Union,
+                    all: true,
+                    left: Box::new(select(anchor_list, vec![source.clone()], start_with)),
+                    right: Box::new(select(
+                        recursive_list,
+                        vec![source, TableReference::Table {
+                            span: None,
+                            catalog: None,
+                            database: None,
+                            table: cte_name.clone(),
+                            alias: Some(TableAlias {
+                                name: prior_name,
+                                columns: vec![],
+                            }),
+                            temporal: None,
+                            with_options: None,
+                            pivot: None,
+                            unpivot: None,
+                            sample: None,
+                        }],
+                        Some(recursive_condition),
+                    )),
+                })),
+                order_by: vec![],
+                limit: vec![],
+                offset: None,
+                fetch: None,
+                ignore_result: false,
+            }),
+        };
+        let subquery = Query {
+            span: None,
+            with: Some(With {
+                span: None,
+                recursive: true,
+                ctes: vec![cte],
+            }),
+            body: select(
+                vec![SelectTarget::StarColumns {
+                    qualified: vec![Indirection::Star(None)],
+                    column_filter: None,
+                }],
+                vec![TableReference::Table {
+                    span: None,
+                    catalog: None,
+                    database: None,
+                    table: cte_name,
+                    alias: None,
+                    temporal: None,
+                    with_options: None,
+                    pivot: None,
+                    unpivot: None,
+                    sample: None,
+                }],
+                None,
+            ),
+            order_by: vec![],
+            limit: vec![],
+            offset: None,
+            fetch: None,
+            ignore_result: false,
+        };
+        new_stmt.from = vec![TableReference::Subquery {
+            span: stmt.span,
+            lateral: false,
+            subquery: Box::new(subquery),
+            alias: Some(TableAlias {
+                name: source_name,
+                columns: vec![],
+            }),
+            pivot: None,
+            unpivot: None,
+        }];
+
+        // The hidden columns are excluded from the star columns.
+        for target in new_stmt.select_list.iter_mut() {
+            if let SelectTarget::StarColumns { column_filter, .. } = target {
+                let hidden = hidden_columns
+                    .iter()
+                    .map(|name| Identifier::from_name(None, name));
+                match column_filter {
+                    Some(ColumnFilter::Excludes(excludes)) => excludes.extend(hidden),
+                    Some(ColumnFilter::Lambda(_)) => {}
+                    None => *column_filter = Some(ColumnFilter::Excludes(hidden.collect())),
+                }
+            }
+        }
+
+        Ok((new_stmt, new_order_by))
+    }
+}
+
+fn select_target(expr: Expr, alias: Option<&str>) -> SelectTarget {
+    SelectTarget::AliasedExpr {
+        expr: Box::new(expr),
+        alias: alias.map(|alias| Identifier::from_name(None, alias)),
+    }
+}
+
+fn select(
+    select_list: Vec<SelectTarget>,
+    from: Vec<TableReference>,
+    selection: Option<Expr>,
+) -> SetExpr {
+    SetExpr::Select(Box::new(SelectStmt {
+        span: None,
+        hints: None,
+        distinct: false,
+        top_n: None,
+        select_list,
+        from,
+        selection,
+        connect_by: None,
+        group_by: None,
+        having: None,
+        window_list: None,
+        qualify: None,
+    }))
+}
+
+fn column_ref(table: Option<&Identifier>, name: &str) -> Expr {
+    Expr::ColumnRef {
+        span: None,
+        column: ColumnRef {
+            database: None,
+            table: table.cloned(),
+            column: ColumnID::Name(Identifier::from_name(None, name)),
+        },
+    }
+}
+
+fn function_call(name: &str, args: Vec<Expr>) -> Expr {
+    Expr::FunctionCall {
+        span: None,
+        func: FunctionCall {
+            distinct: false,
+            name: Identifier::from_name(None, name),
+            args,
+            params: vec![],
+            order_by: vec![],
+            filter: None,
+            window: None,
+            lambda: None,
+        },
+    }
+}
+
+fn string_literal(value: &str) -> Expr {
+    Expr::Literal {
+        span: None,
+        value: Literal::String(value.to_string()),
+    }
+}
+
+fn cast_to_string(expr: Expr) -> Expr {
+    Expr::Cast {
+        span: expr.span(),
+        expr: Box::new(expr),
+        target_type: TypeName::String,
+        pg_style: false,
+    }
+}
+
+fn next_level(level: Expr) -> Expr {
+    Expr::BinaryOp {
+        span: None,
+        op: BinaryOperator::Plus,
+        left: Box::new(level),
+        right: Box::new(Expr::Literal {
+            span: None,
+            value: Literal::UInt64(1),
+        }),
+    }
+}
+
+fn and(left: Expr, right: Expr) -> Expr {
+    Expr::BinaryOp {
+        span: None,
+        op: BinaryOperator::And,
+        left: Box::new(left),
+        right: Box::new(right),
+    }
+}
+
+fn is_pseudo_column(expr: &Expr, name: &str) -> bool {
+    matches!(
+        expr,
+        Expr::ColumnRef {
+            column: ColumnRef {
+                database: None,
+                table: None,
+                column: ColumnID::Name(ident),
+            },
+            ..
+        } if !ident.is_quoted() && ident.name.eq_ignore_ascii_case(name)
+    )
+}
+
+// Qualifies the columns of the source table with the given table, and replaces `LEVEL`.
+// The hidden columns are never the columns of the source table, they are left as is.
+#[derive(VisitorMut)]
+#[visitor(Expr(enter))]
+struct ColumnQualifier<'a> {
+    name_resolution_ctx: &'a NameResolutionContext,
+    source: &'a str,
+    table: Identifier,
+    level: Expr,
+}
+
+impl<'a> ColumnQualifier<'a> {
+    fn new(
+        name_resolution_ctx: &'a NameResolutionContext,
+        source: &'a str,
+        table: &Identifier,
+        level: Expr,
+    ) -> Self {
+        Self {
+            name_resolution_ctx,
+            source,
+            table: table.clone(),
+            level,
+        }
+    }
+
+    fn qualify(&self, expr: &Expr) -> Expr {
+        let mut qualifier = ColumnQualifier::new(
+            self.name_resolution_ctx,
+            self.source,
+            &self.table,
+            self.level.clone(),
+        );
+        let mut expr = expr.clone();
+        expr.drive_mut(&mut qualifier);
+        expr
+    }
+
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        if is_pseudo_column(expr, "level") {
+            *expr = self.level.clone();
+            return;
+        }
+        if let Expr::ColumnRef {
+            column:
+                ColumnRef {
+                    database: None,
+                    table,
+                    column: ColumnID::Name(column),
+                },
+            ..
+        } = expr
+        {
+            if column.name.starts_with("__connect_by_") {
+                return;
+            }
+            let is_source = table.as_ref().map_or(true, |table| {
+                normalize_identifier(table, self.name_resolution_ctx).name == self.source
+            });
+            if is_source {
+                *table = Some(self.table.clone());
+            }
+        }
+    }
+}
+
+// Collects the `PRIOR` expressions of the `CONNECT BY` condition.
+#[derive(Visitor, Default)]
+#[visitor(Expr(enter))]
+struct PriorCollector {
+    priors: Vec<Expr>,
+    error: Option<ErrorCode>,
+}
+
+impl PriorCollector {
+    fn enter_expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::UnaryOp {
+                op: UnaryOperator::Prior,
+                expr: inner,
+                ..
+            } => self.priors.push((**inner).clone()),
+            Expr::UnaryOp {
+                span,
+                op: UnaryOperator::ConnectByRoot,
+                ..
+            } => {
+                self.error = Some(
+                    ErrorCode::SemanticError(
+                        "CONNECT_BY_ROOT can not be used in the CONNECT BY condition",
+                    )
+                    .set_span(*span),
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+// Replaces the `PRIOR` expressions of the condition in order, and qualifies the other
+// columns as the columns of the child rows.
+#[derive(VisitorMut)]
+#[visitor(Expr(enter))]
+struct ConditionRewriter<'a, 'b> {
+    priors: Vec<Expr>,
+    next_prior: usize,
+    child: &'b ColumnQualifier<'a>,
+}
+
+impl<'a, 'b> ConditionRewriter<'a, 'b> {
+    fn new(priors: Vec<Expr>, child: &'b ColumnQualifier<'a>) -> Self {
+        Self {
+            priors,
+            next_prior: 0,
+            child,
+        }
+    }
+
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        if let Expr::UnaryOp {
+            op: UnaryOperator::Prior,
+            ..
+        } = expr
+        {
+            *expr = self.priors[self.next_prior].clone();
+            self.next_prior += 1;
+        } else if matches!(expr, Expr::ColumnRef { .. }) {
+            *expr = self.child.qualify(expr);
+        }
+    }
+}
+
+// Replaces `LEVEL`, `CONNECT_BY_ISCYCLE`, `SYS_CONNECT_BY_PATH(expr, separator)`,
+// `CONNECT_BY_ROOT expr` and `PRIOR expr` in the query by the hidden columns.
+#[derive(VisitorMut)]
+#[visitor(Expr(enter))]
+struct HierarchicalRewriter<'a> {
+    name_resolution_ctx: &'a NameResolutionContext,
+    is_cycle: Option<Expr>,
+    use_is_cycle: bool,
+    paths: Vec<(Expr, Expr)>,
+    roots: Vec<Expr>,
+    parents: Vec<Expr>,
+    error: Option<ErrorCode>,
+}
+
+impl<'a> HierarchicalRewriter<'a> {
+    fn new(name_resolution_ctx: &'a NameResolutionContext, is_cycle: Option<Expr>) -> Self {
+        Self {
+            name_resolution_ctx,
+            is_cycle,
+            use_is_cycle: false,
+            paths: vec![],
+            roots: vec![],
+            parents: vec![],
+            error: None,
+        }
+    }
+
+    fn hidden_column(span: Span, name: String) -> Expr {
+        Expr::ColumnRef {
+            span,
+            column: ColumnRef {
+                database: None,
+                table: None,
+                column: ColumnID::Name(Identifier::from_name(span, name)),
+            },
+        }
+    }
+
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        let span = expr.span();
+        if is_pseudo_column(expr, "level") {
+            *expr = Self::hidden_column(span, LEVEL_COLUMN.to_string());
+            return;
+        }
+        if is_pseudo_column(expr, "connect_by_iscycle") {
+            match &self.is_cycle {
+                Some(is_cycle) => {
+                    self.use_is_cycle = true;
+                    *expr = is_cycle.clone();
+                }
+                None => {
+                    self.error = Some(
+                        ErrorCode::SemanticError(
+                            "CONNECT_BY_ISCYCLE can only be used with CONNECT BY NOCYCLE",
+                        )
+                        .set_span(span),
+                    );
+                }
+            }
+            return;
+        }
+        match expr {
+            Expr::UnaryOp {
+                op: UnaryOperator::Prior,
+                expr: inner,
+                ..
+            } => {
+                let name = format!("__connect_by_parent_{}", self.parents.len());
+                self.parents.push((**inner).clone());
+                *expr = Self::hidden_column(span, name);
+            }
+            Expr::UnaryOp {
+                op: UnaryOperator::ConnectByRoot,
+                expr: inner,
+                ..
+            } => {
+                let name = format!("__connect_by_root_{}", self.roots.len());
+                self.roots.push((**inner).clone());
+                *expr = Self::hidden_column(span, name);
+            }
+            Expr::FunctionCall { func, .. }
+                if normalize_identifier(&func.name, self.name_resolution_ctx).name
+                    == "sys_connect_by_path" =>
+            {
+                if func.args.len() != 2 || func.window.is_some() || func.distinct {
+                    self.error = Some(
+                        ErrorCode::SemanticError(
+                            "SYS_CONNECT_BY_PATH requires an expression and a separator",
+                        )
+                        .set_span(span),
+                    );
+                    return;
+                }
+                let name = format!("__connect_by_path_{}", self.paths.len());
+                self.paths
+                    .push((func.args[0].clone(), func.args[1].clone()));
+                *expr = Self::hidden_column(span, name);
+            }
+            _ => {}
+        }
+    }
+}
@@ -20,10 +20,13 @@ use databend_common_ast::ast::Query;
 use databend_common_ast::ast::SetExpr;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::F64;
 
 use crate::binder::Binder;
 use crate::optimizer::SExpr;
 use crate::plans::Limit;
+use crate::plans::RelOperator;
+use crate::plans::SortItem;
 
 impl Binder {
     pub(super) fn bind_query_limit(
@@ -32,21 +35,64 @@ impl Binder {
         s_expr: SExpr,
         limit: Option<usize>,
         offset: usize,
-    ) -> SExpr {
-        if limit.is_none() && query.offset.is_none() {
-            return s_expr;
+    ) -> Result<SExpr> {
+        if limit.is_none() && query.offset.is_none() && query.fetch.is_none() {
+            return Ok(s_expr);
         }
 
+        let (with_ties, percent) = match &query.fetch {
+            Some(fetch) => {
+                let with_ties = if fetch.with_ties {
+                    Self::find_order_by_items(&s_expr)
+                        .filter(|_| !query.order_by.is_empty())
+                        .ok_or_else(|| {
+                            ErrorCode::SemanticError("FETCH FIRST ... WITH TIES requires ORDER BY")
+                        })?
+                } else {
+                    vec![]
+                };
+                let percent = if fetch.percent {
+                    Some(Self::bind_fetch_percent(&query.limit[0])?)
+                } else {
+                    None
+                };
+                (with_ties, percent)
+            }
+            None => (vec![], None),
+        };
+
         let limit_plan = Limit {
             before_exchange: false,
             limit,
             offset,
+            with_ties,
+            percent,
         };
-        SExpr::create_unary(Arc::new(limit_plan.into()), Arc::new(s_expr))
+        Ok(SExpr::create_unary(
+            Arc::new(limit_plan.into()),
+            Arc::new(s_expr),
+        ))
+    }
+
+    /// Finds the items of the `ORDER BY` sort under the limit, the rows which tie
+    /// on these items are compared by `FETCH FIRST ... WITH TIES`.
+    fn find_order_by_items(s_expr: &SExpr) -> Option<Vec<SortItem>> {
+        match s_expr.plan() {
+            RelOperator::Sort(sort) => Some(sort.items.clone()),
+            RelOperator::EvalScalar(_) | RelOperator::Udf(_) | RelOperator::AsyncFunction(_) => {
+                Self::find_order_by_items(s_expr.child(0).ok()?)
+            }
+            _ => None,
+        }
     }
 
     pub(crate) fn extract_limit_and_offset(&self, query: &Query) -> Result<(Option<usize>, usize)> {
-        let (mut limit, offset) = if !query.limit.is_empty() {
+        let (mut limit, offset) = if let Some(fetch) = &query.fetch
+            && fetch.percent
+        {
+            // The number of rows is known after reading all the input.
+            Self::analyze_limit(None, &query.offset)?
+        } else if !query.limit.is_empty() {
             if query.limit.len() == 1 {
                 Self::analyze_limit(Some(&query.limit[0]), &query.offset)?
             } else {
@@ -95,6 +141,20 @@ impl Binder {
         Ok((limit_cnt, offset_cnt))
     }
 
+    fn bind_fetch_percent(expr: &Expr) -> Result<F64> {
+        let percent = match expr {
+            Expr::Literal { value, .. } => value.as_double().ok(),
+            _ => None,
+        };
+        match percent {
+            Some(percent) if (0.0..=100.0).contains(&percent) => Ok(F64::from(percent)),
+            _ => Err(ErrorCode::SemanticError(
+                "Invalid FETCH PERCENT expression, it must be a number between 0 and 100",
+            )
+            .set_span(expr.span())),
+        }
+    }
+
     /// So far, we only support integer literal as limit argument.
     /// So we will try to extract the integer value from the AST directly.
     /// In the future it's possible to treat the argument as an expression.
@@ -63,6 +63,17 @@ impl Binder {
         order_by: &[OrderByExpr],
         limit: Option<usize>,
     ) -> Result<(SExpr, BindContext)> {
+        if let Some(connect_by) = &stmt.connect_by {
+            // Hierarchical queries are bound as recursive CTEs.
+            let (stmt, order_by) = self.rewrite_connect_by(stmt, connect_by, order_by)?;
+            // The rewritten query checks the loops with an internal function.
+            let allow_internal_functions = self.name_resolution_ctx.allow_internal_functions;
+            self.name_resolution_ctx.allow_internal_functions = true;
+            let result = self.bind_select(bind_context, &stmt, &order_by, limit);
+            self.name_resolution_ctx.allow_internal_functions = allow_internal_functions;
+            return result;
+        }
+
         if let Some(hints) = &stmt.hints {
             if let Some(e) = self.opt_hints_set_var(bind_context, hints).err() {
                 warn!(
@@ -313,6 +324,8 @@ impl<'a> SelectRewriter<'a> {
                     name,
                     args,
                     params: vec![],
+                    order_by: vec![],
+                    filter: None,
                     window: None,
                     lambda: None,
                 },
@@ -55,6 +55,7 @@ impl Binder {
                 named_params,
                 alias,
                 sample,
+                lateral,
                 ..
             } => self.bind_table_function(
                 bind_context,
@@ -64,6 +65,7 @@ impl Binder {
                 named_params,
                 alias,
                 sample,
+                *lateral,
             ),
             TableReference::Subquery {
                 span: _,
@@ -80,6 +82,12 @@ impl Binder {
                 alias,
             } => self.bind_location(bind_context, location, options, alias),
             TableReference::Join { join, .. } => self.bind_join(bind_context, join),
+            TableReference::MatchRecognize {
+                table,
+                match_recognize,
+                alias,
+                ..
+            } => self.bind_match_recognize(bind_context, table, match_recognize, alias),
         }
     }
 }
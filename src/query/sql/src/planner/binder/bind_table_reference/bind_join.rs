@@ -78,7 +78,17 @@ impl Binder {
             .expression_scan_context
             .add_hash_join_build_cache(cache_column_bindings, cache_column_indexes);
 
-        if join.right.is_lateral_table_function() {
+        if matches!(join.op, JoinOperator::Asof { .. })
+            && (join.right.is_lateral_table_function() || join.right.is_lateral_subquery())
+        {
+            return Err(ErrorCode::SemanticError(
+                "ASOF JOIN does not support lateral subqueries or table functions".to_string(),
+            ));
+        }
+
+        let is_lateral_table_udf =
+            join.right.is_lateral_table_function() && self.is_table_udf(&join.right)?;
+        if join.right.is_lateral_table_function() && !is_lateral_table_udf {
             let (result_expr, bind_context) = self.bind_lateral_table_function(
                 &mut left_context,
                 left_child.clone(),
@@ -86,11 +96,12 @@ impl Binder {
             )?;
             return Ok((result_expr, bind_context));
         }
-        let (right_child, right_context) = if join.right.is_lateral_subquery() {
-            self.bind_table_reference(&mut left_context, &join.right)?
-        } else {
-            self.bind_table_reference(bind_context, &join.right)?
-        };
+        let (right_child, right_context) =
+            if join.right.is_lateral_subquery() || is_lateral_table_udf {
+                self.bind_table_reference(&mut left_context, &join.right)?
+            } else {
+                self.bind_table_reference(bind_context, &join.right)?
+            };
 
         let right_column_bindings = right_context.columns.clone();
 
@@ -103,7 +114,7 @@ impl Binder {
             &join.condition,
         )?;
 
-        let join_conditions = self.generate_join_condition(
+        let mut join_conditions = self.generate_join_condition(
             &mut bind_context,
             &join.op,
             &join.condition,
@@ -111,6 +122,20 @@ impl Binder {
             &right_column_bindings,
         )?;
 
+        if let JoinOperator::Asof {
+            match_condition: Some(match_condition),
+            ..
+        } = &join.op
+        {
+            self.bind_asof_match_condition(
+                &bind_context,
+                match_condition,
+                &left_column_bindings,
+                &right_column_bindings,
+                &mut join_conditions,
+            )?;
+        }
+
         let build_side_cache_info = self.expression_scan_context.generate_cache_info(cache_idx);
 
         let join_type = join_type(&join.op);
@@ -210,6 +235,91 @@ impl Binder {
         })
     }
 
+    // Bind the `MATCH_CONDITION` of an ASOF join, it must be a single comparison
+    // between an expression of the left table and an expression of the right table.
+    fn bind_asof_match_condition(
+        &self,
+        bind_context: &BindContext,
+        match_condition: &Expr,
+        left_column_bindings: &[ColumnBinding],
+        right_column_bindings: &[ColumnBinding],
+        join_conditions: &mut JoinConditions,
+    ) -> Result<()> {
+        if !join_conditions.non_equi_conditions.is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "ASOF JOIN only supports equality conditions in the ON clause, use MATCH_CONDITION for the inequality condition".to_string(),
+            )
+            .set_span(join_conditions.non_equi_conditions[0].span()));
+        }
+
+        let mut join_context = bind_context.replace();
+        wrap_nullable_for_column(
+            &JoinOperator::Inner,
+            left_column_bindings,
+            right_column_bindings,
+            &mut join_context,
+        );
+        let mut scalar_binder = ScalarBinder::new(
+            &mut join_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (predicate, _) = scalar_binder.bind(match_condition)?;
+
+        let left_columns: ColumnSet = left_column_bindings.iter().map(|c| c.index).collect();
+        let right_columns: ColumnSet = right_column_bindings.iter().map(|c| c.index).collect();
+        let is_valid = match &predicate {
+            ScalarExpr::FunctionCall(func)
+                if func.arguments.len() == 2
+                    && matches!(func.func_name.as_str(), "gt" | "lt" | "gte" | "lte") =>
+            {
+                let arg0 = func.arguments[0].used_columns();
+                let arg1 = func.arguments[1].used_columns();
+                !arg0.is_empty()
+                    && !arg1.is_empty()
+                    && ((arg0.is_subset(&left_columns) && arg1.is_subset(&right_columns))
+                        || (arg0.is_subset(&right_columns) && arg1.is_subset(&left_columns)))
+            }
+            _ => false,
+        };
+        if !is_valid {
+            return Err(ErrorCode::SemanticError(
+                "MATCH_CONDITION of ASOF JOIN must be a comparison (>, >=, <, <=) between a column of the left table and a column of the right table".to_string(),
+            )
+            .set_span(match_condition.span()));
+        }
+        self.check_asof_match_condition_expr(&predicate)?;
+
+        join_conditions.non_equi_conditions.push(predicate);
+        Ok(())
+    }
+
+    fn check_asof_match_condition_expr(&self, scalar: &ScalarExpr) -> Result<()> {
+        let f = |scalar: &ScalarExpr| {
+            matches!(
+                scalar,
+                ScalarExpr::AggregateFunction(_)
+                    | ScalarExpr::WindowFunction(_)
+                    | ScalarExpr::AsyncFunctionCall(_)
+                    | ScalarExpr::SubqueryExpr(_)
+            )
+        };
+        let mut finder = Finder::new(&f);
+        finder.visit(scalar)?;
+        if !finder.scalars().is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "MATCH_CONDITION can't contain aggregate, window functions or subqueries"
+                    .to_string(),
+            )
+            .set_span(scalar.span()));
+        }
+        Ok(())
+    }
+
     pub(crate) fn bind_join_with_type(
         &mut self,
         mut join_type: JoinType,
@@ -337,6 +447,7 @@ impl Binder {
                 JoinPredicate::ALL(_) => match join_type {
                     JoinType::Cross
                     | JoinType::Inner
+                    | JoinType::Asof
                     | JoinType::LeftSemi
                     | JoinType::LeftAnti
                     | JoinType::RightSemi
@@ -345,7 +456,10 @@ impl Binder {
                         left_push_down.push(predicate.clone());
                         right_push_down.push(predicate.clone());
                     }
-                    JoinType::Left | JoinType::LeftSingle | JoinType::RightMark => {
+                    JoinType::Left
+                    | JoinType::LeftSingle
+                    | JoinType::LeftAsof
+                    | JoinType::RightMark => {
                         need_push_down = true;
                         right_push_down.push(predicate.clone());
                     }
@@ -422,6 +536,14 @@ impl Binder {
                     "cross join should not contain join conditions".to_string(),
                 ));
             }
+            JoinOperator::Asof {
+                match_condition: None,
+                ..
+            } => {
+                return Err(ErrorCode::SemanticError(
+                    "ASOF JOIN should contain a MATCH_CONDITION".to_string(),
+                ));
+            }
             _ => (),
         };
 
@@ -437,7 +559,10 @@ fn wrap_nullable_for_column(
     bind_context: &mut BindContext,
 ) {
     match join_type {
-        JoinOperator::LeftOuter => {
+        JoinOperator::LeftOuter
+        | JoinOperator::Asof {
+            left_outer: true, ..
+        } => {
             for column in left_column_bindings {
                 bind_context.add_column_binding(column.clone());
             }
@@ -847,7 +972,10 @@ impl<'a> JoinConditionResolver<'a> {
         let predicate_used_columns = predicate.used_columns();
         let (left_columns, right_columns) = self.left_right_columns()?;
         match self.join_op {
-            JoinOperator::LeftOuter => {
+            JoinOperator::LeftOuter
+            | JoinOperator::Asof {
+                left_outer: true, ..
+            } => {
                 if predicate_used_columns.is_subset(&right_columns) {
                     other_join_conditions.push(predicate);
                     return Ok(true);
@@ -859,7 +987,10 @@ impl<'a> JoinConditionResolver<'a> {
                     return Ok(true);
                 }
             }
-            JoinOperator::Inner => {
+            JoinOperator::Inner
+            | JoinOperator::Asof {
+                left_outer: false, ..
+            } => {
                 if predicate_used_columns.is_subset(&left_columns)
                     || predicate_used_columns.is_subset(&right_columns)
                 {
@@ -915,6 +1046,12 @@ fn join_type(join_type: &JoinOperator) -> JoinType {
         JoinOperator::RightSemi => JoinType::RightSemi,
         JoinOperator::LeftAnti => JoinType::LeftAnti,
         JoinOperator::RightAnti => JoinType::RightAnti,
+        JoinOperator::Asof {
+            left_outer: false, ..
+        } => JoinType::Asof,
+        JoinOperator::Asof {
+            left_outer: true, ..
+        } => JoinType::LeftAsof,
     }
 }
 
@@ -0,0 +1,814 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::AfterMatchSkip as AstAfterMatchSkip;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::MatchRecognize as AstMatchRecognize;
+use databend_common_ast::ast::RepetitionQuantifier;
+use databend_common_ast::ast::RowPattern;
+use databend_common_ast::ast::RowsPerMatch;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::Span;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut;
+
+use crate::binder::ColumnBindingBuilder;
+use crate::binder::WindowOrderByInfo;
+use crate::optimizer::SExpr;
+use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::NameResolutionContext;
+use crate::plans::AfterMatchSkip;
+use crate::plans::AggregateFunction;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::MatchFunction;
+use crate::plans::MatchFunctionItem;
+use crate::plans::MatchNavigation;
+use crate::plans::MatchPattern;
+use crate::plans::MatchRecognize;
+use crate::plans::PatternVariable;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::plans::Sort;
+use crate::plans::SortItem;
+use crate::BindContext;
+use crate::Binder;
+use crate::IndexType;
+use crate::ScalarBinder;
+use crate::Visibility;
+
+impl Binder {
+    /// Bind `<table> MATCH_RECOGNIZE (...)`.
+    ///
+    /// The plan is `MatchRecognize` over a `Sort` by the partition and order by items,
+    /// which is turned into a `WindowPartition` to shuffle and sort the partitions.
+    /// The rows of the partition being matched are spilled by `TransformMatchRecognize`.
+    pub(crate) fn bind_match_recognize(
+        &mut self,
+        bind_context: &mut BindContext,
+        table: &TableReference,
+        match_recognize: &AstMatchRecognize,
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let (child, mut input_context) = self.bind_table_reference(bind_context, table)?;
+
+        // 1. Resolve the pattern variables, the defined ones come first.
+        let mut variables: Vec<String> = vec![];
+        for define in match_recognize.define.iter() {
+            let name = self.normalize_object_identifier(&define.name);
+            if variables.contains(&name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "pattern variable {name} is defined more than once"
+                ))
+                .set_span(define.name.span));
+            }
+            variables.push(name);
+        }
+        let mut symbols = vec![];
+        collect_pattern_symbols(&match_recognize.pattern, &mut symbols);
+        for symbol in symbols.iter() {
+            let name = self.normalize_object_identifier(symbol);
+            if !variables.contains(&name) {
+                variables.push(name);
+            }
+        }
+        for define in match_recognize.define.iter() {
+            let name = self.normalize_object_identifier(&define.name);
+            if !symbols
+                .iter()
+                .any(|symbol| self.normalize_object_identifier(symbol) == name)
+            {
+                return Err(ErrorCode::SemanticError(format!(
+                    "pattern variable {name} is defined but not used in PATTERN"
+                ))
+                .set_span(define.name.span));
+            }
+        }
+
+        let pattern = self.bind_row_pattern(&match_recognize.pattern, &variables);
+        let after_match_skip = match &match_recognize.after_match_skip {
+            AstAfterMatchSkip::PastLastRow => AfterMatchSkip::PastLastRow,
+            AstAfterMatchSkip::ToNextRow => AfterMatchSkip::ToNextRow,
+            AstAfterMatchSkip::ToFirst(ident) => {
+                AfterMatchSkip::ToFirst(self.resolve_pattern_variable(ident, &variables)?)
+            }
+            AstAfterMatchSkip::ToLast(ident) => {
+                AfterMatchSkip::ToLast(self.resolve_pattern_variable(ident, &variables)?)
+            }
+        };
+
+        // The expressions evaluated before sorting, such as the non-column partition keys.
+        let mut scalar_items: Vec<ScalarItem> = vec![];
+
+        // 2. Bind the partition and order by items.
+        let mut partition_by = Vec::with_capacity(match_recognize.partition_by.len());
+        for expr in match_recognize.partition_by.iter() {
+            let (scalar, data_type) = self.bind_match_recognize_expr(&mut input_context, expr)?;
+            let index = self.match_recognize_column(
+                scalar.clone(),
+                data_type,
+                expr.to_string(),
+                &mut scalar_items,
+            );
+            partition_by.push(ScalarItem { scalar, index });
+        }
+        let mut order_by = Vec::with_capacity(match_recognize.order_by.len());
+        for order in match_recognize.order_by.iter() {
+            let (scalar, data_type) =
+                self.bind_match_recognize_expr(&mut input_context, &order.expr)?;
+            let index = self.match_recognize_column(
+                scalar.clone(),
+                data_type,
+                order.expr.to_string(),
+                &mut scalar_items,
+            );
+            order_by.push(WindowOrderByInfo {
+                order_by_item: ScalarItem { scalar, index },
+                asc: order.asc,
+                nulls_first: order.nulls_first,
+            });
+        }
+
+        // 3. Bind the definitions of the pattern variables. `PREV(expr, n)` and `NEXT(expr, n)`
+        // are replaced by the navigation columns.
+        let mut navigations = vec![];
+        let mut pattern_variables = variables
+            .iter()
+            .map(|name| PatternVariable {
+                name: name.clone(),
+                condition: None,
+            })
+            .collect::<Vec<_>>();
+        for (variable, define) in match_recognize.define.iter().enumerate() {
+            let mut condition = define.condition.clone();
+            let mut variable_rewriter =
+                PatternVariableRewriter::new(&variables, &self.name_resolution_ctx);
+            condition.drive_mut(&mut variable_rewriter);
+            if let Some(other) = variable_rewriter
+                .variables
+                .iter()
+                .find(|other| **other != variable)
+            {
+                return Err(ErrorCode::SemanticError(format!(
+                    "pattern variable {} cannot be referenced in the definition of {}",
+                    variables[*other], variables[variable]
+                ))
+                .set_span(define.condition.span()));
+            }
+
+            let mut navigation_rewriter = NavigationRewriter::new(navigations.len());
+            condition.drive_mut(&mut navigation_rewriter);
+            if let Some(error) = navigation_rewriter.error {
+                return Err(error);
+            }
+
+            let mut condition_context = input_context.clone();
+            for (name, arg, is_prev, offset) in navigation_rewriter.navigations {
+                let (scalar, data_type) =
+                    self.bind_match_recognize_expr(&mut input_context, &arg)?;
+                let arg = self.match_recognize_column(
+                    scalar,
+                    data_type.clone(),
+                    arg.to_string(),
+                    &mut scalar_items,
+                );
+                let data_type = data_type.wrap_nullable();
+                let index =
+                    self.metadata
+                        .write()
+                        .add_derived_column(name.clone(), data_type.clone(), None);
+                condition_context.add_column_binding(
+                    ColumnBindingBuilder::new(
+                        name,
+                        index,
+                        Box::new(data_type),
+                        Visibility::Visible,
+                    )
+                    .build(),
+                );
+                navigations.push(MatchNavigation {
+                    index,
+                    arg,
+                    is_prev,
+                    offset,
+                });
+            }
+
+            let (scalar, data_type) =
+                self.bind_match_recognize_expr(&mut condition_context, &condition)?;
+            if data_type.remove_nullable() != DataType::Boolean {
+                return Err(ErrorCode::SemanticError(format!(
+                    "the definition of pattern variable {} must be a boolean expression, but got {}",
+                    variables[variable], data_type
+                ))
+                .set_span(define.condition.span()));
+            }
+            pattern_variables[variable].condition = Some(scalar);
+        }
+
+        // 4. Bind the measures. The match functions and the columns referenced by the measures
+        // are replaced by the columns of the match functions.
+        let mut functions = vec![];
+        let mut measure_context = BindContext::new();
+        let mut measures = Vec::with_capacity(match_recognize.measures.len());
+        let mut measure_columns = Vec::with_capacity(match_recognize.measures.len());
+        for measure in match_recognize.measures.iter() {
+            let mut expr = measure.expr.clone();
+            let mut function_rewriter = MatchFunctionRewriter::new(functions.len());
+            expr.drive_mut(&mut function_rewriter);
+            if let Some(error) = function_rewriter.error {
+                return Err(error);
+            }
+
+            for (name, func) in function_rewriter.functions {
+                let func = self.bind_match_function(
+                    &mut input_context,
+                    &variables,
+                    func,
+                    &mut scalar_items,
+                )?;
+                let data_type = func.return_type();
+                let index =
+                    self.metadata
+                        .write()
+                        .add_derived_column(name.clone(), data_type.clone(), None);
+                measure_context.add_column_binding(
+                    ColumnBindingBuilder::new(
+                        name,
+                        index,
+                        Box::new(data_type),
+                        Visibility::Visible,
+                    )
+                    .build(),
+                );
+                functions.push(MatchFunctionItem { index, func });
+            }
+
+            let (scalar, data_type) =
+                self.bind_match_recognize_expr(&mut measure_context, &expr)?;
+            let name = self.normalize_object_identifier(&measure.alias);
+            let index =
+                self.metadata
+                    .write()
+                    .add_derived_column(name.clone(), data_type.clone(), None);
+            measures.push(ScalarItem { scalar, index });
+            measure_columns.push(
+                ColumnBindingBuilder::new(name, index, Box::new(data_type), Visibility::Visible)
+                    .build(),
+            );
+        }
+
+        // 5. Build the output columns.
+        let all_rows_per_match = match_recognize.rows_per_match == RowsPerMatch::AllRows;
+        let mut output_context = input_context.clone();
+        if !all_rows_per_match {
+            output_context.columns = partition_by
+                .iter()
+                .zip(match_recognize.partition_by.iter())
+                .map(|(item, expr)| {
+                    input_context
+                        .columns
+                        .iter()
+                        .find(|column| column.index == item.index)
+                        .cloned()
+                        .unwrap_or_else(|| {
+                            let data_type = self.metadata.read().column(item.index).data_type();
+                            ColumnBindingBuilder::new(
+                                expr.to_string(),
+                                item.index,
+                                Box::new(data_type),
+                                Visibility::Visible,
+                            )
+                            .build()
+                        })
+                })
+                .collect();
+        }
+        output_context.columns.extend(measure_columns);
+        if let Some(alias) = alias {
+            output_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+
+        // 6. Build the plan.
+        let child = if !scalar_items.is_empty() {
+            let eval_scalar_plan = EvalScalar {
+                items: scalar_items,
+            };
+            SExpr::create_unary(Arc::new(eval_scalar_plan.into()), Arc::new(child))
+        } else {
+            child
+        };
+
+        let default_nulls_first = self.ctx.get_settings().get_nulls_first();
+        let mut sort_items: Vec<SortItem> = vec![];
+        for part in partition_by.iter() {
+            sort_items.push(SortItem {
+                index: part.index,
+                asc: true,
+                nulls_first: default_nulls_first(true),
+            });
+        }
+        for order in order_by.iter() {
+            let asc = order.asc.unwrap_or(true);
+            sort_items.push(SortItem {
+                index: order.order_by_item.index,
+                asc,
+                nulls_first: order
+                    .nulls_first
+                    .unwrap_or_else(|| default_nulls_first(asc)),
+            });
+        }
+        let child = if !sort_items.is_empty() {
+            let sort_plan = Sort {
+                items: sort_items,
+                limit: None,
+                limit_with_ties: false,
+                after_exchange: None,
+                pre_projection: None,
+                window_partition: partition_by.clone(),
+            };
+            SExpr::create_unary(Arc::new(sort_plan.into()), Arc::new(child))
+        } else {
+            child
+        };
+
+        let match_recognize_plan = MatchRecognize {
+            partition_by,
+            order_by,
+            navigations,
+            variables: pattern_variables,
+            pattern,
+            functions,
+            measures,
+            all_rows_per_match,
+            after_match_skip,
+        };
+        Ok((
+            SExpr::create_unary(Arc::new(match_recognize_plan.into()), Arc::new(child)),
+            output_context,
+        ))
+    }
+
+    fn bind_row_pattern(&self, pattern: &RowPattern, variables: &[String]) -> MatchPattern {
+        match pattern {
+            RowPattern::Symbol(ident) => {
+                let name = self.normalize_object_identifier(ident);
+                MatchPattern::Variable(variables.iter().position(|v| *v == name).unwrap())
+            }
+            RowPattern::Concat(patterns) => MatchPattern::Concat(
+                patterns
+                    .iter()
+                    .map(|pattern| self.bind_row_pattern(pattern, variables))
+                    .collect(),
+            ),
+            RowPattern::Alternation(patterns) => MatchPattern::Alternation(
+                patterns
+                    .iter()
+                    .map(|pattern| self.bind_row_pattern(pattern, variables))
+                    .collect(),
+            ),
+            RowPattern::Repetition {
+                pattern,
+                quantifier,
+            } => {
+                let (min, max) = match quantifier {
+                    RepetitionQuantifier::ZeroOrMore => (0, None),
+                    RepetitionQuantifier::OneOrMore => (1, None),
+                    RepetitionQuantifier::AtMostOne => (0, Some(1)),
+                    RepetitionQuantifier::Range { min, max } => (*min, *max),
+                };
+                MatchPattern::Repetition {
+                    pattern: Box::new(self.bind_row_pattern(pattern, variables)),
+                    min,
+                    max,
+                }
+            }
+            RowPattern::Group(pattern) => self.bind_row_pattern(pattern, variables),
+        }
+    }
+
+    fn resolve_pattern_variable(&self, ident: &Identifier, variables: &[String]) -> Result<usize> {
+        let name = self.normalize_object_identifier(ident);
+        variables.iter().position(|v| *v == name).ok_or_else(|| {
+            ErrorCode::SemanticError(format!("pattern variable {name} is not used in PATTERN"))
+                .set_span(ident.span)
+        })
+    }
+
+    fn bind_match_recognize_expr(
+        &mut self,
+        bind_context: &mut BindContext,
+        expr: &Expr,
+    ) -> Result<(ScalarExpr, DataType)> {
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (scalar, data_type) = scalar_binder.bind(expr)?;
+        if !self.check_allowed_scalar_expr(&scalar)? {
+            return Err(ErrorCode::SemanticError(format!(
+                "MATCH_RECOGNIZE does not support aggregate functions, window functions, subqueries or udf in expression {expr}"
+            ))
+            .set_span(expr.span()));
+        }
+        Ok((scalar, data_type))
+    }
+
+    // Returns the column of the scalar, the non-column scalar is evaluated before sorting.
+    fn match_recognize_column(
+        &mut self,
+        scalar: ScalarExpr,
+        data_type: DataType,
+        name: String,
+        scalar_items: &mut Vec<ScalarItem>,
+    ) -> IndexType {
+        if let ScalarExpr::BoundColumnRef(col) = &scalar {
+            return col.column.index;
+        }
+        let index = self
+            .metadata
+            .write()
+            .add_derived_column(name, data_type, Some(scalar.clone()));
+        scalar_items.push(ScalarItem { scalar, index });
+        index
+    }
+
+    fn bind_match_function(
+        &mut self,
+        input_context: &mut BindContext,
+        variables: &[String],
+        func: MatchFunctionExpr,
+        scalar_items: &mut Vec<ScalarItem>,
+    ) -> Result<MatchFunction> {
+        let (span, mut args) = match func {
+            MatchFunctionExpr::MatchNumber => return Ok(MatchFunction::MatchNumber),
+            MatchFunctionExpr::Classifier => return Ok(MatchFunction::Classifier),
+            MatchFunctionExpr::First { span, ref arg }
+            | MatchFunctionExpr::Last { span, ref arg } => (span, vec![arg.clone()]),
+            MatchFunctionExpr::Aggregate { span, ref args, .. } => (span, args.clone()),
+        };
+
+        // The rows of the function are restricted to the ones mapped to the pattern variable
+        // which qualifies the columns of the arguments.
+        let mut variable_rewriter =
+            PatternVariableRewriter::new(variables, &self.name_resolution_ctx);
+        for arg in args.iter_mut() {
+            arg.drive_mut(&mut variable_rewriter);
+        }
+        let mut referenced = variable_rewriter.variables;
+        referenced.sort();
+        referenced.dedup();
+        if referenced.len() > 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "the arguments of a match function can only reference one pattern variable, but got {}",
+                referenced
+                    .iter()
+                    .map(|variable| variables[*variable].as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+            .set_span(span));
+        }
+        let variable = referenced.first().copied();
+
+        let mut bound_args = Vec::with_capacity(args.len());
+        for arg in args.iter() {
+            let (scalar, data_type) = self.bind_match_recognize_expr(input_context, arg)?;
+            let index = self.match_recognize_column(
+                scalar,
+                data_type.clone(),
+                arg.to_string(),
+                scalar_items,
+            );
+            bound_args.push((index, data_type));
+        }
+
+        Ok(match func {
+            MatchFunctionExpr::First { .. } => {
+                let (arg, data_type) = bound_args.pop().unwrap();
+                MatchFunction::First {
+                    variable,
+                    arg,
+                    return_type: Box::new(data_type.wrap_nullable()),
+                }
+            }
+            MatchFunctionExpr::Last { .. } => {
+                let (arg, data_type) = bound_args.pop().unwrap();
+                MatchFunction::Last {
+                    variable,
+                    arg,
+                    return_type: Box::new(data_type.wrap_nullable()),
+                }
+            }
+            MatchFunctionExpr::Aggregate {
+                name, display_name, ..
+            } => {
+                let arg_types = bound_args
+                    .iter()
+                    .map(|(_, data_type)| data_type.clone())
+                    .collect::<Vec<_>>();
+                let return_type = AggregateFunctionFactory::instance()
+                    .get(&name, vec![], arg_types)?
+                    .return_type()?;
+                let args = bound_args
+                    .into_iter()
+                    .map(|(index, data_type)| {
+                        let column = self.metadata.read().column(index).name();
+                        ScalarExpr::BoundColumnRef(BoundColumnRef {
+                            span,
+                            column: ColumnBindingBuilder::new(
+                                column,
+                                index,
+                                Box::new(data_type),
+                                Visibility::Visible,
+                            )
+                            .build(),
+                        })
+                    })
+                    .collect();
+                MatchFunction::Aggregate {
+                    variable,
+                    agg: AggregateFunction {
+                        span,
+                        func_name: name,
+                        distinct: false,
+                        params: vec![],
+                        args,
+                        return_type: Box::new(return_type),
+                        udaf: None,
+                        display_name,
+                    },
+                }
+            }
+            MatchFunctionExpr::MatchNumber | MatchFunctionExpr::Classifier => unreachable!(),
+        })
+    }
+}
+
+fn collect_pattern_symbols(pattern: &RowPattern, symbols: &mut Vec<Identifier>) {
+    match pattern {
+        RowPattern::Symbol(ident) => symbols.push(ident.clone()),
+        RowPattern::Concat(patterns) | RowPattern::Alternation(patterns) => {
+            for pattern in patterns {
+                collect_pattern_symbols(pattern, symbols);
+            }
+        }
+        RowPattern::Repetition { pattern, .. } | RowPattern::Group(pattern) => {
+            collect_pattern_symbols(pattern, symbols)
+        }
+    }
+}
+
+fn column_ref(span: Span, name: &str) -> Expr {
+    Expr::ColumnRef {
+        span,
+        column: ColumnRef {
+            database: None,
+            table: None,
+            column: ColumnID::Name(Identifier::from_name(span, name)),
+        },
+    }
+}
+
+// Removes the pattern variables which qualify the columns, like `A.price`, and records
+// the referenced variables.
+#[derive(VisitorMut)]
+#[visitor(Expr(enter))]
+struct PatternVariableRewriter<'a> {
+    pattern_variables: &'a [String],
+    name_resolution_ctx: &'a NameResolutionContext,
+    variables: Vec<usize>,
+}
+
+impl<'a> PatternVariableRewriter<'a> {
+    fn new(
+        pattern_variables: &'a [String],
+        name_resolution_ctx: &'a NameResolutionContext,
+    ) -> Self {
+        Self {
+            pattern_variables,
+            name_resolution_ctx,
+            variables: vec![],
+        }
+    }
+
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        if let Expr::ColumnRef {
+            column:
+                ColumnRef {
+                    database: None,
+                    table: table @ Some(_),
+                    ..
+                },
+            ..
+        } = expr
+        {
+            let name = normalize_identifier(table.as_ref().unwrap(), self.name_resolution_ctx).name;
+            if let Some(variable) = self.pattern_variables.iter().position(|v| *v == name) {
+                self.variables.push(variable);
+                *table = None;
+            }
+        }
+    }
+}
+
+// Replaces `PREV(expr, n)` and `NEXT(expr, n)` by the navigation columns.
+#[derive(VisitorMut)]
+#[visitor(Expr(enter))]
+struct NavigationRewriter {
+    next_index: usize,
+    // (column name, argument, is prev, offset)
+    navigations: Vec<(String, Expr, bool, u64)>,
+    error: Option<ErrorCode>,
+}
+
+impl NavigationRewriter {
+    fn new(next_index: usize) -> Self {
+        Self {
+            next_index,
+            navigations: vec![],
+            error: None,
+        }
+    }
+
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        let Expr::FunctionCall {
+            span,
+            func:
+                FunctionCall {
+                    name,
+                    args,
+                    window: None,
+                    ..
+                },
+        } = expr
+        else {
+            return;
+        };
+        let is_prev = match name.name.to_lowercase().as_str() {
+            "prev" => true,
+            "next" => false,
+            _ => return,
+        };
+        let offset = match args.as_slice() {
+            [_] => 1,
+            [
+                _,
+                Expr::Literal {
+                    value: Literal::UInt64(offset),
+                    ..
+                },
+            ] => *offset,
+            _ => {
+                self.error = Some(
+                    ErrorCode::SemanticError(format!(
+                        "{} expects an expression and an optional constant offset",
+                        name.name.to_uppercase()
+                    ))
+                    .set_span(*span),
+                );
+                return;
+            }
+        };
+        let column_name = format!(
+            "__match_recognize_nav_{}",
+            self.next_index + self.navigations.len()
+        );
+        self.navigations
+            .push((column_name.clone(), args[0].clone(), is_prev, offset));
+        *expr = column_ref(*span, &column_name);
+    }
+}
+
+enum MatchFunctionExpr {
+    MatchNumber,
+    Classifier,
+    First {
+        span: Span,
+        arg: Expr,
+    },
+    Last {
+        span: Span,
+        arg: Expr,
+    },
+    Aggregate {
+        span: Span,
+        name: String,
+        args: Vec<Expr>,
+        display_name: String,
+    },
+}
+
+// Replaces the match functions, and the columns which are not the arguments of match functions,
+// by the columns of the match functions.
+#[derive(VisitorMut)]
+#[visitor(Expr(enter))]
+struct MatchFunctionRewriter {
+    next_index: usize,
+    functions: Vec<(String, MatchFunctionExpr)>,
+    error: Option<ErrorCode>,
+}
+
+impl MatchFunctionRewriter {
+    fn new(next_index: usize) -> Self {
+        Self {
+            next_index,
+            functions: vec![],
+            error: None,
+        }
+    }
+
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        let span = expr.span();
+        let func = match &*expr {
+            // A column referenced directly is the value of the last row.
+            Expr::ColumnRef { .. } => MatchFunctionExpr::Last {
+                span,
+                arg: expr.clone(),
+            },
+            Expr::CountAll {
+                filter: None,
+                window: None,
+                ..
+            } => MatchFunctionExpr::Aggregate {
+                span,
+                name: "count".to_string(),
+                args: vec![],
+                display_name: expr.to_string(),
+            },
+            Expr::FunctionCall { func, .. } if func.window.is_none() => {
+                let name = func.name.name.to_lowercase();
+                match (name.as_str(), func.args.as_slice()) {
+                    ("match_number", []) => MatchFunctionExpr::MatchNumber,
+                    ("classifier", []) => MatchFunctionExpr::Classifier,
+                    ("first", [arg]) => MatchFunctionExpr::First {
+                        span,
+                        arg: arg.clone(),
+                    },
+                    ("last", [arg]) => MatchFunctionExpr::Last {
+                        span,
+                        arg: arg.clone(),
+                    },
+                    _ if AggregateFunctionFactory::instance().contains(&name) => {
+                        if func.distinct
+                            || !func.params.is_empty()
+                            || !func.order_by.is_empty()
+                            || func.filter.is_some()
+                        {
+                            self.error = Some(
+                                ErrorCode::SemanticError(format!(
+                                    "MATCH_RECOGNIZE does not support DISTINCT, parameters, WITHIN GROUP or FILTER of aggregate function {name}"
+                                ))
+                                .set_span(span),
+                            );
+                            return;
+                        }
+                        MatchFunctionExpr::Aggregate {
+                            span,
+                            name: name.clone(),
+                            args: func.args.clone(),
+                            display_name: expr.to_string(),
+                        }
+                    }
+                    _ => return,
+                }
+            }
+            _ => return,
+        };
+
+        let column_name = format!(
+            "__match_recognize_func_{}",
+            self.next_index + self.functions.len()
+        );
+        self.functions.push((column_name.clone(), func));
+        *expr = column_ref(span, &column_name);
+    }
+}
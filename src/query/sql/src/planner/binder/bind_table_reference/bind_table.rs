@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::SampleConfig;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableAlias;
 use databend_common_ast::ast::TemporalClause;
 use databend_common_ast::ast::WithOptions;
+use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::Span;
@@ -29,11 +32,14 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_storages_view::view_table::QUERY;
 use databend_storages_common_table_meta::table::get_change_type;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 
 use crate::binder::util::TableIdentifier;
 use crate::binder::Binder;
 use crate::optimizer::SExpr;
+use crate::plans::Filter;
 use crate::BindContext;
+use crate::ScalarBinder;
 
 impl Binder {
     /// Bind a base table.
@@ -253,6 +259,7 @@ impl Binder {
                 }
             }
             _ => {
+                let ttl = table_meta.options().get(OPT_KEY_TTL).cloned();
                 let table_index = self.metadata.write().add_table(
                     catalog,
                     database.clone(),
@@ -264,13 +271,16 @@ impl Binder {
                     false,
                 );
 
-                let (s_expr, mut bind_context) = self.bind_base_table(
+                let (mut s_expr, mut bind_context) = self.bind_base_table(
                     bind_context,
                     database.as_str(),
                     table_index,
                     None,
                     sample,
                 )?;
+                if let Some(ttl) = ttl {
+                    s_expr = self.bind_ttl_filter(&mut bind_context, s_expr, &ttl)?;
+                }
                 if let Some(alias) = alias {
                     bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                 }
@@ -279,6 +289,36 @@ impl Binder {
         }
     }
 
+    /// Hide the rows expired by the TTL of the table, they are removed
+    /// physically by `OPTIMIZE TABLE ... EXPIRE`. Rows whose TTL is NULL never expire.
+    fn bind_ttl_filter(
+        &mut self,
+        bind_context: &mut BindContext,
+        s_expr: SExpr,
+        ttl: &str,
+    ) -> Result<SExpr> {
+        let sql = format!("({ttl}) > NOW() OR ({ttl}) IS NULL");
+        let tokens = tokenize_sql(&sql)?;
+        let expr = parse_expr(&tokens, self.dialect)?;
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (scalar, _) = scalar_binder.bind(&expr)?;
+        let filter = Filter {
+            predicates: vec![scalar],
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(s_expr),
+        ))
+    }
+
     pub(crate) fn check_view_dep(
         bind_context: &BindContext,
         database: &str,
@@ -54,6 +54,7 @@ use crate::ScalarExpr;
 
 impl Binder {
     /// Bind a table function.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn bind_table_function(
         &mut self,
         bind_context: &mut BindContext,
@@ -63,6 +64,7 @@ impl Binder {
         named_params: &[(Identifier, Expr)],
         alias: &Option<TableAlias>,
         sample: &Option<SampleConfig>,
+        lateral: bool,
     ) -> Result<(SExpr, BindContext)> {
         let func_name = normalize_identifier(name, &self.name_resolution_ctx);
 
@@ -90,6 +92,8 @@ impl Binder {
                             ),
                             params: vec![],
                             args,
+                            order_by: vec![],
+                            filter: None,
                             window: None,
                             lambda: None,
                         },
@@ -98,6 +102,7 @@ impl Binder {
                 }],
                 from: vec![],
                 selection: None,
+                connect_by: None,
                 group_by: None,
                 having: None,
                 window_list: None,
@@ -115,6 +120,20 @@ impl Binder {
             );
         }
 
+        if !func_name.name.eq_ignore_ascii_case("result_scan") {
+            if let Some(udf) = self.get_table_udf(&func_name.name)? {
+                return self.bind_table_udf(
+                    bind_context,
+                    span,
+                    udf,
+                    params,
+                    named_params,
+                    alias,
+                    lateral,
+                );
+            }
+        }
+
         let mut scalar_binder = ScalarBinder::new(
             bind_context,
             self.ctx.clone(),
@@ -344,6 +363,8 @@ impl Binder {
                             name: func_name.clone(),
                             args,
                             params: vec![],
+                            order_by: vec![],
+                            filter: None,
                             window: None,
                             lambda: None,
                         },
@@ -0,0 +1,524 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::TypeName;
+use databend_common_ast::parser::expr::type_name;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::run_parser;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_ast::parser::ParseMode;
+use databend_common_ast::Span;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::infer_schema_type;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Scalar;
+use databend_common_meta_app::principal::UDFDefinition;
+use databend_common_meta_app::principal::UDTFScript;
+use databend_common_meta_app::principal::UserDefinedFunction;
+use databend_common_users::UserApiProvider;
+use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut;
+use itertools::Itertools;
+
+use crate::binder::wrap_cast;
+use crate::binder::Binder;
+use crate::binder::ColumnBindingBuilder;
+use crate::binder::Visibility;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::planner::semantic::NameResolutionContext;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::FunctionCall;
+use crate::plans::ScalarItem;
+use crate::plans::UDFCall;
+use crate::plans::UDFType;
+use crate::plans::Udf;
+use crate::BindContext;
+use crate::ScalarExpr;
+
+const UDTF_BODY: &str = "__udtf_body";
+
+impl Binder {
+    /// Returns the user-defined table function with the given name, if any.
+    /// Built-in table functions take precedence over user-defined ones.
+    pub(crate) fn get_table_udf(&self, name: &str) -> Result<Option<UserDefinedFunction>> {
+        if self
+            .catalogs
+            .get_default_catalog(self.ctx.session_state())?
+            .exists_table_function(name)
+        {
+            return Ok(None);
+        }
+        let udf = databend_common_base::runtime::block_on({
+            UserApiProvider::instance().get_udf(&self.ctx.get_tenant(), name)
+        })?;
+        Ok(udf.filter(|udf| udf.definition.is_table_function()))
+    }
+
+    /// Returns whether the table reference is a call of a user-defined table function.
+    pub(crate) fn is_table_udf(&self, table_ref: &TableReference) -> Result<bool> {
+        let TableReference::TableFunction { name, .. } = table_ref else {
+            return Ok(false);
+        };
+        let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        Ok(self.get_table_udf(&name)?.is_some())
+    }
+
+    /// Bind a user-defined table function.
+    ///
+    /// The arguments are evaluated in a projection of their own, and the function reads
+    /// them from its columns:
+    ///
+    /// - A SQL table function is its query joined laterally with the projection, whose
+    ///   columns are named after the parameters and qualified by the function name. The
+    ///   columns of the query take precedence over the parameters of the same name, which
+    ///   can still be referenced as `<function>.<parameter>`.
+    /// - A script table function is evaluated by a `Udf` plan over the projection. Its
+    ///   handler is called through the same runtime as scalar script UDFs and returns the
+    ///   rows of each input row, as arrays or objects keyed by column name.
+    ///
+    /// The columns are then cast to the declared return columns.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn bind_table_udf(
+        &mut self,
+        bind_context: &mut BindContext,
+        span: &Span,
+        udf: UserDefinedFunction,
+        params: &[Expr],
+        named_params: &[(Identifier, Expr)],
+        alias: &Option<TableAlias>,
+        lateral: bool,
+    ) -> Result<(SExpr, BindContext)> {
+        if !named_params.is_empty() {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Table function '{}' does not accept named arguments",
+                udf.name
+            ))
+            .set_span(*span));
+        }
+
+        let (s_expr, mut udtf_context, return_names, return_types) = match udf.definition {
+            UDFDefinition::UDTFSql(def) => {
+                let query = self.udtf_sql_query(
+                    span,
+                    &udf.name,
+                    &def.sql,
+                    params,
+                    &def.arg_names,
+                    &def.arg_types,
+                )?;
+                let (s_expr, udtf_context) =
+                    self.bind_subquery(bind_context, lateral, &query, &None)?;
+                (s_expr, udtf_context, def.return_names, def.return_types)
+            }
+            UDFDefinition::UDTFScript(def) => {
+                let (s_expr, udtf_context) =
+                    self.bind_udtf_script(bind_context, span, lateral, &udf.name, params, &def)?;
+                (s_expr, udtf_context, def.return_names, def.return_types)
+            }
+            _ => {
+                return Err(ErrorCode::Internal(format!(
+                    "'{}' is not a table function",
+                    udf.name
+                )));
+            }
+        };
+
+        let columns = udtf_context
+            .columns
+            .iter()
+            .filter(|column| column.visibility == Visibility::Visible)
+            .cloned()
+            .collect::<Vec<_>>();
+        if columns.len() != return_types.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "Table function '{}' returns {} columns, but its definition declares {}",
+                udf.name,
+                columns.len(),
+                return_types.len()
+            ))
+            .set_span(*span));
+        }
+
+        let mut items = Vec::with_capacity(columns.len());
+        let mut output_columns = Vec::with_capacity(columns.len());
+        for ((column, name), data_type) in columns
+            .into_iter()
+            .zip(return_names.into_iter())
+            .zip(return_types.into_iter())
+        {
+            let mut scalar = ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: *span,
+                column: column.clone(),
+            });
+            if *column.data_type != data_type {
+                scalar = wrap_cast(&scalar, &data_type);
+            }
+            let index = self.metadata.write().add_derived_column(
+                name.clone(),
+                data_type.clone(),
+                Some(scalar.clone()),
+            );
+            output_columns.push(
+                ColumnBindingBuilder::new(name, index, Box::new(data_type), Visibility::Visible)
+                    .table_name(Some(udf.name.clone()))
+                    .build(),
+            );
+            items.push(ScalarItem { scalar, index });
+        }
+        udtf_context.columns = output_columns;
+        if let Some(alias) = alias {
+            udtf_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+
+        let s_expr = SExpr::create_unary(Arc::new(EvalScalar { items }.into()), Arc::new(s_expr));
+        Ok((s_expr, udtf_context))
+    }
+
+    fn udtf_sql_query(
+        &self,
+        span: &Span,
+        name: &str,
+        sql: &str,
+        params: &[Expr],
+        arg_names: &[String],
+        arg_types: &[DataType],
+    ) -> Result<Query> {
+        if params.len() != arg_names.len() {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Table function '{}' requires {} parameters, but got: {}",
+                name,
+                arg_names.len(),
+                params.len()
+            ))
+            .set_span(*span));
+        }
+
+        let tokens = tokenize_sql(sql)?;
+        let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+        let Statement::Query(body) = stmt else {
+            return Err(ErrorCode::Internal(format!(
+                "Invalid table function '{}': the body is not a query",
+                name
+            )));
+        };
+        if params.is_empty() {
+            return Ok(*body);
+        }
+
+        // The parameters are resolved by name in the body like the columns of an outer query.
+        let arg_columns = Self::udtf_arg_columns(params.len());
+        let sql = format!(
+            "SELECT {UDTF_BODY}.* FROM ({}) AS {}, LATERAL (SELECT 1) AS {UDTF_BODY}",
+            Self::udtf_args_projection(&arg_columns, arg_names),
+            quoted_ident(name)
+        );
+        let mut query = Self::parse_udtf_expansion(name, &sql)?;
+        query.drive_mut(&mut ArgumentReplacer {
+            name_resolution_ctx: &self.name_resolution_ctx,
+            args: Self::udtf_args(params, arg_columns, arg_types)?,
+        });
+        if let SetExpr::Select(select) = &mut query.body {
+            if let Some(TableReference::Subquery { subquery, .. }) = select.from.last_mut() {
+                *subquery = body;
+                return Ok(query);
+            }
+        }
+        Err(ErrorCode::Internal(format!(
+            "Invalid expansion of table function '{}'",
+            name
+        )))
+    }
+
+    fn bind_udtf_script(
+        &mut self,
+        bind_context: &mut BindContext,
+        span: &Span,
+        lateral: bool,
+        name: &str,
+        params: &[Expr],
+        def: &UDTFScript,
+    ) -> Result<(SExpr, BindContext)> {
+        if params.len() != def.arg_types.len() {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Table function '{}' requires {} parameters, but got: {}",
+                name,
+                def.arg_types.len(),
+                params.len()
+            ))
+            .set_span(*span));
+        }
+
+        // A function without parameters is called once, on the single row of `SELECT 1`.
+        let arg_columns = Self::udtf_arg_columns(params.len());
+        let sql = match params.is_empty() {
+            true => "SELECT 1".to_string(),
+            false => Self::udtf_args_projection(&arg_columns, &arg_columns),
+        };
+        let mut query = Self::parse_udtf_expansion(name, &sql)?;
+        query.drive_mut(&mut ArgumentReplacer {
+            name_resolution_ctx: &self.name_resolution_ctx,
+            args: Self::udtf_args(params, arg_columns, &def.arg_types)?,
+        });
+        let (s_expr, mut udtf_context) =
+            self.bind_subquery(bind_context, lateral, &query, &None)?;
+        let arguments = udtf_context
+            .columns
+            .iter()
+            .take(params.len())
+            .map(|column| {
+                ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: *span,
+                    column: column.clone(),
+                })
+            })
+            .collect();
+
+        let (func_name, code) = udtf_script_code(name, def)?;
+        let fields_type = def
+            .return_types
+            .iter()
+            .map(|data_type| data_type.wrap_nullable())
+            .collect::<Vec<_>>();
+        let return_type = DataType::Tuple(fields_type.clone());
+        let display_name = format!("{}({})", def.handler, params.iter().join(", "));
+        let udf_call = ScalarExpr::UDFCall(UDFCall {
+            span: *span,
+            name: name.to_string(),
+            func_name,
+            display_name: display_name.clone(),
+            arg_types: def.arg_types.clone(),
+            return_type: Box::new(return_type.clone()),
+            arguments,
+            udf_type: UDFType::Script((
+                def.language.to_lowercase(),
+                def.runtime_version.clone(),
+                code.into_bytes(),
+            )),
+        });
+        let rows_index = self.metadata.write().add_derived_column(
+            display_name.clone(),
+            return_type.clone(),
+            Some(udf_call.clone()),
+        );
+        let udf = Udf {
+            items: vec![ScalarItem {
+                scalar: udf_call,
+                index: rows_index,
+            }],
+            script_udf: true,
+            table_function: true,
+        };
+        let s_expr = SExpr::create_unary(Arc::new(udf.into()), Arc::new(s_expr));
+
+        // Each row is a tuple of the declared columns.
+        let rows_column = ColumnBindingBuilder::new(
+            display_name,
+            rows_index,
+            Box::new(return_type),
+            Visibility::Visible,
+        )
+        .build();
+        let mut items = Vec::with_capacity(fields_type.len());
+        let mut columns = Vec::with_capacity(fields_type.len());
+        for (i, (column, data_type)) in def.return_names.iter().zip(fields_type).enumerate() {
+            let scalar = ScalarExpr::FunctionCall(FunctionCall {
+                span: *span,
+                func_name: "get".to_string(),
+                params: vec![Scalar::Number(NumberScalar::Int64((i + 1) as i64))],
+                arguments: vec![ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: *span,
+                    column: rows_column.clone(),
+                })],
+            });
+            let index = self.metadata.write().add_derived_column(
+                column.clone(),
+                data_type.clone(),
+                Some(scalar.clone()),
+            );
+            columns.push(
+                ColumnBindingBuilder::new(
+                    column.clone(),
+                    index,
+                    Box::new(data_type),
+                    Visibility::Visible,
+                )
+                .build(),
+            );
+            items.push(ScalarItem { scalar, index });
+        }
+        udtf_context.columns = columns;
+
+        self.ctx.set_cacheable(false);
+        let s_expr = SExpr::create_unary(Arc::new(EvalScalar { items }.into()), Arc::new(s_expr));
+        Ok((s_expr, udtf_context))
+    }
+
+    fn udtf_arg_columns(num_params: usize) -> Vec<String> {
+        (0..num_params).map(|i| format!("__udtf_arg{i}")).collect()
+    }
+
+    // The arguments cast to the parameter types, by the column which is replaced by them.
+    fn udtf_args(
+        params: &[Expr],
+        arg_columns: Vec<String>,
+        arg_types: &[DataType],
+    ) -> Result<HashMap<String, Expr>> {
+        let mut args = HashMap::with_capacity(params.len());
+        for ((column, arg_type), param) in arg_columns.into_iter().zip(arg_types).zip(params) {
+            args.insert(column, Expr::Cast {
+                span: param.span(),
+                expr: Box::new(param.clone()),
+                target_type: data_type_to_type_name(arg_type)?,
+                pg_style: false,
+            });
+        }
+        Ok(args)
+    }
+
+    // The arguments are evaluated in a projection without FROM clause, so their columns can
+    // only be resolved in the outer query and are never captured by the function.
+    fn udtf_args_projection(arg_columns: &[String], names: &[String]) -> String {
+        let columns = arg_columns
+            .iter()
+            .zip(names)
+            .map(|(column, name)| format!("{column} AS {}", quoted_ident(name)))
+            .collect::<Vec<_>>();
+        format!("SELECT {}", columns.join(", "))
+    }
+
+    fn parse_udtf_expansion(name: &str, sql: &str) -> Result<Query> {
+        let tokens = tokenize_sql(sql)?;
+        let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+        let Statement::Query(query) = stmt else {
+            return Err(ErrorCode::Internal(format!(
+                "Invalid expansion of table function '{}'",
+                name
+            )));
+        };
+        Ok(*query)
+    }
+}
+
+// The names are stored normalized, they are quoted to be resolved as is.
+fn quoted_ident(name: &str) -> Identifier {
+    Identifier::from_name_with_quoted(None, name, Some('"'))
+}
+
+// Appends a handler to the code of a script table function, which calls the handler of
+// the function and returns its rows as tuples of the declared columns.
+fn udtf_script_code(name: &str, def: &UDTFScript) -> Result<(String, String)> {
+    let func_name = format!("__udtf_{}", def.handler);
+    let names = def
+        .return_names
+        .iter()
+        .map(|name| format!("{:?}", name))
+        .join(", ");
+    let wrapper = match def.language.to_lowercase().as_str() {
+        "javascript" => format!(
+            r#"
+export function {func_name}(...args) {{
+    const names = [{names}];
+    const rows = [];
+    for (const row of {handler}(...args)) {{
+        rows.push(Object.fromEntries(names.map((name, i) => [`${{i + 1}}`, Array.isArray(row) ? row[i] : row[name]])));
+    }}
+    return rows;
+}}
+"#,
+            handler = def.handler
+        ),
+        "python" => format!(
+            r#"
+
+class _UdtfRow(dict):
+    __getattr__ = dict.__getitem__
+
+
+def {func_name}(*args):
+    names = [{names}]
+    rows = []
+    for row in {handler}(*args):
+        values = row if isinstance(row, (list, tuple)) else [row[name] for name in names]
+        rows.append(_UdtfRow((str(i + 1), value) for i, value in enumerate(values)))
+    return rows
+"#,
+            handler = def.handler
+        ),
+        language => {
+            return Err(ErrorCode::SemanticError(format!(
+                "Table function '{}' is written in {}, which does not support table functions",
+                name, language
+            )));
+        }
+    };
+    Ok((func_name, format!("{}\n{}", def.code, wrapper)))
+}
+
+fn data_type_to_type_name(data_type: &DataType) -> Result<TypeName> {
+    let sql_name = infer_schema_type(data_type)?.sql_name();
+    let tokens = tokenize_sql(&sql_name)?;
+    run_parser(
+        &tokens,
+        Dialect::default(),
+        ParseMode::Default,
+        false,
+        type_name,
+    )
+}
+
+// Replaces the unqualified references to the argument columns of a table function expansion
+// by the arguments. The replacement is done on exit, so the arguments are not visited again.
+#[derive(VisitorMut)]
+#[visitor(Expr(exit))]
+struct ArgumentReplacer<'a> {
+    name_resolution_ctx: &'a NameResolutionContext,
+    args: HashMap<String, Expr>,
+}
+
+impl ArgumentReplacer<'_> {
+    fn exit_expr(&mut self, expr: &mut Expr) {
+        if let Expr::ColumnRef {
+            column:
+                ColumnRef {
+                    database: None,
+                    table: None,
+                    column: ColumnID::Name(ident),
+                },
+            ..
+        } = expr
+        {
+            let name = normalize_identifier(ident, self.name_resolution_ctx).name;
+            if let Some(arg) = self.args.get(&name) {
+                *expr = arg.clone();
+            }
+        }
+    }
+}
@@ -15,9 +15,11 @@
 mod bind;
 mod bind_join;
 mod bind_location;
+mod bind_match_recognize;
 mod bind_subquery;
 mod bind_table;
 mod bind_table_function;
+mod bind_udtf;
 
 pub use bind_join::JoinConditions;
 pub use bind_table_function::parse_result_scan_args;
@@ -19,6 +19,7 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use chrono_tz::Tz;
+use databend_common_ast::ast::AlterRoleAction;
 use databend_common_ast::ast::Hint;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Settings;
@@ -54,6 +55,7 @@ use crate::binder::CteInfo;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
 use crate::planner::query_executor::QueryExecutor;
+use crate::plans::AlterRolePlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::DescConnectionPlan;
@@ -361,6 +363,16 @@ impl<'a> Binder {
                 if_exists: *if_exists,
                 role_name: role_name.to_string(),
             })),
+            Statement::AlterRole { role_name, action } => {
+                let workload_group = match action {
+                    AlterRoleAction::SetWorkloadGroup(group) => Some(group.clone()),
+                    AlterRoleAction::UnsetWorkloadGroup => None,
+                };
+                Plan::AlterRole(Box::new(AlterRolePlan {
+                    role_name: role_name.to_string(),
+                    workload_group,
+                }))
+            }
 
             // Stages
             Statement::ShowStages => self.bind_rewrite_to_query(bind_context, "SELECT name, stage_type, number_of_files, creator, created_on, comment FROM system.stages ORDER BY name", RewriteKind::ShowStages).await?,
@@ -550,6 +562,16 @@ impl<'a> Binder {
                 self.bind_desc_password_policy(stmt).await?
             }
             Statement::ShowPasswordPolicies{ show_options } => self.bind_show_password_policies(bind_context, show_options).await?,
+            Statement::CreateWorkloadGroup(stmt) => {
+                self.bind_create_workload_group(stmt).await?
+            }
+            Statement::AlterWorkloadGroup(stmt) => {
+                self.bind_alter_workload_group(stmt).await?
+            }
+            Statement::DropWorkloadGroup(stmt) => {
+                self.bind_drop_workload_group(stmt).await?
+            }
+            Statement::ShowWorkloadGroups => self.bind_show_workload_groups(bind_context).await?,
             Statement::CreateTask(stmt) => {
                 self.bind_create_task(stmt).await?
             }
@@ -612,6 +634,9 @@ impl<'a> Binder {
             Statement::Commit => Plan::Commit,
             Statement::Abort => Plan::Abort,
             Statement::ExecuteImmediate(stmt) => self.bind_execute_immediate(stmt).await?,
+            Statement::Prepare(stmt) => self.bind_prepare(stmt)?,
+            Statement::Execute(stmt) => self.bind_execute(bind_context, stmt).await?,
+            Statement::Deallocate(stmt) => self.bind_deallocate(stmt)?,
             Statement::SetPriority {priority, object_id} => {
                 self.bind_set_priority(priority, object_id).await?
             },
@@ -898,6 +923,24 @@ impl<'a> Binder {
                 }
                 f.scalars().is_empty()
             }
+            RelOperator::MatchRecognize(match_recognize) => {
+                f.reset_finder();
+                for scalar_item in &match_recognize.partition_by {
+                    f.visit(&scalar_item.scalar)?;
+                }
+                for info in &match_recognize.order_by {
+                    f.visit(&info.order_by_item.scalar)?;
+                }
+                for variable in &match_recognize.variables {
+                    if let Some(condition) = &variable.condition {
+                        f.visit(condition)?;
+                    }
+                }
+                for scalar_item in &match_recognize.measures {
+                    f.visit(&scalar_item.scalar)?;
+                }
+                f.scalars().is_empty()
+            }
             RelOperator::Udf(_) => false,
             _ => true,
         };
@@ -67,6 +67,7 @@ use parking_lot::RwLock;
 use crate::binder::bind_query::MaxColumnPosition;
 use crate::binder::location::parse_uri_location;
 use crate::binder::Binder;
+use crate::parse_check_constraints;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
 use crate::plans::Plan;
@@ -187,6 +188,19 @@ impl<'a> Binder {
             None
         };
 
+        // Validate check constraints while reading the files, so that the violating rows
+        // are handled by the ON_ERROR option. The others are validated on write.
+        let check_constraints = if is_transform {
+            vec![]
+        } else {
+            let read_schema = stage_schema.remove_computed_fields();
+            parse_check_constraints(
+                self.ctx.clone(),
+                table.get_table_info(),
+                Arc::new(DataSchema::from(&read_schema)),
+            )?
+        };
+
         Ok(CopyIntoTablePlan {
             catalog_info,
             database_name,
@@ -204,6 +218,7 @@ impl<'a> Binder {
                 duplicated_files_detected: vec![],
                 is_select: false,
                 default_values,
+                check_constraints,
                 copy_into_location_options: Default::default(),
             },
             values_consts: vec![],
@@ -364,6 +379,7 @@ impl<'a> Binder {
                 duplicated_files_detected,
                 is_select: false,
                 default_values: Some(default_values),
+                check_constraints: vec![],
                 copy_into_location_options: Default::default(),
             },
             write_mode,
@@ -149,12 +149,15 @@ impl Binder {
             .get_procedure(&req)
             .await?;
         if let Some(procedure) = procedure {
-            if arg_types.is_empty() {
+            let language = procedure.procedure_meta.procedure_language;
+            if arg_types.is_empty() && language == "SQL" {
                 Ok(Plan::ExecuteImmediate(Box::new(ExecuteImmediatePlan {
                     script: procedure.procedure_meta.script,
                 })))
             } else {
                 Ok(Plan::CallProcedure(Box::new(CallProcedurePlan {
+                    name: name.to_string(),
+                    language,
                     script: procedure.procedure_meta.script,
                     arg_names: procedure.procedure_meta.arg_names,
                     args: arguments.clone(),
@@ -205,6 +208,8 @@ impl Binder {
             },
             procedure_language: match language {
                 ProcedureLanguage::SQL => "SQL".to_string(),
+                ProcedureLanguage::Python => "PYTHON".to_string(),
+                ProcedureLanguage::JavaScript => "JAVASCRIPT".to_string(),
             },
         })
     }
@@ -15,7 +15,9 @@
 use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::Duration;
 
+use databend_common_ast::ast::quote::QuotedIdent;
 use databend_common_ast::ast::AddColumnOption as AstAddColumnOption;
 use databend_common_ast::ast::AlterTableAction;
 use databend_common_ast::ast::AlterTableStmt;
@@ -32,6 +34,7 @@ use databend_common_ast::ast::DescribeTableStmt;
 use databend_common_ast::ast::DropTableStmt;
 use databend_common_ast::ast::Engine;
 use databend_common_ast::ast::ExistsTableStmt;
+use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::InvertedIndexDefinition;
 use databend_common_ast::ast::ModifyColumnAction;
@@ -44,8 +47,12 @@ use databend_common_ast::ast::ShowLimit;
 use databend_common_ast::ast::ShowTablesStatusStmt;
 use databend_common_ast::ast::ShowTablesStmt;
 use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableConstraintDefinition;
+use databend_common_ast::ast::TableConstraintKind;
+use databend_common_ast::ast::TableRefType;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::TableType;
+use databend_common_ast::ast::TimeTravelPoint;
 use databend_common_ast::ast::TruncateTableStmt;
 use databend_common_ast::ast::TypeName;
 use databend_common_ast::ast::UndropTableStmt;
@@ -53,6 +60,7 @@ use databend_common_ast::ast::UriLocation;
 use databend_common_ast::ast::VacuumDropTableStmt;
 use databend_common_ast::ast::VacuumTableStmt;
 use databend_common_ast::ast::VacuumTemporaryFiles;
+use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_base::base::uuid::Uuid;
@@ -61,6 +69,7 @@ use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::lock::LockTableOption;
 use databend_common_catalog::plan::Filters;
 use databend_common_catalog::table::CompactionLimits;
+use databend_common_catalog::table::Table;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -78,12 +87,15 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableConstraint;
 use databend_common_meta_app::schema::TableIndex;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_storage::DataOperator;
 use databend_common_storages_view::view_table::QUERY;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::is_reserved_opt_key;
+use databend_storages_common_table_meta::table::ColumnCodec;
+use databend_storages_common_table_meta::table::TableRefKind;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
@@ -92,6 +104,7 @@ use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 use derive_visitor::DriveMut;
 use log::debug;
 use opendal::Operator;
@@ -111,13 +124,18 @@ use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableConstraintPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CloneTablePlan;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTableRefPlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTableConstraintPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRefPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyColumnAction as ModifyColumnActionInPlan;
 use crate::plans::ModifyTableColumnPlan;
@@ -142,6 +160,7 @@ use crate::plans::VacuumDropTablePlan;
 use crate::plans::VacuumTableOption;
 use crate::plans::VacuumTablePlan;
 use crate::plans::VacuumTemporaryFilesPlan;
+use crate::plans::WarmTableCachePlan;
 use crate::BindContext;
 use crate::Planner;
 use crate::SelectBuilder;
@@ -419,6 +438,10 @@ impl Binder {
         &mut self,
         stmt: &CreateTableStmt,
     ) -> Result<Plan> {
+        if let Some(CreateTableSource::Clone { .. }) = &stmt.source {
+            return self.bind_clone_table(stmt).await;
+        }
+
         let CreateTableStmt {
             create_option,
             catalog,
@@ -427,6 +450,7 @@ impl Binder {
             source,
             table_options,
             cluster_by,
+            ttl,
             as_query,
             table_type,
             engine,
@@ -506,7 +530,7 @@ impl Binder {
         };
 
         // todo(geometry): remove this when geometry stable.
-        if let Some(CreateTableSource::Columns(cols, _)) = &source {
+        if let Some(CreateTableSource::Columns(cols, _, _)) = &source {
             if cols
                 .iter()
                 .any(|col| matches!(col.data_type, TypeName::Geometry | TypeName::Geography))
@@ -598,6 +622,15 @@ impl Binder {
             }
         };
 
+        let (schema, constraints) = match &source {
+            Some(CreateTableSource::Columns(_, _, constraint_defs))
+                if !constraint_defs.is_empty() =>
+            {
+                self.analyze_table_constraints(engine, schema, constraint_defs)?
+            }
+            _ => (schema, BTreeMap::new()),
+        };
+
         if engine == Engine::Memory {
             let catalog = self.ctx.get_catalog(&catalog).await?;
             let db = catalog
@@ -684,6 +717,17 @@ impl Binder {
             }
         }
 
+        if let Some(ttl) = ttl {
+            if engine != Engine::Fuse {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "Table engine {} does not support TTL",
+                    engine
+                )));
+            }
+            let ttl = self.analyze_ttl(ttl, schema.clone())?;
+            options.insert(OPT_KEY_TTL.to_owned(), ttl);
+        }
+
         let plan = CreateTablePlan {
             create_option: create_option.clone().into(),
             tenant: self.ctx.get_tenant(),
@@ -707,10 +751,79 @@ impl Binder {
                 None
             },
             inverted_indexes,
+            constraints,
         };
         Ok(Plan::CreateTable(Box::new(plan)))
     }
 
+    #[async_backtrace::framed]
+    async fn bind_clone_table(&mut self, stmt: &CreateTableStmt) -> Result<Plan> {
+        let Some(CreateTableSource::Clone {
+            catalog: source_catalog,
+            database: source_database,
+            table: source_table,
+            travel_point,
+        }) = &stmt.source
+        else {
+            unreachable!()
+        };
+
+        // The clone inherits the schema, the options and the cluster key of the source.
+        if !stmt.table_options.is_empty()
+            || stmt.cluster_by.is_some()
+            || stmt.ttl.is_some()
+            || stmt.as_query.is_some()
+            || stmt.engine.is_some()
+            || stmt.uri_location.is_some()
+            || stmt.table_type != TableType::Normal
+        {
+            return Err(ErrorCode::SemanticError(
+                "CREATE TABLE ... CLONE does not accept the other clauses of CREATE TABLE",
+            ));
+        }
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(&stmt.catalog, &stmt.database, &stmt.table);
+        let (source_catalog, source_database, source_table) =
+            self.normalize_object_identifier_triple(source_catalog, source_database, source_table);
+
+        let source = self
+            .ctx
+            .get_table(&source_catalog, &source_database, &source_table)
+            .await?;
+        if source.engine() != "FUSE" {
+            return Err(ErrorCode::SemanticError(format!(
+                "Table engine {} does not support CLONE",
+                source.engine()
+            )));
+        }
+
+        let point = match travel_point {
+            Some(TimeTravelPoint::Stream { .. }) => {
+                return Err(ErrorCode::SyntaxException(
+                    "Table can not be cloned at a stream",
+                ));
+            }
+            Some(point) => {
+                let mut bind_context = BindContext::new();
+                Some(self.resolve_data_travel_point(&mut bind_context, point)?)
+            }
+            None => None,
+        };
+
+        Ok(Plan::CloneTable(Box::new(CloneTablePlan {
+            create_option: stmt.create_option.clone().into(),
+            tenant: self.ctx.get_tenant(),
+            catalog,
+            database,
+            table,
+            source_catalog,
+            source_database,
+            source_table,
+            point,
+        })))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_attach_table(
         &mut self,
@@ -779,6 +892,7 @@ impl Binder {
             cluster_key: None,
             as_select: None,
             inverted_indexes: None,
+            constraints: BTreeMap::new(),
         })))
     }
 
@@ -884,14 +998,15 @@ impl Binder {
                 old_column,
                 new_column,
             } => {
-                let schema = self
-                    .ctx
-                    .get_table(&catalog, &database, &table)
-                    .await?
-                    .schema();
+                let table_meta = self.ctx.get_table(&catalog, &database, &table).await?;
                 let (new_schema, old_column, new_column) = self
-                    .analyze_rename_column(old_column, new_column, schema)
+                    .analyze_rename_column(old_column, new_column, table_meta.schema())
                     .await?;
+                self.check_ttl_column(
+                    table_meta.as_ref(),
+                    Arc::new(new_schema.clone()),
+                    &old_column,
+                )?;
                 Ok(Plan::RenameTableColumn(Box::new(RenameTableColumnPlan {
                     tenant: self.ctx.get_tenant(),
                     catalog,
@@ -982,6 +1097,12 @@ impl Binder {
             }
             AlterTableAction::DropColumn { column } => {
                 let column = self.normalize_object_identifier(column);
+                let table_meta = self.ctx.get_table(&catalog, &database, &table).await?;
+                if table_meta.options().contains_key(OPT_KEY_TTL) {
+                    let mut new_schema = table_meta.schema().as_ref().clone();
+                    new_schema.drop_column(&column)?;
+                    self.check_ttl_column(table_meta.as_ref(), Arc::new(new_schema), &column)?;
+                }
                 Ok(Plan::DropTableColumn(Box::new(DropTableColumnPlan {
                     catalog,
                     database,
@@ -1021,39 +1142,10 @@ impl Binder {
                 selection,
                 limit,
             } => {
-                let filters = if let Some(expr) = selection {
-                    let (_, mut context) =
-                        self.bind_table_reference(bind_context, table_reference)?;
-
-                    let mut scalar_binder = ScalarBinder::new(
-                        &mut context,
-                        self.ctx.clone(),
-                        &self.name_resolution_ctx,
-                        self.metadata.clone(),
-                        &[],
-                        self.m_cte_bound_ctx.clone(),
-                        self.ctes_map.clone(),
-                    );
-                    scalar_binder.forbid_udf();
-                    let (scalar, _) = scalar_binder.bind(expr)?;
-
-                    // prepare the filter expression
-                    let filter = cast_expr_to_non_null_boolean(
-                        scalar
-                            .as_expr()?
-                            .project_column_ref(|col| col.column_name.clone()),
-                    )?;
-                    // prepare the inverse filter expression
-                    let inverted_filter =
-                        check_function(None, "not", &[], &[filter.clone()], &BUILTIN_FUNCTIONS)?;
-
-                    Some(Filters {
-                        filter: filter.as_remote_expr(),
-                        inverted_filter: inverted_filter.as_remote_expr(),
-                    })
-                } else {
-                    None
-                };
+                let filters = selection
+                    .as_ref()
+                    .map(|expr| self.bind_table_filters(bind_context, table_reference, expr))
+                    .transpose()?;
 
                 let recluster = RelOperator::Recluster(Recluster {
                     catalog,
@@ -1068,6 +1160,47 @@ impl Binder {
                     is_final: *is_final,
                 })
             }
+            AlterTableAction::WarmCache { columns, selection } => {
+                let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+                if table_info.engine() != "FUSE" {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Table engine {} does not support WARM CACHE",
+                        table_info.engine()
+                    )));
+                }
+                let schema = table_info.schema();
+                let columns = columns
+                    .as_ref()
+                    .map(|columns| {
+                        columns
+                            .iter()
+                            .map(|column| {
+                                let name =
+                                    normalize_identifier(column, &self.name_resolution_ctx).name;
+                                match schema.index_of(&name) {
+                                    Ok(_) => Ok(name),
+                                    Err(_) => Err(ErrorCode::SemanticError(format!(
+                                        "Column {} not found in table {}",
+                                        name, table
+                                    ))
+                                    .set_span(column.span)),
+                                }
+                            })
+                            .collect::<Result<Vec<_>>>()
+                    })
+                    .transpose()?;
+                let filters = selection
+                    .as_ref()
+                    .map(|expr| self.bind_table_filters(bind_context, table_reference, expr))
+                    .transpose()?;
+                Ok(Plan::WarmTableCache(Box::new(WarmTableCachePlan {
+                    catalog,
+                    database,
+                    table,
+                    columns,
+                    filters,
+                })))
+            }
             AlterTableAction::FlashbackTo { point } => {
                 let point = self.resolve_data_travel_point(bind_context, point)?;
                 Ok(Plan::RevertTable(Box::new(RevertTablePlan {
@@ -1094,9 +1227,121 @@ impl Binder {
                     table,
                 })))
             }
+            AlterTableAction::CreateTableRef {
+                ref_type,
+                name,
+                travel_point,
+                retain_days,
+            } => {
+                let point = match travel_point {
+                    Some(TimeTravelPoint::Stream { .. }) => {
+                        return Err(ErrorCode::SyntaxException(format!(
+                            "{ref_type} can not be created at a stream"
+                        )));
+                    }
+                    Some(point) => Some(self.resolve_data_travel_point(bind_context, point)?),
+                    None => None,
+                };
+                Ok(Plan::CreateTableRef(Box::new(CreateTableRefPlan {
+                    catalog,
+                    database,
+                    table,
+                    kind: table_ref_kind(ref_type),
+                    name: normalize_identifier(name, &self.name_resolution_ctx).name,
+                    point,
+                    retention: retain_days.map(|days| Duration::from_secs(days * 24 * 60 * 60)),
+                })))
+            }
+            AlterTableAction::DropTableRef { ref_type, name } => {
+                Ok(Plan::DropTableRef(Box::new(DropTableRefPlan {
+                    catalog,
+                    database,
+                    table,
+                    kind: table_ref_kind(ref_type),
+                    name: normalize_identifier(name, &self.name_resolution_ctx).name,
+                })))
+            }
+            AlterTableAction::AddConstraint { constraint } => {
+                let TableConstraintKind::Check { expr } = &constraint.constraint else {
+                    return Err(ErrorCode::SemanticError(
+                        "Only CHECK constraints can be added to an existing table",
+                    ));
+                };
+                let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+                if table_info.engine() != "FUSE" {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Table engine {} does not support CHECK constraints",
+                        table_info.engine()
+                    )));
+                }
+                let existing = &table_info.get_table_info().meta.constraints;
+                let name = match &constraint.name {
+                    Some(name) => self.normalize_object_identifier(name),
+                    None => default_check_name(existing),
+                };
+                if existing.contains_key(&name) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Duplicated constraint name: {}",
+                        name
+                    )));
+                }
+                let constraint = self.analyze_check_constraint(expr, table_info.schema())?;
+                Ok(Plan::AddTableConstraint(Box::new(AddTableConstraintPlan {
+                    catalog,
+                    database,
+                    table,
+                    name,
+                    constraint,
+                })))
+            }
+            AlterTableAction::DropConstraint { name } => Ok(Plan::DropTableConstraint(Box::new(
+                DropTableConstraintPlan {
+                    catalog,
+                    database,
+                    table,
+                    name: self.normalize_object_identifier(name),
+                },
+            ))),
         }
     }
 
+    // Binds a predicate on the columns of the table, used to prune its blocks.
+    fn bind_table_filters(
+        &mut self,
+        bind_context: &mut BindContext,
+        table_reference: &TableReference,
+        expr: &Expr,
+    ) -> Result<Filters> {
+        let (_, mut context) = self.bind_table_reference(bind_context, table_reference)?;
+
+        let mut scalar_binder = ScalarBinder::new(
+            &mut context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        scalar_binder.forbid_udf();
+        let (scalar, _) = scalar_binder.bind(expr)?;
+
+        // prepare the filter expression
+        let filter = cast_expr_to_non_null_boolean(
+            scalar
+                .as_expr()?
+                .project_column_ref(|col| col.column_name.clone()),
+        )?;
+        // prepare the inverse filter expression
+        let inverted_filter =
+            check_function(None, "not", &[], &[filter.clone()], &BUILTIN_FUNCTIONS)?;
+
+        Ok(Filters {
+            filter: filter.as_remote_expr(),
+            inverted_filter: inverted_filter.as_remote_expr(),
+        })
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_rename_table(
         &mut self,
@@ -1233,6 +1478,28 @@ impl Binder {
                     }))
                 }
             },
+            AstOptimizeTableAction::Expire => {
+                // Expired rows are removed by a delete, blocks that are expired entirely
+                // are dropped without being rewritten.
+                let table_meta = self.ctx.get_table(&catalog, &database, &table).await?;
+                let Some(ttl) = table_meta.options().get(OPT_KEY_TTL) else {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "Table {}.{} has no TTL",
+                        database, table
+                    )));
+                };
+                let quote = self.dialect.default_ident_quote();
+                let sql = format!(
+                    "DELETE FROM {}.{}.{} WHERE ({}) <= NOW()",
+                    QuotedIdent(&catalog, quote),
+                    QuotedIdent(&database, quote),
+                    QuotedIdent(&table, quote),
+                    ttl
+                );
+                let tokens = tokenize_sql(&sql)?;
+                let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+                self.bind_statement(bind_context, &stmt).await?
+            }
         };
 
         Ok(plan)
@@ -1401,7 +1668,8 @@ impl Binder {
         let not_null = self.is_column_not_null();
         let data_type = resolve_type_name(&column.data_type, not_null)?;
         let mut is_deterministic = true;
-        let mut field = TableField::new(&name, data_type);
+        let codec = Self::analyze_column_codec(column, &data_type)?;
+        let mut field = TableField::new(&name, data_type).with_codec(codec);
         if let Some(expr) = &column.expr {
             match expr {
                 ColumnExpr::Default(default_expr) => {
@@ -1431,6 +1699,25 @@ impl Binder {
         Ok((field, comment, is_deterministic))
     }
 
+    /// Validates the `CODEC(...)` of the column and returns it in the canonical form
+    /// kept in the table schema, e.g. `DELTA, ZSTD(3)`.
+    fn analyze_column_codec(
+        column: &ColumnDefinition,
+        data_type: &TableDataType,
+    ) -> Result<Option<String>> {
+        if column.codec.is_empty() {
+            return Ok(None);
+        }
+        let codec = ColumnCodec::try_create(
+            column
+                .codec
+                .iter()
+                .map(|codec| (codec.name.name.as_str(), codec.level)),
+        )?;
+        codec.check_data_type(data_type)?;
+        Ok(Some(codec.to_string()))
+    }
+
     #[async_backtrace::framed]
     pub async fn analyze_create_table_schema_by_columns(
         &self,
@@ -1445,7 +1732,8 @@ impl Binder {
             let schema_data_type = resolve_type_name(&column.data_type, not_null)?;
             fields_comments.push(column.comment.clone().unwrap_or_default());
 
-            let mut field = TableField::new(&name, schema_data_type.clone());
+            let codec = Self::analyze_column_codec(column, &schema_data_type)?;
+            let mut field = TableField::new(&name, schema_data_type.clone()).with_codec(codec);
             if let Some(expr) = &column.expr {
                 match expr {
                     ColumnExpr::Default(default_expr) => {
@@ -1552,7 +1840,7 @@ impl Binder {
         Option<BTreeMap<String, TableIndex>>,
     )> {
         match source {
-            CreateTableSource::Columns(columns, inverted_index_defs) => {
+            CreateTableSource::Columns(columns, inverted_index_defs, _) => {
                 let (schema, comments) =
                     self.analyze_create_table_schema_by_columns(columns).await?;
                 let inverted_indexes = if let Some(inverted_index_defs) = inverted_index_defs {
@@ -1588,6 +1876,9 @@ impl Binder {
                     Ok((table.schema(), table.field_comments().clone(), None))
                 }
             }
+            CreateTableSource::Clone { .. } => Err(ErrorCode::SemanticError(
+                "CLONE is only supported by CREATE TABLE",
+            )),
         }
     }
 
@@ -1714,6 +2005,287 @@ impl Binder {
         Ok(cluster_keys)
     }
 
+    /// Check the TTL expression of a table and return its normalized text.
+    ///
+    /// A row expires once the TTL expression evaluates to a time in the past,
+    /// so the expression must be a deterministic timestamp or date.
+    pub(in crate::planner::binder) fn analyze_ttl(
+        &mut self,
+        ttl: &Expr,
+        schema: TableSchemaRef,
+    ) -> Result<String> {
+        // Build a temporary BindContext to resolve the expr
+        let mut bind_context = BindContext::new();
+        for (index, field) in schema.fields().iter().enumerate() {
+            let column = ColumnBindingBuilder::new(
+                field.name().clone(),
+                index,
+                Box::new(DataType::from(field.data_type())),
+                Visibility::Visible,
+            )
+            .build();
+
+            bind_context.add_column_binding(column);
+        }
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        scalar_binder.forbid_udf();
+
+        let (scalar, _) = scalar_binder.bind(ttl)?;
+        if scalar.used_columns().is_empty() || !scalar.evaluable() {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "TTL expression `{:#}` is invalid, it must refer to the columns of the table",
+                ttl
+            )));
+        }
+
+        let expr = scalar.as_expr()?;
+        if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "TTL expression `{:#}` is not deterministic",
+                ttl
+            )));
+        }
+
+        let data_type = expr.data_type().remove_nullable();
+        if !matches!(data_type, DataType::Timestamp | DataType::Date) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "TTL expression `{:#}` must be of type TIMESTAMP or DATE, but got {}",
+                ttl, data_type
+            )));
+        }
+
+        let mut ttl = ttl.clone();
+        let mut normalizer = IdentifierNormalizer {
+            ctx: &self.name_resolution_ctx,
+        };
+        ttl.drive_mut(&mut normalizer);
+        Ok(format!("{:#}", &ttl))
+    }
+
+    /// Resolves the PRIMARY KEY, UNIQUE and CHECK constraints of a new table.
+    ///
+    /// The columns of the primary key are made NOT NULL, so the returned schema
+    /// replaces the one the constraints were resolved against.
+    pub(in crate::planner::binder) fn analyze_table_constraints(
+        &mut self,
+        engine: Engine,
+        schema: TableSchemaRef,
+        constraint_defs: &[TableConstraintDefinition],
+    ) -> Result<(TableSchemaRef, BTreeMap<String, TableConstraint>)> {
+        let mut constraints = BTreeMap::new();
+        let mut primary_key_ids = vec![];
+        for constraint_def in constraint_defs {
+            let (columns, enforced) = match &constraint_def.constraint {
+                TableConstraintKind::PrimaryKey { columns, enforced }
+                | TableConstraintKind::Unique { columns, enforced } => (columns, *enforced),
+                TableConstraintKind::Check { expr } => {
+                    if engine != Engine::Fuse {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "Table engine {} does not support CHECK constraints",
+                            engine
+                        )));
+                    }
+                    let constraint = self.analyze_check_constraint(expr, schema.clone())?;
+                    let name = match &constraint_def.name {
+                        Some(name) => self.normalize_object_identifier(name),
+                        None => default_check_name(&constraints),
+                    };
+                    if constraints.insert(name.clone(), constraint).is_some() {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "Duplicated constraint name: {}",
+                            name
+                        )));
+                    }
+                    continue;
+                }
+            };
+            if enforced && engine != Engine::Fuse {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Table engine {} does not support enforced constraints, use NOT ENFORCED instead",
+                    engine
+                )));
+            }
+
+            let mut column_ids = Vec::with_capacity(columns.len());
+            let mut column_names = Vec::with_capacity(columns.len());
+            for column in columns {
+                let column_name = self.normalize_object_identifier(column);
+                let field = schema.field_with_name(&column_name).map_err(|_| {
+                    ErrorCode::SemanticError(format!(
+                        "Table does not have column {} referenced by constraint",
+                        column_name
+                    ))
+                })?;
+                if matches!(
+                    field.data_type.remove_nullable(),
+                    TableDataType::Array(_) | TableDataType::Map(_) | TableDataType::Tuple { .. }
+                ) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Column {} of nested type {} can not be part of a key",
+                        column_name, field.data_type
+                    )));
+                }
+                if column_ids.contains(&field.column_id) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Duplicated column {} in constraint",
+                        column_name
+                    )));
+                }
+                column_ids.push(field.column_id);
+                column_names.push(column_name);
+            }
+
+            let (default_name, constraint) = match &constraint_def.constraint {
+                TableConstraintKind::PrimaryKey { .. } => {
+                    if !primary_key_ids.is_empty() {
+                        return Err(ErrorCode::SemanticError(
+                            "Multiple primary keys are not allowed",
+                        ));
+                    }
+                    primary_key_ids = column_ids.clone();
+                    ("primary_key".to_string(), TableConstraint::PrimaryKey {
+                        column_ids,
+                        enforced,
+                    })
+                }
+                TableConstraintKind::Unique { .. } => (
+                    format!("unique_{}", column_names.join("_")),
+                    TableConstraint::Unique {
+                        column_ids,
+                        enforced,
+                    },
+                ),
+                TableConstraintKind::Check { .. } => unreachable!(),
+            };
+            let name = match &constraint_def.name {
+                Some(name) => self.normalize_object_identifier(name),
+                None => default_name,
+            };
+            if constraints.insert(name.clone(), constraint).is_some() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Duplicated constraint name: {}",
+                    name
+                )));
+            }
+        }
+
+        if primary_key_ids.is_empty() {
+            return Ok((schema, constraints));
+        }
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let mut field = field.clone();
+                if primary_key_ids.contains(&field.column_id) {
+                    field.data_type = field.data_type.remove_nullable();
+                }
+                field
+            })
+            .collect();
+        Ok((TableSchemaRefExt::create(fields), constraints))
+    }
+
+    /// Resolves a CHECK constraint against the stored columns of `schema`.
+    pub(in crate::planner::binder) fn analyze_check_constraint(
+        &mut self,
+        check: &Expr,
+        schema: TableSchemaRef,
+    ) -> Result<TableConstraint> {
+        let schema = schema.remove_virtual_computed_fields();
+        let mut bind_context = BindContext::new();
+        for (index, field) in schema.fields().iter().enumerate() {
+            let column = ColumnBindingBuilder::new(
+                field.name().clone(),
+                index,
+                Box::new(DataType::from(field.data_type())),
+                Visibility::Visible,
+            )
+            .build();
+
+            bind_context.add_column_binding(column);
+        }
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        scalar_binder.forbid_udf();
+
+        let (scalar, _) = scalar_binder.bind(check)?;
+        let used_columns = scalar.used_columns();
+        if used_columns.is_empty() || !scalar.evaluable() {
+            return Err(ErrorCode::SemanticError(format!(
+                "CHECK expression `{:#}` is invalid, it must refer to the columns of the table",
+                check
+            )));
+        }
+
+        let expr = scalar.as_expr()?;
+        if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+            return Err(ErrorCode::SemanticError(format!(
+                "CHECK expression `{:#}` is not deterministic",
+                check
+            )));
+        }
+        if expr.data_type().remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "CHECK expression `{:#}` must be of type BOOLEAN, but got {}",
+                check,
+                expr.data_type()
+            )));
+        }
+
+        let mut column_ids: Vec<u32> = used_columns
+            .into_iter()
+            .map(|index| schema.fields()[index].column_id)
+            .collect();
+        column_ids.sort();
+        let mut check = check.clone();
+        let mut normalizer = IdentifierNormalizer {
+            ctx: &self.name_resolution_ctx,
+        };
+        check.drive_mut(&mut normalizer);
+        Ok(TableConstraint::Check {
+            expr: format!("{:#}", &check),
+            column_ids,
+        })
+    }
+
+    /// The columns referenced by the TTL of the table can't be dropped or renamed,
+    /// otherwise the TTL would no longer be bound at read time.
+    fn check_ttl_column(
+        &mut self,
+        table: &dyn Table,
+        new_schema: TableSchemaRef,
+        column: &str,
+    ) -> Result<()> {
+        let Some(ttl) = table.options().get(OPT_KEY_TTL) else {
+            return Ok(());
+        };
+        let tokens = tokenize_sql(ttl)?;
+        let expr = parse_expr(&tokens, self.dialect)?;
+        if self.analyze_ttl(&expr, new_schema).is_err() {
+            return Err(ErrorCode::BadArguments(format!(
+                "column `{}` is referenced by the TTL `{}` of the table",
+                column, ttl
+            )));
+        }
+        Ok(())
+    }
+
     fn valid_cluster_key_type(data_type: &DataType) -> bool {
         let inner_type = data_type.remove_nullable();
         matches!(
@@ -1786,3 +2358,18 @@ async fn verify_external_location_privileges(dal: Operator) -> Result<()> {
         .await
         .expect("join must succeed")
 }
+
+fn table_ref_kind(ref_type: &TableRefType) -> TableRefKind {
+    match ref_type {
+        TableRefType::Tag => TableRefKind::Tag,
+        TableRefType::Branch => TableRefKind::Branch,
+    }
+}
+
+/// Returns the first free name of the form `check_<n>`.
+fn default_check_name(constraints: &BTreeMap<String, TableConstraint>) -> String {
+    (1..)
+        .map(|n| format!("check_{n}"))
+        .find(|name| !constraints.contains_key(name))
+        .unwrap()
+}
@@ -78,6 +78,7 @@ impl Binder {
             catalog,
             database,
             table,
+            branch,
             columns,
             source,
             overwrite,
@@ -100,6 +101,9 @@ impl Binder {
             .map_err(|err| table_identifier.not_found_suggest_error(err))?;
 
         let schema = self.schema_project(&table.schema(), columns)?;
+        let branch = branch
+            .as_ref()
+            .map(|branch| normalize_identifier(branch, &self.name_resolution_ctx).name);
 
         let input_source: Result<InsertInputSource> = match source.clone() {
             InsertSource::Values { rows } => {
@@ -123,6 +127,11 @@ impl Binder {
             InsertSource::RawValues { rest_str, start } => {
                 let values_str = rest_str.trim_end_matches(';').trim_start().to_owned();
                 match self.ctx.get_stage_attachment() {
+                    Some(_) if branch.is_some() => {
+                        return Err(ErrorCode::Unimplemented(
+                            "Insert into a branch with stage attachment is not supported",
+                        ));
+                    }
                     Some(attachment) => {
                         return self
                             .bind_copy_from_attachment(
@@ -160,6 +169,7 @@ impl Binder {
             overwrite: *overwrite,
             source: input_source?,
             table_info: None,
+            branch,
         };
 
         Ok(Plan::Insert(Box::new(plan)))
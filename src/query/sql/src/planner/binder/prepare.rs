@@ -0,0 +1,138 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::DeallocateStmt;
+use databend_common_ast::ast::ExecuteStmt;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::PrepareStmt;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use super::BindContext;
+use super::Binder;
+use crate::planner::semantic::TypeChecker;
+use crate::plans::DeallocatePlan;
+use crate::plans::Plan;
+use crate::plans::PreparePlan;
+use crate::PlaceholderSql;
+use crate::Planner;
+
+impl Binder {
+    pub(in crate::planner::binder) fn bind_prepare(&mut self, stmt: &PrepareStmt) -> Result<Plan> {
+        let PrepareStmt { name, sql } = stmt;
+
+        // Parse the statement to report the syntax errors on PREPARE, the parameters
+        // are not known yet and bound as NULL.
+        let placeholder_sql = PlaceholderSql::try_create(sql)?;
+        let tokens = tokenize_sql(&placeholder_sql.bind_nulls()?)?;
+        let (inner, _) = parse_sql(&tokens, self.dialect)?;
+        check_prepared_statement(&inner)?;
+
+        Ok(Plan::Prepare(Box::new(PreparePlan {
+            name: self.normalize_object_identifier(name),
+            sql: sql.clone(),
+        })))
+    }
+
+    pub(in crate::planner::binder) fn bind_deallocate(
+        &mut self,
+        stmt: &DeallocateStmt,
+    ) -> Result<Plan> {
+        Ok(Plan::Deallocate(Box::new(DeallocatePlan {
+            name: self.normalize_object_identifier(&stmt.name),
+        })))
+    }
+
+    /// Binds the prepared statement with the parameters substituted for the placeholders.
+    ///
+    /// The plans of the queries executed by the planner are cached, see `Planner::plan_execute`.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_execute(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &ExecuteStmt,
+    ) -> Result<Plan> {
+        let placeholder_sql = self.get_prepared_statement(&stmt.name)?;
+        self.bind_execute_params(bind_context, &stmt.params)?;
+        let sql = placeholder_sql.bind(&stmt.params)?;
+        let extras = Planner::new(self.ctx.clone()).parse_sql(&sql)?;
+        check_prepared_statement(&extras.statement)?;
+        self.bind_statement(bind_context, &extras.statement).await
+    }
+
+    pub(crate) fn get_prepared_statement(&self, name: &Identifier) -> Result<PlaceholderSql> {
+        let name = self.normalize_object_identifier(name);
+        match self.ctx.get_prepared_statement(&name) {
+            Some(sql) => PlaceholderSql::try_create(&sql),
+            None => Err(ErrorCode::UnknownPreparedStatement(format!(
+                "Unknown prepared statement '{name}'"
+            ))),
+        }
+    }
+
+    /// Evaluates the parameters of EXECUTE, which must be constant values.
+    pub(crate) fn bind_execute_params(
+        &mut self,
+        bind_context: &mut BindContext,
+        params: &[Expr],
+    ) -> Result<Vec<(Scalar, DataType)>> {
+        let mut type_checker = TypeChecker::try_create(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            false,
+        )?;
+
+        let mut results = Vec::with_capacity(params.len());
+        for param in params {
+            let (scalar, _) = *type_checker.resolve(param)?;
+            let expr = scalar.as_expr()?;
+            let (new_expr, _) =
+                ConstantFolder::fold(&expr, &self.ctx.get_function_context()?, &BUILTIN_FUNCTIONS);
+            match new_expr {
+                databend_common_expression::Expr::Constant {
+                    scalar, data_type, ..
+                } => results.push((scalar, data_type)),
+                _ => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "parameter `{param}` of EXECUTE must be a constant value"
+                    )));
+                }
+            }
+        }
+        Ok(results)
+    }
+}
+
+/// The statements which manage the prepared statements can not be prepared themselves.
+///
+/// Checked on EXECUTE too, since the statements of a HTTP session are restored without PREPARE.
+pub(crate) fn check_prepared_statement(stmt: &Statement) -> Result<()> {
+    match stmt {
+        Statement::Prepare(_) | Statement::Execute(_) | Statement::Deallocate(_) => Err(
+            ErrorCode::SemanticError("PREPARE, EXECUTE and DEALLOCATE can not be prepared"),
+        ),
+        _ => Ok(()),
+    }
+}
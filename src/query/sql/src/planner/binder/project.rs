@@ -493,6 +493,8 @@ impl Binder {
                     lambda: lambda.cloned(),
                     distinct: false,
                     params: vec![],
+                    order_by: vec![],
+                    filter: None,
                     window: None,
                 },
             };
@@ -215,6 +215,7 @@ impl Binder {
         let sort_plan = Sort {
             items: order_by_items,
             limit: None,
+            limit_with_ties: false,
             after_exchange: None,
             pre_projection: None,
             window_partition: vec![],
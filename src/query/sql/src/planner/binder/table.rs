@@ -53,6 +53,7 @@ use databend_common_meta_types::MetaId;
 use databend_common_storage::StageFileInfo;
 use databend_common_storage::StageFilesInfo;
 use databend_storages_common_table_meta::table::ChangeType;
+use databend_storages_common_table_meta::table::TableRefKind;
 use log::info;
 use parking_lot::RwLock;
 
@@ -636,6 +637,14 @@ impl Binder {
                 database,
                 name,
             } => self.resolve_stream_data_travel_point(catalog, database, name),
+            TimeTravelPoint::Tag(name) => Ok(NavigationPoint::TableRef {
+                kind: TableRefKind::Tag,
+                name: normalize_identifier(name, &self.name_resolution_ctx).name,
+            }),
+            TimeTravelPoint::Branch(name) => Ok(NavigationPoint::TableRef {
+                kind: TableRefKind::Branch,
+                name: normalize_identifier(name, &self.name_resolution_ctx).name,
+            }),
         }
     }
 
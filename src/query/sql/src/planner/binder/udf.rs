@@ -18,15 +18,22 @@ use chrono::Utc;
 use databend_common_ast::ast::AlterUDFStmt;
 use databend_common_ast::ast::CreateUDFStmt;
 use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TypeName;
 use databend_common_ast::ast::UDFDefinition;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::udf_client::UDFFlightClient;
 use databend_common_meta_app::principal::LambdaUDF;
+use databend_common_meta_app::principal::UDAFScript;
 use databend_common_meta_app::principal::UDFDefinition as PlanUDFDefinition;
 use databend_common_meta_app::principal::UDFScript;
 use databend_common_meta_app::principal::UDFServer;
+use databend_common_meta_app::principal::UDTFScript;
+use databend_common_meta_app::principal::UDTFSql;
 use databend_common_meta_app::principal::UserDefinedFunction;
 
 use crate::normalize_identifier;
@@ -160,7 +167,141 @@ impl Binder {
                     created_on: Utc::now(),
                 })
             }
+            UDFDefinition::UDTFSql {
+                arg_types,
+                return_types,
+                sql,
+            } => {
+                let tokens = tokenize_sql(sql)?;
+                let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+                if !matches!(stmt, Statement::Query(_)) {
+                    return Err(ErrorCode::InvalidArgument(format!(
+                        "The body of table function '{name}' must be a query"
+                    )));
+                }
+
+                let (arg_names, arg_datatypes) = self.bind_udf_columns(arg_types, true)?;
+                let (return_names, return_datatypes) =
+                    self.bind_udf_columns(return_types, false)?;
+                Ok(UserDefinedFunction {
+                    name,
+                    description: udf_description.clone().unwrap_or_default(),
+                    definition: PlanUDFDefinition::UDTFSql(UDTFSql {
+                        arg_names,
+                        arg_types: arg_datatypes,
+                        return_names,
+                        return_types: return_datatypes,
+                        sql: sql.clone(),
+                    }),
+                    created_on: Utc::now(),
+                })
+            }
+            UDFDefinition::UDTFScript {
+                arg_types,
+                return_types,
+                code,
+                handler,
+                language,
+                runtime_version,
+            } => {
+                let mut arg_datatypes = Vec::with_capacity(arg_types.len());
+                for arg_type in arg_types {
+                    arg_datatypes.push(DataType::from(&resolve_type_name(arg_type, true)?));
+                }
+                let (return_names, return_datatypes) =
+                    self.bind_udf_columns(return_types, false)?;
+
+                if !matches!(language.to_lowercase().as_str(), "javascript" | "python") {
+                    return Err(ErrorCode::InvalidArgument(format!(
+                        "Unallowed UDTF language '{language}', must be python or javascript"
+                    )));
+                }
+
+                let mut runtime_version = runtime_version.to_string();
+                if runtime_version.is_empty() && language.to_lowercase() == "python" {
+                    runtime_version = "3.12.2".to_string();
+                }
+
+                Ok(UserDefinedFunction {
+                    name,
+                    description: udf_description.clone().unwrap_or_default(),
+                    definition: PlanUDFDefinition::UDTFScript(UDTFScript {
+                        code: code.clone(),
+                        handler: handler.clone(),
+                        language: language.clone(),
+                        arg_types: arg_datatypes,
+                        return_names,
+                        return_types: return_datatypes,
+                        runtime_version,
+                    }),
+                    created_on: Utc::now(),
+                })
+            }
+            UDFDefinition::UDAFScript {
+                arg_types,
+                state_types,
+                return_type,
+                code,
+                language,
+                runtime_version,
+            } => {
+                let mut arg_datatypes = Vec::with_capacity(arg_types.len());
+                for arg_type in arg_types {
+                    arg_datatypes.push(DataType::from(&resolve_type_name(arg_type, true)?));
+                }
+                let (state_names, state_datatypes) = self.bind_udf_columns(state_types, false)?;
+                let return_type = DataType::from(&resolve_type_name(return_type, true)?);
+
+                if !matches!(language.to_lowercase().as_str(), "javascript" | "python") {
+                    return Err(ErrorCode::InvalidArgument(format!(
+                        "Unallowed UDAF language '{language}', must be python or javascript"
+                    )));
+                }
+
+                let mut runtime_version = runtime_version.to_string();
+                if runtime_version.is_empty() && language.to_lowercase() == "python" {
+                    runtime_version = "3.12.2".to_string();
+                }
+
+                Ok(UserDefinedFunction {
+                    name,
+                    description: udf_description.clone().unwrap_or_default(),
+                    definition: PlanUDFDefinition::UDAFScript(UDAFScript {
+                        code: code.clone(),
+                        language: language.clone(),
+                        arg_types: arg_datatypes,
+                        state_names,
+                        state_types: state_datatypes,
+                        return_type,
+                        runtime_version,
+                    }),
+                    created_on: Utc::now(),
+                })
+            }
+        }
+    }
+
+    // Returned columns and state fields are nullable unless declared `NOT NULL`,
+    // like the columns of a table.
+    fn bind_udf_columns(
+        &self,
+        columns: &[(Identifier, TypeName)],
+        not_null: bool,
+    ) -> Result<(Vec<String>, Vec<DataType>)> {
+        let mut names = Vec::with_capacity(columns.len());
+        let mut types = Vec::with_capacity(columns.len());
+        for (name, ty) in columns {
+            let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+            if names.contains(&name) {
+                return Err(ErrorCode::InvalidArgument(format!(
+                    "Duplicate name '{name}' in function definition"
+                ))
+                .set_span(columns[names.len()].0.span));
+            }
+            names.push(name);
+            types.push(DataType::from(&resolve_type_name(ty, not_null)?));
         }
+        Ok((names, types))
     }
 
     pub(in crate::planner::binder) async fn bind_create_udf(
@@ -117,6 +117,7 @@ impl Binder {
             let sort_plan = Sort {
                 items: sort_items,
                 limit: window_plan.limit,
+                limit_with_ties: false,
                 after_exchange: None,
                 pre_projection: None,
                 window_partition: window_plan.partition_by.clone(),
@@ -337,6 +338,7 @@ impl<'a> WindowRewriter<'a> {
                     params: agg.params.clone(),
                     args: replaced_args,
                     return_type: agg.return_type.clone(),
+                    udaf: agg.udaf.clone(),
                 })
             }
             WindowFuncType::LagLead(ll) => {
@@ -182,6 +182,8 @@ impl Dataframe {
                     name: Identifier::from_name(None, "count"),
                     args: vec![],
                     params: vec![],
+                    order_by: vec![],
+                    filter: None,
                     window: None,
                     lambda: None,
                 },
@@ -335,6 +337,8 @@ impl Dataframe {
             before_exchange: false,
             limit,
             offset,
+            with_ties: vec![],
+            percent: None,
         };
         self.s_expr =
             SExpr::create_unary(Arc::new(limit_plan.into()), Arc::new(self.s_expr.clone()));
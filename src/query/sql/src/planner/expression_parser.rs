@@ -18,6 +18,7 @@ use databend_common_ast::ast::Expr as AExpr;
 use databend_common_ast::parser::parse_comma_separated_exprs;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_catalog::catalog::CATALOG_DEFAULT;
+use databend_common_catalog::plan::CheckConstraintExpr;
 use databend_common_catalog::plan::Filters;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
@@ -237,6 +238,47 @@ pub fn parse_computed_expr(
     Ok(expr)
 }
 
+/// Binds the CHECK constraints of a table to the columns of `schema`.
+///
+/// Constraints referring to columns missing from `schema` are skipped, they
+/// are validated later in the pipeline, once all the columns are filled.
+pub fn parse_check_constraints(
+    ctx: Arc<dyn TableContext>,
+    table_info: &TableInfo,
+    schema: DataSchemaRef,
+) -> Result<Vec<CheckConstraintExpr>> {
+    let table_schema = table_info.schema();
+    let mut checks = vec![];
+    for (name, constraint) in &table_info.meta.constraints {
+        let Some(expr) = constraint.check_expr() else {
+            continue;
+        };
+        let mut columns = Vec::with_capacity(constraint.column_ids().len());
+        for column_id in constraint.column_ids() {
+            let field = table_schema.field_of_column_id(*column_id)?;
+            if let Ok(offset) = schema.index_of(field.name()) {
+                columns.push((field.name().clone(), offset));
+            }
+        }
+        if columns.len() != constraint.column_ids().len() {
+            continue;
+        }
+
+        let violation = parse_computed_expr(
+            ctx.clone(),
+            schema.clone(),
+            &format!("is_true(NOT ({expr}))"),
+        )?;
+        checks.push(CheckConstraintExpr {
+            name: name.clone(),
+            expr: expr.to_string(),
+            columns,
+            violation: violation.as_remote_expr(),
+        });
+    }
+    Ok(checks)
+}
+
 pub fn parse_default_expr_to_string(
     ctx: Arc<dyn TableContext>,
     field: &TableField,
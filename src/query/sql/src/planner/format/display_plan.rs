@@ -57,6 +57,7 @@ impl Plan {
 
             // Tables
             Plan::CreateTable(create_table) => format_create_table(create_table),
+            Plan::CloneTable(_) => Ok("CloneTable".to_string()),
             Plan::ShowCreateTable(_) => Ok("ShowCreateTable".to_string()),
             Plan::DropTable(_) => Ok("DropTable".to_string()),
             Plan::UndropTable(_) => Ok("UndropTable".to_string()),
@@ -69,10 +70,13 @@ impl Plan {
             Plan::AddTableColumn(_) => Ok("AddTableColumn".to_string()),
             Plan::ModifyTableColumn(_) => Ok("ModifyTableColumn".to_string()),
             Plan::DropTableColumn(_) => Ok("DropTableColumn".to_string()),
+            Plan::AddTableConstraint(_) => Ok("AddTableConstraint".to_string()),
+            Plan::DropTableConstraint(_) => Ok("DropTableConstraint".to_string()),
             Plan::AlterTableClusterKey(_) => Ok("AlterTableClusterKey".to_string()),
             Plan::DropTableClusterKey(_) => Ok("DropTableClusterKey".to_string()),
             Plan::ReclusterTable { .. } => Ok("ReclusterTable".to_string()),
             Plan::TruncateTable(_) => Ok("TruncateTable".to_string()),
+            Plan::WarmTableCache(_) => Ok("WarmTableCache".to_string()),
             Plan::OptimizePurge(_) => Ok("OptimizePurge".to_string()),
             Plan::OptimizeCompactSegment(_) => Ok("OptimizeCompactSegment".to_string()),
             Plan::OptimizeCompactBlock { .. } => Ok("OptimizeCompactBlock".to_string()),
@@ -139,6 +143,7 @@ impl Plan {
             Plan::DescUser(_) => Ok("DescUser".to_string()),
             Plan::CreateRole(_) => Ok("CreateRole".to_string()),
             Plan::DropRole(_) => Ok("DropRole".to_string()),
+            Plan::AlterRole(_) => Ok("AlterRole".to_string()),
             Plan::Presign(_) => Ok("Presign".to_string()),
 
             Plan::Set(_) => Ok("Set".to_string()),
@@ -147,9 +152,13 @@ impl Plan {
             Plan::SetSecondaryRoles(_) => Ok("SetSecondaryRoles".to_string()),
             Plan::UseDatabase(_) => Ok("UseDatabase".to_string()),
             Plan::Kill(_) => Ok("Kill".to_string()),
+            Plan::Prepare(_) => Ok("Prepare".to_string()),
+            Plan::Deallocate(_) => Ok("Deallocate".to_string()),
 
             Plan::ShowRoles(_) => Ok("ShowRoles".to_string()),
             Plan::RevertTable(_) => Ok("RevertTable".to_string()),
+            Plan::CreateTableRef(_) => Ok("CreateTableRef".to_string()),
+            Plan::DropTableRef(_) => Ok("DropTableRef".to_string()),
 
             // data mask
             Plan::CreateDatamaskPolicy(_) => Ok("CreateDatamaskPolicy".to_string()),
@@ -169,6 +178,11 @@ impl Plan {
             Plan::DropPasswordPolicy(_) => Ok("DropPasswordPolicy".to_string()),
             Plan::DescPasswordPolicy(_) => Ok("DescPasswordPolicy".to_string()),
 
+            // workload group
+            Plan::CreateWorkloadGroup(_) => Ok("CreateWorkloadGroup".to_string()),
+            Plan::AlterWorkloadGroup(_) => Ok("AlterWorkloadGroup".to_string()),
+            Plan::DropWorkloadGroup(_) => Ok("DropWorkloadGroup".to_string()),
+
             // task
             Plan::CreateTask(_) => Ok("CreateTask".to_string()),
             Plan::DropTask(_) => Ok("DropTask".to_string()),
@@ -30,6 +30,7 @@ use crate::plans::Filter;
 use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::plans::Limit;
+use crate::plans::MatchRecognize;
 use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
 use crate::plans::Scan;
@@ -147,6 +148,7 @@ pub(super) fn to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = Inde
         RelOperator::Filter(op) => filter_to_format_tree(id_humanizer, op),
         RelOperator::Aggregate(op) => aggregate_to_format_tree(id_humanizer, op),
         RelOperator::Window(op) => window_to_format_tree(id_humanizer, op),
+        RelOperator::MatchRecognize(op) => match_recognize_to_format_tree(id_humanizer, op),
         RelOperator::Udf(op) => udf_to_format_tree(id_humanizer, op),
         RelOperator::AsyncFunction(op) => async_func_to_format_tree(id_humanizer, op),
         RelOperator::Sort(op) => sort_to_format_tree(id_humanizer, op),
@@ -211,6 +213,8 @@ fn format_join(op: &Join) -> String {
         JoinType::RightMark => "RightMark".to_string(),
         JoinType::LeftSingle => "LeftSingle".to_string(),
         JoinType::RightSingle => "RightSingle".to_string(),
+        JoinType::Asof => "Asof".to_string(),
+        JoinType::LeftAsof => "LeftAsof".to_string(),
     };
 
     format!("Join({})", join_type)
@@ -296,6 +300,49 @@ fn window_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexTyp
     ])
 }
 
+fn match_recognize_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexType>>(
+    _id_humanizer: &I,
+    op: &MatchRecognize,
+) -> FormatTreeNode {
+    let partition_by_items = op
+        .partition_by
+        .iter()
+        .map(|item| format_scalar(&item.scalar))
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    let order_by_items = op
+        .order_by
+        .iter()
+        .map(|item| format_scalar(&item.order_by_item.scalar))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let variables = op
+        .variables
+        .iter()
+        .map(|var| var.name.clone())
+        .collect::<Vec<_>>();
+
+    let measures = op
+        .measures
+        .iter()
+        .map(|item| format!("{} AS (#{})", format_scalar(&item.scalar), item.index))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    FormatTreeNode::with_children("MatchRecognize".to_string(), vec![
+        FormatTreeNode::new(format!("partition items: [{}]", partition_by_items)),
+        FormatTreeNode::new(format!("order by items: [{}]", order_by_items)),
+        FormatTreeNode::new(format!("measures: [{}]", measures)),
+        FormatTreeNode::new(format!("pattern: ({})", op.pattern.display(&variables))),
+        FormatTreeNode::new(format!(
+            "after match skip: {}",
+            op.after_match_skip.display(&variables)
+        )),
+    ])
+}
+
 fn udf_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexType>>(
     _id_humanizer: &I,
     op: &Udf,
@@ -384,6 +431,11 @@ fn sort_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexType>
         .collect::<Vec<String>>()
         .join(", ");
     let limit = op.limit.map_or("NONE".to_string(), |l| l.to_string());
+    let limit = if op.limit_with_ties {
+        format!("{} WITH TIES", limit)
+    } else {
+        limit
+    };
 
     FormatTreeNode::with_children("Sort".to_string(), vec![
         FormatTreeNode::new(format!("sort keys: [{}]", scalars)),
@@ -412,14 +464,33 @@ fn constant_scan_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = I
 }
 
 fn limit_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexType>>(
-    _id_humanizer: &I,
+    id_humanizer: &I,
     op: &Limit,
 ) -> FormatTreeNode {
     let limit = op.limit.unwrap_or_default();
-    FormatTreeNode::with_children("Limit".to_string(), vec![
+    let mut children = vec![
         FormatTreeNode::new(format!("limit: [{}]", limit)),
         FormatTreeNode::new(format!("offset: [{}]", op.offset)),
-    ])
+    ];
+    if let Some(percent) = op.percent {
+        children.push(FormatTreeNode::new(format!("percent: [{}]", percent)));
+    }
+    if !op.with_ties.is_empty() {
+        let with_ties = op
+            .with_ties
+            .iter()
+            .map(|item| {
+                format!(
+                    "{} (#{})",
+                    id_humanizer.humanize_column_id(item.index),
+                    item.index
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+        children.push(FormatTreeNode::new(format!("with ties: [{}]", with_ties)));
+    }
+    FormatTreeNode::with_children("Limit".to_string(), children)
 }
 
 fn exchange_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexType>>(
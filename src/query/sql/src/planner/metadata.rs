@@ -85,6 +85,11 @@ impl Metadata {
         self.tables.as_slice()
     }
 
+    /// Replaces the table of a table entry with another version of the same table.
+    pub fn replace_table(&mut self, index: IndexType, table: Arc<dyn Table>) {
+        self.tables[index].table = table;
+    }
+
     pub fn table_index_by_column_indexes(&self, column_indexes: &ColumnSet) -> Option<IndexType> {
         self.columns.iter().find_map(|v| match v {
             ColumnEntry::BaseTableColumn(BaseTableColumn {
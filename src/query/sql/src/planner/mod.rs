@@ -24,8 +24,10 @@ pub mod binder;
 pub mod dataframe;
 mod expression_parser;
 pub mod optimizer;
+mod placeholder;
 mod planner_cache;
 pub mod plans;
+mod prepared_statement;
 mod stream_column;
 mod udf_validator;
 
@@ -41,6 +43,7 @@ pub use bloom_index::BloomIndexColumns;
 pub use expression_parser::*;
 pub use format::format_scalar;
 pub use metadata::*;
+pub use placeholder::PlaceholderSql;
 pub use planner::get_query_kind;
 pub use planner::PlanExtras;
 pub use planner::Planner;
@@ -89,6 +89,7 @@ impl DefaultCostModel {
             RelOperator::EvalScalar(_)
             | RelOperator::Filter(_)
             | RelOperator::Window(_)
+            | RelOperator::MatchRecognize(_)
             | RelOperator::Sort(_)
             | RelOperator::ProjectSet(_)
             | RelOperator::Udf(_)
@@ -43,6 +43,7 @@ use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::Scan;
 use crate::plans::Sort;
+use crate::plans::Udf;
 use crate::plans::UnionAll;
 use crate::plans::Window;
 use crate::BaseTableColumn;
@@ -189,6 +190,9 @@ impl SubqueryRewriter {
                 flatten_info,
                 need_cross_join,
             ),
+            RelOperator::Udf(udf) => {
+                self.flatten_udf(plan, udf, correlated_columns, flatten_info, need_cross_join)
+            }
             RelOperator::Filter(filter) => self.flatten_filter(
                 filter,
                 plan,
@@ -289,6 +293,48 @@ impl SubqueryRewriter {
         ))
     }
 
+    fn flatten_udf(
+        &mut self,
+        plan: &SExpr,
+        udf: &Udf,
+        correlated_columns: &ColumnSet,
+        flatten_info: &mut FlattenInfo,
+        mut need_cross_join: bool,
+    ) -> Result<SExpr> {
+        if udf
+            .used_columns()?
+            .iter()
+            .any(|index| correlated_columns.contains(index))
+        {
+            need_cross_join = true;
+        }
+        let flatten_plan = self.flatten_plan(
+            plan.child(0)?,
+            correlated_columns,
+            flatten_info,
+            need_cross_join,
+        )?;
+        let mut items = Vec::with_capacity(udf.items.len());
+        for item in udf.items.iter() {
+            items.push(ScalarItem {
+                scalar: self.flatten_scalar(&item.scalar, correlated_columns)?,
+                index: item.index,
+            });
+        }
+        // The input columns are kept in the output, so are the derived columns.
+        Ok(SExpr::create_unary(
+            Arc::new(
+                Udf {
+                    items,
+                    script_udf: udf.script_udf,
+                    table_function: udf.table_function,
+                }
+                .into(),
+            ),
+            Arc::new(flatten_plan),
+        ))
+    }
+
     fn flatten_project_set(
         &mut self,
         plan: &SExpr,
@@ -65,6 +65,7 @@ impl SubqueryRewriter {
                     params: agg.params.clone(),
                     args,
                     return_type: agg.return_type.clone(),
+                    udaf: agg.udaf.clone(),
                 }))
             }
             ScalarExpr::FunctionCall(func) => {
@@ -179,12 +179,13 @@ impl SubqueryRewriter {
                 ))
             }
 
-            RelOperator::Limit(_) | RelOperator::Udf(_) | RelOperator::AsyncFunction(_) => {
-                Ok(SExpr::create_unary(
-                    Arc::new(s_expr.plan().clone()),
-                    Arc::new(self.rewrite(s_expr.child(0)?)?),
-                ))
-            }
+            RelOperator::Limit(_)
+            | RelOperator::Udf(_)
+            | RelOperator::AsyncFunction(_)
+            | RelOperator::MatchRecognize(_) => Ok(SExpr::create_unary(
+                Arc::new(s_expr.plan().clone()),
+                Arc::new(self.rewrite(s_expr.child(0)?)?),
+            )),
 
             RelOperator::DummyTableScan(_)
             | RelOperator::Scan(_)
@@ -451,6 +452,8 @@ impl SubqueryRewriter {
                     limit: Some(1),
                     offset: 0,
                     before_exchange: false,
+                    with_ties: vec![],
+                    percent: None,
                 };
                 subquery_expr =
                     SExpr::create_unary(Arc::new(limit.into()), Arc::new(subquery_expr.clone()));
@@ -476,6 +479,7 @@ impl SubqueryRewriter {
                             params: vec![],
                             args: vec![],
                             return_type: Box::new(agg_func.return_type()?),
+                            udaf: None,
                         }
                         .into(),
                         index: agg_func_index,
@@ -141,6 +141,11 @@ impl SortAndLimitPushDownOptimizer {
         let exchange_sexpr = s_expr.child(0)?;
         let mut limit: Limit = s_expr.plan().clone().try_into()?;
 
+        if !limit.is_plain() {
+            // The rows to return depend on all the rows, keep the limit after exchange.
+            return Ok(s_expr.clone());
+        }
+
         if limit.limit.is_none() {
             if limit.offset != 0 {
                 // Only offset: SELECT number from numbers(1000) offset 100;
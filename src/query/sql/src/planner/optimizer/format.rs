@@ -65,6 +65,7 @@ pub fn display_rel_op(rel_op: &RelOperator) -> String {
         RelOperator::DummyTableScan(_) => "DummyTableScan".to_string(),
         RelOperator::ProjectSet(_) => "ProjectSet".to_string(),
         RelOperator::Window(_) => "WindowFunc".to_string(),
+        RelOperator::MatchRecognize(_) => "MatchRecognize".to_string(),
         RelOperator::CteScan(_) => "CteScan".to_string(),
         RelOperator::MaterializedCte(_) => "MaterializedCte".to_string(),
         RelOperator::ConstantTableScan(s) => s.name().to_string(),
@@ -183,6 +183,7 @@ impl DPhpy {
                         | RelOperator::Limit(_)
                         | RelOperator::ProjectSet(_)
                         | RelOperator::Window(_)
+                        | RelOperator::MatchRecognize(_)
                         | RelOperator::Udf(_)
                 ) {
                     left_is_subquery = true;
@@ -195,6 +196,7 @@ impl DPhpy {
                         | RelOperator::Limit(_)
                         | RelOperator::ProjectSet(_)
                         | RelOperator::Window(_)
+                        | RelOperator::MatchRecognize(_)
                         | RelOperator::Udf(_)
                 ) {
                     right_is_subquery = true;
@@ -276,7 +278,7 @@ impl DPhpy {
                     Ok((new_s_expr, optimized))
                 }
             }
-            RelOperator::UnionAll(_) => {
+            RelOperator::UnionAll(_) | RelOperator::MatchRecognize(_) => {
                 let new_s_expr = self.new_children(s_expr).await?;
                 self.join_relations
                     .push(JoinRelation::new(&new_s_expr, self.sample_executor.clone()));
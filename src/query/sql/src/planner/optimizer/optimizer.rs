@@ -71,6 +71,7 @@ pub struct OptimizerContext {
     enable_join_reorder: bool,
     enable_dphyp: bool,
     planning_agg_index: bool,
+    planning_prepared_statement: bool,
     #[educe(Debug(ignore))]
     sample_executor: Option<Arc<dyn QueryExecutor>>,
 }
@@ -86,6 +87,7 @@ impl OptimizerContext {
             enable_dphyp: true,
             sample_executor: None,
             planning_agg_index: false,
+            planning_prepared_statement: false,
         }
     }
 
@@ -113,6 +115,13 @@ impl OptimizerContext {
         self.planning_agg_index = true;
         self
     }
+
+    /// The plan of a prepared statement is reused after the data is changed, the
+    /// rewrites relying on the exact statistics of the tables are skipped.
+    pub fn with_planning_prepared_statement(mut self) -> Self {
+        self.planning_prepared_statement = true;
+        self
+    }
 }
 
 /// A recursive optimizer that will apply the given rules recursively.
@@ -158,6 +167,9 @@ impl<'a> RecursiveOptimizer<'a> {
     fn apply_transform_rules(&self, s_expr: &SExpr, rules: &[RuleID]) -> Result<SExpr> {
         let mut s_expr = s_expr.clone();
         for rule_id in rules {
+            if self.ctx.planning_prepared_statement && *rule_id == RuleID::FoldCountAggregate {
+                continue;
+            }
             let rule = RuleFactory::create_rule(*rule_id, self.ctx.metadata.clone())?;
             let mut state = TransformResult::new();
             if rule
@@ -346,9 +358,12 @@ pub async fn optimize_query(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -
         s_expr = decorrelate_subquery(opt_ctx.metadata.clone(), s_expr.clone())?;
     }
 
-    s_expr = RuleStatsAggregateOptimizer::new(opt_ctx.table_ctx.clone(), opt_ctx.metadata.clone())
-        .run(&s_expr)
-        .await?;
+    if !opt_ctx.planning_prepared_statement {
+        s_expr =
+            RuleStatsAggregateOptimizer::new(opt_ctx.table_ctx.clone(), opt_ctx.metadata.clone())
+                .run(&s_expr)
+                .await?;
+    }
 
     // Collect statistics for each leaf node in SExpr.
     s_expr = CollectStatisticsOptimizer::new(opt_ctx.table_ctx.clone(), opt_ctx.metadata.clone())
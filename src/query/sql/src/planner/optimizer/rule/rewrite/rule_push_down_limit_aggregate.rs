@@ -84,7 +84,9 @@ impl RulePushDownRankLimitAggregate {
         state: &mut TransformResult,
     ) -> databend_common_exception::Result<()> {
         let limit: Limit = s_expr.plan().clone().try_into()?;
-        if let Some(mut count) = limit.limit {
+        if let Some(mut count) = limit.limit
+            && limit.is_plain()
+        {
             count += limit.offset;
             let agg = s_expr.child(0)?;
             let mut agg_limit: Aggregate = agg.plan().clone().try_into()?;
@@ -103,6 +105,7 @@ impl RulePushDownRankLimitAggregate {
             let sort = Sort {
                 items: sort_items.clone(),
                 limit: Some(count),
+                limit_with_ties: false,
                 after_exchange: None,
                 pre_projection: None,
                 window_partition: vec![],
@@ -139,7 +142,10 @@ impl RulePushDownRankLimitAggregate {
 
         let mut agg_limit: Aggregate = agg_limit_expr.plan().clone().try_into()?;
 
-        if let Some(limit) = sort.limit {
+        // The rank limit of aggregate would drop the groups which tie with the last one.
+        if let Some(limit) = sort.limit
+            && !sort.limit_with_ties
+        {
             let is_order_subset = sort
                 .items
                 .iter()
@@ -70,7 +70,7 @@ impl Rule for RulePushDownLimitOuterJoin {
         state: &mut TransformResult,
     ) -> databend_common_exception::Result<()> {
         let limit: Limit = s_expr.plan().clone().try_into()?;
-        if limit.limit.is_some() {
+        if limit.limit.is_some() && limit.is_plain() {
             let child = s_expr.child(0)?;
             let join: Join = child.plan().clone().try_into()?;
             match join.join_type {
@@ -87,6 +87,8 @@ impl Rule for RulePushDownLimitOuterJoin {
                             before_exchange: limit.before_exchange,
                             limit: limit.limit,
                             offset: 0,
+                            with_ties: vec![],
+                            percent: None,
                         })),
                         Arc::new(child),
                     );
@@ -106,6 +108,8 @@ impl Rule for RulePushDownLimitOuterJoin {
                             before_exchange: limit.before_exchange,
                             limit: limit.limit,
                             offset: 0,
+                            with_ties: vec![],
+                            percent: None,
                         })),
                         child,
                     );
@@ -62,7 +62,9 @@ impl Rule for RulePushDownLimitScan {
 
     fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
         let limit: Limit = s_expr.plan().clone().try_into()?;
-        if let Some(mut count) = limit.limit {
+        if let Some(mut count) = limit.limit
+            && limit.is_plain()
+        {
             let child = s_expr.child(0)?;
             let mut get: Scan = child.plan().clone().try_into()?;
             count += limit.offset;
@@ -69,14 +69,22 @@ impl Rule for RulePushDownLimitSort {
         state: &mut TransformResult,
     ) -> databend_common_exception::Result<()> {
         let limit: Limit = s_expr.plan().clone().try_into()?;
-        if let Some(mut count) = limit.limit {
+        if let Some(mut count) = limit.limit
+            && limit.percent.is_none()
+        {
             count += limit.offset;
             let sort = s_expr.child(0)?;
             let mut sort_limit: Sort = sort.plan().clone().try_into()?;
+            let with_ties = !limit.with_ties.is_empty();
+            if with_ties && sort_limit.items != limit.with_ties {
+                return Ok(());
+            }
             let limit = sort_limit.limit.map_or(count, |c| cmp::max(c, count));
 
             if limit <= self.max_limit {
                 sort_limit.limit = Some(limit);
+                // The sort must keep the rows which tie with the last row within the limit.
+                sort_limit.limit_with_ties |= with_ties;
                 let sort = SExpr::create_unary(
                     Arc::new(RelOperator::Sort(sort_limit)),
                     Arc::new(sort.child(0)?.clone()),
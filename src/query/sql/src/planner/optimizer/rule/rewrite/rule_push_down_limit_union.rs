@@ -61,7 +61,7 @@ impl Rule for RulePushDownLimitUnion {
         let union_s_expr = s_expr.child(0)?;
         let union: UnionAll = union_s_expr.plan().clone().try_into()?;
 
-        if limit.limit.is_none() {
+        if limit.limit.is_none() || !limit.is_plain() {
             return Ok(());
         }
         // Create limit which will be pushed down
@@ -72,6 +72,8 @@ impl Rule for RulePushDownLimitUnion {
                 .map(|origin_limit| cmp::max(origin_limit, limit_offset)),
             offset: 0,
             before_exchange: false,
+            with_ties: vec![],
+            percent: None,
         };
 
         // Push down new_limit to union children
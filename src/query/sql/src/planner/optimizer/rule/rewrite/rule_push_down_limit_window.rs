@@ -71,7 +71,9 @@ impl Rule for RulePushDownLimitWindow {
 
     fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
         let limit: Limit = s_expr.plan().clone().try_into()?;
-        if let Some(mut count) = limit.limit {
+        if let Some(mut count) = limit.limit
+            && limit.is_plain()
+        {
             count += limit.offset;
             let window = s_expr.child(0)?;
             let mut window_limit: LogicalWindow = window.plan().clone().try_into()?;
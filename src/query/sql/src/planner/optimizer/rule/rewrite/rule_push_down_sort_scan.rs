@@ -85,7 +85,9 @@ impl Rule for RulePushDownSortScan {
         if get.order_by.is_none() {
             get.order_by = Some(sort.items);
         }
-        if let Some(limit) = sort.limit {
+        if let Some(limit) = sort.limit
+            && !sort.limit_with_ties
+        {
             get.limit = Some(get.limit.map_or(limit, |c| cmp::max(c, limit)));
         }
 
@@ -127,6 +127,7 @@ fn find_group_by_keys(child: &SExpr, group_by_keys: &mut HashSet<IndexType>) ->
         | RelOperator::Limit(_)
         | RelOperator::Exchange(_)
         | RelOperator::UnionAll(_)
+        | RelOperator::MatchRecognize(_)
         | RelOperator::DummyTableScan(_)
         | RelOperator::ProjectSet(_)
         | RelOperator::MaterializedCte(_)
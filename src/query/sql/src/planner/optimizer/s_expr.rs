@@ -331,6 +331,7 @@ impl SExpr {
             RelOperator::Limit(_)
             | RelOperator::UnionAll(_)
             | RelOperator::Sort(_)
+            | RelOperator::MatchRecognize(_)
             | RelOperator::DummyTableScan(_)
             | RelOperator::CteScan(_)
             | RelOperator::MaterializedCte(_)
@@ -429,6 +430,7 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         RelOperator::Scan(_)
         | RelOperator::Limit(_)
         | RelOperator::Exchange(_)
+        | RelOperator::MatchRecognize(_)
         | RelOperator::UnionAll(_)
         | RelOperator::Sort(_)
         | RelOperator::DummyTableScan(_)
@@ -0,0 +1,111 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+/// A SQL text with `?` placeholders, the parameters of a prepared statement.
+///
+/// The parameters are bound by substituting literals for the placeholders, so that the
+/// bound statement goes through the same parsing and planning as any other query,
+/// including the `INSERT ... VALUES` statements whose values are not parsed as expressions.
+#[derive(Clone, Debug)]
+pub struct PlaceholderSql {
+    sql: String,
+    placeholders: Vec<Range<usize>>,
+}
+
+impl PlaceholderSql {
+    pub fn try_create(sql: &str) -> Result<Self> {
+        let tokens = tokenize_sql(sql)?;
+
+        // `?` is also the JSON key existence operator, it is an operator only between
+        // an operand and a literal, e.g. `v ? 'k'`.
+        let mut placeholders = vec![];
+        for (i, token) in tokens.iter().enumerate() {
+            if token.kind != TokenKind::Placeholder {
+                continue;
+            }
+            let after_operand = i > 0
+                && (tokens[i - 1].kind.is_literal()
+                    || matches!(
+                        tokens[i - 1].kind,
+                        TokenKind::Ident | TokenKind::RParen | TokenKind::RBracket
+                    ));
+            let before_literal = tokens.get(i + 1).is_some_and(|t| t.kind.is_literal());
+            if !(after_operand && before_literal) {
+                placeholders.push(std::ops::Range::from(token.span));
+            }
+        }
+
+        Ok(PlaceholderSql {
+            sql: sql.to_string(),
+            placeholders,
+        })
+    }
+
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    pub fn num_placeholders(&self) -> usize {
+        self.placeholders.len()
+    }
+
+    /// Returns the SQL with the placeholders replaced by `params`, in order.
+    pub fn bind(&self, params: &[Expr]) -> Result<String> {
+        if params.len() != self.placeholders.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Prepared statement expects {} parameters, but got {}",
+                self.placeholders.len(),
+                params.len()
+            )));
+        }
+
+        let mut sql = String::with_capacity(self.sql.len());
+        let mut last = 0;
+        for (placeholder, param) in self.placeholders.iter().zip(params) {
+            sql.push_str(&self.sql[last..placeholder.start]);
+            match param {
+                // Parenthesized, e.g. a negative number must not make a comment after `-`.
+                Expr::Literal { value, .. } if !matches!(value, Literal::Float64(_)) => {
+                    sql.push_str(&param.to_string())
+                }
+                _ => sql.push_str(&format!("({param})")),
+            }
+            last = placeholder.end;
+        }
+        sql.push_str(&self.sql[last..]);
+        Ok(sql)
+    }
+
+    /// Returns the SQL with NULL for every placeholder, to plan the statement before
+    /// the parameters are known.
+    pub fn bind_nulls(&self) -> Result<String> {
+        let nulls = vec![
+            Expr::Literal {
+                span: None,
+                value: Literal::Null,
+            };
+            self.placeholders.len()
+        ];
+        self.bind(&nulls)
+    }
+}
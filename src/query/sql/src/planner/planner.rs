@@ -46,6 +46,7 @@ use crate::plans::Plan;
 use crate::Binder;
 use crate::CountSetOps;
 use crate::Metadata;
+use crate::MetadataRef;
 use crate::NameResolutionContext;
 use crate::VariableNormalizer;
 
@@ -221,6 +222,10 @@ impl Planner {
     #[async_backtrace::framed]
     #[fastrace::trace]
     pub async fn plan_stmt(&mut self, stmt: &Statement) -> Result<Plan> {
+        if let Statement::Execute(stmt) = stmt {
+            return self.plan_execute(stmt).await;
+        }
+
         let start = Instant::now();
         let settings = self.ctx.get_settings();
         // Step 3: Bind AST with catalog, and generate a pure logical SExpr
@@ -266,12 +271,7 @@ impl Planner {
             .attach_query_str(get_query_kind(stmt), stmt.to_mask_sql());
 
         // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
-        let opt_ctx = OptimizerContext::new(self.ctx.clone(), metadata.clone())
-            .with_enable_distributed_optimization(!self.ctx.get_cluster().is_empty())
-            .with_enable_join_reorder(unsafe { !settings.get_disable_join_reorder()? })
-            .with_enable_dphyp(settings.get_enable_dphyp()?)
-            .with_sample_executor(self.query_executor.clone());
-
+        let opt_ctx = self.optimizer_context(metadata)?;
         let optimized_plan = optimize(opt_ctx, plan).await?;
 
         if enable_planner_cache {
@@ -280,6 +280,15 @@ impl Planner {
         Ok(optimized_plan)
     }
 
+    pub(crate) fn optimizer_context(&self, metadata: MetadataRef) -> Result<OptimizerContext> {
+        let settings = self.ctx.get_settings();
+        Ok(OptimizerContext::new(self.ctx.clone(), metadata)
+            .with_enable_distributed_optimization(!self.ctx.get_cluster().is_empty())
+            .with_enable_join_reorder(unsafe { !settings.get_disable_join_reorder()? })
+            .with_enable_dphyp(settings.get_enable_dphyp()?)
+            .with_sample_executor(self.query_executor.clone()))
+    }
+
     fn add_max_rows_limit(&self, statement: &mut Statement) {
         let max_rows = self.ctx.get_settings().get_max_result_rows().unwrap();
         if max_rows == 0 {
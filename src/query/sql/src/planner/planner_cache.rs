@@ -73,14 +73,7 @@ impl Planner {
             return (false, None);
         }
 
-        let mut visitor = TableRefVisitor {
-            ctx: self.ctx.clone(),
-            schema_snapshots: vec![],
-            name_resolution_ctx,
-            cache_miss: false,
-        };
-        stmt.drive(&mut visitor);
-
+        let visitor = self.visit_table_refs(name_resolution_ctx, stmt);
         if visitor.schema_snapshots.is_empty() || visitor.cache_miss {
             return (false, None);
         }
@@ -92,14 +85,7 @@ impl Planner {
                 return (true, None);
             }
 
-            let setting_changes = settings
-                .changes()
-                .iter()
-                .map(|s| (s.key().clone(), s.value().clone()))
-                .sorted_by(|a, b| Ord::cmp(&a.0, &b.0))
-                .collect::<Vec<_>>();
-
-            if setting_changes != plan_item.setting_changes
+            if self.setting_changes() != plan_item.setting_changes
                 || self.ctx.get_all_variables() != plan_item.variables
             {
                 return (true, None);
@@ -124,24 +110,52 @@ impl Planner {
     }
 
     pub fn set_cache(&self, key: String, plan: Plan) {
-        let setting_changes = self
-            .ctx
+        let plan_item = PlanCacheItem {
+            plan,
+            setting_changes: self.setting_changes(),
+            variables: self.ctx.get_all_variables(),
+        };
+        let cache = LazyLock::force(&PLAN_CACHE);
+        cache.insert(key, plan_item);
+    }
+
+    /// Returns true if the plan of the query only depends on the snapshots of its tables,
+    /// see [`TableRefVisitor`].
+    pub(crate) fn is_cacheable_query(
+        &self,
+        name_resolution_ctx: NameResolutionContext,
+        stmt: &Statement,
+    ) -> bool {
+        if !matches!(stmt, Statement::Query(_)) {
+            return false;
+        }
+        let visitor = self.visit_table_refs(name_resolution_ctx, stmt);
+        !visitor.schema_snapshots.is_empty() && !visitor.cache_miss
+    }
+
+    pub(crate) fn setting_changes(&self) -> Vec<(String, ChangeValue)> {
+        self.ctx
             .get_settings()
             .changes()
             .iter()
             .map(|s| (s.key().clone(), s.value().clone()))
             .sorted_by(|a, b| Ord::cmp(&a.0, &b.0))
-            .collect::<Vec<_>>();
-
-        let variables = self.ctx.get_all_variables();
+            .collect::<Vec<_>>()
+    }
 
-        let plan_item = PlanCacheItem {
-            plan,
-            setting_changes,
-            variables,
+    fn visit_table_refs(
+        &self,
+        name_resolution_ctx: NameResolutionContext,
+        stmt: &Statement,
+    ) -> TableRefVisitor {
+        let mut visitor = TableRefVisitor {
+            ctx: self.ctx.clone(),
+            schema_snapshots: vec![],
+            name_resolution_ctx,
+            cache_miss: false,
         };
-        let cache = LazyLock::force(&PLAN_CACHE);
-        cache.insert(key, plan_item);
+        stmt.drive(&mut visitor);
+        visitor
     }
 }
 
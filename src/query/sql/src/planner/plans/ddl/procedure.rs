@@ -84,6 +84,8 @@ impl From<&DropProcedurePlan> for DropProcedureReq {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct CallProcedurePlan {
+    pub name: String,
+    pub language: String,
     pub script: String,
     pub arg_names: Vec<String>,
     pub args: Vec<Expr>,
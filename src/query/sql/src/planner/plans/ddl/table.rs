@@ -17,6 +17,8 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use databend_common_ast::ast::Engine;
+use databend_common_catalog::plan::Filters;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::DataField;
@@ -27,12 +29,14 @@ use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableConstraint;
 use databend_common_meta_app::schema::TableIndex;
 use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_pipeline_core::LockGuard;
+use databend_storages_common_table_meta::table::TableRefKind;
 
 use crate::plans::Plan;
 
@@ -56,6 +60,7 @@ pub struct CreateTablePlan {
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
     pub inverted_indexes: Option<BTreeMap<String, TableIndex>>,
+    pub constraints: BTreeMap<String, TableConstraint>,
 }
 
 impl CreateTablePlan {
@@ -64,6 +69,28 @@ impl CreateTablePlan {
     }
 }
 
+/// Create a table sharing the data of another table, `CREATE TABLE .. CLONE ..`.
+#[derive(Clone, Debug)]
+pub struct CloneTablePlan {
+    pub create_option: CreateOption,
+    pub tenant: Tenant,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+
+    pub source_catalog: String,
+    pub source_database: String,
+    pub source_table: String,
+    /// The point of the source which is cloned, the current snapshot if None.
+    pub point: Option<NavigationPoint>,
+}
+
+impl CloneTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
 /// Desc.
 #[derive(Clone, Debug)]
 pub struct DescribeTablePlan {
@@ -271,6 +298,72 @@ impl UnsetOptionsPlan {
     }
 }
 
+/// Create a tag or a branch of table.
+#[derive(Clone, Debug)]
+pub struct CreateTableRefPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub kind: TableRefKind,
+    pub name: String,
+    /// The snapshot which the ref is created at, the current snapshot if None.
+    pub point: Option<NavigationPoint>,
+    pub retention: Option<Duration>,
+}
+
+impl CreateTableRefPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Drop a tag or a branch of table.
+#[derive(Clone, Debug)]
+pub struct DropTableRefPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub kind: TableRefKind,
+    pub name: String,
+}
+
+impl DropTableRefPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Add a CHECK constraint to a table.
+#[derive(Clone, Debug)]
+pub struct AddTableConstraintPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub name: String,
+    pub constraint: TableConstraint,
+}
+
+impl AddTableConstraintPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Drop a constraint of a table.
+#[derive(Clone, Debug)]
+pub struct DropTableConstraintPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub name: String,
+}
+
+impl DropTableConstraintPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 // Table add column
 #[derive(Clone, Debug)]
 pub struct AddTableColumnPlan {
@@ -404,6 +497,30 @@ impl TruncateTablePlan {
     }
 }
 
+/// Warm the caches of a table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct WarmTableCachePlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    /// The columns to load, all the columns if not specified.
+    pub columns: Option<Vec<String>>,
+    /// Only the blocks that may match the filter are loaded.
+    pub filters: Option<Filters>,
+}
+
+impl WarmTableCachePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::new(vec![
+            DataField::new("node", DataType::String),
+            DataField::new("segments", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("blocks", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("bloom_filters", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("bytes", DataType::Number(NumberDataType::UInt64)),
+        ]))
+    }
+}
+
 /// Undrop.
 #[derive(Clone, Debug)]
 pub struct UndropTablePlan {
@@ -57,6 +57,8 @@ pub struct Insert {
     // it should be provided as some `table_info`.
     // otherwise, the table being inserted will be resolved by using `catalog`.`database`.`table`
     pub table_info: Option<TableInfo>,
+    /// The branch of the table to insert into.
+    pub branch: Option<String>,
 }
 
 impl PartialEq for Insert {
@@ -93,6 +95,7 @@ impl Insert {
             // table_info only used create table as select.
             table_info: _,
             source,
+            branch,
         } = self;
 
         let table_name = format!("{}.{}.{}", catalog, database, table);
@@ -103,11 +106,14 @@ impl Insert {
             .collect::<Vec<_>>()
             .join(",");
 
-        let children = vec![
+        let mut children = vec![
             FormatTreeNode::new(format!("table: {table_name}")),
             FormatTreeNode::new(format!("inserted columns: [{inserted_columns}]")),
             FormatTreeNode::new(format!("overwrite: {overwrite}")),
         ];
+        if let Some(branch) = branch {
+            children.push(FormatTreeNode::new(format!("branch: {branch}")));
+        }
 
         let formatted_plan = format_insert_source("InsertPlan", source, verbose, children)?;
 
@@ -63,6 +63,11 @@ pub enum JoinType {
     /// Single Join is a special kind of join that is used to process correlated scalar subquery.
     LeftSingle,
     RightSingle,
+    /// Asof Join keeps, for each left row, only the nearest right row that satisfies the match condition.
+    /// The match condition is the only non-equi condition of the join.
+    Asof,
+    /// Left Asof Join is the outer version of Asof Join, left rows without a match are padded with nulls.
+    LeftAsof,
 }
 
 impl JoinType {
@@ -96,6 +101,10 @@ impl JoinType {
     pub fn is_mark_join(&self) -> bool {
         matches!(self, JoinType::LeftMark | JoinType::RightMark)
     }
+
+    pub fn is_asof_join(&self) -> bool {
+        matches!(self, JoinType::Asof | JoinType::LeftAsof)
+    }
 }
 
 impl Display for JoinType {
@@ -140,6 +149,12 @@ impl Display for JoinType {
             JoinType::RightSingle => {
                 write!(f, "RIGHT SINGLE")
             }
+            JoinType::Asof => {
+                write!(f, "ASOF")
+            }
+            JoinType::LeftAsof => {
+                write!(f, "LEFT ASOF")
+            }
         }
     }
 }
@@ -454,6 +469,8 @@ impl Join {
             JoinType::RightSemi => f64::min(right_cardinality, inner_join_cardinality),
             JoinType::LeftSingle | JoinType::RightMark | JoinType::LeftAnti => left_cardinality,
             JoinType::RightSingle | JoinType::LeftMark | JoinType::RightAnti => right_cardinality,
+            JoinType::Asof => f64::min(left_cardinality, inner_join_cardinality),
+            JoinType::LeftAsof => left_cardinality,
         };
         // Derive column statistics
         let column_stats = if cardinality == 0.0 {
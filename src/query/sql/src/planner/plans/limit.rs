@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_expression::types::F64;
 
 use crate::optimizer::Distribution;
 use crate::optimizer::RelExpr;
@@ -25,22 +26,38 @@ use crate::optimizer::StatInfo;
 use crate::optimizer::Statistics;
 use crate::plans::Operator;
 use crate::plans::RelOp;
+use crate::plans::SortItem;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Limit {
     pub before_exchange: bool,
     pub limit: Option<usize>,
     pub offset: usize,
+    /// The `ORDER BY` items of `FETCH FIRST ... WITH TIES`, rows which tie with
+    /// the last row on these items are also returned.
+    pub with_ties: Vec<SortItem>,
+    /// The percentage of rows to return for `FETCH FIRST ... PERCENT`.
+    pub percent: Option<F64>,
 }
 
 impl Limit {
+    /// Whether the number of output rows depends on the values of the input,
+    /// such a limit cannot be pushed down as a plain limit.
+    pub fn is_plain(&self) -> bool {
+        self.with_ties.is_empty() && self.percent.is_none()
+    }
+
     pub fn derive_limit_stats(&self, stat_info: Arc<StatInfo>) -> Result<Arc<StatInfo>> {
-        let cardinality = match self.limit {
+        let limit = match self.percent {
+            Some(percent) => Some((stat_info.cardinality * percent.0 / 100.0).ceil() as usize),
+            None => self.limit,
+        };
+        let cardinality = match limit {
             Some(limit) if (limit as f64) < stat_info.cardinality => limit as f64,
             _ => stat_info.cardinality,
         };
         let precise_cardinality = match (self.limit, stat_info.statistics.precise_cardinality) {
-            (Some(limit), Some(pc)) => {
+            (Some(limit), Some(pc)) if self.is_plain() => {
                 Some((pc.saturating_sub(self.offset as u64)).min(limit as u64))
             }
             _ => None,
@@ -0,0 +1,323 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::AggregateFunction;
+use crate::binder::WindowOrderByInfo;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::IndexType;
+
+/// Row pattern recognition of `MATCH_RECOGNIZE`.
+///
+/// The input is sorted by the partition and order by items, the rows of each partition
+/// are matched against the pattern, and the measures are computed for each match.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MatchRecognize {
+    pub partition_by: Vec<ScalarItem>,
+    pub order_by: Vec<WindowOrderByInfo>,
+    // `PREV(expr, n)` and `NEXT(expr, n)` referenced by the pattern variable definitions
+    pub navigations: Vec<MatchNavigation>,
+    // pattern variables, the index in this list identifies a variable
+    pub variables: Vec<PatternVariable>,
+    pub pattern: MatchPattern,
+    // match functions referenced by the measures, such as `FIRST(A.price)`, `COUNT(*)`
+    pub functions: Vec<MatchFunctionItem>,
+    // measures, which are computed from the match functions
+    pub measures: Vec<ScalarItem>,
+    pub all_rows_per_match: bool,
+    pub after_match_skip: AfterMatchSkip,
+}
+
+impl MatchRecognize {
+    pub fn used_columns(&self) -> ColumnSet {
+        let mut used_columns = ColumnSet::new();
+        for part in self.partition_by.iter() {
+            used_columns.insert(part.index);
+            used_columns.extend(part.scalar.used_columns());
+        }
+        for order in self.order_by.iter() {
+            used_columns.insert(order.order_by_item.index);
+            used_columns.extend(order.order_by_item.scalar.used_columns());
+        }
+        for variable in self.variables.iter() {
+            if let Some(condition) = &variable.condition {
+                used_columns.extend(condition.used_columns());
+            }
+        }
+        // The navigation columns are computed by the operator itself.
+        for navigation in self.navigations.iter() {
+            used_columns.remove(&navigation.index);
+            used_columns.insert(navigation.arg);
+        }
+        for item in self.functions.iter() {
+            used_columns.extend(item.func.used_columns());
+        }
+        used_columns
+    }
+}
+
+impl Operator for MatchRecognize {
+    fn rel_op(&self) -> RelOp {
+        RelOp::MatchRecognize
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        let mut required = required.clone();
+        if self.partition_by.is_empty() {
+            required.distribution = Distribution::Serial;
+        }
+        Ok(required)
+    }
+
+    fn compute_required_prop_children(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        required: &RequiredProperty,
+    ) -> Result<Vec<Vec<RequiredProperty>>> {
+        let mut required = required.clone();
+        if self.partition_by.is_empty() {
+            required.distribution = Distribution::Serial;
+        }
+        Ok(vec![vec![required]])
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        // Derive output columns
+        let mut output_columns = if self.all_rows_per_match {
+            input_prop.output_columns.clone()
+        } else {
+            self.partition_by.iter().map(|item| item.index).collect()
+        };
+        output_columns.extend(self.measures.iter().map(|item| item.index));
+
+        // Derive outer columns
+        let outer_columns = input_prop
+            .outer_columns
+            .difference(&output_columns)
+            .cloned()
+            .collect();
+
+        // Derive used columns
+        let mut used_columns = self.used_columns();
+        used_columns.extend(input_prop.used_columns.clone());
+
+        // The rows of overlapping matches are not ordered.
+        Ok(Arc::new(RelationalProperty {
+            output_columns,
+            outer_columns,
+            used_columns,
+            orderings: vec![],
+            partition_orderings: None,
+        }))
+    }
+
+    fn derive_stats(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        rel_expr.derive_cardinality_child(0)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MatchNavigation {
+    pub index: IndexType,
+    pub arg: IndexType,
+    /// Is `PREV` or `NEXT`.
+    pub is_prev: bool,
+    pub offset: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PatternVariable {
+    pub name: String,
+    // the condition of `DEFINE`, a variable without definition matches any row
+    pub condition: Option<ScalarExpr>,
+}
+
+/// The row pattern, the variables are referenced by the index in `MatchRecognize.variables`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MatchPattern {
+    Variable(usize),
+    Concat(Vec<MatchPattern>),
+    Alternation(Vec<MatchPattern>),
+    Repetition {
+        pattern: Box<MatchPattern>,
+        min: u64,
+        max: Option<u64>,
+    },
+}
+
+impl MatchPattern {
+    pub fn display(&self, variables: &[String]) -> String {
+        match self {
+            MatchPattern::Variable(var) => variables[*var].clone(),
+            MatchPattern::Concat(patterns) => patterns
+                .iter()
+                .map(|pattern| pattern.display_operand(variables))
+                .collect::<Vec<_>>()
+                .join(" "),
+            MatchPattern::Alternation(patterns) => patterns
+                .iter()
+                .map(|pattern| pattern.display_operand(variables))
+                .collect::<Vec<_>>()
+                .join(" | "),
+            MatchPattern::Repetition { pattern, min, max } => {
+                let pattern = match pattern.as_ref() {
+                    MatchPattern::Variable(_) => pattern.display(variables),
+                    _ => format!("({})", pattern.display(variables)),
+                };
+                match (min, max) {
+                    (0, None) => format!("{pattern}*"),
+                    (1, None) => format!("{pattern}+"),
+                    (0, Some(1)) => format!("{pattern}?"),
+                    (min, None) => format!("{pattern}{{{min},}}"),
+                    (min, Some(max)) if min == max => format!("{pattern}{{{min}}}"),
+                    (min, Some(max)) => format!("{pattern}{{{min},{max}}}"),
+                }
+            }
+        }
+    }
+
+    fn display_operand(&self, variables: &[String]) -> String {
+        match self {
+            MatchPattern::Concat(_) | MatchPattern::Alternation(_) => {
+                format!("({})", self.display(variables))
+            }
+            _ => self.display(variables),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AfterMatchSkip {
+    PastLastRow,
+    ToNextRow,
+    ToFirst(usize),
+    ToLast(usize),
+}
+
+impl AfterMatchSkip {
+    pub fn display(&self, variables: &[String]) -> String {
+        match self {
+            AfterMatchSkip::PastLastRow => "PAST LAST ROW".to_string(),
+            AfterMatchSkip::ToNextRow => "TO NEXT ROW".to_string(),
+            AfterMatchSkip::ToFirst(var) => format!("TO FIRST {}", variables[*var]),
+            AfterMatchSkip::ToLast(var) => format!("TO LAST {}", variables[*var]),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MatchFunctionItem {
+    pub index: IndexType,
+    pub func: MatchFunction,
+}
+
+/// The functions over the rows of a match, `variable` restricts the rows to the ones
+/// mapped to the pattern variable.
+///
+/// With `ALL ROWS PER MATCH`, the functions are computed over the rows from the start
+/// of the match to the current row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MatchFunction {
+    /// `MATCH_NUMBER()`
+    MatchNumber,
+    /// `CLASSIFIER()`
+    Classifier,
+    /// `FIRST(expr)`
+    First {
+        variable: Option<usize>,
+        arg: IndexType,
+        return_type: Box<DataType>,
+    },
+    /// `LAST(expr)`, or a column referenced directly by the measure
+    Last {
+        variable: Option<usize>,
+        arg: IndexType,
+        return_type: Box<DataType>,
+    },
+    Aggregate {
+        variable: Option<usize>,
+        agg: AggregateFunction,
+    },
+}
+
+impl MatchFunction {
+    pub fn used_columns(&self) -> ColumnSet {
+        match self {
+            MatchFunction::MatchNumber | MatchFunction::Classifier => ColumnSet::new(),
+            MatchFunction::First { arg, .. } | MatchFunction::Last { arg, .. } => {
+                ColumnSet::from([*arg])
+            }
+            MatchFunction::Aggregate { agg, .. } => {
+                agg.args.iter().flat_map(|arg| arg.used_columns()).collect()
+            }
+        }
+    }
+
+    pub fn return_type(&self) -> DataType {
+        match self {
+            MatchFunction::MatchNumber => DataType::Number(NumberDataType::UInt64),
+            MatchFunction::Classifier => DataType::String,
+            MatchFunction::First { return_type, .. } | MatchFunction::Last { return_type, .. } => {
+                (**return_type).clone()
+            }
+            MatchFunction::Aggregate { agg, .. } => (*agg.return_type).clone(),
+        }
+    }
+
+    pub fn display(&self, variables: &[String]) -> String {
+        let variable = |variable: &Option<usize>| match variable {
+            Some(var) => format!("{}.", variables[*var]),
+            None => "".to_string(),
+        };
+        match self {
+            MatchFunction::MatchNumber => "MATCH_NUMBER()".to_string(),
+            MatchFunction::Classifier => "CLASSIFIER()".to_string(),
+            MatchFunction::First {
+                variable: var, arg, ..
+            } => format!("FIRST({}#{arg})", variable(var)),
+            MatchFunction::Last {
+                variable: var, arg, ..
+            } => format!("LAST({}#{arg})", variable(var)),
+            MatchFunction::Aggregate { variable: var, agg } => {
+                format!("{}{}", variable(var), agg.display_name)
+            }
+        }
+    }
+}
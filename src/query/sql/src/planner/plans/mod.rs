@@ -32,12 +32,14 @@ mod insert_multi_table;
 mod join;
 mod kill;
 mod limit;
+mod match_recognize;
 mod materialized_cte;
 mod mutation;
 mod mutation_source;
 mod operator;
 mod optimize;
 mod plan;
+mod prepare;
 mod presign;
 mod project_set;
 mod r_cte_scan;
@@ -74,6 +76,7 @@ pub use insert_multi_table::*;
 pub use join::*;
 pub use kill::KillPlan;
 pub use limit::*;
+pub use match_recognize::*;
 pub use materialized_cte::MaterializedCte;
 pub use mutation::MatchedEvaluator;
 pub use mutation::Mutation;
@@ -85,6 +88,7 @@ pub use mutation_source::MutationSource;
 pub use operator::*;
 pub use optimize::*;
 pub use plan::*;
+pub use prepare::*;
 pub use presign::*;
 pub use project_set::*;
 pub use r_cte_scan::*;
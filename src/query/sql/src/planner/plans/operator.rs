@@ -38,6 +38,7 @@ use crate::plans::ExpressionScan;
 use crate::plans::Filter;
 use crate::plans::Join;
 use crate::plans::Limit;
+use crate::plans::MatchRecognize;
 use crate::plans::Mutation;
 use crate::plans::OptimizeCompactBlock;
 use crate::plans::ProjectSet;
@@ -46,7 +47,9 @@ use crate::plans::Scan;
 use crate::plans::Sort;
 use crate::plans::Udf;
 use crate::plans::UnionAll;
+use crate::plans::VisitorMut;
 use crate::plans::Window;
+use crate::plans::WindowFuncType;
 
 pub trait Operator {
     /// Get relational operator kind
@@ -109,6 +112,7 @@ pub enum RelOp {
     UnionAll,
     DummyTableScan,
     Window,
+    MatchRecognize,
     ProjectSet,
     MaterializedCte,
     ConstantTableScan,
@@ -141,6 +145,7 @@ pub enum RelOperator {
     UnionAll(UnionAll),
     DummyTableScan(DummyTableScan),
     Window(Window),
+    MatchRecognize(MatchRecognize),
     ProjectSet(ProjectSet),
     MaterializedCte(MaterializedCte),
     ConstantTableScan(ConstantTableScan),
@@ -170,6 +175,7 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.rel_op(),
             RelOperator::ProjectSet(rel_op) => rel_op.rel_op(),
             RelOperator::Window(rel_op) => rel_op.rel_op(),
+            RelOperator::MatchRecognize(rel_op) => rel_op.rel_op(),
             RelOperator::CteScan(rel_op) => rel_op.rel_op(),
             RelOperator::MaterializedCte(rel_op) => rel_op.rel_op(),
             RelOperator::ConstantTableScan(rel_op) => rel_op.rel_op(),
@@ -199,6 +205,7 @@ impl Operator for RelOperator {
             RelOperator::UnionAll(rel_op) => rel_op.arity(),
             RelOperator::DummyTableScan(rel_op) => rel_op.arity(),
             RelOperator::Window(rel_op) => rel_op.arity(),
+            RelOperator::MatchRecognize(rel_op) => rel_op.arity(),
             RelOperator::ProjectSet(rel_op) => rel_op.arity(),
             RelOperator::MaterializedCte(rel_op) => rel_op.arity(),
             RelOperator::ConstantTableScan(rel_op) => rel_op.arity(),
@@ -228,6 +235,7 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::MatchRecognize(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::CteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::MaterializedCte(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
@@ -257,6 +265,7 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::MatchRecognize(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::CteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::MaterializedCte(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
@@ -286,6 +295,7 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_stats(rel_expr),
+            RelOperator::MatchRecognize(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::CteScan(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::MaterializedCte(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_stats(rel_expr),
@@ -342,6 +352,9 @@ impl Operator for RelOperator {
             RelOperator::Window(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::MatchRecognize(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
             RelOperator::ProjectSet(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
@@ -427,6 +440,9 @@ impl Operator for RelOperator {
             RelOperator::Window(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
+            RelOperator::MatchRecognize(rel_op) => {
+                rel_op.compute_required_prop_children(ctx, rel_expr, required)
+            }
             RelOperator::ProjectSet(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
@@ -630,6 +646,26 @@ impl TryFrom<RelOperator> for Window {
     }
 }
 
+impl From<MatchRecognize> for RelOperator {
+    fn from(v: MatchRecognize) -> Self {
+        Self::MatchRecognize(v)
+    }
+}
+
+impl TryFrom<RelOperator> for MatchRecognize {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::MatchRecognize(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(format!(
+                "Cannot downcast {:?} to MatchRecognize",
+                value.rel_op()
+            )))
+        }
+    }
+}
+
 impl From<Sort> for RelOperator {
     fn from(v: Sort) -> Self {
         Self::Sort(v)
@@ -913,3 +949,186 @@ impl TryFrom<RelOperator> for MutationSource {
         }
     }
 }
+
+/// Visits the scalar expressions of an operator, without its children.
+pub fn walk_operator_mut<'a, V: VisitorMut<'a>>(
+    visitor: &mut V,
+    operator: &'a mut RelOperator,
+) -> Result<()> {
+    match operator {
+        RelOperator::Scan(scan) => {
+            for predicate in scan.push_down_predicates.iter_mut().flatten() {
+                visitor.visit(predicate)?;
+            }
+            if let Some(prewhere) = &mut scan.prewhere {
+                for predicate in prewhere.predicates.iter_mut() {
+                    visitor.visit(predicate)?;
+                }
+            }
+            if let Some(agg_index) = &mut scan.agg_index {
+                for predicate in agg_index.predicates.iter_mut() {
+                    visitor.visit(predicate)?;
+                }
+                for item in agg_index.selection.iter_mut() {
+                    visitor.visit(&mut item.scalar)?;
+                }
+            }
+        }
+        RelOperator::Join(join) => {
+            for condition in join.equi_conditions.iter_mut() {
+                visitor.visit(&mut condition.left)?;
+                visitor.visit(&mut condition.right)?;
+            }
+            for condition in join.non_equi_conditions.iter_mut() {
+                visitor.visit(condition)?;
+            }
+        }
+        RelOperator::EvalScalar(eval_scalar) => {
+            for item in eval_scalar.items.iter_mut() {
+                visitor.visit(&mut item.scalar)?;
+            }
+        }
+        RelOperator::Filter(filter) => {
+            for predicate in filter.predicates.iter_mut() {
+                visitor.visit(predicate)?;
+            }
+        }
+        RelOperator::Aggregate(aggregate) => {
+            for item in aggregate
+                .group_items
+                .iter_mut()
+                .chain(aggregate.aggregate_functions.iter_mut())
+            {
+                visitor.visit(&mut item.scalar)?;
+            }
+        }
+        RelOperator::Sort(sort) => {
+            for item in sort.window_partition.iter_mut() {
+                visitor.visit(&mut item.scalar)?;
+            }
+        }
+        RelOperator::Exchange(Exchange::Hash(keys)) => {
+            for key in keys.iter_mut() {
+                visitor.visit(key)?;
+            }
+        }
+        RelOperator::UnionAll(union_all) => {
+            for output in union_all
+                .left_outputs
+                .iter_mut()
+                .chain(union_all.right_outputs.iter_mut())
+                .filter_map(|(_, output)| output.as_mut())
+            {
+                visitor.visit(output)?;
+            }
+        }
+        RelOperator::Window(window) => {
+            match &mut window.function {
+                WindowFuncType::Aggregate(agg) => visitor.visit_aggregate_function(agg)?,
+                WindowFuncType::LagLead(lag_lead) => {
+                    visitor.visit(&mut lag_lead.arg)?;
+                    if let Some(default) = &mut lag_lead.default {
+                        visitor.visit(default)?;
+                    }
+                }
+                WindowFuncType::NthValue(nth_value) => visitor.visit(&mut nth_value.arg)?,
+                WindowFuncType::RowNumber
+                | WindowFuncType::CumeDist
+                | WindowFuncType::Rank
+                | WindowFuncType::DenseRank
+                | WindowFuncType::PercentRank
+                | WindowFuncType::Ntile(_) => {}
+            }
+            for item in window
+                .arguments
+                .iter_mut()
+                .chain(window.partition_by.iter_mut())
+            {
+                visitor.visit(&mut item.scalar)?;
+            }
+            for order_by in window.order_by.iter_mut() {
+                visitor.visit(&mut order_by.order_by_item.scalar)?;
+            }
+        }
+        RelOperator::MatchRecognize(match_recognize) => {
+            for item in match_recognize
+                .partition_by
+                .iter_mut()
+                .chain(match_recognize.measures.iter_mut())
+            {
+                visitor.visit(&mut item.scalar)?;
+            }
+            for order_by in match_recognize.order_by.iter_mut() {
+                visitor.visit(&mut order_by.order_by_item.scalar)?;
+            }
+            for condition in match_recognize
+                .variables
+                .iter_mut()
+                .filter_map(|var| var.condition.as_mut())
+            {
+                visitor.visit(condition)?;
+            }
+        }
+        RelOperator::ProjectSet(project_set) => {
+            for item in project_set.srfs.iter_mut() {
+                visitor.visit(&mut item.scalar)?;
+            }
+        }
+        RelOperator::ExpressionScan(expression_scan) => {
+            for value in expression_scan.values.iter_mut().flatten() {
+                visitor.visit(value)?;
+            }
+        }
+        RelOperator::Udf(udf) => {
+            for item in udf.items.iter_mut() {
+                visitor.visit(&mut item.scalar)?;
+            }
+        }
+        RelOperator::AsyncFunction(async_function) => {
+            for item in async_function.items.iter_mut() {
+                visitor.visit(&mut item.scalar)?;
+            }
+        }
+        RelOperator::Mutation(mutation) => {
+            for evaluator in mutation.matched_evaluators.iter_mut() {
+                if let Some(condition) = &mut evaluator.condition {
+                    visitor.visit(condition)?;
+                }
+                for value in evaluator
+                    .update
+                    .iter_mut()
+                    .flat_map(|update| update.values_mut())
+                {
+                    visitor.visit(value)?;
+                }
+            }
+            for evaluator in mutation.unmatched_evaluators.iter_mut() {
+                if let Some(condition) = &mut evaluator.condition {
+                    visitor.visit(condition)?;
+                }
+                for value in evaluator.values.iter_mut() {
+                    visitor.visit(value)?;
+                }
+            }
+            if let Some(filter) = &mut mutation.direct_filter {
+                visitor.visit(filter)?;
+            }
+        }
+        RelOperator::MutationSource(mutation_source) => {
+            if let Some(filter) = &mut mutation_source.filter {
+                visitor.visit(filter)?;
+            }
+        }
+        RelOperator::Exchange(Exchange::Broadcast | Exchange::Merge | Exchange::MergeSort)
+        | RelOperator::CteScan(_)
+        | RelOperator::Limit(_)
+        | RelOperator::DummyTableScan(_)
+        | RelOperator::MaterializedCte(_)
+        | RelOperator::ConstantTableScan(_)
+        | RelOperator::CacheScan(_)
+        | RelOperator::RecursiveCteScan(_)
+        | RelOperator::Recluster(_)
+        | RelOperator::CompactBlock(_) => {}
+    }
+    Ok(())
+}
@@ -612,10 +612,21 @@ pub struct AggregateFunction {
     pub params: Vec<Scalar>,
     pub args: Vec<ScalarExpr>,
     pub return_type: Box<DataType>,
+    /// Set when `func_name` is a user-defined aggregate function.
+    pub udaf: Option<UDAFDesc>,
 
     pub display_name: String,
 }
 
+/// The script and state of a user-defined aggregate function.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct UDAFDesc {
+    pub udf_type: UDFType,
+    pub state_names: Vec<String>,
+    pub state_types: Vec<DataType>,
+    pub return_type: DataType,
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct LagLeadFunction {
     /// Is `lag` or `lead`.
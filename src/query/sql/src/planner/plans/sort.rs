@@ -33,6 +33,8 @@ use crate::IndexType;
 pub struct Sort {
     pub items: Vec<SortItem>,
     pub limit: Option<usize>,
+    /// If the rows which tie with the last row within the limit are also returned.
+    pub limit_with_ties: bool,
 
     /// If the sort plan is after the exchange plan.
     /// It's [None] if the sorting plan is in single node mode.
@@ -31,6 +31,9 @@ use crate::plans::ScalarItem;
 pub struct Udf {
     pub items: Vec<ScalarItem>,
     pub script_udf: bool,
+    /// Evaluates the single item as a script table function: the item returns the
+    /// rows produced for each input row, and the input row is repeated for each of them.
+    pub table_function: bool,
 }
 
 impl Udf {
@@ -0,0 +1,402 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::time::Instant;
+
+use databend_common_ast::ast::ExecuteStmt;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall as AstFunctionCall;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Statement;
+use databend_common_catalog::catalog::CatalogManager;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableSchemaRef;
+use databend_common_settings::ChangeValue;
+use databend_storages_common_cache::CacheAccessor;
+use databend_storages_common_cache::CacheValue;
+use databend_storages_common_cache::InMemoryLruCache;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use log::info;
+use parking_lot::RwLock;
+
+use crate::binder::check_prepared_statement;
+use crate::optimizer::optimize;
+use crate::optimizer::SExpr;
+use crate::planner::get_query_kind;
+use crate::plans::walk_expr_mut;
+use crate::plans::walk_operator_mut;
+use crate::plans::CastExpr;
+use crate::plans::ConstantExpr;
+use crate::plans::Plan;
+use crate::plans::ScalarExpr;
+use crate::plans::SubqueryExpr;
+use crate::plans::VisitorMut;
+use crate::BindContext;
+use crate::Binder;
+use crate::Metadata;
+use crate::MetadataRef;
+use crate::NameResolutionContext;
+use crate::Planner;
+
+/// The name of the function standing for a parameter in the cached plan of a prepared
+/// statement, `prepared_param(<index>, <value of the first execution>)`.
+const PREPARED_PARAM_FUNC: &str = "prepared_param";
+
+/// A cached plan is re-optimized once the number of rows of one of its tables
+/// changed by more than this ratio since it was planned.
+const REPLAN_ROWS_CHANGE_RATIO: f64 = 0.2;
+
+#[derive(Clone)]
+pub struct PreparedPlanCacheItem {
+    plan: Plan,
+    setting_changes: Vec<(String, ChangeValue)>,
+    variables: HashMap<String, Scalar>,
+    tables: Vec<PreparedTable>,
+}
+
+/// The version of a table which the cached plan was optimized with.
+#[derive(Clone)]
+struct PreparedTable {
+    table_id: u64,
+    schema: TableSchemaRef,
+    snapshot_location: Option<String>,
+    num_rows: u64,
+}
+
+static PREPARED_PLAN_CACHE: LazyLock<InMemoryLruCache<PreparedPlanCacheItem>> =
+    LazyLock::new(|| InMemoryLruCache::with_items_capacity("prepared_plan_cache".to_string(), 512));
+
+impl From<PreparedPlanCacheItem> for CacheValue<PreparedPlanCacheItem> {
+    fn from(val: PreparedPlanCacheItem) -> Self {
+        CacheValue::new(val, 1024)
+    }
+}
+
+impl Planner {
+    /// Plans `EXECUTE <name> USING <params>`.
+    ///
+    /// A query is planned once with placeholders standing for the parameters, the
+    /// optimized plan is cached and reused by the executions with parameters of the
+    /// same types. It's planned again when the number of rows of its tables changed
+    /// significantly. The other statements are planned with the parameters bound.
+    #[async_backtrace::framed]
+    pub(crate) async fn plan_execute(&mut self, stmt: &ExecuteStmt) -> Result<Plan> {
+        let start = Instant::now();
+        let settings = self.ctx.get_settings();
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let mut binder = Binder::new(
+            self.ctx.clone(),
+            CatalogManager::instance(),
+            name_resolution_ctx.clone(),
+            Arc::new(RwLock::new(Metadata::default())),
+        )
+        .with_subquery_executor(self.query_executor.clone());
+        let placeholder_sql = binder.get_prepared_statement(&stmt.name)?;
+        let params = binder.bind_execute_params(&mut BindContext::new(), &stmt.params)?;
+
+        let literal = self.parse_sql(&placeholder_sql.bind(&stmt.params)?)?;
+        check_prepared_statement(&literal.statement)?;
+        if !settings.get_enable_planner_cache()?
+            || !self.is_cacheable_query(name_resolution_ctx.clone(), &literal.statement)
+        {
+            return self.plan_stmt(&literal.statement).await;
+        }
+
+        let param_exprs = stmt
+            .params
+            .iter()
+            .enumerate()
+            .map(|(i, param)| prepared_param_expr(i, param))
+            .collect::<Vec<_>>();
+        let param_sql = placeholder_sql.bind(&param_exprs)?;
+        let param_types = params.iter().map(|(_, ty)| ty).collect::<Vec<_>>();
+        let key = Self::planner_cache_key(&format!(
+            "{}.{}\n{}\n{:?}",
+            self.ctx.get_current_catalog(),
+            self.ctx.get_current_database(),
+            placeholder_sql.sql(),
+            param_types
+        ));
+
+        let plan = match self.get_prepared_plan(&key).await? {
+            Some(plan) => {
+                info!(
+                    "prepared statement plan from cache, time used: {:?}",
+                    start.elapsed()
+                );
+                plan
+            }
+            None => {
+                let stmt = self.parse_sql(&param_sql)?.statement;
+                let plan = match self.plan_prepared_query(name_resolution_ctx, &stmt).await {
+                    Ok(plan) => plan,
+                    // The parameters might be required to be constants, e.g. `LIMIT ?`.
+                    Err(e) => {
+                        info!("prepared statement is planned with the parameters bound: {e}");
+                        return self.plan_stmt(&literal.statement).await;
+                    }
+                };
+                self.set_prepared_plan(key, &plan);
+                plan
+            }
+        };
+
+        self.ctx.attach_query_str(
+            get_query_kind(&literal.statement),
+            literal.statement.to_mask_sql(),
+        );
+        match plan {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                rewrite_kind,
+                formatted_ast,
+                ignore_result,
+            } => Ok(Plan::Query {
+                s_expr: Box::new(bind_prepared_params(&s_expr, &params)?),
+                metadata,
+                bind_context,
+                rewrite_kind,
+                // The query result cache is keyed by the values of the parameters.
+                formatted_ast: formatted_ast.map(|_| literal.statement.to_string()),
+                ignore_result,
+            }),
+            _ => Err(ErrorCode::Internal(
+                "The cached plan of a prepared statement must be a query",
+            )),
+        }
+    }
+
+    async fn plan_prepared_query(
+        &mut self,
+        name_resolution_ctx: NameResolutionContext,
+        stmt: &Statement,
+    ) -> Result<Plan> {
+        let metadata = Arc::new(RwLock::new(Metadata::default()));
+        let name_resolution_ctx = NameResolutionContext {
+            allow_internal_functions: true,
+            ..name_resolution_ctx
+        };
+        let binder = Binder::new(
+            self.ctx.clone(),
+            CatalogManager::instance(),
+            name_resolution_ctx,
+            metadata.clone(),
+        )
+        .with_subquery_executor(self.query_executor.clone());
+        let plan = binder.bind(stmt).await?;
+
+        let opt_ctx = self
+            .optimizer_context(metadata)?
+            .with_planning_prepared_statement();
+        let plan = optimize(opt_ctx, plan).await?;
+        match plan {
+            Plan::Query { .. } => Ok(plan),
+            _ => Err(ErrorCode::Internal(
+                "The plan of a prepared query must be a query",
+            )),
+        }
+    }
+
+    // Returns a copy of the cached plan with the tables of this query.
+    async fn get_prepared_plan(&self, key: &str) -> Result<Option<Plan>> {
+        let cache = LazyLock::force(&PREPARED_PLAN_CACHE);
+        let Some(item) = cache.get(key) else {
+            return Ok(None);
+        };
+        if self.setting_changes() != item.setting_changes
+            || self.ctx.get_all_variables() != item.variables
+        {
+            return Ok(None);
+        }
+
+        let Plan::Query { metadata, .. } = &item.plan else {
+            return Ok(None);
+        };
+        let mut new_metadata = metadata.read().clone();
+        let entries = new_metadata
+            .tables()
+            .iter()
+            .map(|entry| {
+                let name = (entry.catalog(), entry.database(), entry.name());
+                (
+                    entry.index(),
+                    name.0.to_string(),
+                    name.1.to_string(),
+                    name.2.to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+        for ((index, catalog, database, name), prepared) in entries.into_iter().zip(&item.tables) {
+            let Ok(table) = self.ctx.get_table(&catalog, &database, &name).await else {
+                return Ok(None);
+            };
+            if table.get_id() != prepared.table_id || table.schema() != prepared.schema {
+                return Ok(None);
+            }
+            if table.options().get(OPT_KEY_SNAPSHOT_LOCATION) != prepared.snapshot_location.as_ref()
+            {
+                let num_rows = table.get_table_info().meta.statistics.number_of_rows;
+                let change = num_rows.abs_diff(prepared.num_rows) as f64;
+                if change > prepared.num_rows.max(1) as f64 * REPLAN_ROWS_CHANGE_RATIO {
+                    return Ok(None);
+                }
+            }
+            new_metadata.replace_table(index, table);
+        }
+
+        Ok(Some(with_metadata(&item.plan, new_metadata)))
+    }
+
+    fn set_prepared_plan(&self, key: String, plan: &Plan) {
+        let Plan::Query { metadata, .. } = plan else {
+            return;
+        };
+        let tables = prepared_tables(metadata);
+        // The metadata is not shared with the executions, which might change it.
+        let metadata = metadata.read().clone();
+        let item = PreparedPlanCacheItem {
+            plan: with_metadata(plan, metadata),
+            setting_changes: self.setting_changes(),
+            variables: self.ctx.get_all_variables(),
+            tables,
+        };
+        let cache = LazyLock::force(&PREPARED_PLAN_CACHE);
+        cache.insert(key, item);
+    }
+}
+
+fn with_metadata(plan: &Plan, new_metadata: Metadata) -> Plan {
+    let mut plan = plan.clone();
+    if let Plan::Query { metadata, .. } = &mut plan {
+        *metadata = Arc::new(RwLock::new(new_metadata));
+    }
+    plan
+}
+
+fn prepared_tables(metadata: &MetadataRef) -> Vec<PreparedTable> {
+    metadata
+        .read()
+        .tables()
+        .iter()
+        .map(|entry| {
+            let table = entry.table();
+            PreparedTable {
+                table_id: table.get_id(),
+                schema: table.schema(),
+                snapshot_location: table.options().get(OPT_KEY_SNAPSHOT_LOCATION).cloned(),
+                num_rows: table.get_table_info().meta.statistics.number_of_rows,
+            }
+        })
+        .collect()
+}
+
+// `prepared_param(<index>, <param>)`, which keeps the type of the parameter.
+fn prepared_param_expr(index: usize, param: &Expr) -> Expr {
+    Expr::FunctionCall {
+        span: None,
+        func: AstFunctionCall {
+            distinct: false,
+            name: Identifier::from_name(None, PREPARED_PARAM_FUNC),
+            args: vec![
+                Expr::Literal {
+                    span: None,
+                    value: Literal::UInt64(index as u64),
+                },
+                param.clone(),
+            ],
+            params: vec![],
+            order_by: vec![],
+            filter: None,
+            window: None,
+            lambda: None,
+        },
+    }
+}
+
+/// Replaces the placeholders of the cached plan with the parameters.
+#[recursive::recursive]
+fn bind_prepared_params(s_expr: &SExpr, params: &[(Scalar, DataType)]) -> Result<SExpr> {
+    let mut visitor = PreparedParamVisitor { params };
+    let mut plan = s_expr.plan().clone();
+    walk_operator_mut(&mut visitor, &mut plan)?;
+
+    let children = s_expr
+        .children()
+        .map(|child| Ok(Arc::new(bind_prepared_params(child, params)?)))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(s_expr
+        .replace_plan(Arc::new(plan))
+        .replace_children(children))
+}
+
+struct PreparedParamVisitor<'a> {
+    params: &'a [(Scalar, DataType)],
+}
+
+impl<'a> VisitorMut<'_> for PreparedParamVisitor<'a> {
+    fn visit(&mut self, expr: &mut ScalarExpr) -> Result<()> {
+        if let ScalarExpr::FunctionCall(func) = expr {
+            if func.func_name == PREPARED_PARAM_FUNC {
+                let index = match func.arguments.first() {
+                    Some(ScalarExpr::ConstantExpr(ConstantExpr {
+                        value: Scalar::Number(index),
+                        ..
+                    })) => index.integer_to_i128(),
+                    _ => None,
+                };
+                let Some((value, data_type)) =
+                    index.and_then(|index| self.params.get(index as usize))
+                else {
+                    return Err(ErrorCode::Internal(format!(
+                        "Invalid parameter of prepared statement: {func:?}"
+                    )));
+                };
+
+                let constant = ScalarExpr::ConstantExpr(ConstantExpr {
+                    span: func.span,
+                    value: value.clone(),
+                });
+                *expr = if value.as_ref().infer_data_type() == *data_type {
+                    constant
+                } else {
+                    ScalarExpr::CastExpr(CastExpr {
+                        span: func.span,
+                        is_try: false,
+                        argument: Box::new(constant),
+                        target_type: Box::new(data_type.clone()),
+                    })
+                };
+                return Ok(());
+            }
+        }
+        walk_expr_mut(self, expr)
+    }
+
+    fn visit_subquery_expr(&mut self, subquery: &mut SubqueryExpr) -> Result<()> {
+        if let Some(child_expr) = subquery.child_expr.as_mut() {
+            self.visit(child_expr)?;
+        }
+        subquery.subquery = Box::new(bind_prepared_params(&subquery.subquery, self.params)?);
+        Ok(())
+    }
+}
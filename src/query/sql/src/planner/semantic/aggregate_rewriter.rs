@@ -32,11 +32,12 @@ impl AggregateRewriter {
                         distinct,
                         name,
                         args,
+                        filter,
                         window,
                         ..
                     },
                 ..
-            } if !*distinct && args.len() == 1 && window.is_none() => {
+            } if !*distinct && args.len() == 1 && filter.is_none() && window.is_none() => {
                 match name.name.to_ascii_lowercase().to_lowercase().as_str() {
                     "sum" => self.rewrite_sum(args),
                     "avg" => Some(self.rewrite_avg(args)),
@@ -79,6 +80,8 @@ impl AggregateRewriter {
                                         name: Identifier::from_name(l.span(), "count"),
                                         args: vec![other.clone()],
                                         params: vec![],
+                                        order_by: vec![],
+                                        filter: None,
                                         window: None,
                                         lambda: None,
                                     },
@@ -91,6 +94,8 @@ impl AggregateRewriter {
                                     name: Identifier::from_name(other.span(), "sum"),
                                     args: vec![other.clone()],
                                     params: vec![],
+                                    order_by: vec![],
+                                    filter: None,
                                     window: None,
                                     lambda: None,
                                 },
@@ -110,6 +115,8 @@ impl AggregateRewriter {
                                     name: Identifier::from_name(other.span(), "sum"),
                                     args: vec![other.clone()],
                                     params: vec![],
+                                    order_by: vec![],
+                                    filter: None,
                                     window: None,
                                     lambda: None,
                                 },
@@ -125,6 +132,8 @@ impl AggregateRewriter {
                                         name: Identifier::from_name(l.span(), "count"),
                                         args: vec![other.clone()],
                                         params: vec![],
+                                        order_by: vec![],
+                                        filter: None,
                                         window: None,
                                         lambda: None,
                                     },
@@ -154,6 +163,8 @@ impl AggregateRewriter {
                     name: Identifier::from_name(args[0].span(), "sum"),
                     args: vec![args[0].clone()],
                     params: vec![],
+                    order_by: vec![],
+                    filter: None,
                     window: None,
                     lambda: None,
                 },
@@ -174,6 +185,8 @@ impl AggregateRewriter {
                                     name: Identifier::from_name(args[0].span(), "count"),
                                     args: vec![args[0].clone()],
                                     params: vec![],
+                                    order_by: vec![],
+                                    filter: None,
                                     window: None,
                                     lambda: None,
                                 },
@@ -194,12 +207,16 @@ impl AggregateRewriter {
                                 name: Identifier::from_name(args[0].span(), "count"),
                                 args: vec![args[0].clone()],
                                 params: vec![],
+                                order_by: vec![],
+                                filter: None,
                                 window: None,
                                 lambda: None,
                             },
                         },
                     ],
                     params: vec![],
+                    order_by: vec![],
+                    filter: None,
                     window: None,
                     lambda: None,
                 },
@@ -56,6 +56,8 @@ impl AggregatingIndexRewriter {
                         distinct,
                         name,
                         args,
+                        order_by,
+                        filter,
                         window,
                         lambda,
                         ..
@@ -64,6 +66,8 @@ impl AggregatingIndexRewriter {
             } if !*distinct
                 && SUPPORTED_AGGREGATING_INDEX_FUNCTIONS
                     .contains(&&*name.name.to_ascii_lowercase().to_lowercase())
+                && order_by.is_empty()
+                && filter.is_none()
                 && window.is_none()
                 && lambda.is_none() =>
             {
@@ -82,7 +86,7 @@ impl AggregatingIndexRewriter {
                     self.extracted_aggs.insert(agg);
                 }
             }
-            Expr::CountAll { window, .. } if window.is_none() => {
+            Expr::CountAll { filter, window, .. } if filter.is_none() && window.is_none() => {
                 self.agg_func_positions
                     .insert(self.current_position.unwrap());
                 self.extracted_aggs.insert("COUNT()".to_string());
@@ -212,6 +216,8 @@ impl AggregatingIndexChecker {
             name,
             params: _,
             args: _,
+            order_by: vec![],
+            filter: None,
             window: _,
             lambda: _,
         } = func;
@@ -238,7 +244,11 @@ impl AggregatingIndexChecker {
         if self.not_support {
             return;
         }
-        if stmt.having.is_some() || stmt.window_list.is_some() || stmt.qualify.is_some() {
+        if stmt.having.is_some()
+            || stmt.window_list.is_some()
+            || stmt.qualify.is_some()
+            || stmt.connect_by.is_some()
+        {
             self.not_support = true;
             return;
         }
@@ -283,6 +293,8 @@ impl RefreshAggregatingIndexRewriter {
                     FunctionCall {
                         distinct,
                         name,
+                        order_by,
+                        filter,
                         window,
                         ..
                     },
@@ -290,12 +302,18 @@ impl RefreshAggregatingIndexRewriter {
             } if !*distinct
                 && SUPPORTED_AGGREGATING_INDEX_FUNCTIONS
                     .contains(&&*name.name.to_ascii_lowercase().to_lowercase())
+                && order_by.is_empty()
+                && filter.is_none()
                 && window.is_none() =>
             {
                 self.has_agg_function = true;
                 name.name = format!("{}_STATE", name.name);
             }
-            Expr::CountAll { span, window } if window.is_none() => {
+            Expr::CountAll {
+                span,
+                filter,
+                window,
+            } if filter.is_none() && window.is_none() => {
                 self.has_agg_function = true;
                 *expr = Expr::FunctionCall {
                     span: None,
@@ -304,6 +322,8 @@ impl RefreshAggregatingIndexRewriter {
                         name: Identifier::from_name(*span, "COUNT_STATE"),
                         args: vec![],
                         params: vec![],
+                        order_by: vec![],
+                        filter: None,
                         window: None,
                         lambda: None,
                     },
@@ -36,6 +36,7 @@ impl DistinctToGroupBy {
             select_list,
             from,
             selection,
+            connect_by,
             group_by,
             having,
             window_list,
@@ -53,6 +54,7 @@ impl DistinctToGroupBy {
                                 distinct,
                                 name,
                                 args,
+                                filter,
                                 window,
                                 ..
                             },
@@ -60,7 +62,7 @@ impl DistinctToGroupBy {
                 alias,
             } = &select_list[0]
             {
-                if window.is_some() {
+                if filter.is_some() || window.is_some() {
                     return;
                 }
                 let sub_query_name = "_distinct_group_by_subquery";
@@ -85,6 +87,7 @@ impl DistinctToGroupBy {
                                 .collect(),
                             from: from.clone(),
                             selection: selection.clone(),
+                            connect_by: connect_by.clone(),
                             group_by: Some(GroupBy::Normal(args.clone())),
                             having: None,
                             window_list: None,
@@ -93,6 +96,7 @@ impl DistinctToGroupBy {
                         order_by: vec![],
                         limit: vec![],
                         offset: None,
+                        fetch: None,
                         ignore_result: false,
                     };
 
@@ -118,6 +122,8 @@ impl DistinctToGroupBy {
                                         },
                                     }],
                                     params: vec![],
+                                    order_by: vec![],
+                                    filter: None,
                                     window: None,
                                     lambda: None,
                                 },
@@ -136,6 +142,7 @@ impl DistinctToGroupBy {
                             unpivot: None,
                         }],
                         selection: None,
+                        connect_by: None,
                         group_by: None,
                         having: having.clone(),
                         window_list: window_list.clone(),
@@ -29,6 +29,8 @@ pub struct NameResolutionContext {
     pub unquoted_ident_case_sensitive: bool,
     pub quoted_ident_case_sensitive: bool,
     pub deny_column_reference: bool,
+    /// Allows the internal functions, which are generated by the planner.
+    pub allow_internal_functions: bool,
 }
 
 pub enum NameResolutionSuggest {
@@ -61,6 +63,7 @@ impl Default for NameResolutionContext {
             unquoted_ident_case_sensitive: false,
             quoted_ident_case_sensitive: true,
             deny_column_reference: false,
+            allow_internal_functions: false,
         }
     }
 }
@@ -76,6 +79,7 @@ impl TryFrom<&Settings> for NameResolutionContext {
             unquoted_ident_case_sensitive,
             quoted_ident_case_sensitive,
             deny_column_reference: false,
+            allow_internal_functions: false,
         })
     }
 }
@@ -30,6 +30,7 @@ use databend_common_ast::ast::IntervalKind as ASTIntervalKind;
 use databend_common_ast::ast::Lambda;
 use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::MapAccessor;
+use databend_common_ast::ast::OrderByExpr;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::SelectTarget;
 use databend_common_ast::ast::SetExpr;
@@ -89,7 +90,9 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_functions::GENERAL_LAMBDA_FUNCTIONS;
 use databend_common_functions::GENERAL_SEARCH_FUNCTIONS;
 use databend_common_functions::GENERAL_WINDOW_FUNCTIONS;
+use databend_common_functions::ORDERED_SET_AGGREGATE_FUNCTIONS;
 use databend_common_meta_app::principal::LambdaUDF;
+use databend_common_meta_app::principal::UDAFScript;
 use databend_common_meta_app::principal::UDFDefinition;
 use databend_common_meta_app::principal::UDFScript;
 use databend_common_meta_app::principal::UDFServer;
@@ -146,6 +149,7 @@ use crate::plans::ScalarItem;
 use crate::plans::SqlSource;
 use crate::plans::SubqueryExpr;
 use crate::plans::SubqueryType;
+use crate::plans::UDAFDesc;
 use crate::plans::UDFCall;
 use crate::plans::UDFLambdaCall;
 use crate::plans::UDFType;
@@ -419,6 +423,8 @@ impl<'a> TypeChecker<'a> {
                             name: Identifier::from_name(*span, "array_distinct"),
                             args: vec![array_expr],
                             params: vec![],
+                            order_by: vec![],
+                            filter: None,
                             window: None,
                             lambda: None,
                             distinct: false,
@@ -433,6 +439,8 @@ impl<'a> TypeChecker<'a> {
                                 name: Identifier::from_name(*span, "contains"),
                                 args: args.iter().copied().cloned().collect(),
                                 params: vec![],
+                                order_by: vec![],
+                                filter: None,
                                 window: None,
                                 lambda: None,
                             },
@@ -680,6 +688,8 @@ impl<'a> TypeChecker<'a> {
                                     name: Identifier::from_name(*span, "eq"),
                                     args: vec![*operand.clone(), c.clone()],
                                     params: vec![],
+                                    order_by: vec![],
+                                    filter: None,
                                     window: None,
                                     lambda: None,
                                 },
@@ -729,16 +739,31 @@ impl<'a> TypeChecker<'a> {
                         name,
                         args,
                         params,
+                        order_by,
+                        filter,
                         window,
                         lambda,
                     },
             } => {
                 let func_name = normalize_identifier(name, self.name_resolution_ctx).to_string();
                 let func_name = func_name.as_str();
-                if !is_builtin_function(func_name)
+                // `PERCENTILE_CONT(p) WITHIN GROUP (ORDER BY x)` is resolved as `QUANTILE_CONT(p)(x)`
+                let percentile;
+                let (func_name, params, args, order_by) = match func_name {
+                    "percentile_cont" | "percentile_disc" => {
+                        percentile = Self::rewrite_percentile(
+                            *span, func_name, *distinct, params, args, order_by,
+                        )?;
+                        (percentile.0, &percentile.1, &percentile.2, &[][..])
+                    }
+                    _ => (func_name, params, args, order_by.as_slice()),
+                };
+                let is_internal_function = BUILTIN_FUNCTIONS.is_internal(func_name)
+                    && !self.name_resolution_ctx.allow_internal_functions;
+                if (!is_builtin_function(func_name) || is_internal_function)
                     && !Self::all_sugar_functions().contains(&func_name)
                 {
-                    if let Some(udf) = self.resolve_udf(*span, func_name, args)? {
+                    if let Some(udf) = self.resolve_udf(*span, func_name, expr, args)? {
                         return Ok(udf);
                     } else {
                         // Function not found, try to find and suggest similar function name.
@@ -797,6 +822,27 @@ impl<'a> TypeChecker<'a> {
                     )
                     .set_span(*span));
                 }
+                // check aggregate clauses legal
+                if (filter.is_some() || !order_by.is_empty())
+                    && !AggregateFunctionFactory::instance().contains(func_name)
+                {
+                    return Err(ErrorCode::SemanticError(
+                        "only aggregate functions allowed in FILTER and WITHIN GROUP syntax",
+                    )
+                    .set_span(*span));
+                }
+                if !order_by.is_empty() && !ORDERED_SET_AGGREGATE_FUNCTIONS.contains(&func_name) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "aggregate function {func_name} not support WITHIN GROUP clause"
+                    ))
+                    .set_span(*span));
+                }
+                if !order_by.is_empty() && *distinct {
+                    return Err(ErrorCode::SemanticError(
+                        "DISTINCT is not supported with WITHIN GROUP clause",
+                    )
+                    .set_span(*span));
+                }
 
                 let args: Vec<&Expr> = args.iter().collect();
 
@@ -846,7 +892,14 @@ impl<'a> TypeChecker<'a> {
                     self.in_window_function = self.in_window_function || window.is_some();
                     let in_aggregate_function = self.in_aggregate_function;
                     let (new_agg_func, data_type) = self.resolve_aggregate_function(
-                        *span, func_name, expr, *distinct, new_params, &args,
+                        *span,
+                        func_name,
+                        expr,
+                        *distinct,
+                        new_params,
+                        &args,
+                        order_by,
+                        filter.as_deref(),
                     )?;
                     self.in_window_function = in_window;
                     self.in_aggregate_function = in_aggregate_function;
@@ -920,9 +973,21 @@ impl<'a> TypeChecker<'a> {
                 }
             }
 
-            Expr::CountAll { span, window } => {
-                let (new_agg_func, data_type) =
-                    self.resolve_aggregate_function(*span, "count", expr, false, vec![], &[])?;
+            Expr::CountAll {
+                span,
+                filter,
+                window,
+            } => {
+                let (new_agg_func, data_type) = self.resolve_aggregate_function(
+                    *span,
+                    "count",
+                    expr,
+                    false,
+                    vec![],
+                    &[],
+                    &[],
+                    filter.as_deref(),
+                )?;
 
                 if let Some(window) = window {
                     // aggregate window function
@@ -1634,17 +1699,8 @@ impl<'a> TypeChecker<'a> {
         }))
     }
 
-    /// Resolve aggregation function call.
-
-    fn resolve_aggregate_function(
-        &mut self,
-        span: Span,
-        func_name: &str,
-        expr: &Expr,
-        distinct: bool,
-        params: Vec<Scalar>,
-        args: &[&Expr],
-    ) -> Result<(AggregateFunction, DataType)> {
+    /// Checks that an aggregate function can be called in the current context.
+    fn check_aggregate_function_context(&mut self, span: Span, expr: &Expr) -> Result<()> {
         if matches!(
             self.bind_context.expr_context,
             ExprContext::InLambdaFunction
@@ -1681,6 +1737,23 @@ impl<'a> TypeChecker<'a> {
                 .set_span(expr.span()));
             }
         }
+        Ok(())
+    }
+
+    /// Resolve aggregation function call.
+    #[allow(clippy::too_many_arguments)]
+    fn resolve_aggregate_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        expr: &Expr,
+        distinct: bool,
+        params: Vec<Scalar>,
+        args: &[&Expr],
+        order_by: &[OrderByExpr],
+        filter: Option<&Expr>,
+    ) -> Result<(AggregateFunction, DataType)> {
+        self.check_aggregate_function_context(span, expr)?;
 
         // Check aggregate function
         self.in_aggregate_function = true;
@@ -1691,18 +1764,28 @@ impl<'a> TypeChecker<'a> {
             arguments.push(argument);
             arg_types.push(arg_type);
         }
+        let mut sort_keys = Vec::with_capacity(order_by.len());
+        for order in order_by {
+            let box (key, key_type) = self.resolve(&order.expr)?;
+            sort_keys.push((key, key_type));
+        }
+        let condition = match filter {
+            Some(cond) => Some(self.resolve_function(span, "is_true", vec![], &[cond])?),
+            None => None,
+        };
         self.in_aggregate_function = false;
 
         // Convert the delimiter of string_agg to params
-        let params = if func_name.eq_ignore_ascii_case("string_agg")
+        let params = if (func_name.eq_ignore_ascii_case("string_agg")
+            || func_name.eq_ignore_ascii_case("listagg"))
             && arguments.len() == 2
             && params.is_empty()
         {
             let delimiter_value = ConstantExpr::try_from(arguments[1].clone());
             if arg_types[1] != DataType::String || delimiter_value.is_err() {
-                return Err(ErrorCode::SemanticError(
-                    "The delimiter of `string_agg` must be a constant string",
-                ));
+                return Err(ErrorCode::SemanticError(format!(
+                    "The delimiter of `{func_name}` must be a constant string",
+                )));
             }
             let delimiter = delimiter_value.unwrap();
             vec![delimiter.value]
@@ -1745,11 +1828,47 @@ impl<'a> TypeChecker<'a> {
             func_name.to_string()
         };
 
+        // Rewrite `xxx(...) WITHIN GROUP (ORDER BY ...)` to `xxx_within_group(...)`,
+        // and then `xxx(...) FILTER (WHERE ...)` to `xxx_if(...)`
+        let mut params = params;
+        let func_name = if !sort_keys.is_empty() {
+            let default_nulls_first = self.ctx.get_settings().get_nulls_first();
+            let sort_descs = order_by
+                .iter()
+                .map(|order| {
+                    let asc = order.asc.unwrap_or(true);
+                    let nulls_first = order
+                        .nulls_first
+                        .unwrap_or_else(|| default_nulls_first(asc));
+                    format!(
+                        "{} NULLS {}",
+                        if asc { "ASC" } else { "DESC" },
+                        if nulls_first { "FIRST" } else { "LAST" }
+                    )
+                })
+                .join(", ");
+            params.push(Scalar::String(sort_descs));
+            for (key, key_type) in sort_keys {
+                arguments.push(key);
+                arg_types.push(key_type);
+            }
+            format!("{}_within_group", func_name)
+        } else {
+            func_name
+        };
+        let func_name = if let Some(box (cond, cond_type)) = condition {
+            arguments.push(cond);
+            arg_types.push(cond_type);
+            format!("{}_if", func_name)
+        } else {
+            func_name
+        };
+
         let agg_func = AggregateFunctionFactory::instance()
             .get(&func_name, params.clone(), arg_types)
             .map_err(|e| e.set_span(span))?;
 
-        let args = if optimize_remove_count_args(&func_name, distinct, args) {
+        let args = if optimize_remove_count_args(&func_name, distinct, &args) {
             vec![]
         } else {
             arguments
@@ -1764,6 +1883,7 @@ impl<'a> TypeChecker<'a> {
             params,
             args,
             return_type: Box::new(agg_func.return_type()?),
+            udaf: None,
         };
 
         let data_type = agg_func.return_type()?;
@@ -1771,6 +1891,54 @@ impl<'a> TypeChecker<'a> {
         Ok((new_agg_func, data_type))
     }
 
+    /// Rewrites `PERCENTILE_CONT(p) WITHIN GROUP (ORDER BY x)` to the name, params and args
+    /// of `QUANTILE_CONT(p)(x)`, the descending order takes the fraction `1 - p`.
+    /// `PERCENTILE_DISC` is rewritten to `PERCENTILE_DISC(p[, desc])(x)`.
+    fn rewrite_percentile(
+        span: Span,
+        func_name: &str,
+        distinct: bool,
+        params: &[Expr],
+        args: &[Expr],
+        order_by: &[OrderByExpr],
+    ) -> Result<(&'static str, Vec<Expr>, Vec<Expr>)> {
+        if distinct || !params.is_empty() || args.len() != 1 || order_by.len() != 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "{func_name} expects a fraction and one ORDER BY expression, like `{func_name}(0.5) WITHIN GROUP (ORDER BY x)`"
+            ))
+            .set_span(span));
+        }
+        let desc = order_by[0].asc == Some(false);
+        if func_name == "percentile_disc" {
+            // The descending order can't be taken as the fraction `1 - p` of the ascending
+            // order, since the index is rounded up.
+            let mut params = vec![args[0].clone()];
+            if desc {
+                params.push(Expr::Literal {
+                    span: None,
+                    value: Literal::Boolean(true),
+                });
+            }
+            return Ok(("percentile_disc", params, vec![order_by[0].expr.clone()]));
+        }
+        let fraction = if desc {
+            Expr::BinaryOp {
+                span: args[0].span(),
+                op: BinaryOperator::Minus,
+                left: Box::new(Expr::Literal {
+                    span: None,
+                    value: Literal::UInt64(1),
+                }),
+                right: Box::new(args[0].clone()),
+            }
+        } else {
+            args[0].clone()
+        };
+        Ok(("quantile_cont", vec![fraction], vec![
+            order_by[0].expr.clone(),
+        ]))
+    }
+
     fn transform_to_max_type(&self, ty: &DataType) -> Result<DataType> {
         let max_ty = match ty.remove_nullable() {
             DataType::Number(s) => {
@@ -2801,6 +2969,13 @@ impl<'a> TypeChecker<'a> {
                 // Omit unary + operator
                 self.resolve(child)
             }
+            UnaryOperator::Prior | UnaryOperator::ConnectByRoot => {
+                // Hierarchical operators are rewritten before binding in `CONNECT BY` queries.
+                Err(ErrorCode::SemanticError(format!(
+                    "{op} can only be used in a query with CONNECT BY clause"
+                ))
+                .set_span(span))
+            }
             other => {
                 let name = other.to_func_name();
                 self.resolve_function(span, name.as_str(), vec![], &[child])
@@ -3130,6 +3305,8 @@ impl<'a> TypeChecker<'a> {
                             name: Identifier::from_name(span, "is_not_null"),
                             args: vec![arg_x.clone()],
                             params: vec![],
+                            order_by: vec![],
+                            filter: None,
                             window: None,
                             lambda: None,
                         },
@@ -3146,6 +3323,8 @@ impl<'a> TypeChecker<'a> {
                             name: Identifier::from_name(span, "is_not_error"),
                             args: vec![arg_x.clone()],
                             params: vec![],
+                            order_by: vec![],
+                            filter: None,
                             window: None,
                             lambda: None,
                         },
@@ -3165,6 +3344,8 @@ impl<'a> TypeChecker<'a> {
                             name: Identifier::from_name(span, "is_not_error"),
                             args: vec![(*arg).clone()],
                             params: vec![],
+                            order_by: vec![],
+                            filter: None,
                             window: None,
                             lambda: None,
                         },
@@ -3214,6 +3395,8 @@ impl<'a> TypeChecker<'a> {
                             name: Identifier::from_name(span, "assume_not_null"),
                             args: vec![(*arg).clone()],
                             params: vec![],
+                            order_by: vec![],
+                            filter: None,
                             window: None,
                             lambda: None,
                         },
@@ -3559,6 +3742,7 @@ impl<'a> TypeChecker<'a> {
         &mut self,
         span: Span,
         udf_name: &str,
+        expr: &Expr,
         arguments: &[Expr],
     ) -> Result<Option<Box<(ScalarExpr, DataType)>>> {
         if self.forbid_udf {
@@ -3585,6 +3769,15 @@ impl<'a> TypeChecker<'a> {
             UDFDefinition::UDFScript(udf_def) => Ok(Some(
                 self.resolve_udf_script(span, name, arguments, udf_def)?,
             )),
+            UDFDefinition::UDTFSql(_) | UDFDefinition::UDTFScript(_) => {
+                Err(ErrorCode::SemanticError(format!(
+                    "Table function '{name}' can only be used in the FROM clause"
+                ))
+                .set_span(span))
+            }
+            UDFDefinition::UDAFScript(udf_def) => Ok(Some(
+                self.resolve_udaf_script(span, name, expr, arguments, udf_def)?,
+            )),
         }
     }
 
@@ -3749,6 +3942,112 @@ impl<'a> TypeChecker<'a> {
         )))
     }
 
+    fn resolve_udaf_script(
+        &mut self,
+        span: Span,
+        name: String,
+        expr: &Expr,
+        arguments: &[Expr],
+        udf_definition: UDAFScript,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let Expr::FunctionCall {
+            func:
+                ASTFunctionCall {
+                    distinct,
+                    params,
+                    order_by,
+                    filter,
+                    window,
+                    ..
+                },
+            ..
+        } = expr
+        else {
+            unreachable!()
+        };
+        if *distinct || !params.is_empty() || !order_by.is_empty() || filter.is_some() {
+            return Err(ErrorCode::SemanticError(format!(
+                "aggregate function {name} not support DISTINCT, parameters, FILTER or WITHIN GROUP"
+            ))
+            .set_span(span));
+        }
+        if arguments.len() != udf_definition.arg_types.len() {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Require {} parameters, but got: {}",
+                udf_definition.arg_types.len(),
+                arguments.len()
+            ))
+            .set_span(span));
+        }
+
+        let in_window = self.in_window_function;
+        self.in_window_function = self.in_window_function || window.is_some();
+        let in_aggregate_function = self.in_aggregate_function;
+        self.check_aggregate_function_context(span, expr)?;
+
+        self.in_aggregate_function = true;
+        let mut args = Vec::with_capacity(arguments.len());
+        for (argument, dest_type) in arguments.iter().zip(udf_definition.arg_types.iter()) {
+            let box (arg, ty) = self.resolve(argument)?;
+            // NULL inputs are passed through to the aggregate function.
+            let dest_type = if ty.is_nullable_or_null() {
+                dest_type.wrap_nullable()
+            } else {
+                dest_type.clone()
+            };
+            if ty != dest_type {
+                args.push(wrap_cast(&arg, &dest_type));
+            } else {
+                args.push(arg);
+            }
+        }
+        self.in_window_function = in_window;
+        self.in_aggregate_function = in_aggregate_function;
+
+        let udf_type =
+            databend_common_base::runtime::block_on(self.resolve_udf_with_stage(&UDFScript {
+                code: udf_definition.code,
+                handler: name.clone(),
+                language: udf_definition.language,
+                arg_types: udf_definition.arg_types,
+                return_type: udf_definition.return_type.clone(),
+                runtime_version: udf_definition.runtime_version,
+            }))?;
+
+        let return_type = udf_definition.return_type.wrap_nullable();
+        let display_name = format!("{:#}", expr);
+        let agg_func = AggregateFunction {
+            span,
+            func_name: name.clone(),
+            distinct: false,
+            params: vec![],
+            args,
+            return_type: Box::new(return_type.clone()),
+            udaf: Some(UDAFDesc {
+                udf_type,
+                state_names: udf_definition.state_names,
+                state_types: udf_definition.state_types,
+                return_type: return_type.clone(),
+            }),
+            display_name: display_name.clone(),
+        };
+
+        self.ctx.set_cacheable(false);
+        match window {
+            Some(window) => {
+                if window.ignore_nulls.is_some() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "window function {name} not support IGNORE/RESPECT NULLS option"
+                    ))
+                    .set_span(span));
+                }
+                let func = WindowFuncType::Aggregate(agg_func);
+                self.resolve_window(span, display_name, &window.window, func)
+            }
+            None => Ok(Box::new((agg_func.into(), return_type))),
+        }
+    }
+
     fn resolve_lambda_udf(
         &mut self,
         span: Span,
@@ -4636,6 +4935,8 @@ impl<'a> TypeChecker<'a> {
                             name,
                             args,
                             params,
+                            order_by,
+                            filter,
                             window,
                             lambda,
                         },
@@ -4649,6 +4950,13 @@ impl<'a> TypeChecker<'a> {
                             .map(|arg| self.clone_expr_with_replacement(arg, replacement_fn))
                             .collect::<Result<Vec<Expr>>>()?,
                         params: params.clone(),
+                        order_by: order_by.clone(),
+                        filter: match filter {
+                            Some(filter) => Some(Box::new(
+                                self.clone_expr_with_replacement(filter, replacement_fn)?,
+                            )),
+                            None => None,
+                        },
                         window: window.clone(),
                         lambda: if let Some(lambda) = lambda {
                             Some(Lambda {
@@ -137,6 +137,7 @@ impl UdfRewriter {
             let udf_plan = Udf {
                 items: udf_functions,
                 script_udf: self.script_udf,
+                table_function: false,
             };
             child_expr = Arc::new(SExpr::create_unary(Arc::new(udf_plan.into()), child_expr));
         }
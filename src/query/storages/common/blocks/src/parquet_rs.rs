@@ -12,18 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::str::FromStr;
 use std::sync::Arc;
 
 use databend_common_exception::Result;
 use databend_common_expression::converts::arrow::table_schema_to_arrow_schema;
 use databend_common_expression::DataBlock;
 use databend_common_expression::TableSchema;
+use databend_storages_common_table_meta::table::ColumnCodec;
 use databend_storages_common_table_meta::table::TableCompression;
 use parquet::arrow::ArrowWriter;
 use parquet::basic::Encoding;
 use parquet::file::properties::EnabledStatistics;
 use parquet::file::properties::WriterProperties;
 use parquet::format::FileMetaData;
+use parquet::schema::types::ColumnPath;
 
 /// Serialize data blocks to parquet format.
 pub fn blocks_to_parquet(
@@ -33,15 +36,23 @@ pub fn blocks_to_parquet(
     compression: TableCompression,
 ) -> Result<FileMetaData> {
     assert!(!blocks.is_empty());
-    let props = WriterProperties::builder()
+    let mut builder = WriterProperties::builder()
         .set_compression(compression.into())
         // use `usize::MAX` to effectively limit the number of row groups to 1
         .set_max_row_group_size(usize::MAX)
         .set_encoding(Encoding::PLAIN)
         .set_dictionary_enabled(false)
         .set_statistics_enabled(EnabledStatistics::None)
-        .set_bloom_filter_enabled(false)
-        .build();
+        .set_bloom_filter_enabled(false);
+    // columns declared with `CODEC(...)` override the table level settings
+    for field in table_schema.fields() {
+        if let Some(codec) = field.codec() {
+            let codec = ColumnCodec::from_str(codec)?;
+            let path = ColumnPath::from(field.name().as_str());
+            builder = codec.apply_parquet_properties(builder, path)?;
+        }
+    }
+    let props = builder.build();
     let batches = blocks
         .into_iter()
         .map(|block| block.to_record_batch(table_schema))
@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use databend_common_base::base::GlobalInstance;
 use databend_common_config::CacheConfig;
+use databend_common_config::CacheEvictionPolicy;
 use databend_common_config::CacheStorageTypeInnerConfig;
 use databend_common_config::DiskCacheKeyReloadPolicy;
 use databend_common_exception::Result;
@@ -37,6 +38,7 @@ use crate::caches::TableSnapshotStatisticCache;
 use crate::InMemoryLruCache;
 use crate::TableDataCache;
 use crate::TableDataCacheBuilder;
+use crate::DISK_TABLE_DATA_CACHE_NAME;
 
 static DEFAULT_PARQUET_META_DATA_CACHE_ITEMS: usize = 3000;
 
@@ -92,6 +94,7 @@ impl CacheManager {
                         &real_disk_cache_root,
                         queue_size,
                         config.disk_cache_config.max_bytes as usize,
+                        &config.eviction_policy_of(DISK_TABLE_DATA_CACHE_NAME),
                         config.data_cache_key_reload_policy.clone(),
                         config.disk_cache_config.sync_data,
                     )?
@@ -110,7 +113,7 @@ impl CacheManager {
 
         // Cache of deserialized table data
         let in_memory_table_data_cache =
-            Self::new_named_bytes_cache(MEMORY_CACHE_TABLE_DATA, memory_cache_capacity);
+            Self::new_named_bytes_cache(MEMORY_CACHE_TABLE_DATA, memory_cache_capacity, config);
 
         // setup in-memory table meta cache
         if !config.enable_table_meta_cache {
@@ -132,26 +135,32 @@ impl CacheManager {
             let table_snapshot_cache = Self::new_named_items_cache(
                 config.table_meta_snapshot_count as usize,
                 MEMORY_CACHE_TABLE_SNAPSHOT,
+                config,
             );
             let table_statistic_cache = Self::new_named_items_cache(
                 config.table_meta_statistic_count as usize,
                 MEMORY_CACHE_TABLE_STATISTICS,
+                config,
             );
             let compact_segment_info_cache = Self::new_named_bytes_cache(
                 MEMORY_CACHE_COMPACT_SEGMENT_INFO,
                 config.table_meta_segment_bytes as usize,
+                config,
             );
             let bloom_index_filter_cache = Self::new_named_bytes_cache(
                 MEMORY_CACHE_BLOOM_INDEX_FILTER,
                 config.table_bloom_index_filter_size as usize,
+                config,
             );
             let bloom_index_meta_cache = Self::new_named_items_cache(
                 config.table_bloom_index_meta_count as usize,
                 MEMORY_CACHE_BLOOM_INDEX_FILE_META_DATA,
+                config,
             );
             let inverted_index_meta_cache = Self::new_named_items_cache(
                 config.inverted_index_meta_count as usize,
                 MEMORY_CACHE_INVERTED_INDEX_FILE_META_DATA,
+                config,
             );
 
             // setup in-memory inverted index filter cache
@@ -165,20 +174,24 @@ impl CacheManager {
             let inverted_index_file_cache = Self::new_named_bytes_cache(
                 MEMORY_CACHE_INVERTED_INDEX_FILE,
                 inverted_index_file_size,
+                config,
             );
             let prune_partitions_cache = Self::new_named_items_cache(
                 config.table_prune_partitions_count as usize,
                 MEMORY_CACHE_PRUNE_PARTITIONS,
+                config,
             );
 
             let parquet_meta_data_cache = Self::new_named_items_cache(
                 DEFAULT_PARQUET_META_DATA_CACHE_ITEMS,
                 MEMORY_CACHE_PARQUET_META_DATA,
+                config,
             );
 
             let block_meta_cache = Self::new_named_items_cache(
                 config.block_meta_count as usize,
                 MEMORY_CACHE_BLOCK_META,
+                config,
             );
 
             GlobalInstance::set(Arc::new(Self {
@@ -255,22 +268,31 @@ impl CacheManager {
     pub fn new_named_items_cache<V: Into<CacheValue<V>>>(
         capacity: usize,
         name: impl Into<String>,
+        config: &CacheConfig,
     ) -> Option<InMemoryLruCache<V>> {
+        let name = name.into();
+        let policy = config.eviction_policy_of(&name);
         match capacity {
             0 => None,
-            _ => Some(InMemoryLruCache::with_items_capacity(name.into(), capacity)),
+            _ => Some(InMemoryLruCache::with_items_capacity_and_policy(
+                name, capacity, &policy,
+            )),
         }
     }
 
     fn new_named_bytes_cache<V: Into<CacheValue<V>>>(
         name: impl Into<String>,
         bytes_capacity: usize,
+        config: &CacheConfig,
     ) -> Option<InMemoryLruCache<V>> {
+        let name = name.into();
+        let policy = config.eviction_policy_of(&name);
         match bytes_capacity {
             0 => None,
-            _ => Some(InMemoryLruCache::with_bytes_capacity(
-                name.into(),
+            _ => Some(InMemoryLruCache::with_bytes_capacity_and_policy(
+                name,
                 bytes_capacity,
+                &policy,
             )),
         }
     }
@@ -279,6 +301,7 @@ impl CacheManager {
         path: &PathBuf,
         population_queue_size: u32,
         disk_cache_bytes_size: usize,
+        disk_cache_eviction_policy: &CacheEvictionPolicy,
         disk_cache_key_reload_policy: DiskCacheKeyReloadPolicy,
         sync_data: bool,
     ) -> Result<Option<TableDataCache>> {
@@ -287,6 +310,7 @@ impl CacheManager {
                 path,
                 population_queue_size,
                 disk_cache_bytes_size,
+                disk_cache_eviction_policy,
                 disk_cache_key_reload_policy,
                 sync_data,
             )?;
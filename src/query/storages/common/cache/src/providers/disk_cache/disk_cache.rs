@@ -25,6 +25,9 @@ use std::time::Instant;
 
 use databend_common_cache::Cache;
 use databend_common_cache::LruCache;
+use databend_common_cache::PolicyCache;
+use databend_common_cache::S3FifoCache;
+use databend_common_config::CacheEvictionPolicy;
 use databend_common_config::DiskCacheKeyReloadPolicy;
 use databend_common_exception::Result;
 use log::error;
@@ -38,7 +41,7 @@ use crate::CacheValue;
 use crate::DiskCacheKey;
 
 pub struct DiskCache {
-    cache: LruCache<String, CacheValue<FileSize>>,
+    cache: PolicyCache<String, CacheValue<FileSize>>,
     root: PathBuf,
     sync_data: bool,
 }
@@ -62,8 +65,32 @@ impl DiskCache {
     where
         PathBuf: From<T>,
     {
+        Self::new_with_eviction_policy(
+            path,
+            size,
+            &CacheEvictionPolicy::Lru,
+            disk_cache_key_reload_policy,
+            sync_data,
+        )
+    }
+
+    /// Create an `DiskCache` like `new`, evicting files according to the `eviction_policy`.
+    pub fn new_with_eviction_policy<T>(
+        path: T,
+        size: usize,
+        eviction_policy: &CacheEvictionPolicy,
+        disk_cache_key_reload_policy: DiskCacheKeyReloadPolicy,
+        sync_data: bool,
+    ) -> self::io_result::Result<Self>
+    where
+        PathBuf: From<T>,
+    {
+        let cache = match eviction_policy {
+            CacheEvictionPolicy::Lru => LruCache::with_bytes_capacity(size).into(),
+            CacheEvictionPolicy::S3Fifo => S3FifoCache::with_bytes_capacity(size).into(),
+        };
         DiskCache {
-            cache: LruCache::<String, CacheValue<FileSize>>::with_bytes_capacity(size),
+            cache,
             root: PathBuf::from(path),
             sync_data,
         }
@@ -97,6 +124,11 @@ impl DiskCache {
         self.cache.bytes_capacity()
     }
 
+    /// Return the name of the eviction policy.
+    pub fn policy_name(&self) -> &'static str {
+        self.cache.policy_name()
+    }
+
     /// Return the path in which the cache is stored.
     pub fn path(&self) -> &Path {
         self.root.as_path()
@@ -18,9 +18,12 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use bytes::Bytes;
+use databend_common_config::CacheEvictionPolicy;
 use databend_common_config::DiskCacheKeyReloadPolicy;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_metrics::cache::metrics_inc_cache_admission_count;
+use databend_common_metrics::cache::metrics_inc_cache_eviction_count;
 use databend_common_metrics::cache::metrics_inc_cache_miss_bytes;
 use log::error;
 use log::warn;
@@ -28,6 +31,7 @@ use parking_lot::RwLock;
 
 use crate::providers::disk_cache::DiskCache;
 use crate::CacheAccessor;
+use crate::DISK_TABLE_DATA_CACHE_NAME;
 
 impl CacheAccessor for LruDiskCacheHolder {
     type V = Bytes;
@@ -95,8 +99,15 @@ impl CacheAccessor for LruDiskCacheHolder {
         let crc = crc32fast::hash(value.as_ref());
         let crc_bytes = crc.to_le_bytes();
         let mut cache = self.write();
+        let len = cache.len() + usize::from(!cache.contains_key(&key));
         if let Err(e) = cache.insert_bytes(&key, &[value.as_ref(), &crc_bytes]) {
             error!("put disk cache item failed {}", e);
+        } else {
+            metrics_inc_cache_admission_count(1, DISK_TABLE_DATA_CACHE_NAME);
+            let evicted = len.saturating_sub(cache.len());
+            if evicted > 0 {
+                metrics_inc_cache_eviction_count(evicted as u64, DISK_TABLE_DATA_CACHE_NAME);
+            }
         }
         Arc::new(value)
     }
@@ -171,12 +182,14 @@ impl LruDiskCacheBuilder {
     pub fn new_disk_cache(
         path: &PathBuf,
         disk_cache_bytes_size: usize,
+        disk_cache_eviction_policy: &CacheEvictionPolicy,
         disk_cache_reload_policy: DiskCacheKeyReloadPolicy,
         sync_data: bool,
     ) -> Result<LruDiskCacheHolder> {
-        let external_cache = DiskCache::new(
+        let external_cache = DiskCache::new_with_eviction_policy(
             path,
             disk_cache_bytes_size,
+            disk_cache_eviction_policy,
             disk_cache_reload_policy,
             sync_data,
         )
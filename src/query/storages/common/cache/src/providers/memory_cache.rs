@@ -16,6 +16,9 @@ use std::sync::Arc;
 
 use databend_common_cache::Cache;
 use databend_common_cache::LruCache;
+use databend_common_cache::PolicyCache;
+use databend_common_cache::S3FifoCache;
+use databend_common_config::CacheEvictionPolicy;
 use parking_lot::RwLock;
 
 use crate::caches::CacheValue;
@@ -24,7 +27,7 @@ use crate::Unit;
 pub struct InMemoryLruCache<V: Into<CacheValue<V>>> {
     unit: Unit,
     name: String,
-    inner: Arc<RwLock<LruCache<String, CacheValue<V>>>>,
+    inner: Arc<RwLock<PolicyCache<String, CacheValue<V>>>>,
 }
 
 impl<V: Into<CacheValue<V>>> Clone for InMemoryLruCache<V> {
@@ -39,18 +42,42 @@ impl<V: Into<CacheValue<V>>> Clone for InMemoryLruCache<V> {
 
 impl<V: Into<CacheValue<V>>> InMemoryLruCache<V> {
     pub fn with_items_capacity(name: String, items_capacity: usize) -> Self {
+        Self::with_items_capacity_and_policy(name, items_capacity, &CacheEvictionPolicy::Lru)
+    }
+
+    pub fn with_bytes_capacity(name: String, bytes_capacity: usize) -> Self {
+        Self::with_bytes_capacity_and_policy(name, bytes_capacity, &CacheEvictionPolicy::Lru)
+    }
+
+    pub fn with_items_capacity_and_policy(
+        name: String,
+        items_capacity: usize,
+        policy: &CacheEvictionPolicy,
+    ) -> Self {
+        let cache = match policy {
+            CacheEvictionPolicy::Lru => LruCache::with_items_capacity(items_capacity).into(),
+            CacheEvictionPolicy::S3Fifo => S3FifoCache::with_items_capacity(items_capacity).into(),
+        };
         Self {
             name,
             unit: Unit::Count,
-            inner: Arc::new(RwLock::new(LruCache::with_items_capacity(items_capacity))),
+            inner: Arc::new(RwLock::new(cache)),
         }
     }
 
-    pub fn with_bytes_capacity(name: String, bytes_capacity: usize) -> Self {
+    pub fn with_bytes_capacity_and_policy(
+        name: String,
+        bytes_capacity: usize,
+        policy: &CacheEvictionPolicy,
+    ) -> Self {
+        let cache = match policy {
+            CacheEvictionPolicy::Lru => LruCache::with_bytes_capacity(bytes_capacity).into(),
+            CacheEvictionPolicy::S3Fifo => S3FifoCache::with_bytes_capacity(bytes_capacity).into(),
+        };
         Self {
             unit: Unit::Bytes,
             name,
-            inner: Arc::new(RwLock::new(LruCache::with_bytes_capacity(bytes_capacity))),
+            inner: Arc::new(RwLock::new(cache)),
         }
     }
 
@@ -61,6 +88,11 @@ impl<V: Into<CacheValue<V>>> InMemoryLruCache<V> {
     pub fn unit(&self) -> Unit {
         self.unit
     }
+
+    /// Returns the name of the eviction policy, e.g. "lru".
+    pub fn policy(&self) -> &'static str {
+        self.inner.read().policy_name()
+    }
 }
 
 // default impls
@@ -68,6 +100,8 @@ mod impls {
     use std::sync::Arc;
 
     use databend_common_metrics::cache::metrics_inc_cache_access_count;
+    use databend_common_metrics::cache::metrics_inc_cache_admission_count;
+    use databend_common_metrics::cache::metrics_inc_cache_eviction_count;
     use databend_common_metrics::cache::metrics_inc_cache_hit_count;
     use databend_common_metrics::cache::metrics_inc_cache_miss_bytes;
     use databend_common_metrics::cache::metrics_inc_cache_miss_count;
@@ -106,8 +140,16 @@ mod impls {
         fn insert(&self, k: String, v: V) -> Arc<V> {
             let cache_value = v.into();
             let res = cache_value.get_inner();
-            let mut guard = self.inner.write();
-            guard.insert(k, cache_value);
+            let evicted = {
+                let mut guard = self.inner.write();
+                let len = guard.len();
+                let replaced = guard.insert(k, cache_value).is_some();
+                (len + usize::from(!replaced)).saturating_sub(guard.len())
+            };
+            metrics_inc_cache_admission_count(1, &self.name);
+            if evicted > 0 {
+                metrics_inc_cache_eviction_count(evicted as u64, &self.name);
+            }
             res
         }
 
@@ -20,6 +20,7 @@ use bytes::Bytes;
 use crossbeam_channel::TrySendError;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_config::CacheEvictionPolicy;
 use databend_common_config::DiskCacheKeyReloadPolicy;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -77,12 +78,14 @@ impl TableDataCacheBuilder {
         path: &PathBuf,
         population_queue_size: u32,
         disk_cache_bytes_size: usize,
+        disk_cache_eviction_policy: &CacheEvictionPolicy,
         disk_cache_reload_policy: DiskCacheKeyReloadPolicy,
         sync_data: bool,
     ) -> Result<TableDataCache<LruDiskCacheHolder>> {
         let disk_cache = LruDiskCacheBuilder::new_disk_cache(
             path,
             disk_cache_bytes_size,
+            disk_cache_eviction_policy,
             disk_cache_reload_policy,
             sync_data,
         )?;
@@ -96,6 +99,13 @@ impl TableDataCacheBuilder {
     }
 }
 
+impl TableDataCache {
+    /// Returns the name of the eviction policy of the disk cache.
+    pub fn policy(&self) -> &'static str {
+        self.external_cache.read().policy_name()
+    }
+}
+
 impl CacheAccessor for TableDataCache {
     type V = Bytes;
 
@@ -17,6 +17,7 @@ pub use v2::BlockMeta;
 pub use v2::ClusterStatistics;
 pub use v2::ColumnMeta;
 pub use v2::ColumnStatistics;
+pub use v2::DeletionVectorMeta;
 pub use v2::MetaHLL;
 pub use v2::Statistics;
 pub use v3::TableSnapshotStatistics;
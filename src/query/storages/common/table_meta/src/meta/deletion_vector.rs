@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::io::Read;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_io::prelude::BinaryRead;
+
+use crate::meta::format::compress;
+use crate::meta::format::decompress;
+use crate::meta::FormatVersion;
+use crate::meta::MetaCompression;
+
+/// The rows of a block which are deleted without rewriting the block.
+///
+/// One bit per row of the block, a set bit marks a deleted row.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeletionVector {
+    deleted: Bitmap,
+}
+
+impl DeletionVector {
+    pub const VERSION: FormatVersion = 0;
+
+    pub fn new(deleted: Bitmap) -> Self {
+        Self { deleted }
+    }
+
+    pub fn deleted(&self) -> &Bitmap {
+        &self.deleted
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.deleted.len()
+    }
+
+    pub fn deleted_rows(&self) -> usize {
+        self.deleted.len() - self.deleted.unset_bits()
+    }
+
+    /// The filter that keeps the rows which are not deleted.
+    pub fn live_rows(&self) -> Bitmap {
+        !&self.deleted
+    }
+
+    /// Marks the rows set in `deleted` as deleted as well.
+    pub fn merge(&self, deleted: &Bitmap) -> Self {
+        Self {
+            deleted: &self.deleted | deleted,
+        }
+    }
+
+    /// Serializes the deletion vector as:
+    ///
+    /// | version: u64 | compression: u8 | num_rows: u64 | compressed bitmap |
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let compression = MetaCompression::default();
+        let (bytes, offset, len) = self.deleted.as_slice();
+        let bytes = if offset == 0 {
+            bytes.to_vec()
+        } else {
+            let aligned: Bitmap = self.deleted.iter().collect();
+            aligned.as_slice().0.to_vec()
+        };
+        let compressed = compress(&compression, bytes)?;
+
+        let mut buf = Vec::with_capacity(8 + 1 + 8 + compressed.len());
+        buf.extend_from_slice(&Self::VERSION.to_le_bytes());
+        buf.push(compression as u8);
+        buf.extend_from_slice(&(len as u64).to_le_bytes());
+        buf.extend(compressed);
+        Ok(buf)
+    }
+
+    pub fn from_slice(bytes: &[u8]) -> Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let version = cursor.read_scalar::<u64>()?;
+        if version != Self::VERSION {
+            return Err(ErrorCode::Internal(format!(
+                "unknown deletion vector version {version}, versions supported: 0"
+            )));
+        }
+        let compression = MetaCompression::try_from(cursor.read_scalar::<u8>()?)?;
+        let num_rows = cursor.read_scalar::<u64>()? as usize;
+
+        let mut compressed = Vec::new();
+        cursor.read_to_end(&mut compressed)?;
+        let bytes = decompress(&compression, compressed)?;
+        let deleted = Bitmap::try_new(bytes, num_rows)
+            .map_err(|e| ErrorCode::Internal(format!("invalid deletion vector: {e}")))?;
+        Ok(Self { deleted })
+    }
+}
@@ -82,6 +82,10 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// rows deleted from the block without rewriting it
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorMeta>,
 }
 
 impl BlockMeta {
@@ -113,6 +117,7 @@ impl BlockMeta {
             inverted_index_size,
             compression,
             create_on,
+            deletion_vector: None,
         }
     }
 
@@ -120,6 +125,14 @@ impl BlockMeta {
         self.compression
     }
 
+    /// Number of the rows which are not deleted by the deletion vector.
+    pub fn live_row_count(&self) -> u64 {
+        match &self.deletion_vector {
+            Some(deletion_vector) => self.row_count - deletion_vector.deleted_rows,
+            None => self.row_count,
+        }
+    }
+
     /// Get the page size of the block.
     ///
     /// - If the format is parquet, its page size is its row count.
@@ -135,6 +148,16 @@ impl BlockMeta {
     }
 }
 
+/// Meta information of the deletion vector of a block
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeletionVectorMeta {
+    /// location of deletion vector file
+    pub location: Location,
+    /// number of rows marked as deleted
+    pub deleted_rows: u64,
+    pub file_size: u64,
+}
+
 #[typetag::serde(name = "blockmeta")]
 impl BlockMetaInfo for BlockMeta {
     fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
@@ -254,6 +277,7 @@ impl BlockMeta {
             compression: Compression::Lz4,
             inverted_index_size: None,
             create_on: None,
+            deletion_vector: None,
         }
     }
 
@@ -278,6 +302,7 @@ impl BlockMeta {
             compression: s.compression,
             inverted_index_size: None,
             create_on: None,
+            deletion_vector: None,
         }
     }
 }
@@ -64,6 +64,7 @@ impl From<BlockMeta> for crate::meta::BlockMeta {
             inverted_index_size: None,
             compression: value.compression.into(),
             create_on: None,
+            deletion_vector: None,
         }
     }
 }
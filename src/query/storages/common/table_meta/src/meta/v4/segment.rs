@@ -62,6 +62,12 @@ pub struct SegmentInfo {
 }
 
 impl SegmentInfo {
+    /// The format version of the segments which have blocks with deletion vectors.
+    ///
+    /// The layout is the same as that of v4. Readers that do not apply deletion vectors
+    /// refuse these segments, instead of returning the deleted rows.
+    pub const VERSION_WITH_DELETION_VECTORS: FormatVersion = 5;
+
     pub fn new(blocks: Vec<Arc<BlockMeta>>, summary: Statistics) -> Self {
         assert!(
             blocks.len() <= MAX_SEGMENT_BLOCK_NUMBER,
@@ -70,8 +76,13 @@ impl SegmentInfo {
             MAX_SEGMENT_BLOCK_NUMBER,
         );
 
+        let format_version = if blocks.iter().any(|b| b.deletion_vector.is_some()) {
+            SegmentInfo::VERSION_WITH_DELETION_VECTORS
+        } else {
+            SegmentInfo::VERSION
+        };
         Self {
-            format_version: SegmentInfo::VERSION,
+            format_version,
             blocks,
             summary,
         }
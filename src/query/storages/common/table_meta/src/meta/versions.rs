@@ -152,8 +152,12 @@ mod converters {
                 2 => Ok(SegmentInfoVersion::V2(testify_version::<_, 2>(PhantomData))),
                 3 => Ok(SegmentInfoVersion::V3(testify_version::<_, 3>(PhantomData))),
                 4 => Ok(SegmentInfoVersion::V4(testify_version::<_, 4>(PhantomData))),
+                // v4 segments which have blocks with deletion vectors
+                v4::SegmentInfo::VERSION_WITH_DELETION_VECTORS => {
+                    Ok(SegmentInfoVersion::V4(testify_version::<_, 4>(PhantomData)))
+                }
                 _ => Err(ErrorCode::Internal(format!(
-                    "unknown segment version {value}, versions supported: 0, 1, 2, 3, 4"
+                    "unknown segment version {value}, versions supported: 0, 1, 2, 3, 4, 5"
                 ))),
             }
         }
@@ -0,0 +1,292 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use databend_common_arrow::native;
+use databend_common_arrow::native::write::WriteOptions;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use parquet::basic::Compression as ParquetCompression;
+use parquet::basic::Encoding;
+use parquet::basic::ZstdLevel;
+use parquet::file::properties::WriterPropertiesBuilder;
+use parquet::schema::types::ColumnPath;
+
+use crate::table::TableCompression;
+
+const MAX_ZSTD_LEVEL: i32 = 22;
+
+/// The encoding of a column declared by `CODEC(...)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnEncoding {
+    Plain,
+    Delta,
+    Dictionary,
+    Rle,
+    /// Float encoding, native format only.
+    Patas,
+}
+
+impl ColumnEncoding {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "PLAIN" => Some(ColumnEncoding::Plain),
+            "DELTA" => Some(ColumnEncoding::Delta),
+            "DICT" | "DICTIONARY" => Some(ColumnEncoding::Dictionary),
+            "RLE" => Some(ColumnEncoding::Rle),
+            "PATAS" => Some(ColumnEncoding::Patas),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ColumnEncoding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnEncoding::Plain => write!(f, "PLAIN"),
+            ColumnEncoding::Delta => write!(f, "DELTA"),
+            ColumnEncoding::Dictionary => write!(f, "DICT"),
+            ColumnEncoding::Rle => write!(f, "RLE"),
+            ColumnEncoding::Patas => write!(f, "PATAS"),
+        }
+    }
+}
+
+/// The encoding and compression of a column, e.g. `CODEC(DELTA, ZSTD(3))`.
+///
+/// It is kept in the table schema in the canonical form produced by `Display`,
+/// e.g. `DELTA, ZSTD(3)`, and honoured by the block writers of both formats.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ColumnCodec {
+    pub encoding: Option<ColumnEncoding>,
+    pub compression: Option<TableCompression>,
+    pub compression_level: Option<i32>,
+}
+
+impl ColumnCodec {
+    /// Creates the codec from a list of `(name, level)`, names are case-insensitive.
+    pub fn try_create<'a>(items: impl IntoIterator<Item = (&'a str, Option<u64>)>) -> Result<Self> {
+        let mut codec = ColumnCodec::default();
+        for (name, level) in items {
+            let upper = name.to_uppercase();
+            if let Some(encoding) = ColumnEncoding::from_name(&upper) {
+                if codec.encoding.is_some() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "only one encoding can be specified in CODEC, got another '{}'",
+                        name
+                    )));
+                }
+                if level.is_some() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "encoding '{}' does not accept a level",
+                        name
+                    )));
+                }
+                codec.encoding = Some(encoding);
+                continue;
+            }
+
+            let compression = match upper.as_str() {
+                "NONE" => TableCompression::None,
+                "LZ4" => TableCompression::LZ4,
+                "SNAPPY" => TableCompression::Snappy,
+                "ZSTD" => TableCompression::Zstd,
+                _ => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "unknown codec '{}', expected one of PLAIN, DELTA, DICT, RLE, PATAS, NONE, LZ4, SNAPPY, ZSTD",
+                        name
+                    )));
+                }
+            };
+            if codec.compression.is_some() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "only one compression can be specified in CODEC, got another '{}'",
+                    name
+                )));
+            }
+            if let Some(level) = level {
+                if !matches!(compression, TableCompression::Zstd) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "compression '{}' does not accept a level",
+                        name
+                    )));
+                }
+                if level == 0 || level > MAX_ZSTD_LEVEL as u64 {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "zstd level must be between 1 and {}, got {}",
+                        MAX_ZSTD_LEVEL, level
+                    )));
+                }
+                codec.compression_level = Some(level as i32);
+            }
+            codec.compression = Some(compression);
+        }
+        Ok(codec)
+    }
+
+    /// Checks whether the codec can be applied to a column of `data_type`.
+    pub fn check_data_type(&self, data_type: &TableDataType) -> Result<()> {
+        let data_type = data_type.remove_nullable();
+        if matches!(
+            data_type,
+            TableDataType::Array(_) | TableDataType::Map(_) | TableDataType::Tuple { .. }
+        ) {
+            return Err(ErrorCode::SemanticError(format!(
+                "CODEC is not supported for column of nested type {}",
+                data_type
+            )));
+        }
+        let supported = match self.encoding {
+            Some(ColumnEncoding::Delta) => {
+                matches!(
+                    data_type,
+                    TableDataType::Number(n) if n.is_integer()
+                ) || matches!(data_type, TableDataType::Date | TableDataType::Timestamp)
+            }
+            Some(ColumnEncoding::Patas) => matches!(
+                data_type,
+                TableDataType::Number(NumberDataType::Float32 | NumberDataType::Float64)
+            ),
+            _ => true,
+        };
+        if !supported {
+            return Err(ErrorCode::SemanticError(format!(
+                "encoding {} is not supported for column of type {}",
+                self.encoding.unwrap(),
+                data_type
+            )));
+        }
+        Ok(())
+    }
+
+    /// Write options of the native format for the column, based on the default ones.
+    pub fn native_write_options(&self, default: &WriteOptions) -> WriteOptions {
+        let mut options = default.clone();
+        if let Some(compression) = self.compression {
+            options.default_compression = compression.into();
+        }
+        options.compression_level = self.compression_level.unwrap_or_default();
+        match self.encoding {
+            Some(ColumnEncoding::Plain) => {
+                options.forbidden_compressions = vec![
+                    native::Compression::Rle,
+                    native::Compression::Dict,
+                    native::Compression::OneValue,
+                    native::Compression::Freq,
+                    native::Compression::Bitpacking,
+                    native::Compression::DeltaBitpacking,
+                    native::Compression::Patas,
+                ];
+            }
+            Some(ColumnEncoding::Delta) => {
+                options.preferred_compression = Some(native::Compression::DeltaBitpacking);
+            }
+            Some(ColumnEncoding::Dictionary) => {
+                options.preferred_compression = Some(native::Compression::Dict);
+            }
+            Some(ColumnEncoding::Rle) => {
+                options.preferred_compression = Some(native::Compression::Rle);
+            }
+            Some(ColumnEncoding::Patas) => {
+                options.preferred_compression = Some(native::Compression::Patas);
+            }
+            None => {}
+        }
+        options
+    }
+
+    /// Applies the codec to the parquet writer properties of the column at `path`.
+    pub fn apply_parquet_properties(
+        &self,
+        mut builder: WriterPropertiesBuilder,
+        path: ColumnPath,
+    ) -> Result<WriterPropertiesBuilder> {
+        if let Some(compression) = self.compression {
+            let compression = match (compression, self.compression_level) {
+                (TableCompression::Zstd, Some(level)) => {
+                    ParquetCompression::ZSTD(ZstdLevel::try_new(level)?)
+                }
+                (compression, _) => compression.into(),
+            };
+            builder = builder.set_column_compression(path.clone(), compression);
+        }
+        builder = match self.encoding {
+            Some(ColumnEncoding::Plain) => builder.set_column_encoding(path, Encoding::PLAIN),
+            Some(ColumnEncoding::Delta) => {
+                builder.set_column_encoding(path, Encoding::DELTA_BINARY_PACKED)
+            }
+            Some(ColumnEncoding::Dictionary) | Some(ColumnEncoding::Rle) => {
+                builder.set_column_dictionary_enabled(path, true)
+            }
+            Some(ColumnEncoding::Patas) => {
+                builder.set_column_encoding(path, Encoding::BYTE_STREAM_SPLIT)
+            }
+            None => builder,
+        };
+        Ok(builder)
+    }
+}
+
+impl Display for ColumnCodec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut items = vec![];
+        if let Some(encoding) = self.encoding {
+            items.push(encoding.to_string());
+        }
+        if let Some(compression) = self.compression {
+            let name = match compression {
+                TableCompression::None => "NONE",
+                TableCompression::LZ4 => "LZ4",
+                TableCompression::Snappy => "SNAPPY",
+                TableCompression::Zstd => "ZSTD",
+            };
+            match self.compression_level {
+                Some(level) => items.push(format!("{}({})", name, level)),
+                None => items.push(name.to_string()),
+            }
+        }
+        write!(f, "{}", items.join(", "))
+    }
+}
+
+/// Parses the canonical form kept in the table schema, e.g. `DELTA, ZSTD(3)`.
+impl FromStr for ColumnCodec {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut items = vec![];
+        for item in s.split(',').map(|item| item.trim()) {
+            match item.split_once('(') {
+                Some((name, level)) => {
+                    let level =
+                        level
+                            .trim_end_matches(')')
+                            .trim()
+                            .parse::<u64>()
+                            .map_err(|_| {
+                                ErrorCode::UnknownFormat(format!("invalid column codec: {}", s))
+                            })?;
+                    items.push((name.trim(), Some(level)));
+                }
+                None => items.push((item, None)),
+            }
+        }
+        Self::try_create(items)
+    }
+}
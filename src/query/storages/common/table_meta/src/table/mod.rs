@@ -12,14 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod column_codec;
 mod dynamic_table_keys;
 mod stream_keys;
+mod table_clones;
 mod table_compression;
 mod table_keys;
 mod table_prefix;
+mod table_refs;
 
+pub use column_codec::ColumnCodec;
+pub use column_codec::ColumnEncoding;
 pub use dynamic_table_keys::*;
 pub use stream_keys::*;
+pub use table_clones::*;
 pub use table_compression::TableCompression;
 pub use table_keys::*;
 pub use table_prefix::*;
+pub use table_refs::*;
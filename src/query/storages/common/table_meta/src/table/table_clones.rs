@@ -0,0 +1,56 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+/// The snapshots shared with the clones of a table, stored under this internal key as a JSON.
+pub const OPT_KEY_TABLE_CLONES: &str = "table_clones";
+
+/// The id of the table a clone was created from, stored under this internal key of the clone.
+///
+/// A clone can't be cloned again: the files it shares with its source are only kept for the
+/// clones that the source knows about.
+pub const OPT_KEY_TABLE_CLONE_SOURCE: &str = "table_clone_source";
+
+/// The location of the snapshot each clone was created from, keyed by the table id of the clone.
+///
+/// The files referenced by these snapshots are kept by purge and vacuum as long as the
+/// clone exists, since the clone may still reference any of them.
+pub type TableClones = BTreeMap<u64, String>;
+
+pub fn get_table_clones(options: &BTreeMap<String, String>) -> Result<TableClones> {
+    match options.get(OPT_KEY_TABLE_CLONES) {
+        None => Ok(TableClones::new()),
+        Some(clones) => serde_json::from_str(clones).map_err(|e| {
+            ErrorCode::Internal(format!("invalid table option {OPT_KEY_TABLE_CLONES}: {e}"))
+        }),
+    }
+}
+
+pub fn set_table_clones(
+    options: &mut BTreeMap<String, String>,
+    clones: &TableClones,
+) -> Result<()> {
+    if clones.is_empty() {
+        options.remove(OPT_KEY_TABLE_CLONES);
+        return Ok(());
+    }
+
+    let clones = serde_json::to_string(clones)?;
+    options.insert(OPT_KEY_TABLE_CLONES.to_string(), clones);
+    Ok(())
+}
@@ -21,7 +21,7 @@ use parquet::basic::ZstdLevel;
 
 use crate::meta;
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum TableCompression {
     None,
     LZ4,
@@ -16,6 +16,12 @@ use std::collections::HashSet;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::sync::LazyLock;
+
+use crate::table::OPT_KEY_TABLE_BRANCH;
+use crate::table::OPT_KEY_TABLE_CLONES;
+use crate::table::OPT_KEY_TABLE_CLONE_SOURCE;
+use crate::table::OPT_KEY_TABLE_REFS;
+
 pub const OPT_KEY_DATABASE_ID: &str = "database_id";
 pub const OPT_KEY_STORAGE_PREFIX: &str = "storage_prefix";
 pub const OPT_KEY_TEMP_PREFIX: &str = "temp_prefix";
@@ -27,6 +33,11 @@ pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
 pub const OPT_KEY_CHANGE_TRACKING_BEGIN_VER: &str = "begin_version";
+/// The expression of the time a row expires at, set by the `TTL` clause of `CREATE TABLE`.
+///
+/// It can't be altered afterwards. Expired rows are hidden at read time and removed
+/// physically by `OPTIMIZE TABLE ... EXPIRE` only, there is no background expiration.
+pub const OPT_KEY_TTL: &str = "ttl";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
@@ -49,6 +60,13 @@ pub const OPT_KEY_ENGINE_META: &str = "engine_meta";
 pub const OPT_KEY_LEGACY_SNAPSHOT_LOC: &str = "snapshot_loc";
 // the following are used in for random engine
 pub const OPT_KEY_RANDOM_SEED: &str = "seed";
+// the following are used in for kafka engine
+pub const OPT_KEY_KAFKA_BROKERS: &str = "brokers";
+pub const OPT_KEY_KAFKA_TOPIC: &str = "topic";
+pub const OPT_KEY_KAFKA_FORMAT: &str = "format";
+pub const OPT_KEY_KAFKA_AVRO_SCHEMA: &str = "avro_schema";
+/// The committed offsets of the partitions, advanced together with the commit of the consumer.
+pub const OPT_KEY_KAFKA_OFFSETS: &str = "kafka_offsets";
 
 pub const OPT_KEY_CLUSTER_TYPE: &str = "cluster_type";
 pub const LINEAR_CLUSTER_TYPE: &str = "linear";
@@ -61,6 +79,12 @@ pub static RESERVED_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_KAFKA_OFFSETS);
+    r.insert(OPT_KEY_TABLE_REFS);
+    r.insert(OPT_KEY_TABLE_BRANCH);
+    r.insert(OPT_KEY_TABLE_CLONES);
+    r.insert(OPT_KEY_TABLE_CLONE_SOURCE);
+    r.insert(OPT_KEY_TTL);
     r
 });
 
@@ -72,6 +96,12 @@ pub static INTERNAL_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     r.insert(OPT_KEY_ENGINE_META);
     r.insert(OPT_KEY_CHANGE_TRACKING_BEGIN_VER);
     r.insert(OPT_KEY_TEMP_PREFIX);
+    r.insert(OPT_KEY_KAFKA_OFFSETS);
+    r.insert(OPT_KEY_TABLE_REFS);
+    r.insert(OPT_KEY_TABLE_BRANCH);
+    r.insert(OPT_KEY_TABLE_CLONES);
+    r.insert(OPT_KEY_TABLE_CLONE_SOURCE);
+    r.insert(OPT_KEY_TTL);
     r
 });
 
@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+/// The named tags and branches of a table, stored under this internal key as a JSON.
+pub const OPT_KEY_TABLE_REFS: &str = "table_refs";
+
+/// The branch a table handle reads from and writes to.
+///
+/// Only set in memory by the navigation to a branch, it is never persisted.
+pub const OPT_KEY_TABLE_BRANCH: &str = "table_branch";
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TableRefKind {
+    /// An immutable name of a snapshot.
+    Tag,
+    /// A line of snapshots which accepts writes independently of the table.
+    Branch,
+}
+
+impl Display for TableRefKind {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TableRefKind::Tag => write!(f, "TAG"),
+            TableRefKind::Branch => write!(f, "BRANCH"),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TableRef {
+    pub kind: TableRefKind,
+    /// None if the table was empty when the ref was created.
+    pub snapshot_location: Option<String>,
+    pub created_on: DateTime<Utc>,
+    /// The ref and the snapshots it holds can be purged after this time.
+    pub expire_at: Option<DateTime<Utc>>,
+}
+
+impl TableRef {
+    pub fn is_expired(&self, now: &DateTime<Utc>) -> bool {
+        self.expire_at
+            .as_ref()
+            .is_some_and(|expire_at| expire_at <= now)
+    }
+}
+
+pub type TableRefs = BTreeMap<String, TableRef>;
+
+pub fn get_table_refs(options: &BTreeMap<String, String>) -> Result<TableRefs> {
+    match options.get(OPT_KEY_TABLE_REFS) {
+        None => Ok(TableRefs::new()),
+        Some(refs) => serde_json::from_str(refs).map_err(|e| {
+            ErrorCode::Internal(format!("invalid table option {OPT_KEY_TABLE_REFS}: {e}"))
+        }),
+    }
+}
+
+pub fn set_table_refs(options: &mut BTreeMap<String, String>, refs: &TableRefs) -> Result<()> {
+    if refs.is_empty() {
+        options.remove(OPT_KEY_TABLE_REFS);
+        return Ok(());
+    }
+
+    let refs = serde_json::to_string(refs)?;
+    options.insert(OPT_KEY_TABLE_REFS.to_string(), refs);
+    Ok(())
+}
+
+pub fn get_table_ref(options: &BTreeMap<String, String>, name: &str) -> Result<TableRef> {
+    get_table_refs(options)?
+        .remove(name)
+        .ok_or_else(|| ErrorCode::UnknownTableRef(format!("Unknown tag or branch '{name}'")))
+}
@@ -22,6 +22,7 @@ use databend_common_exception::Result;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_storages_delta::DeltaTable;
 use databend_common_storages_iceberg::IcebergTable;
+use databend_common_storages_kafka::KafkaTable;
 use databend_common_storages_memory::MemoryTable;
 use databend_common_storages_null::NullTable;
 use databend_common_storages_random::RandomTable;
@@ -161,6 +162,13 @@ impl StorageFactory {
             table_info_refresher: None,
         });
 
+        // Register KAFKA table engine
+        creators.insert("KAFKA".to_string(), Storage {
+            creator: Arc::new(KafkaTable::try_create),
+            descriptor: Arc::new(KafkaTable::description),
+            table_info_refresher: None,
+        });
+
         StorageFactory {
             storages: creators,
             schema_refreshing_timeout: DEFAULT_SCHEMA_REFRESHING_TIMEOUT_MS,
@@ -70,9 +70,11 @@ use databend_storages_common_table_meta::meta::ClusterKey;
 use databend_storages_common_table_meta::meta::CompactSegmentInfo;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::Statistics as FuseStatistics;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
 use databend_storages_common_table_meta::meta::Versioned;
+use databend_storages_common_table_meta::table::get_table_ref;
 use databend_storages_common_table_meta::table::ChangeType;
 use databend_storages_common_table_meta::table::ClusterType;
 use databend_storages_common_table_meta::table::TableCompression;
@@ -84,6 +86,7 @@ use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_BRANCH;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use log::info;
 use log::warn;
@@ -111,6 +114,7 @@ use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use crate::FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS;
+use crate::FUSE_OPT_KEY_ENABLE_DELETION_VECTORS;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
@@ -364,6 +368,10 @@ impl FuseTable {
             DatabaseType::NormalDB => {
                 let options = self.table_info.options();
 
+                if let Some(branch) = options.get(OPT_KEY_TABLE_BRANCH) {
+                    return Ok(get_table_ref(options, branch)?.snapshot_location);
+                }
+
                 if let Some(storage_prefix) = options.get(OPT_KEY_STORAGE_PREFIX) {
                     // if table is attached, parse snapshot location from hint file
                     let hint = format!("{}/{}", storage_prefix, FUSE_TBL_LAST_SNAPSHOT_HINT);
@@ -429,6 +437,11 @@ impl FuseTable {
             .is_some()
     }
 
+    /// Whether the reads and writes of the table go to a branch.
+    pub fn is_branch(&self) -> bool {
+        self.table_info.options().contains_key(OPT_KEY_TABLE_BRANCH)
+    }
+
     pub fn cluster_key_types(&self, ctx: Arc<dyn TableContext>) -> Vec<DataType> {
         let Some((_, cluster_key_str)) = &self.cluster_key_meta else {
             return vec![];
@@ -465,6 +478,11 @@ impl FuseTable {
     pub fn get_storage_format(&self) -> FuseStorageFormat {
         self.storage_format
     }
+
+    /// Whether DELETE records the deleted rows in deletion vectors instead of rewriting blocks.
+    pub fn deletion_vectors_enabled(&self) -> bool {
+        self.get_option(FUSE_OPT_KEY_ENABLE_DELETION_VECTORS, false)
+    }
 }
 
 #[async_trait::async_trait]
@@ -782,6 +800,28 @@ impl Table for FuseTable {
                     number_of_segments: Some(snapshot.segments.len() as u64),
                 }
             }
+            // The statistics in the table meta are the ones of the main line.
+            _ if self.is_branch() => match self.read_table_snapshot().await? {
+                Some(snapshot) => {
+                    let summary = &snapshot.summary;
+                    TableStatistics {
+                        num_rows: Some(summary.row_count),
+                        data_size: Some(summary.uncompressed_byte_size),
+                        data_size_compressed: Some(summary.compressed_byte_size),
+                        index_size: Some(summary.index_size),
+                        number_of_blocks: Some(summary.block_count),
+                        number_of_segments: Some(snapshot.segments.len() as u64),
+                    }
+                }
+                None => TableStatistics {
+                    num_rows: Some(0),
+                    data_size: Some(0),
+                    data_size_compressed: Some(0),
+                    index_size: Some(0),
+                    number_of_blocks: Some(0),
+                    number_of_segments: Some(0),
+                },
+            },
             _ => {
                 let s = &self.table_info.meta.statistics;
                 TableStatistics {
@@ -803,18 +843,23 @@ impl Table for FuseTable {
         _ctx: Arc<dyn TableContext>,
     ) -> Result<Box<dyn ColumnStatisticsProvider>> {
         let provider = if let Some(snapshot) = self.read_table_snapshot().await? {
-            let stats = &snapshot.summary.col_stats;
+            // The column statistics count the rows of deletion vectors as well.
+            let stats = if self.deletion_vectors_enabled() {
+                StatisticsOfColumns::default()
+            } else {
+                snapshot.summary.col_stats.clone()
+            };
             let table_statistics = self.read_table_snapshot_statistics(Some(&snapshot)).await?;
             if let Some(table_statistics) = table_statistics {
                 FuseTableColumnStatisticsProvider::new(
-                    stats.clone(),
+                    stats,
                     table_statistics.histograms.clone(),
                     Some(table_statistics.column_distinct_values()),
                     snapshot.summary.row_count,
                 )
             } else {
                 FuseTableColumnStatisticsProvider::new(
-                    stats.clone(),
+                    stats,
                     HashMap::new(),
                     None,
                     snapshot.summary.row_count,
@@ -836,6 +881,11 @@ impl Table for FuseTable {
             return Ok(Some(HashMap::new()));
         }
 
+        // The ranges of the blocks still cover the rows of deletion vectors.
+        if self.deletion_vectors_enabled() {
+            return Ok(None);
+        }
+
         let Some(snapshot) = self.read_table_snapshot().await? else {
             return Ok(Some(HashMap::new()));
         };
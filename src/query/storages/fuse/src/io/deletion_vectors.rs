@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_storages_common_table_meta::meta::DeletionVector;
+use databend_storages_common_table_meta::meta::DeletionVectorMeta;
+use opendal::Operator;
+
+use crate::io::TableMetaLocationGenerator;
+
+// Read and write the deletion vectors of blocks.
+pub struct DeletionVectorsIO;
+
+impl DeletionVectorsIO {
+    #[async_backtrace::framed]
+    pub async fn read(dal: &Operator, meta: &DeletionVectorMeta) -> Result<DeletionVector> {
+        let bytes = dal.read(&meta.location.0).await?.to_vec();
+        DeletionVector::from_slice(&bytes)
+    }
+
+    pub fn sync_read(dal: &Operator, meta: &DeletionVectorMeta) -> Result<DeletionVector> {
+        let bytes = dal.blocking().read(&meta.location.0)?.to_vec();
+        DeletionVector::from_slice(&bytes)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn write(
+        dal: &Operator,
+        location_gen: &TableMetaLocationGenerator,
+        deletion_vector: &DeletionVector,
+    ) -> Result<DeletionVectorMeta> {
+        let location = location_gen.gen_deletion_vector_location();
+        let data = deletion_vector.to_bytes()?;
+        let file_size = data.len() as u64;
+        dal.write(&location.0, data).await?;
+        Ok(DeletionVectorMeta {
+            location,
+            deleted_rows: deletion_vector.deleted_rows() as u64,
+            file_size,
+        })
+    }
+
+    /// Removes the deleted rows from a block which is read in full.
+    pub fn apply(
+        data_block: DataBlock,
+        deletion_vector: Option<&DeletionVector>,
+    ) -> Result<DataBlock> {
+        match deletion_vector {
+            Some(deletion_vector) => data_block.filter_with_bitmap(&deletion_vector.live_rows()),
+            None => Ok(data_block),
+        }
+    }
+}
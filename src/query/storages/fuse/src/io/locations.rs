@@ -17,6 +17,7 @@ use std::marker::PhantomData;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_storages_common_table_meta::meta::trim_vacuum2_object_prefix;
+use databend_storages_common_table_meta::meta::DeletionVector;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::SnapshotVersion;
@@ -27,6 +28,7 @@ use uuid::Uuid;
 use uuid::Version;
 
 use crate::constants::FUSE_TBL_BLOCK_PREFIX;
+use crate::constants::FUSE_TBL_DELETION_VECTOR_PREFIX;
 use crate::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
@@ -105,6 +107,20 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    pub fn gen_deletion_vector_location(&self) -> Location {
+        let uuid = Uuid::new_v4().simple().to_string();
+        (
+            format!(
+                "{}/{}/{}_v{}.bin",
+                &self.prefix,
+                FUSE_TBL_DELETION_VECTOR_PREFIX,
+                uuid,
+                DeletionVector::VERSION,
+            ),
+            DeletionVector::VERSION,
+        )
+    }
+
     pub fn gen_segment_info_location(&self) -> String {
         let segment_uuid = Uuid::new_v4().simple().to_string();
         format!(
@@ -48,6 +48,7 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
                 );
                 let res = self
                     .reader
@@ -95,6 +96,7 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
                 );
                 Some((part, res))
             }
@@ -14,12 +14,14 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Instant;
 
 use chrono::Utc;
 use databend_common_arrow::arrow::chunk::Chunk as ArrowChunk;
 use databend_common_arrow::native::write::NativeWriter;
+use databend_common_arrow::native::write::WriteOptions;
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
@@ -48,6 +50,7 @@ use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
 use databend_storages_common_table_meta::meta::ColumnMeta;
 use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::table::ColumnCodec;
 use databend_storages_common_table_meta::table::TableCompression;
 use opendal::Operator;
 
@@ -79,21 +82,29 @@ pub fn serialize_block(
             let arrow_schema = schema.as_ref().into();
             let leaf_column_ids = schema.to_leaf_column_ids();
 
-            let mut default_compress_ratio = Some(2.10f64);
-            if matches!(write_settings.table_compression, TableCompression::Zstd) {
-                default_compress_ratio = Some(3.72f64);
+            let options = WriteOptions {
+                default_compression: write_settings.table_compression.into(),
+                max_page_size: Some(write_settings.max_page_size),
+                default_compress_ratio: native_compress_ratio(write_settings.table_compression),
+                forbidden_compressions: vec![],
+                ..Default::default()
+            };
+
+            // columns declared with `CODEC(...)` override the table level options
+            let mut column_options = HashMap::new();
+            for (index, field) in schema.fields().iter().enumerate() {
+                if let Some(codec) = field.codec() {
+                    let codec = ColumnCodec::from_str(codec)?;
+                    let mut column_option = codec.native_write_options(&options);
+                    if let Some(compression) = codec.compression {
+                        column_option.default_compress_ratio = native_compress_ratio(compression);
+                    }
+                    column_options.insert(index, column_option);
+                }
             }
 
-            let mut writer = NativeWriter::new(
-                buf,
-                arrow_schema,
-                databend_common_arrow::native::write::WriteOptions {
-                    default_compression: write_settings.table_compression.into(),
-                    max_page_size: Some(write_settings.max_page_size),
-                    default_compress_ratio,
-                    forbidden_compressions: vec![],
-                },
-            )?;
+            let mut writer =
+                NativeWriter::new(buf, arrow_schema, options)?.with_column_options(column_options);
 
             let batch = ArrowChunk::try_from(block)?;
 
@@ -113,6 +124,14 @@ pub fn serialize_block(
     }
 }
 
+fn native_compress_ratio(compression: TableCompression) -> Option<f64> {
+    if matches!(compression, TableCompression::Zstd) {
+        Some(3.72f64)
+    } else {
+        Some(2.10f64)
+    }
+}
+
 /// Take ownership here to avoid extra copy.
 #[async_backtrace::framed]
 pub async fn write_data(data: Vec<u8>, data_accessor: &Operator, location: &str) -> Result<()> {
@@ -405,6 +424,7 @@ impl BlockBuilder {
             compression: self.write_settings.table_compression.into(),
             inverted_index_size,
             create_on: Some(Utc::now()),
+            deletion_vector: None,
         };
 
         let serialized = BlockSerialization {
@@ -71,7 +71,10 @@ impl Marshal for SegmentInfo {
     fn marshal(&self) -> Result<Vec<u8>> {
         // make sure the table meta we write down to object store always has the current version
         // can we expressed as type constraint?
-        assert_eq!(self.format_version, SegmentInfo::VERSION);
+        assert!(
+            self.format_version == SegmentInfo::VERSION
+                || self.format_version == SegmentInfo::VERSION_WITH_DELETION_VECTORS
+        );
         self.to_bytes()
     }
 }
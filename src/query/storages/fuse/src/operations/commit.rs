@@ -47,8 +47,11 @@ use databend_storages_common_table_meta::meta::Statistics;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
 use databend_storages_common_table_meta::meta::Versioned;
+use databend_storages_common_table_meta::table::get_table_refs;
+use databend_storages_common_table_meta::table::set_table_refs;
 use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_BRANCH;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use log::debug;
 use log::info;
@@ -184,6 +187,19 @@ impl FuseTable {
         new_snapshot: &TableSnapshot,
     ) -> Result<TableMeta> {
         let mut new_table_meta = old_meta.clone();
+        // A commit on a branch only moves the head of the branch,
+        // the snapshot location and statistics of the table are kept.
+        if let Some(branch) = new_table_meta.options.remove(OPT_KEY_TABLE_BRANCH) {
+            let mut refs = get_table_refs(&new_table_meta.options)?;
+            let table_ref = refs.get_mut(&branch).ok_or_else(|| {
+                ErrorCode::UnknownTableRef(format!("Unknown branch '{}'", branch))
+            })?;
+            table_ref.snapshot_location = Some(new_snapshot_location.to_owned());
+            set_table_refs(&mut new_table_meta.options, &refs)?;
+            new_table_meta.updated_on = Utc::now();
+            return Ok(new_table_meta);
+        }
+
         // 1.1 set new snapshot location
         new_table_meta.options.insert(
             OPT_KEY_SNAPSHOT_LOCATION.to_owned(),
@@ -265,7 +281,10 @@ impl FuseTable {
 
         // update_table_meta succeed, populate the snapshot cache item and try keeping a hit file of last snapshot
         TableSnapshot::cache().insert(snapshot_location.clone(), snapshot);
-        Self::write_last_snapshot_hint(ctx, operator, location_generator, &snapshot_location).await;
+        if !table_info.options().contains_key(OPT_KEY_TABLE_BRANCH) {
+            Self::write_last_snapshot_hint(ctx, operator, location_generator, &snapshot_location)
+                .await;
+        }
 
         Ok(())
     }
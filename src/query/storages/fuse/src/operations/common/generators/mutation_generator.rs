@@ -46,6 +46,10 @@ impl MutationGenerator {
             mutation_kind,
         }
     }
+
+    pub fn mutation_kind(&self) -> MutationKind {
+        self.mutation_kind
+    }
 }
 
 impl SnapshotGenerator for MutationGenerator {
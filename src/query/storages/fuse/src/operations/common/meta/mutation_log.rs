@@ -19,6 +19,7 @@ use databend_common_expression::BlockMetaInfo;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::DataBlock;
 use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::DeletionVectorMeta;
 use databend_storages_common_table_meta::meta::FormatVersion;
 use databend_storages_common_table_meta::meta::Statistics;
 
@@ -52,6 +53,11 @@ pub enum MutationLogEntry {
         index: BlockMetaIndex,
         block_meta: Arc<BlockMeta>,
     },
+    /// Replace the deletion vector of the block, the block file is kept.
+    DeletionVector {
+        index: BlockMetaIndex,
+        deletion_vector: DeletionVectorMeta,
+    },
     CompactExtras {
         extras: CompactExtraInfo,
     },
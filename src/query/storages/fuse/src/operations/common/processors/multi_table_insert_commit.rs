@@ -32,7 +32,6 @@ use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateTempTableReq;
 use databend_common_meta_types::MatchSeq;
 use databend_common_pipeline_sinks::AsyncSink;
-use databend_storages_common_session::TxnManagerRef;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
 use log::debug;
@@ -103,7 +102,7 @@ impl AsyncSink for CommitMultiTableInsert {
                     build_update_table_meta_req(
                         table.as_ref(),
                         &snapshot_generator,
-                        self.ctx.txn_mgr(),
+                        self.ctx.clone(),
                     )
                     .await?,
                     table.get_table_info().clone(),
@@ -190,7 +189,7 @@ impl AsyncSink for CommitMultiTableInsert {
                                 *req = build_update_table_meta_req(
                                     table.as_ref(),
                                     snapshot_generators.get(&tid).unwrap(),
-                                    self.ctx.txn_mgr(),
+                                    self.ctx.clone(),
                                 )
                                 .await?;
                                 break;
@@ -244,19 +243,22 @@ impl AsyncSink for CommitMultiTableInsert {
 async fn build_update_table_meta_req(
     table: &dyn Table,
     snapshot_generator: &AppendGenerator,
-    txn_mgr: TxnManagerRef,
+    ctx: Arc<dyn TableContext>,
 ) -> Result<UpdateTableMetaReq> {
     let fuse_table = FuseTable::try_from_table(table)?;
     let previous = fuse_table.read_table_snapshot().await?;
     let snapshot = snapshot_generator.generate_new_snapshot(
         table.schema().as_ref().clone(),
         fuse_table.cluster_key_meta.clone(),
-        previous,
+        previous.clone(),
         Some(fuse_table.table_info.ident.seq),
-        txn_mgr,
+        ctx.txn_mgr(),
         table.get_id(),
         table.name(),
     )?;
+    fuse_table
+        .validate_unique_keys(ctx, previous.as_deref(), &snapshot)
+        .await?;
 
     // write snapshot
     let dal = fuse_table.get_operator();
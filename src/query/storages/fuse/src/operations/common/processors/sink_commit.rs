@@ -34,6 +34,7 @@ use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_sql::executor::physical_plans::MutationKind;
 use databend_storages_common_table_meta::meta::ClusterKey;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SnapshotId;
@@ -49,6 +50,7 @@ use crate::io::TableMetaLocationGenerator;
 use crate::operations::set_backoff;
 use crate::operations::AppendGenerator;
 use crate::operations::CommitMeta;
+use crate::operations::MutationGenerator;
 use crate::operations::SnapshotGenerator;
 use crate::operations::TruncateGenerator;
 use crate::operations::TruncateMode;
@@ -66,6 +68,7 @@ enum State {
     TryCommit {
         data: Vec<u8>,
         snapshot: TableSnapshot,
+        previous: Option<Arc<TableSnapshot>>,
         table_info: TableInfo,
     },
     Abort(ErrorCode),
@@ -200,6 +203,33 @@ where F: SnapshotGenerator + Send + 'static
             .downcast_ref::<AppendGenerator>()
             .is_some()
     }
+
+    // Whether the enforced PRIMARY KEY and UNIQUE constraints should be validated
+    // before committing, i.e. the txn may write new keys into a table that has them.
+    fn need_validate_unique_keys(&self) -> bool {
+        let has_enforced_keys = self
+            .table
+            .get_table_info()
+            .meta
+            .constraints
+            .values()
+            .any(|constraint| constraint.enforced_key().is_some());
+        if !has_enforced_keys {
+            return false;
+        }
+
+        self.is_append_only_txn()
+            || self
+                .snapshot_gen
+                .as_any()
+                .downcast_ref::<MutationGenerator>()
+                .is_some_and(|gen| {
+                    !matches!(
+                        gen.mutation_kind(),
+                        MutationKind::Delete | MutationKind::Compact | MutationKind::Recluster
+                    )
+                })
+    }
 }
 
 #[async_trait::async_trait]
@@ -284,7 +314,7 @@ where F: SnapshotGenerator + Send + 'static
                 match self.snapshot_gen.generate_new_snapshot(
                     schema,
                     cluster_key_meta,
-                    previous,
+                    previous.clone(),
                     Some(table_info.ident.seq),
                     self.ctx.txn_mgr(),
                     table_info.ident.table_id,
@@ -294,6 +324,7 @@ where F: SnapshotGenerator + Send + 'static
                         self.state = State::TryCommit {
                             data: snapshot.to_bytes()?,
                             snapshot,
+                            previous,
                             table_info,
                         };
                     }
@@ -356,8 +387,20 @@ where F: SnapshotGenerator + Send + 'static
             State::TryCommit {
                 data,
                 snapshot,
+                previous,
                 table_info,
             } => {
+                if self.need_validate_unique_keys() {
+                    let fuse_table = FuseTable::try_from_table(self.table.as_ref())?;
+                    if let Err(e) = fuse_table
+                        .validate_unique_keys(self.ctx.clone(), previous.as_deref(), &snapshot)
+                        .await
+                    {
+                        self.state = State::Abort(e);
+                        return Ok(());
+                    }
+                }
+
                 let location = self
                     .location_gen
                     .snapshot_location_from_uuid(&snapshot.snapshot_id, TableSnapshot::VERSION)?;
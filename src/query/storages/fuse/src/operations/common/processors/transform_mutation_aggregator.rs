@@ -30,10 +30,10 @@ use databend_common_metrics::storage::metrics_inc_recluster_write_block_nums;
 use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
 use databend_common_sql::executor::physical_plans::MutationKind;
 use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::DeletionVectorMeta;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::Statistics;
-use databend_storages_common_table_meta::meta::Versioned;
 use itertools::Itertools;
 use log::debug;
 use log::info;
@@ -188,6 +188,15 @@ impl TableMutationAggregator {
                 metrics_inc_recluster_write_block_nums();
                 self.recluster_merged_blocks.push(block_meta);
             }
+            MutationLogEntry::DeletionVector {
+                index,
+                deletion_vector,
+            } => {
+                self.mutations
+                    .entry(index.segment_idx)
+                    .or_default()
+                    .push_deletion_vector(index.block_idx, deletion_vector);
+            }
             MutationLogEntry::DeletedBlock { index } => {
                 self.mutations
                     .entry(index.segment_idx)
@@ -225,7 +234,7 @@ impl TableMutationAggregator {
                     Entry::Vacant(v) => {
                         v.insert(BlockMutations {
                             replaced_blocks: extras.unchanged_blocks,
-                            deleted_blocks: vec![],
+                            ..Default::default()
                         });
                     }
                 }
@@ -299,17 +308,15 @@ impl TableMutationAggregator {
         if new_segments_len > removed_segments_len {
             // The remain new segments will be append.
             let appended = new_segments.split_off(removed_segments_len);
-            for (location, stats) in appended.into_iter().rev() {
-                let segment_loc = (location, SegmentInfo::VERSION);
+            for (segment_loc, stats) in appended.into_iter().rev() {
                 new_segment_locs.push(segment_loc.clone());
                 appended_segments.push(segment_loc);
                 merge_statistics_mut(&mut merged_statistics, &stats, self.default_cluster_key_id);
             }
         }
 
-        for (i, (location, stats)) in new_segments.into_iter().enumerate() {
+        for (i, (segment_loc, stats)) in new_segments.into_iter().enumerate() {
             // The old segments will be replaced with the news.
-            let segment_loc = (location, SegmentInfo::VERSION);
             new_segment_locs.push(segment_loc.clone());
             replaced_segments.insert(self.removed_segment_indexes[i], segment_loc);
             merge_statistics_mut(&mut merged_statistics, &stats, self.default_cluster_key_id);
@@ -344,9 +351,8 @@ impl TableMutationAggregator {
         for chunk in segment_indices.chunks(chunk_size) {
             let results = self.partial_apply_mutation(chunk.to_vec()).await?;
             for result in results {
-                if let Some((location, summary)) = result.new_segment_info {
+                if let Some((new_segment_loc, summary)) = result.new_segment_info {
                     // replace the old segment location with the new one.
-                    let new_segment_loc = (location, SegmentInfo::VERSION);
                     new_segment_locs.push(new_segment_loc.clone());
                     merge_statistics_mut(
                         &mut merged_statistics,
@@ -432,6 +438,13 @@ impl TableMutationAggregator {
                     for (idx, new_meta) in segment_mutation.replaced_blocks {
                         block_editor.insert(idx, new_meta);
                     }
+                    for (idx, deletion_vector) in segment_mutation.deletion_vectors {
+                        if let Some(block_meta) = block_editor.get_mut(&idx) {
+                            let mut new_meta = block_meta.as_ref().clone();
+                            new_meta.deletion_vector = Some(deletion_vector);
+                            *block_meta = Arc::new(new_meta);
+                        }
+                    }
                     for idx in segment_mutation.deleted_blocks {
                         block_editor.remove(&idx);
                     }
@@ -450,6 +463,7 @@ impl TableMutationAggregator {
                 } else {
                     // use by compact.
                     assert!(segment_mutation.deleted_blocks.is_empty());
+                    assert!(segment_mutation.deletion_vectors.is_empty());
                     // There are more than 1 blocks, means that the blocks can no longer be compacted.
                     // They can be marked as perfect blocks.
                     all_perfect = segment_mutation.replaced_blocks.len() > 1;
@@ -499,20 +513,21 @@ impl TableMutationAggregator {
 struct BlockMutations {
     replaced_blocks: Vec<(BlockIndex, Arc<BlockMeta>)>,
     deleted_blocks: Vec<BlockIndex>,
+    deletion_vectors: Vec<(BlockIndex, DeletionVectorMeta)>,
 }
 
 impl BlockMutations {
     fn new_replacement(block_idx: BlockIndex, block_meta: Arc<BlockMeta>) -> Self {
         BlockMutations {
             replaced_blocks: vec![(block_idx, block_meta)],
-            deleted_blocks: vec![],
+            ..Default::default()
         }
     }
 
     fn new_deletion(block_idx: BlockIndex) -> Self {
         BlockMutations {
-            replaced_blocks: vec![],
             deleted_blocks: vec![block_idx],
+            ..Default::default()
         }
     }
 
@@ -523,13 +538,17 @@ impl BlockMutations {
     fn push_deleted(&mut self, block_idx: BlockIndex) {
         self.deleted_blocks.push(block_idx)
     }
+
+    fn push_deletion_vector(&mut self, block_idx: BlockIndex, deletion_vector: DeletionVectorMeta) {
+        self.deletion_vectors.push((block_idx, deletion_vector))
+    }
 }
 
 struct SegmentLite {
     // segment index.
     index: usize,
     // new segment location and summary.
-    new_segment_info: Option<(String, Statistics)>,
+    new_segment_info: Option<(Location, Statistics)>,
     // origin segment summary.
     origin_summary: Option<Statistics>,
 }
@@ -542,7 +561,7 @@ async fn write_segment(
     default_cluster_key: Option<u32>,
     all_perfect: bool,
     kind: MutationKind,
-) -> Result<(String, Statistics)> {
+) -> Result<(Location, Statistics)> {
     let location = location_gen.gen_segment_info_location();
     let mut new_summary = reduce_block_metas(&blocks, thresholds, default_cluster_key);
     if all_perfect {
@@ -560,5 +579,5 @@ async fn write_segment(
     new_segment
         .write_meta_through_cache(&dal, &location)
         .await?;
-    Ok((location, new_summary))
+    Ok(((location, new_segment.format_version), new_summary))
 }
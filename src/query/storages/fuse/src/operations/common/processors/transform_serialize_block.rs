@@ -246,6 +246,15 @@ impl Processor for TransformSerializeBlock {
                         Ok(Event::Sync)
                     }
                 }
+                SerializeDataMeta::DeletionVector(info) => {
+                    // rows deleted from the block, the block file is kept
+                    let data_block = Self::mutation_logs(MutationLogEntry::DeletionVector {
+                        index: info.index,
+                        deletion_vector: info.deletion_vector,
+                    });
+                    self.output.push_data(Ok(data_block));
+                    Ok(Event::NeedConsume)
+                }
                 SerializeDataMeta::CompactExtras(compact_extras) => {
                     // compact extras
                     let data_block = Self::mutation_logs(MutationLogEntry::CompactExtras {
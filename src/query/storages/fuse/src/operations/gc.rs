@@ -17,6 +17,7 @@ use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
 
+use chrono::Utc;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -35,6 +36,7 @@ use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
+use databend_storages_common_table_meta::table::get_table_refs;
 use log::error;
 use log::info;
 use log::warn;
@@ -159,6 +161,9 @@ impl FuseTable {
                 if let Ok(loc) =
                     location_gen.snapshot_location_from_uuid(&s.snapshot_id, s.format_version)
                 {
+                    if root_snapshot_info.ref_snapshots.contains(&loc) {
+                        continue;
+                    }
                     if purged_snapshot_count >= purged_snapshot_limit {
                         break;
                     }
@@ -188,6 +193,8 @@ impl FuseTable {
                 ctx.set_status_info(&status);
             }
 
+            root_snapshot_info
+                .retain_unreferenced(&mut segments_to_be_purged, &mut ts_to_be_purged);
             if !snapshots_to_be_purged.is_empty() {
                 if dry_run {
                     debug_assert!(num_snapshot_limit.is_some());
@@ -233,6 +240,9 @@ impl FuseTable {
                 if let Ok(loc) =
                     location_gen.snapshot_location_from_uuid(&s.snapshot_id, s.format_version)
                 {
+                    if root_snapshot_info.ref_snapshots.contains(&loc) {
+                        continue;
+                    }
                     if purged_snapshot_count >= purged_snapshot_limit {
                         break;
                     }
@@ -246,6 +256,8 @@ impl FuseTable {
                     ts_to_be_purged.insert(s.table_statistics_location.unwrap());
                 }
             }
+            root_snapshot_info
+                .retain_unreferenced(&mut segments_to_be_purged, &mut ts_to_be_purged);
             if dry_run {
                 self.dry_run_purge(
                     ctx,
@@ -326,9 +338,38 @@ impl FuseTable {
         };
 
         // root snapshot cannot ignore storage not find error.
-        let referenced_locations = self
+        let mut referenced_locations = self
             .get_block_locations(ctx.clone(), &root_snapshot.segments, put_cache, false)
             .await?;
+
+        let mut held_snapshots = self.held_snapshot_locations(ctx).await?;
+        held_snapshots.remove(&snapshot_location);
+
+        let mut ref_snapshots = HashSet::new();
+        let mut ref_segments = HashSet::new();
+        let mut ref_table_statistics = HashSet::new();
+        for location in held_snapshots {
+            let (snapshot, _) =
+                SnapshotsIO::read_snapshot(location.clone(), self.get_operator()).await?;
+            let locations = self
+                .get_block_locations(ctx.clone(), &snapshot.segments, false, false)
+                .await?;
+            referenced_locations
+                .block_location
+                .extend(locations.block_location);
+            referenced_locations
+                .bloom_location
+                .extend(locations.bloom_location);
+            referenced_locations
+                .deletion_vector_location
+                .extend(locations.deletion_vector_location);
+            ref_segments.extend(snapshot.segments.iter().cloned());
+            if let Some(ts_location) = &snapshot.table_statistics_location {
+                ref_table_statistics.insert(ts_location.clone());
+            }
+            ref_snapshots.insert(location);
+        }
+
         let snapshot_lite = Arc::new(SnapshotLiteExtended {
             format_version: ver,
             snapshot_id: root_snapshot.snapshot_id,
@@ -340,6 +381,9 @@ impl FuseTable {
             snapshot_location,
             referenced_locations,
             snapshot_lite,
+            ref_snapshots,
+            ref_segments,
+            ref_table_statistics,
         }))
     }
 
@@ -384,11 +428,23 @@ impl FuseTable {
                 purge_files.push(loc.to_string())
             }
 
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                purge_files.push(loc.to_string())
+            }
+
             purge_files.extend(chunk.iter().map(|loc| loc.0.clone()));
         }
         purge_files.extend(ts_to_be_purged.iter().map(|loc| loc.to_string()));
         purge_files.extend(snapshots_to_be_purged.iter().map(|loc| loc.to_string()));
 
+        let prefix = format!("{}/", self.meta_location_generator().prefix());
+        purge_files.retain(|loc| loc.starts_with(&prefix));
         Ok(())
     }
 
@@ -449,6 +505,17 @@ impl FuseTable {
                 blooms_to_be_purged.insert(loc.to_string());
             }
 
+            // deletion vectors are purged along with the blocks.
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                blocks_to_be_purged.insert(loc.to_string());
+            }
+
             let segment_locations_to_be_purged = HashSet::from_iter(
                 chunk
                     .iter()
@@ -528,10 +595,13 @@ impl FuseTable {
             ));
         }
 
+        let mut blocks_to_be_purged = root_location_tuple.block_location;
+        // deletion vectors are purged along with the blocks.
+        blocks_to_be_purged.extend(root_location_tuple.deletion_vector_location);
         self.purge_block_segments(
             ctx,
             counter,
-            root_location_tuple.block_location,
+            blocks_to_be_purged,
             agg_indexes_to_be_purged,
             inverted_indexes_to_be_purged,
             root_location_tuple.bloom_location,
@@ -675,6 +745,13 @@ impl FuseTable {
         ctx: Arc<dyn TableContext>,
         locations_to_be_purged: HashSet<String>,
     ) -> Result<()> {
+        // The files out of the storage prefix are shared with the table this table was cloned
+        // from, and are purged by that table.
+        let prefix = format!("{}/", self.meta_location_generator().prefix());
+        let locations_to_be_purged = locations_to_be_purged
+            .into_iter()
+            .filter(|location| location.starts_with(&prefix))
+            .collect::<HashSet<_>>();
         let fuse_file = Files::create(ctx.clone(), self.operator.clone());
         fuse_file.remove_file_in_batch(locations_to_be_purged).await
     }
@@ -708,6 +785,7 @@ impl FuseTable {
     ) -> Result<LocationTuple> {
         let mut blocks = HashSet::new();
         let mut blooms = HashSet::new();
+        let mut deletion_vectors = HashSet::new();
 
         let fuse_segments = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
@@ -731,15 +809,36 @@ impl FuseTable {
                 };
                 blocks.extend(location_tuple.block_location.into_iter());
                 blooms.extend(location_tuple.bloom_location.into_iter());
+                deletion_vectors.extend(location_tuple.deletion_vector_location.into_iter());
             }
         }
 
         Ok(LocationTuple {
             block_location: blocks,
             bloom_location: blooms,
+            deletion_vector_location: deletion_vectors,
         })
     }
 
+    /// The snapshots which are roots of the garbage collection besides the current snapshot.
+    ///
+    /// The snapshots held by tags and branches are roots until the refs expire, and the
+    /// snapshots shared with the clones of the table are roots until the clones are gone.
+    #[async_backtrace::framed]
+    pub async fn held_snapshot_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<HashSet<String>> {
+        let now = Utc::now();
+        let mut held_snapshots = get_table_refs(self.table_info.options())?
+            .into_values()
+            .filter(|table_ref| !table_ref.is_expired(&now))
+            .filter_map(|table_ref| table_ref.snapshot_location)
+            .collect::<HashSet<_>>();
+        held_snapshots.extend(self.live_table_clones(ctx).await?.into_values());
+        Ok(held_snapshots)
+    }
+
     pub async fn list_snapshot_files(&self) -> Result<Vec<String>> {
         let prefix = format!(
             "{}/{}/",
@@ -754,12 +853,29 @@ struct RootSnapshotInfo {
     snapshot_location: String,
     referenced_locations: LocationTuple,
     snapshot_lite: Arc<SnapshotLiteExtended>,
+    /// The snapshots held by the tags, the branches and the clones of the table, and the files
+    /// they reference.
+    ref_snapshots: HashSet<String>,
+    ref_segments: HashSet<Location>,
+    ref_table_statistics: HashSet<String>,
+}
+
+impl RootSnapshotInfo {
+    fn retain_unreferenced(
+        &self,
+        segments_to_be_purged: &mut HashSet<Location>,
+        ts_to_be_purged: &mut HashSet<String>,
+    ) {
+        segments_to_be_purged.retain(|location| !self.ref_segments.contains(location));
+        ts_to_be_purged.retain(|location| !self.ref_table_statistics.contains(location));
+    }
 }
 
 #[derive(Default)]
 pub struct LocationTuple {
     pub block_location: HashSet<String>,
     pub bloom_location: HashSet<String>,
+    pub deletion_vector_location: HashSet<String>,
 }
 
 impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
@@ -767,16 +883,21 @@ impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
     fn try_from(value: Arc<CompactSegmentInfo>) -> Result<Self> {
         let mut block_location = HashSet::new();
         let mut bloom_location = HashSet::new();
+        let mut deletion_vector_location = HashSet::new();
         let block_metas = value.block_metas()?;
         for block_meta in block_metas.into_iter() {
             block_location.insert(block_meta.location.0.clone());
             if let Some(bloom_loc) = &block_meta.bloom_filter_index_location {
                 bloom_location.insert(bloom_loc.0.clone());
             }
+            if let Some(deletion_vector) = &block_meta.deletion_vector {
+                deletion_vector_location.insert(deletion_vector.location.0.clone());
+            }
         }
         Ok(Self {
             block_location,
             bloom_location,
+            deletion_vector_location,
         })
     }
 }
@@ -53,6 +53,7 @@ use crate::io::BlockBuilder;
 use crate::io::BlockReader;
 use crate::io::BlockWriter;
 use crate::io::CompactSegmentInfoReader;
+use crate::io::DeletionVectorsIO;
 use crate::io::MetaReaders;
 use crate::io::WriteSettings;
 use crate::operations::acquire_task_permit;
@@ -411,10 +412,19 @@ impl AggregationContext {
             origin_data_block.add_column(row_num);
         }
 
+        // the rows deleted by the deletion vector are removed as well.
+        let deletion_vector = match &block_meta.deletion_vector {
+            Some(meta) => Some(DeletionVectorsIO::read(&self.data_accessor, meta).await?),
+            None => None,
+        };
+
         // apply delete
         let mut bitmap = MutableBitmap::new();
         for row in 0..origin_num_rows {
-            if modified_offsets.contains(&row) {
+            let deleted = deletion_vector
+                .as_ref()
+                .is_some_and(|v| v.deleted().get_bit(row));
+            if modified_offsets.contains(&row) || deleted {
                 bitmap.push(false);
             } else {
                 bitmap.push(true);
@@ -33,8 +33,12 @@ mod recluster;
 mod replace;
 mod replace_into;
 mod revert;
+mod table_clone;
+mod table_ref;
 mod truncate;
+mod unique_key;
 mod util;
+mod warm_cache;
 
 pub use agg_index_sink::AggIndexSink;
 pub use analyze::HistogramInfoSink;
@@ -51,3 +55,4 @@ pub use util::acquire_task_permit;
 pub use util::column_parquet_metas;
 pub use util::read_block;
 pub use util::set_backoff;
+pub use warm_cache::WarmCacheStats;
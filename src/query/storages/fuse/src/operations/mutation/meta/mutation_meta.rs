@@ -18,6 +18,8 @@ use databend_common_expression::BlockMetaInfo;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
+use databend_storages_common_table_meta::meta::DeletionVector;
+use databend_storages_common_table_meta::meta::DeletionVectorMeta;
 
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::CompactExtraInfo;
@@ -29,6 +31,7 @@ pub enum SerializeDataMeta {
     SerializeBlock(SerializeBlock),
     DeletedSegment(DeletedSegmentInfo),
     CompactExtras(CompactExtraInfo),
+    DeletionVector(DeletionVectorInfo),
 }
 
 #[typetag::serde(name = "serialize_data_meta")]
@@ -60,9 +63,25 @@ impl SerializeBlock {
     }
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct DeletionVectorInfo {
+    pub index: BlockMetaIndex,
+    pub deletion_vector: DeletionVectorMeta,
+}
+
+impl DeletionVectorInfo {
+    pub fn create(index: BlockMetaIndex, deletion_vector: DeletionVectorMeta) -> Self {
+        DeletionVectorInfo {
+            index,
+            deletion_vector,
+        }
+    }
+}
+
 pub enum CompactSourceMeta {
     Concat {
         read_res: Vec<BlockReadResult>,
+        deletion_vectors: Vec<Option<DeletionVector>>,
         metas: Vec<Arc<BlockMeta>>,
         index: BlockMetaIndex,
     },
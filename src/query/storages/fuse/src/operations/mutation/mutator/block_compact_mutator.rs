@@ -445,7 +445,7 @@ impl CompactTaskBuilder {
             }
         }
 
-        let total_rows = self.total_rows + block.row_count as usize;
+        let total_rows = self.total_rows + block.live_row_count() as usize;
         let total_size = self.total_size + block.block_size as usize;
         if !thresholds.check_large_enough(total_rows, total_size) {
             // blocks < N
@@ -481,6 +481,11 @@ impl CompactTaskBuilder {
     }
 
     fn check_compact(&self, block: &Arc<BlockMeta>) -> bool {
+        // Fold the deleted rows into a new block.
+        if block.deletion_vector.is_some() {
+            return true;
+        }
+
         // The snapshot schema does not contain stream columns,
         // so the stream columns need to be filtered out.
         let column_ids = block
@@ -579,7 +584,7 @@ impl CompactTaskBuilder {
 
                 let (total_rows, total_size) =
                     blocks.iter().chain(tail.iter()).fold((0, 0), |mut acc, x| {
-                        acc.0 += x.row_count as usize;
+                        acc.0 += x.live_row_count() as usize;
                         acc.1 += x.block_size as usize;
                         acc
                     });
@@ -21,7 +21,6 @@ use databend_common_metrics::storage::metrics_set_compact_segments_select_durati
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::Statistics;
-use databend_storages_common_table_meta::meta::Versioned;
 use log::info;
 use opendal::Operator;
 
@@ -364,7 +363,7 @@ impl<'a> SegmentCompactor<'a> {
             .push(location.clone());
         self.compacted_state
             .segments_locations
-            .push((location, SegmentInfo::VERSION));
+            .push((location, new_segment.format_version));
         Ok(())
     }
 
@@ -18,6 +18,7 @@ use std::time::Instant;
 use databend_common_base::base::ProgressValues;
 use databend_common_catalog::plan::gen_mutation_stream_meta;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_metrics::storage::*;
@@ -28,6 +29,7 @@ use databend_common_sql::StreamContext;
 use databend_storages_common_io::ReadSettings;
 
 use crate::io::BlockReader;
+use crate::io::DeletionVectorsIO;
 use crate::operations::ClusterStatsGenType;
 use crate::operations::CompactBlockPartInfo;
 use crate::operations::CompactSourceMeta;
@@ -88,14 +90,21 @@ impl PrefetchAsyncSource for CompactSource {
                                 metrics_inc_compact_block_read_bytes(block.block_size);
                             }
 
-                            block_reader
+                            let read_res = block_reader
                                 .read_columns_data_by_merge_io(
                                     &settings,
                                     &block.location.0,
                                     &block.col_metas,
                                     &None,
                                 )
-                                .await
+                                .await?;
+                            let deletion_vector = match &block.deletion_vector {
+                                Some(meta) => Some(
+                                    DeletionVectorsIO::read(&block_reader.operator, meta).await?,
+                                ),
+                                None => None,
+                            };
+                            Ok::<_, ErrorCode>((read_res, deletion_vector))
                         })
                         .await
                         .unwrap()
@@ -104,13 +113,17 @@ impl PrefetchAsyncSource for CompactSource {
 
                 let start = Instant::now();
 
-                let read_res = futures::future::try_join_all(task_futures).await?;
+                let (read_res, deletion_vectors) = futures::future::try_join_all(task_futures)
+                    .await?
+                    .into_iter()
+                    .unzip();
                 // Perf.
                 {
                     metrics_inc_compact_block_read_milliseconds(start.elapsed().as_millis() as u64);
                 }
                 Box::new(CompactSourceMeta::Concat {
                     read_res,
+                    deletion_vectors,
                     metas: task.blocks.clone(),
                     index: task.index.clone(),
                 })
@@ -155,18 +168,21 @@ impl BlockMetaTransform<CompactSourceMeta> for CompactTransform {
         match meta {
             CompactSourceMeta::Concat {
                 read_res,
+                deletion_vectors,
                 metas,
                 index,
             } => {
                 let blocks = read_res
                     .into_iter()
+                    .zip(deletion_vectors)
                     .zip(metas.into_iter())
-                    .map(|(data, meta)| {
-                        let mut block = self.block_reader.deserialize_chunks_with_meta(
+                    .map(|((data, deletion_vector), meta)| {
+                        let block = self.block_reader.deserialize_chunks_with_meta(
                             &meta,
                             &self.storage_format,
                             data,
                         )?;
+                        let mut block = DeletionVectorsIO::apply(block, deletion_vector.as_ref())?;
 
                         if let Some(stream_ctx) = &self.stream_ctx {
                             let stream_meta = gen_mutation_stream_meta(None, &meta.location.0)?;
@@ -16,6 +16,7 @@ use std::any::Any;
 use std::ops::Not;
 use std::sync::Arc;
 
+use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_base::base::ProgressValues;
 use databend_common_catalog::plan::build_origin_block_row_num;
 use databend_common_catalog::plan::gen_mutation_stream_meta;
@@ -39,11 +40,15 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_storage::MutationStatus;
 use databend_storages_common_io::ReadSettings;
+use databend_storages_common_table_meta::meta::DeletionVector;
 
 use crate::fuse_part::FuseBlockPartInfo;
 use crate::io::BlockReader;
+use crate::io::DeletionVectorsIO;
+use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::ClusterStatsGenType;
+use crate::operations::mutation::DeletionVectorInfo;
 use crate::operations::mutation::Mutation;
 use crate::operations::mutation::SerializeBlock;
 use crate::operations::mutation::SerializeDataMeta;
@@ -71,6 +76,7 @@ enum State {
         filter: Option<Value<BooleanType>>,
     },
     PerformOperator(DataBlock, String),
+    WriteDeletionVector(DeletionVector),
     Output(Option<PartInfoPtr>, DataBlock),
     Finish,
 }
@@ -86,9 +92,13 @@ pub struct MutationSource {
     operators: Vec<BlockOperator>,
     storage_format: FuseStorageFormat,
     action: MutationAction,
+    // Some if the deleted rows are recorded in deletion vectors instead of rewriting the blocks.
+    deletion_vector_location_gen: Option<TableMetaLocationGenerator>,
 
     index: BlockMetaIndex,
     stats_type: ClusterStatsGenType,
+    // the deletion vector of the block being mutated.
+    deletion_vector: Option<DeletionVector>,
 }
 
 impl MutationSource {
@@ -102,6 +112,7 @@ impl MutationSource {
         remain_reader: Arc<Option<BlockReader>>,
         operators: Vec<BlockOperator>,
         storage_format: FuseStorageFormat,
+        deletion_vector_location_gen: Option<TableMetaLocationGenerator>,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(MutationSource {
             state: State::ReadData(None),
@@ -113,8 +124,10 @@ impl MutationSource {
             operators,
             storage_format,
             action,
+            deletion_vector_location_gen,
             index: BlockMetaIndex::default(),
             stats_type: ClusterStatsGenType::Generally,
+            deletion_vector: None,
         })))
     }
 }
@@ -161,7 +174,10 @@ impl Processor for MutationSource {
             }
         }
 
-        if matches!(self.state, State::ReadData(_) | State::ReadRemain { .. }) {
+        if matches!(
+            self.state,
+            State::ReadData(_) | State::ReadRemain { .. } | State::WriteDeletionVector(_)
+        ) {
             Ok(Event::Async)
         } else {
             Ok(Event::Sync)
@@ -177,6 +193,13 @@ impl Processor for MutationSource {
                     chunks,
                     &self.storage_format,
                 )?;
+                let write_deletion_vector = self.deletion_vector_location_gen.is_some()
+                    && matches!(self.action, MutationAction::Deletion);
+                if !write_deletion_vector {
+                    // the block will be rewritten, the deleted rows are removed first.
+                    data_block =
+                        DeletionVectorsIO::apply(data_block, self.deletion_vector.as_ref())?;
+                }
                 let num_rows = data_block.num_rows();
 
                 let fuse_part = FuseBlockPartInfo::from_part(&part)?;
@@ -192,6 +215,11 @@ impl Processor for MutationSource {
                         .try_downcast::<BooleanType>()
                         .unwrap();
 
+                    if write_deletion_vector {
+                        self.mark_deleted_rows(predicates, num_rows);
+                        return Ok(());
+                    }
+
                     let affect_rows = match &predicates {
                         Value::Scalar(v) => {
                             if *v {
@@ -286,6 +314,8 @@ impl Processor for MutationSource {
                         chunks,
                         &self.storage_format,
                     )?;
+                    let remain_block =
+                        DeletionVectorsIO::apply(remain_block, self.deletion_vector.as_ref())?;
 
                     let remain_block = if let Some(filter) = filter {
                         // for deletion.
@@ -351,12 +381,17 @@ impl Processor for MutationSource {
 
                         let inner_part = part.inner_part.clone();
                         let fuse_part = FuseBlockPartInfo::from_part(&inner_part)?;
+                        self.deletion_vector = None;
 
                         if part.whole_block_mutation
                             && matches!(self.action, MutationAction::Deletion)
                         {
                             // whole block deletion.
-                            self.update_mutation_status(fuse_part.nums_rows);
+                            let deleted_rows = fuse_part
+                                .deletion_vector
+                                .as_ref()
+                                .map_or(0, |v| v.deleted_rows as usize);
+                            self.update_mutation_status(fuse_part.nums_rows - deleted_rows);
                             let meta = Box::new(SerializeDataMeta::SerializeBlock(
                                 SerializeBlock::create(self.index.clone(), self.stats_type.clone()),
                             ));
@@ -374,6 +409,12 @@ impl Processor for MutationSource {
                                     &None,
                                 )
                                 .await?;
+                            if let Some(meta) = &fuse_part.deletion_vector {
+                                self.deletion_vector = Some(
+                                    DeletionVectorsIO::read(&self.block_reader.operator, meta)
+                                        .await?,
+                                );
+                            }
                             self.state = State::FilterData(inner_part, read_res);
                         }
                     }
@@ -406,6 +447,22 @@ impl Processor for MutationSource {
                     return Err(ErrorCode::Internal("It's a bug. No remain reader"));
                 }
             }
+            State::WriteDeletionVector(deletion_vector) => {
+                let location_gen = self.deletion_vector_location_gen.as_ref().ok_or_else(|| {
+                    ErrorCode::Internal("It's a bug. No deletion vector location generator")
+                })?;
+                let deletion_vector = DeletionVectorsIO::write(
+                    &self.block_reader.operator,
+                    location_gen,
+                    &deletion_vector,
+                )
+                .await?;
+                let meta = Box::new(SerializeDataMeta::DeletionVector(
+                    DeletionVectorInfo::create(self.index.clone(), deletion_vector),
+                ));
+                self.state =
+                    State::Output(self.ctx.get_partition(), DataBlock::empty_with_meta(meta));
+            }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
         Ok(())
@@ -413,6 +470,44 @@ impl Processor for MutationSource {
 }
 
 impl MutationSource {
+    /// Marks the rows matched by the predicates as deleted, without rewriting the block.
+    fn mark_deleted_rows(&mut self, predicates: Value<BooleanType>, num_rows: usize) {
+        let deleted = match predicates {
+            Value::Scalar(v) => Bitmap::new_constant(v, num_rows),
+            Value::Column(bitmap) => bitmap,
+        };
+        // the rows deleted before are not counted again.
+        let (deleted, live_rows) = match &self.deletion_vector {
+            Some(deletion_vector) => (
+                &deleted & &deletion_vector.live_rows(),
+                num_rows - deletion_vector.deleted_rows(),
+            ),
+            None => (deleted, num_rows),
+        };
+        let affect_rows = deleted.len() - deleted.unset_bits();
+        if affect_rows == 0 {
+            // Do nothing.
+            self.state = State::Output(self.ctx.get_partition(), DataBlock::empty());
+            return;
+        }
+
+        self.update_mutation_status(affect_rows);
+        if affect_rows == live_rows {
+            // all the rows should be removed.
+            let meta = Box::new(SerializeDataMeta::SerializeBlock(SerializeBlock::create(
+                self.index.clone(),
+                self.stats_type.clone(),
+            )));
+            self.state = State::Output(self.ctx.get_partition(), DataBlock::empty_with_meta(meta));
+        } else {
+            let deletion_vector = match self.deletion_vector.take() {
+                Some(deletion_vector) => deletion_vector.merge(&deleted),
+                None => DeletionVector::new(deleted),
+            };
+            self.state = State::WriteDeletionVector(deletion_vector);
+        }
+    }
+
     fn update_mutation_status(&self, num_rows: usize) {
         let progress_values = ProgressValues {
             rows: num_rows,
@@ -151,6 +151,14 @@ impl FuseTable {
         projection.sort_by_key(|&i| source_col_indices[i]);
         let ops = vec![BlockOperator::Project { projection }];
 
+        let deletion_vector_location_gen = if self.deletion_vectors_enabled()
+            && matches!(mutation_action, MutationAction::Deletion)
+        {
+            Some(self.meta_location_generator().clone())
+        } else {
+            None
+        };
+
         let max_threads = (ctx.get_settings().get_max_threads()? as usize)
             .min(ctx.partition_num())
             .max(1);
@@ -166,6 +174,7 @@ impl FuseTable {
                     remain_reader.clone(),
                     ops.clone(),
                     self.storage_format,
+                    deletion_vector_location_gen.clone(),
                 )
             },
             max_threads,
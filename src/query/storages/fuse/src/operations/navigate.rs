@@ -26,8 +26,11 @@ use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableStatistics;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::table::get_table_ref;
+use databend_storages_common_table_meta::table::TableRefKind;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_TABLE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_BRANCH;
 use futures::TryStreamExt;
 use log::warn;
 use opendal::EntryMode;
@@ -63,6 +66,55 @@ impl FuseTable {
                     .await
             }
             NavigationPoint::StreamInfo(info) => self.navigate_to_stream(info).await,
+            NavigationPoint::TableRef { kind, name } => {
+                self.navigate_to_table_ref(*kind, name).await
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn navigate_to_table_ref(
+        &self,
+        kind: TableRefKind,
+        name: &str,
+    ) -> Result<Arc<FuseTable>> {
+        let table_ref = get_table_ref(self.table_info.options(), name)?;
+        if table_ref.kind != kind {
+            return Err(ErrorCode::UnknownTableRef(format!(
+                "'{}' of table '{}' is a {}, not a {}",
+                name, self.table_info.desc, table_ref.kind, kind
+            )));
+        }
+        if table_ref.is_expired(&Utc::now()) {
+            return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                "The {} '{}' of table '{}' has expired",
+                table_ref.kind, name, self.table_info.desc
+            )));
+        }
+
+        match table_ref.kind {
+            TableRefKind::Branch => {
+                // The branch handle resolves its head on each read, thus follows the writes.
+                let mut table_info = self.table_info.clone();
+                table_info
+                    .meta
+                    .options
+                    .insert(OPT_KEY_TABLE_BRANCH.to_owned(), name.to_owned());
+                let table = FuseTable::do_create(table_info)?;
+                Ok(table.into())
+            }
+            TableRefKind::Tag => {
+                let Some(snapshot_loc) = table_ref.snapshot_location else {
+                    let mut table_info = self.table_info.clone();
+                    table_info.meta.options.remove(OPT_KEY_SNAPSHOT_LOCATION);
+                    table_info.meta.statistics = TableStatistics::default();
+                    let table = FuseTable::do_create(table_info)?;
+                    return Ok(table.into());
+                };
+                let (snapshot, format_version) =
+                    SnapshotsIO::read_snapshot(snapshot_loc, self.get_operator()).await?;
+                self.load_table_by_snapshot(snapshot.as_ref(), format_version)
+            }
         }
     }
 
@@ -244,6 +296,9 @@ impl FuseTable {
                     .await
             }
             Some(NavigationPoint::StreamInfo(info)) => self.list_by_stream(info, time_point).await,
+            Some(NavigationPoint::TableRef { kind, name }) => {
+                self.list_by_table_ref(kind, &name, time_point).await
+            }
             None => self.list_by_time_point(time_point).await,
         }?;
 
@@ -352,6 +407,32 @@ impl FuseTable {
             })?
             .parse::<String>()?;
 
+        self.list_by_snapshot_location(snapshot_loc, retention_point)
+            .await
+    }
+
+    #[async_backtrace::framed]
+    pub async fn list_by_table_ref(
+        &self,
+        kind: TableRefKind,
+        name: &str,
+        retention_point: DateTime<Utc>,
+    ) -> Result<(String, Vec<String>)> {
+        let table = self.navigate_to_table_ref(kind, name).await?;
+        let snapshot_loc = table.snapshot_loc().await?.ok_or_else(|| {
+            ErrorCode::TableHistoricalDataNotFound("No historical data found at given point")
+        })?;
+
+        self.list_by_snapshot_location(snapshot_loc, retention_point)
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn list_by_snapshot_location(
+        &self,
+        snapshot_loc: String,
+        retention_point: DateTime<Utc>,
+    ) -> Result<(String, Vec<String>)> {
         let mut found = false;
         let prefix = format!(
             "{}/{}/",
@@ -248,7 +248,7 @@ impl Processor for DeserializeDataTransform {
 
                     self.output_data = Some(block);
                 }
-                ParquetDataSource::Normal((data, virtual_data)) => {
+                ParquetDataSource::Normal((data, virtual_data, deletion_vector)) => {
                     let start = Instant::now();
                     let columns_chunks = data.columns_chunks()?;
                     let part = FuseBlockPartInfo::from_part(&part)?;
@@ -263,6 +263,11 @@ impl Processor for DeserializeDataTransform {
 
                     let origin_num_rows = data_block.num_rows();
 
+                    let live_rows = deletion_vector.map(|v| v.live_rows());
+                    if let Some(bitmap) = &live_rows {
+                        data_block = data_block.filter_with_bitmap(bitmap)?;
+                    }
+
                     let mut filter = None;
                     if self.ctx.has_bloom_runtime_filters(self.table_index) {
                         if let Some(bitmap) = self.runtime_filter(data_block.clone())? {
@@ -299,12 +304,25 @@ impl Processor for DeserializeDataTransform {
 
                     // Fill `BlockMetaIndex` as `DataBlock.meta` if query internal columns,
                     // `TransformAddInternalColumns` will generate internal columns using `BlockMetaIndex` in next pipeline.
-                    let offsets = if self.block_reader.query_internal_columns() {
-                        filter.as_ref().map(|bitmap| {
-                            (0..origin_num_rows)
+                    let offsets = if self.block_reader.query_internal_columns()
+                        && (live_rows.is_some() || filter.is_some())
+                    {
+                        // The offsets are of the rows in the block file.
+                        let mut offsets: Vec<usize> = match &live_rows {
+                            Some(bitmap) => (0..origin_num_rows)
                                 .filter(|i| unsafe { bitmap.get_bit_unchecked(*i) })
-                                .collect()
-                        })
+                                .collect(),
+                            None => (0..origin_num_rows).collect(),
+                        };
+                        if let Some(bitmap) = &filter {
+                            let mut i = 0;
+                            offsets.retain(|_| {
+                                let keep = unsafe { bitmap.get_bit_unchecked(i) };
+                                i += 1;
+                                keep
+                            });
+                        }
+                        Some(offsets)
                     } else {
                         None
                     };
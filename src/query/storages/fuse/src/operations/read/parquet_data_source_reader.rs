@@ -37,6 +37,7 @@ use super::parquet_data_source::ParquetDataSource;
 use crate::fuse_part::FuseBlockPartInfo;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
+use crate::io::DeletionVectorsIO;
 use crate::io::TableMetaLocationGenerator;
 use crate::io::VirtualColumnReader;
 use crate::operations::read::data_source_with_meta::DataSourceWithMeta;
@@ -136,8 +137,16 @@ impl SyncSource for ReadParquetDataSource<true> {
                     return Ok(Some(DataBlock::empty()));
                 }
 
-                if let Some(index_reader) = self.index_reader.as_ref() {
-                    let fuse_part = FuseBlockPartInfo::from_part(&part)?;
+                let fuse_part = FuseBlockPartInfo::from_part(&part)?;
+                // The aggregating index and virtual columns still cover the deleted rows.
+                let has_deletion_vector = fuse_part.deletion_vector.is_some();
+
+                if let Some(index_reader) = self
+                    .index_reader
+                    .as_ref()
+                    .as_ref()
+                    .filter(|_| !has_deletion_vector)
+                {
                     let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &fuse_part.location,
@@ -157,8 +166,12 @@ impl SyncSource for ReadParquetDataSource<true> {
                 }
 
                 // If virtual column file exists, read the data from the virtual columns directly.
-                let virtual_source = if let Some(virtual_reader) = self.virtual_reader.as_ref() {
-                    let fuse_part = FuseBlockPartInfo::from_part(&part)?;
+                let virtual_source = if let Some(virtual_reader) = self
+                    .virtual_reader
+                    .as_ref()
+                    .as_ref()
+                    .filter(|_| !has_deletion_vector)
+                {
                     let loc =
                         TableMetaLocationGenerator::gen_virtual_block_location(&fuse_part.location);
 
@@ -180,11 +193,17 @@ impl SyncSource for ReadParquetDataSource<true> {
                     &part,
                     ignore_column_ids,
                 )?;
+                let deletion_vector = fuse_part
+                    .deletion_vector
+                    .as_ref()
+                    .map(|meta| DeletionVectorsIO::sync_read(&self.block_reader.operator, meta))
+                    .transpose()?;
 
                 Ok(Some(DataBlock::empty_with_meta(
                     DataSourceWithMeta::create(vec![part], vec![ParquetDataSource::Normal((
                         source,
                         virtual_source,
+                        deletion_vector,
                     ))]),
                 )))
             }
@@ -260,8 +279,12 @@ impl Processor for ReadParquetDataSource<false> {
                 chunks.push(async move {
                     databend_common_base::runtime::spawn(async move {
                         let part = FuseBlockPartInfo::from_part(&part)?;
+                        // The aggregating index and virtual columns still cover the deleted rows.
+                        let has_deletion_vector = part.deletion_vector.is_some();
 
-                        if let Some(index_reader) = index_reader.as_ref() {
+                        if let Some(index_reader) =
+                            index_reader.as_ref().as_ref().filter(|_| !has_deletion_vector)
+                        {
                             let loc =
                                 TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                                     &part.location,
@@ -277,7 +300,9 @@ impl Processor for ReadParquetDataSource<false> {
                         }
 
                         // If virtual column file exists, read the data from the virtual columns directly.
-                        let virtual_source = if let Some(virtual_reader) = virtual_reader.as_ref() {
+                        let virtual_source = if let Some(virtual_reader) =
+                            virtual_reader.as_ref().as_ref().filter(|_| !has_deletion_vector)
+                        {
                             let loc = TableMetaLocationGenerator::gen_virtual_block_location(
                                 &part.location,
                             );
@@ -303,8 +328,18 @@ impl Processor for ReadParquetDataSource<false> {
                                 ignore_column_ids,
                             )
                             .await?;
+                        let deletion_vector = match &part.deletion_vector {
+                            Some(meta) => {
+                                Some(DeletionVectorsIO::read(&block_reader.operator, meta).await?)
+                            }
+                            None => None,
+                        };
 
-                        Ok(ParquetDataSource::Normal((source, virtual_source)))
+                        Ok(ParquetDataSource::Normal((
+                            source,
+                            virtual_source,
+                            deletion_vector,
+                        )))
                     })
                         .await
                         .unwrap()
@@ -86,6 +86,12 @@ impl FuseTable {
                 }
 
                 if !dry_run && snapshot.segments.len() > nodes_num {
+                    // the blocks of a segment are read by the node the segment is assigned to
+                    let kind = if ctx.get_settings().get_enable_cache_affinity_scheduling()? {
+                        PartitionsShuffleKind::CacheAffinity
+                    } else {
+                        PartitionsShuffleKind::Mod
+                    };
                     let mut segments = Vec::with_capacity(snapshot.segments.len());
                     for (idx, segment_location) in snapshot.segments.iter().enumerate() {
                         segments.push(FuseLazyPartInfo::create(idx, segment_location.clone()))
@@ -99,7 +105,7 @@ impl FuseTable {
                             snapshot.segments.len(),
                             snapshot.segments.len(),
                         ),
-                        Partitions::create(PartitionsShuffleKind::Mod, segments),
+                        Partitions::create(kind, segments),
                     ));
                 }
 
@@ -263,9 +269,15 @@ impl FuseTable {
             .map(|topk| field_default_value(ctx.clone(), &topk.field).map(|d| (topk, d)))
             .transpose()?;
 
-        let (mut statistics, parts) =
+        let ordered = top_k.is_some();
+        let (mut statistics, mut parts) =
             Self::to_partitions(Some(&schema), block_metas, &column_nodes, top_k, push_downs);
 
+        // Route each block to the node that read it last time, so that it is found in the cache.
+        if !ordered && ctx.get_settings().get_enable_cache_affinity_scheduling()? {
+            parts.kind = PartitionsShuffleKind::CacheAffinity;
+        }
+
         // Update planner statistics.
         statistics.partitions_total = partitions_total;
         statistics.partitions_scanned = partitions_scanned;
@@ -373,7 +385,7 @@ impl FuseTable {
 
         let mut remaining = limit;
         for (block_meta_index, block_meta) in block_metas.iter() {
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
             partitions.partitions.push(Self::all_columns_part(
                 schema,
                 block_meta_index,
@@ -423,7 +435,7 @@ impl FuseTable {
                 projection,
             ));
 
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
 
             statistics.read_rows += rows;
             for column in &columns {
@@ -496,6 +508,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.deletion_vector.clone(),
         )
     }
 
@@ -544,6 +557,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.deletion_vector.clone(),
         )
     }
 }
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use ahash::AHashMap;
+use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_arrow::arrow::bitmap::MutableBitmap;
 use databend_common_base::base::tokio::sync::Semaphore;
 use databend_common_base::base::ProgressValues;
@@ -63,6 +64,7 @@ use crate::io::BlockBuilder;
 use crate::io::BlockReader;
 use crate::io::BlockWriter;
 use crate::io::CompactSegmentInfoReader;
+use crate::io::DeletionVectorsIO;
 use crate::io::MetaReaders;
 use crate::io::WriteSettings;
 use crate::operations::acquire_task_permit;
@@ -439,9 +441,20 @@ impl AggregationContext {
                 .value);
         }
 
+        let deletion_vector = match &block_meta.deletion_vector {
+            Some(meta) => Some(DeletionVectorsIO::read(&self.data_accessor, meta).await?),
+            None => None,
+        };
+
         let mut bitmap = MutableBitmap::new();
         for row in 0..num_rows {
-            if let Some(hash) = row_hash_of_columns(&columns, row)? {
+            if deletion_vector
+                .as_ref()
+                .is_some_and(|v| v.deleted().get_bit(row))
+            {
+                // deleted already, removed below but not counted
+                bitmap.push(true);
+            } else if let Some(hash) = row_hash_of_columns(&columns, row)? {
                 // some row hash means on-conflict columns of this row contains non-null values
                 // let's check it out
                 bitmap.push(!deleted_key_hashes.contains(&hash));
@@ -474,12 +487,11 @@ impl AggregationContext {
             .incr(&progress_values);
 
         // shortcut: whole block deletion
-        if delete_nums == block_meta.row_count as usize {
+        if delete_nums == block_meta.live_row_count() as usize {
             info!("whole block deletion");
             metrics_inc_replace_whole_block_deletion(1);
             metrics_inc_replace_deleted_blocks_rows(num_rows as u64);
             // whole block deletion
-            // NOTE that if deletion marker is enabled, check the real meaning of `row_count`
             let mutation = MutationLogEntry::DeletedBlock {
                 index: BlockMetaIndex {
                     segment_idx: segment_index,
@@ -490,7 +502,10 @@ impl AggregationContext {
             return Ok(Some(mutation));
         }
 
-        let bitmap = bitmap.into();
+        let bitmap: Bitmap = match &deletion_vector {
+            Some(deletion_vector) => &Bitmap::from(bitmap) & &deletion_vector.live_rows(),
+            None => bitmap.into(),
+        };
         let mut key_columns_data_after_deletion = key_columns_data.filter_with_bitmap(&bitmap)?;
 
         let mut new_block = match &self.remain_column_reader {
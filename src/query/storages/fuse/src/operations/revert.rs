@@ -22,6 +22,7 @@ use databend_common_exception::Result;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_types::MatchSeq;
 
+use crate::io::SnapshotsIO;
 use crate::FuseTable;
 
 impl FuseTable {
@@ -38,6 +39,17 @@ impl FuseTable {
                 ctx.clone().get_abort_checker(),
             )
             .await?;
+        // Reverting to a branch promotes the head of the branch.
+        let table = if table.is_branch() {
+            let snapshot_location = table.snapshot_loc().await?.ok_or_else(|| {
+                ErrorCode::TableHistoricalDataNotFound("Empty branch has no historical data")
+            })?;
+            let (snapshot, format_version) =
+                SnapshotsIO::read_snapshot(snapshot_location, self.get_operator()).await?;
+            self.load_table_by_snapshot(snapshot.as_ref(), format_version)?
+        } else {
+            table
+        };
         let table_reverting_to = FuseTable::try_from_table(table.as_ref())?;
         let table_info = table_reverting_to.get_table_info();
 
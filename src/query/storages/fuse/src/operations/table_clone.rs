@@ -0,0 +1,176 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_catalog::table::NavigationPoint;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_storages_common_table_meta::table::get_table_clones;
+use databend_storages_common_table_meta::table::is_internal_opt_key;
+use databend_storages_common_table_meta::table::set_table_clones;
+use databend_storages_common_table_meta::table::TableClones;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_CLONE_SOURCE;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
+
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Builds the meta of a clone of the table at `point`, or of the current table.
+    ///
+    /// No data is copied: the first snapshot of the clone is the snapshot of this table,
+    /// the location of which is returned along with the meta, and must be pinned by
+    /// [`FuseTable::add_table_clone`] once the clone is created.
+    #[async_backtrace::framed]
+    pub async fn build_clone_table_meta(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        point: Option<&NavigationPoint>,
+    ) -> Result<(TableMeta, Option<String>)> {
+        if self.is_temp() {
+            return Err(ErrorCode::StorageUnsupported(
+                "Cannot clone temporary table",
+            ));
+        }
+        // The files of an attached table are purged by the table they are attached from,
+        // which does not know about the clones.
+        if self.is_read_only()
+            || self
+                .table_info
+                .options()
+                .contains_key(OPT_KEY_STORAGE_PREFIX)
+        {
+            return Err(ErrorCode::StorageUnsupported(format!(
+                "Cannot clone attached table '{}'",
+                self.table_info.desc
+            )));
+        }
+        if self
+            .table_info
+            .options()
+            .contains_key(OPT_KEY_TABLE_CLONE_SOURCE)
+        {
+            return Err(ErrorCode::StorageUnsupported(format!(
+                "Cannot clone table '{}' which is a clone itself, clone the table it was cloned from instead",
+                self.table_info.desc
+            )));
+        }
+        if self
+            .table_info
+            .meta
+            .column_mask_policy
+            .as_ref()
+            .is_some_and(|policies| !policies.is_empty())
+        {
+            return Err(ErrorCode::StorageUnsupported(format!(
+                "Cannot clone table '{}' with masking policies",
+                self.table_info.desc
+            )));
+        }
+
+        let (table_meta, snapshot_location) = match point {
+            Some(point) => {
+                let table = self
+                    .navigate_to_point(point, ctx.clone().get_abort_checker())
+                    .await?;
+                (table.table_info.meta.clone(), table.snapshot_loc().await?)
+            }
+            None => (self.table_info.meta.clone(), self.snapshot_loc().await?),
+        };
+
+        let now = Utc::now();
+        let mut options = table_meta.options;
+        options.retain(|key, _| {
+            (!is_internal_opt_key(key) || key == OPT_KEY_TTL)
+                && key != OPT_KEY_SNAPSHOT_LOCATION
+                && key != OPT_KEY_STORAGE_PREFIX
+        });
+        // A clone of an empty table shares no file with it.
+        if let Some(location) = &snapshot_location {
+            options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), location.clone());
+            options.insert(
+                OPT_KEY_TABLE_CLONE_SOURCE.to_owned(),
+                self.table_info.ident.table_id.to_string(),
+            );
+        }
+
+        let table_meta = TableMeta {
+            options,
+            created_on: now,
+            updated_on: now,
+            drop_on: None,
+            shared_by: Default::default(),
+            column_mask_policy: None,
+            ..table_meta
+        };
+        Ok((table_meta, snapshot_location))
+    }
+
+    /// Pins the snapshot which the table `clone_id` was created from.
+    ///
+    /// The pins of the clones that no longer exist are dropped on the way.
+    #[async_backtrace::framed]
+    pub async fn add_table_clone(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        clone_id: u64,
+        snapshot_location: String,
+    ) -> Result<()> {
+        let mut clones = self.live_table_clones(&ctx).await?;
+        clones.insert(clone_id, snapshot_location);
+
+        let mut new_table_meta = self.table_info.meta.clone();
+        set_table_clones(&mut new_table_meta.options, &clones)?;
+        new_table_meta.updated_on = Utc::now();
+
+        let req = UpdateTableMetaReq {
+            table_id: self.table_info.ident.table_id,
+            seq: MatchSeq::Exact(self.table_info.ident.seq),
+            new_table_meta,
+        };
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        catalog
+            .update_single_table_meta(req, &self.table_info)
+            .await?;
+        Ok(())
+    }
+
+    /// The snapshots pinned by the clones of the table which still exist.
+    ///
+    /// A dropped clone still counts until it is vacuumed, since it can be undropped.
+    #[async_backtrace::framed]
+    pub async fn live_table_clones(&self, ctx: &Arc<dyn TableContext>) -> Result<TableClones> {
+        let clones = get_table_clones(self.table_info.options())?;
+        if clones.is_empty() {
+            return Ok(clones);
+        }
+
+        let catalog = ctx.get_default_catalog()?;
+        let mut live_clones = TableClones::new();
+        for (table_id, snapshot_location) in clones {
+            if catalog.get_table_meta_by_id(table_id).await?.is_some() {
+                live_clones.insert(table_id, snapshot_location);
+            }
+        }
+        Ok(live_clones)
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use databend_common_catalog::table::NavigationPoint;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_storages_common_table_meta::table::get_table_refs;
+use databend_storages_common_table_meta::table::set_table_refs;
+use databend_storages_common_table_meta::table::TableRef;
+use databend_storages_common_table_meta::table::TableRefKind;
+use databend_storages_common_table_meta::table::TableRefs;
+
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Name the snapshot at `point` (or the current snapshot) as a tag or a branch.
+    ///
+    /// The snapshot is kept by purge and vacuum until the ref is dropped or expires.
+    #[async_backtrace::framed]
+    pub async fn create_table_ref(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        kind: TableRefKind,
+        name: &str,
+        point: Option<&NavigationPoint>,
+        retention: Option<Duration>,
+    ) -> Result<()> {
+        if self.is_temp() {
+            return Err(ErrorCode::StorageUnsupported(
+                "Tags and branches are not supported on temporary table",
+            ));
+        }
+
+        let now = Utc::now();
+        let mut refs = get_table_refs(self.table_info.options())?;
+        // Expired refs are dropped on the way.
+        refs.retain(|_, table_ref| !table_ref.is_expired(&now));
+        if refs.contains_key(name) {
+            return Err(ErrorCode::TableRefAlreadyExists(format!(
+                "Tag or branch '{}' of table '{}' already exists",
+                name, self.table_info.desc
+            )));
+        }
+
+        let snapshot_location = match point {
+            Some(point) => {
+                let table = self
+                    .navigate_to_point(point, ctx.clone().get_abort_checker())
+                    .await?;
+                table.snapshot_loc().await?
+            }
+            None => self.snapshot_loc().await?,
+        };
+        let expire_at = retention
+            .map(chrono::Duration::from_std)
+            .transpose()
+            .map_err(|e| ErrorCode::BadArguments(format!("invalid retention: {e}")))?
+            .map(|retention| now + retention);
+        refs.insert(name.to_string(), TableRef {
+            kind,
+            snapshot_location,
+            created_on: now,
+            expire_at,
+        });
+
+        self.update_table_refs(ctx, &refs).await
+    }
+
+    #[async_backtrace::framed]
+    pub async fn drop_table_ref(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        kind: TableRefKind,
+        name: &str,
+    ) -> Result<()> {
+        let mut refs = get_table_refs(self.table_info.options())?;
+        match refs.get(name) {
+            Some(table_ref) if table_ref.kind == kind => {
+                refs.remove(name);
+            }
+            _ => {
+                return Err(ErrorCode::UnknownTableRef(format!(
+                    "Unknown {} '{}' of table '{}'",
+                    kind, name, self.table_info.desc
+                )));
+            }
+        }
+
+        self.update_table_refs(ctx, &refs).await
+    }
+
+    async fn update_table_refs(&self, ctx: Arc<dyn TableContext>, refs: &TableRefs) -> Result<()> {
+        let mut new_table_meta = self.table_info.meta.clone();
+        set_table_refs(&mut new_table_meta.options, refs)?;
+        new_table_meta.updated_on = Utc::now();
+
+        let req = UpdateTableMetaReq {
+            table_id: self.table_info.ident.table_id,
+            seq: MatchSeq::Exact(self.table_info.ident.seq),
+            new_table_meta,
+        };
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        catalog
+            .update_single_table_meta(req, &self.table_info)
+            .await?;
+        Ok(())
+    }
+}
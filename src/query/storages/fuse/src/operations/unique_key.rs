@@ -0,0 +1,525 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::Projection;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnId;
+use databend_common_expression::FieldIndex;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::TableField;
+use databend_common_expression::Value;
+use databend_storages_common_index::filters::Filter;
+use databend_storages_common_index::filters::Xor8Filter;
+use databend_storages_common_index::BloomIndex;
+use databend_storages_common_io::ReadSettings;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::ColumnStatistics;
+use databend_storages_common_table_meta::meta::CompactSegmentInfo;
+use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::TableSnapshot;
+use futures_util::future::try_join_all;
+use log::info;
+use log::warn;
+
+use crate::io::read::bloom::block_filter_reader::BloomBlockFilterReader;
+use crate::io::BlockReader;
+use crate::io::DeletionVectorsIO;
+use crate::io::SegmentsIO;
+use crate::operations::read_block;
+use crate::operations::replace_into::row_hash_of_columns;
+use crate::operations::replace_into::UniqueKeyDigest;
+use crate::FuseTable;
+
+// Bloom filters are probed once per new key, beyond this number of keys
+// probing costs more than reading the key columns of the block.
+const MAX_BLOOM_PRUNING_KEYS: usize = 100_000;
+
+impl FuseTable {
+    /// Checks that the enforced PRIMARY KEY and UNIQUE constraints of the table
+    /// still hold once `snapshot` replaces `previous`.
+    ///
+    /// Only the blocks added by `snapshot` are checked, against each other and
+    /// against the blocks it keeps from `previous`. Rows with a NULL key column
+    /// never conflict.
+    #[async_backtrace::framed]
+    pub async fn validate_unique_keys(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        previous: Option<&TableSnapshot>,
+        snapshot: &TableSnapshot,
+    ) -> Result<()> {
+        let keys = self
+            .table_info
+            .meta
+            .constraints
+            .iter()
+            .filter_map(|(name, constraint)| constraint.enforced_key().map(|ids| (name, ids)))
+            .collect::<Vec<_>>();
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        let diff = self.diff_blocks(ctx.clone(), previous, snapshot).await?;
+        if diff.new_blocks.is_empty() {
+            return Ok(());
+        }
+        info!(
+            "validating {} unique keys of table {}, {} new blocks",
+            keys.len(),
+            self.table_info.desc,
+            diff.new_blocks.len()
+        );
+
+        for (name, column_ids) in keys {
+            let checker = UniqueKeyChecker::try_create(ctx.clone(), self, name, column_ids)?;
+            let new_keys = checker.collect_new_keys(&diff.new_blocks).await?;
+            if new_keys.digests.is_empty() {
+                continue;
+            }
+
+            let mut candidates = diff
+                .old_blocks
+                .iter()
+                .filter(|block| checker.overlapped(&block.col_stats, &new_keys.min_max))
+                .cloned()
+                .collect::<Vec<_>>();
+            let segments_io = SegmentsIO::create(ctx.clone(), self.get_operator(), self.schema());
+            let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+            for chunk in diff.shared_segments.chunks(chunk_size.max(1)) {
+                let segments = segments_io
+                    .read_segments::<Arc<CompactSegmentInfo>>(chunk, true)
+                    .await?;
+                for segment in segments {
+                    let segment = segment?;
+                    if !checker.overlapped(&segment.summary.col_stats, &new_keys.min_max) {
+                        continue;
+                    }
+                    candidates.extend(
+                        segment.block_metas()?.into_iter().filter(|block| {
+                            checker.overlapped(&block.col_stats, &new_keys.min_max)
+                        }),
+                    );
+                }
+            }
+            checker.check_old_blocks(&candidates, &new_keys).await?;
+        }
+        Ok(())
+    }
+
+    // Splits the blocks of `snapshot` into the ones written by the commit and the
+    // ones kept from `previous`. Segments kept as a whole are not read here.
+    async fn diff_blocks(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        previous: Option<&TableSnapshot>,
+        snapshot: &TableSnapshot,
+    ) -> Result<BlocksDiff> {
+        let base_segments: HashSet<&Location> = previous
+            .map(|previous| previous.segments.iter().collect())
+            .unwrap_or_default();
+        let latest_segments: HashSet<&Location> = snapshot.segments.iter().collect();
+
+        let segments_io = SegmentsIO::create(ctx.clone(), self.get_operator(), self.schema());
+        let chunk_size = (ctx.get_settings().get_max_threads()? as usize * 4).max(1);
+
+        let removed_segments = base_segments
+            .iter()
+            .filter(|location| !latest_segments.contains(*location))
+            .map(|location| (*location).clone())
+            .collect::<Vec<_>>();
+        let mut base_blocks = HashSet::new();
+        for chunk in removed_segments.chunks(chunk_size) {
+            let segments = segments_io
+                .read_segments::<SegmentInfo>(chunk, false)
+                .await?;
+            for segment in segments {
+                let segment = segment?;
+                base_blocks.extend(segment.blocks.iter().map(|block| block.location.0.clone()));
+            }
+        }
+
+        let added_segments = snapshot
+            .segments
+            .iter()
+            .filter(|location| !base_segments.contains(location))
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut new_blocks = vec![];
+        let mut old_blocks = vec![];
+        for chunk in added_segments.chunks(chunk_size) {
+            let segments = segments_io
+                .read_segments::<SegmentInfo>(chunk, false)
+                .await?;
+            for segment in segments {
+                let segment = segment?;
+                for block in segment.blocks.iter() {
+                    if base_blocks.contains(&block.location.0) {
+                        old_blocks.push(block.clone());
+                    } else {
+                        new_blocks.push(block.clone());
+                    }
+                }
+            }
+        }
+
+        let shared_segments = snapshot
+            .segments
+            .iter()
+            .filter(|location| base_segments.contains(location))
+            .cloned()
+            .collect();
+        Ok(BlocksDiff {
+            new_blocks,
+            old_blocks,
+            shared_segments,
+        })
+    }
+}
+
+struct BlocksDiff {
+    // blocks written by the commit
+    new_blocks: Vec<Arc<BlockMeta>>,
+    // blocks kept from the previous snapshot, in segments written by the commit
+    old_blocks: Vec<Arc<BlockMeta>>,
+    // segments kept from the previous snapshot
+    shared_segments: Vec<Location>,
+}
+
+// The keys written by a commit.
+struct NewKeys {
+    digests: HashSet<UniqueKeyDigest>,
+    // min and max value of each key column
+    min_max: Vec<(Scalar, Scalar)>,
+    // digests of the bloom filter columns, one entry per key
+    bloom_hashes: Vec<Vec<u64>>,
+}
+
+struct UniqueKeyChecker<'a> {
+    table: &'a FuseTable,
+    constraint_name: &'a str,
+    key_fields: Vec<TableField>,
+    // indexes into `key_fields` of the columns which have bloom filters
+    bloom_field_indexes: Vec<usize>,
+    key_column_reader: Arc<BlockReader>,
+    read_settings: ReadSettings,
+    func_ctx: FunctionContext,
+    max_io_requests: usize,
+}
+
+impl<'a> UniqueKeyChecker<'a> {
+    fn try_create(
+        ctx: Arc<dyn TableContext>,
+        table: &'a FuseTable,
+        constraint_name: &'a str,
+        column_ids: &[ColumnId],
+    ) -> Result<Self> {
+        let schema = table.schema();
+        let mut key_fields = Vec::with_capacity(column_ids.len());
+        let mut key_field_indexes: Vec<FieldIndex> = Vec::with_capacity(column_ids.len());
+        for column_id in column_ids {
+            let index = schema
+                .fields()
+                .iter()
+                .position(|field| field.column_id == *column_id)
+                .ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "column id {} of constraint {} not found in table {}",
+                        column_id, constraint_name, table.table_info.desc
+                    ))
+                })?;
+            key_fields.push(schema.fields()[index].clone());
+            key_field_indexes.push(index);
+        }
+
+        let bloom_column_ids = table
+            .bloom_index_cols()
+            .bloom_index_fields(schema.clone(), BloomIndex::supported_type)
+            .unwrap_or_default()
+            .into_values()
+            .map(|field| field.column_id)
+            .collect::<HashSet<_>>();
+        let bloom_field_indexes = key_fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| bloom_column_ids.contains(&field.column_id))
+            .map(|(idx, _)| idx)
+            .collect();
+
+        let key_column_reader = BlockReader::create(
+            ctx.clone(),
+            table.get_operator(),
+            schema,
+            Projection::Columns(key_field_indexes),
+            false,
+            false,
+            false,
+        )?;
+
+        Ok(Self {
+            table,
+            constraint_name,
+            key_fields,
+            bloom_field_indexes,
+            key_column_reader,
+            read_settings: ReadSettings::from_ctx(&ctx)?,
+            func_ctx: ctx.get_function_context()?,
+            max_io_requests: ctx.get_settings().get_max_storage_io_requests()? as usize,
+        })
+    }
+
+    // Reads the live rows of the key columns, NULL keys are returned as well.
+    async fn read_keys(&self, block_meta: &BlockMeta) -> Result<(Vec<Column>, Option<Vec<bool>>)> {
+        let block = read_block(
+            self.table.storage_format,
+            &self.key_column_reader,
+            block_meta,
+            &self.read_settings,
+        )
+        .await?;
+        let num_rows = block.num_rows();
+        let columns = block
+            .columns()
+            .iter()
+            .map(|entry| {
+                entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows)
+            })
+            .collect();
+
+        let deleted = match &block_meta.deletion_vector {
+            Some(meta) => {
+                let deletion_vector =
+                    DeletionVectorsIO::read(&self.table.get_operator(), meta).await?;
+                Some(deletion_vector.deleted().iter().collect())
+            }
+            None => None,
+        };
+        Ok((columns, deleted))
+    }
+
+    async fn read_blocks_keys(
+        &self,
+        blocks: &[Arc<BlockMeta>],
+    ) -> Result<Vec<(Vec<Column>, Option<Vec<bool>>)>> {
+        let mut keys = Vec::with_capacity(blocks.len());
+        for chunk in blocks.chunks(self.max_io_requests.max(1)) {
+            keys.extend(try_join_all(chunk.iter().map(|block| self.read_keys(block))).await?);
+        }
+        Ok(keys)
+    }
+
+    async fn collect_new_keys(&self, new_blocks: &[Arc<BlockMeta>]) -> Result<NewKeys> {
+        let mut digests = HashSet::new();
+        let mut min_max: Vec<Option<(Scalar, Scalar)>> = vec![None; self.key_fields.len()];
+        let mut bloom_hashes = vec![vec![]; self.bloom_field_indexes.len()];
+
+        for (columns, deleted) in self.read_blocks_keys(new_blocks).await? {
+            let values = columns
+                .iter()
+                .map(|column| Value::Column(column.clone()))
+                .collect::<Vec<Value<AnyType>>>();
+            let value_refs = values.iter().collect::<Vec<_>>();
+            let column_digests = self
+                .bloom_field_indexes
+                .iter()
+                .map(|idx| {
+                    let column = &columns[*idx];
+                    BloomIndex::calculate_nullable_column_digest(
+                        &self.func_ctx,
+                        column,
+                        &column.data_type(),
+                    )
+                    .map(|(hashes, _)| hashes)
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            for row in 0..columns.first().map_or(0, |c| c.len()) {
+                if deleted.as_ref().is_some_and(|deleted| deleted[row]) {
+                    continue;
+                }
+                let Some(digest) = row_hash_of_columns(&value_refs, row)? else {
+                    continue;
+                };
+                if !digests.insert(digest) {
+                    return Err(self.violation(&columns, row));
+                }
+
+                for (idx, column) in columns.iter().enumerate() {
+                    let value = column.index(row).unwrap().to_owned();
+                    let (min, max) =
+                        min_max[idx].get_or_insert_with(|| (value.clone(), value.clone()));
+                    if value < *min {
+                        *min = value;
+                    } else if value > *max {
+                        *max = value;
+                    }
+                }
+                for (hashes, column_digests) in bloom_hashes.iter_mut().zip(&column_digests) {
+                    hashes.push(column_digests[row]);
+                }
+            }
+        }
+
+        Ok(NewKeys {
+            digests,
+            min_max: min_max.into_iter().flatten().collect(),
+            bloom_hashes,
+        })
+    }
+
+    async fn check_old_blocks(&self, blocks: &[Arc<BlockMeta>], new_keys: &NewKeys) -> Result<()> {
+        let mut candidates = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            if !self.bloom_pruned(block, new_keys).await {
+                candidates.push(block.clone());
+            }
+        }
+        info!(
+            "checking {} of {} overlapped blocks for constraint {}",
+            candidates.len(),
+            blocks.len(),
+            self.constraint_name
+        );
+
+        for (columns, deleted) in self.read_blocks_keys(&candidates).await? {
+            let values = columns
+                .iter()
+                .map(|column| Value::Column(column.clone()))
+                .collect::<Vec<Value<AnyType>>>();
+            let value_refs = values.iter().collect::<Vec<_>>();
+            for row in 0..columns.first().map_or(0, |c| c.len()) {
+                if deleted.as_ref().is_some_and(|deleted| deleted[row]) {
+                    continue;
+                }
+                if let Some(digest) = row_hash_of_columns(&value_refs, row)? {
+                    if new_keys.digests.contains(&digest) {
+                        return Err(self.violation(&columns, row));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // if any key column of the block does NOT overlap with the new keys, returns false.
+    fn overlapped(
+        &self,
+        column_stats: &HashMap<ColumnId, ColumnStatistics>,
+        min_max: &[(Scalar, Scalar)],
+    ) -> bool {
+        self.key_fields
+            .iter()
+            .zip(min_max)
+            .all(
+                |(field, (key_min, key_max))| match column_stats.get(&field.column_id) {
+                    Some(stats) => {
+                        std::cmp::min(key_max, stats.max()) >= std::cmp::max(key_min, stats.min())
+                    }
+                    // if column range index does not exist, assume overlapped
+                    None => true,
+                },
+            )
+    }
+
+    // returns true if the bloom filters show that none of the new keys is in the block.
+    async fn bloom_pruned(&self, block_meta: &BlockMeta, new_keys: &NewKeys) -> bool {
+        if self.bloom_field_indexes.is_empty() || new_keys.digests.len() > MAX_BLOOM_PRUNING_KEYS {
+            return false;
+        }
+        let Some(location) = &block_meta.bloom_filter_index_location else {
+            return false;
+        };
+        let filters = match self
+            .load_bloom_filters(location, block_meta.bloom_filter_index_size)
+            .await
+        {
+            Ok(filters) => filters,
+            Err(e) => {
+                // broken index should not stop us
+                warn!("failed to load bloom filter of {}: {}", location.0, e);
+                return false;
+            }
+        };
+
+        // a key may be in the block only if every column of it is in the filters
+        (0..new_keys.bloom_hashes[0].len()).all(|row| {
+            new_keys
+                .bloom_hashes
+                .iter()
+                .zip(&filters)
+                .any(|(hashes, filter)| {
+                    filter
+                        .as_ref()
+                        .is_some_and(|filter| !filter.contains_digest(hashes[row]))
+                })
+        })
+    }
+
+    async fn load_bloom_filters(
+        &self,
+        location: &Location,
+        index_len: u64,
+    ) -> Result<Vec<Option<Arc<Xor8Filter>>>> {
+        let col_names = self
+            .bloom_field_indexes
+            .iter()
+            .map(|idx| BloomIndex::build_filter_column_name(location.1, &self.key_fields[*idx]))
+            .collect::<Result<Vec<_>>>()?;
+        let block_filter = location
+            .read_block_filter(self.table.get_operator(), &col_names, index_len)
+            .await?;
+        Ok(col_names
+            .iter()
+            .map(|name| {
+                block_filter
+                    .filter_schema
+                    .index_of(name)
+                    .ok()
+                    .map(|idx| block_filter.filters[idx].clone())
+            })
+            .collect())
+    }
+
+    fn violation(&self, columns: &[Column], row: usize) -> ErrorCode {
+        let values = columns
+            .iter()
+            .map(|column| column.index(row).unwrap_or(ScalarRef::Null).to_string())
+            .collect::<Vec<_>>();
+        let names = self
+            .key_fields
+            .iter()
+            .map(|field| field.name().as_str())
+            .collect::<Vec<_>>();
+        ErrorCode::ConstraintViolated(format!(
+            "Duplicate key ({})=({}) violates constraint {} of table {}",
+            names.join(", "),
+            values.join(", "),
+            self.constraint_name,
+            self.table.table_info.name
+        ))
+    }
+}
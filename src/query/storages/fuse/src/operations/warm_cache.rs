@@ -0,0 +1,241 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Instant;
+
+use databend_common_base::base::ProgressValues;
+use databend_common_catalog::plan::PartitionsShuffleKind;
+use databend_common_catalog::plan::Projection;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_storage::ColumnNodes;
+use databend_storages_common_index::BloomIndex;
+use databend_storages_common_io::ReadSettings;
+use databend_storages_common_pruner::BlockMetaIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use futures_util::future::try_join_all;
+use log::info;
+
+use crate::fuse_part::FuseBlockPartInfo;
+use crate::io::read::bloom::block_filter_reader::BloomBlockFilterReader;
+use crate::io::BlockReader;
+use crate::operations::read_block;
+use crate::pruning::create_segment_location_vector;
+use crate::pruning::FusePruner;
+use crate::FuseTable;
+
+/// What has been loaded into the caches by [`FuseTable::warm_cache`].
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct WarmCacheStats {
+    pub segments: u64,
+    pub blocks: u64,
+    pub bloom_filters: u64,
+    /// The size of the column data read from the storage.
+    pub bytes: u64,
+}
+
+impl FuseTable {
+    /// Loads the segments, the bloom filters and the column data of the blocks that
+    /// survive the pruning of `push_downs` into the caches of this node.
+    ///
+    /// All the columns are loaded if `columns` is not specified. The blocks are read
+    /// exactly as a query would read them, so the data lands in the disk data cache and
+    /// the column array cache, if they are enabled.
+    ///
+    /// If `executors` are specified, only the blocks that the cache affinity scheduling
+    /// assigns to this node among them are loaded.
+    #[async_backtrace::framed]
+    pub async fn warm_cache(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        columns: Option<Vec<String>>,
+        push_downs: Option<PushDownInfo>,
+        executors: Option<Vec<String>>,
+    ) -> Result<WarmCacheStats> {
+        let start = Instant::now();
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(WarmCacheStats::default());
+        };
+
+        let schema = self.schema_with_stream();
+        let field_indexes = match &columns {
+            Some(columns) => columns
+                .iter()
+                .map(|name| schema.index_of(name))
+                .collect::<Result<Vec<_>>>()?,
+            None => (0..schema.num_fields()).collect(),
+        };
+
+        // segments are loaded by the pruning, whether or not their blocks are selected
+        let snapshot_loc = self
+            .meta_location_generator
+            .snapshot_location_from_uuid(&snapshot.snapshot_id, snapshot.format_version)?;
+        let segments_location =
+            create_segment_location_vector(snapshot.segments.clone(), Some(snapshot_loc));
+        let mut pruner = FusePruner::create(
+            &ctx,
+            self.operator.clone(),
+            schema.clone(),
+            &push_downs,
+            self.bloom_index_cols(),
+            None,
+        )?;
+        let mut block_metas = pruner.read_pruning(segments_location).await?;
+        if let Some(executors) = executors {
+            let local_blocks = self.affinity_blocks(&ctx, &schema, &block_metas, executors)?;
+            block_metas.retain(|(_, block_meta)| local_blocks.contains(&block_meta.location.0));
+        }
+
+        let mut stats = WarmCacheStats {
+            segments: snapshot.segments.len() as u64,
+            ..WarmCacheStats::default()
+        };
+        ctx.set_status_info(&format!(
+            "warm cache: read segment files:{}, selected blocks:{}, cost:{:?}",
+            stats.segments,
+            block_metas.len(),
+            start.elapsed()
+        ));
+
+        let bloom_fields = self
+            .bloom_index_cols()
+            .bloom_index_fields(schema.clone(), BloomIndex::supported_type)?
+            .into_iter()
+            .filter(|(idx, _)| field_indexes.contains(idx))
+            .map(|(_, field)| field)
+            .collect::<Vec<_>>();
+        let column_ids = field_indexes
+            .iter()
+            .flat_map(|idx| schema.field(*idx).leaf_column_ids())
+            .collect::<Vec<_>>();
+        let reader = BlockReader::create(
+            ctx.clone(),
+            self.operator.clone(),
+            schema,
+            Projection::Columns(field_indexes),
+            false,
+            false,
+            true,
+        )?;
+        let read_settings = ReadSettings::from_ctx(&ctx)?;
+
+        let max_io_requests = ctx.get_settings().get_max_storage_io_requests()? as usize;
+        let number_blocks = block_metas.len();
+        for chunk in block_metas.chunks(max_io_requests.max(1)) {
+            let loaded = try_join_all(chunk.iter().map(|(_, block_meta)| {
+                self.warm_block_cache(&reader, &read_settings, &bloom_fields, block_meta)
+            }))
+            .await?;
+
+            for (block_meta, bloom_filters) in chunk.iter().map(|(_, m)| m).zip(loaded) {
+                let bytes = column_ids
+                    .iter()
+                    .filter_map(|id| block_meta.col_metas.get(id))
+                    .map(|meta| meta.offset_length().1)
+                    .sum::<u64>();
+                stats.blocks += 1;
+                stats.bloom_filters += bloom_filters;
+                stats.bytes += bytes;
+                ctx.get_scan_progress().incr(&ProgressValues {
+                    rows: block_meta.row_count as usize,
+                    bytes: bytes as usize,
+                });
+            }
+
+            ctx.set_status_info(&format!(
+                "warm cache: loaded blocks:{}/{}, bytes:{}, cost:{:?}",
+                stats.blocks,
+                number_blocks,
+                stats.bytes,
+                start.elapsed()
+            ));
+        }
+
+        info!(
+            "warm cache of table {} done, segments:{}, blocks:{}, bloom filters:{}, bytes:{}, cost:{:?}",
+            self.table_info.desc,
+            stats.segments,
+            stats.blocks,
+            stats.bloom_filters,
+            stats.bytes,
+            start.elapsed()
+        );
+        Ok(stats)
+    }
+
+    // The locations of the blocks scheduled to this node, the same way as the partitions
+    // of a query are scheduled by cache affinity.
+    fn affinity_blocks(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        schema: &TableSchemaRef,
+        block_metas: &[(BlockMetaIndex, Arc<BlockMeta>)],
+        executors: Vec<String>,
+    ) -> Result<HashSet<String>> {
+        let block_metas = block_metas
+            .iter()
+            .map(|(index, block_meta)| (Some(index.clone()), block_meta.clone()))
+            .collect::<Vec<_>>();
+        let arrow_schema = schema.as_ref().into();
+        let column_nodes = ColumnNodes::new_from_schema(&arrow_schema, Some(schema));
+        let (_, mut parts) =
+            Self::to_partitions(Some(schema), &block_metas, &column_nodes, None, None);
+        parts.kind = PartitionsShuffleKind::CacheAffinity;
+
+        let local_id = ctx.get_cluster().local_id.clone();
+        let Some(local_parts) = parts.reshuffle(executors)?.remove(&local_id) else {
+            return Ok(HashSet::new());
+        };
+        local_parts
+            .partitions
+            .iter()
+            .map(|part| FuseBlockPartInfo::from_part(part).map(|part| part.location.clone()))
+            .collect()
+    }
+
+    // Reads the bloom filters and the columns of a block, returns the number of bloom filters.
+    async fn warm_block_cache(
+        &self,
+        reader: &BlockReader,
+        read_settings: &ReadSettings,
+        bloom_fields: &[TableField],
+        block_meta: &BlockMeta,
+    ) -> Result<u64> {
+        let mut bloom_filters = 0;
+        if let Some(location) = &block_meta.bloom_filter_index_location {
+            if !bloom_fields.is_empty() {
+                let col_names = bloom_fields
+                    .iter()
+                    .map(|field| BloomIndex::build_filter_column_name(location.1, field))
+                    .collect::<Result<Vec<_>>>()?;
+                let block_filter = location
+                    .read_block_filter(
+                        self.operator.clone(),
+                        &col_names,
+                        block_meta.bloom_filter_index_size,
+                    )
+                    .await?;
+                bloom_filters = block_filter.filters.len() as u64;
+            }
+        }
+
+        read_block(self.storage_format, reader, block_meta, read_settings).await?;
+        Ok(bloom_filters)
+    }
+}
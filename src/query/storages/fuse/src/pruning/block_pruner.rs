@@ -172,7 +172,8 @@ impl BlockPruner {
                                     .should_keep(&index_location, index_size, &block_meta.col_stats, column_ids, &block_meta)
                                     .await;
 
-                                let keep = keep_by_bloom && limit_pruner.within_limit(row_count);
+                                let keep = keep_by_bloom
+                                    && limit_pruner.within_limit(block_meta.live_row_count());
                                 if keep {
                                     // Perf.
                                     {
@@ -186,7 +187,7 @@ impl BlockPruner {
                                 }
                                 keep
                             } else {
-                                limit_pruner.within_limit(row_count)
+                                limit_pruner.within_limit(block_meta.live_row_count())
                             };
                             if keep {
                                 let (keep, range) =
@@ -315,9 +316,8 @@ impl BlockPruner {
             if limit_pruner.exceeded() {
                 break;
             }
-            let row_count = block_meta.row_count;
             if range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas))
-                && limit_pruner.within_limit(row_count)
+                && limit_pruner.within_limit(block_meta.live_row_count())
             {
                 // Perf.
                 {
@@ -17,7 +17,6 @@ use std::sync::Arc;
 use databend_common_arrow::arrow::datatypes::Field;
 use databend_common_arrow::native::read::reader::NativeReader;
 use databend_common_arrow::native::stat::stat_simple;
-use databend_common_arrow::native::stat::ColumnInfo;
 use databend_common_arrow::native::stat::PageBody;
 use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
 use databend_common_catalog::plan::DataSourcePlan;
@@ -49,9 +48,12 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_expression::Value;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_storage::read_metadata_async;
 use databend_storages_common_io::MergeIOReader;
 use databend_storages_common_io::ReadSettings;
+use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::SegmentInfo;
+use parquet::basic::Compression as ParquetCompression;
 
 use crate::io::SegmentsIO;
 use crate::sessions::TableContext;
@@ -123,6 +125,19 @@ impl SimpleArgFunc for FuseEncoding {
     }
 }
 
+/// The encoding of a page in native blocks, or a column chunk in parquet blocks.
+struct EncodingInfo {
+    column_name: String,
+    column_type: String,
+    validity_size: Option<u32>,
+    compressed_size: u32,
+    uncompressed_size: u32,
+    level_one: String,
+    level_two: Option<String>,
+    /// The codec declared by `CODEC(...)` of the column.
+    codec: Option<String>,
+}
+
 pub struct FuseEncodingImpl<'a> {
     pub ctx: Arc<dyn TableContext>,
     pub tables: Vec<&'a FuseTable>,
@@ -146,9 +161,6 @@ impl<'a> FuseEncodingImpl<'a> {
     pub async fn get_blocks(&self) -> Result<DataBlock> {
         let mut info = Vec::new();
         for table in self.tables.clone() {
-            if matches!(table.storage_format, FuseStorageFormat::Parquet) {
-                continue;
-            }
             let mut columns_info = vec![];
             let snapshot = table.read_table_snapshot().await?;
             if snapshot.is_none() {
@@ -170,38 +182,15 @@ impl<'a> FuseEncodingImpl<'a> {
                 for segment in segments {
                     let segment = segment?;
                     for block in segment.blocks.iter() {
-                        for field in fields {
-                            if field.is_nested() {
-                                continue;
+                        match table.storage_format {
+                            FuseStorageFormat::Native => {
+                                self.native_encodings(table, block, fields, &mut columns_info)
+                                    .await?
+                            }
+                            FuseStorageFormat::Parquet => {
+                                self.parquet_encodings(table, block, fields, &mut columns_info)
+                                    .await?
                             }
-                            let column_id = field.column_id;
-                            let arrow_field: Field = field.into();
-                            let column_meta = block.col_metas.get(&column_id).unwrap();
-                            let (offset, len) = column_meta.offset_length();
-                            let ranges = vec![(column_id, offset..(offset + len))];
-                            let read_settings = ReadSettings::from_ctx(&self.ctx)?;
-                            let merge_io_result = MergeIOReader::merge_io_read(
-                                &read_settings,
-                                table.operator.clone(),
-                                &block.location.0,
-                                &ranges,
-                            )
-                            .await?;
-
-                            let block_read_res =
-                                BlockReadResult::create(merge_io_result, vec![], vec![]);
-
-                            let column_chunks = block_read_res.columns_chunks()?;
-                            let pages = column_chunks
-                                .get(&column_id)
-                                .unwrap()
-                                .as_raw_data()
-                                .unwrap();
-                            let pages = std::io::Cursor::new(pages);
-                            let page_metas = column_meta.as_native().unwrap().pages.clone();
-                            let reader = NativeReader::new(pages, page_metas, vec![]);
-                            let this_column_info = stat_simple(reader, arrow_field.clone())?;
-                            columns_info.push((field.data_type.sql_name(), this_column_info));
                         }
                     }
                 }
@@ -229,7 +218,124 @@ impl<'a> FuseEncodingImpl<'a> {
     }
 
     #[async_backtrace::framed]
-    async fn to_block(&self, info: &Vec<(&str, Vec<(String, ColumnInfo)>)>) -> Result<DataBlock> {
+    async fn native_encodings(
+        &self,
+        table: &FuseTable,
+        block: &BlockMeta,
+        fields: &[TableField],
+        columns_info: &mut Vec<EncodingInfo>,
+    ) -> Result<()> {
+        for field in fields {
+            if field.is_nested() {
+                continue;
+            }
+            let column_id = field.column_id;
+            let arrow_field: Field = field.into();
+            // columns added after the block was written are not in it
+            let Some(column_meta) = block.col_metas.get(&column_id) else {
+                continue;
+            };
+            let (offset, len) = column_meta.offset_length();
+            let ranges = vec![(column_id, offset..(offset + len))];
+            let read_settings = ReadSettings::from_ctx(&self.ctx)?;
+            let merge_io_result = MergeIOReader::merge_io_read(
+                &read_settings,
+                table.operator.clone(),
+                &block.location.0,
+                &ranges,
+            )
+            .await?;
+
+            let block_read_res = BlockReadResult::create(merge_io_result, vec![], vec![]);
+
+            let column_chunks = block_read_res.columns_chunks()?;
+            let pages = column_chunks
+                .get(&column_id)
+                .unwrap()
+                .as_raw_data()
+                .unwrap();
+            let pages = std::io::Cursor::new(pages);
+            let page_metas = column_meta.as_native().unwrap().pages.clone();
+            let reader = NativeReader::new(pages, page_metas, vec![]);
+            let column_info = stat_simple(reader, arrow_field.clone())?;
+            for page in column_info.pages.iter() {
+                let level_two = match &page.body {
+                    PageBody::Dict(dict) => Some(encoding_to_string(&dict.indices.body)),
+                    PageBody::Freq(freq) => freq
+                        .exceptions
+                        .as_ref()
+                        .map(|e| encoding_to_string(&e.body)),
+                    _ => None,
+                };
+                columns_info.push(EncodingInfo {
+                    column_name: field.name().clone(),
+                    column_type: field.data_type.sql_name(),
+                    validity_size: page.validity_size,
+                    compressed_size: page.compressed_size,
+                    uncompressed_size: page.uncompressed_size,
+                    level_one: encoding_to_string(&page.body),
+                    level_two,
+                    codec: field.codec().cloned(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// For parquet blocks, each column chunk is reported with its data page encodings
+    /// as `level_one`, and its compression as `level_two`.
+    #[async_backtrace::framed]
+    async fn parquet_encodings(
+        &self,
+        table: &FuseTable,
+        block: &BlockMeta,
+        fields: &[TableField],
+        columns_info: &mut Vec<EncodingInfo>,
+    ) -> Result<()> {
+        let metadata =
+            read_metadata_async(&block.location.0, &table.operator, Some(block.file_size)).await?;
+        let Some(row_group) = metadata.row_groups().first() else {
+            return Ok(());
+        };
+        for field in fields {
+            if field.is_nested() {
+                continue;
+            }
+            let Some(column) = row_group
+                .columns()
+                .iter()
+                .find(|column| column.column_path().parts() == [field.name().as_str()])
+            else {
+                continue;
+            };
+            let level_one = column
+                .encodings()
+                .iter()
+                .map(|encoding| format!("{:?}", encoding))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let level_two = match column.compression() {
+                ParquetCompression::ZSTD(level) => {
+                    format!("Zstd({})", level.compression_level())
+                }
+                compression => format!("{:?}", compression),
+            };
+            columns_info.push(EncodingInfo {
+                column_name: field.name().clone(),
+                column_type: field.data_type.sql_name(),
+                validity_size: None,
+                compressed_size: column.compressed_size() as u32,
+                uncompressed_size: column.uncompressed_size() as u32,
+                level_one,
+                level_two: Some(level_two),
+                codec: field.codec().cloned(),
+            });
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn to_block(&self, info: &Vec<(&str, Vec<EncodingInfo>)>) -> Result<DataBlock> {
         let mut validity_size = Vec::new();
         let mut compressed_size = Vec::new();
         let mut uncompressed_size = Vec::new();
@@ -238,42 +344,33 @@ impl<'a> FuseEncodingImpl<'a> {
         let mut table_name = StringColumnBuilder::with_capacity(0, 0);
         let mut column_name = StringColumnBuilder::with_capacity(0, 0);
         let mut column_type = StringColumnBuilder::with_capacity(0, 0);
+        let mut codec = NullableColumnBuilder::<StringType>::with_capacity(0, &[]);
         let mut all_num_rows = 0;
         for (table, columns_info) in info {
-            for (type_str, column_info) in columns_info {
-                let pages_info = &column_info.pages;
-                let num_row = pages_info.len();
-                all_num_rows += num_row;
-                validity_size.reserve(num_row);
-                compressed_size.reserve(num_row);
-                uncompressed_size.reserve(num_row);
-                let tmp_table_name = StringColumnBuilder::repeat(table, num_row);
-                let tmp_column_name = StringColumnBuilder::repeat(&column_info.field.name, num_row);
-                let tmp_column_type = StringColumnBuilder::repeat(type_str, num_row);
-                for p in pages_info {
-                    validity_size.push(p.validity_size);
-                    compressed_size.push(p.compressed_size);
-                    uncompressed_size.push(p.uncompressed_size);
-                    l1.put_str(&encoding_to_string(&p.body));
-                    l1.commit_row();
-                    let l2_encoding = match &p.body {
-                        PageBody::Dict(dict) => Some(encoding_to_string(&dict.indices.body)),
-                        PageBody::Freq(freq) => freq
-                            .exceptions
-                            .as_ref()
-                            .map(|e| encoding_to_string(&e.body)),
-                        _ => None,
-                    };
-                    if let Some(l2_encoding) = l2_encoding {
-                        l2.push(&l2_encoding);
-                    } else {
-                        l2.push_null();
-                    }
+            all_num_rows += columns_info.len();
+            validity_size.reserve(columns_info.len());
+            compressed_size.reserve(columns_info.len());
+            uncompressed_size.reserve(columns_info.len());
+            for column_info in columns_info {
+                table_name.put_str(table);
+                table_name.commit_row();
+                column_name.put_str(&column_info.column_name);
+                column_name.commit_row();
+                column_type.put_str(&column_info.column_type);
+                column_type.commit_row();
+                validity_size.push(column_info.validity_size);
+                compressed_size.push(column_info.compressed_size);
+                uncompressed_size.push(column_info.uncompressed_size);
+                l1.put_str(&column_info.level_one);
+                l1.commit_row();
+                match &column_info.level_two {
+                    Some(level_two) => l2.push(level_two),
+                    None => l2.push_null(),
+                }
+                match &column_info.codec {
+                    Some(c) => codec.push(c),
+                    None => codec.push_null(),
                 }
-
-                table_name.append_column(&tmp_table_name.build());
-                column_name.append_column(&tmp_column_name.build());
-                column_type.append_column(&tmp_column_type.build());
             }
         }
 
@@ -308,6 +405,10 @@ impl<'a> FuseEncodingImpl<'a> {
                     DataType::Nullable(Box::new(DataType::String)),
                     Value::Column(Column::Nullable(Box::new(l2.build().upcast()))),
                 ),
+                BlockEntry::new(
+                    DataType::Nullable(Box::new(DataType::String)),
+                    Value::Column(Column::Nullable(Box::new(codec.build().upcast()))),
+                ),
             ],
             all_num_rows,
         ))
@@ -335,6 +436,10 @@ impl<'a> FuseEncodingImpl<'a> {
                 "level_two",
                 TableDataType::Nullable(Box::new(TableDataType::String)),
             ),
+            TableField::new(
+                "codec",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
         ])
     }
 }
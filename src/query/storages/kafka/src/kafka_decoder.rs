@@ -0,0 +1,203 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use csv_core::ReadRecordResult;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::TableSchemaRef;
+use databend_common_formats::FieldJsonAstDecoder;
+use databend_common_formats::FileFormatOptionsExt;
+use databend_common_formats::SeparatedTextDecoder;
+use databend_common_meta_app::principal::CsvFileFormatParams;
+use serde_json::Value;
+
+/// The format of the value of the messages in the topic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KafkaMessageFormat {
+    Json,
+    Csv,
+    Avro,
+}
+
+impl FromStr for KafkaMessageFormat {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(KafkaMessageFormat::Json),
+            "csv" => Ok(KafkaMessageFormat::Csv),
+            "avro" => Ok(KafkaMessageFormat::Avro),
+            other => Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid kafka message format '{}', expect one of JSON, CSV and AVRO",
+                other
+            ))),
+        }
+    }
+}
+
+enum DecoderInner {
+    Json(FieldJsonAstDecoder),
+    Csv {
+        decoder: SeparatedTextDecoder,
+        reader: csv_core::Reader,
+    },
+    Avro {
+        decoder: FieldJsonAstDecoder,
+        schema: apache_avro::Schema,
+    },
+}
+
+/// Decodes the value of each message into one row of the table schema.
+pub struct KafkaMessageDecoder {
+    schema: TableSchemaRef,
+    inner: DecoderInner,
+}
+
+impl KafkaMessageDecoder {
+    pub fn create(
+        format: KafkaMessageFormat,
+        schema: TableSchemaRef,
+        avro_schema: Option<&str>,
+        options_ext: &FileFormatOptionsExt,
+    ) -> Result<Self> {
+        let inner = match format {
+            KafkaMessageFormat::Json => {
+                DecoderInner::Json(FieldJsonAstDecoder::create(options_ext))
+            }
+            KafkaMessageFormat::Csv => {
+                let params = CsvFileFormatParams::default();
+                DecoderInner::Csv {
+                    decoder: SeparatedTextDecoder::create_csv(&params, options_ext),
+                    reader: csv_core::ReaderBuilder::new()
+                        .delimiter(params.field_delimiter.as_bytes()[0])
+                        .quote(params.quote.as_bytes()[0])
+                        .build(),
+                }
+            }
+            KafkaMessageFormat::Avro => {
+                let avro_schema = avro_schema.ok_or_else(|| {
+                    ErrorCode::TableOptionInvalid(
+                        "table option avro_schema is required by AVRO kafka messages",
+                    )
+                })?;
+                let schema = apache_avro::Schema::parse_str(avro_schema).map_err(|e| {
+                    ErrorCode::TableOptionInvalid(format!("invalid avro_schema: {}", e))
+                })?;
+                DecoderInner::Avro {
+                    decoder: FieldJsonAstDecoder::create(options_ext),
+                    schema,
+                }
+            }
+        };
+
+        Ok(KafkaMessageDecoder { schema, inner })
+    }
+
+    pub fn schema(&self) -> &TableSchemaRef {
+        &self.schema
+    }
+
+    pub fn decode(&mut self, columns: &mut [ColumnBuilder], payload: &[u8]) -> Result<()> {
+        match &mut self.inner {
+            DecoderInner::Json(decoder) => {
+                let value = serde_json::from_slice::<Value>(payload)
+                    .map_err(|e| ErrorCode::BadBytes(format!("invalid json message: {}", e)))?;
+                Self::read_json(&self.schema, decoder, columns, &value)
+            }
+            DecoderInner::Csv { decoder, reader } => {
+                Self::read_csv(&self.schema, decoder, reader, columns, payload)
+            }
+            DecoderInner::Avro { decoder, schema } => {
+                let value = apache_avro::from_avro_datum(schema, &mut &payload[..], None)
+                    .map_err(|e| ErrorCode::BadBytes(format!("invalid avro message: {}", e)))?;
+                let value = Value::try_from(value)
+                    .map_err(|e| ErrorCode::BadBytes(format!("invalid avro message: {}", e)))?;
+                Self::read_json(&self.schema, decoder, columns, &value)
+            }
+        }
+    }
+
+    fn read_json(
+        schema: &TableSchemaRef,
+        decoder: &FieldJsonAstDecoder,
+        columns: &mut [ColumnBuilder],
+        value: &Value,
+    ) -> Result<()> {
+        let Value::Object(object) = value else {
+            return Err(ErrorCode::BadBytes(format!(
+                "kafka message must be an object, but got {}",
+                value
+            )));
+        };
+
+        for (field, column) in schema.fields().iter().zip(columns.iter_mut()) {
+            match object.get(field.name()) {
+                None | Some(Value::Null) if field.is_nullable() => column.push_default(),
+                None => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "kafka message has no value of the non-nullable column {}",
+                        field.name()
+                    )));
+                }
+                Some(value) => decoder.read_field(column, value)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn read_csv(
+        schema: &TableSchemaRef,
+        decoder: &SeparatedTextDecoder,
+        reader: &mut csv_core::Reader,
+        columns: &mut [ColumnBuilder],
+        payload: &[u8],
+    ) -> Result<()> {
+        let mut input = payload.to_vec();
+        if !input.ends_with(b"\n") {
+            input.push(b'\n');
+        }
+
+        let num_fields = schema.num_fields();
+        let mut output = vec![0; input.len()];
+        let mut field_ends = vec![0; num_fields + 1];
+
+        reader.reset();
+        let (result, _, _, n_ends) = reader.read_record(&input, &mut output, &mut field_ends);
+        match result {
+            ReadRecordResult::Record if n_ends == num_fields => {}
+            ReadRecordResult::Record | ReadRecordResult::OutputEndsFull => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "kafka message has a wrong number of csv fields, expect {}",
+                    num_fields
+                )));
+            }
+            _ => return Err(ErrorCode::BadBytes("invalid csv message")),
+        }
+
+        let mut start = 0;
+        for (end, column) in field_ends.iter().zip(columns.iter_mut()).take(num_fields) {
+            let data = &output[start..*end];
+            if data.is_empty() {
+                column.push_default();
+            } else {
+                decoder.read_field(column, data)?;
+            }
+            start = *end;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PartInfo;
+use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+/// The offsets `[start_offset, end_offset)` of a topic partition to read.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct KafkaPartInfo {
+    pub partition: i32,
+    pub start_offset: i64,
+    pub end_offset: i64,
+}
+
+#[typetag::serde(name = "kafka")]
+impl PartInfo for KafkaPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        info.as_any()
+            .downcast_ref::<KafkaPartInfo>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn hash(&self) -> u64 {
+        self.partition as u64
+    }
+}
+
+impl KafkaPartInfo {
+    pub fn create(partition: i32, start_offset: i64, end_offset: i64) -> Arc<Box<dyn PartInfo>> {
+        Arc::new(Box::new(KafkaPartInfo {
+            partition,
+            start_offset,
+            end_offset,
+        }))
+    }
+
+    pub fn from_part(info: &PartInfoPtr) -> Result<&KafkaPartInfo> {
+        info.as_any()
+            .downcast_ref::<KafkaPartInfo>()
+            .ok_or_else(|| ErrorCode::Internal("Cannot downcast from PartInfo to KafkaPartInfo."))
+    }
+}
@@ -0,0 +1,169 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_formats::FileFormatOptionsExt;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use rskafka::client::partition::OffsetAt;
+use rskafka::client::partition::PartitionClient;
+
+use crate::KafkaMessageDecoder;
+use crate::KafkaPartInfo;
+use crate::KafkaTable;
+use crate::KafkaTopic;
+
+// The max bytes of the messages fetched in one request.
+const MAX_FETCH_BYTES: i32 = 16 * 1024 * 1024;
+const MAX_FETCH_WAIT_MS: i32 = 500;
+// The empty fetches tolerated before the read fails, the broker may lag behind
+// the offsets of the snapshot for a while, e.g. during a leader change.
+const MAX_EMPTY_FETCHES: usize = 3;
+
+/// Reads the messages of a partition in `[start_offset, end_offset)`.
+pub struct KafkaSource {
+    topic: KafkaTopic,
+    partition: i32,
+    next_offset: i64,
+    end_offset: i64,
+    client: Option<PartitionClient>,
+    decoder: KafkaMessageDecoder,
+    max_block_size: usize,
+}
+
+impl KafkaSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        table: &KafkaTable,
+        part: &KafkaPartInfo,
+    ) -> Result<ProcessorPtr> {
+        let settings = ctx.get_settings();
+        let options_ext = FileFormatOptionsExt::create_from_settings(&settings, false)?;
+        let decoder = KafkaMessageDecoder::create(
+            table.format(),
+            table.schema(),
+            table.avro_schema(),
+            &options_ext,
+        )?;
+
+        AsyncSourcer::create(ctx, output, KafkaSource {
+            topic: table.topic().clone(),
+            partition: part.partition,
+            next_offset: part.start_offset,
+            end_offset: part.end_offset,
+            client: None,
+            decoder,
+            max_block_size: settings.get_max_block_size()? as usize,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for KafkaSource {
+    const NAME: &'static str = "KafkaSource";
+
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.next_offset >= self.end_offset {
+            return Ok(None);
+        }
+
+        if self.client.is_none() {
+            let client = self.topic.client().await?;
+            let client = self.topic.partition_client(&client, self.partition).await?;
+            self.client = Some(client);
+        }
+        let client = self.client.as_ref().unwrap();
+
+        let schema = self.decoder.schema();
+        let mut columns = schema
+            .fields()
+            .iter()
+            .map(|f| ColumnBuilder::with_capacity(&f.data_type().into(), self.max_block_size))
+            .collect::<Vec<_>>();
+
+        let mut num_rows = 0;
+        let mut empty_fetches = 0;
+        while num_rows < self.max_block_size && self.next_offset < self.end_offset {
+            let (records, _) = client
+                .fetch_records(self.next_offset, 1..MAX_FETCH_BYTES, MAX_FETCH_WAIT_MS)
+                .await
+                .map_err(|e| {
+                    ErrorCode::StorageUnavailable(format!(
+                        "failed to fetch messages from partition {} of kafka topic {}: {}",
+                        self.partition, self.topic.topic, e
+                    ))
+                })?;
+
+            if records.is_empty() {
+                // Only skip the offsets if they are proved to be removed by the retention,
+                // otherwise the messages would be committed without being read.
+                let low_watermark = KafkaTable::fetch_offset(client, OffsetAt::Earliest).await?;
+                if low_watermark > self.next_offset {
+                    self.next_offset = low_watermark.min(self.end_offset);
+                    empty_fetches = 0;
+                    continue;
+                }
+
+                empty_fetches += 1;
+                if empty_fetches >= MAX_EMPTY_FETCHES {
+                    return Err(ErrorCode::StorageUnavailable(format!(
+                        "no messages were fetched from partition {} of kafka topic {} at offset {}, expect messages until offset {}",
+                        self.partition, self.topic.topic, self.next_offset, self.end_offset
+                    )));
+                }
+                continue;
+            }
+            empty_fetches = 0;
+
+            for record in records {
+                if record.offset >= self.end_offset {
+                    break;
+                }
+                self.next_offset = record.offset + 1;
+
+                // Tombstones of compacted topics have no value.
+                if let Some(value) = &record.record.value {
+                    self.decoder.decode(&mut columns, value).map_err(|e| {
+                        e.add_message_back(format!(
+                            " (partition {}, offset {})",
+                            self.partition, record.offset
+                        ))
+                    })?;
+                    num_rows += 1;
+                }
+
+                if num_rows >= self.max_block_size {
+                    break;
+                }
+            }
+        }
+
+        let columns = columns
+            .into_iter()
+            .map(|builder| builder.build())
+            .collect::<Vec<_>>();
+        Ok(Some(DataBlock::new_from_columns(columns)))
+    }
+}
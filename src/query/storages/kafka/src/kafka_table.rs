@@ -0,0 +1,330 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::StorageDescription;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PartitionsShuffleKind;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_core::SourcePipeBuilder;
+use databend_common_pipeline_sources::EmptySource;
+use databend_storages_common_table_meta::table::OPT_KEY_KAFKA_AVRO_SCHEMA;
+use databend_storages_common_table_meta::table::OPT_KEY_KAFKA_BROKERS;
+use databend_storages_common_table_meta::table::OPT_KEY_KAFKA_FORMAT;
+use databend_storages_common_table_meta::table::OPT_KEY_KAFKA_OFFSETS;
+use databend_storages_common_table_meta::table::OPT_KEY_KAFKA_TOPIC;
+use rskafka::client::partition::OffsetAt;
+use rskafka::client::partition::PartitionClient;
+use rskafka::client::partition::UnknownTopicHandling;
+use rskafka::client::Client;
+use rskafka::client::ClientBuilder;
+use tokio::sync::OnceCell;
+
+use crate::kafka_source::KafkaSource;
+use crate::KafkaMessageFormat;
+use crate::KafkaPartInfo;
+
+pub const KAFKA_ENGINE: &str = "KAFKA";
+
+/// A table reading the messages of a kafka topic.
+///
+/// The table keeps the committed offset of each partition in its options. A query reads the
+/// messages between the committed offsets and the high watermarks fetched when the table is
+/// first read, DML consuming the table advances the committed offsets in the same meta
+/// transaction that commits its target table, so every message is appended exactly once.
+pub struct KafkaTable {
+    table_info: TableInfo,
+    topic: KafkaTopic,
+    format: KafkaMessageFormat,
+    committed_offsets: BTreeMap<i32, i64>,
+    // (partition, start offset, end offset) of the partitions, fetched once per query.
+    snapshot: OnceCell<Vec<(i32, i64, i64)>>,
+}
+
+impl KafkaTable {
+    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let options = &table_info.meta.options;
+        let brokers = Self::required_option(options, OPT_KEY_KAFKA_BROKERS)?
+            .split(',')
+            .map(|broker| broker.trim().to_string())
+            .filter(|broker| !broker.is_empty())
+            .collect::<Vec<_>>();
+        let topic = Self::required_option(options, OPT_KEY_KAFKA_TOPIC)?.to_string();
+        let format = match options.get(OPT_KEY_KAFKA_FORMAT) {
+            None => KafkaMessageFormat::Json,
+            Some(format) => KafkaMessageFormat::from_str(format)?,
+        };
+        let committed_offsets = match options.get(OPT_KEY_KAFKA_OFFSETS) {
+            None => BTreeMap::new(),
+            Some(offsets) => decode_offsets(offsets)?,
+        };
+
+        Ok(Box::new(KafkaTable {
+            table_info,
+            topic: KafkaTopic { brokers, topic },
+            format,
+            committed_offsets,
+            snapshot: OnceCell::new(),
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: KAFKA_ENGINE.to_string(),
+            comment: "KAFKA Storage Engine".to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn try_from_table(tbl: &dyn Table) -> Result<&KafkaTable> {
+        tbl.as_any().downcast_ref::<KafkaTable>().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "expects table of engine KAFKA, but got {}",
+                tbl.engine()
+            ))
+        })
+    }
+
+    /// Validate the options of a KAFKA table in `CREATE TABLE`.
+    pub fn check_options(options: &BTreeMap<String, String>) -> Result<()> {
+        Self::required_option(options, OPT_KEY_KAFKA_BROKERS)?;
+        Self::required_option(options, OPT_KEY_KAFKA_TOPIC)?;
+        if let Some(format) = options.get(OPT_KEY_KAFKA_FORMAT) {
+            let format = KafkaMessageFormat::from_str(format)?;
+            if format == KafkaMessageFormat::Avro
+                && !options.contains_key(OPT_KEY_KAFKA_AVRO_SCHEMA)
+            {
+                return Err(ErrorCode::TableOptionInvalid(
+                    "table option avro_schema is required by AVRO kafka messages",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn required_option<'a>(options: &'a BTreeMap<String, String>, key: &str) -> Result<&'a str> {
+        options.get(key).map(|v| v.as_str()).ok_or_else(|| {
+            ErrorCode::TableOptionInvalid(format!(
+                "table option {} is required by KAFKA engine",
+                key
+            ))
+        })
+    }
+
+    pub fn topic(&self) -> &KafkaTopic {
+        &self.topic
+    }
+
+    pub fn format(&self) -> KafkaMessageFormat {
+        self.format
+    }
+
+    pub fn avro_schema(&self) -> Option<&str> {
+        self.table_info
+            .meta
+            .options
+            .get(OPT_KEY_KAFKA_AVRO_SCHEMA)
+            .map(|v| v.as_str())
+    }
+
+    /// The `(partition, start offset, end offset)` of the partitions read by this query.
+    #[async_backtrace::framed]
+    pub async fn snapshot(&self) -> Result<&[(i32, i64, i64)]> {
+        let snapshot = self
+            .snapshot
+            .get_or_try_init(|| async {
+                let client = self.topic.client().await?;
+                let topics = client.list_topics().await.map_err(|e| {
+                    ErrorCode::StorageUnavailable(format!("failed to list kafka topics: {}", e))
+                })?;
+                let topic = topics
+                    .into_iter()
+                    .find(|topic| topic.name == self.topic.topic)
+                    .ok_or_else(|| {
+                        ErrorCode::StorageOther(format!(
+                            "kafka topic {} not found",
+                            self.topic.topic
+                        ))
+                    })?;
+
+                let mut snapshot = Vec::with_capacity(topic.partitions.len());
+                for partition in topic.partitions {
+                    let partition_client = self.topic.partition_client(&client, partition).await?;
+                    let earliest =
+                        Self::fetch_offset(&partition_client, OffsetAt::Earliest).await?;
+                    let latest = Self::fetch_offset(&partition_client, OffsetAt::Latest).await?;
+
+                    // Messages older than the retention of the topic are lost.
+                    let start = match self.committed_offsets.get(&partition) {
+                        None => earliest,
+                        Some(committed) => (*committed).max(earliest),
+                    };
+                    snapshot.push((partition, start, latest.max(start)));
+                }
+                Ok::<_, ErrorCode>(snapshot)
+            })
+            .await?;
+        Ok(snapshot)
+    }
+
+    pub(crate) async fn fetch_offset(client: &PartitionClient, at: OffsetAt) -> Result<i64> {
+        client.get_offset(at).await.map_err(|e| {
+            ErrorCode::StorageUnavailable(format!("failed to fetch kafka offset: {}", e))
+        })
+    }
+
+    /// The table options after consuming the messages read by this query.
+    #[async_backtrace::framed]
+    pub async fn consumed_options(&self) -> Result<BTreeMap<String, String>> {
+        let mut offsets = self.committed_offsets.clone();
+        for (partition, _, end) in self.snapshot().await? {
+            offsets.insert(*partition, *end);
+        }
+
+        let mut options = self.table_info.meta.options.clone();
+        options.insert(OPT_KEY_KAFKA_OFFSETS.to_string(), encode_offsets(&offsets));
+        Ok(options)
+    }
+}
+
+/// The brokers and the name of a kafka topic.
+#[derive(Clone, Debug)]
+pub struct KafkaTopic {
+    pub brokers: Vec<String>,
+    pub topic: String,
+}
+
+impl KafkaTopic {
+    pub async fn client(&self) -> Result<Client> {
+        ClientBuilder::new(self.brokers.clone())
+            .build()
+            .await
+            .map_err(|e| {
+                ErrorCode::StorageUnavailable(format!(
+                    "failed to connect to kafka brokers {:?}: {}",
+                    self.brokers, e
+                ))
+            })
+    }
+
+    pub async fn partition_client(
+        &self,
+        client: &Client,
+        partition: i32,
+    ) -> Result<PartitionClient> {
+        client
+            .partition_client(self.topic.clone(), partition, UnknownTopicHandling::Error)
+            .await
+            .map_err(|e| {
+                ErrorCode::StorageUnavailable(format!(
+                    "failed to connect to partition {} of kafka topic {}: {}",
+                    partition, self.topic, e
+                ))
+            })
+    }
+}
+
+/// Offsets are kept as `partition:offset` pairs separated by commas.
+fn encode_offsets(offsets: &BTreeMap<i32, i64>) -> String {
+    offsets
+        .iter()
+        .map(|(partition, offset)| format!("{}:{}", partition, offset))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn decode_offsets(s: &str) -> Result<BTreeMap<i32, i64>> {
+    let mut offsets = BTreeMap::new();
+    for pair in s.split(',').filter(|pair| !pair.is_empty()) {
+        let (partition, offset) = pair
+            .split_once(':')
+            .ok_or_else(|| ErrorCode::Internal(format!("invalid kafka offsets {}", s)))?;
+        offsets.insert(partition.parse::<i32>()?, offset.parse::<i64>()?);
+    }
+    Ok(offsets)
+}
+
+#[async_trait::async_trait]
+impl Table for KafkaTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let mut parts = vec![];
+        let mut num_messages = 0;
+        for (partition, start, end) in self.snapshot().await? {
+            if start < end {
+                num_messages += (end - start) as usize;
+                parts.push(KafkaPartInfo::create(*partition, *start, *end));
+            }
+        }
+
+        let statistics =
+            PartStatistics::new_estimated(None, num_messages, 0, parts.len(), parts.len());
+        Ok((
+            statistics,
+            Partitions::create(PartitionsShuffleKind::Seq, parts),
+        ))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        let mut builder = SourcePipeBuilder::create();
+        for part in plan.parts.partitions.iter() {
+            let part = KafkaPartInfo::from_part(part)?;
+            let output = OutputPort::create();
+            builder.add_source(
+                output.clone(),
+                KafkaSource::create(ctx.clone(), output, self, part)?,
+            );
+        }
+
+        if plan.parts.is_empty() {
+            let output = OutputPort::create();
+            builder.add_source(output.clone(), EmptySource::create(output)?);
+        }
+
+        pipeline.add_pipe(builder.finalize());
+        Ok(())
+    }
+}
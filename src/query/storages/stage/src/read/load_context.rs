@@ -15,6 +15,7 @@
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 
+use databend_common_catalog::plan::CheckConstraintExpr;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table_context::TableContext;
@@ -41,6 +42,7 @@ pub struct LoadContext {
 
     pub schema: TableSchemaRef,
     pub default_values: Option<Vec<RemoteExpr>>,
+    pub check_constraints: Vec<CheckConstraintExpr>,
     pub pos_projection: Option<Vec<usize>>,
     pub is_copy: bool,
 
@@ -74,6 +76,7 @@ impl LoadContext {
             .collect::<Vec<_>>();
         let schema = TableSchemaRefExt::create(fields);
         let default_values = stage_table_info.default_values.clone();
+        let check_constraints = stage_table_info.check_constraints.clone();
         let is_copy = ctx.get_query_kind() == QueryKind::CopyIntoTable;
         Ok(Self {
             table_context: ctx,
@@ -81,6 +84,7 @@ impl LoadContext {
             block_compact_thresholds,
             schema,
             default_values,
+            check_constraints,
             pos_projection,
             is_copy,
             file_format_options_ext,
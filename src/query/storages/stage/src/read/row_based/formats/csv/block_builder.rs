@@ -179,6 +179,7 @@ impl RowDecoder for CsvDecoder {
             } else {
                 state.num_rows += 1;
                 state.file_status.num_rows_loaded += 1;
+                state.lines.push(i + batch.start_pos.rows);
             }
             start = *end;
             field_end_idx += num_fields;
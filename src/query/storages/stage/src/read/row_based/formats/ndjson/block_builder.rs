@@ -183,6 +183,7 @@ impl RowDecoder for NdJsonDecoder {
                 } else {
                     state.num_rows += 1;
                     state.file_status.num_rows_loaded += 1;
+                    state.lines.push(batch.start_pos.rows + row_id);
                 }
             }
         }
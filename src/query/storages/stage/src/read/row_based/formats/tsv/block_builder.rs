@@ -207,6 +207,7 @@ impl RowDecoder for TsvDecoder {
                 } else {
                     state.num_rows += 1;
                     state.file_status.num_rows_loaded += 1;
+                    state.lines.push(batch.start_pos.rows + row_id);
                 }
             }
         }
@@ -15,13 +15,16 @@
 use std::mem;
 use std::sync::Arc;
 
+use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::Column;
 use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use databend_common_storage::FileParseError;
 use databend_common_storage::FileStatus;
 use log::debug;
 
@@ -35,6 +38,8 @@ pub struct BlockBuilderState {
     pub num_rows: usize,
     pub file_status: FileStatus,
     pub file_name: String,
+    /// The line in the file of each buffered row.
+    pub lines: Vec<usize>,
 }
 
 impl BlockBuilderState {
@@ -58,6 +63,7 @@ impl BlockBuilderState {
             num_rows: 0,
             file_status: Default::default(),
             file_name: "".to_string(),
+            lines: vec![],
         }
     }
 
@@ -66,6 +72,7 @@ impl BlockBuilderState {
         let capacity = if on_finish { 0 } else { 1024 };
         self.num_rows = 0;
         self.file_name = "".to_string();
+        self.lines.clear();
         Ok(self
             .mutable_columns
             .iter_mut()
@@ -114,6 +121,50 @@ impl BlockBuilder {
             Ok(vec![DataBlock::new_from_columns(columns)])
         }
     }
+
+    /// Removes the rows violating the check constraints of the table, which
+    /// are reported to the error handler.
+    fn check_constraints(
+        &mut self,
+        block: DataBlock,
+        file_name: &str,
+        lines: &[usize],
+    ) -> Result<DataBlock> {
+        let mut violated: Option<Bitmap> = None;
+        for check in &self.ctx.check_constraints {
+            let Some(violations) =
+                check.violations(&block, &self.ctx.func_ctx, &BUILTIN_FUNCTIONS)?
+            else {
+                continue;
+            };
+            for (row, is_violated) in violations.iter().enumerate() {
+                // report each row once, for the first constraint it violates
+                if !is_violated || violated.as_ref().is_some_and(|v| v.get_bit(row)) {
+                    continue;
+                }
+                self.state.file_status.num_rows_loaded -= 1;
+                let e = FileParseError::ConstraintViolated {
+                    message: check.violation_message(&block, row),
+                };
+                self.ctx.error_handler.on_error(
+                    e,
+                    None,
+                    &mut self.state.file_status,
+                    file_name,
+                    lines[row],
+                )?;
+            }
+            violated = Some(match violated {
+                None => violations,
+                Some(v) => &v | &violations,
+            });
+        }
+        match violated {
+            None => Ok(block),
+            Some(violated) => block.filter_with_bitmap(&!&violated),
+        }
+    }
+
     pub fn try_flush_block_by_memory(&mut self) -> Result<Vec<DataBlock>> {
         let mem = self.state.memory_size();
         debug!(
@@ -142,6 +193,18 @@ impl AccumulatingTransform for BlockBuilder {
             self.state.file_name = batch.start_pos.path.clone();
         }
         let mut blocks = self.decoder.add(&mut self.state, batch)?;
+        if !self.ctx.check_constraints.is_empty() {
+            // Check the rows of each batch while their lines are known.
+            let lines = mem::take(&mut self.state.lines);
+            let file_name = self.state.file_name.clone();
+            for block in self.flush_block(false)? {
+                let block = self.check_constraints(block, &file_name, &lines)?;
+                if block.num_rows() > 0 {
+                    blocks.push(block);
+                }
+            }
+            self.state.file_name = file_name;
+        }
         self.state.flush_status(&self.ctx.table_context)?;
         let more = self.try_flush_block_by_memory()?;
         blocks.extend(more);
@@ -0,0 +1,233 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum AuditEventType {
+    Login,
+    CreateUser,
+    AlterUser,
+    DropUser,
+    CreateRole,
+    DropRole,
+    Grant,
+    Revoke,
+    CreateNetworkPolicy,
+    AlterNetworkPolicy,
+    DropNetworkPolicy,
+    NetworkPolicyReject,
+    CreatePasswordPolicy,
+    AlterPasswordPolicy,
+    DropPasswordPolicy,
+    CreateDataMask,
+    DropDataMask,
+    SetColumnDataMask,
+    UnsetColumnDataMask,
+}
+
+impl std::fmt::Display for AuditEventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum AuditOutcome {
+    Success,
+    Failure,
+}
+
+impl std::fmt::Display for AuditOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+fn date_str<S>(dt: &i32, s: S) -> std::result::Result<S::Ok, S::Error>
+where S: Serializer {
+    let t = DateTime::from_timestamp(i64::from(*dt) * 24 * 3600, 0)
+        .unwrap()
+        .naive_utc();
+    s.serialize_str(t.format("%Y-%m-%d").to_string().as_str())
+}
+
+fn datetime_str<S>(dt: &i64, s: S) -> std::result::Result<S::Ok, S::Error>
+where S: Serializer {
+    let t = DateTime::from_timestamp(
+        dt / 1_000_000,
+        TryFrom::try_from((dt % 1_000_000) * 1000).unwrap_or(0),
+    )
+    .unwrap()
+    .naive_utc();
+    s.serialize_str(t.format("%Y-%m-%d %H:%M:%S%.6f").to_string().as_str())
+}
+
+/// One record of the audit trail.
+///
+/// Records of a node are chained: `hash` is the digest of the record together with
+/// the `hash` of the previous record (`prev_hash`), so removing or editing a record
+/// breaks the chain from that point on.
+#[derive(Clone, Serialize)]
+pub struct AuditLogElement {
+    #[serde(serialize_with = "date_str")]
+    pub event_date: i32,
+    #[serde(serialize_with = "datetime_str")]
+    pub event_time: i64,
+    pub event_type: AuditEventType,
+
+    pub tenant_id: String,
+    pub cluster_id: String,
+    pub node_id: String,
+    pub session_type: String,
+    pub query_id: String,
+
+    // Who did it, from where.
+    pub actor: String,
+    pub client_address: String,
+
+    // What was done.
+    pub object_name: String,
+    pub detail: String,
+
+    pub outcome: AuditOutcome,
+    pub error_code: i32,
+    pub error_message: String,
+
+    pub sequence: u64,
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+impl SystemLogElement for AuditLogElement {
+    const TABLE_NAME: &'static str = "audit_log";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("event_date", TableDataType::Date),
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("event_type", TableDataType::String),
+            TableField::new("tenant_id", TableDataType::String),
+            TableField::new("cluster_id", TableDataType::String),
+            TableField::new("node_id", TableDataType::String),
+            TableField::new("session_type", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("actor", TableDataType::String),
+            TableField::new("client_address", TableDataType::String),
+            TableField::new("object_name", TableDataType::String),
+            TableField::new("detail", TableDataType::String),
+            TableField::new("outcome", TableDataType::String),
+            TableField::new("error_code", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new("error_message", TableDataType::String),
+            TableField::new("sequence", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("prev_hash", TableDataType::String),
+            TableField::new("hash", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Date(self.event_date).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.event_type.to_string()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.tenant_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.cluster_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.node_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.session_type.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.actor.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.client_address.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.object_name.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.detail.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.outcome.to_string()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::Int32(self.error_code)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.error_message.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.sequence)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.prev_hash.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.hash.clone()).as_ref());
+        Ok(())
+    }
+}
+
+pub type AuditLogQueue = SystemLogQueue<AuditLogElement>;
+pub type AuditLogTable = SystemLogTable<AuditLogElement>;
@@ -28,6 +28,8 @@ use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_metrics::cache::get_cache_access_count;
+use databend_common_metrics::cache::get_cache_admission_count;
+use databend_common_metrics::cache::get_cache_eviction_count;
 use databend_common_metrics::cache::get_cache_hit_count;
 use databend_common_metrics::cache::get_cache_miss_count;
 use databend_common_storages_fuse::TableContext;
@@ -53,9 +55,12 @@ struct CachesTableColumns {
     size: Vec<u64>,
     capacity: Vec<u64>,
     unit: Vec<String>,
+    policy: Vec<String>,
     access: Vec<u64>,
     hit: Vec<u64>,
     miss: Vec<u64>,
+    admission: Vec<u64>,
+    eviction: Vec<u64>,
 }
 
 impl SyncSystemTable for CachesTable {
@@ -133,12 +138,17 @@ impl SyncSystemTable for CachesTable {
             columns.size.push(cache.bytes_size());
             columns.capacity.push(cache.bytes_capacity());
             columns.unit.push(Unit::Bytes.to_string());
+            columns.policy.push(cache.policy().to_string());
             let access = get_cache_access_count(DISK_TABLE_DATA_CACHE_NAME);
             let hit = get_cache_hit_count(DISK_TABLE_DATA_CACHE_NAME);
             let miss = get_cache_miss_count(DISK_TABLE_DATA_CACHE_NAME);
+            let admission = get_cache_admission_count(DISK_TABLE_DATA_CACHE_NAME);
+            let eviction = get_cache_eviction_count(DISK_TABLE_DATA_CACHE_NAME);
             columns.access.push(access);
             columns.hit.push(hit);
             columns.miss.push(miss);
+            columns.admission.push(admission);
+            columns.eviction.push(eviction);
         }
 
         if let Some(table_column_array_cache) = table_column_array_cache {
@@ -152,9 +162,12 @@ impl SyncSystemTable for CachesTable {
             UInt64Type::from_data(columns.size),
             UInt64Type::from_data(columns.capacity),
             StringType::from_data(columns.unit),
+            StringType::from_data(columns.policy),
             UInt64Type::from_data(columns.access),
             UInt64Type::from_data(columns.hit),
             UInt64Type::from_data(columns.miss),
+            UInt64Type::from_data(columns.admission),
+            UInt64Type::from_data(columns.eviction),
         ]))
     }
 }
@@ -168,9 +181,12 @@ impl CachesTable {
             TableField::new("size", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("capacity", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("unit", TableDataType::String),
+            TableField::new("policy", TableDataType::String),
             TableField::new("access", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("hit", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("miss", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("admission", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("eviction", TableDataType::Number(NumberDataType::UInt64)),
         ]);
 
         let table_info = TableInfo {
@@ -208,13 +224,18 @@ impl CachesTable {
                 columns.capacity.push(cache.items_capacity());
             }
         }
+        columns.policy.push(cache.policy().to_string());
 
         let access = get_cache_access_count(cache.name());
         let hit = get_cache_hit_count(cache.name());
         let miss = get_cache_miss_count(cache.name());
+        let admission = get_cache_admission_count(cache.name());
+        let eviction = get_cache_eviction_count(cache.name());
 
         columns.access.push(access);
         columns.hit.push(hit);
         columns.miss.push(miss);
+        columns.admission.push(admission);
+        columns.eviction.push(eviction);
     }
 }
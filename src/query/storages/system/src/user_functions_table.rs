@@ -21,6 +21,7 @@ use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::DataType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::TimestampType;
 use databend_common_expression::types::VariantType;
@@ -79,7 +80,7 @@ impl AsyncSystemTable for UserFunctionsTable {
 
         for user_function in &user_functions {
             names.push(user_function.name.as_str());
-            is_aggregate.push(None);
+            is_aggregate.push(Some(user_function.is_aggregate));
             languages.push(user_function.language.as_str());
             descriptions.push(user_function.description.as_str());
             arguments.push(serde_json::to_vec(&user_function.arguments)?);
@@ -159,12 +160,15 @@ impl UserFunctionsTable {
             .into_iter()
             .map(|user_function| UserFunction {
                 name: user_function.name,
-                is_aggregate: false,
+                is_aggregate: user_function.definition.is_aggregate_function(),
                 description: user_function.description,
                 language: match &user_function.definition {
                     UDFDefinition::LambdaUDF(_) => String::from("SQL"),
                     UDFDefinition::UDFServer(x) => x.language.clone(),
                     UDFDefinition::UDFScript(x) => x.language.clone(),
+                    UDFDefinition::UDTFSql(_) => String::from("SQL"),
+                    UDFDefinition::UDTFScript(x) => x.language.clone(),
+                    UDFDefinition::UDAFScript(x) => x.language.clone(),
                 },
                 definition: user_function.definition.to_string(),
                 created_on: user_function.created_on,
@@ -184,8 +188,32 @@ impl UserFunctionsTable {
                         return_type: Some(x.return_type.to_string()),
                         arg_types: x.arg_types.iter().map(ToString::to_string).collect(),
                     },
+                    UDFDefinition::UDTFSql(x) => UserFunctionArguments {
+                        parameters: x.arg_names.clone(),
+                        return_type: Some(table_return_type(&x.return_names, &x.return_types)),
+                        arg_types: x.arg_types.iter().map(ToString::to_string).collect(),
+                    },
+                    UDFDefinition::UDTFScript(x) => UserFunctionArguments {
+                        parameters: vec![],
+                        return_type: Some(table_return_type(&x.return_names, &x.return_types)),
+                        arg_types: x.arg_types.iter().map(ToString::to_string).collect(),
+                    },
+                    UDFDefinition::UDAFScript(x) => UserFunctionArguments {
+                        parameters: vec![],
+                        return_type: Some(x.return_type.to_string()),
+                        arg_types: x.arg_types.iter().map(ToString::to_string).collect(),
+                    },
                 },
             })
             .collect())
     }
 }
+
+fn table_return_type(names: &[String], types: &[DataType]) -> String {
+    let columns = names
+        .iter()
+        .zip(types.iter())
+        .map(|(name, ty)| format!("{name} {ty}"))
+        .collect::<Vec<_>>();
+    format!("TABLE({})", columns.join(", "))
+}
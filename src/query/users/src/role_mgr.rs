@@ -225,6 +225,28 @@ impl UserApiProvider {
             .map_err(|e| e.add_message_back("(while revoke role privileges)"))
     }
 
+    #[async_backtrace::framed]
+    pub async fn set_role_workload_group(
+        &self,
+        tenant: &Tenant,
+        role: &String,
+        workload_group: Option<String>,
+    ) -> Result<Option<u64>> {
+        if let Some(group) = &workload_group {
+            // Make sure the workload group exists.
+            self.get_workload_group(tenant, group).await?;
+        }
+
+        let client = self.role_api(tenant);
+        client
+            .update_role_with(role, MatchSeq::GE(1), |ri: &mut RoleInfo| {
+                ri.update_role_time();
+                ri.workload_group = workload_group;
+            })
+            .await
+            .map_err(|e| e.add_message_back("(while set role workload group)"))
+    }
+
     // the grant_role can not have cycle with target_role.
     #[async_backtrace::framed]
     pub async fn grant_role_to_role(
@@ -36,6 +36,7 @@ use databend_common_management::StageApi;
 use databend_common_management::StageMgr;
 use databend_common_management::UserApi;
 use databend_common_management::UserMgr;
+use databend_common_management::WorkloadGroupMgr;
 use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::principal::UserDefinedFunction;
@@ -167,6 +168,10 @@ impl UserApiProvider {
         PasswordPolicyMgr::create(self.client.clone(), tenant)
     }
 
+    pub fn workload_group_api(&self, tenant: &Tenant) -> WorkloadGroupMgr {
+        WorkloadGroupMgr::create(self.client.clone(), tenant)
+    }
+
     pub fn client_session_api(&self, tenant: &Tenant) -> ClientSessionMgr {
         ClientSessionMgr::create(self.client.clone(), tenant)
     }
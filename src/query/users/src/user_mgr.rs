@@ -82,7 +82,7 @@ impl UserApiProvider {
             let ip_addr: Ipv4Addr = match client_ip {
                 Some(client_ip) => client_ip.parse().unwrap(),
                 None => {
-                    return Err(ErrorCode::AuthenticateFailure("Unknown client ip"));
+                    return Err(ErrorCode::NetworkPolicyRejected("Unknown client ip"));
                 }
             };
 
@@ -90,7 +90,7 @@ impl UserApiProvider {
             for blocked_ip in network_policy.blocked_ip_list {
                 let blocked_cidr: Ipv4Cidr = blocked_ip.parse().unwrap();
                 if blocked_cidr.contains(&ip_addr) {
-                    return Err(ErrorCode::AuthenticateFailure(format!(
+                    return Err(ErrorCode::NetworkPolicyRejected(format!(
                         "client ip `{}` is blocked",
                         ip_addr
                     )));
@@ -105,7 +105,7 @@ impl UserApiProvider {
                 }
             }
             if !allow {
-                return Err(ErrorCode::AuthenticateFailure(format!(
+                return Err(ErrorCode::NetworkPolicyRejected(format!(
                     "client ip `{}` is not allowed to login",
                     ip_addr
                 )));
@@ -157,6 +157,14 @@ impl UserApiProvider {
                 )));
             }
         }
+        if let Some(name) = user_info.option.workload_group() {
+            if self.get_workload_group(tenant, name).await.is_err() {
+                return Err(ErrorCode::UnknownWorkloadGroup(format!(
+                    "workload group `{}` is not exist",
+                    name
+                )));
+            }
+        }
         if self.get_configured_user(&user_info.name).is_some() {
             return Err(ErrorCode::UserAlreadyExists(format!(
                 "Same name with configured user `{}`",
@@ -315,6 +323,14 @@ impl UserApiProvider {
                     )));
                 }
             }
+            if let Some(name) = user_option.workload_group() {
+                if self.get_workload_group(tenant, name).await.is_err() {
+                    return Err(ErrorCode::UnknownWorkloadGroup(format!(
+                        "workload group `{}` is not exist",
+                        name
+                    )));
+                }
+            }
         }
         if self.get_configured_user(&user.username).is_some() {
             return Err(ErrorCode::UserAlreadyExists(format!(
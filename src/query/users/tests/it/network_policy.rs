@@ -17,6 +17,7 @@ use chrono::Utc;
 use databend_common_base::base::tokio;
 use databend_common_config::GlobalConfig;
 use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_grpc::RpcClientConf;
 use databend_common_meta_app::principal::AuthInfo;
@@ -90,17 +91,22 @@ async fn test_network_policy() -> Result<()> {
     let res = user_mgr
         .get_user_with_client_ip(&tenant, user.clone(), Some("192.168.0.10"))
         .await;
-    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().code(), ErrorCode::NETWORK_POLICY_REJECTED);
 
     let res = user_mgr
         .get_user_with_client_ip(&tenant, user.clone(), Some("192.168.0.20"))
         .await;
-    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().code(), ErrorCode::NETWORK_POLICY_REJECTED);
 
     let res = user_mgr
         .get_user_with_client_ip(&tenant, user.clone(), Some("127.0.0.1"))
         .await;
-    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().code(), ErrorCode::NETWORK_POLICY_REJECTED);
+
+    let res = user_mgr
+        .get_user_with_client_ip(&tenant, user.clone(), None)
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::NETWORK_POLICY_REJECTED);
 
     // update network policy
     let new_allowed_ip_list = vec!["127.0.0.0/24".to_string()];
@@ -85,6 +85,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 engine: Some(Engine::Fuse),
                 uri_location: None,
                 cluster_by: None,
+                ttl: None,
                 table_options: BTreeMap::new(),
                 as_query: None,
                 table_type: TableType::Normal,
@@ -187,6 +188,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             name: new_column_name,
             data_type,
             expr: None,
+            codec: vec![],
             comment: None,
         }
     }
@@ -209,11 +211,12 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 name: Identifier::from_name(None, name),
                 data_type,
                 expr: default_expr,
+                codec: vec![],
                 comment: None,
             };
             column_defs.push(column_def);
         }
-        CreateTableSource::Columns(column_defs, None)
+        CreateTableSource::Columns(column_defs, None, vec![])
     }
 }
 
@@ -446,6 +446,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                     name,
                     data_type,
                     expr: None,
+                    codec: vec![],
                     comment: None,
                 };
                 (
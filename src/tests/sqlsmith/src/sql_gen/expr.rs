@@ -152,6 +152,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         name: Identifier::from_name(None, "to_date".to_string()),
                         args: vec![arg],
                         params: vec![],
+                        order_by: vec![],
+                        filter: None,
                         window: None,
                         lambda: None,
                     },
@@ -169,6 +171,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         name: Identifier::from_name(None, "to_timestamp".to_string()),
                         args: vec![arg],
                         params: vec![],
+                        order_by: vec![],
+                        filter: None,
                         window: None,
                         lambda: None,
                     },
@@ -226,6 +230,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         name: Identifier::from_name(None, "to_bitmap".to_string()),
                         args: vec![arg],
                         params: vec![],
+                        order_by: vec![],
+                        filter: None,
                         window: None,
                         lambda: None,
                     },
@@ -244,6 +250,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         name: Identifier::from_name(None, "parse_json".to_string()),
                         args: vec![arg],
                         params: vec![],
+                        order_by: vec![],
+                        filter: None,
                         window: None,
                         lambda: None,
                     },
@@ -261,6 +269,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         name: Identifier::from_name(None, "to_binary".to_string()),
                         args: vec![arg],
                         params: vec![],
+                        order_by: vec![],
+                        filter: None,
                         window: None,
                         lambda: None,
                     },
@@ -280,6 +290,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         name: Identifier::from_name(None, "to_geometry".to_string()),
                         args: vec![arg],
                         params: vec![],
+                        order_by: vec![],
+                        filter: None,
                         window: None,
                         lambda: None,
                     },
@@ -299,6 +311,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         name: Identifier::from_name(None, "st_geographyfromewkt".to_string()),
                         args: vec![arg],
                         params: vec![],
+                        order_by: vec![],
+                        filter: None,
                         window: None,
                         lambda: None,
                     },
@@ -527,6 +541,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 }
                 2 => Expr::CountAll {
                     span: None,
+                    filter: None,
                     window: None,
                 },
                 3 => {
@@ -628,6 +643,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         name: Identifier::from_name(None, "to_binary"),
                         args: vec![arg],
                         params: vec![],
+                        order_by: vec![],
+                        filter: None,
                         window: None,
                         lambda: None,
                     },
@@ -666,6 +683,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         name: Identifier::from_name(None, func_name),
                         args,
                         params: vec![],
+                        order_by: vec![],
+                        filter: None,
                         window: None,
                         lambda: None,
                     },
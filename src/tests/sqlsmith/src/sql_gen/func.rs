@@ -735,6 +735,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
         name: String,
         params: Vec<Literal>,
         args_type: Vec<DataType>,
+        order_by: vec![],
+        filter: None,
         window: Option<WindowDesc>,
         lambda: Option<Lambda>,
     ) -> Expr {
@@ -781,6 +783,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 name,
                 args,
                 params,
+                order_by: vec![],
+                filter: None,
                 window,
                 lambda,
             },
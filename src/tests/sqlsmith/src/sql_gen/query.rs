@@ -65,6 +65,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             order_by,
             limit,
             offset,
+            fetch: None,
             ignore_result: false,
         }
     }
@@ -119,6 +120,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             select_list,
             from,
             selection: None,
+            connect_by: None,
             group_by: None,
             having: None,
             window_list: None,
@@ -133,6 +135,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             order_by: vec![],
             limit: vec![],
             offset: None,
+            fetch: None,
             ignore_result: false,
         };
 
@@ -312,6 +315,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             select_list,
             from,
             selection,
+            connect_by: None,
             group_by,
             having: self.gen_selection(),
             window_list: self.gen_window_list(),
@@ -543,6 +547,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                                     name: Identifier::from_name(None, "to_timestamp".to_string()),
                                     args: vec![arg],
                                     params: vec![],
+                                    order_by: vec![],
+                                    filter: None,
                                     window: None,
                                     lambda: None,
                                 },
@@ -560,6 +566,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                                     name: Identifier::from_name(None, "to_date".to_string()),
                                     args: vec![arg],
                                     params: vec![],
+                                    order_by: vec![],
+                                    filter: None,
                                     window: None,
                                     lambda: None,
                                 },
@@ -0,0 +1,35 @@
+#!/usr/bin/env bash
+
+set -e
+
+rpk() {
+	docker exec -i redpanda rpk "$@"
+}
+
+for _ in $(seq 1 30); do
+	if rpk cluster health | grep -q "Healthy:.*true"; then
+		break
+	fi
+	sleep 1
+done
+
+rpk topic delete databend_json databend_csv databend_avro >/dev/null 2>&1 || true
+rpk topic create databend_json -p 2
+rpk topic create databend_csv -p 1
+rpk topic create databend_avro -p 1
+
+printf '%s\n' \
+	'{"id": 1, "name": "a"}' \
+	'{"id": 2, "name": "b"}' \
+	'{"id": 3}' \
+	'{"id": 4, "name": "d"}' | rpk topic produce databend_json
+
+printf '%s\n' \
+	'1,"x"' \
+	'2,"y, z"' | rpk topic produce databend_csv
+
+# Avro datums of the record {id: int, name: string}, ints are zigzag varints and
+# strings are prefixed by their zigzag varint length.
+printf '%b\n' \
+	'\002\002a' \
+	'\004\004bb' | rpk topic produce databend_avro
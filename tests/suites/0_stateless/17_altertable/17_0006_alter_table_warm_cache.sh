@@ -0,0 +1,35 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+# Each row of the result is: node, segments, blocks, bloom filters, bytes.
+# The node ids differ between runs, the rows of the nodes are the same unless
+# the blocks are scheduled by cache affinity.
+per_node() {
+	awk -F'\t' '{print $2, $3, $4, ($5 > 0 ? "loaded" : "empty")}' | sort -u
+}
+
+echo "DROP DATABASE IF EXISTS test_warm_cache" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE DATABASE test_warm_cache" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE TABLE test_warm_cache.t(a int, b string, c int) bloom_index_columns='b'" | $BENDSQL_CLIENT_CONNECT
+
+echo "== empty table"
+echo "set enable_cache_affinity_scheduling = 0; alter table test_warm_cache.t warm cache" | $BENDSQL_CLIENT_CONNECT | per_node
+
+echo "INSERT INTO test_warm_cache.t values(1, 'x', 10),(2, 'y', 20)" | $BENDSQL_CLIENT_CONNECT
+echo "INSERT INTO test_warm_cache.t values(3, 'z', 30),(4, 'w', 40)" | $BENDSQL_CLIENT_CONNECT
+
+echo "== all the columns"
+echo "set enable_cache_affinity_scheduling = 0; alter table test_warm_cache.t warm cache" | $BENDSQL_CLIENT_CONNECT | per_node
+
+echo "== the columns without bloom filter"
+echo "set enable_cache_affinity_scheduling = 0; alter table test_warm_cache.t warm cache columns (a, c)" | $BENDSQL_CLIENT_CONNECT | per_node
+
+echo "== the blocks of the pruned segments"
+echo "set enable_cache_affinity_scheduling = 0; alter table test_warm_cache.t warm cache where a > 2" | $BENDSQL_CLIENT_CONNECT | per_node
+
+echo "== the blocks spread by cache affinity"
+echo "set enable_cache_affinity_scheduling = 1; alter table test_warm_cache.t warm cache" | $BENDSQL_CLIENT_CONNECT | awk -F'\t' '{blocks += $3} END {print blocks}'
+
+echo "DROP DATABASE test_warm_cache" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,27 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop user if exists wg_user" | $BENDSQL_CLIENT_CONNECT
+echo "drop workload group if exists wg_limits" | $BENDSQL_CLIENT_CONNECT
+echo "create workload group wg_limits max_concurrency = 1 queue_timeout = 1" | $BENDSQL_CLIENT_CONNECT
+echo "create user wg_user identified by 'password' with set workload_group = 'wg_limits'" | $BENDSQL_CLIENT_CONNECT
+
+export TEST_WORKLOAD_GROUP_USER_CONNECT="bendsql --user=wg_user --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+echo "## the only running slot of the group is taken, even a light query waits for it"
+echo "select sleep(3)" | $TEST_WORKLOAD_GROUP_USER_CONNECT >/dev/null 2>&1 &
+sleep 1
+echo "select 1" | $TEST_WORKLOAD_GROUP_USER_CONNECT 2>&1 | grep -o "query queuing timeout in workload group wg_limits"
+wait
+
+echo "## the slot is released when the query finishes"
+echo "select 1" | $TEST_WORKLOAD_GROUP_USER_CONNECT
+
+echo "## the memory quota of the group applies to its queries"
+echo "alter workload group wg_limits set max_concurrency = 0 memory_quota = 1048576" | $BENDSQL_CLIENT_CONNECT
+echo "select count(distinct number::string) from numbers(1000000)" | $TEST_WORKLOAD_GROUP_USER_CONNECT 2>&1 | grep -o "exceeds limit" | head -n 1
+
+echo "drop user wg_user" | $BENDSQL_CLIENT_CONNECT
+echo "drop workload group wg_limits" | $BENDSQL_CLIENT_CONNECT
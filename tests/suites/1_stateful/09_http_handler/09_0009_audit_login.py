@@ -0,0 +1,63 @@
+#!/usr/bin/env python3
+
+import requests
+
+query_url = "http://localhost:8000/v1/query"
+login_url = "http://localhost:8000/v1/session/login"
+root_auth = ("root", "")
+user = "u_09_0009"
+auth = (user, "p_09_0009")
+
+
+def do_query(query, auth=None, headers=None):
+    query_payload = {"sql": query, "pagination": {"wait_time_secs": 11}}
+    return requests.post(
+        query_url,
+        auth=auth,
+        headers={"Content-Type": "application/json", **(headers or {})},
+        json=query_payload,
+    )
+
+
+def main():
+    do_query(f"drop user if exists {user}", root_auth)
+    do_query(f"create user {user} identified by '{auth[1]}'", root_auth)
+
+    # one login of a client session
+    resp = requests.post(
+        login_url,
+        auth=auth,
+        headers={"Content-Type": "application/json"},
+        json={},
+    ).json()
+    session_token = resp.get("session_token")
+    session_id = resp.get("session_id")
+    for _ in range(2):
+        do_query("select 1", headers={"Authorization": f"Bearer {session_token}"})
+
+    # the requests of the client session with password are not logins again
+    for _ in range(2):
+        do_query("select 2", auth, {"X-DATABEND-SESSION-ID": session_id})
+
+    # each request without client session is a login
+    for _ in range(2):
+        do_query("select 3", auth)
+
+    print(do_query("select 4", (user, "wrong")).status_code)
+
+    resp = do_query(
+        "select outcome, count(*) from system.audit_log "
+        f"where event_type = 'Login' and object_name = '{user}' "
+        "group by outcome order by outcome",
+        root_auth,
+    ).json()
+    print(resp.get("data"))
+
+    do_query(f"drop user {user}", root_auth)
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception as e:
+        print(f"An error occurred: {e}")
@@ -0,0 +1,29 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "create or replace table test_http_prepare(id int, name string);" | $BENDSQL_CLIENT_CONNECT
+echo "insert into test_http_prepare values(1, 'a'), (2, 'b'), (3, 'c');" | $BENDSQL_CLIENT_CONNECT
+
+post() {
+	curl -s -u root: -XPOST "http://localhost:8000/v1/query" --header 'Content-Type: application/json' -d "$1"
+}
+
+# the prepared statements are kept in the session state returned to the client
+session=$(post '{"sql": "prepare s from '"'"'select name from test_http_prepare where id > ? and name <> ? order by id'"'"'", "pagination": { "wait_time_secs": 5}}' | jq -c ".session")
+
+echo "execute by name"
+post '{"prepared": {"name": "s", "params": [1, "c"]}, "session": '"$session"', "pagination": { "wait_time_secs": 5}}' | jq -c ".data, .error"
+post '{"prepared": {"name": "s", "params": [0, "x"]}, "session": '"$session"', "pagination": { "wait_time_secs": 5}}' | jq -c ".data, .error"
+
+echo "wrong number of parameters"
+post '{"prepared": {"name": "s", "params": [1]}, "session": '"$session"', "pagination": { "wait_time_secs": 5}}' | jq -c ".error.code"
+
+echo "unknown prepared statement"
+post '{"prepared": {"name": "S", "params": [1, "c"]}, "session": '"$session"', "pagination": { "wait_time_secs": 5}}' | jq -c ".error.code"
+
+echo "sql and prepared"
+post '{"sql": "select 1", "prepared": {"name": "s", "params": [1, "c"]}, "session": '"$session"', "pagination": { "wait_time_secs": 5}}' | jq -c ".error.code"
+
+echo "drop table if exists test_http_prepare;" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,32 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+## Setup
+echo "drop database if exists test_vacuum_refs" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE DATABASE test_vacuum_refs" | $BENDSQL_CLIENT_CONNECT
+echo "create table test_vacuum_refs.a(c int)" | $BENDSQL_CLIENT_CONNECT
+
+echo "INSERT INTO test_vacuum_refs.a VALUES (1)" | $BENDSQL_CLIENT_CONNECT
+echo "INSERT INTO test_vacuum_refs.a VALUES (2)" | $BENDSQL_CLIENT_CONNECT
+echo "alter table test_vacuum_refs.a create tag v1" | $BENDSQL_CLIENT_CONNECT
+echo "alter table test_vacuum_refs.a create branch dev" | $BENDSQL_CLIENT_CONNECT
+echo "INSERT INTO test_vacuum_refs.a at (branch => dev) VALUES (10)" | $BENDSQL_CLIENT_CONNECT
+
+# the blocks held by the tag and the branch are no longer referenced by the current snapshot
+echo "truncate table test_vacuum_refs.a" | $BENDSQL_CLIENT_CONNECT
+echo "INSERT INTO test_vacuum_refs.a VALUES (3)" | $BENDSQL_CLIENT_CONNECT
+
+echo "set data_retention_time_in_days=0; vacuum table test_vacuum_refs.a" | $BENDSQL_CLIENT_CONNECT >/dev/null
+
+# the tag and the branch MUST still be readable after vacuum
+echo "select c from test_vacuum_refs.a at (tag => v1) order by c" | $BENDSQL_CLIENT_CONNECT
+echo "select c from test_vacuum_refs.a at (branch => dev) order by c" | $BENDSQL_CLIENT_CONNECT
+echo "select c from test_vacuum_refs.a order by c" | $BENDSQL_CLIENT_CONNECT
+
+# purge the history before the tag, the tag MUST still be readable
+echo "set data_retention_time_in_days=0; optimize table test_vacuum_refs.a purge before (tag => v1)" | $BENDSQL_CLIENT_CONNECT
+echo "select c from test_vacuum_refs.a at (tag => v1) order by c" | $BENDSQL_CLIENT_CONNECT
+
+echo "drop database if exists test_vacuum_refs" | $BENDSQL_CLIENT_CONNECT